@@ -44,7 +44,7 @@ impl DependentFile {
     pub fn update(&self, updated_version: &str) {
         let updated_contents = self
             .regex
-            .replace(&self.contents, (self.replacement)(updated_version).as_str());
+            .replace_all(&self.contents, (self.replacement)(updated_version).as_str());
         fs::write(&self.path, updated_contents.as_ref())
             .unwrap_or_else(|error| panic!("should write {}: {:?}", self.path.display(), error));
     }
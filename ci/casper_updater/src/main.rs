@@ -179,6 +179,9 @@ fn main() {
     let types = Package::cargo("types", &*regex_data::types::DEPENDENT_FILES);
     types.update();
 
+    let node_types = Package::cargo("node_types", &*regex_data::node_types::DEPENDENT_FILES);
+    node_types.update();
+
     let execution_engine = Package::cargo(
         "execution_engine",
         &*regex_data::execution_engine::DEPENDENT_FILES,
@@ -194,6 +197,9 @@ fn main() {
     let client = Package::cargo("client", &*regex_data::client::DEPENDENT_FILES);
     client.update();
 
+    let rpc_client = Package::cargo("rpc_client", &*regex_data::rpc_client::DEPENDENT_FILES);
+    rpc_client.update();
+
     let smart_contracts_contract = Package::cargo(
         "smart_contracts/contract",
         &*regex_data::smart_contracts_contract::DEPENDENT_FILES,
@@ -52,6 +52,16 @@ pub mod types {
                 Regex::new(r#"(?m)(^casper-types = \{[^\}]*version = )"(?:[^"]+)"#).unwrap(),
                 replacement,
             ),
+            DependentFile::new(
+                "node_types/Cargo.toml",
+                Regex::new(r#"(?m)(^casper-types = \{[^\}]*version = )"(?:[^"]+)"#).unwrap(),
+                replacement,
+            ),
+            DependentFile::new(
+                "rpc_client/Cargo.toml",
+                Regex::new(r#"(?m)(^casper-types = \{[^\}]*version = )"(?:[^"]+)"#).unwrap(),
+                replacement,
+            ),
             DependentFile::new(
                 "smart_contracts/contract/Cargo.toml",
                 Regex::new(r#"(?m)(^casper-types = \{[^\}]*version = )"(?:[^"]+)"#).unwrap(),
@@ -138,6 +148,48 @@ pub mod node_macros {
     });
 }
 
+pub mod node_types {
+    use super::*;
+
+    pub static DEPENDENT_FILES: Lazy<Vec<DependentFile>> = Lazy::new(|| {
+        vec![
+            DependentFile::new(
+                "client/Cargo.toml",
+                Regex::new(r#"(?m)(^casper-node-types = \{[^\}]*version = )"(?:[^"]+)"#).unwrap(),
+                replacement,
+            ),
+            DependentFile::new(
+                "execution_engine/Cargo.toml",
+                Regex::new(r#"(?m)(^casper-node-types = \{[^\}]*version = )"(?:[^"]+)"#).unwrap(),
+                replacement,
+            ),
+            DependentFile::new(
+                "node/Cargo.toml",
+                Regex::new(r#"(?m)(^casper-node-types = \{[^\}]*version = )"(?:[^"]+)"#).unwrap(),
+                replacement,
+            ),
+            DependentFile::new(
+                "node_types/Cargo.toml",
+                MANIFEST_VERSION_REGEX.clone(),
+                replacement,
+            ),
+            DependentFile::new(
+                "node_types/src/lib.rs",
+                Regex::new(
+                    r#"(?m)(#!\[doc\(html_root_url = "https://docs.rs/casper-node-types)/(?:[^"]+)"#,
+                )
+                .unwrap(),
+                replacement_with_slash,
+            ),
+            DependentFile::new(
+                "rpc_client/Cargo.toml",
+                Regex::new(r#"(?m)(^casper-node-types = \{[^\}]*version = )"(?:[^"]+)"#).unwrap(),
+                replacement,
+            ),
+        ]
+    });
+}
+
 pub mod node {
     use super::*;
 
@@ -177,6 +229,31 @@ pub mod client {
     });
 }
 
+pub mod rpc_client {
+    use super::*;
+
+    pub static DEPENDENT_FILES: Lazy<Vec<DependentFile>> = Lazy::new(|| {
+        vec![
+            DependentFile::new(
+                "client/Cargo.toml",
+                Regex::new(r#"(?m)(^casper-rpc-client = \{[^\}]*version = )"(?:[^"]+)"#).unwrap(),
+                replacement,
+            ),
+            DependentFile::new(
+                "rpc_client/Cargo.toml",
+                MANIFEST_VERSION_REGEX.clone(),
+                replacement,
+            ),
+            DependentFile::new(
+                "rpc_client/src/lib.rs",
+                Regex::new(r#"(?m)(#!\[doc\(html_root_url = "https://docs.rs/casper-rpc-client)/(?:[^"]+)"#)
+                    .unwrap(),
+                replacement_with_slash,
+            ),
+        ]
+    });
+}
+
 pub mod smart_contracts_contract {
     use super::*;
 
@@ -2,28 +2,31 @@
 
 #include "casper_client.h"
 
-#define RESPONSE_BUFFER_LEN 1048576
-#define ERROR_LEN 255
 #define NODE_ADDRESS "http://localhost:50101"
 #define RPC_ID "1"
 #define VERBOSE 0
 
 int main(int argc, char **argv) {
-    casper_setup_client();
+    casper_client_t *client = casper_client_new();
+    if (client == NULL) {
+        printf("Failed to create client.\n");
+        return 1;
+    }
 
-    unsigned char response_buffer[RESPONSE_BUFFER_LEN] = {0};
+    casper_string_t *response = NULL;
+    casper_string_t *error = NULL;
     casper_error_t success = casper_get_auction_info(
-        RPC_ID, NODE_ADDRESS, VERBOSE, response_buffer, RESPONSE_BUFFER_LEN);
+        client, RPC_ID, NODE_ADDRESS, VERBOSE, &response, &error);
     if (success == CASPER_SUCCESS) {
-        printf("Got successful response:\n%s\n", response_buffer);
+        printf("Got successful response:\n%s\n", casper_string_ptr(response));
     } else {
-        unsigned char error[ERROR_LEN] = {0};
-        casper_get_last_error(error, ERROR_LEN);
-        printf("Got error:\n%s\n", error);
+        printf("Got error:\n%s\n", casper_string_ptr(error));
     }
     printf("Done.\n");
 
-    casper_shutdown_client();
+    casper_string_free(response);
+    casper_string_free(error);
+    casper_client_free(client);
 
     return 0;
 }
@@ -2,14 +2,16 @@
 
 #include "casper_client.h"
 
-#define RESPONSE_BUFFER_LEN 1024
-#define ERROR_LEN 255
 #define NODE_ADDRESS "http://localhost:50101"
 #define RPC_ID "1"
 #define VERBOSE 0
 
 int main(int argc, char **argv) {
-    casper_setup_client();
+    casper_client_t *client = casper_client_new();
+    if (client == NULL) {
+        printf("Failed to create client.\n");
+        return 1;
+    }
 
     casper_deploy_params_t deploy_params = {0};
     deploy_params.secret_key = "resources/local/secret_keys/node-1.pem";
@@ -29,20 +31,21 @@ int main(int argc, char **argv) {
 
     casper_session_params_t session_params = {0};
 
-    unsigned char response_buffer[RESPONSE_BUFFER_LEN] = {0};
+    casper_string_t *response = NULL;
+    casper_string_t *error = NULL;
     casper_error_t success = casper_put_deploy(
-        RPC_ID, NODE_ADDRESS, VERBOSE, &deploy_params, &session_params,
-        &payment_params, response_buffer, RESPONSE_BUFFER_LEN);
+        client, RPC_ID, NODE_ADDRESS, VERBOSE, &deploy_params, &session_params,
+        &payment_params, &response, &error);
     if (success == CASPER_SUCCESS) {
-        printf("Got successful response\n%s\n", response_buffer);
+        printf("Got successful response\n%s\n", casper_string_ptr(response));
     } else {
-        unsigned char error[ERROR_LEN] = {0};
-        casper_get_last_error(error, ERROR_LEN);
-        printf("Got error:\n%s\n", error);
+        printf("Got error:\n%s\n", casper_string_ptr(error));
     }
     printf("Done.\n");
 
-    casper_shutdown_client();
+    casper_string_free(response);
+    casper_string_free(error);
+    casper_client_free(client);
 
     return 0;
 }
@@ -1,26 +1,33 @@
+#include <string.h>
+
 #include "unity.h"
 #include "casper_client.h"
 
+static casper_client_t *client = NULL;
+
 void setUp(void) {
-    casper_setup_client();
+    client = casper_client_new();
 }
 
 void tearDown(void) {
-    casper_shutdown_client();
+    casper_client_free(client);
+    client = NULL;
+}
+
+static void set_deploy_params(casper_deploy_params_t *deploy_params) {
+    deploy_params->secret_key = "resources/local/secret_keys/node-1.pem";
+    deploy_params->ttl = "10s";
+    deploy_params->chain_name = "casper-charlie-testnet1";
+    deploy_params->gas_price = "11";
 }
 
-void test_should_be_no_last_error_on_startup(void) {
-    unsigned char error[255] = {0};
-    int bytes_read = casper_get_last_error(error, 255);
-    TEST_ASSERT_EQUAL_INT(0, bytes_read);
+void test_should_create_client(void) {
+    TEST_ASSERT_NOT_NULL(client);
 }
 
-void test_should_get_last_error_after_bad_request(void) {
+void test_should_get_error_after_bad_request(void) {
     casper_deploy_params_t deploy_params = {0};
-    deploy_params.secret_key = "resources/local/secret_keys/node-1.pem";
-    deploy_params.ttl = "10s";
-    deploy_params.chain_name = "casper-charlie-testnet1";
-    deploy_params.gas_price = "11";
+    set_deploy_params(&deploy_params);
 
     casper_payment_params_t payment_params = {0};
     payment_params.payment_amount = "1000";
@@ -36,23 +43,80 @@ void test_should_get_last_error_after_bad_request(void) {
     session_params.session_name = "standard_payment";
     session_params.session_entry_point = "session_entry_point";
 
-    unsigned char response_buffer[1024] = {0};
-
+    casper_string_t *response = NULL;
+    casper_string_t *error = NULL;
     casper_error_t success =
-        casper_put_deploy("1", "", false, &deploy_params, &session_params,
-                          &payment_params, response_buffer, 1024);
+        casper_put_deploy(client, "1", "", false, &deploy_params,
+                          &session_params, &payment_params, &response, &error);
 
     TEST_ASSERT_NOT_EQUAL_INT(CASPER_SUCCESS, success);
-
-    unsigned char error[255] = {0};
-    int bytes_read = casper_get_last_error(error, 255);
+    TEST_ASSERT_NULL(response);
     TEST_ASSERT_EQUAL_STRING("Failed to get RPC response: builder error: relative URL without a base",
-                             error);
+                             casper_string_ptr(error));
+    casper_string_free(error);
+}
+
+void test_should_report_null_client(void) {
+    casper_string_t *response = NULL;
+    casper_string_t *error = NULL;
+    casper_error_t success =
+        casper_get_auction_info(NULL, "1", "", false, &response, &error);
+
+    TEST_ASSERT_EQUAL_INT(CASPER_FFI_PTR_NULL_BUT_REQUIRED, success);
+    TEST_ASSERT_EQUAL_STRING("Required argument 'client' was null",
+                             casper_string_ptr(error));
+    casper_string_free(error);
+}
+
+void test_should_create_sign_and_serialize_deploy(void) {
+    casper_deploy_params_t deploy_params = {0};
+    set_deploy_params(&deploy_params);
+
+    casper_payment_params_t payment_params = {0};
+    payment_params.payment_amount = "1000";
+
+    casper_session_params_t session_params = {0};
+    session_params.session_name = "standard_payment";
+    session_params.session_entry_point = "session_entry_point";
+
+    casper_deploy_t *deploy = NULL;
+    casper_string_t *error = NULL;
+    TEST_ASSERT_EQUAL_INT(
+        CASPER_SUCCESS, casper_deploy_new(&deploy_params, &session_params,
+                                          &payment_params, &deploy, &error));
+    TEST_ASSERT_EQUAL_INT(
+        CASPER_SUCCESS,
+        casper_deploy_sign(deploy, "resources/local/secret_keys/node-2.pem",
+                           &error));
+
+    casper_string_t *hash = NULL;
+    TEST_ASSERT_EQUAL_INT(CASPER_SUCCESS,
+                          casper_deploy_hash(deploy, &hash, &error));
+    TEST_ASSERT_EQUAL_INT(64, casper_string_len(hash));
+
+    casper_string_t *json = NULL;
+    TEST_ASSERT_EQUAL_INT(CASPER_SUCCESS,
+                          casper_deploy_to_json(deploy, &json, &error));
+    TEST_ASSERT_NOT_NULL(strstr(casper_string_ptr(json),
+                                casper_string_ptr(hash)));
+
+    casper_deploy_t *parsed = NULL;
+    TEST_ASSERT_EQUAL_INT(
+        CASPER_SUCCESS,
+        casper_deploy_from_json(casper_string_ptr(json), &parsed, &error));
+    TEST_ASSERT_NULL(error);
+
+    casper_deploy_free(parsed);
+    casper_string_free(json);
+    casper_string_free(hash);
+    casper_deploy_free(deploy);
 }
 
 int main(int argc, char **argv) {
     UNITY_BEGIN();
-    RUN_TEST(test_should_be_no_last_error_on_startup);
-    RUN_TEST(test_should_get_last_error_after_bad_request);
+    RUN_TEST(test_should_create_client);
+    RUN_TEST(test_should_get_error_after_bad_request);
+    RUN_TEST(test_should_report_null_client);
+    RUN_TEST(test_should_create_sign_and_serialize_deploy);
     return UNITY_END();
 }
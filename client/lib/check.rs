@@ -0,0 +1,328 @@
+//! Checking a deploy against the chain name and deploy limits of a network before sending it, so
+//! that problems are reported precisely instead of as a bare rejection by the node.
+
+use std::fs;
+
+use serde::{Deserialize, Serialize};
+
+use casper_node::{rpcs::info::GetDeployConfigResult, types::chainspec::DeployConfig};
+use casper_node_types::{Deploy, DeployHash, Timestamp};
+use casper_types::U512;
+
+use crate::{
+    error::{Error, Result},
+    rpc::RpcCall,
+};
+
+/// The JSON-RPC error code returned by nodes which don't support a method.
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// The chain name and deploy limits of a network.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct DeployLimits {
+    /// The name of the chain, which the deploy's `chain_name` must match.
+    pub chain_name: String,
+    /// The limits applied to deploys, as configured in the chainspec.
+    pub deploy_config: DeployConfig,
+}
+
+/// The sections of a chainspec file relevant for checking deploys.
+#[derive(Deserialize)]
+struct ChainspecDeploySections {
+    network: ChainspecNetworkSection,
+    deploys: DeployConfig,
+}
+
+#[derive(Deserialize)]
+struct ChainspecNetworkSection {
+    name: String,
+}
+
+impl DeployLimits {
+    /// Reads the limits from the `[network]` and `[deploys]` sections of a chainspec file.
+    pub(crate) fn from_chainspec_file(path: &str) -> Result<Self> {
+        let contents = fs::read_to_string(path).map_err(|error| Error::IoError {
+            context: format!("unable to read chainspec file at '{}'", path),
+            error,
+        })?;
+        let sections: ChainspecDeploySections = toml::from_str(&contents).map_err(|error| {
+            Error::InvalidArgument("chainspec_path", format!("{}: {}", path, error))
+        })?;
+        Ok(DeployLimits {
+            chain_name: sections.network.name,
+            deploy_config: sections.deploys,
+        })
+    }
+
+    /// Fetches the limits from the node, or returns `None` if the node doesn't provide them.
+    pub(crate) async fn from_node(
+        maybe_rpc_id: &str,
+        node_address: &str,
+        verbosity_level: u64,
+    ) -> Result<Option<Self>> {
+        let response = match RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+            .get_deploy_config()
+            .await
+        {
+            Ok(response) => response,
+            Err(Error::ResponseIsError(error)) if error.code == METHOD_NOT_FOUND => {
+                return Ok(None)
+            }
+            Err(error) => return Err(error),
+        };
+        let result = response
+            .get_result()
+            .cloned()
+            .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+        let result: GetDeployConfigResult = serde_json::from_value(result)?;
+        Ok(Some(DeployLimits {
+            chain_name: result.chain_name,
+            deploy_config: DeployConfig::from(result.deploy_config),
+        }))
+    }
+}
+
+/// Summary of a deploy which passed all checks.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct DeployCheckReport {
+    /// The hash of the deploy.
+    pub deploy_hash: DeployHash,
+    /// The chain the deploy is intended for.
+    pub chain_name: String,
+    /// The number of approvals of the deploy.
+    pub approval_count: usize,
+    /// The size of the serialized deploy in bytes.
+    pub serialized_size: usize,
+    /// The amount of motes offered for payment, taken from the payment code's "amount" arg.  Zero
+    /// for native transfers.
+    pub payment_amount: U512,
+    /// The gas price of the deploy.
+    pub gas_price: u64,
+    /// The maximum gas the deploy can consume, i.e. the payment amount divided by the gas price.
+    pub gas_limit: U512,
+}
+
+/// Checks `deploy` against `limits` as the node's deploy acceptor and block proposer would, at time
+/// `now`.
+pub(crate) fn check_deploy(
+    deploy: &Deploy,
+    limits: &DeployLimits,
+    now: Timestamp,
+) -> Result<DeployCheckReport> {
+    let header = deploy.header();
+    let config = &limits.deploy_config;
+
+    if deploy.approvals().is_empty() {
+        return Err(Error::InvalidDeploy(
+            "the deploy has no approvals, it must be signed by at least one key".to_string(),
+        ));
+    }
+    if header.expired(now) {
+        return Err(Error::InvalidDeploy(format!(
+            "the deploy expired at {}",
+            header.expires()
+        )));
+    }
+
+    deploy
+        .clone()
+        .is_acceptable(&limits.chain_name, config)
+        .map_err(|failure| Error::InvalidDeploy(failure.to_string()))?;
+
+    let deploy_type = deploy
+        .deploy_type()
+        .map_err(|error| Error::InvalidDeploy(error.to_string()))?;
+    let payment_amount = deploy_type.payment_amount().value();
+
+    let max_payment_cost = config.max_payment_cost().value();
+    if !max_payment_cost.is_zero() && payment_amount > max_payment_cost {
+        return Err(Error::InvalidDeploy(format!(
+            "payment amount of {} motes exceeds limit of {}",
+            payment_amount, max_payment_cost
+        )));
+    }
+
+    if header.gas_price() == 0 {
+        return Err(Error::InvalidDeploy(
+            "the gas price must not be zero".to_string(),
+        ));
+    }
+    let gas_limit = payment_amount / header.gas_price();
+    if gas_limit > U512::from(config.block_gas_limit()) {
+        return Err(Error::InvalidDeploy(format!(
+            "gas limit of {} exceeds the block gas limit of {}, so the deploy would never be \
+            included in a block",
+            gas_limit,
+            config.block_gas_limit()
+        )));
+    }
+
+    let serialized_size = deploy_type.size();
+    let max_block_size = config.max_block_size() as usize;
+    if max_block_size != 0 && serialized_size > max_block_size {
+        return Err(Error::InvalidDeploy(format!(
+            "serialized deploy of {} bytes exceeds the block size limit of {}",
+            serialized_size, max_block_size
+        )));
+    }
+
+    Ok(DeployCheckReport {
+        deploy_hash: *deploy.id(),
+        chain_name: header.chain_name().to_string(),
+        approval_count: deploy.approvals().len(),
+        serialized_size,
+        payment_amount,
+        gas_price: header.gas_price(),
+        gas_limit,
+    })
+}
+
+/// Checks `deploy` against the limits provided by the node.  The check is skipped if the node
+/// doesn't provide them.
+pub(crate) async fn check_deploy_with_node(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy: &Deploy,
+) -> Result<()> {
+    match DeployLimits::from_node(maybe_rpc_id, node_address, verbosity_level).await? {
+        Some(limits) => check_deploy(deploy, &limits, Timestamp::now()).map(|_| ()),
+        None => {
+            if verbosity_level > 0 {
+                println!("Node doesn't provide its deploy limits, skipping the deploy check");
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use casper_node_types::{crypto::AsymmetricKeyExt, ExecutableDeployItem, TimeDiff};
+    use casper_types::{bytesrepr::Bytes, RuntimeArgs, SecretKey};
+
+    use super::*;
+
+    const CHAIN_NAME: &str = "casper-test";
+
+    fn limits() -> DeployLimits {
+        let chainspec = r#"
+            [network]
+            name = 'casper-test'
+            timestamp = '2021-01-01T00:00:00Z'
+
+            [deploys]
+            max_payment_cost = '0'
+            max_ttl = '1day'
+            max_dependencies = 10
+            max_block_size = 10_485_760
+            block_max_deploy_count = 100
+            block_max_transfer_count = 1000
+            block_gas_limit = 10_000_000_000_000
+            payment_args_max_length = 1024
+            session_args_max_length = 1024
+            native_transfer_minimum_motes = 2_500_000_000
+        "#;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("chainspec.toml");
+        fs::write(&path, chainspec).unwrap();
+        DeployLimits::from_chainspec_file(path.to_str().unwrap()).unwrap()
+    }
+
+    fn deploy(ttl: &str, payment_amount: u64, session_args: RuntimeArgs, sign: bool) -> Deploy {
+        let mut payment_args = RuntimeArgs::new();
+        payment_args
+            .insert("amount", U512::from(payment_amount))
+            .unwrap();
+        let secret_key = SecretKey::generate_ed25519().unwrap();
+        let mut rng = rand::thread_rng();
+        let mut deploy = Deploy::new(
+            Timestamp::now(),
+            TimeDiff::from_str(ttl).unwrap(),
+            1,
+            vec![],
+            CHAIN_NAME.to_string(),
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::new(),
+                args: payment_args,
+            },
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: vec![0; 16].into(),
+                args: session_args,
+            },
+            &secret_key,
+            &mut rng,
+        );
+        if !sign {
+            deploy = serde_json::from_value({
+                let mut json = serde_json::to_value(&deploy).unwrap();
+                json["approvals"] = serde_json::json!([]);
+                json
+            })
+            .unwrap();
+        }
+        deploy
+    }
+
+    fn error_message(result: Result<DeployCheckReport>) -> String {
+        match result {
+            Err(Error::InvalidDeploy(message)) => message,
+            other => panic!("expected an invalid deploy error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_accept_valid_deploy() {
+        let deploy = deploy("1h", 1_000_000, RuntimeArgs::new(), true);
+        let report = check_deploy(&deploy, &limits(), Timestamp::now()).unwrap();
+        assert_eq!(report.approval_count, 1);
+        assert_eq!(report.payment_amount, U512::from(1_000_000));
+        assert_eq!(report.gas_limit, U512::from(1_000_000));
+    }
+
+    #[test]
+    fn should_reject_invalid_deploys_precisely() {
+        let limits = limits();
+
+        let unsigned = deploy("1h", 1_000_000, RuntimeArgs::new(), false);
+        assert!(
+            error_message(check_deploy(&unsigned, &limits, Timestamp::now()))
+                .contains("no approvals")
+        );
+
+        let long_lived = deploy("2days", 1_000_000, RuntimeArgs::new(), true);
+        assert!(
+            error_message(check_deploy(&long_lived, &limits, Timestamp::now()))
+                .contains("time-to-live of 2days exceeds limit of 1day")
+        );
+
+        let mut session_args = RuntimeArgs::new();
+        session_args.insert("blob", vec![0u8; 2048]).unwrap();
+        let oversized = deploy("1h", 1_000_000, session_args, true);
+        assert!(
+            error_message(check_deploy(&oversized, &limits, Timestamp::now()))
+                .contains("session code runtime args")
+        );
+
+        let expensive = deploy("1h", 20_000_000_000_000, RuntimeArgs::new(), true);
+        assert!(
+            error_message(check_deploy(&expensive, &limits, Timestamp::now()))
+                .contains("block gas limit")
+        );
+
+        let valid = deploy("1h", 1_000_000, RuntimeArgs::new(), true);
+        let later = Timestamp::now() + TimeDiff::from(Duration::from_secs(7200));
+        assert!(error_message(check_deploy(&valid, &limits, later)).contains("expired"));
+
+        let other_chain = DeployLimits {
+            chain_name: "casper-other".to_string(),
+            ..limits
+        };
+        assert!(
+            error_message(check_deploy(&valid, &other_chain, Timestamp::now()))
+                .contains("invalid chain name")
+        );
+    }
+}
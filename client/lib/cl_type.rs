@@ -1,51 +1,150 @@
 //! Supported `CLType` and `CLValue` parsing and validation.
 
-use std::{result::Result as StdResult, str::FromStr};
+use std::{borrow::Cow, convert::TryFrom, result::Result as StdResult, str::FromStr};
 
 use casper_types::{
-    account::AccountHash, bytesrepr::ToBytes, AsymmetricType, CLType, CLTyped, CLValue, Key,
-    PublicKey, URef, U128, U256, U512,
+    account::AccountHash,
+    bytesrepr::{self, ToBytes, OPTION_NONE_TAG, OPTION_SOME_TAG, RESULT_ERR_TAG, RESULT_OK_TAG},
+    AsymmetricType, CLType, CLTyped, CLValue, Key, PublicKey, URef, U128, U256, U512,
 };
+use serde_json::Value as JsonValue;
 
 use crate::error::{Error, Result};
 
+const OPTION: &str = "option";
+const LIST: &str = "list";
+const BYTE_ARRAY: &str = "byte_array";
+const RESULT: &str = "result";
+const MAP: &str = "map";
+const TUPLE1: &str = "tuple1";
+const TUPLE2: &str = "tuple2";
+const TUPLE3: &str = "tuple3";
+const ANY: &str = "any";
+
 /// Parse a `CLType` from `&str`.
+///
+/// Besides the names listed in `supported_cl_types()`, the following compound types are supported,
+/// where `T`, `E`, `K`, `V`, `T1`, `T2` and `T3` can be any supported type including compound ones:
+/// `option<T>`, `list<T>`, `byte_array<N>`, `result<T, E>`, `map<K, V>`, `tuple1<T>`,
+/// `tuple2<T1, T2>`, `tuple3<T1, T2, T3>` and `any`.  Parsing is case-insensitive and ignores
+/// whitespace.
 pub(crate) fn parse(strval: &str) -> StdResult<CLType, ()> {
-    let supported_types = supported_cl_types();
-    let cl_type = match strval.to_lowercase() {
-        t if t == supported_types[0].0 => supported_types[0].1.clone(),
-        t if t == supported_types[1].0 => supported_types[1].1.clone(),
-        t if t == supported_types[2].0 => supported_types[2].1.clone(),
-        t if t == supported_types[3].0 => supported_types[3].1.clone(),
-        t if t == supported_types[4].0 => supported_types[4].1.clone(),
-        t if t == supported_types[5].0 => supported_types[5].1.clone(),
-        t if t == supported_types[6].0 => supported_types[6].1.clone(),
-        t if t == supported_types[7].0 => supported_types[7].1.clone(),
-        t if t == supported_types[8].0 => supported_types[8].1.clone(),
-        t if t == supported_types[9].0 => supported_types[9].1.clone(),
-        t if t == supported_types[10].0 => supported_types[10].1.clone(),
-        t if t == supported_types[11].0 => supported_types[11].1.clone(),
-        t if t == supported_types[12].0 => supported_types[12].1.clone(),
-        t if t == supported_types[13].0 => supported_types[13].1.clone(),
-        t if t == supported_types[14].0 => supported_types[14].1.clone(),
-        t if t == supported_types[15].0 => supported_types[15].1.clone(),
-        t if t == supported_types[16].0 => supported_types[16].1.clone(),
-        t if t == supported_types[17].0 => supported_types[17].1.clone(),
-        t if t == supported_types[18].0 => supported_types[18].1.clone(),
-        t if t == supported_types[19].0 => supported_types[19].1.clone(),
-        t if t == supported_types[20].0 => supported_types[20].1.clone(),
-        t if t == supported_types[21].0 => supported_types[21].1.clone(),
-        t if t == supported_types[22].0 => supported_types[22].1.clone(),
-        t if t == supported_types[23].0 => supported_types[23].1.clone(),
-        t if t == supported_types[24].0 => supported_types[24].1.clone(),
-        t if t == supported_types[25].0 => supported_types[25].1.clone(),
-        t if t == supported_types[26].0 => supported_types[26].1.clone(),
-        t if t == supported_types[27].0 => supported_types[27].1.clone(),
-        t if t == supported_types[28].0 => supported_types[28].1.clone(),
-        t if t == supported_types[29].0 => supported_types[29].1.clone(),
+    let normalized: String = strval
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    match parse_prefix(&normalized)? {
+        (cl_type, "") => Ok(cl_type),
+        _ => Err(()),
+    }
+}
+
+/// Parses a type from the start of `input`, returning it along with the remaining input.
+fn parse_prefix(input: &str) -> StdResult<(CLType, &str), ()> {
+    let name_len = input
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or_else(|| input.len());
+    let (name, remainder) = input.split_at(name_len);
+
+    let remainder = match remainder.strip_prefix('<') {
+        Some(remainder) => remainder,
+        None => {
+            if name == ANY {
+                return Ok((CLType::Any, remainder));
+            }
+            let cl_type = supported_cl_types()
+                .into_iter()
+                .find(|(supported_name, _)| *supported_name == name)
+                .map(|(_, cl_type)| cl_type)
+                .ok_or(())?;
+            return Ok((cl_type, remainder));
+        }
+    };
+
+    let (cl_type, remainder) = match name {
+        OPTION => {
+            let (inner, remainder) = parse_prefix(remainder)?;
+            (CLType::Option(Box::new(inner)), remainder)
+        }
+        LIST => {
+            let (inner, remainder) = parse_prefix(remainder)?;
+            (CLType::List(Box::new(inner)), remainder)
+        }
+        BYTE_ARRAY => {
+            let len_end = remainder.find('>').ok_or(())?;
+            let len = u32::from_str(&remainder[..len_end]).map_err(|_| ())?;
+            (CLType::ByteArray(len), &remainder[len_end..])
+        }
+        RESULT => {
+            let (ok, remainder) = parse_prefix(remainder)?;
+            let remainder = remainder.strip_prefix(',').ok_or(())?;
+            let (err, remainder) = parse_prefix(remainder)?;
+            let cl_type = CLType::Result {
+                ok: Box::new(ok),
+                err: Box::new(err),
+            };
+            (cl_type, remainder)
+        }
+        MAP => {
+            let (key, remainder) = parse_prefix(remainder)?;
+            let remainder = remainder.strip_prefix(',').ok_or(())?;
+            let (value, remainder) = parse_prefix(remainder)?;
+            let cl_type = CLType::Map {
+                key: Box::new(key),
+                value: Box::new(value),
+            };
+            (cl_type, remainder)
+        }
+        TUPLE1 => {
+            let (t1, remainder) = parse_prefix(remainder)?;
+            (CLType::Tuple1([Box::new(t1)]), remainder)
+        }
+        TUPLE2 => {
+            let (t1, remainder) = parse_prefix(remainder)?;
+            let remainder = remainder.strip_prefix(',').ok_or(())?;
+            let (t2, remainder) = parse_prefix(remainder)?;
+            (CLType::Tuple2([Box::new(t1), Box::new(t2)]), remainder)
+        }
+        TUPLE3 => {
+            let (t1, remainder) = parse_prefix(remainder)?;
+            let remainder = remainder.strip_prefix(',').ok_or(())?;
+            let (t2, remainder) = parse_prefix(remainder)?;
+            let remainder = remainder.strip_prefix(',').ok_or(())?;
+            let (t3, remainder) = parse_prefix(remainder)?;
+            (
+                CLType::Tuple3([Box::new(t1), Box::new(t2), Box::new(t3)]),
+                remainder,
+            )
+        }
         _ => return Err(()),
     };
-    Ok(cl_type)
+    let remainder = remainder.strip_prefix('>').ok_or(())?;
+    Ok((cl_type, remainder))
+}
+
+/// Returns the name of a `CLType` as accepted by `parse()`.
+pub(crate) fn name(cl_type: &CLType) -> String {
+    if let Some((name, _)) = supported_cl_types()
+        .into_iter()
+        .find(|(_, supported_type)| supported_type == cl_type)
+    {
+        return name.to_string();
+    }
+    match cl_type {
+        CLType::Option(inner) => format!("{}<{}>", OPTION, name(inner)),
+        CLType::List(inner) => format!("{}<{}>", LIST, name(inner)),
+        CLType::ByteArray(len) => format!("{}<{}>", BYTE_ARRAY, len),
+        CLType::Result { ok, err } => format!("{}<{}, {}>", RESULT, name(ok), name(err)),
+        CLType::Map { key, value } => format!("{}<{}, {}>", MAP, name(key), name(value)),
+        CLType::Tuple1([t1]) => format!("{}<{}>", TUPLE1, name(t1)),
+        CLType::Tuple2([t1, t2]) => format!("{}<{}, {}>", TUPLE2, name(t1), name(t2)),
+        CLType::Tuple3([t1, t2, t3]) => {
+            format!("{}<{}, {}, {}>", TUPLE3, name(t1), name(t2), name(t3))
+        }
+        CLType::Any => ANY.to_string(),
+        _ => unreachable!("all other types are supported simple types"),
+    }
 }
 
 pub(crate) fn supported_cl_types() -> Vec<(&'static str, CLType)> {
@@ -100,12 +199,14 @@ pub mod help {
     pub fn supported_cl_type_list() -> String {
         let mut msg = String::new();
         let supported_types = super::supported_cl_types();
-        for (index, item) in supported_types.iter().map(|(name, _)| name).enumerate() {
+        for item in supported_types.iter().map(|(name, _)| name) {
             msg.push_str(item);
-            if index < supported_types.len() - 1 {
-                msg.push_str(", ")
-            }
+            msg.push_str(", ");
         }
+        msg.push_str(
+            "option<T>, list<T>, byte_array<N>, result<T, E>, map<K, V>, tuple1<T>, \
+             tuple2<T1, T2>, tuple3<T1, T2, T3>, any",
+        );
         msg
     }
 
@@ -146,6 +247,19 @@ Prefix the type with "opt_" and use the term "null" without quotes to specify a
 "name_08:opt_string='a value'"  # Some("a value".to_string())
 "name_09:opt_string='null'"     # Some("null".to_string())
 "name_10:opt_string=null"       # None
+
+Compound types can be nested, and their values are given as JSON, apart from byte arrays and
+"any" which are given as hex:
+"name_01:list<u64>='[1, 2, 3]'"
+"name_02:list<key>='["{}"]'"
+"name_03:map<string, u512>='{{"alice": "1000", "bob": "20"}}'"
+"name_04:map<u8, bool>='[[1, true], [2, false]]'"
+"name_05:byte_array<4>='01020304'"
+"name_06:result<string, u32>='{{"Ok": "done"}}'"
+"name_07:result<string, u32>='{{"Err": 7}}'"
+"name_08:tuple2<string, option<u8>>='["a value", null]'"
+"name_09:option<list<u8>>=null"
+"name_10:any='0102'"
 "#,
             Key::Account(AccountHash::new(array)).to_formatted_string(),
             Key::Hash(array).to_formatted_string(),
@@ -157,6 +271,7 @@ Prefix the type with "opt_" and use the term "null" without quotes to specify a
             )
             .unwrap()
             .to_hex(),
+            Key::Hash(array).to_formatted_string(),
         )
     }
 }
@@ -189,7 +304,17 @@ where
 }
 
 /// Returns a value built from a single arg which has been split into its constituent parts.
+///
+/// Values of the types listed in `supported_cl_types()` are given as plain text, values of
+/// `byte_array<N>` and `any` as hex, and values of all other compound types as JSON.
 pub fn parts_to_cl_value(cl_type: CLType, value: &str) -> Result<CLValue> {
+    let is_simple = supported_cl_types()
+        .iter()
+        .any(|(_, supported_type)| *supported_type == cl_type);
+    if !is_simple {
+        return parse_compound(cl_type, value);
+    }
+
     let (cl_type_to_parse, optional_status, trimmed_value) = match cl_type {
         CLType::Option(inner_type) => {
             if value == "null" {
@@ -336,11 +461,15 @@ pub fn parts_to_cl_value(cl_type: CLType, value: &str) -> Result<CLValue> {
         }
         CLType::ByteArray(32) => {
             let parse = || {
-                AccountHash::from_formatted_str(trimmed_value).map_err(|error| {
-                    Error::InvalidCLValue(format!(
-                        "can't parse {} as AccountHash: {:?}",
-                        trimmed_value, error
-                    ))
+                AccountHash::from_formatted_str(trimmed_value).or_else(|error| {
+                    parse_byte_array(trimmed_value, 32)
+                        .map(|bytes| AccountHash::new(<[u8; 32]>::try_from(&bytes[..]).unwrap()))
+                        .map_err(|_| {
+                            Error::InvalidCLValue(format!(
+                                "can't parse {} as AccountHash: {:?}",
+                                trimmed_value, error
+                            ))
+                        })
                 })
             };
             parse_to_cl_value(optional_status, parse)
@@ -371,3 +500,525 @@ pub fn parts_to_cl_value(cl_type: CLType, value: &str) -> Result<CLValue> {
         _ => unreachable!(),
     }
 }
+
+/// A value of a compound type, parsed from JSON.
+///
+/// The derived ordering matches that of the corresponding Rust types, so that map entries can be
+/// serialized in the same order as a `BTreeMap`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Value {
+    Bool(bool),
+    I32(i32),
+    I64(i64),
+    U8(u8),
+    U32(u32),
+    U64(u64),
+    U128(U128),
+    U256(U256),
+    U512(U512),
+    Unit,
+    String(String),
+    Key(Key),
+    URef(URef),
+    PublicKey(PublicKey),
+    Option(Option<Box<Value>>),
+    List(Vec<Value>),
+    ByteArray(Vec<u8>),
+    Result(StdResult<Box<Value>, Box<Value>>),
+    Map(Vec<(Value, Value)>),
+    Tuple(Vec<Value>),
+    Any(Vec<u8>),
+}
+
+impl Value {
+    /// Serializes the value the same way as the corresponding Rust type.
+    fn write_bytes(&self, bytes: &mut Vec<u8>) -> StdResult<(), bytesrepr::Error> {
+        match self {
+            Value::Bool(value) => bytes.append(&mut value.to_bytes()?),
+            Value::I32(value) => bytes.append(&mut value.to_bytes()?),
+            Value::I64(value) => bytes.append(&mut value.to_bytes()?),
+            Value::U8(value) => bytes.append(&mut value.to_bytes()?),
+            Value::U32(value) => bytes.append(&mut value.to_bytes()?),
+            Value::U64(value) => bytes.append(&mut value.to_bytes()?),
+            Value::U128(value) => bytes.append(&mut value.to_bytes()?),
+            Value::U256(value) => bytes.append(&mut value.to_bytes()?),
+            Value::U512(value) => bytes.append(&mut value.to_bytes()?),
+            Value::Unit => (),
+            Value::String(value) => bytes.append(&mut value.to_bytes()?),
+            Value::Key(value) => bytes.append(&mut value.to_bytes()?),
+            Value::URef(value) => bytes.append(&mut value.to_bytes()?),
+            Value::PublicKey(value) => bytes.append(&mut value.to_bytes()?),
+            Value::Option(None) => bytes.push(OPTION_NONE_TAG),
+            Value::Option(Some(inner)) => {
+                bytes.push(OPTION_SOME_TAG);
+                inner.write_bytes(bytes)?;
+            }
+            Value::List(items) => {
+                write_length(items.len(), bytes)?;
+                for item in items {
+                    item.write_bytes(bytes)?;
+                }
+            }
+            Value::ByteArray(raw) | Value::Any(raw) => bytes.extend_from_slice(raw),
+            Value::Result(Ok(inner)) => {
+                bytes.push(RESULT_OK_TAG);
+                inner.write_bytes(bytes)?;
+            }
+            Value::Result(Err(inner)) => {
+                bytes.push(RESULT_ERR_TAG);
+                inner.write_bytes(bytes)?;
+            }
+            Value::Map(entries) => {
+                write_length(entries.len(), bytes)?;
+                for (key, value) in entries {
+                    key.write_bytes(bytes)?;
+                    value.write_bytes(bytes)?;
+                }
+            }
+            Value::Tuple(items) => {
+                for item in items {
+                    item.write_bytes(bytes)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_length(length: usize, bytes: &mut Vec<u8>) -> StdResult<(), bytesrepr::Error> {
+    bytes.append(&mut (length as u32).to_bytes()?);
+    Ok(())
+}
+
+/// Parses a value of a type not listed in `supported_cl_types()`.
+fn parse_compound(cl_type: CLType, value: &str) -> Result<CLValue> {
+    let parsed = if value == "null" && matches!(cl_type, CLType::Option(_)) {
+        Value::Option(None)
+    } else {
+        let trimmed_value = value.trim_matches('\'');
+        if value == trimmed_value {
+            return Err(Error::InvalidCLValue(format!(
+                "value in simple arg should be surrounded by single quotes unless it's a null \
+                 optional value (value passed: {})",
+                value
+            )));
+        }
+        match cl_type {
+            CLType::ByteArray(_) | CLType::Any => {
+                json_to_value(&cl_type, &JsonValue::String(trimmed_value.to_string()))?
+            }
+            _ => {
+                let json = serde_json::from_str(trimmed_value).map_err(|error| {
+                    Error::InvalidCLValue(format!(
+                        "can't parse {} as JSON for {}: {}",
+                        trimmed_value,
+                        name(&cl_type),
+                        error
+                    ))
+                })?;
+                json_to_value(&cl_type, &json)?
+            }
+        }
+    };
+
+    let mut bytes = vec![];
+    parsed.write_bytes(&mut bytes).map_err(|error| {
+        Error::InvalidCLValue(format!(
+            "unable to serialize cl value of type {}: {:?}",
+            name(&cl_type),
+            error
+        ))
+    })?;
+    Ok(CLValue::from_components(cl_type, bytes))
+}
+
+fn invalid_json(json: &JsonValue, cl_type: &CLType) -> Error {
+    Error::InvalidCLValue(format!("can't parse {} as {}", json, name(cl_type)))
+}
+
+/// Returns the text of a JSON string or number, so that integers can be given as either.
+fn number_text<'a>(json: &'a JsonValue, cl_type: &CLType) -> Result<Cow<'a, str>> {
+    match json {
+        JsonValue::String(text) => Ok(Cow::Borrowed(text)),
+        JsonValue::Number(number) => Ok(Cow::Owned(number.to_string())),
+        _ => Err(invalid_json(json, cl_type)),
+    }
+}
+
+/// Parses a byte array of the given length from hex.
+fn parse_byte_array(hex_value: &str, length: u32) -> Result<Vec<u8>> {
+    let bytes = hex::decode(hex_value).map_err(|error| {
+        Error::InvalidCLValue(format!("can't parse {} as hex: {}", hex_value, error))
+    })?;
+    if bytes.len() != length as usize {
+        return Err(Error::InvalidCLValue(format!(
+            "expected {} bytes, but {} has {}",
+            length,
+            hex_value,
+            bytes.len()
+        )));
+    }
+    Ok(bytes)
+}
+
+/// Parses a value of the given type from JSON.
+fn json_to_value(cl_type: &CLType, json: &JsonValue) -> Result<Value> {
+    let invalid = || invalid_json(json, cl_type);
+    let value = match cl_type {
+        CLType::Bool => Value::Bool(json.as_bool().ok_or_else(invalid)?),
+        CLType::I32 => {
+            Value::I32(i32::from_str(&number_text(json, cl_type)?).map_err(|_| invalid())?)
+        }
+        CLType::I64 => {
+            Value::I64(i64::from_str(&number_text(json, cl_type)?).map_err(|_| invalid())?)
+        }
+        CLType::U8 => Value::U8(u8::from_str(&number_text(json, cl_type)?).map_err(|_| invalid())?),
+        CLType::U32 => {
+            Value::U32(u32::from_str(&number_text(json, cl_type)?).map_err(|_| invalid())?)
+        }
+        CLType::U64 => {
+            Value::U64(u64::from_str(&number_text(json, cl_type)?).map_err(|_| invalid())?)
+        }
+        CLType::U128 => {
+            Value::U128(U128::from_dec_str(&number_text(json, cl_type)?).map_err(|_| invalid())?)
+        }
+        CLType::U256 => {
+            Value::U256(U256::from_dec_str(&number_text(json, cl_type)?).map_err(|_| invalid())?)
+        }
+        CLType::U512 => {
+            Value::U512(U512::from_dec_str(&number_text(json, cl_type)?).map_err(|_| invalid())?)
+        }
+        CLType::Unit => {
+            if !json.is_null() {
+                return Err(invalid());
+            }
+            Value::Unit
+        }
+        CLType::String => Value::String(json.as_str().ok_or_else(invalid)?.to_string()),
+        CLType::Key => {
+            let text = json.as_str().ok_or_else(invalid)?;
+            Value::Key(Key::from_formatted_str(text).map_err(|_| invalid())?)
+        }
+        CLType::URef => {
+            let text = json.as_str().ok_or_else(invalid)?;
+            Value::URef(URef::from_formatted_str(text).map_err(|_| invalid())?)
+        }
+        CLType::PublicKey => {
+            let text = json.as_str().ok_or_else(invalid)?;
+            Value::PublicKey(PublicKey::from_hex(text).map_err(|_| invalid())?)
+        }
+        CLType::Option(inner_type) => match json {
+            JsonValue::Null => Value::Option(None),
+            _ => Value::Option(Some(Box::new(json_to_value(inner_type, json)?))),
+        },
+        CLType::List(inner_type) => {
+            let items = json
+                .as_array()
+                .ok_or_else(invalid)?
+                .iter()
+                .map(|item| json_to_value(inner_type, item))
+                .collect::<Result<_>>()?;
+            Value::List(items)
+        }
+        CLType::ByteArray(length) => match json {
+            JsonValue::String(text) => {
+                let account_hash = if *length == 32 {
+                    AccountHash::from_formatted_str(text).ok()
+                } else {
+                    None
+                };
+                match account_hash {
+                    Some(account_hash) => Value::ByteArray(account_hash.value().to_vec()),
+                    None => Value::ByteArray(parse_byte_array(text, *length)?),
+                }
+            }
+            JsonValue::Array(items) => {
+                if items.len() != *length as usize {
+                    return Err(Error::InvalidCLValue(format!(
+                        "expected {} bytes, but {} has {}",
+                        length,
+                        json,
+                        items.len()
+                    )));
+                }
+                let bytes = items
+                    .iter()
+                    .map(|item| match json_to_value(&CLType::U8, item)? {
+                        Value::U8(byte) => Ok(byte),
+                        _ => unreachable!("parsed as u8"),
+                    })
+                    .collect::<Result<_>>()?;
+                Value::ByteArray(bytes)
+            }
+            _ => return Err(invalid()),
+        },
+        CLType::Result { ok, err } => {
+            let object = json.as_object().filter(|object| object.len() == 1);
+            match object.and_then(|object| object.iter().next()) {
+                Some((variant, inner)) if variant == "Ok" => {
+                    Value::Result(Ok(Box::new(json_to_value(ok, inner)?)))
+                }
+                Some((variant, inner)) if variant == "Err" => {
+                    Value::Result(Err(Box::new(json_to_value(err, inner)?)))
+                }
+                _ => {
+                    return Err(Error::InvalidCLValue(format!(
+                    "can't parse {} as {}. Should be {{\"Ok\": <value>}} or {{\"Err\": <value>}}",
+                    json,
+                    name(cl_type)
+                )))
+                }
+            }
+        }
+        CLType::Map { key, value } => {
+            let mut entries = match json {
+                JsonValue::Object(object) => object
+                    .iter()
+                    .map(|(map_key, map_value)| {
+                        Ok((
+                            json_to_value(key, &JsonValue::String(map_key.clone()))?,
+                            json_to_value(value, map_value)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+                JsonValue::Array(pairs) => pairs
+                    .iter()
+                    .map(|pair| match pair.as_array().map(Vec::as_slice) {
+                        Some([map_key, map_value]) => Ok((
+                            json_to_value(key, map_key)?,
+                            json_to_value(value, map_value)?,
+                        )),
+                        _ => Err(Error::InvalidCLValue(format!(
+                            "can't parse {} as a map entry. Should be [<key>, <value>]",
+                            pair
+                        ))),
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+                _ => return Err(invalid()),
+            };
+            entries.sort_by(|(key1, _), (key2, _)| key1.cmp(key2));
+            if entries.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+                return Err(Error::InvalidCLValue(format!(
+                    "can't parse {} as {}: duplicate key",
+                    json,
+                    name(cl_type)
+                )));
+            }
+            Value::Map(entries)
+        }
+        CLType::Tuple1(types) => Value::Tuple(json_to_tuple(types, json, cl_type)?),
+        CLType::Tuple2(types) => Value::Tuple(json_to_tuple(types, json, cl_type)?),
+        CLType::Tuple3(types) => Value::Tuple(json_to_tuple(types, json, cl_type)?),
+        CLType::Any => {
+            let text = json.as_str().ok_or_else(invalid)?;
+            Value::Any(hex::decode(text).map_err(|error| {
+                Error::InvalidCLValue(format!("can't parse {} as hex: {}", text, error))
+            })?)
+        }
+    };
+    Ok(value)
+}
+
+/// Parses a tuple given as a JSON array with exactly one element per type.
+fn json_to_tuple(types: &[Box<CLType>], json: &JsonValue, cl_type: &CLType) -> Result<Vec<Value>> {
+    match json.as_array() {
+        Some(items) if items.len() == types.len() => types
+            .iter()
+            .zip(items)
+            .map(|(item_type, item)| json_to_value(item_type, item))
+            .collect(),
+        _ => Err(Error::InvalidCLValue(format!(
+            "can't parse {} as {}. Should be an array of {} values",
+            json,
+            name(cl_type),
+            types.len()
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    const KEY: &str = "hash-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+
+    fn cl_value(type_str: &str, value: &str) -> CLValue {
+        parts_to_cl_value(parse(type_str).unwrap(), value).unwrap()
+    }
+
+    fn parse_error(type_str: &str, value: &str) -> Error {
+        parts_to_cl_value(parse(type_str).unwrap(), value).unwrap_err()
+    }
+
+    #[test]
+    fn should_round_trip_type_names() {
+        let types = vec![
+            CLType::U512,
+            CLType::Option(Box::new(CLType::Key)),
+            CLType::Option(Box::new(CLType::Option(Box::new(CLType::U8)))),
+            CLType::List(Box::new(CLType::Key)),
+            CLType::ByteArray(7),
+            CLType::ByteArray(32),
+            CLType::Result {
+                ok: Box::new(CLType::String),
+                err: Box::new(CLType::List(Box::new(CLType::U32))),
+            },
+            CLType::Map {
+                key: Box::new(CLType::String),
+                value: Box::new(CLType::U512),
+            },
+            CLType::Tuple1([Box::new(CLType::Bool)]),
+            CLType::Tuple2([Box::new(CLType::PublicKey), Box::new(CLType::Unit)]),
+            CLType::Tuple3([
+                Box::new(CLType::URef),
+                Box::new(CLType::Any),
+                Box::new(CLType::Map {
+                    key: Box::new(CLType::U64),
+                    value: Box::new(CLType::Tuple1([Box::new(CLType::I64)])),
+                }),
+            ]),
+            CLType::Any,
+        ];
+        for cl_type in types {
+            assert_eq!(parse(&name(&cl_type)), Ok(cl_type));
+        }
+
+        assert_eq!(parse("List<Key>"), Ok(CLType::List(Box::new(CLType::Key))));
+        assert_eq!(
+            parse(" Map< String , U512 > "),
+            Ok(CLType::Map {
+                key: Box::new(CLType::String),
+                value: Box::new(CLType::U512),
+            })
+        );
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_types() {
+        for invalid in &[
+            "",
+            "list",
+            "list<>",
+            "list<u8",
+            "list<u8>>",
+            "map<u8>",
+            "map<u8,u8,u8>",
+            "tuple2<u8>",
+            "byte_array<x>",
+            "byte_array<-1>",
+            "option<foo>",
+            "foo<u8>",
+        ] {
+            assert_eq!(parse(invalid), Err(()), "{} should fail to parse", invalid);
+        }
+    }
+
+    #[test]
+    fn should_parse_list_and_map_values() {
+        let key = Key::from_formatted_str(KEY).unwrap();
+        assert_eq!(
+            cl_value("list<key>", &format!("'[\"{}\", \"{}\"]'", KEY, KEY)),
+            CLValue::from_t(vec![key, key]).unwrap()
+        );
+
+        let mut map = BTreeMap::new();
+        let _ = map.insert("bob".to_string(), U512::from(20));
+        let _ = map.insert("alice".to_string(), U512::from(1000));
+        let expected = CLValue::from_t(map).unwrap();
+        assert_eq!(
+            cl_value("map<string, u512>", r#"'{"bob": 20, "alice": "1000"}'"#),
+            expected
+        );
+        assert_eq!(
+            cl_value("map<string, u512>", r#"'[["bob", 20], ["alice", 1000]]'"#),
+            expected
+        );
+
+        // Map entries are serialized in the order of the keys, not of the JSON input.
+        let mut map = BTreeMap::new();
+        let _ = map.insert(10u8, true);
+        let _ = map.insert(2u8, false);
+        assert_eq!(
+            cl_value("map<u8, bool>", "'{\"10\": true, \"2\": false}'"),
+            CLValue::from_t(map).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_parse_byte_array_values() {
+        let bytes = (1..33).collect::<Vec<u8>>();
+        let array = <[u8; 32]>::try_from(bytes.as_ref()).unwrap();
+        let expected = CLValue::from_t(array).unwrap();
+        assert_eq!(
+            cl_value("byte_array<32>", &format!("'{}'", hex::encode(&bytes))),
+            expected
+        );
+        assert_eq!(
+            cl_value(
+                "byte_array<32>",
+                &format!("'{}'", AccountHash::new(array).to_formatted_string())
+            ),
+            expected
+        );
+        assert_eq!(
+            cl_value("byte_array<3>", "'0a0b0c'"),
+            CLValue::from_t([10u8, 11, 12]).unwrap()
+        );
+        assert_eq!(
+            cl_value("list<byte_array<2>>", "'[\"0102\", [3, 4]]'"),
+            CLValue::from_t(vec![[1u8, 2], [3, 4]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_parse_result_tuple_and_option_values() {
+        assert_eq!(
+            cl_value("result<string, u32>", r#"'{"Ok": "done"}'"#),
+            CLValue::from_t(Ok::<String, u32>("done".to_string())).unwrap()
+        );
+        assert_eq!(
+            cl_value("result<string, u32>", r#"'{"Err": 7}'"#),
+            CLValue::from_t(Err::<String, u32>(7)).unwrap()
+        );
+        assert_eq!(
+            cl_value("tuple2<string, option<u8>>", r#"'["a value", null]'"#),
+            CLValue::from_t(("a value".to_string(), None::<u8>)).unwrap()
+        );
+        assert_eq!(
+            cl_value("tuple3<bool, unit, i64>", "'[true, null, -5]'"),
+            CLValue::from_t((true, (), -5i64)).unwrap()
+        );
+        assert_eq!(
+            cl_value("option<list<u8>>", "null"),
+            CLValue::from_t(None::<Vec<u8>>).unwrap()
+        );
+        assert_eq!(
+            cl_value("option<list<u8>>", "'[1, 2]'"),
+            CLValue::from_t(Some(vec![1u8, 2])).unwrap()
+        );
+        assert_eq!(
+            cl_value("any", "'0102'"),
+            CLValue::from_components(CLType::Any, vec![1, 2])
+        );
+    }
+
+    #[test]
+    fn should_fail_to_parse_invalid_compound_values() {
+        let errors = vec![
+            parse_error("map<string, u8>", r#"'[["a", 1], ["a", 2]]'"#),
+            parse_error("byte_array<4>", "'010203'"),
+            parse_error("byte_array<2>", "'[1, 256]'"),
+            parse_error("list<u8>", "[1, 2]"),
+            parse_error("list<u8>", "'[1, \"x\"]'"),
+            parse_error("list<u8>", "null"),
+            parse_error("tuple2<u8, u8>", "'[1]'"),
+            parse_error("result<u8, u8>", r#"'{"Ok": 1, "Err": 2}'"#),
+            parse_error("option<list<u8>>", "'nul'"),
+        ];
+        for error in errors {
+            assert!(matches!(error, Error::InvalidCLValue(_)), "{:?}", error);
+        }
+    }
+}
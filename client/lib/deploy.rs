@@ -6,39 +6,11 @@ use std::{
 use semver::Version;
 use serde::{Deserialize, Serialize};
 
-use casper_execution_engine::core::engine_state::ExecutableDeployItem;
-use casper_node::{
-    rpcs::{account::PutDeploy, chain::GetBlockResult, info::GetDeploy, RpcWithParams},
-    types::{Deploy, DeployHash, TimeDiff, Timestamp},
-};
+use casper_node_types::{crypto, Deploy, DeployHash, ExecutableDeployItem, TimeDiff, Timestamp};
+use casper_rpc_client::rpcs::chain::GetBlockResult;
 use casper_types::SecretKey;
 
-use crate::{
-    error::{Error, Result},
-    rpc::RpcClient,
-};
-
-/// SendDeploy allows sending a deploy to the node.
-pub(crate) struct SendDeploy;
-
-/// Transfer allows transferring an amount between accounts.
-pub(crate) struct Transfer {}
-
-impl RpcClient for PutDeploy {
-    const RPC_METHOD: &'static str = Self::METHOD;
-}
-
-impl RpcClient for GetDeploy {
-    const RPC_METHOD: &'static str = Self::METHOD;
-}
-
-impl RpcClient for SendDeploy {
-    const RPC_METHOD: &'static str = PutDeploy::METHOD;
-}
-
-impl RpcClient for Transfer {
-    const RPC_METHOD: &'static str = PutDeploy::METHOD;
-}
+use crate::error::{Error, Result};
 
 /// Result for "chain_get_block" RPC response.
 #[derive(Serialize, Deserialize, Debug)]
@@ -58,11 +30,11 @@ impl From<GetBlockResult> for ListDeploysResult {
             deploy_hashes: get_block_result
                 .block
                 .as_ref()
-                .map(|block| block.deploy_hashes().clone()),
+                .map(|block| block.body.deploy_hashes.clone()),
             transfer_hashes: get_block_result
                 .block
                 .as_ref()
-                .map(|block| block.transfer_hashes().clone()),
+                .map(|block| block.body.transfer_hashes.clone()),
         }
     }
 }
@@ -130,6 +102,10 @@ pub(super) trait DeployExt {
     where
         R: Read,
         W: Write;
+
+    /// Adds the approvals of `other`, which must be a copy of this `Deploy`, skipping those of
+    /// signers which have approved already.  Returns the number of approvals added.
+    fn merge_approvals(&mut self, other: &Deploy) -> Result<usize>;
 }
 
 impl DeployExt for Deploy {
@@ -146,7 +122,7 @@ impl DeployExt for Deploy {
             chain_name,
             secret_key,
         } = params;
-        let mut rng = casper_node::new_rng();
+        let mut rng = rand::thread_rng();
         Deploy::new(
             timestamp,
             ttl,
@@ -187,18 +163,53 @@ impl DeployExt for Deploy {
         W: Write,
     {
         let mut deploy = Deploy::read_deploy(input)?;
-        let mut rng = casper_node::new_rng();
+        let mut rng = rand::thread_rng();
         deploy.sign(&secret_key, &mut rng);
         deploy.write_deploy(output)?;
         Ok(())
     }
+
+    fn merge_approvals(&mut self, other: &Deploy) -> Result<usize> {
+        if other.id() != self.id() {
+            return Err(Error::InvalidArgument(
+                "deploy",
+                format!(
+                    "expected copies of deploy {}, but got deploy {}",
+                    hex::encode(self.id().inner()),
+                    hex::encode(other.id().inner())
+                ),
+            ));
+        }
+        let mut added = 0;
+        for approval in other.approvals() {
+            crypto::verify(other.id(), approval.signature(), approval.signer()).map_err(
+                |error| Error::CryptoError {
+                    context: "approval",
+                    error,
+                },
+            )?;
+            if self.add_approval(approval.clone()) {
+                added += 1;
+            }
+        }
+        Ok(added)
+    }
+}
+
+/// Reads a previously-saved `Deploy` from the file at `input_path`.
+pub(crate) fn read_deploy_file(input_path: &str) -> Result<Deploy> {
+    let input = File::open(input_path).map_err(|error| Error::IoError {
+        context: format!("unable to read deploy file at '{}'", input_path),
+        error,
+    })?;
+    Deploy::read_deploy(input)
 }
 
 #[cfg(test)]
 mod tests {
     use std::convert::TryInto;
 
-    use casper_node::crypto::AsymmetricKeyExt;
+    use casper_node_types::crypto::AsymmetricKeyExt;
 
     use super::*;
     use crate::{DeployStrParams, PaymentStrParams, SessionStrParams};
@@ -328,7 +339,7 @@ mod tests {
 
         // The test output can be used to generate data for SAMPLE_DEPLOY:
         // let secret_key = SecretKey::generate_ed25519().unwrap();
-        // deploy.sign(&secret_key, &mut casper_node::new_rng());
+        // deploy.sign(&secret_key, &mut rand::thread_rng());
         // println!("{}", serde_json::to_string_pretty(&deploy).unwrap());
 
         let result = String::from_utf8(output).unwrap();
@@ -378,4 +389,26 @@ mod tests {
             signed_deploy
         );
     }
+
+    #[test]
+    fn should_create_and_sign_deploy_in_memory() {
+        let payment_params =
+            PaymentStrParams::with_package_hash(PKG_HASH, VERSION, ENTRYPOINT, args_simple(), "");
+        let session_params =
+            SessionStrParams::with_package_hash(PKG_HASH, VERSION, ENTRYPOINT, args_simple(), "");
+        let mut deploy =
+            crate::create_deploy(deploy_params(), session_params, payment_params).unwrap();
+        assert_eq!(deploy.approvals().len(), 1);
+
+        let secret_key = SecretKey::generate_secp256k1().unwrap();
+        crate::sign_deploy(&mut deploy, &secret_key);
+
+        let json = crate::deploy_to_json(&deploy).unwrap();
+        let mut parsed = crate::deploy_from_json(&json).unwrap();
+        parsed
+            .is_valid()
+            .unwrap_or_else(|error| panic!("{} - {:#?}", error, parsed));
+        assert_eq!(parsed.id(), deploy.id());
+        assert_eq!(parsed.approvals().len(), 2);
+    }
 }
@@ -0,0 +1,352 @@
+//! The JSON Schema of deploy files and a breakdown of the canonical `ToBytes` serialization of
+//! deploys, allowing third-party implementations to verify their hashing.
+
+use schemars::schema_for;
+use serde::Serialize;
+
+use casper_node_types::{
+    crypto::hash::{self, Digest},
+    Deploy, ExecutableDeployItem,
+};
+use casper_types::{bytesrepr::ToBytes, RuntimeArgs};
+
+use crate::error::Result;
+
+/// Returns the JSON Schema of a `Deploy` as read and written by the client, e.g. by
+/// `make_deploy` and `sign_deploy_file`.
+pub(crate) fn deploy_json_schema() -> Result<String> {
+    Ok(serde_json::to_string_pretty(&schema_for!(Deploy))?)
+}
+
+/// A field of a serialized value, possibly broken down further into the fields making it up.
+#[derive(Clone, Debug, Serialize)]
+pub struct EncodedField {
+    /// The name of the field.
+    pub name: String,
+    /// The offset of the field's first byte within the serialized deploy.
+    pub offset: usize,
+    /// The serialized field.
+    #[serde(serialize_with = "hex::serialize")]
+    pub bytes: Vec<u8>,
+    /// The fields making up this one, if any, in the order in which they are serialized.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<EncodedField>,
+}
+
+/// The canonical `ToBytes` serialization of a `Deploy`, broken down field by field, along with
+/// the hashes computed from it.
+#[derive(Clone, Debug, Serialize)]
+pub struct DeployEncoding {
+    /// The serialized deploy.
+    #[serde(serialize_with = "hex::serialize")]
+    pub bytes: Vec<u8>,
+    /// The fields making up the serialized deploy, in order.
+    pub fields: Vec<EncodedField>,
+    /// The serialized payment followed by the serialized session, which is hashed to give the
+    /// header's body hash.
+    #[serde(serialize_with = "hex::serialize")]
+    pub hashed_body: Vec<u8>,
+    /// The hash of `hashed_body`.
+    pub computed_body_hash: Digest,
+    /// Whether `computed_body_hash` equals the body hash in the deploy's header.
+    pub body_hash_matches: bool,
+    /// The serialized header, which is hashed to give the deploy hash.
+    #[serde(serialize_with = "hex::serialize")]
+    pub hashed_header: Vec<u8>,
+    /// The hash of `hashed_header`.
+    pub computed_deploy_hash: Digest,
+    /// Whether `computed_deploy_hash` equals the deploy's hash.
+    pub deploy_hash_matches: bool,
+}
+
+impl DeployEncoding {
+    /// Serializes `deploy` and breaks the serialization down into its fields.
+    pub fn new(deploy: &Deploy) -> Result<Self> {
+        let mut fields = Fields::new(0);
+        fields.add_nested("header", |fields| {
+            let header = deploy.header();
+            fields.add_tagged("account", header.account())?;
+            fields.add("timestamp", &header.timestamp())?;
+            fields.add("ttl", &header.ttl())?;
+            fields.add("gas_price", &header.gas_price())?;
+            fields.add("body_hash", header.body_hash())?;
+            fields.add_nested("dependencies", |fields| {
+                fields.add("length", &(header.dependencies().len() as u32))?;
+                for (index, dependency) in header.dependencies().iter().enumerate() {
+                    fields.add(&format!("dependencies[{}]", index), dependency)?;
+                }
+                Ok(())
+            })?;
+            fields.add_nested("chain_name", |fields| {
+                fields.add_string(header.chain_name());
+                Ok(())
+            })
+        })?;
+        fields.add("hash", deploy.id())?;
+        fields.add_nested("payment", |fields| fields.add_item(deploy.payment()))?;
+        fields.add_nested("session", |fields| fields.add_item(deploy.session()))?;
+        fields.add_nested("approvals", |fields| {
+            fields.add("length", &(deploy.approvals().len() as u32))?;
+            for (index, approval) in deploy.approvals().iter().enumerate() {
+                fields.add_nested(&format!("approvals[{}]", index), |fields| {
+                    fields.add_tagged("signer", approval.signer())?;
+                    fields.add_tagged("signature", approval.signature())
+                })?;
+            }
+            Ok(())
+        })?;
+
+        let mut hashed_body = deploy.payment().to_bytes()?;
+        hashed_body.extend(deploy.session().to_bytes()?);
+        let computed_body_hash = hash::hash(&hashed_body);
+        let hashed_header = deploy.header().to_bytes()?;
+        let computed_deploy_hash = hash::hash(&hashed_header);
+
+        Ok(DeployEncoding {
+            bytes: deploy.to_bytes()?,
+            fields: fields.fields,
+            hashed_body,
+            computed_body_hash,
+            body_hash_matches: computed_body_hash == *deploy.header().body_hash(),
+            hashed_header,
+            computed_deploy_hash,
+            deploy_hash_matches: computed_deploy_hash == *deploy.id().inner(),
+        })
+    }
+}
+
+/// Accumulates the consecutive fields of a serialized value.
+struct Fields {
+    offset: usize,
+    fields: Vec<EncodedField>,
+}
+
+impl Fields {
+    fn new(offset: usize) -> Self {
+        Fields {
+            offset,
+            fields: vec![],
+        }
+    }
+
+    fn add_bytes(&mut self, name: &str, bytes: Vec<u8>, fields: Vec<EncodedField>) {
+        let offset = self.offset;
+        self.offset += bytes.len();
+        self.fields.push(EncodedField {
+            name: name.to_string(),
+            offset,
+            bytes,
+            fields,
+        });
+    }
+
+    fn add<T: ToBytes>(&mut self, name: &str, value: &T) -> Result<()> {
+        self.add_bytes(name, value.to_bytes()?, vec![]);
+        Ok(())
+    }
+
+    /// Adds a field made up of the fields added by `add_fields`.
+    fn add_nested<F>(&mut self, name: &str, add_fields: F) -> Result<()>
+    where
+        F: FnOnce(&mut Fields) -> Result<()>,
+    {
+        let mut nested = Fields::new(self.offset);
+        add_fields(&mut nested)?;
+        let bytes = nested
+            .fields
+            .iter()
+            .flat_map(|field| field.bytes.iter().copied())
+            .collect();
+        self.add_bytes(name, bytes, nested.fields);
+        Ok(())
+    }
+
+    /// Adds a value serialized as a one-byte tag followed by the tagged value, such as a public
+    /// key or signature.
+    fn add_tagged<T: ToBytes>(&mut self, name: &str, value: &T) -> Result<()> {
+        let mut bytes = value.to_bytes()?;
+        let value_bytes = bytes.split_off(1.min(bytes.len()));
+        self.add_nested(name, |fields| {
+            fields.add_bytes("tag", bytes, vec![]);
+            fields.add_bytes("value", value_bytes, vec![]);
+            Ok(())
+        })
+    }
+
+    /// Adds the fields of a string, i.e. its length followed by its UTF-8 bytes.
+    fn add_string(&mut self, value: &str) {
+        self.add_bytes(
+            "length",
+            (value.len() as u32).to_le_bytes().to_vec(),
+            vec![],
+        );
+        self.add_bytes("value", value.as_bytes().to_vec(), vec![]);
+    }
+
+    /// Adds the fields of a payment or session item.
+    fn add_item(&mut self, item: &ExecutableDeployItem) -> Result<()> {
+        let tag = item.to_bytes()?[..1].to_vec();
+        self.add_bytes("tag", tag, vec![]);
+        match item {
+            ExecutableDeployItem::ModuleBytes { module_bytes, args } => {
+                self.add("module_bytes", module_bytes)?;
+                self.add_args(args)
+            }
+            ExecutableDeployItem::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+            } => {
+                self.add("hash", hash)?;
+                self.add("entry_point", entry_point)?;
+                self.add_args(args)
+            }
+            ExecutableDeployItem::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            } => {
+                self.add("name", name)?;
+                self.add("entry_point", entry_point)?;
+                self.add_args(args)
+            }
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash,
+                version,
+                entry_point,
+                args,
+            } => {
+                self.add("hash", hash)?;
+                self.add("version", version)?;
+                self.add("entry_point", entry_point)?;
+                self.add_args(args)
+            }
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                args,
+            } => {
+                self.add("name", name)?;
+                self.add("version", version)?;
+                self.add("entry_point", entry_point)?;
+                self.add_args(args)
+            }
+            ExecutableDeployItem::Transfer { args } => self.add_args(args),
+        }
+    }
+
+    /// Adds the runtime args, i.e. their number followed by each name and `CLValue`, the latter
+    /// being its length-prefixed serialized value followed by its `CLType`.
+    fn add_args(&mut self, args: &RuntimeArgs) -> Result<()> {
+        self.add_nested("args", |fields| {
+            fields.add("length", &(args.len() as u32))?;
+            for named_arg in args.named_args() {
+                fields.add_nested(named_arg.name(), |fields| {
+                    fields.add_nested("name", |fields| {
+                        fields.add_string(named_arg.name());
+                        Ok(())
+                    })?;
+                    let cl_value = named_arg.cl_value();
+                    let mut value_bytes = cl_value.to_bytes()?;
+                    let cl_type_bytes =
+                        value_bytes.split_off(cl_value.inner_bytes().serialized_length());
+                    fields.add_nested("value", |fields| {
+                        fields.add_bytes("bytes", value_bytes, vec![]);
+                        fields.add_bytes("cl_type", cl_type_bytes, vec![]);
+                        Ok(())
+                    })
+                })?;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_node_types::{DeployHash, TimeDiff, Timestamp};
+    use casper_types::{runtime_args, SecretKey, U512};
+
+    use super::*;
+
+    fn deploy() -> Deploy {
+        let secret_key = SecretKey::ed25519([1; SecretKey::ED25519_LENGTH]);
+        let mut rng = rand::thread_rng();
+        let payment = ExecutableDeployItem::ModuleBytes {
+            module_bytes: vec![].into(),
+            args: runtime_args! { "amount" => U512::from(1_000) },
+        };
+        let session = ExecutableDeployItem::StoredContractByName {
+            name: "faucet".to_string(),
+            entry_point: "call_faucet".to_string(),
+            args: runtime_args! { "target" => String::from("ab"), "amount" => 5_u64 },
+        };
+        Deploy::new(
+            Timestamp::from(1_000),
+            TimeDiff::from(60_000),
+            1,
+            vec![DeployHash::new([2; 32].into())],
+            "casper-test".to_string(),
+            payment,
+            session,
+            &secret_key,
+            &mut rng,
+        )
+    }
+
+    /// Checks that the fields are consecutive, start at `offset` and concatenate to `bytes`.
+    fn assert_consistent(fields: &[EncodedField], offset: usize, bytes: &[u8]) {
+        let mut expected_offset = offset;
+        let mut concatenated = vec![];
+        for field in fields {
+            assert_eq!(field.offset, expected_offset, "offset of {}", field.name);
+            if !field.fields.is_empty() {
+                assert_consistent(&field.fields, field.offset, &field.bytes);
+            }
+            expected_offset += field.bytes.len();
+            concatenated.extend_from_slice(&field.bytes);
+        }
+        assert_eq!(concatenated, bytes);
+    }
+
+    #[test]
+    fn should_break_down_deploy_serialization() {
+        let deploy = deploy();
+        let encoding = DeployEncoding::new(&deploy).unwrap();
+
+        assert_consistent(&encoding.fields, 0, &encoding.bytes);
+        let names: Vec<&str> = encoding
+            .fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec!["header", "hash", "payment", "session", "approvals"]
+        );
+        assert!(encoding.body_hash_matches);
+        assert!(encoding.deploy_hash_matches);
+        assert_eq!(encoding.fields[0].bytes, encoding.hashed_header);
+    }
+
+    #[test]
+    fn should_detect_mismatched_body_hash() {
+        let deploy = deploy();
+        let mut json = serde_json::to_value(&deploy).unwrap();
+        json["session"]["StoredContractByName"]["entry_point"] = "other".into();
+        let tampered: Deploy = serde_json::from_value(json).unwrap();
+
+        let encoding = DeployEncoding::new(&tampered).unwrap();
+        assert!(!encoding.body_hash_matches);
+        assert!(encoding.deploy_hash_matches);
+    }
+
+    #[test]
+    fn should_produce_deploy_json_schema() {
+        let schema: serde_json::Value =
+            serde_json::from_str(&deploy_json_schema().unwrap()).unwrap();
+        assert_eq!(schema["title"], "Deploy");
+        assert!(schema["definitions"]["DeployHeader"].is_object());
+    }
+}
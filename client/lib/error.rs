@@ -4,11 +4,12 @@ use humantime::{DurationError, TimestampError};
 use jsonrpc_lite::JsonRpc;
 use thiserror::Error;
 
-use casper_node::crypto::Error as CryptoError;
+use casper_node_types::crypto::Error as CryptoError;
 use casper_types::{
     bytesrepr::Error as ToBytesError, CLValueError, UIntParseError, URefFromStrError,
 };
 
+#[cfg(feature = "network")]
 use crate::validation::ValidateResponseError;
 
 /// Crate-wide Result type wrapper.
@@ -44,10 +45,12 @@ pub enum Error {
     FailedToParseUint(&'static str, UIntParseError),
 
     /// Failed to get a response from the node.
+    #[cfg(feature = "network")]
     #[error("Failed to get RPC response: {0}")]
     FailedToGetResponse(reqwest::Error),
 
     /// Failed to parse the response from the node.
+    #[cfg(feature = "network")]
     #[error("Failed to parse as JSON-RPC response: {0}")]
     FailedToParseResponse(reqwest::Error),
 
@@ -115,15 +118,72 @@ pub enum Error {
         args: Vec<String>,
     },
 
+    /// The deploy was not executed before the timeout elapsed.
+    #[error("Timed out after {timeout} waiting for deploy {deploy_hash} to be executed")]
+    WaitForDeployTimedOut {
+        /// The hex-encoded hash of the deploy.
+        deploy_hash: String,
+        /// The duration waited for, formatted for humans.
+        timeout: String,
+    },
+
+    /// The approvals of a deploy don't meet the deployment threshold of its account.
+    #[error(
+        "Deploy approvals have a total weight of {total_weight}, but the account's deployment \
+        threshold is {threshold}"
+    )]
+    InsufficientApprovalWeight {
+        /// The total weight of the valid approvals by associated keys of the account.
+        total_weight: u32,
+        /// The deployment threshold of the account.
+        threshold: u8,
+    },
+
+    /// A deploy was approved by keys which are not associated keys of its account.
+    #[error("Deploy approved by keys not associated with the account: {0:?}")]
+    UnauthorizedApprovals(Vec<String>),
+
+    /// Failed to decrypt an encrypted secret key file, e.g. due to an incorrect password.
+    #[error("Failed to decrypt secret key: {0}")]
+    FailedToDecryptSecretKey(String),
+
+    /// Invalid BIP-39 mnemonic phrase.
+    #[error("Invalid mnemonic: {0}")]
+    InvalidMnemonic(String),
+
+    /// Invalid key derivation path.
+    #[error("Invalid derivation path '{0}': {1}")]
+    InvalidDerivationPath(String, String),
+
+    /// A deploy failed the checks against the chain name and deploy limits of the network, and
+    /// would be rejected or never executed by the node.
+    #[error("Invalid deploy: {0}")]
+    InvalidDeploy(String),
+
+    /// While exploring global state, a path segment named neither a key nor one of the named keys
+    /// at that location.
+    #[error("No named key '{name}' at {location}")]
+    NoSuchNamedKey {
+        /// The path of the location in which the named key was looked up.
+        location: String,
+        /// The name which wasn't found.
+        name: String,
+    },
+
+    /// While exploring global state, the value at a path was neither a purse nor an account.
+    #[error("Not a purse or account: {0}")]
+    NotAPurse(String),
+
+    /// The node's event stream, required to observe executions during a load test, could not be
+    /// subscribed to.
+    #[error("Failed to subscribe to the event stream at {0}")]
+    EventStreamUnavailable(String),
+
     /// Failed to validate response.
+    #[cfg(feature = "network")]
     #[error("Invalid response: {0}")]
     InvalidResponse(#[from] ValidateResponseError),
 
-    /// Must call FFI's setup function prior to making ffi calls.
-    #[cfg(feature = "ffi")]
-    #[error("Failed to call casper_setup_client()")]
-    FFISetupNotCalled,
-
     /// Must pass valid pointer values to FFI calls.
     #[cfg(feature = "ffi")]
     #[error("Required argument '{0}' was null")]
@@ -0,0 +1,440 @@
+//! Browsing global state like a file system, as used by the client's `explore` subcommand.
+//!
+//! Accounts, contracts and contract packages are treated as directories whose entries are their
+//! named keys, along with their main purse, contract package and contract versions respectively.
+//! Any other stored value is treated as a file.  Whenever a key is read, stored `Key`s and `URef`s
+//! are followed to the value they point to.
+
+use std::fmt::{self, Display, Formatter};
+
+use serde::Serialize;
+
+use casper_node_types::{crypto::hash::Digest, json_compatibility::StoredValue};
+use casper_rpc_client::rpcs::{
+    chain::GetStateRootHashResult,
+    state::{GetBalanceResult, GetItemResult},
+};
+use casper_types::{AsymmetricType, CLType, Key, NamedKey, PublicKey, URef, U512};
+
+use crate::{
+    error::{Error, Result},
+    rpc::RpcCall,
+};
+
+/// The maximum number of keys followed when reading a single value, guarding against cycles.
+const MAX_FOLLOWED_KEYS: usize = 16;
+/// The name of the entry holding an account's main purse.
+const MAIN_PURSE: &str = "main_purse";
+/// The name of the entry holding a contract's package.
+const CONTRACT_PACKAGE: &str = "contract_package";
+
+/// A location in global state, reached via a named key or given explicitly as a key.
+#[derive(Clone, Debug)]
+struct Location {
+    name: String,
+    key: Key,
+}
+
+/// A value read from global state, along with the keys followed to reach it.
+#[derive(Clone, Debug, Serialize)]
+pub struct ExploredValue {
+    /// The keys read, in order, with the value stored under the last one.
+    pub followed_keys: Vec<Key>,
+    /// The value stored under the last of the followed keys.
+    pub stored_value: StoredValue,
+}
+
+impl Display for ExploredValue {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        if self.followed_keys.len() > 1 {
+            let followed_keys: Vec<String> = self
+                .followed_keys
+                .iter()
+                .map(Key::to_formatted_string)
+                .collect();
+            writeln!(formatter, "{}", followed_keys.join(" -> "))?;
+        }
+        match &self.stored_value {
+            StoredValue::CLValue(cl_value) => {
+                let json = serde_json::to_value(cl_value).map_err(|_| fmt::Error)?;
+                match json.get("parsed").filter(|parsed| !parsed.is_null()) {
+                    Some(parsed) => write!(formatter, "{:?}: {}", cl_value.cl_type(), parsed),
+                    None => write!(
+                        formatter,
+                        "{:?}: 0x{}",
+                        cl_value.cl_type(),
+                        hex::encode(cl_value.inner_bytes())
+                    ),
+                }
+            }
+            stored_value => {
+                let json = serde_json::to_string_pretty(stored_value).map_err(|_| fmt::Error)?;
+                write!(formatter, "{}", json)
+            }
+        }
+    }
+}
+
+/// An interactive session browsing the global state of a node at a given state root hash.
+pub struct Explorer {
+    maybe_rpc_id: String,
+    node_address: String,
+    verbosity_level: u64,
+    state_root_hash: Digest,
+    /// The locations from the root to the current one, which is the last.
+    path: Vec<Location>,
+}
+
+impl Explorer {
+    /// Constructs a new `Explorer` at the root, using the given state root hash, or the latest
+    /// one known to the node if `maybe_state_root_hash` is empty.
+    pub async fn new(
+        maybe_rpc_id: &str,
+        node_address: &str,
+        verbosity_level: u64,
+        maybe_state_root_hash: &str,
+    ) -> Result<Self> {
+        let mut explorer = Explorer {
+            maybe_rpc_id: maybe_rpc_id.to_string(),
+            node_address: node_address.to_string(),
+            verbosity_level,
+            state_root_hash: Digest::default(),
+            path: vec![],
+        };
+        explorer.set_state_root_hash(maybe_state_root_hash).await?;
+        Ok(explorer)
+    }
+
+    /// Returns the address of the node being queried.
+    pub fn node_address(&self) -> &str {
+        &self.node_address
+    }
+
+    /// Switches to querying the given node, keeping the current state root hash and location.
+    pub fn set_node_address(&mut self, node_address: &str) {
+        self.node_address = node_address.to_string();
+    }
+
+    /// Returns the state root hash of the global state being browsed.
+    pub fn state_root_hash(&self) -> Digest {
+        self.state_root_hash
+    }
+
+    /// Switches to browsing the given state root hash, or the latest one known to the node if
+    /// `maybe_state_root_hash` is empty, keeping the current location.
+    pub async fn set_state_root_hash(&mut self, maybe_state_root_hash: &str) -> Result<()> {
+        if !maybe_state_root_hash.is_empty() {
+            self.state_root_hash =
+                Digest::from_hex(maybe_state_root_hash).map_err(|error| Error::CryptoError {
+                    context: "state_root_hash",
+                    error,
+                })?;
+            return Ok(());
+        }
+
+        let response = self.rpc_call().get_state_root_hash("").await?;
+        let result = response
+            .get_result()
+            .cloned()
+            .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+        let result: GetStateRootHashResult = serde_json::from_value(result)?;
+        self.state_root_hash = result
+            .state_root_hash
+            .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+        Ok(())
+    }
+
+    /// Returns the path of the current location, e.g. `/account-hash-ab12.../my_contract`.
+    pub fn current_path(&self) -> String {
+        format_path(&self.path)
+    }
+
+    /// Changes the current location to `path`.
+    ///
+    /// Paths are made of `/`-separated segments, each being either a key, one of the named keys of
+    /// the previous location, `.` or `..`.  Paths starting with `/` are resolved from the root,
+    /// and keys reset the path to themselves.  An empty path changes to the root.
+    pub async fn change_location(&mut self, path: &str) -> Result<()> {
+        if path.is_empty() {
+            self.path.clear();
+            return Ok(());
+        }
+        let new_path = self.resolve_path(path).await?;
+        // Ensure the new location exists before moving to it.
+        if let Some(location) = new_path.last() {
+            let _ = self.read(location.key).await?;
+        }
+        self.path = new_path;
+        Ok(())
+    }
+
+    /// Returns the entries at `path`, or at the current location if `path` is empty.
+    pub async fn list(&self, path: &str) -> Result<Vec<NamedKey>> {
+        let path = self.resolve_path(path).await?;
+        match path.last() {
+            Some(location) => Ok(entries(&self.read(location.key).await?.stored_value)),
+            None => Ok(vec![]),
+        }
+    }
+
+    /// Reads the value at `path`, or at the current location if `path` is empty.
+    pub async fn read_value(&self, path: &str) -> Result<ExploredValue> {
+        let path = self.resolve_path(path).await?;
+        match path.last() {
+            Some(location) => self.read(location.key).await,
+            None => Err(Error::InvalidArgument(
+                "path",
+                "the root has no value; change to a key first".to_string(),
+            )),
+        }
+    }
+
+    /// Returns the balance of the purse at `path`, or at the current location if `path` is empty.
+    /// If the value there is an account, the balance of its main purse is returned.
+    pub async fn balance(&self, path: &str) -> Result<U512> {
+        let value = self.read_value(path).await?;
+        let purse = match (&value.stored_value, value.followed_keys.last()) {
+            (StoredValue::Account(account), _) => account.main_purse(),
+            (_, Some(Key::URef(uref))) => *uref,
+            _ => {
+                let path = format_path(&self.resolve_path(path).await?);
+                return Err(Error::NotAPurse(path));
+            }
+        };
+
+        let response = self
+            .rpc_call()
+            .get_balance(
+                &hex::encode(self.state_root_hash),
+                &purse.to_formatted_string(),
+            )
+            .await?;
+        let result = response
+            .get_result()
+            .cloned()
+            .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+        let result: GetBalanceResult = serde_json::from_value(result)?;
+        Ok(result.balance_value)
+    }
+
+    fn rpc_call(&self) -> RpcCall {
+        RpcCall::new(&self.maybe_rpc_id, &self.node_address, self.verbosity_level)
+    }
+
+    /// Returns the locations from the root to the one at `path`.
+    async fn resolve_path(&self, path: &str) -> Result<Vec<Location>> {
+        let mut locations = if path.starts_with('/') {
+            vec![]
+        } else {
+            self.path.clone()
+        };
+
+        for segment in path.split('/') {
+            match segment {
+                "" | "." => continue,
+                ".." => {
+                    let _ = locations.pop();
+                    continue;
+                }
+                _ => (),
+            }
+
+            if let Some(key) = parse_key(segment) {
+                locations = vec![Location {
+                    name: segment.to_string(),
+                    key,
+                }];
+                continue;
+            }
+
+            let entry = match locations.last() {
+                Some(location) => entries(&self.read(location.key).await?.stored_value)
+                    .into_iter()
+                    .find(|entry| entry.name == segment),
+                None => None,
+            };
+            let entry = entry.ok_or_else(|| Error::NoSuchNamedKey {
+                location: format_path(&locations),
+                name: segment.to_string(),
+            })?;
+            let key = Key::from_formatted_str(&entry.key).map_err(|_| Error::FailedToParseKey)?;
+            locations.push(Location {
+                name: entry.name,
+                key,
+            });
+        }
+
+        Ok(locations)
+    }
+
+    /// Reads the value under `key`, following any stored keys.
+    async fn read(&self, key: Key) -> Result<ExploredValue> {
+        let mut value = ExploredValue {
+            followed_keys: vec![key],
+            stored_value: self.query(key).await?,
+        };
+
+        while let Some(next_key) = followed_key(&value.stored_value) {
+            if value.followed_keys.len() >= MAX_FOLLOWED_KEYS
+                || value.followed_keys.contains(&next_key)
+            {
+                break;
+            }
+            // A stored key needn't point to a value, in which case the key itself is shown.
+            let stored_value = match self.query(next_key).await {
+                Ok(stored_value) => stored_value,
+                Err(Error::ResponseIsError(_)) => break,
+                Err(error) => return Err(error),
+            };
+            value.followed_keys.push(next_key);
+            value.stored_value = stored_value;
+        }
+
+        Ok(value)
+    }
+
+    async fn query(&self, key: Key) -> Result<StoredValue> {
+        let response = self
+            .rpc_call()
+            .get_item(
+                &hex::encode(self.state_root_hash),
+                &key.to_formatted_string(),
+                "",
+            )
+            .await?;
+        let result = response
+            .get_result()
+            .cloned()
+            .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+        let result: GetItemResult = serde_json::from_value(result)?;
+        Ok(result.stored_value)
+    }
+}
+
+/// Parses a formatted key or hex-encoded public key, the latter as its account's key.
+fn parse_key(input: &str) -> Option<Key> {
+    if let Ok(key) = Key::from_formatted_str(input) {
+        return Some(key);
+    }
+    PublicKey::from_hex(input)
+        .ok()
+        .map(|public_key| Key::Account(public_key.to_account_hash()))
+}
+
+fn format_path(locations: &[Location]) -> String {
+    let names: Vec<&str> = locations
+        .iter()
+        .map(|location| location.name.as_str())
+        .collect();
+    format!("/{}", names.join("/"))
+}
+
+/// Returns the key held by `stored_value` if it is a `CLValue` of type `Key` or `URef`.
+fn followed_key(stored_value: &StoredValue) -> Option<Key> {
+    match stored_value {
+        StoredValue::CLValue(cl_value) => match cl_value.cl_type() {
+            CLType::Key => cl_value.clone().into_t::<Key>().ok(),
+            CLType::URef => cl_value.clone().into_t::<URef>().ok().map(Key::URef),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the entries of `stored_value` if it is an account, contract or contract package.
+fn entries(stored_value: &StoredValue) -> Vec<NamedKey> {
+    match stored_value {
+        StoredValue::Account(account) => {
+            let mut entries = account.named_keys().to_vec();
+            entries.push(NamedKey {
+                name: MAIN_PURSE.to_string(),
+                key: Key::URef(account.main_purse()).to_formatted_string(),
+            });
+            entries
+        }
+        StoredValue::Contract(contract) => {
+            let mut entries = contract.named_keys().to_vec();
+            entries.push(NamedKey {
+                name: CONTRACT_PACKAGE.to_string(),
+                key: Key::from(contract.contract_package_hash()).to_formatted_string(),
+            });
+            entries
+        }
+        StoredValue::ContractPackage(contract_package) => contract_package
+            .versions()
+            .iter()
+            .map(|version| NamedKey {
+                name: format!(
+                    "{}.{}",
+                    version.protocol_version_major(),
+                    version.contract_version()
+                ),
+                key: Key::from(version.contract_hash()).to_formatted_string(),
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{account::AccountHash, AccessRights, CLValue, SecretKey};
+
+    use super::*;
+
+    #[test]
+    fn should_parse_keys_and_public_keys() {
+        let account_hash = AccountHash::new([1; 32]);
+        let key = Key::Account(account_hash);
+        assert_eq!(parse_key(&key.to_formatted_string()), Some(key));
+
+        let public_key = PublicKey::from(&SecretKey::ed25519([2; 32]));
+        assert_eq!(
+            parse_key(&public_key.to_hex()),
+            Some(Key::Account(public_key.to_account_hash()))
+        );
+
+        assert_eq!(parse_key("my_contract"), None);
+    }
+
+    #[test]
+    fn should_follow_stored_keys_and_urefs() {
+        let uref = URef::new([3; 32], AccessRights::READ_ADD_WRITE);
+        let stored_uref = StoredValue::CLValue(CLValue::from_t(uref).unwrap());
+        assert_eq!(followed_key(&stored_uref), Some(Key::URef(uref)));
+
+        let hash = Key::Hash([4; 32]);
+        let stored_key = StoredValue::CLValue(CLValue::from_t(hash).unwrap());
+        assert_eq!(followed_key(&stored_key), Some(hash));
+
+        let stored_number = StoredValue::CLValue(CLValue::from_t(5_u64).unwrap());
+        assert_eq!(followed_key(&stored_number), None);
+    }
+
+    #[test]
+    fn should_display_decoded_cl_values() {
+        let value = ExploredValue {
+            followed_keys: vec![Key::Hash([4; 32]), Key::URef(URef::default())],
+            stored_value: StoredValue::CLValue(CLValue::from_t(String::from("hello")).unwrap()),
+        };
+        let displayed = value.to_string();
+        assert!(displayed.contains(" -> "));
+        assert!(displayed.ends_with("String: \"hello\""));
+    }
+
+    #[test]
+    fn should_format_paths() {
+        assert_eq!(format_path(&[]), "/");
+        let locations = vec![
+            Location {
+                name: "account-hash-01".to_string(),
+                key: Key::Account(AccountHash::new([1; 32])),
+            },
+            Location {
+                name: "counter".to_string(),
+                key: Key::Hash([5; 32]),
+            },
+        ];
+        assert_eq!(format_path(&locations), "/account-hash-01/counter");
+    }
+}
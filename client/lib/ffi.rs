@@ -1,30 +1,35 @@
 //! Foreign function interfaces.
+//!
+//! Functions which contact a node take a `casper_client_t` handle created with
+//! `casper_client_new`, which owns the async runtime used for the requests.  A handle may be shared
+//! between threads.  Deploys can be held in memory as `casper_deploy_t` handles, and strings
+//! returned by the library are `casper_string_t` handles.  All handles must be released with their
+//! respective `_free` function.
+//!
+//! Every function returns a `casper_error_t`.  If it is not `CASPER_SUCCESS` and the `error`
+//! out-parameter is non-null, a description of the error is stored there, so concurrent callers
+//! never observe each other's errors.
 
 use std::{
     convert::TryInto,
-    ffi::CStr,
-    os::raw::{c_char, c_uchar},
-    slice,
-    sync::Mutex,
+    ffi::{CStr, CString},
+    future::Future,
+    os::raw::c_char,
+    ptr, slice,
 };
 
-use once_cell::sync::Lazy;
+use futures::executor;
+use jsonrpc_lite::JsonRpc;
 use tokio::runtime;
 
-use super::error::{Error, Result};
-
-static LAST_ERROR: Lazy<Mutex<Option<Error>>> = Lazy::new(|| Mutex::new(None));
-static RUNTIME: Lazy<Mutex<Option<runtime::Runtime>>> = Lazy::new(|| Mutex::new(None));
+use casper_node_types::Deploy;
 
-fn set_last_error(error: Error) {
-    let last_error = &mut *LAST_ERROR.lock().expect("should lock");
-    *last_error = Some(error)
-}
+use super::error::{Error, Result};
 
 /// FFI representation of [super::Error](super::Error)
 ///
-/// The full error can be extracted with get_last_error.
-/// See [super::Error](super::Error) for more details on what these mean.
+/// Every function returns one of these, and the full error can be obtained through its `error`
+/// out-parameter.  See [super::Error](super::Error) for more details on what these mean.
 #[allow(non_snake_case, non_camel_case_types, missing_docs)]
 #[repr(C)]
 pub enum casper_error_t {
@@ -49,9 +54,18 @@ pub enum casper_error_t {
     CASPER_INVALID_CL_VALUE = -18,
     CASPER_INVALID_ARGUMENT = -19,
     CASPER_INVALID_RESPONSE = -20,
-    CASPER_FFI_SETUP_NOT_CALLED = -21,
     CASPER_FFI_PTR_NULL_BUT_REQUIRED = -22,
     CASPER_CONFLICTING_ARGUMENTS = -23,
+    CASPER_WAIT_FOR_DEPLOY_TIMED_OUT = -24,
+    CASPER_INSUFFICIENT_APPROVAL_WEIGHT = -25,
+    CASPER_UNAUTHORIZED_APPROVALS = -26,
+    CASPER_FAILED_TO_DECRYPT_SECRET_KEY = -27,
+    CASPER_INVALID_MNEMONIC = -28,
+    CASPER_INVALID_DERIVATION_PATH = -29,
+    CASPER_INVALID_DEPLOY = -30,
+    CASPER_NO_SUCH_NAMED_KEY = -31,
+    CASPER_NOT_A_PURSE = -32,
+    CASPER_EVENT_STREAM_UNAVAILABLE = -33,
 }
 
 trait AsFFIError {
@@ -81,73 +95,61 @@ impl AsFFIError for Error {
             Error::InvalidCLValue(_) => casper_error_t::CASPER_INVALID_CL_VALUE,
             Error::InvalidArgument(_, _) => casper_error_t::CASPER_INVALID_ARGUMENT,
             Error::InvalidResponse(_) => casper_error_t::CASPER_INVALID_RESPONSE,
-            Error::FFISetupNotCalled => casper_error_t::CASPER_FFI_SETUP_NOT_CALLED,
             Error::FFIPtrNullButRequired(_) => casper_error_t::CASPER_FFI_PTR_NULL_BUT_REQUIRED,
             Error::ConflictingArguments { .. } => casper_error_t::CASPER_CONFLICTING_ARGUMENTS,
+            Error::WaitForDeployTimedOut { .. } => casper_error_t::CASPER_WAIT_FOR_DEPLOY_TIMED_OUT,
+            Error::InsufficientApprovalWeight { .. } => {
+                casper_error_t::CASPER_INSUFFICIENT_APPROVAL_WEIGHT
+            }
+            Error::UnauthorizedApprovals(_) => casper_error_t::CASPER_UNAUTHORIZED_APPROVALS,
+            Error::FailedToDecryptSecretKey(_) => {
+                casper_error_t::CASPER_FAILED_TO_DECRYPT_SECRET_KEY
+            }
+            Error::InvalidMnemonic(_) => casper_error_t::CASPER_INVALID_MNEMONIC,
+            Error::InvalidDerivationPath(_, _) => casper_error_t::CASPER_INVALID_DERIVATION_PATH,
+            Error::InvalidDeploy(_) => casper_error_t::CASPER_INVALID_DEPLOY,
+            Error::NoSuchNamedKey { .. } => casper_error_t::CASPER_NO_SUCH_NAMED_KEY,
+            Error::NotAPurse(_) => casper_error_t::CASPER_NOT_A_PURSE,
+            Error::EventStreamUnavailable(_) => casper_error_t::CASPER_EVENT_STREAM_UNAVAILABLE,
         }
     }
 }
 
-/// Private macro for parsing arguments from c strings, (const char *, or *const c_char in rust
-/// terms). The sad path contract here is that we indicate there was an error by returning `false`,
-/// then we store the argument -name- as an Error::InvalidArgument in LAST_ERROR. The happy path is
-/// left up to callsites to define.
-macro_rules! r#try_unsafe_arg {
-    ($arg:expr) => {{
-        let result = unsafe_str_arg($arg, stringify!($arg));
-        try_unwrap_result!(result)
-    }};
-}
-
-/// Private macro for unwrapping a result value or setting an appropriate error and returning
-/// early with `false` to indicate it's existence.
+/// Private macro for unwrapping a result value, or storing the error in the `error`
+/// out-parameter and returning early with its error code.
 macro_rules! r#try_unwrap_result {
-    ($result:expr) => {
+    ($result:expr, $error:ident) => {
         match $result {
             Ok(value) => value,
-            Err(error) => {
-                let error_code = AsFFIError::as_ffi_error(&error);
-                set_last_error(error);
-                return error_code;
-            }
+            Err(err) => return report_error(err, $error),
         }
     };
 }
 
-/// Private macro for unwrapping an optional value or setting an appropriate error and returning
-/// early with `false` to indicate it's existence.
-macro_rules! r#try_unwrap_option {
-    ($arg:expr, or_else => $err:expr) => {
-        match $arg {
-            Some(value) => value,
-            None => {
-                let err_code = $err.as_ffi_error();
-                set_last_error($err);
-                return err_code;
-            }
-        }
-    };
-}
-
-/// Private macro for unwrapping our internal json-rpcs or, optionally, storing the error in
-/// LAST_ERROR and returning `false` to indicate that an error has occurred. Similar to
-/// `try_unsafe_arg!`, this handles the sad path, and the happy path is left up to callsites.
-macro_rules! r#try_unwrap_rpc {
-    ($rpc:expr) => {{
-        let rpc = try_unwrap_result!($rpc.map_err(Into::into));
-        let rpc_result = try_unwrap_option!(rpc.get_result(), or_else => {
-            let rpc_err = rpc.get_error().expect("should be error");
-            Error::ResponseIsError(rpc_err.to_owned())
-        });
-        try_unwrap_result!(serde_json::to_string(&rpc_result).map_err(Into::into))
+/// Private macro for parsing arguments from c strings, (const char *, or *const c_char in rust
+/// terms). The sad path contract here is that we report an Error::InvalidArgument naming the
+/// argument via the `error` out-parameter. The happy path is left up to callsites to define.
+macro_rules! r#try_unsafe_arg {
+    ($arg:ident, $error:ident) => {{
+        try_unwrap_result!(unsafe_str_arg($arg, stringify!($arg)), $error)
     }};
 }
 
 /// Private macro to wrap TryInto implementing types with a human-readable error message describing
 /// the field name at the callsite.
 macro_rules! r#try_arg_into {
-    ($arg:expr) => {{
-        try_unwrap_result!(unsafe_try_into($arg, stringify!($arg)))
+    ($arg:ident, $error:ident) => {{
+        try_unwrap_result!(unsafe_try_into($arg, stringify!($arg)), $error)
+    }};
+}
+
+/// Private macro for dereferencing a required handle, or reporting an error naming the argument.
+macro_rules! r#try_unsafe_handle {
+    ($arg:ident, $error:ident) => {{
+        try_unwrap_result!(
+            unsafe { $arg.as_ref() }.ok_or(Error::FFIPtrNullButRequired(stringify!($arg))),
+            $error
+        )
     }};
 }
 
@@ -165,8 +167,7 @@ fn unsafe_str_arg(arg: *const c_char, arg_name: &'static str) -> Result<&'static
             arg_name,
             format!(
                 "invalid utf8 value passed for arg '{}': {:?}",
-                stringify!($arg),
-                error,
+                arg_name, error,
             ),
         )
     })
@@ -177,6 +178,12 @@ fn unsafe_vec_of_str_arg(
     len: usize,
     arg_name: &'static str,
 ) -> Result<Vec<&'static str>> {
+    if len == 0 {
+        return Ok(vec![]);
+    }
+    if arg.is_null() {
+        return Err(Error::FFIPtrNullButRequired(arg_name));
+    }
     let slice = unsafe { slice::from_raw_parts(arg, len) };
     let mut vec = Vec::with_capacity(len);
     for bytes in slice {
@@ -191,7 +198,6 @@ fn unsafe_vec_of_str_arg(
 }
 
 /// Helper to call TryInto::try_into on a *const ptr of our rust type implementing it.
-/// This is used for
 fn unsafe_try_into<T, I>(value: *const I, field_name: &'static str) -> Result<T>
 where
     I: Clone,
@@ -205,47 +211,264 @@ where
     }
 }
 
-/// Copy the contents of `strval` to a user-provided buffer.
+/// Moves `value` to the heap and stores the pointer in `out`, unless `out` is null.
+fn set_out_param<T>(out: *mut *mut T, value: T) {
+    if !out.is_null() {
+        unsafe { *out = Box::into_raw(Box::new(value)) };
+    }
+}
+
+/// Stores the value of a successful `result` via `on_success`, or the description of the error in
+/// `error`, and returns the corresponding error code.
+fn complete<T, F>(
+    result: Result<T>,
+    on_success: F,
+    error: *mut *mut casper_string_t,
+) -> casper_error_t
+where
+    F: FnOnce(T),
+{
+    match result {
+        Ok(value) => {
+            on_success(value);
+            casper_error_t::CASPER_SUCCESS
+        }
+        Err(err) => report_error(err, error),
+    }
+}
+
+/// Stores the description of `err` in `error` and returns its error code.
+fn report_error(err: Error, error: *mut *mut casper_string_t) -> casper_error_t {
+    let error_code = err.as_ffi_error();
+    set_out_param(error, casper_string_t::new(err.to_string()));
+    error_code
+}
+
+/// Like `complete`, for calls returning a JSON-RPC response.  On success, the JSON-encoded result
+/// of the response is stored in `response`.
+fn complete_rpc(
+    result: Result<JsonRpc>,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
+) -> casper_error_t {
+    let result = result.and_then(|rpc| match rpc.get_result() {
+        Some(rpc_result) => Ok(serde_json::to_string(rpc_result)?),
+        None => Err(match rpc.get_error() {
+            Some(rpc_error) => Error::ResponseIsError(rpc_error.clone()),
+            None => Error::InvalidRpcResponse(rpc.clone()),
+        }),
+    });
+    complete(
+        result,
+        |json| set_out_param(response, casper_string_t::new(json)),
+        error,
+    )
+}
+
+/// A string returned by the library, always null-terminated.
+///
+/// Must be released with `casper_string_free`.
+#[allow(non_camel_case_types)]
+pub struct casper_string_t(CString);
+
+impl casper_string_t {
+    fn new(value: String) -> Self {
+        // Interior nul bytes can't be represented; JSON never contains them unescaped.
+        let value = value.replace('\0', "");
+        casper_string_t(CString::new(value).expect("should not contain nul bytes"))
+    }
+}
+
+/// Returns a pointer to the null-terminated contents of `string`, which remains valid until
+/// `string` is released.
+#[no_mangle]
+pub extern "C" fn casper_string_ptr(string: *const casper_string_t) -> *const c_char {
+    match unsafe { string.as_ref() } {
+        Some(string) => string.0.as_ptr(),
+        None => ptr::null(),
+    }
+}
+
+/// Returns the length of `string` in bytes, excluding the terminating null byte.
+#[no_mangle]
+pub extern "C" fn casper_string_len(string: *const casper_string_t) -> usize {
+    match unsafe { string.as_ref() } {
+        Some(string) => string.0.as_bytes().len(),
+        None => 0,
+    }
+}
+
+/// Releases a string returned by the library.  Passing null is a no-op.
+#[no_mangle]
+pub extern "C" fn casper_string_free(string: *mut casper_string_t) {
+    if !string.is_null() {
+        drop(unsafe { Box::from_raw(string) });
+    }
+}
+
+/// A handle for making requests to nodes.
+///
+/// Created with `casper_client_new` and released with `casper_client_free`.
+#[allow(non_camel_case_types)]
+pub struct casper_client_t {
+    runtime: runtime::Runtime,
+}
+
+impl casper_client_t {
+    /// Drives `future`, which makes requests to a node, to completion on this client's runtime.
+    fn run<T>(&self, future: impl Future<Output = Result<T>>) -> Result<T> {
+        self.runtime.enter(|| executor::block_on(future))
+    }
+}
+
+/// Creates a client handle, or returns null if its runtime couldn't be started.
+#[no_mangle]
+pub extern "C" fn casper_client_new() -> *mut casper_client_t {
+    match runtime::Runtime::new() {
+        Ok(runtime) => Box::into_raw(Box::new(casper_client_t { runtime })),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Releases a client handle and the resources it holds.  Passing null is a no-op.
+#[no_mangle]
+pub extern "C" fn casper_client_free(client: *mut casper_client_t) {
+    if !client.is_null() {
+        drop(unsafe { Box::from_raw(client) });
+    }
+}
+
+/// A `Deploy` held in memory.
+///
+/// Created with `casper_deploy_new` or `casper_deploy_from_json`, and released with
+/// `casper_deploy_free`.
+#[allow(non_camel_case_types)]
+pub struct casper_deploy_t(Deploy);
+
+/// Creates a `Deploy` signed with the secret key given in `deploy_params`, and stores the handle in
+/// `deploy`.
 ///
-/// `strval` is the rust `&str` utf8 string to copy.
-/// `buf` is the caller-provided buffer to write into.
-/// `len` is the size of the buffer `buf` in bytes.
-/// - returns the number of bytes written to `buf`.
-fn copy_str_to_buf(strval: &str, buf: *mut c_uchar, len: usize) -> usize {
-    let mut_buf = unsafe { slice::from_raw_parts_mut::<u8>(buf, len) };
-    let lesser_len = len.min(strval.len());
-    let strval = strval.as_bytes();
-    mut_buf[0..lesser_len].clone_from_slice(&strval[0..lesser_len]);
-    len
-}
-
-/// Perform needed setup for the client library.
+/// See [super::create_deploy](super::create_deploy) for more details.
 #[no_mangle]
-pub extern "C" fn casper_setup_client() {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    // TODO: runtime opts
-    *runtime = Some(runtime::Runtime::new().expect("should create tokio runtime"));
+pub extern "C" fn casper_deploy_new(
+    deploy_params: *const casper_deploy_params_t,
+    session_params: *const casper_session_params_t,
+    payment_params: *const casper_payment_params_t,
+    deploy: *mut *mut casper_deploy_t,
+    error: *mut *mut casper_string_t,
+) -> casper_error_t {
+    let deploy_params = try_arg_into!(deploy_params, error);
+    let session_params = try_arg_into!(session_params, error);
+    let payment_params = try_arg_into!(payment_params, error);
+    let result = super::create_deploy(deploy_params, session_params, payment_params);
+    complete(
+        result,
+        |value| set_out_param(deploy, casper_deploy_t(value)),
+        error,
+    )
+}
+
+/// Parses a `Deploy` from JSON, and stores the handle in `deploy`.
+///
+/// See [super::deploy_from_json](super::deploy_from_json) for more details.
+#[no_mangle]
+pub extern "C" fn casper_deploy_from_json(
+    json: *const c_char,
+    deploy: *mut *mut casper_deploy_t,
+    error: *mut *mut casper_string_t,
+) -> casper_error_t {
+    let json = try_unsafe_arg!(json, error);
+    let result = super::deploy_from_json(json);
+    complete(
+        result,
+        |value| set_out_param(deploy, casper_deploy_t(value)),
+        error,
+    )
+}
+
+/// Serializes a `Deploy` to JSON, and stores the string in `json`.
+#[no_mangle]
+pub extern "C" fn casper_deploy_to_json(
+    deploy: *const casper_deploy_t,
+    json: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
+) -> casper_error_t {
+    let deploy = try_unsafe_handle!(deploy, error);
+    let result = super::deploy_to_json(&deploy.0);
+    complete(
+        result,
+        |value| set_out_param(json, casper_string_t::new(value)),
+        error,
+    )
 }
 
-/// Perform a clean shutdown of resources gathered in the client library.
+/// Stores the hex-encoded hash of a `Deploy` in `hash`.
 #[no_mangle]
-pub extern "C" fn casper_shutdown_client() {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    *runtime = None; // triggers drop on our runtime
+pub extern "C" fn casper_deploy_hash(
+    deploy: *const casper_deploy_t,
+    hash: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
+) -> casper_error_t {
+    let deploy = try_unsafe_handle!(deploy, error);
+    set_out_param(
+        hash,
+        casper_string_t::new(hex::encode(deploy.0.id().inner())),
+    );
+    casper_error_t::CASPER_SUCCESS
 }
 
-/// Gets the last error copied to the provided buffer.
+/// Cryptographically signs a `Deploy` with the secret key read from the file at `secret_key`,
+/// adding the approval to any existing ones.
 ///
-/// * `buf` is the buffer where the result will be stored.
-/// * `len` is the length of the `buf` buffer in bytes.
-/// - returns the number of bytes written to `buf`.
+/// See [super::sign_deploy](super::sign_deploy) for more details.
+#[no_mangle]
+pub extern "C" fn casper_deploy_sign(
+    deploy: *mut casper_deploy_t,
+    secret_key: *const c_char,
+    error: *mut *mut casper_string_t,
+) -> casper_error_t {
+    let deploy = try_unwrap_result!(
+        unsafe { deploy.as_mut() }.ok_or(Error::FFIPtrNullButRequired("deploy")),
+        error
+    );
+    let secret_key = try_unsafe_arg!(secret_key, error);
+    let secret_key = try_unwrap_result!(super::parsing::secret_key(secret_key), error);
+    super::sign_deploy(&mut deploy.0, &secret_key);
+    casper_error_t::CASPER_SUCCESS
+}
+
+/// Releases a `Deploy` handle.  Passing null is a no-op.
 #[no_mangle]
-pub extern "C" fn casper_get_last_error(buf: *mut c_uchar, len: usize) -> usize {
-    if let Some(last_err) = &*LAST_ERROR.lock().expect("should lock") {
-        let err_str = format!("{}", last_err);
-        return copy_str_to_buf(&err_str, buf, len);
+pub extern "C" fn casper_deploy_free(deploy: *mut casper_deploy_t) {
+    if !deploy.is_null() {
+        drop(unsafe { Box::from_raw(deploy) });
     }
-    0
+}
+
+/// Sends a `Deploy` to the network for execution.
+///
+/// See [super::send_deploy](super::send_deploy) for more details.
+#[no_mangle]
+pub extern "C" fn casper_send_deploy(
+    client: *const casper_client_t,
+    maybe_rpc_id: *const c_char,
+    node_address: *const c_char,
+    verbosity_level: u64,
+    deploy: *const casper_deploy_t,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
+) -> casper_error_t {
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let deploy = try_unsafe_handle!(deploy, error).0.clone();
+    let result = client.run(super::send_deploy(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Creates a `Deploy` and sends it to the network for execution.
@@ -253,35 +476,31 @@ pub extern "C" fn casper_get_last_error(buf: *mut c_uchar, len: usize) -> usize
 /// See [super::put_deploy](super::put_deploy) for more details
 #[no_mangle]
 pub extern "C" fn casper_put_deploy(
+    client: *const casper_client_t,
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
     deploy_params: *const casper_deploy_params_t,
     session_params: *const casper_session_params_t,
     payment_params: *const casper_payment_params_t,
-    response_buf: *mut c_uchar,
-    response_buf_len: usize,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
-    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
-    let node_address = try_unsafe_arg!(node_address);
-    let deploy_params = try_arg_into!(deploy_params);
-    let session_params = try_arg_into!(session_params);
-    let payment_params = try_arg_into!(payment_params);
-    runtime.block_on(async move {
-        let result = super::put_deploy(
-            maybe_rpc_id,
-            node_address,
-            verbosity_level,
-            deploy_params,
-            session_params,
-            payment_params,
-        );
-        let response = try_unwrap_rpc!(result);
-        copy_str_to_buf(&response, response_buf, response_buf_len);
-        casper_error_t::CASPER_SUCCESS
-    })
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let deploy_params = try_arg_into!(deploy_params, error);
+    let session_params = try_arg_into!(session_params, error);
+    let payment_params = try_arg_into!(payment_params, error);
+    let result = client.run(super::put_deploy(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_params,
+        session_params,
+        payment_params,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Creates a `Deploy` and outputs it to a file or stdout.
@@ -293,19 +512,19 @@ pub extern "C" fn casper_make_deploy(
     deploy_params: *const casper_deploy_params_t,
     session_params: *const casper_session_params_t,
     payment_params: *const casper_payment_params_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let maybe_output_path = try_unsafe_arg!(maybe_output_path);
-    let deploy_params = try_arg_into!(deploy_params);
-    let session_params = try_arg_into!(session_params);
-    let payment_params = try_arg_into!(payment_params);
+    let maybe_output_path = try_unsafe_arg!(maybe_output_path, error);
+    let deploy_params = try_arg_into!(deploy_params, error);
+    let session_params = try_arg_into!(session_params, error);
+    let payment_params = try_arg_into!(payment_params, error);
     let result = super::make_deploy(
         maybe_output_path,
         deploy_params,
         session_params,
         payment_params,
     );
-    try_unwrap_result!(result);
-    casper_error_t::CASPER_SUCCESS
+    complete(result, |()| (), error)
 }
 
 /// Reads a previously-saved `Deploy` from a file, cryptographically signs it, and outputs it to a
@@ -317,13 +536,13 @@ pub extern "C" fn casper_sign_deploy_file(
     input_path: *const c_char,
     secret_key: *const c_char,
     maybe_output_path: *const c_char,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let input_path = try_unsafe_arg!(input_path);
-    let secret_key = try_unsafe_arg!(secret_key);
-    let maybe_output_path = try_unsafe_arg!(maybe_output_path);
+    let input_path = try_unsafe_arg!(input_path, error);
+    let secret_key = try_unsafe_arg!(secret_key, error);
+    let maybe_output_path = try_unsafe_arg!(maybe_output_path, error);
     let result = super::sign_deploy_file(input_path, secret_key, maybe_output_path);
-    try_unwrap_result!(result);
-    casper_error_t::CASPER_SUCCESS
+    complete(result, |()| (), error)
 }
 
 /// Reads a previously-saved `Deploy` from a file and sends it to the network for execution.
@@ -331,25 +550,25 @@ pub extern "C" fn casper_sign_deploy_file(
 /// See [super::send_deploy_file](super::send_deploy_file) for more details.
 #[no_mangle]
 pub extern "C" fn casper_send_deploy_file(
+    client: *const casper_client_t,
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
     input_path: *const c_char,
-    response_buf: *mut c_uchar,
-    response_buf_len: usize,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
-    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
-    let node_address = try_unsafe_arg!(node_address);
-    let input_path = try_unsafe_arg!(input_path);
-    runtime.block_on(async move {
-        let result =
-            super::send_deploy_file(maybe_rpc_id, node_address, verbosity_level, input_path);
-        let response = try_unwrap_rpc!(result);
-        copy_str_to_buf(&response, response_buf, response_buf_len);
-        casper_error_t::CASPER_SUCCESS
-    })
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let input_path = try_unsafe_arg!(input_path, error);
+    let result = client.run(super::send_deploy_file(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        input_path,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Transfers funds between purses.
@@ -357,6 +576,7 @@ pub extern "C" fn casper_send_deploy_file(
 /// See [super::transfer](super::transfer) for more details
 #[no_mangle]
 pub extern "C" fn casper_transfer(
+    client: *const casper_client_t,
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
@@ -365,33 +585,28 @@ pub extern "C" fn casper_transfer(
     maybe_id: *const c_char,
     deploy_params: *const casper_deploy_params_t,
     payment_params: *const casper_payment_params_t,
-    response_buf: *mut c_uchar,
-    response_buf_len: usize,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
-    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
-    let node_address = try_unsafe_arg!(node_address);
-    let amount = try_unsafe_arg!(amount);
-    let maybe_target_account = try_unsafe_arg!(maybe_target_account);
-    let maybe_id = try_unsafe_arg!(maybe_id);
-    let deploy_params = try_arg_into!(deploy_params);
-    let payment_params = try_arg_into!(payment_params);
-    runtime.block_on(async move {
-        let result = super::transfer(
-            maybe_rpc_id,
-            node_address,
-            verbosity_level,
-            amount,
-            maybe_target_account,
-            maybe_id,
-            deploy_params,
-            payment_params,
-        );
-        let response = try_unwrap_rpc!(result);
-        copy_str_to_buf(&response, response_buf, response_buf_len);
-        casper_error_t::CASPER_SUCCESS
-    })
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let amount = try_unsafe_arg!(amount, error);
+    let maybe_target_account = try_unsafe_arg!(maybe_target_account, error);
+    let maybe_id = try_unsafe_arg!(maybe_id, error);
+    let deploy_params = try_arg_into!(deploy_params, error);
+    let payment_params = try_arg_into!(payment_params, error);
+    let result = client.run(super::transfer(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        amount,
+        maybe_target_account,
+        maybe_id,
+        deploy_params,
+        payment_params,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Retrieves a `Deploy` from the network.
@@ -399,24 +614,25 @@ pub extern "C" fn casper_transfer(
 /// See [super::get_deploy](super::get_deploy) for more details.
 #[no_mangle]
 pub extern "C" fn casper_get_deploy(
+    client: *const casper_client_t,
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
     deploy_hash: *const c_char,
-    response_buf: *mut c_uchar,
-    response_buf_len: usize,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
-    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
-    let node_address = try_unsafe_arg!(node_address);
-    let deploy_hash = try_unsafe_arg!(deploy_hash);
-    runtime.block_on(async move {
-        let result = super::get_deploy(maybe_rpc_id, node_address, verbosity_level, deploy_hash);
-        let response = try_unwrap_rpc!(result);
-        copy_str_to_buf(&response, response_buf, response_buf_len);
-        casper_error_t::CASPER_SUCCESS
-    })
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let deploy_hash = try_unsafe_arg!(deploy_hash, error);
+    let result = client.run(super::get_deploy(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        deploy_hash,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Retrieves a `Block` from the network.
@@ -424,50 +640,51 @@ pub extern "C" fn casper_get_deploy(
 /// See [super::get_block](super::get_block) for more details.
 #[no_mangle]
 pub extern "C" fn casper_get_block(
+    client: *const casper_client_t,
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
     maybe_block_id: *const c_char,
-    response_buf: *mut c_uchar,
-    response_buf_len: usize,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
-    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
-    let node_address = try_unsafe_arg!(node_address);
-    let maybe_block_id = try_unsafe_arg!(maybe_block_id);
-    runtime.block_on(async move {
-        let result = super::get_block(maybe_rpc_id, node_address, verbosity_level, maybe_block_id);
-        let response = try_unwrap_rpc!(result);
-        copy_str_to_buf(&response, response_buf, response_buf_len);
-        casper_error_t::CASPER_SUCCESS
-    })
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let maybe_block_id = try_unsafe_arg!(maybe_block_id, error);
+    let result = client.run(super::get_block(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Retrieves all `Transfer` items for a `Block` from the network.
 ///
-/// See [super::casper_get_block_transfers](super::casper_get_block_transfers) for more details.
+/// See [super::get_block_transfers](super::get_block_transfers) for more details.
 #[no_mangle]
 pub extern "C" fn casper_get_block_transfers(
+    client: *const casper_client_t,
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
     maybe_block_id: *const c_char,
-    response_buf: *mut c_uchar,
-    response_buf_len: usize,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
-    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
-    let node_address = try_unsafe_arg!(node_address);
-    let maybe_block_id = try_unsafe_arg!(maybe_block_id);
-    runtime.block_on(async move {
-        let result =
-            super::get_block_transfers(maybe_rpc_id, node_address, verbosity_level, maybe_block_id);
-        let response = try_unwrap_rpc!(result);
-        copy_str_to_buf(&response, response_buf, response_buf_len);
-        casper_error_t::CASPER_SUCCESS
-    })
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let maybe_block_id = try_unsafe_arg!(maybe_block_id, error);
+    let result = client.run(super::get_block_transfers(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Retrieves a state root hash at a given `Block`.
@@ -475,25 +692,25 @@ pub extern "C" fn casper_get_block_transfers(
 /// See [super::get_state_root_hash](super::get_state_root_hash) for more details.
 #[no_mangle]
 pub extern "C" fn casper_get_state_root_hash(
+    client: *const casper_client_t,
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
     maybe_block_id: *const c_char,
-    response_buf: *mut c_uchar,
-    response_buf_len: usize,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
-    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
-    let node_address = try_unsafe_arg!(node_address);
-    let maybe_block_id = try_unsafe_arg!(maybe_block_id);
-    runtime.block_on(async move {
-        let result =
-            super::get_state_root_hash(maybe_rpc_id, node_address, verbosity_level, maybe_block_id);
-        let response = try_unwrap_rpc!(result);
-        copy_str_to_buf(&response, response_buf, response_buf_len);
-        casper_error_t::CASPER_SUCCESS
-    })
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let maybe_block_id = try_unsafe_arg!(maybe_block_id, error);
+    let result = client.run(super::get_state_root_hash(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Retrieves a stored value from the network.
@@ -501,35 +718,31 @@ pub extern "C" fn casper_get_state_root_hash(
 /// See [super::get_item](super::get_item) for more details.
 #[no_mangle]
 pub extern "C" fn casper_get_item(
+    client: *const casper_client_t,
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
     state_root_hash: *const c_char,
     key: *const c_char,
     path: *const c_char,
-    response_buf: *mut c_uchar,
-    response_buf_len: usize,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
-    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
-    let node_address = try_unsafe_arg!(node_address);
-    let state_root_hash = try_unsafe_arg!(state_root_hash);
-    let key = try_unsafe_arg!(key);
-    let path = try_unsafe_arg!(path);
-    runtime.block_on(async move {
-        let result = super::get_item(
-            maybe_rpc_id,
-            node_address,
-            verbosity_level,
-            state_root_hash,
-            key,
-            path,
-        );
-        let response = try_unwrap_rpc!(result);
-        copy_str_to_buf(&response, response_buf, response_buf_len);
-        casper_error_t::CASPER_SUCCESS
-    })
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let state_root_hash = try_unsafe_arg!(state_root_hash, error);
+    let key = try_unsafe_arg!(key, error);
+    let path = try_unsafe_arg!(path, error);
+    let result = client.run(super::get_item(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        state_root_hash,
+        key,
+        path,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Retrieves a purse's balance from the network.
@@ -537,32 +750,28 @@ pub extern "C" fn casper_get_item(
 /// See [super::get_balance](super::get_balance) for more details.
 #[no_mangle]
 pub extern "C" fn casper_get_balance(
+    client: *const casper_client_t,
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
     state_root_hash: *const c_char,
     purse: *const c_char,
-    response_buf: *mut c_uchar,
-    response_buf_len: usize,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
-    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
-    let node_address = try_unsafe_arg!(node_address);
-    let state_root_hash = try_unsafe_arg!(state_root_hash);
-    let purse = try_unsafe_arg!(purse);
-    runtime.block_on(async move {
-        let result = super::get_balance(
-            maybe_rpc_id,
-            node_address,
-            verbosity_level,
-            state_root_hash,
-            purse,
-        );
-        let response = try_unwrap_rpc!(result);
-        copy_str_to_buf(&response, response_buf, response_buf_len);
-        casper_error_t::CASPER_SUCCESS
-    })
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let state_root_hash = try_unsafe_arg!(state_root_hash, error);
+    let purse = try_unsafe_arg!(purse, error);
+    let result = client.run(super::get_balance(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        state_root_hash,
+        purse,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Retrieves era information from the network.
@@ -570,29 +779,25 @@ pub extern "C" fn casper_get_balance(
 /// See [super::get_era_info_by_switch_block](super::get_era_info_by_switch_block) for more details.
 #[no_mangle]
 pub extern "C" fn casper_get_era_info_by_switch_block(
+    client: *const casper_client_t,
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
     maybe_block_id: *const c_char,
-    response_buf: *mut c_uchar,
-    response_buf_len: usize,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
-    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
-    let node_address = try_unsafe_arg!(node_address);
-    let maybe_block_id = try_unsafe_arg!(maybe_block_id);
-    runtime.block_on(async move {
-        let result = super::get_era_info_by_switch_block(
-            maybe_rpc_id,
-            node_address,
-            verbosity_level,
-            maybe_block_id,
-        );
-        let response = try_unwrap_rpc!(result);
-        copy_str_to_buf(&response, response_buf, response_buf_len);
-        casper_error_t::CASPER_SUCCESS
-    })
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let maybe_block_id = try_unsafe_arg!(maybe_block_id, error);
+    let result = client.run(super::get_era_info_by_switch_block(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_block_id,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Retrieves the bids and validators as of the most recently added `Block`.
@@ -600,22 +805,22 @@ pub extern "C" fn casper_get_era_info_by_switch_block(
 /// See [super::get_auction_info](super::get_auction_info) for more details.
 #[no_mangle]
 pub extern "C" fn casper_get_auction_info(
+    client: *const casper_client_t,
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
-    response_buf: *mut c_uchar,
-    response_buf_len: usize,
+    response: *mut *mut casper_string_t,
+    error: *mut *mut casper_string_t,
 ) -> casper_error_t {
-    let mut runtime = RUNTIME.lock().expect("should lock");
-    let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
-    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
-    let node_address = try_unsafe_arg!(node_address);
-    runtime.block_on(async move {
-        let result = super::get_auction_info(maybe_rpc_id, node_address, verbosity_level);
-        let response = try_unwrap_rpc!(result);
-        copy_str_to_buf(&response, response_buf, response_buf_len);
-        casper_error_t::CASPER_SUCCESS
-    })
+    let client = try_unsafe_handle!(client, error);
+    let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id, error);
+    let node_address = try_unsafe_arg!(node_address, error);
+    let result = client.run(super::get_auction_info(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+    ));
+    complete_rpc(result, response, error)
 }
 
 /// Container for `Deploy` construction options.
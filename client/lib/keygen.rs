@@ -2,10 +2,13 @@
 
 use std::{fs, path::Path};
 
-use casper_node::crypto::AsymmetricKeyExt;
+use casper_node_types::crypto::AsymmetricKeyExt;
 use casper_types::{AsymmetricType, PublicKey, SecretKey};
 
-use crate::error::{Error, Result};
+use crate::{
+    error::{Error, Result},
+    keystore, mnemonic,
+};
 
 /// Default filename for the PEM-encoded secret key file.
 pub const SECRET_KEY_PEM: &str = "secret_key.pem";
@@ -33,6 +36,36 @@ pub const SECP256K1: &str = "secp256k1";
 /// files exist, [`Error::FileAlreadyExists`](../enum.Error.html#variant.FileAlreadyExists) is
 /// returned and no files are written.
 pub fn generate_files(output_dir: &str, algorithm: &str, force: bool) -> Result<()> {
+    generate_files_with_options(output_dir, algorithm, force, KeygenOptions::default())
+}
+
+/// Options controlling how the secret key is generated and stored by
+/// [`generate_files_with_options`](fn.generate_files_with_options.html).
+#[derive(Clone, Copy, Default, Debug)]
+pub struct KeygenOptions<'a> {
+    /// If non-empty, the secret key file is encrypted with this password.  See the
+    /// [`keystore`](../keystore/index.html) module.
+    pub password: &'a str,
+    /// If non-empty, the secret key is derived from this BIP-39 mnemonic phrase rather than
+    /// generated randomly.
+    pub mnemonic: &'a str,
+    /// The optional BIP-39 passphrase used with `mnemonic`.
+    pub mnemonic_passphrase: &'a str,
+    /// The derivation path used with `mnemonic`.  If empty, the default path of the algorithm is
+    /// used.
+    pub derivation_path: &'a str,
+}
+
+/// Generates or derives an asymmetric key pair as specified by `options`, and writes them to files
+/// in the specified directory, as [`generate_files`](fn.generate_files.html) does.
+///
+/// If `options.password` is non-empty, "secret_key.pem" is encrypted with it.
+pub fn generate_files_with_options(
+    output_dir: &str,
+    algorithm: &str,
+    force: bool,
+    options: KeygenOptions,
+) -> Result<()> {
     if output_dir.is_empty() {
         return Err(Error::InvalidArgument(
             "generate_files",
@@ -58,7 +91,14 @@ pub fn generate_files(output_dir: &str, algorithm: &str, force: bool) -> Result<
         }
     }
 
-    let secret_key = if algorithm.eq_ignore_ascii_case(ED25519) {
+    let secret_key = if !options.mnemonic.is_empty() {
+        mnemonic::derive_secret_key(
+            options.mnemonic,
+            options.mnemonic_passphrase,
+            algorithm,
+            options.derivation_path,
+        )?
+    } else if algorithm.eq_ignore_ascii_case(ED25519) {
         SecretKey::generate_ed25519().unwrap()
     } else if algorithm.eq_ignore_ascii_case(SECP256K1) {
         SecretKey::generate_secp256k1().unwrap()
@@ -78,12 +118,20 @@ pub fn generate_files(output_dir: &str, algorithm: &str, force: bool) -> Result<
     })?;
 
     let secret_key_path = output_dir.join(SECRET_KEY_PEM);
-    secret_key
-        .to_file(&secret_key_path)
-        .map_err(|error| Error::CryptoError {
-            context: "secret_key",
+    if options.password.is_empty() {
+        secret_key
+            .to_file(&secret_key_path)
+            .map_err(|error| Error::CryptoError {
+                context: "secret_key",
+                error,
+            })?;
+    } else {
+        let encrypted = keystore::encrypt_secret_key(&secret_key, options.password)?;
+        fs::write(&secret_key_path, encrypted).map_err(|error| Error::IoError {
+            context: format!("unable to write secret key file at {:?}", secret_key_path),
             error,
         })?;
+    }
 
     let public_key_path = output_dir.join(PUBLIC_KEY_PEM);
     public_key
@@ -0,0 +1,261 @@
+//! Password-based encryption of secret key files.
+//!
+//! An encrypted secret key file is a PEM file tagged `ENCRYPTED CASPER SECRET KEY`.  Its contents
+//! are a version byte, the number of PBKDF2 iterations as a big-endian `u32`, a random salt and
+//! nonce, followed by the DER-encoded secret key encrypted with AES-256-GCM.  The encryption key is
+//! derived from the password with PBKDF2-HMAC-SHA256, and everything preceding the ciphertext is
+//! authenticated as associated data.
+
+#[cfg(target_arch = "wasm32")]
+use std::io;
+use std::{env, fs};
+
+use aes_gcm::{
+    aead::{generic_array::GenericArray, Aead, NewAead, Payload},
+    Aes256Gcm,
+};
+use hmac::Hmac;
+use pem::Pem;
+use rand::{rngs::OsRng, RngCore};
+use sha2::Sha256;
+
+use casper_node_types::crypto::AsymmetricKeyExt;
+use casper_types::SecretKey;
+
+use crate::error::{Error, Result};
+
+/// The environment variable from which the password of an encrypted secret key file is read.  If
+/// unset, the password is prompted for.
+pub const PASSWORD_ENV_VAR: &str = "CASPER_SECRET_KEY_PASSWORD";
+
+const ENCRYPTED_PEM_TAG: &str = "ENCRYPTED CASPER SECRET KEY";
+const VERSION: u8 = 1;
+const PBKDF2_ITERATIONS: u32 = 200_000;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+const KEY_LENGTH: usize = 32;
+const HEADER_LENGTH: usize = 1 + 4 + SALT_LENGTH + NONCE_LENGTH;
+
+fn cipher(password: &str, salt: &[u8], iterations: u32) -> Aes256Gcm {
+    let mut key = [0u8; KEY_LENGTH];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(password.as_bytes(), salt, iterations, &mut key);
+    Aes256Gcm::new(GenericArray::from_slice(&key))
+}
+
+/// Encrypts `secret_key` with `password`, returning the contents of a PEM file.
+pub fn encrypt_secret_key(secret_key: &SecretKey, password: &str) -> Result<String> {
+    encrypt_with_iterations(secret_key, password, PBKDF2_ITERATIONS)
+}
+
+fn encrypt_with_iterations(
+    secret_key: &SecretKey,
+    password: &str,
+    iterations: u32,
+) -> Result<String> {
+    let der = secret_key.to_der().map_err(|error| Error::CryptoError {
+        context: "secret_key",
+        error,
+    })?;
+
+    let mut salt = [0u8; SALT_LENGTH];
+    OsRng.fill_bytes(&mut salt);
+    let mut nonce = [0u8; NONCE_LENGTH];
+    OsRng.fill_bytes(&mut nonce);
+
+    let mut contents = Vec::with_capacity(HEADER_LENGTH + der.len() + 16);
+    contents.push(VERSION);
+    contents.extend_from_slice(&iterations.to_be_bytes());
+    contents.extend_from_slice(&salt);
+    contents.extend_from_slice(&nonce);
+
+    let ciphertext = cipher(password, &salt, iterations)
+        .encrypt(
+            GenericArray::from_slice(&nonce),
+            Payload {
+                msg: &der,
+                aad: &contents,
+            },
+        )
+        .map_err(|_| Error::FailedToDecryptSecretKey("encryption failed".to_string()))?;
+    contents.extend_from_slice(&ciphertext);
+
+    Ok(pem::encode(&Pem {
+        tag: ENCRYPTED_PEM_TAG.to_string(),
+        contents,
+    }))
+}
+
+/// Decrypts the contents of an encrypted secret key PEM file with `password`.
+pub fn decrypt_secret_key(input: &str, password: &str) -> Result<SecretKey> {
+    let invalid = |reason: &str| Error::FailedToDecryptSecretKey(reason.to_string());
+    let pem = pem::parse(input).map_err(|error| invalid(&error.to_string()))?;
+    if pem.tag != ENCRYPTED_PEM_TAG {
+        return Err(invalid(&format!("unexpected PEM tag '{}'", pem.tag)));
+    }
+    let contents = pem.contents;
+    if contents.len() <= HEADER_LENGTH {
+        return Err(invalid("file is too short"));
+    }
+    if contents[0] != VERSION {
+        return Err(invalid(&format!("unsupported version {}", contents[0])));
+    }
+    let (header, ciphertext) = contents.split_at(HEADER_LENGTH);
+    let mut iterations = [0u8; 4];
+    iterations.copy_from_slice(&header[1..5]);
+    let salt = &header[5..5 + SALT_LENGTH];
+    let nonce = &header[5 + SALT_LENGTH..];
+
+    let der = cipher(password, salt, u32::from_be_bytes(iterations))
+        .decrypt(
+            GenericArray::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: header,
+            },
+        )
+        .map_err(|_| invalid("incorrect password or corrupted file"))?;
+    SecretKey::from_der(der).map_err(|error| Error::CryptoError {
+        context: "secret_key",
+        error,
+    })
+}
+
+/// Returns whether `input` is the contents of an encrypted secret key PEM file.
+pub fn is_encrypted(input: &str) -> bool {
+    input.contains(&format!("-----BEGIN {}-----", ENCRYPTED_PEM_TAG))
+}
+
+/// Reads a secret key from a plaintext or encrypted PEM file.
+///
+/// The password of an encrypted file is read from the `CASPER_SECRET_KEY_PASSWORD` environment
+/// variable, or prompted for if that is unset.
+pub fn read_secret_key(path: &str) -> Result<SecretKey> {
+    let input = fs::read_to_string(path).map_err(|error| Error::IoError {
+        context: format!("unable to read secret key file at '{}'", path),
+        error,
+    })?;
+    if !is_encrypted(&input) {
+        return secret_key_from_pem(&input, "");
+    }
+    let password = match env::var(PASSWORD_ENV_VAR) {
+        Ok(password) => password,
+        Err(_) => prompt_secret(&format!("Password for {}: ", path))?,
+    };
+    decrypt_secret_key(&input, &password)
+}
+
+/// Reads the password for a new encrypted secret key file from the `CASPER_SECRET_KEY_PASSWORD`
+/// environment variable, or prompts for it twice if that is unset.
+pub fn read_new_password() -> Result<String> {
+    if let Ok(password) = env::var(PASSWORD_ENV_VAR) {
+        return Ok(password);
+    }
+    let password = prompt_secret("New password: ")?;
+    if password.is_empty() {
+        return Err(Error::InvalidArgument(
+            "password",
+            "must not be empty".to_string(),
+        ));
+    }
+    if prompt_secret("Repeat password: ")? != password {
+        return Err(Error::InvalidArgument(
+            "password",
+            "passwords do not match".to_string(),
+        ));
+    }
+    Ok(password)
+}
+
+/// Prompts for a secret on the terminal without echoing it.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn prompt_secret(prompt: &str) -> Result<String> {
+    rpassword::read_password_from_tty(Some(prompt)).map_err(|error| Error::IoError {
+        context: "unable to read from the terminal".to_string(),
+        error,
+    })
+}
+
+/// There is no terminal to prompt on when targeting WebAssembly, so this always fails.
+#[cfg(target_arch = "wasm32")]
+pub fn prompt_secret(_prompt: &str) -> Result<String> {
+    Err(Error::IoError {
+        context: "unable to read from the terminal".to_string(),
+        error: io::Error::new(io::ErrorKind::Other, "not supported on this platform"),
+    })
+}
+
+/// Reads a secret key from the contents of a plaintext or encrypted PEM file.  `password` is only
+/// used if the contents are encrypted.
+pub fn secret_key_from_pem(input: &str, password: &str) -> Result<SecretKey> {
+    if is_encrypted(input) {
+        return decrypt_secret_key(input, password);
+    }
+    SecretKey::from_pem(input).map_err(|error| Error::CryptoError {
+        context: "secret_key",
+        error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Keeps the tests fast; the iteration count is stored in the file.
+    const TEST_ITERATIONS: u32 = 1_000;
+
+    #[test]
+    fn should_round_trip_encrypted_secret_keys() {
+        for secret_key in &[
+            SecretKey::generate_ed25519().unwrap(),
+            SecretKey::generate_secp256k1().unwrap(),
+        ] {
+            let encrypted =
+                encrypt_with_iterations(secret_key, "hunter2", TEST_ITERATIONS).unwrap();
+            assert!(is_encrypted(&encrypted));
+            let decrypted = decrypt_secret_key(&encrypted, "hunter2").unwrap();
+            assert_eq!(decrypted.as_slice(), secret_key.as_slice());
+        }
+    }
+
+    #[test]
+    fn should_reject_incorrect_password_and_tampering() {
+        let secret_key = SecretKey::generate_ed25519().unwrap();
+        let encrypted = encrypt_with_iterations(&secret_key, "hunter2", TEST_ITERATIONS).unwrap();
+        assert!(matches!(
+            decrypt_secret_key(&encrypted, "hunter3"),
+            Err(Error::FailedToDecryptSecretKey(_))
+        ));
+
+        let mut pem = pem::parse(&encrypted).unwrap();
+        // Tampering with the authenticated header must be detected too.
+        pem.contents[4] ^= 1;
+        assert!(decrypt_secret_key(&pem::encode(&pem), "hunter2").is_err());
+
+        let plaintext = secret_key.to_pem().unwrap();
+        assert!(!is_encrypted(&plaintext));
+        assert!(decrypt_secret_key(&plaintext, "hunter2").is_err());
+    }
+
+    #[test]
+    fn should_read_plaintext_and_encrypted_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let secret_key = SecretKey::generate_secp256k1().unwrap();
+
+        let plaintext_path = temp_dir.path().join("plain.pem");
+        secret_key.to_file(&plaintext_path).unwrap();
+        let read = read_secret_key(plaintext_path.to_str().unwrap()).unwrap();
+        assert_eq!(read.as_slice(), secret_key.as_slice());
+
+        let encrypted_path = temp_dir.path().join("encrypted.pem");
+        let password = "correct horse battery staple";
+        fs::write(
+            &encrypted_path,
+            encrypt_with_iterations(&secret_key, password, TEST_ITERATIONS).unwrap(),
+        )
+        .unwrap();
+        env::set_var(PASSWORD_ENV_VAR, password);
+        let read = read_secret_key(encrypted_path.to_str().unwrap()).unwrap();
+        env::remove_var(PASSWORD_ENV_VAR);
+        assert_eq!(read.as_slice(), secret_key.as_slice());
+    }
+}
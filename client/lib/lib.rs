@@ -12,36 +12,73 @@
     unused_qualifications
 )]
 
+#[cfg(feature = "network")]
+mod check;
 mod cl_type;
 mod deploy;
+mod encoding;
 mod error;
+#[cfg(feature = "network")]
+mod explorer;
 #[cfg(feature = "ffi")]
 pub mod ffi;
 pub mod keygen;
+pub mod keystore;
+#[cfg(feature = "network")]
+mod load_test;
+pub mod mnemonic;
+mod multisig;
 mod parsing;
+#[cfg(feature = "network")]
 mod rpc;
+#[cfg(feature = "network")]
 mod validation;
+#[cfg(feature = "network")]
+mod wait;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 use std::{convert::TryInto, fs::File};
 
+#[cfg(feature = "network")]
 use jsonrpc_lite::JsonRpc;
 use serde::Serialize;
 
-use casper_execution_engine::core::engine_state::ExecutableDeployItem;
-use casper_node::types::Deploy;
+#[cfg(feature = "network")]
+use casper_node_types::{crypto::hash::Digest, Timestamp};
+use casper_node_types::{Deploy, ExecutableDeployItem};
+use casper_types::SecretKey;
+#[cfg(feature = "network")]
 use casper_types::{UIntParseError, U512};
 
+#[cfg(feature = "network")]
+pub use check::{DeployCheckReport, DeployLimits};
 pub use cl_type::help;
 pub use deploy::ListDeploysResult;
 use deploy::{DeployExt, DeployParams};
+pub use encoding::{DeployEncoding, EncodedField};
 pub use error::Error;
 use error::Result;
+#[cfg(feature = "network")]
+pub use explorer::{ExploredValue, Explorer};
+#[cfg(feature = "network")]
+pub use load_test::{LoadTestReport, LoadTestStrParams, Percentiles, PhaseReport};
+pub use multisig::{
+    AccountApprovalStatus, ActionThresholds, ApprovalSummary, AssociatedKey, DeployApprovals,
+};
 use parsing::none_if_empty;
+#[cfg(feature = "network")]
 use rpc::{RpcCall, TransferTarget};
+#[cfg(feature = "network")]
 pub use validation::ValidateResponseError;
+#[cfg(feature = "network")]
+pub use wait::ExecutedDeploy;
 
 /// Creates a `Deploy` and sends it to the network for execution.
 ///
+/// Before sending, the `Deploy` is checked against the chain name and deploy limits provided by
+/// the node, as [`check_deploy()`](fn.check_deploy.html) does.
+///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
 ///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
 ///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
@@ -58,7 +95,8 @@ pub use validation::ValidateResponseError;
 ///   [`SessionStrParams`](struct.SessionStrParams.html) for more details.
 /// * `payment` contains payment-related options for this `Deploy`. See
 ///   [`PaymentStrParams`](struct.PaymentStrParams.html) for more details.
-pub fn put_deploy(
+#[cfg(feature = "network")]
+pub async fn put_deploy(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
@@ -66,12 +104,11 @@ pub fn put_deploy(
     session: SessionStrParams<'_>,
     payment: PaymentStrParams<'_>,
 ) -> Result<JsonRpc> {
-    let deploy = Deploy::with_payment_and_session(
-        deploy.try_into()?,
-        payment.try_into()?,
-        session.try_into()?,
-    );
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).put_deploy(deploy)
+    let deploy = create_deploy(deploy, session, payment)?;
+    check::check_deploy_with_node(maybe_rpc_id, node_address, verbosity_level, &deploy).await?;
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .put_deploy(deploy)
+        .await
 }
 
 /// Creates a `Deploy` and outputs it to a file or stdout.
@@ -109,8 +146,47 @@ pub fn make_deploy(
         }
     })?;
 
-    Deploy::with_payment_and_session(deploy.try_into()?, payment.try_into()?, session.try_into()?)
-        .write_deploy(output)
+    create_deploy(deploy, session, payment)?.write_deploy(output)
+}
+
+/// Creates a `Deploy` in memory, signed with the secret key given in `deploy`.
+///
+/// No node is contacted and nothing is written, so this is also available without the `network`
+/// feature.  Use [`deploy_to_json()`](fn.deploy_to_json.html) to serialize the result.
+///
+/// * `deploy` contains deploy-related options for this `Deploy`. See
+///   [`DeployStrParams`](struct.DeployStrParams.html) for more details.
+/// * `session` contains session-related options for this `Deploy`. See
+///   [`SessionStrParams`](struct.SessionStrParams.html) for more details.
+/// * `payment` contains payment-related options for this `Deploy`. See
+///   [`PaymentStrParams`](struct.PaymentStrParams.html) for more details.
+pub fn create_deploy(
+    deploy: DeployStrParams<'_>,
+    session: SessionStrParams<'_>,
+    payment: PaymentStrParams<'_>,
+) -> Result<Deploy> {
+    Ok(Deploy::with_payment_and_session(
+        deploy.try_into()?,
+        payment.try_into()?,
+        session.try_into()?,
+    ))
+}
+
+/// Cryptographically signs `deploy` with `secret_key`, adding the approval to any existing ones.
+pub fn sign_deploy(deploy: &mut Deploy, secret_key: &SecretKey) {
+    let mut rng = rand::thread_rng();
+    deploy.sign(secret_key, &mut rng);
+}
+
+/// Parses a `Deploy` from JSON, as output by [`deploy_to_json()`](fn.deploy_to_json.html) or
+/// [`make_deploy()`](fn.make_deploy.html).
+pub fn deploy_from_json(json: &str) -> Result<Deploy> {
+    Deploy::read_deploy(json.as_bytes())
+}
+
+/// Serializes `deploy` to pretty-printed JSON.
+pub fn deploy_to_json(deploy: &Deploy) -> Result<String> {
+    Ok(serde_json::to_string_pretty(deploy)?)
 }
 
 /// Reads a previously-saved `Deploy` from a file, cryptographically signs it, and outputs it to a
@@ -140,8 +216,102 @@ pub fn sign_deploy_file(input_path: &str, secret_key: &str, maybe_output_path: &
     Deploy::sign_and_write_deploy(input, secret_key, output)
 }
 
+/// Reads previously-saved copies of a `Deploy` from several files, each signed by some of the
+/// required signers, and outputs the `Deploy` with all of their approvals to a file or stdout.
+///
+/// * `input_paths` specifies the paths to the previously-saved copies of the `Deploy`. All of them
+///   must contain the same `Deploy`, and all approvals must be valid.
+/// * `maybe_output_path` specifies the output file, or if empty, will print it to `stdout`. If the
+///   file already exists, it will be overwritten.
+pub fn merge_deploy_files(input_paths: &[&str], maybe_output_path: &str) -> Result<()> {
+    let (first_path, other_paths) = input_paths.split_first().ok_or_else(|| {
+        Error::InvalidArgument("input_paths", "at least one path is required".to_string())
+    })?;
+    let first = deploy::read_deploy_file(first_path)?;
+    let mut deploy = first.clone();
+    // Merging the first copy into itself verifies its approvals.
+    deploy.merge_approvals(&first)?;
+    for input_path in other_paths {
+        let other = deploy::read_deploy_file(input_path)?;
+        deploy.merge_approvals(&other)?;
+    }
+
+    let maybe_output_path = parsing::output(maybe_output_path);
+    let output = deploy::output_or_stdout(maybe_output_path).map_err(|error| Error::IoError {
+        context: format!(
+            "unable to get file or stdout, provided '{:?}'",
+            maybe_output_path
+        ),
+        error,
+    })?;
+    deploy.write_deploy(output)
+}
+
+/// Reads a previously-saved `Deploy` from a file and summarizes its approvals.
+///
+/// No node is contacted, so the approvals are not checked against the associated keys of the
+/// `Deploy`'s account; see [`check_deploy_approvals`](fn.check_deploy_approvals.html) for that.
+///
+/// * `input_path` specifies the path to the previously-saved `Deploy` file.
+pub fn inspect_deploy_file(input_path: &str) -> Result<DeployApprovals> {
+    let deploy = deploy::read_deploy_file(input_path)?;
+    Ok(DeployApprovals::new(&deploy))
+}
+
+/// Reads a previously-saved `Deploy` from a file and returns its canonical `ToBytes`
+/// serialization, broken down into the fields of its header, body and approvals.
+///
+/// The result also holds the exact bytes hashed to give the header's body hash and the deploy
+/// hash, along with the hashes computed from them, so that other implementations can verify
+/// their serialization and hashing.
+///
+/// * `input_path` specifies the path to the previously-saved `Deploy` file.
+pub fn encode_deploy_file(input_path: &str) -> Result<DeployEncoding> {
+    let deploy = deploy::read_deploy_file(input_path)?;
+    DeployEncoding::new(&deploy)
+}
+
+/// Returns the JSON Schema of `Deploy` files, as written by
+/// [`make_deploy()`](fn.make_deploy.html) and [`sign_deploy_file()`](fn.sign_deploy_file.html).
+pub fn deploy_json_schema() -> Result<String> {
+    encoding::deploy_json_schema()
+}
+
+/// Reads a previously-saved `Deploy` from a file and checks its approvals against the associated
+/// keys and action thresholds of its account, as stored under the latest state root hash.
+///
+/// The result lists the associated keys which haven't approved the `Deploy` yet, and whether the
+/// summed weights of the approvals meet the account's deployment threshold. Use
+/// [`DeployApprovals::ensure_executable`](struct.DeployApprovals.html#method.ensure_executable)
+/// to turn an insufficient result into an error.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC requests will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the requests will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the requests
+///   will not be printed to `stdout`.
+/// * `input_path` specifies the path to the previously-saved `Deploy` file.
+#[cfg(feature = "network")]
+pub async fn check_deploy_approvals(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    input_path: &str,
+) -> Result<DeployApprovals> {
+    let deploy = deploy::read_deploy_file(input_path)?;
+    multisig::check_approvals(maybe_rpc_id, node_address, verbosity_level, &deploy).await
+}
+
 /// Reads a previously-saved `Deploy` from a file and sends it to the network for execution.
 ///
+/// Before sending, the `Deploy` is checked against the chain name and deploy limits provided by
+/// the node, as [`check_deploy()`](fn.check_deploy.html) does.
+///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
 ///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
 ///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
@@ -153,13 +323,93 @@ pub fn sign_deploy_file(input_path: &str, secret_key: &str, maybe_output_path: &
 ///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
 ///   will not be printed to `stdout`.
 /// * `input_path` specifies the path to the previously-saved `Deploy` file.
-pub fn send_deploy_file(
+#[cfg(feature = "network")]
+pub async fn send_deploy_file(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
     input_path: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).send_deploy_file(input_path)
+    let deploy = deploy::read_deploy_file(input_path)?;
+    send_deploy(maybe_rpc_id, node_address, verbosity_level, deploy).await
+}
+
+/// Sends a `Deploy` to the network for execution.
+///
+/// Before sending, the `Deploy` is checked against the chain name and deploy limits provided by
+/// the node, as [`check_deploy()`](fn.check_deploy.html) does.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `deploy` is the `Deploy` to send, e.g. as created by
+///   [`create_deploy()`](fn.create_deploy.html).
+#[cfg(feature = "network")]
+pub async fn send_deploy(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy: Deploy,
+) -> Result<JsonRpc> {
+    check::check_deploy_with_node(maybe_rpc_id, node_address, verbosity_level, &deploy).await?;
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .put_deploy(deploy)
+        .await
+}
+
+/// Reads a previously-saved `Deploy` from a file and checks it against the chain name and deploy
+/// limits of the network, as the node would when receiving it.
+///
+/// Besides the checks of the node's deploy acceptor, e.g. on the TTL, the number of dependencies
+/// and the length of the session and payment args, the `Deploy` must have at least one approval,
+/// must not have expired, and its gas limit and size must fit in a block.  On success, a summary
+/// of the `Deploy` including its gas limit is returned.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `maybe_chainspec_path` is the path to a chainspec file from which the chain name and deploy
+///   limits are read.  If empty, they are fetched from the node instead.
+/// * `input_path` specifies the path to the previously-saved `Deploy` file.
+#[cfg(feature = "network")]
+pub async fn check_deploy(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_chainspec_path: &str,
+    input_path: &str,
+) -> Result<DeployCheckReport> {
+    let deploy = deploy::read_deploy_file(input_path)?;
+    let limits = if maybe_chainspec_path.is_empty() {
+        DeployLimits::from_node(maybe_rpc_id, node_address, verbosity_level)
+            .await?
+            .ok_or_else(|| {
+                Error::InvalidArgument(
+                    "node_address",
+                    format!(
+                        "{} doesn't provide its deploy limits, pass a chainspec file instead",
+                        node_address
+                    ),
+                )
+            })?
+    } else {
+        DeployLimits::from_chainspec_file(maybe_chainspec_path)?
+    };
+    check::check_deploy(&deploy, &limits, Timestamp::now())
 }
 
 /// Transfers funds between purses.
@@ -190,8 +440,9 @@ pub fn send_deploy_file(
 ///   [`DeployStrParams`](struct.DeployStrParams.html) for more details.
 /// * `payment` contains payment-related options for this `Deploy`. See
 ///   [`PaymentStrParams`](struct.PaymentStrParams.html) for more details.
+#[cfg(feature = "network")]
 #[allow(clippy::too_many_arguments)]
-pub fn transfer(
+pub async fn transfer(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
@@ -210,14 +461,16 @@ pub fn transfer(
 
     let maybe_id = parsing::transfer_id(maybe_id)?;
 
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).transfer(
-        amount,
-        source_purse,
-        target,
-        maybe_id,
-        deploy_params.try_into()?,
-        payment_params.try_into()?,
-    )
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .transfer(
+            amount,
+            source_purse,
+            target,
+            maybe_id,
+            deploy_params.try_into()?,
+            payment_params.try_into()?,
+        )
+        .await
 }
 
 /// Retrieves a `Deploy` from the network.
@@ -233,13 +486,101 @@ pub fn transfer(
 ///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
 ///   will not be printed to `stdout`.
 /// * `deploy_hash` must be a hex-encoded, 32-byte hash digest.
-pub fn get_deploy(
+#[cfg(feature = "network")]
+pub async fn get_deploy(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
     deploy_hash: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_deploy(deploy_hash)
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .get_deploy(deploy_hash)
+        .await
+}
+
+/// Waits until a `Deploy` has been executed and returns the result of its execution.
+///
+/// The node's event stream is subscribed to in order to be notified of the execution, and the
+/// node is polled via `info_get_deploy` in case the event stream is unavailable.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the polling requests. If it can be
+///   parsed as an `i64` it will be used as a JSON integer. If empty, a random `i64` will be
+///   assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is greater than `0`, progress messages such as whether the event stream
+///   could be subscribed to will be printed to `stdout`.
+/// * `maybe_event_stream_address` is the hostname or IP and port of the node's event stream server,
+///   e.g. `"http://127.0.0.1:9999"`. If empty, the host of `node_address` with the default port
+///   `9999` will be used.
+/// * `deploy_hash` must be a hex-encoded, 32-byte hash digest.
+/// * `timeout` is how long to wait for, e.g. `"5min"`. For all options, see [`humantime::parse_duration`](https://docs.rs/humantime/latest/humantime/fn.parse_duration.html).
+///
+/// Returns `Error::WaitForDeployTimedOut` if the deploy was not executed within `timeout`. Note
+/// that a deploy which was executed but failed is returned successfully; use
+/// [`ExecutedDeploy::error_message`](struct.ExecutedDeploy.html#method.error_message) to check.
+#[cfg(feature = "network")]
+pub async fn wait_for_deploy(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_event_stream_address: &str,
+    deploy_hash: &str,
+    timeout: &str,
+) -> Result<ExecutedDeploy> {
+    let hash = Digest::from_hex(deploy_hash).map_err(|error| Error::CryptoError {
+        context: "deploy_hash",
+        error,
+    })?;
+    let duration = humantime::parse_duration(timeout)
+        .map_err(|error| Error::FailedToParseTimeDiff("timeout", error))?;
+    let maybe_executed = wait::wait_for_deploy(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_event_stream_address,
+        hash.into(),
+        duration,
+    )
+    .await?;
+    maybe_executed.ok_or_else(|| Error::WaitForDeployTimedOut {
+        deploy_hash: deploy_hash.to_string(),
+        timeout: timeout.to_string(),
+    })
+}
+
+/// Sends deploys to the network at a target rate from accounts derived from a seed, and reports
+/// how quickly they were accepted and executed.
+///
+/// If a faucet secret key is given, the derived accounts are first funded from it via native
+/// transfers.  Then either the given session Wasm is sent from each account in turn, or if none is
+/// given, native transfers are sent from each account to the next.  Executions are observed via
+/// the node's event stream, which must be available.
+///
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is greater than `0`, progress messages will be printed to `stdout`.
+/// * `maybe_event_stream_address` is the hostname or IP and port of the node's event stream server,
+///   e.g. `"http://127.0.0.1:9999"`. If empty, the host of `node_address` with the default port
+///   `9999` will be used.
+/// * `params` contains the parameters of the load test; see
+///   [`LoadTestStrParams`](struct.LoadTestStrParams.html) for more details.
+///
+/// Returns `Error::EventStreamUnavailable` if the event stream could not be subscribed to.
+#[cfg(feature = "network")]
+pub async fn load_test(
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_event_stream_address: &str,
+    params: LoadTestStrParams<'_>,
+) -> Result<LoadTestReport> {
+    load_test::load_test(
+        node_address,
+        verbosity_level,
+        maybe_event_stream_address,
+        params,
+    )
+    .await
 }
 
 /// Retrieves a `Block` from the network.
@@ -256,13 +597,16 @@ pub fn get_deploy(
 ///   will not be printed to `stdout`.
 /// * `maybe_block_id` must be a hex-encoded, 32-byte hash digest or a `u64` representing the
 ///   `Block` height or empty. If empty, the latest `Block` will be retrieved.
-pub fn get_block(
+#[cfg(feature = "network")]
+pub async fn get_block(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
     maybe_block_id: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_block(maybe_block_id)
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .get_block(maybe_block_id)
+        .await
 }
 
 /// Retrieves all `Transfer` items for a `Block` from the network.
@@ -279,13 +623,16 @@ pub fn get_block(
 ///   will not be printed to `stdout`.
 /// * `maybe_block_id` must be a hex-encoded, 32-byte hash digest or a `u64` representing the
 ///   `Block` height or empty. If empty, the latest `Block` transfers will be retrieved.
-pub fn get_block_transfers(
+#[cfg(feature = "network")]
+pub async fn get_block_transfers(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
     maybe_block_id: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_block_transfers(maybe_block_id)
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .get_block_transfers(maybe_block_id)
+        .await
 }
 
 /// Retrieves a state root hash at a given `Block`.
@@ -302,13 +649,16 @@ pub fn get_block_transfers(
 ///   will not be printed to `stdout`.
 /// * `maybe_block_id` must be a hex-encoded, 32-byte hash digest or a `u64` representing the
 ///   `Block` height or empty. If empty, the latest `Block` will be used.
-pub fn get_state_root_hash(
+#[cfg(feature = "network")]
+pub async fn get_state_root_hash(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
     maybe_block_id: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_state_root_hash(maybe_block_id)
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .get_state_root_hash(maybe_block_id)
+        .await
 }
 
 /// Retrieves a stored value from the network.
@@ -336,7 +686,8 @@ pub fn get_state_root_hash(
 /// deploy-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20      # Key::DeployInfo
 /// ```
 /// * `path` is comprised of components starting from the `key`, separated by `/`s.
-pub fn get_item(
+#[cfg(feature = "network")]
+pub async fn get_item(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
@@ -344,7 +695,9 @@ pub fn get_item(
     key: &str,
     path: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_item(state_root_hash, key, path)
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .get_item(state_root_hash, key, path)
+        .await
 }
 
 /// Retrieves a purse's balance from the network.
@@ -364,14 +717,17 @@ pub fn get_item(
 /// ```text
 /// uref-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20-007
 /// ```
-pub fn get_balance(
+#[cfg(feature = "network")]
+pub async fn get_balance(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
     state_root_hash: &str,
     purse: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_balance(state_root_hash, purse)
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .get_balance(state_root_hash, purse)
+        .await
 }
 
 /// Retrieves era information from the network.
@@ -389,7 +745,8 @@ pub fn get_balance(
 /// * `maybe_block_id` must be a hex-encoded, 32-byte hash digest or a `u64` representing the
 ///   `Block` height or empty. If empty, era information from the latest block will be returned if
 ///   available.
-pub fn get_era_info_by_switch_block(
+#[cfg(feature = "network")]
+pub async fn get_era_info_by_switch_block(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
@@ -397,6 +754,7 @@ pub fn get_era_info_by_switch_block(
 ) -> Result<JsonRpc> {
     RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
         .get_era_info_by_switch_block(maybe_block_id)
+        .await
 }
 
 /// Retrieves the bids and validators as of the most recently added `Block`.
@@ -411,12 +769,15 @@ pub fn get_era_info_by_switch_block(
 ///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
 ///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
 ///   will not be printed to `stdout`.
-pub fn get_auction_info(
+#[cfg(feature = "network")]
+pub async fn get_auction_info(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_auction_info()
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .get_auction_info()
+        .await
 }
 
 /// Retrieves information and examples for all currently supported RPCs.
@@ -431,8 +792,15 @@ pub fn get_auction_info(
 ///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
 ///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
 ///   will not be printed to `stdout`.
-pub fn list_rpcs(maybe_rpc_id: &str, node_address: &str, verbosity_level: u64) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).list_rpcs()
+#[cfg(feature = "network")]
+pub async fn list_rpcs(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .list_rpcs()
+        .await
 }
 
 /// Container for `Deploy` construction options.
@@ -497,8 +865,10 @@ impl<'a> TryInto<DeployParams> for DeployStrParams<'a> {
 /// For methods taking `payment_args_simple`, this parameter is the payment contract arguments, in
 /// the form `<NAME:TYPE='VALUE'>` or `<NAME:TYPE=null>`.
 ///
-/// It can only be used with the following simple `CLType`s: bool, i32, i64, u8, u32, u64, u128,
-/// u256, u512, unit, string, key, account_hash, uref, public_key and `Option` of each of these.
+/// It can be used with the following simple `CLType`s: bool, i32, i64, u8, u32, u64, u128, u256,
+/// u512, unit, string, key, account_hash, uref, public_key and `Option` of each of these, as well
+/// as with the compound types option<T>, list<T>, byte_array<N>, result<T, E>, map<K, V>,
+/// tuple1<T>, tuple2<T1, T2>, tuple3<T1, T2, T3> and any.
 ///
 /// Example inputs are:
 ///
@@ -538,6 +908,19 @@ impl<'a> TryInto<DeployParams> for DeployStrParams<'a> {
 /// name_10:opt_string=null       # None
 /// ```
 ///
+/// Compound types can be nested, and their values are given as JSON, apart from byte arrays and
+/// any which are given as hex:
+///
+/// ```text
+/// name_01:list<u64>='[1, 2, 3]'
+/// name_02:map<string, u512>='{"alice": "1000", "bob": "20"}'
+/// name_03:map<u8, bool>='[[1, true], [2, false]]'
+/// name_04:byte_array<4>='01020304'
+/// name_05:result<string, u32>='{"Ok": "done"}'
+/// name_06:tuple2<string, option<u8>>='["a value", null]'
+/// name_07:option<list<u8>>=null
+/// ```
+///
 /// To get a list of supported types, call
 /// [`supported_cl_type_list()`](help/fn.supported_cl_type_list.html). To get this list of examples
 /// for supported types, call
@@ -1234,7 +1617,7 @@ mod param_tests {
 
         #[test]
         fn should_fail_to_convert_with_bad_dependencies() {
-            use casper_node::crypto::Error as CryptoError;
+            use casper_node_types::crypto::Error as CryptoError;
             let mut params = test_value();
             params.dependencies = vec!["invalid dep"];
             let result: StdResult<DeployParams, Error> = params.try_into();
@@ -0,0 +1,683 @@
+//! Generating load on a network by sending deploys at a target rate, and measuring how quickly
+//! they are accepted and executed.
+//!
+//! The deploys are sent from accounts derived deterministically from a seed, so repeated runs
+//! against the same network use the same accounts, which only need to be funded once.  Executions
+//! are observed via the node's event stream, which is subscribed to before any deploy is sent.
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap},
+    time::Duration,
+};
+
+use futures::{stream::FuturesUnordered, StreamExt};
+use jsonrpc_lite::JsonRpc;
+use reqwest::Response;
+use serde::Serialize;
+use tokio::time::{self, Instant};
+
+use casper_node_types::{
+    crypto::hash, Deploy, DeployHash, ExecutableDeployItem, TimeDiff, Timestamp,
+};
+use casper_types::{AsymmetricType, PublicKey, SecretKey, UIntParseError, U512};
+
+use crate::{
+    error::{Error, Result},
+    parsing,
+    rpc::{self, RpcCall, TransferTarget},
+    wait::{self, ExecutedDeploy},
+};
+
+/// The parameters of a load test.
+#[derive(Default, Debug)]
+pub struct LoadTestStrParams<'a> {
+    /// The seed from which the sending accounts are derived.
+    pub seed: &'a str,
+    /// The number of accounts to derive and send deploys from.
+    pub account_count: &'a str,
+    /// The number of deploys to send.
+    pub deploy_count: &'a str,
+    /// The number of deploys to send per second.
+    pub rate: &'a str,
+    /// The path to the secret key of the account funding the derived accounts.  If empty, the
+    /// derived accounts are expected to have been funded already, e.g. by a previous run.
+    pub faucet_secret_key: &'a str,
+    /// The amount of motes transferred from the faucet to each derived account.
+    pub funding_amount: &'a str,
+    /// The amount of motes sent by each native transfer between the derived accounts.
+    pub transfer_amount: &'a str,
+    /// The path to session Wasm to send instead of native transfers.  If empty, native transfers
+    /// between the derived accounts are sent.
+    pub session_path: &'a str,
+    /// The simple args passed to the session Wasm, formatted as `"NAME:TYPE='VALUE'"`.
+    pub session_args: Vec<&'a str>,
+    /// The amount of motes paid for each deploy via the standard payment contract.
+    pub payment_amount: &'a str,
+    /// The name of the chain.
+    pub chain_name: &'a str,
+    /// The gas price of each deploy.
+    pub gas_price: &'a str,
+    /// The time to live of each deploy, e.g. `"30min"`.
+    pub ttl: &'a str,
+    /// The maximum time to wait for the deploys of each phase to be executed after the last one
+    /// was sent, e.g. `"5min"`.
+    pub timeout: &'a str,
+}
+
+/// Percentiles of a set of measurements, using the nearest-rank method.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Percentiles {
+    /// The smallest measurement.
+    pub min: f64,
+    /// The median.
+    pub p50: f64,
+    /// The 90th percentile.
+    pub p90: f64,
+    /// The 99th percentile.
+    pub p99: f64,
+    /// The largest measurement.
+    pub max: f64,
+}
+
+impl Percentiles {
+    fn new(mut values: Vec<f64>) -> Option<Self> {
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(|lhs, rhs| lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal));
+        let nearest_rank = |percentile: usize| {
+            let rank = (percentile * values.len() + 99) / 100;
+            values[rank.max(1) - 1]
+        };
+        Some(Percentiles {
+            min: values[0],
+            p50: nearest_rank(50),
+            p90: nearest_rank(90),
+            p99: nearest_rank(99),
+            max: values[values.len() - 1],
+        })
+    }
+}
+
+/// The results of sending one batch of deploys.
+#[derive(Clone, Debug, Serialize)]
+pub struct PhaseReport {
+    /// The number of deploys sent.
+    pub sent: usize,
+    /// The number of deploys accepted by the node.
+    pub accepted: usize,
+    /// The number of deploys rejected by the node.
+    pub rejected: usize,
+    /// The errors returned for rejected deploys, along with how often each was returned.
+    pub rejection_reasons: BTreeMap<String, usize>,
+    /// The number of deploys seen to be executed.
+    pub executed: usize,
+    /// The number of executed deploys whose execution failed.
+    pub failed: usize,
+    /// The number of accepted deploys not seen to be executed before the timeout elapsed or the
+    /// event stream ended.
+    pub not_executed: usize,
+    /// The time from sending the first deploy until the last deploy was sent or executed, in
+    /// seconds.
+    pub duration_secs: f64,
+    /// The number of deploys sent per second.
+    pub send_rate: f64,
+    /// The number of deploys executed per second over the duration.
+    pub execution_rate: f64,
+    /// The time from sending each accepted deploy until the node's response, in milliseconds.
+    pub acceptance_latency_ms: Option<Percentiles>,
+    /// The time from sending each executed deploy until its execution was reported, in
+    /// milliseconds.
+    pub execution_latency_ms: Option<Percentiles>,
+    /// The number of deploys executed in each second from the first execution to the last.
+    pub executions_per_second: Option<Percentiles>,
+}
+
+/// The results of a load test.
+#[derive(Clone, Debug, Serialize)]
+pub struct LoadTestReport {
+    /// The hex-encoded public keys of the accounts the deploys were sent from.
+    pub accounts: Vec<String>,
+    /// The funding of the accounts by the faucet, if a faucet was given.
+    pub funding: Option<PhaseReport>,
+    /// The deploys sent from the accounts.
+    pub load: PhaseReport,
+}
+
+/// Derives the secret key of the account with the given index from the seed.
+fn derive_account(seed: &str, index: usize) -> SecretKey {
+    SecretKey::ed25519(hash::hash(format!("{}/{}", seed, index)).to_array())
+}
+
+/// The event stream, yielding the executions of deploys.
+struct EventStream {
+    response: Option<Response>,
+    buffer: Vec<u8>,
+}
+
+impl EventStream {
+    /// Returns the next execution, or `None` once the event stream has ended.
+    ///
+    /// Dropping the returned future before it completes doesn't lose any events.
+    async fn next_executed(&mut self) -> Result<Option<ExecutedDeploy>> {
+        loop {
+            if let Some(line) = wait::take_line(&mut self.buffer) {
+                if let Some(executed) = wait::parse_deploy_processed(&line)? {
+                    return Ok(Some(executed));
+                }
+                continue;
+            }
+            let response = match self.response.as_mut() {
+                Some(response) => response,
+                None => return Ok(None),
+            };
+            match response.chunk().await {
+                Ok(Some(bytes)) => self.buffer.extend_from_slice(&bytes),
+                Ok(None) | Err(_) => {
+                    self.response = None;
+                    return Ok(None);
+                }
+            }
+        }
+    }
+
+    fn has_ended(&self) -> bool {
+        self.response.is_none()
+    }
+}
+
+/// A deploy which has been sent.
+struct SentDeploy {
+    sent_at: Instant,
+    accepted_after: Option<Duration>,
+    rejected: bool,
+    executed_after: Option<Duration>,
+    failed: bool,
+}
+
+/// The deploys sent in one phase, along with what happened to them.
+struct Phase {
+    started_at: Instant,
+    last_event_at: Instant,
+    deploys: HashMap<DeployHash, SentDeploy>,
+    rejection_reasons: BTreeMap<String, usize>,
+    executed_at: Vec<Instant>,
+}
+
+impl Phase {
+    fn new() -> Self {
+        let now = Instant::now();
+        Phase {
+            started_at: now,
+            last_event_at: now,
+            deploys: HashMap::new(),
+            rejection_reasons: BTreeMap::new(),
+            executed_at: vec![],
+        }
+    }
+
+    fn record_sent(&mut self, deploy_hash: DeployHash) {
+        let now = Instant::now();
+        self.last_event_at = now;
+        let _ = self.deploys.insert(
+            deploy_hash,
+            SentDeploy {
+                sent_at: now,
+                accepted_after: None,
+                rejected: false,
+                executed_after: None,
+                failed: false,
+            },
+        );
+    }
+
+    fn record_response(&mut self, deploy_hash: DeployHash, response: Result<JsonRpc>) {
+        let deploy = match self.deploys.get_mut(&deploy_hash) {
+            Some(deploy) => deploy,
+            None => return,
+        };
+        match response {
+            Ok(_) => deploy.accepted_after = Some(deploy.sent_at.elapsed()),
+            Err(error) => {
+                deploy.rejected = true;
+                let reason = match error {
+                    Error::ResponseIsError(error) => error.message,
+                    error => error.to_string(),
+                };
+                *self.rejection_reasons.entry(reason).or_default() += 1;
+            }
+        }
+    }
+
+    fn record_executed(&mut self, executed: ExecutedDeploy) {
+        let deploy = match self.deploys.get_mut(&executed.deploy_hash) {
+            Some(deploy) if deploy.executed_after.is_none() => deploy,
+            _ => return,
+        };
+        let now = Instant::now();
+        deploy.executed_after = Some(now - deploy.sent_at);
+        deploy.failed = executed.error_message().is_some();
+        self.last_event_at = now;
+        self.executed_at.push(now);
+    }
+
+    fn all_executed(&self) -> bool {
+        self.deploys
+            .values()
+            .all(|deploy| deploy.rejected || deploy.executed_after.is_some())
+    }
+
+    fn report(&self) -> PhaseReport {
+        let count = |predicate: fn(&SentDeploy) -> bool| {
+            self.deploys
+                .values()
+                .filter(|deploy| predicate(deploy))
+                .count()
+        };
+        let millis = |durations: Vec<Duration>| {
+            Percentiles::new(
+                durations
+                    .into_iter()
+                    .map(|duration| duration.as_secs_f64() * 1_000.0)
+                    .collect(),
+            )
+        };
+        let duration_secs = (self.last_event_at - self.started_at).as_secs_f64();
+        let per_second = |count: usize| {
+            if duration_secs > 0.0 {
+                count as f64 / duration_secs
+            } else {
+                0.0
+            }
+        };
+
+        let executions_per_second = match (self.executed_at.first(), self.executed_at.last()) {
+            (Some(first), Some(last)) => {
+                let mut buckets = vec![0.0; (*last - *first).as_secs() as usize + 1];
+                for executed_at in &self.executed_at {
+                    buckets[(*executed_at - *first).as_secs() as usize] += 1.0;
+                }
+                Percentiles::new(buckets)
+            }
+            _ => None,
+        };
+
+        let executed = count(|deploy| deploy.executed_after.is_some());
+        PhaseReport {
+            sent: self.deploys.len(),
+            accepted: count(|deploy| !deploy.rejected),
+            rejected: count(|deploy| deploy.rejected),
+            rejection_reasons: self.rejection_reasons.clone(),
+            executed,
+            failed: count(|deploy| deploy.failed),
+            not_executed: count(|deploy| !deploy.rejected && deploy.executed_after.is_none()),
+            duration_secs,
+            send_rate: per_second(self.deploys.len()),
+            execution_rate: per_second(executed),
+            acceptance_latency_ms: millis(
+                self.deploys
+                    .values()
+                    .filter_map(|deploy| deploy.accepted_after)
+                    .collect(),
+            ),
+            execution_latency_ms: millis(
+                self.deploys
+                    .values()
+                    .filter_map(|deploy| deploy.executed_after)
+                    .collect(),
+            ),
+            executions_per_second,
+        }
+    }
+}
+
+/// The parsed parameters of a load test.
+struct LoadTest {
+    node_address: String,
+    verbosity_level: u64,
+    accounts: Vec<SecretKey>,
+    deploy_count: usize,
+    send_interval: Duration,
+    timeout: Duration,
+    chain_name: String,
+    gas_price: u64,
+    ttl: TimeDiff,
+    payment: ExecutableDeployItem,
+    /// The session Wasm, or `None` if native transfers are sent.
+    session: Option<ExecutableDeployItem>,
+    transfer_amount: U512,
+}
+
+impl LoadTest {
+    fn new_deploy(&self, secret_key: &SecretKey, session: ExecutableDeployItem) -> Deploy {
+        let mut rng = rand::thread_rng();
+        Deploy::new(
+            Timestamp::now(),
+            self.ttl,
+            self.gas_price,
+            vec![],
+            self.chain_name.clone(),
+            self.payment.clone(),
+            session,
+            secret_key,
+            &mut rng,
+        )
+    }
+
+    fn transfer(
+        &self,
+        secret_key: &SecretKey,
+        target: &SecretKey,
+        amount: U512,
+        id: usize,
+    ) -> Result<Deploy> {
+        let target = TransferTarget::Account(PublicKey::from(target));
+        let session = rpc::transfer_session(amount, None, target, Some(id as u64))?;
+        Ok(self.new_deploy(secret_key, session))
+    }
+
+    /// Sends `count` deploys created by `new_deploy` at the target rate, then waits for them to be
+    /// executed.
+    async fn run_phase<F>(
+        &self,
+        events: &mut EventStream,
+        count: usize,
+        mut new_deploy: F,
+    ) -> Result<PhaseReport>
+    where
+        F: FnMut(usize) -> Result<Deploy>,
+    {
+        let mut phase = Phase::new();
+        let mut responses = FuturesUnordered::new();
+        let mut sent = 0;
+        let mut next_send = Instant::now();
+        let mut deadline = None;
+
+        loop {
+            let wake_up = if sent < count {
+                next_send
+            } else {
+                let deadline = *deadline.get_or_insert_with(|| Instant::now() + self.timeout);
+                let done = phase.all_executed() || events.has_ended();
+                if (done && responses.is_empty()) || Instant::now() >= deadline {
+                    break;
+                }
+                deadline
+            };
+
+            tokio::select! {
+                _ = time::delay_until(wake_up) => {
+                    if sent < count {
+                        let deploy = new_deploy(sent)?;
+                        let deploy_hash = *deploy.id();
+                        phase.record_sent(deploy_hash);
+                        let rpc_call = RpcCall::new("", &self.node_address, 0);
+                        responses.push(async move {
+                            (deploy_hash, rpc_call.put_deploy(deploy).await)
+                        });
+                        sent += 1;
+                        next_send += self.send_interval;
+                    }
+                }
+                Some((deploy_hash, response)) = responses.next(), if !responses.is_empty() => {
+                    phase.record_response(deploy_hash, response);
+                }
+                executed = events.next_executed(), if !events.has_ended() => {
+                    if let Some(executed) = executed? {
+                        phase.record_executed(executed);
+                    }
+                }
+            }
+        }
+
+        if events.has_ended() && self.verbosity_level > 0 {
+            println!("Event stream ended, so further executions could not be observed");
+        }
+        Ok(phase.report())
+    }
+
+    async fn run(
+        self,
+        maybe_event_stream_address: &str,
+        faucet: Option<SecretKey>,
+        funding_amount: U512,
+    ) -> Result<LoadTestReport> {
+        let url = wait::event_stream_url(maybe_event_stream_address, &self.node_address)?;
+        let response = wait::subscribe(&url, self.verbosity_level, wait::SUBSCRIBE_TIMEOUT)
+            .await
+            .ok_or_else(|| Error::EventStreamUnavailable(url.to_string()))?;
+        let mut events = EventStream {
+            response: Some(response),
+            buffer: vec![],
+        };
+
+        let funding = match faucet {
+            Some(faucet) => {
+                if self.verbosity_level > 0 {
+                    println!("Funding {} accounts", self.accounts.len());
+                }
+                let accounts = &self.accounts;
+                let report = self
+                    .run_phase(&mut events, accounts.len(), |index| {
+                        self.transfer(&faucet, &accounts[index], funding_amount, index)
+                    })
+                    .await?;
+                Some(report)
+            }
+            None => None,
+        };
+
+        if self.verbosity_level > 0 {
+            println!(
+                "Sending {} deploys from {} accounts",
+                self.deploy_count,
+                self.accounts.len()
+            );
+        }
+        let accounts = &self.accounts;
+        let load = self
+            .run_phase(&mut events, self.deploy_count, |index| {
+                let sender = &accounts[index % accounts.len()];
+                match &self.session {
+                    Some(session) => Ok(self.new_deploy(sender, session.clone())),
+                    None => {
+                        let target = &accounts[(index + 1) % accounts.len()];
+                        self.transfer(sender, target, self.transfer_amount, index)
+                    }
+                }
+            })
+            .await?;
+
+        Ok(LoadTestReport {
+            accounts: self
+                .accounts
+                .iter()
+                .map(|secret_key| PublicKey::from(secret_key).to_hex())
+                .collect(),
+            funding,
+            load,
+        })
+    }
+}
+
+fn parse_count(name: &'static str, value: &str) -> Result<usize> {
+    value
+        .parse::<usize>()
+        .map_err(|error| Error::FailedToParseInt(name, error))
+}
+
+fn parse_amount(name: &'static str, value: &str) -> Result<U512> {
+    U512::from_dec_str(value)
+        .map_err(|error| Error::FailedToParseUint(name, UIntParseError::FromDecStr(error)))
+}
+
+/// Runs a load test, see [`load_test()`](../fn.load_test.html).
+pub(crate) async fn load_test(
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_event_stream_address: &str,
+    params: LoadTestStrParams<'_>,
+) -> Result<LoadTestReport> {
+    let account_count = parse_count("account_count", params.account_count)?;
+    let deploy_count = parse_count("deploy_count", params.deploy_count)?;
+    let rate: f64 = params
+        .rate
+        .parse()
+        .ok()
+        .filter(|rate: &f64| rate.is_finite() && *rate > 0.0)
+        .ok_or_else(|| {
+            Error::InvalidArgument(
+                "rate",
+                format!("must be a positive number: {}", params.rate),
+            )
+        })?;
+    let session = if params.session_path.is_empty() {
+        if account_count < 2 {
+            return Err(Error::InvalidArgument(
+                "account_count",
+                "native transfers require at least two accounts".to_string(),
+            ));
+        }
+        None
+    } else if account_count == 0 {
+        return Err(Error::InvalidArgument(
+            "account_count",
+            "must be at least one".to_string(),
+        ));
+    } else {
+        Some(parsing::parse_session_info(
+            "",
+            "",
+            "",
+            "",
+            params.session_path,
+            &params.session_args,
+            "",
+            "",
+            "",
+        )?)
+    };
+    let faucet = if params.faucet_secret_key.is_empty() {
+        None
+    } else {
+        Some(parsing::secret_key(params.faucet_secret_key)?)
+    };
+    let timeout = humantime::parse_duration(params.timeout)
+        .map_err(|error| Error::FailedToParseTimeDiff("timeout", error))?;
+
+    let load_test = LoadTest {
+        node_address: node_address.trim_end_matches('/').to_string(),
+        verbosity_level,
+        accounts: (0..account_count)
+            .map(|index| derive_account(params.seed, index))
+            .collect(),
+        deploy_count,
+        send_interval: Duration::from_secs_f64(1.0 / rate),
+        timeout,
+        chain_name: params.chain_name.to_string(),
+        gas_price: parsing::gas_price(params.gas_price)?,
+        ttl: parsing::ttl(params.ttl)?,
+        payment: parsing::parse_payment_info(
+            params.payment_amount,
+            "",
+            "",
+            "",
+            "",
+            "",
+            &[],
+            "",
+            "",
+            "",
+        )?,
+        session,
+        transfer_amount: parse_amount("transfer_amount", params.transfer_amount)?,
+    };
+    let funding_amount = if faucet.is_some() {
+        parse_amount("funding_amount", params.funding_amount)?
+    } else {
+        U512::zero()
+    };
+    load_test
+        .run(maybe_event_stream_address, faucet, funding_amount)
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_node_types::BlockHash;
+    use casper_types::{ExecutionEffect, ExecutionResult};
+
+    use super::*;
+
+    #[test]
+    fn should_derive_accounts_deterministically() {
+        let first = PublicKey::from(&derive_account("seed", 0));
+        assert_eq!(first, PublicKey::from(&derive_account("seed", 0)));
+        assert_ne!(first, PublicKey::from(&derive_account("seed", 1)));
+        assert_ne!(first, PublicKey::from(&derive_account("other seed", 0)));
+    }
+
+    #[test]
+    fn should_calculate_nearest_rank_percentiles() {
+        assert_eq!(Percentiles::new(vec![]), None);
+
+        let values = (1..=100).rev().map(f64::from).collect();
+        let percentiles = Percentiles::new(values).unwrap();
+        assert_eq!(percentiles.min, 1.0);
+        assert_eq!(percentiles.p50, 50.0);
+        assert_eq!(percentiles.p90, 90.0);
+        assert_eq!(percentiles.p99, 99.0);
+        assert_eq!(percentiles.max, 100.0);
+
+        let percentiles = Percentiles::new(vec![7.0]).unwrap();
+        assert_eq!(percentiles.p50, 7.0);
+        assert_eq!(percentiles.p99, 7.0);
+    }
+
+    #[test]
+    fn should_report_phase_outcomes() {
+        let mut phase = Phase::new();
+        let hashes: Vec<DeployHash> = (0..3_u8)
+            .map(|index| DeployHash::new([index; 32].into()))
+            .collect();
+        for hash in &hashes {
+            phase.record_sent(*hash);
+        }
+        phase.record_response(
+            hashes[0],
+            Ok(JsonRpc::success(1_i64, &serde_json::Value::Null)),
+        );
+        phase.record_response(
+            hashes[1],
+            Ok(JsonRpc::success(2_i64, &serde_json::Value::Null)),
+        );
+        phase.record_response(
+            hashes[2],
+            Err(Error::InvalidArgument("deploy", "rejected".to_string())),
+        );
+        assert!(!phase.all_executed());
+
+        let executed = ExecutedDeploy {
+            deploy_hash: hashes[0],
+            block_hash: BlockHash::new([0; 32].into()),
+            execution_result: ExecutionResult::Success {
+                effect: ExecutionEffect::default(),
+                transfers: vec![],
+                cost: U512::from(123),
+                payment: Default::default(),
+            },
+        };
+        phase.record_executed(executed);
+
+        let report = phase.report();
+        assert_eq!(report.sent, 3);
+        assert_eq!(report.accepted, 2);
+        assert_eq!(report.rejected, 1);
+        assert_eq!(report.rejection_reasons.values().sum::<usize>(), 1);
+        assert_eq!(report.executed, 1);
+        assert_eq!(report.failed, 0);
+        assert_eq!(report.not_executed, 1);
+        assert_eq!(report.executions_per_second.unwrap().max, 1.0);
+    }
+}
@@ -0,0 +1,349 @@
+//! BIP-39 mnemonic phrases and deterministic derivation of secret keys from them.
+//!
+//! The seed of a mnemonic is derived as specified by
+//! [BIP-39](https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki).  From the seed,
+//! secp256k1 keys are derived as specified by
+//! [BIP-32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki), and Ed25519 keys as
+//! specified by [SLIP-0010](https://github.com/satoshilabs/slips/blob/master/slip-0010.md), which
+//! only supports hardened derivation.
+
+use bip39::{Language, Mnemonic, MnemonicType, Seed};
+use hmac::{Hmac, Mac, NewMac};
+use sha2::Sha512;
+
+use casper_types::{AsymmetricType, PublicKey, SecretKey};
+
+use crate::{
+    error::{Error, Result},
+    keygen::{ED25519, SECP256K1},
+};
+
+/// The default derivation path for secp256k1 keys, using Casper's registered coin type 506.
+pub const DEFAULT_SECP256K1_PATH: &str = "m/44'/506'/0'/0/0";
+/// The default derivation path for Ed25519 keys, which can only be derived hardened.
+pub const DEFAULT_ED25519_PATH: &str = "m/44'/506'/0'/0'/0'";
+
+/// The HMAC key used to derive the BIP-32 master key.
+const SECP256K1_SEED_KEY: &[u8] = b"Bitcoin seed";
+/// The HMAC key used to derive the SLIP-0010 Ed25519 master key.
+const ED25519_SEED_KEY: &[u8] = b"ed25519 seed";
+/// The offset of hardened child indices.
+const HARDENED_OFFSET: u32 = 1 << 31;
+/// The order of the secp256k1 group, big-endian.
+const SECP256K1_ORDER: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xfe,
+    0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36, 0x41, 0x41,
+];
+
+/// A secret key together with its chain code.
+struct ExtendedKey {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+}
+
+impl ExtendedKey {
+    fn from_hmac(key: &[u8], data: &[&[u8]]) -> Self {
+        let mut mac = Hmac::<Sha512>::new_varkey(key).expect("HMAC accepts keys of any length");
+        for part in data {
+            mac.update(part);
+        }
+        let output = mac.finalize().into_bytes();
+        let mut extended_key = ExtendedKey {
+            key: [0; 32],
+            chain_code: [0; 32],
+        };
+        extended_key.key.copy_from_slice(&output[..32]);
+        extended_key.chain_code.copy_from_slice(&output[32..]);
+        extended_key
+    }
+}
+
+/// Generates a new random mnemonic phrase with the given number of words, which must be 12, 15,
+/// 18, 21 or 24.
+pub fn generate_mnemonic(word_count: usize) -> Result<String> {
+    let mnemonic_type = MnemonicType::for_word_count(word_count).map_err(|error| {
+        Error::InvalidArgument("word_count", format!("{}: {}", word_count, error))
+    })?;
+    Ok(Mnemonic::new(mnemonic_type, Language::English).into_phrase())
+}
+
+/// Derives a secret key from a mnemonic phrase.
+///
+/// * `phrase` is a BIP-39 mnemonic phrase using the English word list.
+/// * `passphrase` is the optional BIP-39 passphrase, which may be empty.
+/// * `algorithm` is either `"Ed25519"` or `"secp256k1"`.
+/// * `derivation_path` is a path like `"m/44'/506'/0'/0/0"`, where a trailing `'` or `h` marks a
+///   hardened index. If empty, [`DEFAULT_ED25519_PATH`](constant.DEFAULT_ED25519_PATH.html) or
+///   [`DEFAULT_SECP256K1_PATH`](constant.DEFAULT_SECP256K1_PATH.html) is used.
+pub fn derive_secret_key(
+    phrase: &str,
+    passphrase: &str,
+    algorithm: &str,
+    derivation_path: &str,
+) -> Result<SecretKey> {
+    let mnemonic = Mnemonic::from_phrase(phrase.trim(), Language::English)
+        .map_err(|error| Error::InvalidMnemonic(error.to_string()))?;
+    let seed = Seed::new(&mnemonic, passphrase);
+
+    if algorithm.eq_ignore_ascii_case(ED25519) {
+        let path = parse_path(or_default(derivation_path, DEFAULT_ED25519_PATH))?;
+        let key = derive_ed25519(seed.as_bytes(), &path)?;
+        SecretKey::ed25519_from_bytes(key.key)
+    } else if algorithm.eq_ignore_ascii_case(SECP256K1) {
+        let path = parse_path(or_default(derivation_path, DEFAULT_SECP256K1_PATH))?;
+        let key = derive_secp256k1(seed.as_bytes(), &path)?;
+        SecretKey::secp256k1_from_bytes(key.key)
+    } else {
+        return Err(Error::UnsupportedAlgorithm(algorithm.to_string()));
+    }
+    .map_err(|error| Error::InvalidMnemonic(error.to_string()))
+}
+
+fn or_default<'a>(derivation_path: &'a str, default: &'a str) -> &'a str {
+    if derivation_path.is_empty() {
+        default
+    } else {
+        derivation_path
+    }
+}
+
+/// Parses a derivation path into its child indices, with hardened indices offset by 2^31.
+fn parse_path(path: &str) -> Result<Vec<u32>> {
+    let invalid = |reason: &str| Error::InvalidDerivationPath(path.to_string(), reason.to_string());
+    let mut segments = path.trim().split('/');
+    if segments.next() != Some("m") {
+        return Err(invalid("must start with 'm'"));
+    }
+    segments
+        .map(|segment| {
+            let (index, hardened) = match segment
+                .strip_suffix('\'')
+                .or_else(|| segment.strip_suffix('h'))
+            {
+                Some(index) => (index, true),
+                None => (segment, false),
+            };
+            let index: u32 = index
+                .parse()
+                .map_err(|_| invalid(&format!("invalid index '{}'", segment)))?;
+            if index >= HARDENED_OFFSET {
+                return Err(invalid(&format!("index '{}' is too large", segment)));
+            }
+            Ok(if hardened {
+                index + HARDENED_OFFSET
+            } else {
+                index
+            })
+        })
+        .collect()
+}
+
+/// Derives an Ed25519 key as specified by SLIP-0010.
+fn derive_ed25519(seed: &[u8], path: &[u32]) -> Result<ExtendedKey> {
+    let mut extended_key = ExtendedKey::from_hmac(ED25519_SEED_KEY, &[seed]);
+    for index in path {
+        if *index < HARDENED_OFFSET {
+            return Err(Error::InvalidDerivationPath(
+                index.to_string(),
+                "Ed25519 keys only support hardened indices".to_string(),
+            ));
+        }
+        extended_key = ExtendedKey::from_hmac(
+            &extended_key.chain_code,
+            &[&[0], &extended_key.key, &index.to_be_bytes()],
+        );
+    }
+    Ok(extended_key)
+}
+
+/// Derives a secp256k1 key as specified by BIP-32.
+fn derive_secp256k1(seed: &[u8], path: &[u32]) -> Result<ExtendedKey> {
+    let invalid_key = || {
+        Error::InvalidMnemonic(
+            "derived an invalid secp256k1 key, use a different derivation path".to_string(),
+        )
+    };
+    let mut extended_key = ExtendedKey::from_hmac(SECP256K1_SEED_KEY, &[seed]);
+    if extended_key.key == [0; 32] || extended_key.key >= SECP256K1_ORDER {
+        return Err(invalid_key());
+    }
+    for index in path {
+        let child = if *index >= HARDENED_OFFSET {
+            ExtendedKey::from_hmac(
+                &extended_key.chain_code,
+                &[&[0], &extended_key.key, &index.to_be_bytes()],
+            )
+        } else {
+            let secret_key = SecretKey::secp256k1_from_bytes(extended_key.key)
+                .map_err(|error| Error::InvalidMnemonic(error.to_string()))?;
+            let public_key = PublicKey::from(&secret_key);
+            ExtendedKey::from_hmac(
+                &extended_key.chain_code,
+                &[public_key.as_ref(), &index.to_be_bytes()],
+            )
+        };
+        if child.key >= SECP256K1_ORDER {
+            return Err(invalid_key());
+        }
+        let key = add_mod_order(&child.key, &extended_key.key);
+        if key == [0; 32] {
+            return Err(invalid_key());
+        }
+        extended_key = ExtendedKey {
+            key,
+            chain_code: child.chain_code,
+        };
+    }
+    Ok(extended_key)
+}
+
+/// Adds two big-endian numbers less than the secp256k1 group order, modulo the order.
+fn add_mod_order(lhs: &[u8; 32], rhs: &[u8; 32]) -> [u8; 32] {
+    let mut sum = [0u8; 32];
+    let mut carry = 0u16;
+    for index in (0..32).rev() {
+        let digit = u16::from(lhs[index]) + u16::from(rhs[index]) + carry;
+        sum[index] = digit as u8;
+        carry = digit >> 8;
+    }
+    if carry == 0 && sum < SECP256K1_ORDER {
+        return sum;
+    }
+    // The sum is less than twice the order, so subtracting it once suffices.  Any borrow out of
+    // the most significant byte cancels the carry.
+    let mut borrow = 0i16;
+    for index in (0..32).rev() {
+        let digit = i16::from(sum[index]) - i16::from(SECP256K1_ORDER[index]) - borrow;
+        sum[index] = digit as u8;
+        borrow = if digit < 0 { 1 } else { 0 };
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The seed of test vector 1 of both BIP-32 and SLIP-0010.
+    const SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+    fn derive(derive_fn: fn(&[u8], &[u32]) -> Result<ExtendedKey>, path: &str) -> (String, String) {
+        let seed = hex::decode(SEED).unwrap();
+        let extended_key = derive_fn(&seed, &parse_path(path).unwrap()).unwrap();
+        (
+            hex::encode(extended_key.key),
+            hex::encode(extended_key.chain_code),
+        )
+    }
+
+    #[test]
+    fn should_match_bip39_test_vector() {
+        // From https://github.com/trezor/python-mnemonic/blob/master/vectors.json
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+            abandon abandon about";
+        let mnemonic = Mnemonic::from_phrase(phrase, Language::English).unwrap();
+        assert_eq!(
+            hex::encode(Seed::new(&mnemonic, "TREZOR").as_bytes()),
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1\
+            c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+        );
+    }
+
+    #[test]
+    fn should_match_bip32_test_vector() {
+        assert_eq!(
+            derive(derive_secp256k1, "m"),
+            (
+                "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35".to_string(),
+                "873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508".to_string()
+            )
+        );
+        assert_eq!(
+            derive(derive_secp256k1, "m/0'"),
+            (
+                "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea".to_string(),
+                "47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141".to_string()
+            )
+        );
+        assert_eq!(
+            derive(derive_secp256k1, "m/0'/1"),
+            (
+                "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368".to_string(),
+                "2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn should_match_slip10_ed25519_test_vector() {
+        assert_eq!(
+            derive(derive_ed25519, "m"),
+            (
+                "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7".to_string(),
+                "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb".to_string()
+            )
+        );
+        assert_eq!(
+            derive(derive_ed25519, "m/0h"),
+            (
+                "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3".to_string(),
+                "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69".to_string()
+            )
+        );
+        assert_eq!(
+            derive(derive_ed25519, "m/0h/1h"),
+            (
+                "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2".to_string(),
+                "a320425f77d1b5c2505a6b1b27382b37368ee640e3557c315416801243552f14".to_string()
+            )
+        );
+
+        let seed = hex::decode(SEED).unwrap();
+        assert!(derive_ed25519(&seed, &parse_path("m/0").unwrap()).is_err());
+    }
+
+    #[test]
+    fn should_derive_deterministic_keys() {
+        let phrase = generate_mnemonic(24).unwrap();
+        assert_eq!(phrase.split_whitespace().count(), 24);
+
+        for algorithm in &[ED25519, SECP256K1] {
+            let first = derive_secret_key(&phrase, "", algorithm, "").unwrap();
+            let second = derive_secret_key(&phrase, "", algorithm, "").unwrap();
+            assert_eq!(first.as_slice(), second.as_slice());
+
+            let with_passphrase = derive_secret_key(&phrase, "secret", algorithm, "").unwrap();
+            assert_ne!(first.as_slice(), with_passphrase.as_slice());
+        }
+
+        assert!(derive_secret_key("abandon abandon", "", ED25519, "").is_err());
+        assert!(generate_mnemonic(13).is_err());
+    }
+
+    #[test]
+    fn should_reject_invalid_paths() {
+        assert!(parse_path("44'/0").is_err());
+        assert!(parse_path("m/x").is_err());
+        assert!(parse_path("m/2147483648").is_err());
+        assert_eq!(
+            parse_path("m/44'/506h/0").unwrap(),
+            vec![44 + HARDENED_OFFSET, 506 + HARDENED_OFFSET, 0]
+        );
+    }
+
+    #[test]
+    fn should_add_modulo_order() {
+        let mut order_minus_one = SECP256K1_ORDER;
+        order_minus_one[31] -= 1;
+        let mut two = [0u8; 32];
+        two[31] = 2;
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert_eq!(add_mod_order(&order_minus_one, &two), one);
+        assert_eq!(add_mod_order(&order_minus_one, &order_minus_one), {
+            let mut expected = SECP256K1_ORDER;
+            expected[31] -= 2;
+            expected
+        });
+    }
+}
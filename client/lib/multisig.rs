@@ -0,0 +1,370 @@
+//! Inspection of the approvals of a deploy, and checking them against the associated keys and
+//! action thresholds of the deploy's account.
+
+#[cfg(any(feature = "network", test))]
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use casper_node_types::{crypto, Deploy, DeployHash};
+#[cfg(feature = "network")]
+use casper_types::Key;
+use casper_types::{account::AccountHash, AsymmetricType, PublicKey};
+
+use crate::error::{Error, Result};
+#[cfg(feature = "network")]
+use crate::rpc::RpcCall;
+
+/// An associated key of an account, as returned by `state_get_item`.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct AssociatedKey {
+    /// The account hash of the key.
+    pub account_hash: AccountHash,
+    /// The weight of the key.
+    pub weight: u8,
+}
+
+/// The action thresholds of an account, as returned by `state_get_item`.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub struct ActionThresholds {
+    /// The total weight of approvals required to execute a deploy.
+    pub deployment: u8,
+    /// The total weight of approvals required to manage the account's keys.
+    pub key_management: u8,
+}
+
+/// The subset of an account relevant for authorizing deploys.
+#[cfg(any(feature = "network", test))]
+#[derive(Deserialize, Debug)]
+struct AccountKeys {
+    associated_keys: Vec<AssociatedKey>,
+    action_thresholds: ActionThresholds,
+}
+
+/// A single approval of a deploy.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct ApprovalSummary {
+    /// The public key of the signer.
+    pub signer: PublicKey,
+    /// The account hash of the signer.
+    pub account_hash: AccountHash,
+    /// Whether the signature is a valid signature of the deploy hash.
+    pub valid_signature: bool,
+    /// The weight of the signer's key in the account, `None` if not checked against the account
+    /// or if the key is not associated with it.
+    pub weight: Option<u8>,
+}
+
+/// The approvals of a deploy checked against the deploy's account.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct AccountApprovalStatus {
+    /// The associated keys of the account which haven't approved the deploy yet.
+    pub missing_signers: Vec<AssociatedKey>,
+    /// The signers which are not associated keys of the account.  A deploy with such approvals
+    /// is rejected.
+    pub unauthorized_signers: Vec<PublicKey>,
+    /// The total weight of the valid approvals by associated keys.
+    pub total_weight: u32,
+    /// The action thresholds of the account.
+    pub action_thresholds: ActionThresholds,
+    /// Whether the deploy can be executed, i.e. the total weight meets the deployment threshold
+    /// and all signers are associated keys.
+    pub meets_deployment_threshold: bool,
+}
+
+/// The approvals of a deploy.
+#[derive(Clone, PartialEq, Eq, Serialize, Debug)]
+pub struct DeployApprovals {
+    /// The hash of the deploy.
+    pub deploy_hash: DeployHash,
+    /// The account in whose context the deploy is executed.
+    pub account: PublicKey,
+    /// The approvals of the deploy.
+    pub approvals: Vec<ApprovalSummary>,
+    /// The approvals checked against the account, `None` if not checked.
+    pub account_status: Option<AccountApprovalStatus>,
+}
+
+impl DeployApprovals {
+    /// Summarizes the approvals of `deploy`, without checking them against the account.
+    pub(crate) fn new(deploy: &Deploy) -> Self {
+        let approvals = deploy
+            .approvals()
+            .iter()
+            .map(|approval| ApprovalSummary {
+                signer: approval.signer().clone(),
+                account_hash: approval.signer().to_account_hash(),
+                valid_signature: crypto::verify(
+                    deploy.id(),
+                    approval.signature(),
+                    approval.signer(),
+                )
+                .is_ok(),
+                weight: None,
+            })
+            .collect();
+        DeployApprovals {
+            deploy_hash: *deploy.id(),
+            account: deploy.header().account().clone(),
+            approvals,
+            account_status: None,
+        }
+    }
+
+    /// Checks the approvals against the associated keys and action thresholds of the account.
+    ///
+    /// Each associated key's weight is counted at most once, however many valid approvals it
+    /// signed.
+    #[cfg(any(feature = "network", test))]
+    fn check_against(&mut self, account: AccountKeys) {
+        let mut total_weight = 0u32;
+        let mut counted = BTreeSet::new();
+        let mut unauthorized_signers = vec![];
+        for approval in &mut self.approvals {
+            approval.weight = account
+                .associated_keys
+                .iter()
+                .find(|key| key.account_hash == approval.account_hash)
+                .map(|key| key.weight);
+            match approval.weight {
+                Some(weight) if approval.valid_signature => {
+                    if counted.insert(approval.account_hash) {
+                        total_weight += u32::from(weight);
+                    }
+                }
+                Some(_) => (),
+                None => unauthorized_signers.push(approval.signer.clone()),
+            }
+        }
+        let approvals = &self.approvals;
+        let missing_signers = account
+            .associated_keys
+            .into_iter()
+            .filter(|key| {
+                !approvals.iter().any(|approval| {
+                    approval.valid_signature && approval.account_hash == key.account_hash
+                })
+            })
+            .collect();
+        let meets_deployment_threshold = unauthorized_signers.is_empty()
+            && total_weight >= u32::from(account.action_thresholds.deployment);
+        self.account_status = Some(AccountApprovalStatus {
+            missing_signers,
+            unauthorized_signers,
+            total_weight,
+            action_thresholds: account.action_thresholds,
+            meets_deployment_threshold,
+        });
+    }
+
+    /// Returns an error if the approvals were checked against the account and are insufficient
+    /// for the deploy to be executed.
+    pub fn ensure_executable(&self) -> Result<()> {
+        let status = match &self.account_status {
+            Some(status) => status,
+            None => return Ok(()),
+        };
+        if !status.unauthorized_signers.is_empty() {
+            return Err(Error::UnauthorizedApprovals(
+                status
+                    .unauthorized_signers
+                    .iter()
+                    .map(PublicKey::to_hex)
+                    .collect(),
+            ));
+        }
+        if !status.meets_deployment_threshold {
+            return Err(Error::InsufficientApprovalWeight {
+                total_weight: status.total_weight,
+                threshold: status.action_thresholds.deployment,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Summarizes the approvals of `deploy` and checks them against its account, as stored under the
+/// latest state root hash of the node.
+#[cfg(feature = "network")]
+pub(crate) async fn check_approvals(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    deploy: &Deploy,
+) -> Result<DeployApprovals> {
+    let response = RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .get_state_root_hash("")
+        .await?;
+    let state_root_hash = response
+        .get_result()
+        .and_then(|result| result.get("state_root_hash"))
+        .and_then(|state_root_hash| state_root_hash.as_str())
+        .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+
+    let key = Key::Account(deploy.header().account().to_account_hash());
+    let response = RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .get_item(state_root_hash, &key.to_formatted_string(), "")
+        .await?;
+    let account = response
+        .get_result()
+        .and_then(|result| result.get("stored_value"))
+        .and_then(|stored_value| stored_value.get("Account"))
+        .cloned()
+        .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+    let account: AccountKeys = serde_json::from_value(account)?;
+
+    let mut approvals = DeployApprovals::new(deploy);
+    approvals.check_against(account);
+    Ok(approvals)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use casper_node_types::{crypto::AsymmetricKeyExt, ExecutableDeployItem, TimeDiff, Timestamp};
+    use casper_types::{RuntimeArgs, SecretKey};
+
+    use super::*;
+    use crate::deploy::DeployExt;
+
+    /// Returns a deploy of the account of `owner`, signed by `owner` and all of `signers`.
+    fn signed_deploy(owner: &SecretKey, signers: &[&SecretKey]) -> Deploy {
+        let item = || ExecutableDeployItem::ModuleBytes {
+            module_bytes: vec![].into(),
+            args: RuntimeArgs::new(),
+        };
+        let mut rng = rand::thread_rng();
+        let mut deploy = Deploy::new(
+            Timestamp::now(),
+            TimeDiff::from(Duration::from_secs(60)),
+            1,
+            vec![],
+            "casper-test-chain".to_string(),
+            item(),
+            item(),
+            owner,
+            &mut rng,
+        );
+        for signer in signers {
+            deploy.sign(signer, &mut rng);
+        }
+        deploy
+    }
+
+    fn associated_key(secret_key: &SecretKey, weight: u8) -> AssociatedKey {
+        AssociatedKey {
+            account_hash: PublicKey::from(secret_key).to_account_hash(),
+            weight,
+        }
+    }
+
+    #[test]
+    fn should_sum_weights_of_associated_keys() {
+        let owner = SecretKey::generate_ed25519().unwrap();
+        let alice = SecretKey::generate_ed25519().unwrap();
+        let bob = SecretKey::generate_secp256k1().unwrap();
+        let carol = SecretKey::generate_ed25519().unwrap();
+        let deploy = signed_deploy(&owner, &[&alice, &bob]);
+
+        let account = |owner_weight| AccountKeys {
+            associated_keys: vec![
+                associated_key(&owner, owner_weight),
+                associated_key(&alice, 1),
+                associated_key(&carol, 2),
+            ],
+            action_thresholds: ActionThresholds {
+                deployment: 3,
+                key_management: 5,
+            },
+        };
+
+        // Bob is not an associated key, and Carol's approval is missing.
+        let mut approvals = DeployApprovals::new(&deploy);
+        approvals.check_against(account(1));
+        let status = approvals.account_status.clone().unwrap();
+        assert_eq!(status.unauthorized_signers, vec![PublicKey::from(&bob)]);
+        assert_eq!(status.total_weight, 2);
+        assert_eq!(status.missing_signers, vec![associated_key(&carol, 2)]);
+        assert!(!status.meets_deployment_threshold);
+        assert!(matches!(
+            approvals.ensure_executable(),
+            Err(Error::UnauthorizedApprovals(_))
+        ));
+
+        let deploy = signed_deploy(&owner, &[&alice]);
+        let mut approvals = DeployApprovals::new(&deploy);
+        approvals.check_against(account(1));
+        assert!(matches!(
+            approvals.ensure_executable(),
+            Err(Error::InsufficientApprovalWeight {
+                total_weight: 2,
+                threshold: 3
+            })
+        ));
+
+        let mut approvals = DeployApprovals::new(&deploy);
+        approvals.check_against(account(2));
+        assert!(approvals.ensure_executable().is_ok());
+    }
+
+    #[test]
+    fn should_count_each_signer_once() {
+        let owner = SecretKey::generate_ed25519().unwrap();
+        let alice = SecretKey::generate_ed25519().unwrap();
+        let deploy = signed_deploy(&owner, &[&alice, &alice]);
+        assert_eq!(deploy.approvals().len(), 2);
+
+        let account = AccountKeys {
+            associated_keys: vec![associated_key(&owner, 1), associated_key(&alice, 2)],
+            action_thresholds: ActionThresholds {
+                deployment: 4,
+                key_management: 4,
+            },
+        };
+
+        // A deploy received from elsewhere may still list the same approval twice.
+        let mut json = serde_json::to_value(&deploy).unwrap();
+        let approvals = json["approvals"].as_array_mut().unwrap();
+        let duplicate = approvals[1].clone();
+        approvals.push(duplicate);
+        let deploy: Deploy = serde_json::from_value(json).unwrap();
+        assert_eq!(deploy.approvals().len(), 3);
+
+        let mut approvals = DeployApprovals::new(&deploy);
+        approvals.check_against(account);
+        let status = approvals.account_status.clone().unwrap();
+        assert_eq!(status.total_weight, 3);
+        assert!(!status.meets_deployment_threshold);
+        assert!(matches!(
+            approvals.ensure_executable(),
+            Err(Error::InsufficientApprovalWeight {
+                total_weight: 3,
+                threshold: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn should_merge_approvals() {
+        let owner = SecretKey::generate_ed25519().unwrap();
+        let alice = SecretKey::generate_ed25519().unwrap();
+        let bob = SecretKey::generate_secp256k1().unwrap();
+        let unsigned_by_others = signed_deploy(&owner, &[]);
+
+        let mut by_alice = unsigned_by_others.clone();
+        by_alice.sign(&alice, &mut rand::thread_rng());
+        let mut by_both = unsigned_by_others.clone();
+        by_both.sign(&alice, &mut rand::thread_rng());
+        by_both.sign(&bob, &mut rand::thread_rng());
+
+        let mut merged = unsigned_by_others;
+        assert_eq!(merged.merge_approvals(&by_alice).unwrap(), 1);
+        assert_eq!(merged.merge_approvals(&by_both).unwrap(), 1);
+        assert_eq!(merged.approvals().len(), 3);
+        assert!(merged.is_valid().is_ok());
+
+        let other = signed_deploy(&owner, &[]);
+        assert!(merged.merge_approvals(&other).is_err());
+    }
+}
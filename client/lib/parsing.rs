@@ -1,25 +1,27 @@
 //! This module contains structs and helpers which are used by multiple subcommands related to
 //! creating deploys.
 
-use std::{convert::TryInto, fs, io, path::PathBuf, str::FromStr};
+use std::{convert::TryInto, fs, io, str::FromStr};
 
 use serde::{self, Deserialize};
 
-use casper_execution_engine::core::engine_state::executable_deploy_item::ExecutableDeployItem;
-use casper_node::{
-    crypto::{hash::Digest, AsymmetricKeyExt},
-    types::{DeployHash, TimeDiff, Timestamp},
+use casper_node_types::{
+    crypto::hash::Digest, DeployHash, ExecutableDeployItem, TimeDiff, Timestamp,
 };
 use casper_types::{
-    bytesrepr, AsymmetricType, CLType, CLValue, HashAddr, Key, NamedArg, PublicKey, RuntimeArgs,
-    SecretKey, UIntParseError, U512,
+    bytesrepr, CLType, CLValue, HashAddr, Key, NamedArg, RuntimeArgs, SecretKey, UIntParseError,
+    U512,
 };
+#[cfg(feature = "network")]
+use casper_types::{AsymmetricType, PublicKey};
 
+#[cfg(feature = "network")]
+use crate::TransferTarget;
 use crate::{
     cl_type,
     deploy::DeployParams,
     error::{Error, Result},
-    help, TransferTarget,
+    help, keystore,
 };
 
 pub(super) fn none_if_empty(value: &'_ str) -> Option<&'_ str> {
@@ -36,11 +38,11 @@ fn timestamp(value: &str) -> Result<Timestamp> {
     Timestamp::from_str(value).map_err(|error| Error::FailedToParseTimestamp("timestamp", error))
 }
 
-fn ttl(value: &str) -> Result<TimeDiff> {
+pub(super) fn ttl(value: &str) -> Result<TimeDiff> {
     TimeDiff::from_str(value).map_err(|error| Error::FailedToParseTimeDiff("ttl", error))
 }
 
-fn gas_price(value: &str) -> Result<u64> {
+pub(super) fn gas_price(value: &str) -> Result<u64> {
     Ok(value
         .parse::<u64>()
         .map_err(|error| Error::FailedToParseInt("gas_price", error))?)
@@ -222,11 +224,7 @@ fn standard_payment(value: &str) -> Result<RuntimeArgs> {
 }
 
 pub(crate) fn secret_key(value: &str) -> Result<SecretKey> {
-    let path = PathBuf::from(value);
-    SecretKey::from_file(path).map_err(|error| Error::CryptoError {
-        context: "secret_key",
-        error,
-    })
+    keystore::read_secret_key(value)
 }
 
 fn args_from_simple_or_complex(
@@ -341,6 +339,25 @@ pub(super) fn parse_deploy_params(
     chain_name: &str,
 ) -> Result<DeployParams> {
     let secret_key = self::secret_key(secret_key)?;
+    parse_deploy_params_with_secret_key(
+        secret_key,
+        timestamp,
+        ttl,
+        gas_price,
+        dependencies,
+        chain_name,
+    )
+}
+
+/// Like `parse_deploy_params`, but taking an already-loaded secret key rather than a path.
+pub(super) fn parse_deploy_params_with_secret_key(
+    secret_key: SecretKey,
+    timestamp: &str,
+    ttl: &str,
+    gas_price: &str,
+    dependencies: &[&str],
+    chain_name: &str,
+) -> Result<DeployParams> {
     let timestamp = self::timestamp(timestamp)?;
     let ttl = self::ttl(ttl)?;
     let gas_price = self::gas_price(gas_price)?;
@@ -534,6 +551,20 @@ pub(super) fn parse_payment_info(
     })
 }
 
+/// Parses session code given as raw Wasm rather than as a path, for use where there is no file
+/// system.
+#[cfg(feature = "wasm")]
+pub(crate) fn parse_session_module_bytes(
+    module_bytes: Vec<u8>,
+    session_args: &[&str],
+) -> Result<ExecutableDeployItem> {
+    Ok(ExecutableDeployItem::ModuleBytes {
+        module_bytes: module_bytes.into(),
+        args: arg_simple::session::parse(session_args)?.unwrap_or_default(),
+    })
+}
+
+#[cfg(feature = "network")]
 pub(crate) fn get_transfer_target(target_account: &str) -> Result<TransferTarget> {
     if !target_account.is_empty() {
         let account = account(target_account)?;
@@ -580,6 +611,7 @@ fn version(value: &str) -> Result<u32> {
         .map_err(|error| Error::FailedToParseInt("version", error))
 }
 
+#[cfg(feature = "network")]
 fn account(value: &str) -> Result<PublicKey> {
     PublicKey::from_hex(value).map_err(|error| Error::CryptoError {
         context: "account",
@@ -587,6 +619,7 @@ fn account(value: &str) -> Result<PublicKey> {
     })
 }
 
+#[cfg(feature = "network")]
 pub(crate) fn transfer_id(value: &str) -> Result<Option<u64>> {
     if str::is_empty(value) {
         return Ok(None);
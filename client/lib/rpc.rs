@@ -1,32 +1,27 @@
-use std::fs::File;
-
-use futures::executor;
 use jsonrpc_lite::{Id, JsonRpc, Params};
 use rand::Rng;
 use reqwest::Client;
 use serde::Serialize;
 use serde_json::{json, Map, Value};
 
-use casper_execution_engine::core::engine_state::ExecutableDeployItem;
-use casper_node::{
-    crypto::hash::Digest,
-    rpcs::{
-        account::{PutDeploy, PutDeployParams},
-        chain::{
-            BlockIdentifier, GetBlock, GetBlockParams, GetBlockTransfers, GetBlockTransfersParams,
-            GetEraInfoBySwitchBlock, GetEraInfoParams, GetStateRootHash, GetStateRootHashParams,
-        },
-        docs::ListRpcs,
-        info::{GetDeploy, GetDeployParams},
-        state::{GetAuctionInfo, GetBalance, GetBalanceParams, GetItem, GetItemParams},
-        RpcWithOptionalParams, RpcWithParams, RpcWithoutParams, RPC_API_PATH,
+use casper_node_types::{
+    crypto::hash::Digest, BlockHash, BlockIdentifier, Deploy, DeployHash, ExecutableDeployItem,
+};
+use casper_rpc_client::rpcs::{
+    account::{PutDeploy, PutDeployParams},
+    chain::{
+        GetBlock, GetBlockParams, GetBlockTransfers, GetBlockTransfersParams,
+        GetEraInfoBySwitchBlock, GetEraInfoParams, GetStateRootHash, GetStateRootHashParams,
     },
-    types::{BlockHash, Deploy, DeployHash},
+    docs::ListRpcs,
+    info::{GetDeploy, GetDeployConfig, GetDeployParams},
+    state::{GetAuctionInfo, GetBalance, GetBalanceParams, GetItem, GetItemParams},
+    RpcMethod, RPC_API_PATH,
 };
 use casper_types::{AsymmetricType, Key, PublicKey, RuntimeArgs, URef, U512};
 
 use crate::{
-    deploy::{DeployExt, DeployParams, SendDeploy, Transfer},
+    deploy::{DeployExt, DeployParams},
     error::{Error, Result},
     validation,
 };
@@ -37,6 +32,35 @@ pub(crate) enum TransferTarget {
     Account(PublicKey),
 }
 
+/// Returns the session code of a native transfer.
+pub(crate) fn transfer_session(
+    amount: U512,
+    source_purse: Option<URef>,
+    target: TransferTarget,
+    id: Option<u64>,
+) -> Result<ExecutableDeployItem> {
+    const TRANSFER_ARG_AMOUNT: &str = "amount";
+    const TRANSFER_ARG_SOURCE: &str = "source";
+    const TRANSFER_ARG_TARGET: &str = "target";
+    const TRANSFER_ARG_ID: &str = "id";
+
+    let mut transfer_args = RuntimeArgs::new();
+    transfer_args.insert(TRANSFER_ARG_AMOUNT, amount)?;
+    if let Some(source_purse) = source_purse {
+        transfer_args.insert(TRANSFER_ARG_SOURCE, source_purse)?;
+    }
+    match target {
+        TransferTarget::Account(target_account) => {
+            let target_account_hash = target_account.to_account_hash().value();
+            transfer_args.insert(TRANSFER_ARG_TARGET, target_account_hash)?;
+        }
+    }
+    transfer_args.insert(TRANSFER_ARG_ID, id)?;
+    Ok(ExecutableDeployItem::Transfer {
+        args: transfer_args,
+    })
+}
+
 /// Struct representing a single JSON-RPC call to the casper node.
 #[derive(Debug)]
 pub(crate) struct RpcCall {
@@ -76,18 +100,25 @@ impl RpcCall {
         }
     }
 
-    pub(crate) fn get_deploy(self, deploy_hash: &str) -> Result<JsonRpc> {
+    pub(crate) async fn get_deploy(self, deploy_hash: &str) -> Result<JsonRpc> {
         let hash = Digest::from_hex(deploy_hash).map_err(|error| Error::CryptoError {
             context: "deploy_hash",
             error,
         })?;
-        let params = GetDeployParams {
-            deploy_hash: DeployHash::new(hash),
-        };
-        GetDeploy::request_with_map_params(self, params)
+        self.get_deploy_by_hash(DeployHash::new(hash)).await
     }
 
-    pub(crate) fn get_item(self, state_root_hash: &str, key: &str, path: &str) -> Result<JsonRpc> {
+    pub(crate) async fn get_deploy_by_hash(self, deploy_hash: DeployHash) -> Result<JsonRpc> {
+        let params = GetDeployParams { deploy_hash };
+        self.request_with_map_params::<GetDeploy, _>(params).await
+    }
+
+    pub(crate) async fn get_item(
+        self,
+        state_root_hash: &str,
+        key: &str,
+        path: &str,
+    ) -> Result<JsonRpc> {
         let state_root_hash =
             Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
                 context: "state_root_hash",
@@ -115,22 +146,27 @@ impl RpcCall {
             key: key.to_formatted_string(),
             path: path.clone(),
         };
-        let response = GetItem::request_with_map_params(self, params)?;
+        let response = self.request_with_map_params::<GetItem, _>(params).await?;
         validation::validate_query_response(&response, &state_root_hash, &key, &path)?;
         Ok(response)
     }
 
-    pub(crate) fn get_state_root_hash(self, maybe_block_identifier: &str) -> Result<JsonRpc> {
+    pub(crate) async fn get_state_root_hash(self, maybe_block_identifier: &str) -> Result<JsonRpc> {
         match Self::block_identifier(maybe_block_identifier)? {
             Some(block_identifier) => {
                 let params = GetStateRootHashParams { block_identifier };
-                GetStateRootHash::request_with_map_params(self, params)
+                self.request_with_map_params::<GetStateRootHash, _>(params)
+                    .await
             }
-            None => GetStateRootHash::request(self),
+            None => self.request_without_params::<GetStateRootHash>().await,
         }
     }
 
-    pub(crate) fn get_balance(self, state_root_hash: &str, purse_uref: &str) -> Result<JsonRpc> {
+    pub(crate) async fn get_balance(
+        self,
+        state_root_hash: &str,
+        purse_uref: &str,
+    ) -> Result<JsonRpc> {
         let state_root_hash =
             Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
                 context: "state_root_hash",
@@ -144,35 +180,45 @@ impl RpcCall {
             state_root_hash,
             purse_uref: purse_uref.to_string(),
         };
-        let response = GetBalance::request_with_map_params(self, params)?;
+        let response = self
+            .request_with_map_params::<GetBalance, _>(params)
+            .await?;
         validation::validate_get_balance_response(&response, &state_root_hash, &key)?;
         Ok(response)
     }
 
-    pub(crate) fn get_era_info_by_switch_block(
+    pub(crate) async fn get_era_info_by_switch_block(
         self,
         maybe_block_identifier: &str,
     ) -> Result<JsonRpc> {
         let response = match Self::block_identifier(maybe_block_identifier)? {
-            None => GetEraInfoBySwitchBlock::request(self),
+            None => {
+                self.request_without_params::<GetEraInfoBySwitchBlock>()
+                    .await
+            }
             Some(block_identifier) => {
                 let params = GetEraInfoParams { block_identifier };
-                GetEraInfoBySwitchBlock::request_with_map_params(self, params)
+                self.request_with_map_params::<GetEraInfoBySwitchBlock, _>(params)
+                    .await
             }
         }?;
         validation::validate_get_era_info_response(&response)?;
         Ok(response)
     }
 
-    pub(crate) fn get_auction_info(self) -> Result<JsonRpc> {
-        GetAuctionInfo::request(self)
+    pub(crate) async fn get_auction_info(self) -> Result<JsonRpc> {
+        self.request_without_params::<GetAuctionInfo>().await
     }
 
-    pub(crate) fn list_rpcs(self) -> Result<JsonRpc> {
-        ListRpcs::request(self)
+    pub(crate) async fn get_deploy_config(self) -> Result<JsonRpc> {
+        self.request_without_params::<GetDeployConfig>().await
     }
 
-    pub(crate) fn transfer(
+    pub(crate) async fn list_rpcs(self) -> Result<JsonRpc> {
+        self.request_without_params::<ListRpcs>().await
+    }
+
+    pub(crate) async fn transfer(
         self,
         amount: U512,
         source_purse: Option<URef>,
@@ -181,69 +227,38 @@ impl RpcCall {
         deploy_params: DeployParams,
         payment: ExecutableDeployItem,
     ) -> Result<JsonRpc> {
-        const TRANSFER_ARG_AMOUNT: &str = "amount";
-        const TRANSFER_ARG_SOURCE: &str = "source";
-        const TRANSFER_ARG_TARGET: &str = "target";
-        const TRANSFER_ARG_ID: &str = "id";
-
-        let mut transfer_args = RuntimeArgs::new();
-        transfer_args.insert(TRANSFER_ARG_AMOUNT, amount)?;
-        if let Some(source_purse) = source_purse {
-            transfer_args.insert(TRANSFER_ARG_SOURCE, source_purse)?;
-        }
-        match target {
-            TransferTarget::Account(target_account) => {
-                let target_account_hash = target_account.to_account_hash().value();
-                transfer_args.insert(TRANSFER_ARG_TARGET, target_account_hash)?;
-            }
-        }
-        transfer_args.insert(TRANSFER_ARG_ID, id)?;
-        let session = ExecutableDeployItem::Transfer {
-            args: transfer_args,
-        };
+        let session = transfer_session(amount, source_purse, target, id)?;
         let deploy = Deploy::with_payment_and_session(deploy_params, payment, session);
-        let params = PutDeployParams { deploy };
-        Transfer::request_with_map_params(self, params)
+        self.put_deploy(deploy).await
     }
 
-    pub(crate) fn send_deploy_file(self, input_path: &str) -> Result<JsonRpc> {
-        let input = File::open(input_path).map_err(|error| Error::IoError {
-            context: format!("unable to read input file '{}'", input_path),
-            error,
-        })?;
-        let deploy = Deploy::read_deploy(input)?;
+    pub(crate) async fn put_deploy(self, deploy: Deploy) -> Result<JsonRpc> {
         let params = PutDeployParams { deploy };
-        SendDeploy::request_with_map_params(self, params)
+        self.request_with_map_params::<PutDeploy, _>(params).await
     }
 
-    pub(crate) fn put_deploy(self, deploy: Deploy) -> Result<JsonRpc> {
-        let params = PutDeployParams { deploy };
-        PutDeploy::request_with_map_params(self, params)
-    }
-
-    pub(crate) fn get_block(self, maybe_block_identifier: &str) -> Result<JsonRpc> {
+    pub(crate) async fn get_block(self, maybe_block_identifier: &str) -> Result<JsonRpc> {
         let maybe_block_identifier = Self::block_identifier(maybe_block_identifier)?;
         let response = match maybe_block_identifier {
             Some(block_identifier) => {
                 let params = GetBlockParams { block_identifier };
-                GetBlock::request_with_map_params(self, params)
+                self.request_with_map_params::<GetBlock, _>(params).await
             }
-            None => GetBlock::request(self),
+            None => self.request_without_params::<GetBlock>().await,
         }?;
         validation::validate_get_block_response(&response, &maybe_block_identifier)?;
         Ok(response)
     }
 
-    pub(crate) fn get_block_transfers(self, maybe_block_identifier: &str) -> Result<JsonRpc> {
-        let maybe_block_identifier = Self::block_identifier(maybe_block_identifier)?;
-        let response = match maybe_block_identifier {
+    pub(crate) async fn get_block_transfers(self, maybe_block_identifier: &str) -> Result<JsonRpc> {
+        match Self::block_identifier(maybe_block_identifier)? {
             Some(block_identifier) => {
                 let params = GetBlockTransfersParams { block_identifier };
-                GetBlockTransfers::request_with_map_params(self, params)
+                self.request_with_map_params::<GetBlockTransfers, _>(params)
+                    .await
             }
-            None => GetBlockTransfers::request(self),
-        }?;
-        Ok(response)
+            None => self.request_without_params::<GetBlockTransfers>().await,
+        }
     }
 
     fn block_identifier(maybe_block_identifier: &str) -> Result<Option<BlockIdentifier>> {
@@ -266,6 +281,20 @@ impl RpcCall {
         }
     }
 
+    /// Calls the node's JSON-RPC endpoint for an RPC which takes no parameters.
+    async fn request_without_params<M: RpcMethod>(self) -> Result<JsonRpc> {
+        self.request(M::METHOD, Params::None(())).await
+    }
+
+    /// Calls the node's JSON-RPC endpoint with the given parameters.
+    async fn request_with_map_params<M: RpcMethod, T: IntoJsonMap>(
+        self,
+        params: T,
+    ) -> Result<JsonRpc> {
+        self.request(M::METHOD, Params::from(params.into_json_map()))
+            .await
+    }
+
     async fn request(self, method: &str, params: Params) -> Result<JsonRpc> {
         let url = format!("{}/{}", self.node_address, RPC_API_PATH);
         let rpc_req = JsonRpc::request_with_params(self.rpc_id, method, params);
@@ -319,57 +348,6 @@ impl RpcCall {
     }
 }
 
-/// General purpose client trait for making requests to casper node's HTTP endpoints.
-pub(crate) trait RpcClient {
-    const RPC_METHOD: &'static str;
-
-    /// Calls a casper node's JSON-RPC endpoint.
-    fn request(rpc_call: RpcCall) -> Result<JsonRpc> {
-        executor::block_on(async { rpc_call.request(Self::RPC_METHOD, Params::None(())).await })
-    }
-
-    /// Calls a casper node's JSON-RPC endpoint with parameters.
-    fn request_with_map_params<T: IntoJsonMap>(rpc_call: RpcCall, params: T) -> Result<JsonRpc> {
-        executor::block_on(async {
-            rpc_call
-                .request(Self::RPC_METHOD, Params::from(params.into_json_map()))
-                .await
-        })
-    }
-}
-
-impl RpcClient for GetBalance {
-    const RPC_METHOD: &'static str = Self::METHOD;
-}
-
-impl RpcClient for GetBlock {
-    const RPC_METHOD: &'static str = Self::METHOD;
-}
-
-impl RpcClient for GetBlockTransfers {
-    const RPC_METHOD: &'static str = Self::METHOD;
-}
-
-impl RpcClient for GetStateRootHash {
-    const RPC_METHOD: &'static str = Self::METHOD;
-}
-
-impl RpcClient for GetItem {
-    const RPC_METHOD: &'static str = <Self as RpcWithParams>::METHOD;
-}
-
-impl RpcClient for GetEraInfoBySwitchBlock {
-    const RPC_METHOD: &'static str = Self::METHOD;
-}
-
-impl RpcClient for GetAuctionInfo {
-    const RPC_METHOD: &'static str = Self::METHOD;
-}
-
-impl RpcClient for ListRpcs {
-    const RPC_METHOD: &'static str = Self::METHOD;
-}
-
 pub(crate) trait IntoJsonMap: Serialize {
     fn into_json_map(self) -> Map<String, Value>
     where
@@ -390,4 +368,3 @@ impl IntoJsonMap for GetDeployParams {}
 impl IntoJsonMap for GetBalanceParams {}
 impl IntoJsonMap for GetItemParams {}
 impl IntoJsonMap for GetEraInfoParams {}
-impl IntoJsonMap for ListRpcs {}
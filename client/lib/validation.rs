@@ -10,8 +10,9 @@ use casper_execution_engine::{
 use casper_node::{
     crypto::hash::Digest,
     rpcs::chain::{BlockIdentifier, EraSummary, GetEraInfoResult},
-    types::{json_compatibility, Block, BlockValidationError, JsonBlock},
+    types::{json_compatibility, Block, BlockValidationError},
 };
+use casper_rpc_client::types::Block as JsonBlock;
 use casper_types::{bytesrepr, Key, U512};
 
 const GET_ITEM_RESULT_BALANCE_VALUE: &str = "balance_value";
@@ -0,0 +1,320 @@
+//! Waiting for a deploy to be executed.
+//!
+//! The node's event stream is subscribed to first, so that the `DeployProcessed` event of the
+//! deploy is seen as soon as its block is executed.  As events can be missed, e.g. if the event
+//! stream server is unreachable or drops a lagging client, the node is additionally polled via
+//! `info_get_deploy`, frequently if there is no event stream and rarely otherwise.
+
+use std::time::Duration;
+
+use reqwest::{Client, Response, Url};
+use serde::Serialize;
+use serde_json::Value;
+use tokio::time::{self, Instant};
+
+use casper_node_types::{BlockHash, DeployHash};
+use casper_rpc_client::rpcs::info::GetDeployResult;
+use casper_types::ExecutionResult;
+
+use crate::{
+    error::{Error, Result},
+    rpc::RpcCall,
+};
+
+/// The port of the node's event stream server if not specified otherwise.
+const DEFAULT_EVENT_STREAM_PORT: u16 = 9999;
+/// The path of the node's event stream.
+const EVENT_STREAM_PATH: &str = "events";
+/// The interval at which the node is polled if the event stream is unavailable.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// The interval at which the node is polled while subscribed to the event stream.
+const STREAMING_POLL_INTERVAL: Duration = Duration::from_secs(30);
+/// The maximum time to wait for the event stream server to respond to the subscription.
+pub(crate) const SUBSCRIBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A deploy which has been executed in a block.
+#[derive(Clone, Debug, Serialize)]
+pub struct ExecutedDeploy {
+    /// The hash of the deploy.
+    pub deploy_hash: DeployHash,
+    /// The hash of the block in which the deploy was executed.
+    pub block_hash: BlockHash,
+    /// The result of executing the deploy.
+    pub execution_result: ExecutionResult,
+}
+
+impl ExecutedDeploy {
+    /// Returns the error message if the execution failed.
+    pub fn error_message(&self) -> Option<&str> {
+        match &self.execution_result {
+            ExecutionResult::Failure { error_message, .. } => Some(error_message),
+            ExecutionResult::Success { .. } => None,
+        }
+    }
+}
+
+/// Returns the URL of the event stream, derived from the node address if not given explicitly.
+pub(crate) fn event_stream_url(
+    maybe_event_stream_address: &str,
+    node_address: &str,
+) -> Result<Url> {
+    let mut url = if maybe_event_stream_address.is_empty() {
+        let mut url = Url::parse(node_address).map_err(|error| {
+            Error::InvalidArgument("node_address", format!("{}: {}", node_address, error))
+        })?;
+        url.set_port(Some(DEFAULT_EVENT_STREAM_PORT))
+            .map_err(|()| Error::InvalidArgument("node_address", node_address.to_string()))?;
+        url
+    } else {
+        Url::parse(maybe_event_stream_address).map_err(|error| {
+            Error::InvalidArgument(
+                "event_stream_address",
+                format!("{}: {}", maybe_event_stream_address, error),
+            )
+        })?
+    };
+    url.set_path(EVENT_STREAM_PATH);
+    Ok(url)
+}
+
+/// Parses a single line of the event stream, returning the reported execution if the line is a
+/// `DeployProcessed` event.
+pub(crate) fn parse_deploy_processed(line: &str) -> Result<Option<ExecutedDeploy>> {
+    let data = match line.strip_prefix("data:") {
+        Some(data) => data.trim(),
+        None => return Ok(None),
+    };
+    let mut event: Value = match serde_json::from_str(data) {
+        Ok(event) => event,
+        Err(_) => return Ok(None),
+    };
+    let processed = match event.get_mut("DeployProcessed") {
+        Some(processed) => processed.take(),
+        None => return Ok(None),
+    };
+    Ok(Some(ExecutedDeploy {
+        deploy_hash: serde_json::from_value(processed["deploy_hash"].clone())?,
+        block_hash: serde_json::from_value(processed["block_hash"].clone())?,
+        execution_result: serde_json::from_value(processed["execution_result"].clone())?,
+    }))
+}
+
+/// Removes the first complete line from `buffer` and returns it, leaving a trailing incomplete
+/// line in place.
+pub(crate) fn take_line(buffer: &mut Vec<u8>) -> Option<String> {
+    let index = buffer.iter().position(|byte| *byte == b'\n')?;
+    let line: Vec<u8> = buffer.drain(..=index).collect();
+    Some(String::from_utf8_lossy(&line).trim_end().to_string())
+}
+
+/// Parses the complete lines in `buffer`, leaving a trailing incomplete line in place.
+fn parse_event_lines(
+    buffer: &mut Vec<u8>,
+    deploy_hash: &DeployHash,
+) -> Result<Option<ExecutedDeploy>> {
+    while let Some(line) = take_line(buffer) {
+        if let Some(executed) = parse_deploy_processed(&line)? {
+            if executed.deploy_hash == *deploy_hash {
+                return Ok(Some(executed));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Queries the node for the deploy, returning its execution if it has been executed already.
+async fn poll(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    deploy_hash: DeployHash,
+) -> Result<Option<ExecutedDeploy>> {
+    let response = match RpcCall::new(maybe_rpc_id, node_address, 0)
+        .get_deploy_by_hash(deploy_hash)
+        .await
+    {
+        Ok(response) => response,
+        // The node doesn't know the deploy (yet), e.g. as it is still being gossiped to it.
+        Err(Error::ResponseIsError(_)) => return Ok(None),
+        Err(error) => return Err(error),
+    };
+    let result = match response.get_result() {
+        Some(result) => result.clone(),
+        None => return Err(Error::InvalidRpcResponse(response)),
+    };
+    let get_deploy_result: GetDeployResult = serde_json::from_value(result)?;
+    Ok(get_deploy_result
+        .execution_results
+        .into_iter()
+        .next()
+        .map(|json_result| ExecutedDeploy {
+            deploy_hash,
+            block_hash: json_result.block_hash,
+            execution_result: json_result.result,
+        }))
+}
+
+/// Subscribes to the event stream, or returns `None` if it is unavailable.
+pub(crate) async fn subscribe(
+    url: &Url,
+    verbosity_level: u64,
+    timeout: Duration,
+) -> Option<Response> {
+    let request = Client::new().get(url.clone()).send();
+    let error = match time::timeout(timeout.min(SUBSCRIBE_TIMEOUT), request).await {
+        Ok(Ok(response)) => match response.error_for_status() {
+            Ok(response) => {
+                if verbosity_level > 0 {
+                    println!("Subscribed to events at {}", url);
+                }
+                return Some(response);
+            }
+            Err(error) => error.to_string(),
+        },
+        Ok(Err(error)) => error.to_string(),
+        Err(elapsed) => elapsed.to_string(),
+    };
+    if verbosity_level > 0 {
+        println!(
+            "Failed to subscribe to events at {}, polling instead: {}",
+            url, error
+        );
+    }
+    None
+}
+
+/// Waits until the deploy has been executed, or until `timeout` has elapsed.
+pub(crate) async fn wait_for_deploy(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_event_stream_address: &str,
+    deploy_hash: DeployHash,
+    timeout: Duration,
+) -> Result<Option<ExecutedDeploy>> {
+    let deadline = Instant::now() + timeout;
+    let url = event_stream_url(maybe_event_stream_address, node_address)?;
+    let mut events = subscribe(&url, verbosity_level, timeout).await;
+    let mut buffer = Vec::new();
+    // Poll immediately after subscribing, in case the deploy was executed before.
+    let mut next_poll = Instant::now();
+
+    loop {
+        let now = Instant::now();
+        if now >= next_poll {
+            if let Some(executed) = poll(maybe_rpc_id, node_address, deploy_hash).await? {
+                return Ok(Some(executed));
+            }
+            let interval = if events.is_some() {
+                STREAMING_POLL_INTERVAL
+            } else {
+                POLL_INTERVAL
+            };
+            next_poll = Instant::now() + interval;
+        }
+        if now >= deadline {
+            return Ok(None);
+        }
+        let wake_up = next_poll.min(deadline);
+
+        let mut stream_ended = false;
+        match events.as_mut() {
+            Some(response) => {
+                tokio::select! {
+                    chunk = response.chunk() => match chunk {
+                        Ok(Some(bytes)) => {
+                            buffer.extend_from_slice(&bytes);
+                            if let Some(executed) = parse_event_lines(&mut buffer, &deploy_hash)? {
+                                return Ok(Some(executed));
+                            }
+                        }
+                        Ok(None) | Err(_) => stream_ended = true,
+                    },
+                    _ = time::delay_until(wake_up) => {}
+                }
+            }
+            None => time::delay_until(wake_up).await,
+        }
+
+        if stream_ended {
+            if verbosity_level > 0 {
+                println!("Event stream ended, polling instead");
+            }
+            events = None;
+            next_poll = Instant::now();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_node_types::crypto::hash::Digest;
+
+    use super::*;
+
+    const DEPLOY_HASH: &str = "5c9b3b099c1378aa8e4a5f07f59ff1fcdc69a83179427c7e67ae0377d94d93fa";
+    const BLOCK_HASH: &str = "4e14dcb1eff4bf0ba2b52a3e80d5ab3ed6f7be27ec2b35c2da4c6b1098a8f0d1";
+
+    fn deploy_hash(hex: &str) -> DeployHash {
+        DeployHash::new(Digest::from_hex(hex).unwrap())
+    }
+
+    fn deploy_processed(deploy_hash: &str, execution_result: &str) -> String {
+        format!(
+            r#"data:{{"DeployProcessed":{{"deploy_hash":"{}","account":"01","timestamp":"2021-01-01T00:00:00.000Z","ttl":"1h","dependencies":[],"block_hash":"{}","execution_result":{}}}}}"#,
+            deploy_hash, BLOCK_HASH, execution_result
+        )
+    }
+
+    #[test]
+    fn should_parse_matching_deploy_processed_event() {
+        let failure = r#"{"Failure":{"effect":{"operations":[],"transforms":[]},"transfers":[],"cost":"123","error_message":"User error: 1"}}"#;
+        let mut buffer = format!(
+            "data:{{\"ApiVersion\":\"1.0.0\"}}\n\n:\n{}\nid:1\n\n",
+            deploy_processed(DEPLOY_HASH, failure)
+        )
+        .into_bytes();
+
+        let executed = parse_event_lines(&mut buffer, &deploy_hash(DEPLOY_HASH))
+            .unwrap()
+            .expect("should find the event");
+        assert_eq!(
+            executed.block_hash,
+            BlockHash::new(Digest::from_hex(BLOCK_HASH).unwrap())
+        );
+        assert_eq!(executed.error_message(), Some("User error: 1"));
+    }
+
+    #[test]
+    fn should_ignore_other_deploys_and_keep_incomplete_lines() {
+        let success = r#"{"Success":{"effect":{"operations":[],"transforms":[]},"transfers":[],"cost":"123"}}"#;
+        let other_hash = "1111111111111111111111111111111111111111111111111111111111111111";
+        let event = deploy_processed(DEPLOY_HASH, success);
+        let (first_half, second_half) = event.split_at(event.len() / 2);
+
+        let mut buffer = format!(
+            "{}\n\n{}",
+            deploy_processed(other_hash, success),
+            first_half
+        )
+        .into_bytes();
+        assert!(parse_event_lines(&mut buffer, &deploy_hash(DEPLOY_HASH))
+            .unwrap()
+            .is_none());
+        assert_eq!(buffer, first_half.as_bytes());
+
+        buffer.extend_from_slice(format!("{}\n", second_half).as_bytes());
+        let executed = parse_event_lines(&mut buffer, &deploy_hash(DEPLOY_HASH))
+            .unwrap()
+            .expect("should find the event");
+        assert!(executed.error_message().is_none());
+    }
+
+    #[test]
+    fn should_derive_event_stream_url_from_node_address() {
+        let url = event_stream_url("", "http://10.0.0.1:7777").unwrap();
+        assert_eq!(url.as_str(), "http://10.0.0.1:9999/events");
+
+        let url = event_stream_url("http://localhost:18101/", "http://10.0.0.1:7777").unwrap();
+        assert_eq!(url.as_str(), "http://localhost:18101/events");
+    }
+}
@@ -0,0 +1,213 @@
+//! WebAssembly bindings for constructing, signing and hashing deploys, e.g. in browser wallets.
+//!
+//! Build with the `wasm` feature and without the default features, which require tokio and the
+//! node:
+//!
+//! ```text
+//! cargo build -p casper-client --lib --release --target wasm32-unknown-unknown \
+//!     --no-default-features --features wasm
+//! ```
+//!
+//! None of these functions contact a node; send the resulting deploy with the `account_put_deploy`
+//! JSON-RPC.  Options are passed as plain JavaScript objects whose fields mirror
+//! [`DeployStrParams`](../struct.DeployStrParams.html),
+//! [`SessionStrParams`](../struct.SessionStrParams.html) and
+//! [`PaymentStrParams`](../struct.PaymentStrParams.html) in camel case, but with secret keys given
+//! as the contents of PEM files and session Wasm given as hex-encoded bytes, since there is no file
+//! system.  Deploys are passed as JSON strings.
+
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+use casper_node_types::{Deploy, ExecutableDeployItem, Timestamp};
+
+use crate::{
+    deploy::DeployExt,
+    error::{Error, Result},
+    keystore, parsing,
+};
+
+/// Deploy-related options, see `DeployStrParams`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct DeployOptions {
+    /// The contents of the secret key PEM file, which may be encrypted.
+    secret_key: String,
+    /// The password of an encrypted `secret_key`.
+    #[serde(default)]
+    password: String,
+    /// Defaults to the current time.
+    #[serde(default)]
+    timestamp: String,
+    ttl: String,
+    gas_price: String,
+    #[serde(default)]
+    dependencies: Vec<String>,
+    chain_name: String,
+}
+
+/// Session-related options, see `SessionStrParams`.
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct SessionOptions {
+    /// Hex-encoded Wasm, in place of `session_path`.
+    module_bytes: String,
+    hash: String,
+    name: String,
+    package_hash: String,
+    package_name: String,
+    args: Vec<String>,
+    version: String,
+    entry_point: String,
+}
+
+/// Payment-related options, see `PaymentStrParams`.
+#[derive(Default, Deserialize)]
+#[serde(rename_all = "camelCase", default, deny_unknown_fields)]
+struct PaymentOptions {
+    amount: String,
+    hash: String,
+    name: String,
+    package_hash: String,
+    package_name: String,
+    args: Vec<String>,
+    version: String,
+    entry_point: String,
+}
+
+fn to_js_error(error: Error) -> JsValue {
+    js_sys::Error::new(&error.to_string()).into()
+}
+
+fn from_js<T: for<'de> Deserialize<'de>>(value: &JsValue, arg_name: &'static str) -> Result<T> {
+    value
+        .into_serde()
+        .map_err(|error| Error::InvalidArgument(arg_name, error.to_string()))
+}
+
+fn session(options: SessionOptions) -> Result<ExecutableDeployItem> {
+    let args: Vec<&str> = options.args.iter().map(String::as_str).collect();
+    if options.module_bytes.is_empty() {
+        return parsing::parse_session_info(
+            &options.hash,
+            &options.name,
+            &options.package_hash,
+            &options.package_name,
+            "",
+            &args,
+            "",
+            &options.version,
+            &options.entry_point,
+        );
+    }
+
+    let conflicting = [
+        ("hash", &options.hash),
+        ("name", &options.name),
+        ("packageHash", &options.package_hash),
+        ("packageName", &options.package_name),
+        ("version", &options.version),
+        ("entryPoint", &options.entry_point),
+    ]
+    .iter()
+    .filter(|(_, value)| !value.is_empty())
+    .map(|(field, value)| format!("{}={}", field, value))
+    .collect::<Vec<_>>();
+    if !conflicting.is_empty() {
+        return Err(Error::ConflictingArguments {
+            context: "session",
+            args: conflicting,
+        });
+    }
+    let module_bytes = hex::decode(&options.module_bytes)
+        .map_err(|error| Error::InvalidArgument("session.moduleBytes", error.to_string()))?;
+    parsing::parse_session_module_bytes(module_bytes, &args)
+}
+
+fn payment(options: PaymentOptions) -> Result<ExecutableDeployItem> {
+    let args: Vec<&str> = options.args.iter().map(String::as_str).collect();
+    parsing::parse_payment_info(
+        &options.amount,
+        &options.hash,
+        &options.name,
+        &options.package_hash,
+        &options.package_name,
+        "",
+        &args,
+        "",
+        &options.version,
+        &options.entry_point,
+    )
+}
+
+fn make_deploy_inner(deploy: &JsValue, session: &JsValue, payment: &JsValue) -> Result<String> {
+    let deploy: DeployOptions = from_js(deploy, "deploy")?;
+    let session = self::session(from_js(session, "session")?)?;
+    let payment = self::payment(from_js(payment, "payment")?)?;
+
+    let secret_key = keystore::secret_key_from_pem(&deploy.secret_key, &deploy.password)?;
+    // `SystemTime` is unavailable in the browser, so the default is taken from JavaScript.
+    let timestamp = if deploy.timestamp.is_empty() {
+        Timestamp::from(js_sys::Date::now() as u64).to_string()
+    } else {
+        deploy.timestamp
+    };
+    let dependencies: Vec<&str> = deploy.dependencies.iter().map(String::as_str).collect();
+    let params = parsing::parse_deploy_params_with_secret_key(
+        secret_key,
+        &timestamp,
+        &deploy.ttl,
+        &deploy.gas_price,
+        &dependencies,
+        &deploy.chain_name,
+    )?;
+
+    crate::deploy_to_json(&Deploy::with_payment_and_session(params, payment, session))
+}
+
+/// Creates a signed deploy and returns it as JSON.
+///
+/// `deploy`, `session` and `payment` are objects holding the deploy-, session- and
+/// payment-related options respectively.
+#[wasm_bindgen(js_name = makeDeploy)]
+pub fn make_deploy(
+    deploy: &JsValue,
+    session: &JsValue,
+    payment: &JsValue,
+) -> std::result::Result<String, JsValue> {
+    make_deploy_inner(deploy, session, payment).map_err(to_js_error)
+}
+
+fn sign_deploy_inner(deploy: &str, secret_key: &str, password: &str) -> Result<String> {
+    let mut deploy = crate::deploy_from_json(deploy)?;
+    let secret_key = keystore::secret_key_from_pem(secret_key, password)?;
+    crate::sign_deploy(&mut deploy, &secret_key);
+    crate::deploy_to_json(&deploy)
+}
+
+/// Signs the deploy given as JSON with the secret key given as the contents of a PEM file, and
+/// returns the deploy with the added approval as JSON.  `password` is only used if the secret key
+/// is encrypted.
+#[wasm_bindgen(js_name = signDeploy)]
+pub fn sign_deploy(
+    deploy: &str,
+    secret_key: &str,
+    password: &str,
+) -> std::result::Result<String, JsValue> {
+    sign_deploy_inner(deploy, secret_key, password).map_err(to_js_error)
+}
+
+fn deploy_hash_inner(deploy: &str) -> Result<String> {
+    let mut deploy = crate::deploy_from_json(deploy)?;
+    deploy
+        .is_valid()
+        .map_err(|failure| Error::InvalidDeploy(failure.to_string()))?;
+    Ok(hex::encode(deploy.id().inner()))
+}
+
+/// Verifies the hashes and approvals of the deploy given as JSON, and returns its hex-encoded
+/// hash.
+#[wasm_bindgen(js_name = deployHash)]
+pub fn deploy_hash(deploy: &str) -> std::result::Result<String, JsValue> {
+    deploy_hash_inner(deploy).map_err(to_js_error)
+}
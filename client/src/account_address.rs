@@ -1,6 +1,7 @@
 use std::{fs, str};
 
 use clap::{App, Arg, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::Error;
 use casper_types::{AsymmetricType, PublicKey};
@@ -15,7 +16,7 @@ enum DisplayOrder {
 
 /// Handles providing the arg for and retrieval of the public key.
 mod public_key {
-    use casper_node::crypto::AsymmetricKeyExt;
+    use casper_node_types::crypto::AsymmetricKeyExt;
     use casper_types::AsymmetricType;
 
     use super::*;
@@ -79,13 +80,16 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GenerateAccountHash {
             .arg(public_key::arg())
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let hex_public_key = public_key::get(matches)?;
-        let public_key = PublicKey::from_hex(&hex_public_key).map_err(|error| {
-            eprintln!("Can't parse {} as a public key: {}", hex_public_key, error);
-            Error::FailedToParseKey
-        })?;
-        let account_hash = public_key.to_account_hash();
-        Ok(Success::Output(account_hash.to_string()))
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let hex_public_key = public_key::get(matches)?;
+            let public_key = PublicKey::from_hex(&hex_public_key).map_err(|error| {
+                eprintln!("Can't parse {} as a public key: {}", hex_public_key, error);
+                Error::FailedToParseKey
+            })?;
+            let account_hash = public_key.to_account_hash();
+            Ok(Success::Output(account_hash.to_string()))
+        }
+        .boxed_local()
     }
 }
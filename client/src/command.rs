@@ -1,7 +1,8 @@
 use clap::{App, ArgMatches};
+use futures::future::LocalBoxFuture;
 use jsonrpc_lite::JsonRpc;
 
-use casper_client::Error;
+use casper_client::{Error, ExecutedDeploy};
 
 /// The result of a successful execution of a given client command.
 pub enum Success {
@@ -9,6 +10,8 @@ pub enum Success {
     Response(JsonRpc),
     /// The output which should be presented to the user for non-RPC client commands.
     Output(String),
+    /// The execution of a deploy which was waited for.
+    Executed(ExecutedDeploy),
 }
 
 impl From<JsonRpc> for Success {
@@ -23,5 +26,5 @@ pub trait ClientCommand<'a, 'b> {
     /// Constructs the clap `SubCommand` and returns the clap `App`.
     fn build(display_order: usize) -> App<'a, 'b>;
     /// Parses the arg matches and runs the subcommand.
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error>;
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>>;
 }
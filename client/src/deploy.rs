@@ -1,15 +1,29 @@
+mod check;
 mod creation_common;
+mod encode;
 mod get;
+mod inspect;
 mod list;
+mod load_test;
 mod make;
+mod merge;
 mod put;
+mod schema;
 mod send;
 mod sign;
 mod transfer;
+mod wait;
 
 pub use transfer::Transfer;
 
+pub use check::CheckDeploy;
+pub use encode::EncodeDeploy;
+pub use inspect::InspectDeploy;
 pub use list::ListDeploys;
+pub use load_test::LoadTest;
 pub use make::MakeDeploy;
+pub use merge::MergeDeploys;
+pub use schema::DeploySchema;
 pub use send::SendDeploy;
 pub use sign::SignDeploy;
+pub use wait::WaitDeploy;
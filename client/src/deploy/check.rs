@@ -0,0 +1,75 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
+
+use casper_client::Error;
+
+use super::creation_common::{self, DisplayOrder};
+use crate::{command::ClientCommand, common, Success};
+
+/// Handles providing the arg for and retrieval of the chainspec path.
+mod chainspec_path {
+    use super::*;
+
+    const ARG_NAME: &str = "chainspec-path";
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str =
+        "Path to the network's chainspec.toml file, from which the chain name and deploy limits \
+        are read. If omitted, they are fetched from the node";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::ChainspecPath as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+pub struct CheckDeploy;
+
+impl<'a, 'b> ClientCommand<'a, 'b> for CheckDeploy {
+    const NAME: &'static str = "check-deploy";
+    const ABOUT: &'static str =
+        "Reads a previously-saved deploy from a file and checks it against the chain name and \
+        deploy limits of the network, as the node would. On success, prints a summary of the \
+        deploy including its gas limit. This check is also run by put-deploy and send-deploy";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(creation_common::input::arg())
+            .arg(chainspec_path::arg())
+    }
+
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+            let input_path = creation_common::input::get(matches);
+            let maybe_chainspec_path = chainspec_path::get(matches);
+
+            let report = casper_client::check_deploy(
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                maybe_chainspec_path,
+                input_path,
+            )
+            .await?;
+            Ok(Success::Output(serde_json::to_string_pretty(&report)?))
+        }
+        .boxed_local()
+    }
+}
@@ -7,6 +7,7 @@ use clap::{App, AppSettings, Arg, ArgGroup, ArgMatches};
 
 use casper_client::{help, PaymentStrParams, SessionStrParams};
 
+use super::wait;
 use crate::common;
 
 /// This struct defines the order in which the args are shown for this subcommand's help message.
@@ -18,6 +19,14 @@ pub(super) enum DisplayOrder {
     SecretKey,
     Input,
     Output,
+    Offline,
+    ChainspecPath,
+    Seed,
+    AccountCount,
+    DeployCount,
+    Rate,
+    FaucetSecretKey,
+    FundingAmount,
     TransferAmount,
     TransferTargetAccount,
     TransferId,
@@ -26,6 +35,9 @@ pub(super) enum DisplayOrder {
     GasPrice,
     Dependencies,
     ChainName,
+    Wait,
+    WaitTimeout,
+    EventStreamAddress,
     SessionCode,
     SessionArgSimple,
     SessionArgsComplex,
@@ -347,9 +359,9 @@ pub(super) mod arg_simple {
 
     static ARG_HELP: Lazy<String> = Lazy::new(|| {
         format!(
-            "For simple CLTypes, a named and typed arg which is passed to the Wasm code. To see \
-            an example for each type, run '--{}'. This arg can be repeated to pass multiple named, \
-            typed args, but can only be used for the following types: {}",
+            "A named and typed arg which is passed to the Wasm code. To see an example for each \
+            type, run '--{}'. This arg can be repeated to pass multiple named, typed args, and can \
+            be used for the following types: {}",
             super::show_arg_examples::ARG_NAME,
             help::supported_cl_type_list()
         )
@@ -535,6 +547,16 @@ pub(super) fn apply_common_creation_options<'a, 'b>(
     subcommand
 }
 
+/// Adds the options for waiting until the sent deploy has been executed.
+pub(super) fn apply_wait_options<'a, 'b>(subcommand: App<'a, 'b>) -> App<'a, 'b> {
+    subcommand
+        .arg(wait::wait::arg(DisplayOrder::Wait as usize))
+        .arg(wait::wait_timeout::arg(DisplayOrder::WaitTimeout as usize))
+        .arg(wait::event_stream_address::arg(
+            DisplayOrder::EventStreamAddress as usize,
+        ))
+}
+
 pub(super) fn apply_common_session_options<'a, 'b>(subcommand: App<'a, 'b>) -> App<'a, 'b> {
     subcommand
         .arg(session_path::arg())
@@ -0,0 +1,35 @@
+use clap::{App, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
+
+use casper_client::Error;
+
+use super::creation_common::{self, DisplayOrder};
+use crate::{command::ClientCommand, common, Success};
+
+pub struct EncodeDeploy;
+
+impl<'a, 'b> ClientCommand<'a, 'b> for EncodeDeploy {
+    const NAME: &'static str = "encode-deploy";
+    const ABOUT: &'static str =
+        "Reads a previously-saved deploy from a file and shows its canonical binary \
+        serialization, broken down into the fields of its header, body and approvals, along with \
+        the exact bytes hashed to give its body hash and deploy hash";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(creation_common::input::arg())
+    }
+
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let input_path = creation_common::input::get(matches);
+
+            let encoding = casper_client::encode_deploy_file(input_path)?;
+            Ok(Success::Output(serde_json::to_string_pretty(&encoding)?))
+        }
+        .boxed_local()
+    }
+}
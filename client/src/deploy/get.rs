@@ -1,9 +1,10 @@
 use std::str;
 
 use clap::{App, Arg, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::Error;
-use casper_node::rpcs::info::GetDeploy;
+use casper_rpc_client::rpcs::info::GetDeploy;
 
 use crate::{command::ClientCommand, common, Success};
 
@@ -54,13 +55,17 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetDeploy {
             .arg(deploy_hash::arg())
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let maybe_rpc_id = common::rpc_id::get(matches);
-        let node_address = common::node_address::get(matches);
-        let verbosity_level = common::verbose::get(matches);
-        let deploy_hash = deploy_hash::get(matches);
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+            let deploy_hash = deploy_hash::get(matches);
 
-        casper_client::get_deploy(maybe_rpc_id, node_address, verbosity_level, deploy_hash)
-            .map(Success::from)
+            casper_client::get_deploy(maybe_rpc_id, node_address, verbosity_level, deploy_hash)
+                .await
+                .map(Success::from)
+        }
+        .boxed_local()
     }
 }
@@ -0,0 +1,75 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
+
+use casper_client::Error;
+
+use super::creation_common::{self, DisplayOrder};
+use crate::{command::ClientCommand, common, Success};
+
+/// Handles providing the arg for and retrieval of whether to skip querying the node.
+mod offline {
+    use super::*;
+
+    const ARG_NAME: &str = "offline";
+    const ARG_HELP: &str =
+        "If passed, the node is not queried for the account's associated keys and thresholds, so \
+        only the approvals themselves are shown";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Offline as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> bool {
+        matches.is_present(ARG_NAME)
+    }
+}
+
+pub struct InspectDeploy;
+
+impl<'a, 'b> ClientCommand<'a, 'b> for InspectDeploy {
+    const NAME: &'static str = "inspect-deploy";
+    const ABOUT: &'static str =
+        "Reads a previously-saved deploy from a file and shows its approvals, along with the \
+        associated keys of the deploy's account which haven't approved it yet and whether the \
+        approvals meet the account's deployment threshold";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(creation_common::input::arg())
+            .arg(offline::arg())
+    }
+
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+            let input_path = creation_common::input::get(matches);
+
+            let approvals = if offline::get(matches) {
+                casper_client::inspect_deploy_file(input_path)?
+            } else {
+                casper_client::check_deploy_approvals(
+                    maybe_rpc_id,
+                    node_address,
+                    verbosity_level,
+                    input_path,
+                )
+                .await?
+            };
+            Ok(Success::Output(serde_json::to_string_pretty(&approvals)?))
+        }
+        .boxed_local()
+    }
+}
@@ -1,9 +1,10 @@
 use std::str;
 
 use clap::{App, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::{Error, ListDeploysResult};
-use casper_node::rpcs::chain::GetBlockResult;
+use casper_rpc_client::rpcs::chain::GetBlockResult;
 
 use crate::{command::ClientCommand, common, Success};
 
@@ -35,21 +36,29 @@ impl<'a, 'b> ClientCommand<'a, 'b> for ListDeploys {
             ))
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let maybe_rpc_id = common::rpc_id::get(matches);
-        let node_address = common::node_address::get(matches);
-        let verbosity_level = common::verbose::get(matches);
-        let maybe_block_id = common::block_identifier::get(matches);
-
-        let result =
-            casper_client::get_block(maybe_rpc_id, node_address, verbosity_level, maybe_block_id);
-
-        result.map(|response| {
-            let response_value = response.get_result().cloned().unwrap();
-            let get_block_result =
-                serde_json::from_value::<GetBlockResult>(response_value).expect("should parse");
-            let list = ListDeploysResult::from(get_block_result);
-            Success::Output(serde_json::to_string_pretty(&list).expect("should encode"))
-        })
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+            let maybe_block_id = common::block_identifier::get(matches);
+
+            let result = casper_client::get_block(
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                maybe_block_id,
+            )
+            .await;
+
+            result.map(|response| {
+                let response_value = response.get_result().cloned().unwrap();
+                let get_block_result =
+                    serde_json::from_value::<GetBlockResult>(response_value).expect("should parse");
+                let list = ListDeploysResult::from(get_block_result);
+                Success::Output(serde_json::to_string_pretty(&list).expect("should encode"))
+            })
+        }
+        .boxed_local()
     }
 }
@@ -0,0 +1,338 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
+
+use casper_client::{Error, LoadTestStrParams};
+
+use super::creation_common::{self, DisplayOrder};
+use crate::{command::ClientCommand, common, Success};
+
+/// Handles providing the arg for and retrieval of the seed from which the accounts are derived.
+mod seed {
+    use super::*;
+
+    const ARG_NAME: &str = "seed";
+    const ARG_VALUE_NAME: &str = common::ARG_STRING;
+    const ARG_DEFAULT: &str = "casper-load-test";
+    const ARG_HELP: &str =
+        "Seed from which the secret keys of the sending accounts are derived. Using the same seed \
+        again yields the same accounts, so they only need to be funded once";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Seed as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the number of sending accounts.
+mod account_count {
+    use super::*;
+
+    const ARG_NAME: &str = "account-count";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_DEFAULT: &str = "10";
+    const ARG_HELP: &str =
+        "Number of accounts to send the deploys from. Native transfers require at least two";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::AccountCount as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the number of deploys to send.
+mod deploy_count {
+    use super::*;
+
+    const ARG_NAME: &str = "deploy-count";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_DEFAULT: &str = "100";
+    const ARG_HELP: &str = "Number of deploys to send, spread evenly across the accounts";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::DeployCount as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the target send rate.
+mod rate {
+    use super::*;
+
+    const ARG_NAME: &str = "rate";
+    const ARG_VALUE_NAME: &str = "DEPLOYS PER SECOND";
+    const ARG_DEFAULT: &str = "10";
+    const ARG_HELP: &str =
+        "Number of deploys to send per second. Fractional rates such as '0.5' are allowed";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Rate as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the faucet's secret key.
+mod faucet_secret_key {
+    use super::*;
+
+    const ARG_NAME: &str = "faucet-secret-key";
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str =
+        "Path to the secret key of an account from which the sending accounts are funded before \
+        the load is sent. If omitted, the sending accounts must already be funded, e.g. by a \
+        previous run using the same seed";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::FaucetSecretKey as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the amount each account is funded with.
+mod funding_amount {
+    use super::*;
+
+    const ARG_NAME: &str = "funding-amount";
+    const ARG_VALUE_NAME: &str = "512-BIT INTEGER";
+    const ARG_DEFAULT: &str = "1000000000000";
+    const ARG_HELP: &str =
+        "The number of motes transferred from the faucet to each sending account. This must cover \
+        the payment and transfer amounts of all the deploys the account sends";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::FundingAmount as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the amount sent by each native transfer.
+mod transfer_amount {
+    use super::*;
+
+    const ARG_NAME: &str = "transfer-amount";
+    const ARG_VALUE_NAME: &str = "512-BIT INTEGER";
+    const ARG_DEFAULT: &str = "2500000000";
+    const ARG_HELP: &str =
+        "The number of motes sent by each native transfer between the sending accounts. Ignored \
+        if session code is given";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::TransferAmount as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the standard payment amount of each deploy.
+mod payment_amount {
+    use super::*;
+
+    const ARG_NAME: &str = "payment-amount";
+    const ARG_SHORT: &str = "p";
+    const ARG_VALUE_NAME: &str = "AMOUNT";
+    const ARG_HELP: &str =
+        "The 'amount' arg of the standard-payment contract used by every deploy, including the \
+        funding transfers";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required_unless(creation_common::show_arg_examples::ARG_NAME)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::StandardPayment as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+/// Handles providing the arg for and retrieval of the node's event stream address.
+mod event_stream_address {
+    use super::*;
+
+    const ARG_NAME: &str = "event-stream-address";
+    const ARG_VALUE_NAME: &str = "HOST:PORT";
+    const ARG_HELP: &str =
+        "Hostname or IP and port of the node's event stream server, used to observe the \
+        executions. If omitted, the host of the node address with port 9999 is used";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::EventStreamAddress as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the maximum time to wait for executions.
+mod timeout {
+    use super::*;
+
+    const ARG_NAME: &str = "timeout";
+    const ARG_VALUE_NAME: &str = "DURATION";
+    const ARG_DEFAULT: &str = "5min";
+    const ARG_HELP: &str =
+        "Maximum time to wait for the deploys to be executed after the last one was sent, both \
+        when funding the accounts and when sending the load. Input examples: '90sec', '10min'. \
+        For all options, see https://docs.rs/humantime/latest/humantime/fn.parse_duration.html";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::WaitTimeout as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+pub struct LoadTest;
+
+impl<'a, 'b> ClientCommand<'a, 'b> for LoadTest {
+    const NAME: &'static str = "load-test";
+    const ABOUT: &'static str =
+        "Sends deploys at a target rate from accounts derived from a seed, optionally funding \
+        them from a faucet first. Native transfers between the accounts are sent unless session \
+        code is given. Prints the acceptance and execution latencies and the throughput observed \
+        via the node's event stream";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(creation_common::show_arg_examples::arg())
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(
+                common::node_address::arg(DisplayOrder::NodeAddress as usize)
+                    .required_unless(creation_common::show_arg_examples::ARG_NAME),
+            )
+            .arg(seed::arg())
+            .arg(account_count::arg())
+            .arg(deploy_count::arg())
+            .arg(rate::arg())
+            .arg(faucet_secret_key::arg())
+            .arg(funding_amount::arg())
+            .arg(transfer_amount::arg())
+            .arg(creation_common::ttl::arg())
+            .arg(creation_common::gas_price::arg())
+            .arg(creation_common::chain_name::arg())
+            .arg(timeout::arg())
+            .arg(event_stream_address::arg())
+            .arg(creation_common::session_path::arg())
+            .arg(creation_common::arg_simple::session::arg())
+            .arg(payment_amount::arg())
+    }
+
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            creation_common::show_arg_examples_and_exit_if_required(matches);
+
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+            let maybe_event_stream_address = event_stream_address::get(matches);
+
+            let params = LoadTestStrParams {
+                seed: seed::get(matches),
+                account_count: account_count::get(matches),
+                deploy_count: deploy_count::get(matches),
+                rate: rate::get(matches),
+                faucet_secret_key: faucet_secret_key::get(matches),
+                funding_amount: funding_amount::get(matches),
+                transfer_amount: transfer_amount::get(matches),
+                session_path: creation_common::session_path::get(matches).unwrap_or_default(),
+                session_args: creation_common::arg_simple::session::get(matches),
+                payment_amount: payment_amount::get(matches),
+                chain_name: creation_common::chain_name::get(matches),
+                gas_price: creation_common::gas_price::get(matches),
+                ttl: creation_common::ttl::get(matches),
+                timeout: timeout::get(matches),
+            };
+
+            let report = casper_client::load_test(
+                node_address,
+                verbosity_level,
+                maybe_event_stream_address,
+                params,
+            )
+            .await?;
+            Ok(Success::Output(serde_json::to_string_pretty(&report)?))
+        }
+        .boxed_local()
+    }
+}
@@ -1,4 +1,5 @@
 use clap::{App, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::{DeployStrParams, Error};
 
@@ -24,34 +25,37 @@ impl<'a, 'b> ClientCommand<'a, 'b> for MakeDeploy {
         creation_common::apply_common_creation_options(subcommand, false)
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        creation_common::show_arg_examples_and_exit_if_required(matches);
-
-        let secret_key = common::secret_key::get(matches);
-        let timestamp = creation_common::timestamp::get(matches);
-        let ttl = creation_common::ttl::get(matches);
-        let gas_price = creation_common::gas_price::get(matches);
-        let dependencies = creation_common::dependencies::get(matches);
-        let chain_name = creation_common::chain_name::get(matches);
-
-        let session_str_params = creation_common::session_str_params(matches);
-        let payment_str_params = creation_common::payment_str_params(matches);
-
-        let maybe_output_path = creation_common::output::get(matches);
-
-        casper_client::make_deploy(
-            maybe_output_path.unwrap_or_default(),
-            DeployStrParams {
-                secret_key,
-                timestamp,
-                ttl,
-                dependencies,
-                gas_price,
-                chain_name,
-            },
-            session_str_params,
-            payment_str_params,
-        )
-        .map(|_| Success::Output("Made the deploy".to_string()))
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            creation_common::show_arg_examples_and_exit_if_required(matches);
+
+            let secret_key = common::secret_key::get(matches);
+            let timestamp = creation_common::timestamp::get(matches);
+            let ttl = creation_common::ttl::get(matches);
+            let gas_price = creation_common::gas_price::get(matches);
+            let dependencies = creation_common::dependencies::get(matches);
+            let chain_name = creation_common::chain_name::get(matches);
+
+            let session_str_params = creation_common::session_str_params(matches);
+            let payment_str_params = creation_common::payment_str_params(matches);
+
+            let maybe_output_path = creation_common::output::get(matches);
+
+            casper_client::make_deploy(
+                maybe_output_path.unwrap_or_default(),
+                DeployStrParams {
+                    secret_key,
+                    timestamp,
+                    ttl,
+                    dependencies,
+                    gas_price,
+                    chain_name,
+                },
+                session_str_params,
+                payment_str_params,
+            )
+            .map(|_| Success::Output("Made the deploy".to_string()))
+        }
+        .boxed_local()
     }
 }
@@ -0,0 +1,65 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
+
+use casper_client::Error;
+
+use super::creation_common::{self, DisplayOrder};
+use crate::{command::ClientCommand, common, Success};
+
+/// Handles providing the arg for and retrieval of the paths of the deploy files to merge.
+mod inputs {
+    use super::*;
+
+    const ARG_NAME: &str = "input";
+    const ARG_SHORT_NAME: &str = "i";
+    const ARG_VALUE_NAME: &str = common::ARG_PATH;
+    const ARG_HELP: &str =
+        "Path to a copy of the deploy, signed by some of the required signers. Pass this option \
+        once for each copy";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .required(true)
+            .long(ARG_NAME)
+            .short(ARG_SHORT_NAME)
+            .value_name(ARG_VALUE_NAME)
+            .multiple(true)
+            .number_of_values(1)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Input as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> Vec<&'a str> {
+        matches
+            .values_of(ARG_NAME)
+            .map(|values| values.collect())
+            .unwrap_or_default()
+    }
+}
+
+pub struct MergeDeploys;
+
+impl<'a, 'b> ClientCommand<'a, 'b> for MergeDeploys {
+    const NAME: &'static str = "merge-deploys";
+    const ABOUT: &'static str =
+        "Reads several copies of a previously-saved deploy, each signed by some of the required \
+        signers, and outputs the deploy with all of their approvals to a file or stdout";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(inputs::arg())
+            .arg(creation_common::output::arg())
+    }
+
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let input_paths = inputs::get(matches);
+            let maybe_output = creation_common::output::get(matches);
+            casper_client::merge_deploy_files(&input_paths, maybe_output.unwrap_or_default())
+                .map(|_| Success::Output("Merged the deploy approvals".to_string()))
+        }
+        .boxed_local()
+    }
+}
@@ -0,0 +1,26 @@
+use clap::{App, ArgMatches, SubCommand};
+use futures::future::{self, FutureExt, LocalBoxFuture};
+
+use casper_client::Error;
+
+use super::creation_common::DisplayOrder;
+use crate::{command::ClientCommand, common, Success};
+
+pub struct DeploySchema;
+
+impl<'a, 'b> ClientCommand<'a, 'b> for DeploySchema {
+    const NAME: &'static str = "deploy-schema";
+    const ABOUT: &'static str =
+        "Prints the JSON Schema of the deploy files read and written by the deploy subcommands";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+    }
+
+    fn run<'m>(_matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        future::ready(casper_client::deploy_json_schema().map(Success::Output)).boxed_local()
+    }
+}
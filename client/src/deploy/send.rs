@@ -1,8 +1,12 @@
 use clap::{App, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::Error;
 
-use super::creation_common::{self, DisplayOrder};
+use super::{
+    creation_common::{self, DisplayOrder},
+    wait,
+};
 use crate::{command::ClientCommand, common, Success};
 
 pub struct SendDeploy;
@@ -10,10 +14,12 @@ pub struct SendDeploy;
 impl<'a, 'b> ClientCommand<'a, 'b> for SendDeploy {
     const NAME: &'static str = "send-deploy";
     const ABOUT: &'static str =
-        "Reads a previously-saved deploy from a file and sends it to the network for execution";
+        "Reads a previously-saved deploy from a file and sends it to the network for execution, \
+        after checking that its approvals meet the deployment threshold of its account and that \
+        it complies with the network's deploy limits (see check-deploy)";
 
     fn build(display_order: usize) -> App<'a, 'b> {
-        SubCommand::with_name(Self::NAME)
+        let subcommand = SubCommand::with_name(Self::NAME)
             .about(Self::ABOUT)
             .display_order(display_order)
             .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
@@ -21,16 +27,36 @@ impl<'a, 'b> ClientCommand<'a, 'b> for SendDeploy {
                 DisplayOrder::NodeAddress as usize,
             ))
             .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
-            .arg(creation_common::input::arg())
+            .arg(creation_common::input::arg());
+        creation_common::apply_wait_options(subcommand)
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let maybe_rpc_id = common::rpc_id::get(matches);
-        let node_address = common::node_address::get(matches);
-        let verbosity_level = common::verbose::get(matches);
-        let input_path = creation_common::input::get(matches);
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+            let input_path = creation_common::input::get(matches);
 
-        casper_client::send_deploy_file(maybe_rpc_id, node_address, verbosity_level, &input_path)
-            .map(Success::from)
+            // Fail early rather than having the node reject a deploy lacking approvals.
+            casper_client::check_deploy_approvals(
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                &input_path,
+            )
+            .await?
+            .ensure_executable()?;
+
+            let response = casper_client::send_deploy_file(
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                &input_path,
+            )
+            .await?;
+            wait::wait_if_required(matches, response).await
+        }
+        .boxed_local()
     }
 }
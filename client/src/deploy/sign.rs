@@ -1,4 +1,5 @@
 use clap::{App, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::Error;
 
@@ -24,11 +25,18 @@ impl<'a, 'b> ClientCommand<'a, 'b> for SignDeploy {
             .arg(creation_common::output::arg())
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let input_path = creation_common::input::get(matches);
-        let secret_key = common::secret_key::get(matches);
-        let maybe_output = creation_common::output::get(matches);
-        casper_client::sign_deploy_file(&input_path, secret_key, maybe_output.unwrap_or_default())
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let input_path = creation_common::input::get(matches);
+            let secret_key = common::secret_key::get(matches);
+            let maybe_output = creation_common::output::get(matches);
+            casper_client::sign_deploy_file(
+                &input_path,
+                secret_key,
+                maybe_output.unwrap_or_default(),
+            )
             .map(|_| Success::Output("Signed the deploy".to_string()))
+        }
+        .boxed_local()
     }
 }
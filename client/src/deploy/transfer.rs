@@ -1,8 +1,12 @@
 use clap::{App, Arg, ArgGroup, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::{DeployStrParams, Error};
 
-use super::creation_common::{self, DisplayOrder};
+use super::{
+    creation_common::{self, DisplayOrder},
+    wait,
+};
 use crate::{command::ClientCommand, common, Success};
 
 /// Handles providing the arg for and retrieval of the transfer amount.
@@ -104,46 +108,51 @@ impl<'a, 'b> ClientCommand<'a, 'b> for Transfer {
                     .required(true),
             );
         let subcommand = creation_common::apply_common_payment_options(subcommand);
-        creation_common::apply_common_creation_options(subcommand, true)
+        let subcommand = creation_common::apply_common_creation_options(subcommand, true);
+        creation_common::apply_wait_options(subcommand)
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        creation_common::show_arg_examples_and_exit_if_required(matches);
-
-        let amount = amount::get(matches);
-        let target_account = target_account::get(matches);
-        let transfer_id = transfer_id::get(matches);
-
-        let maybe_rpc_id = common::rpc_id::get(matches);
-        let node_address = common::node_address::get(matches);
-        let verbosity_level = common::verbose::get(matches);
-
-        let secret_key = common::secret_key::get(matches);
-        let timestamp = creation_common::timestamp::get(matches);
-        let ttl = creation_common::ttl::get(matches);
-        let gas_price = creation_common::gas_price::get(matches);
-        let dependencies = creation_common::dependencies::get(matches);
-        let chain_name = creation_common::chain_name::get(matches);
-
-        let payment_str_params = creation_common::payment_str_params(matches);
-
-        casper_client::transfer(
-            maybe_rpc_id,
-            node_address,
-            verbosity_level,
-            amount,
-            target_account,
-            transfer_id,
-            DeployStrParams {
-                secret_key,
-                timestamp,
-                ttl,
-                dependencies,
-                gas_price,
-                chain_name,
-            },
-            payment_str_params,
-        )
-        .map(Success::from)
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            creation_common::show_arg_examples_and_exit_if_required(matches);
+
+            let amount = amount::get(matches);
+            let target_account = target_account::get(matches);
+            let transfer_id = transfer_id::get(matches);
+
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+
+            let secret_key = common::secret_key::get(matches);
+            let timestamp = creation_common::timestamp::get(matches);
+            let ttl = creation_common::ttl::get(matches);
+            let gas_price = creation_common::gas_price::get(matches);
+            let dependencies = creation_common::dependencies::get(matches);
+            let chain_name = creation_common::chain_name::get(matches);
+
+            let payment_str_params = creation_common::payment_str_params(matches);
+
+            let response = casper_client::transfer(
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                amount,
+                target_account,
+                transfer_id,
+                DeployStrParams {
+                    secret_key,
+                    timestamp,
+                    ttl,
+                    dependencies,
+                    gas_price,
+                    chain_name,
+                },
+                payment_str_params,
+            )
+            .await?;
+            wait::wait_if_required(matches, response).await
+        }
+        .boxed_local()
     }
 }
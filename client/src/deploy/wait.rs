@@ -0,0 +1,198 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
+use jsonrpc_lite::JsonRpc;
+
+use casper_client::Error;
+
+use crate::{command::ClientCommand, common, Success};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    EventStreamAddress,
+    WaitTimeout,
+    DeployHash,
+}
+
+/// Handles providing the arg for and retrieval of the deploy hash.
+mod deploy_hash {
+    use super::*;
+
+    const ARG_NAME: &str = "deploy-hash";
+    const ARG_VALUE_NAME: &str = common::ARG_HEX_STRING;
+    const ARG_HELP: &str = "Hex-encoded deploy hash";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::DeployHash as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+    }
+}
+
+/// Handles providing the arg for and retrieval of whether to wait for the deploy to be executed.
+pub(super) mod wait {
+    use super::*;
+
+    const ARG_NAME: &str = "wait";
+    const ARG_HELP: &str =
+        "If passed, waits until the deploy has been executed and prints the execution result. \
+        The process exits with code 2 if the execution failed, and with code 3 if the deploy was \
+        not executed before the wait timeout elapsed";
+
+    pub(in crate::deploy) fn arg(order: usize) -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .help(ARG_HELP)
+            .display_order(order)
+    }
+
+    pub(in crate::deploy) fn get(matches: &ArgMatches) -> bool {
+        matches.is_present(ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the maximum time to wait for execution.
+pub(super) mod wait_timeout {
+    use super::*;
+
+    const ARG_NAME: &str = "wait-timeout";
+    const ARG_VALUE_NAME: &str = "DURATION";
+    const ARG_DEFAULT: &str = "5min";
+    const ARG_HELP: &str =
+        "Maximum time to wait for the deploy to be executed. Input examples: '90sec', '10min'. \
+        For all options, see https://docs.rs/humantime/latest/humantime/fn.parse_duration.html";
+
+    pub(in crate::deploy) fn arg(order: usize) -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .default_value(ARG_DEFAULT)
+            .help(ARG_HELP)
+            .display_order(order)
+    }
+
+    pub(in crate::deploy) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the node's event stream address.
+pub(super) mod event_stream_address {
+    use super::*;
+
+    const ARG_NAME: &str = "event-stream-address";
+    const ARG_VALUE_NAME: &str = "HOST:PORT";
+    const ARG_HELP: &str =
+        "Hostname or IP and port of the node's event stream server, used to be notified of the \
+        deploy's execution. If omitted, the host of the node address with port 9999 is used. If \
+        the event stream is unavailable, the node is polled instead";
+
+    pub(in crate::deploy) fn arg(order: usize) -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(order)
+    }
+
+    pub(in crate::deploy) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Returns the response to sending a deploy, or, if `--wait` was passed, prints the response and
+/// waits for the deploy to be executed.
+pub(super) async fn wait_if_required(
+    matches: &ArgMatches<'_>,
+    response: JsonRpc,
+) -> Result<Success, Error> {
+    if !wait::get(matches) {
+        return Ok(Success::Response(response));
+    }
+
+    let maybe_rpc_id = common::rpc_id::get(matches);
+    let node_address = common::node_address::get(matches);
+    let verbosity_level = common::verbose::get(matches);
+    let maybe_event_stream_address = event_stream_address::get(matches);
+    let timeout = wait_timeout::get(matches);
+
+    casper_client::pretty_print_at_level(&response, verbosity_level.max(1));
+    let deploy_hash = response
+        .get_result()
+        .and_then(|result| result.get("deploy_hash"))
+        .and_then(|deploy_hash| deploy_hash.as_str())
+        .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+    println!("Waiting for deploy {} to be executed...", deploy_hash);
+
+    casper_client::wait_for_deploy(
+        maybe_rpc_id,
+        node_address,
+        verbosity_level,
+        maybe_event_stream_address,
+        deploy_hash,
+        timeout,
+    )
+    .await
+    .map(Success::Executed)
+}
+
+pub struct WaitDeploy;
+
+impl<'a, 'b> ClientCommand<'a, 'b> for WaitDeploy {
+    const NAME: &'static str = "wait-deploy";
+    const ABOUT: &'static str =
+        "Waits until a deploy has been executed and prints the execution result. Exits with code \
+        2 if the execution failed, and with code 3 on timeout";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(event_stream_address::arg(
+                DisplayOrder::EventStreamAddress as usize,
+            ))
+            .arg(wait_timeout::arg(DisplayOrder::WaitTimeout as usize))
+            .arg(deploy_hash::arg())
+    }
+
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+            let maybe_event_stream_address = event_stream_address::get(matches);
+            let timeout = wait_timeout::get(matches);
+            let deploy_hash = deploy_hash::get(matches);
+
+            casper_client::wait_for_deploy(
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                maybe_event_stream_address,
+                deploy_hash,
+                timeout,
+            )
+            .await
+            .map(Success::Executed)
+        }
+        .boxed_local()
+    }
+}
@@ -1,9 +1,10 @@
 use std::str;
 
 use clap::{App, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::Error;
-use casper_node::rpcs::docs::ListRpcs;
+use casper_rpc_client::rpcs::docs::ListRpcs;
 
 use crate::{command::ClientCommand, common, Success};
 
@@ -29,11 +30,16 @@ impl<'a, 'b> ClientCommand<'a, 'b> for ListRpcs {
             .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let maybe_rpc_id = common::rpc_id::get(matches);
-        let node_address = common::node_address::get(matches);
-        let verbosity_level = common::verbose::get(matches);
-
-        casper_client::list_rpcs(maybe_rpc_id, node_address, verbosity_level).map(Success::from)
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+
+            casper_client::list_rpcs(maybe_rpc_id, node_address, verbosity_level)
+                .await
+                .map(Success::from)
+        }
+        .boxed_local()
     }
 }
@@ -0,0 +1,208 @@
+use std::io::{self, BufRead, Write};
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
+
+use casper_client::{Error, Explorer};
+
+use crate::{command::ClientCommand, common, Success};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    StateRootHash,
+    Key,
+}
+
+const COMMANDS_HELP: &str = "\
+ls [PATH]         List the entries at PATH, or at the current location
+cd [PATH]         Change the current location to PATH, or to the root if omitted
+cat [PATH]        Show the value at PATH, or at the current location
+balance [PATH]    Show the balance of the purse or account at PATH, or at the current location
+pwd               Show the current location
+root [HASH]       Show the state root hash, or switch to HASH, or to the latest if HASH is 'latest'
+node [ADDRESS]    Show the node address, or switch to ADDRESS
+help              Show this help
+exit              Leave the explorer
+
+PATH is made of '/'-separated keys, named keys, '.' and '..', e.g.
+'account-hash-<HEX STRING>/my_contract/counter'.  Public keys can be used in place of account
+hashes.  Stored keys and URefs are followed automatically.";
+
+/// Handles providing the arg for and retrieval of the state root hash, which unlike for other
+/// subcommands is optional.
+mod state_root_hash {
+    use super::*;
+
+    const ARG_NAME: &str = "state-root-hash";
+    const ARG_SHORT: &str = "s";
+    const ARG_VALUE_NAME: &str = common::ARG_HEX_STRING;
+    const ARG_HELP: &str =
+        "Hex-encoded hash of the state root to explore. If not given, the latest state root hash \
+        known at the given node will be used";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::StateRootHash as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the initial location.
+mod key {
+    use super::*;
+
+    const ARG_NAME: &str = "key";
+    const ARG_SHORT: &str = "k";
+    const ARG_VALUE_NAME: &str = "FORMATTED STRING";
+    const ARG_HELP: &str =
+        "The key at which to start exploring, e.g. \"account-hash-<HEX STRING>\", \
+        \"hash-<HEX STRING>\" or a hex-encoded public key. If not given, exploring starts at the \
+        root";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Key as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+pub struct Explore;
+
+impl<'a, 'b> ClientCommand<'a, 'b> for Explore {
+    const NAME: &'static str = "explore";
+    const ABOUT: &'static str =
+        "Interactively browses global state, walking the named keys of accounts and contracts";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .after_help(COMMANDS_HELP)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(state_root_hash::arg())
+            .arg(key::arg())
+    }
+
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+            let maybe_state_root_hash = state_root_hash::get(matches);
+            let key = key::get(matches);
+
+            let mut explorer = Explorer::new(
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                maybe_state_root_hash,
+            )
+            .await?;
+            explorer.change_location(key).await?;
+            println!(
+                "Exploring state root hash {:x} at {}. Enter 'help' for the available commands.",
+                explorer.state_root_hash(),
+                explorer.node_address()
+            );
+
+            let stdin = io::stdin();
+            let mut lines = stdin.lock().lines();
+            loop {
+                print!("{}> ", explorer.current_path());
+                io::stdout().flush().map_err(|error| Error::IoError {
+                    context: "failed to write the prompt".to_string(),
+                    error,
+                })?;
+
+                let line = match lines.next() {
+                    Some(line) => line.map_err(|error| Error::IoError {
+                        context: "failed to read the command".to_string(),
+                        error,
+                    })?,
+                    None => break,
+                };
+                let mut words = line.split_whitespace();
+                let command = match words.next() {
+                    Some(command) => command,
+                    None => continue,
+                };
+                let argument = words.next().unwrap_or_default();
+                if command == "exit" || command == "quit" {
+                    break;
+                }
+                match execute(&mut explorer, command, argument).await {
+                    Ok(output) if output.is_empty() => (),
+                    Ok(output) => println!("{}", output),
+                    Err(error) => println!("{}", error),
+                }
+            }
+
+            // Ends the line of the last prompt if the input was closed.
+            Ok(Success::Output(String::new()))
+        }
+        .boxed_local()
+    }
+}
+
+/// Executes a single command, returning the output to be shown.
+async fn execute(explorer: &mut Explorer, command: &str, argument: &str) -> Result<String, Error> {
+    let output = match command {
+        "ls" => explorer
+            .list(argument)
+            .await?
+            .into_iter()
+            .map(|entry| format!("{:<32} {}", entry.name, entry.key))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "cd" => {
+            explorer.change_location(argument).await?;
+            String::new()
+        }
+        "cat" => explorer.read_value(argument).await?.to_string(),
+        "balance" => explorer.balance(argument).await?.to_string(),
+        "pwd" => explorer.current_path(),
+        "root" => {
+            match argument {
+                "" => (),
+                "latest" => explorer.set_state_root_hash("").await?,
+                hash => explorer.set_state_root_hash(hash).await?,
+            }
+            format!("{:x}", explorer.state_root_hash())
+        }
+        "node" => {
+            if !argument.is_empty() {
+                explorer.set_node_address(argument);
+            }
+            explorer.node_address().to_string()
+        }
+        "help" => COMMANDS_HELP.to_string(),
+        _ => format!(
+            "Unknown command '{}'. Enter 'help' for the available commands.",
+            command
+        ),
+    };
+    Ok(output)
+}
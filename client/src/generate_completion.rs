@@ -1,6 +1,7 @@
 use std::{fs::File, path::PathBuf, process, str::FromStr};
 
 use clap::{crate_name, App, Arg, ArgMatches, Shell, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::Error;
 
@@ -92,30 +93,33 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GenerateCompletion {
             .arg(shell::arg())
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let output_path = output_file::get(matches);
-        let force = common::force::get(matches);
-        let shell = shell::get(matches);
-
-        if !force && output_path.exists() {
-            eprintln!(
-                "{} exists. To overwrite, rerun with --{}",
-                output_path.display(),
-                common::force::ARG_NAME
-            );
-            process::exit(1);
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let output_path = output_file::get(matches);
+            let force = common::force::get(matches);
+            let shell = shell::get(matches);
+
+            if !force && output_path.exists() {
+                eprintln!(
+                    "{} exists. To overwrite, rerun with --{}",
+                    output_path.display(),
+                    common::force::ARG_NAME
+                );
+                process::exit(1);
+            }
+
+            let mut output_file = File::create(&output_path).map_err(|error| Error::IoError {
+                context: output_path.display().to_string(),
+                error,
+            })?;
+            super::cli().gen_completions_to(crate_name!(), shell, &mut output_file);
+
+            Ok(Success::Output(format!(
+                "Wrote completion script for {} to {}",
+                shell,
+                output_path.display()
+            )))
         }
-
-        let mut output_file = File::create(&output_path).map_err(|error| Error::IoError {
-            context: output_path.display().to_string(),
-            error,
-        })?;
-        super::cli().gen_completions_to(crate_name!(), shell, &mut output_file);
-
-        Ok(Success::Output(format!(
-            "Wrote completion script for {} to {}",
-            shell,
-            output_path.display()
-        )))
+        .boxed_local()
     }
 }
@@ -1,9 +1,10 @@
 use std::str;
 
 use clap::{App, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::Error;
-use casper_node::rpcs::state::GetAuctionInfo;
+use casper_rpc_client::rpcs::state::GetAuctionInfo;
 
 use crate::{command::ClientCommand, common, Success};
 
@@ -30,12 +31,16 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetAuctionInfo {
             .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let maybe_rpc_id = common::rpc_id::get(matches);
-        let node_address = common::node_address::get(matches);
-        let verbosity_level = common::verbose::get(matches);
-
-        casper_client::get_auction_info(maybe_rpc_id, node_address, verbosity_level)
-            .map(Success::from)
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+
+            casper_client::get_auction_info(maybe_rpc_id, node_address, verbosity_level)
+                .await
+                .map(Success::from)
+        }
+        .boxed_local()
     }
 }
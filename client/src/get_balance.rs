@@ -1,9 +1,10 @@
 use std::str;
 
 use clap::{App, Arg, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::Error;
-use casper_node::rpcs::state::GetBalance;
+use casper_rpc_client::rpcs::state::GetBalance;
 
 use crate::{command::ClientCommand, common, Success};
 
@@ -63,20 +64,24 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetBalance {
             .arg(purse_uref::arg())
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let maybe_rpc_id = common::rpc_id::get(matches);
-        let node_address = common::node_address::get(matches);
-        let verbosity_level = common::verbose::get(matches);
-        let state_root_hash = common::state_root_hash::get(&matches);
-        let purse_uref = purse_uref::get(&matches);
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+            let state_root_hash = common::state_root_hash::get(&matches);
+            let purse_uref = purse_uref::get(&matches);
 
-        casper_client::get_balance(
-            maybe_rpc_id,
-            node_address,
-            verbosity_level,
-            state_root_hash,
-            purse_uref,
-        )
-        .map(Success::from)
+            casper_client::get_balance(
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                state_root_hash,
+                purse_uref,
+            )
+            .await
+            .map(Success::from)
+        }
+        .boxed_local()
     }
 }
@@ -1,9 +1,10 @@
 use std::str;
 
 use clap::{App, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::Error;
-use casper_node::rpcs::chain::GetStateRootHash;
+use casper_rpc_client::rpcs::chain::GetStateRootHash;
 
 use crate::{command::ClientCommand, common, Success};
 
@@ -33,18 +34,22 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetStateRootHash {
             ))
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let maybe_rpc_id = common::rpc_id::get(matches);
-        let node_address = common::node_address::get(matches);
-        let verbosity_level = common::verbose::get(matches);
-        let maybe_block_id = common::block_identifier::get(matches);
-
-        casper_client::get_state_root_hash(
-            maybe_rpc_id,
-            node_address,
-            verbosity_level,
-            maybe_block_id,
-        )
-        .map(Success::from)
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+            let maybe_block_id = common::block_identifier::get(matches);
+
+            casper_client::get_state_root_hash(
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                maybe_block_id,
+            )
+            .await
+            .map(Success::from)
+        }
+        .boxed_local()
     }
 }
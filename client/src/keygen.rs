@@ -1,9 +1,12 @@
+use std::env;
+
 use clap::{App, Arg, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 use once_cell::sync::Lazy;
 
 use casper_client::{
-    keygen::{self, FILES, PUBLIC_KEY_HEX},
-    Error,
+    keygen::{self, KeygenOptions, FILES, PUBLIC_KEY_HEX},
+    keystore, mnemonic, Error,
 };
 
 use crate::{command::ClientCommand, common, Success};
@@ -23,8 +26,17 @@ enum DisplayOrder {
     OutputDir,
     Force,
     Algorithm,
+    Encrypt,
+    MnemonicWords,
+    FromMnemonic,
+    DerivationPath,
 }
 
+/// The environment variable from which the mnemonic phrase is read if `--from-mnemonic` is passed.
+const MNEMONIC_ENV_VAR: &str = "CASPER_MNEMONIC";
+/// The environment variable from which the optional BIP-39 passphrase is read.
+const MNEMONIC_PASSPHRASE_ENV_VAR: &str = "CASPER_MNEMONIC_PASSPHRASE";
+
 /// Handles providing the arg for and retrieval of the output directory.
 mod output_dir {
     use super::*;
@@ -77,6 +89,108 @@ mod algorithm {
     }
 }
 
+/// Handles providing the arg for and retrieval of whether to encrypt the secret key file.
+mod encrypt {
+    use super::*;
+
+    const ARG_NAME: &str = "encrypt";
+    const ARG_HELP: &str =
+        "If passed, the secret key file is encrypted with a password, read from the \
+        CASPER_SECRET_KEY_PASSWORD environment variable or else prompted for. Encrypted key files \
+        can be used with all --secret-key args, which read the password the same way";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Encrypt as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> bool {
+        matches.is_present(ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the number of words of a new mnemonic phrase.
+mod mnemonic_words {
+    use super::*;
+
+    const ARG_NAME: &str = "mnemonic-words";
+    const ARG_VALUE_NAME: &str = "WORDS";
+    const ARG_HELP: &str =
+        "If passed, a new BIP-39 mnemonic phrase with the given number of words is generated and \
+        printed, and the keys are derived from it. Write the phrase down and keep it safe: it can \
+        be used to recreate the keys with --from-mnemonic";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .possible_values(&["12", "15", "18", "21", "24"])
+            .conflicts_with(from_mnemonic::ARG_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::MnemonicWords as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> Option<usize> {
+        matches
+            .value_of(ARG_NAME)
+            .map(|words| words.parse().expect("should be a possible value"))
+    }
+}
+
+/// Handles providing the arg for and retrieval of whether to derive the keys from an existing
+/// mnemonic phrase.
+mod from_mnemonic {
+    use super::*;
+
+    pub(super) const ARG_NAME: &str = "from-mnemonic";
+    const ARG_HELP: &str =
+        "If passed, the keys are derived from an existing BIP-39 mnemonic phrase, read from the \
+        CASPER_MNEMONIC environment variable or else prompted for. For both this and \
+        --mnemonic-words, an optional BIP-39 passphrase is read from the \
+        CASPER_MNEMONIC_PASSPHRASE environment variable";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::FromMnemonic as usize)
+    }
+
+    pub(super) fn get(matches: &ArgMatches) -> bool {
+        matches.is_present(ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the derivation path.
+mod derivation_path {
+    use super::*;
+
+    const ARG_NAME: &str = "derivation-path";
+    const ARG_VALUE_NAME: &str = "PATH";
+    const ARG_HELP: &str =
+        "The path used to derive the keys from the mnemonic phrase. Hardened indices are marked \
+        with a trailing ' or h; Ed25519 keys only support hardened indices. Defaults to \
+        m/44'/506'/0'/0'/0' for Ed25519 and m/44'/506'/0'/0/0 for secp256k1";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::DerivationPath as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
 pub struct Keygen {}
 
 impl<'a, 'b> ClientCommand<'a, 'b> for Keygen {
@@ -91,14 +205,57 @@ impl<'a, 'b> ClientCommand<'a, 'b> for Keygen {
             .arg(output_dir::arg())
             .arg(common::force::arg(DisplayOrder::Force as usize, false))
             .arg(algorithm::arg())
+            .arg(encrypt::arg())
+            .arg(mnemonic_words::arg())
+            .arg(from_mnemonic::arg())
+            .arg(derivation_path::arg())
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let output_dir = output_dir::get(matches);
-        let algorithm = algorithm::get(matches);
-        let force = common::force::get(matches);
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let output_dir = output_dir::get(matches);
+            let algorithm = algorithm::get(matches);
+            let force = common::force::get(matches);
+            let derivation_path = derivation_path::get(matches);
+
+            let (phrase, new_phrase) = match mnemonic_words::get(matches) {
+                Some(word_count) => (mnemonic::generate_mnemonic(word_count)?, true),
+                None if from_mnemonic::get(matches) => match env::var(MNEMONIC_ENV_VAR) {
+                    Ok(phrase) => (phrase, false),
+                    Err(_) => (keystore::prompt_secret("Mnemonic phrase: ")?, false),
+                },
+                None => (String::new(), false),
+            };
+            if phrase.is_empty() && !derivation_path.is_empty() {
+                return Err(Error::InvalidArgument(
+                    "derivation_path",
+                    "requires --mnemonic-words or --from-mnemonic".to_string(),
+                ));
+            }
+            let mnemonic_passphrase = env::var(MNEMONIC_PASSPHRASE_ENV_VAR).unwrap_or_default();
+            let password = if encrypt::get(matches) {
+                keystore::read_new_password()?
+            } else {
+                String::new()
+            };
+
+            let options = KeygenOptions {
+                password: &password,
+                mnemonic: &phrase,
+                mnemonic_passphrase: &mnemonic_passphrase,
+                derivation_path,
+            };
+            keygen::generate_files_with_options(&output_dir, algorithm, force, options)?;
 
-        keygen::generate_files(&output_dir, algorithm, force)
-            .map(|_| Success::Output(format!("Wrote files to {}", output_dir)))
+            let mut output = format!("Wrote files to {}", output_dir);
+            if new_phrase {
+                output = format!(
+                    "Mnemonic phrase (write it down and keep it safe):\n{}\n\n{}",
+                    phrase, output
+                );
+            }
+            Ok(Success::Output(output))
+        }
+        .boxed_local()
     }
 }
@@ -4,6 +4,7 @@ mod command;
 mod common;
 mod deploy;
 mod docs;
+mod explore;
 mod generate_completion;
 mod get_auction_info;
 mod get_balance;
@@ -17,7 +18,7 @@ use std::process;
 use clap::{crate_description, crate_version, App};
 
 use casper_client::Error;
-use casper_node::rpcs::{
+use casper_rpc_client::rpcs::{
     account::PutDeploy,
     chain::{GetBlock, GetBlockTransfers, GetEraInfoBySwitchBlock, GetStateRootHash},
     docs::ListRpcs,
@@ -25,23 +26,39 @@ use casper_node::rpcs::{
     state::{GetAuctionInfo, GetBalance, GetItem as QueryState},
 };
 
-use deploy::{ListDeploys, MakeDeploy, SendDeploy, SignDeploy};
+use deploy::{
+    CheckDeploy, DeploySchema, EncodeDeploy, InspectDeploy, ListDeploys, LoadTest, MakeDeploy,
+    MergeDeploys, SendDeploy, SignDeploy, WaitDeploy,
+};
 
 use account_address::GenerateAccountHash as AccountAddress;
 use command::{ClientCommand, Success};
 use deploy::Transfer;
+use explore::Explore;
 use generate_completion::GenerateCompletion;
 use keygen::Keygen;
 
 const APP_NAME: &str = "Casper client";
 
+/// The exit code if a deploy which was waited for failed to execute.
+const EXECUTION_FAILED_EXIT_CODE: i32 = 2;
+/// The exit code if a deploy which was waited for wasn't executed before the timeout.
+const WAIT_TIMED_OUT_EXIT_CODE: i32 = 3;
+
 /// This struct defines the order in which the subcommands are shown in the app's help message.
 enum DisplayOrder {
     PutDeploy,
     MakeDeploy,
     SignDeploy,
+    MergeDeploys,
+    InspectDeploy,
+    CheckDeploy,
+    EncodeDeploy,
+    DeploySchema,
+    LoadTest,
     SendDeploy,
     Transfer,
+    WaitDeploy,
     GetDeploy,
     GetBlock,
     GetBlockTransfers,
@@ -51,6 +68,7 @@ enum DisplayOrder {
     GetBalance,
     GetEraInfo,
     GetAuctionInfo,
+    Explore,
     Keygen,
     GenerateCompletion,
     GetRpcs,
@@ -64,8 +82,15 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         .subcommand(PutDeploy::build(DisplayOrder::PutDeploy as usize))
         .subcommand(MakeDeploy::build(DisplayOrder::MakeDeploy as usize))
         .subcommand(SignDeploy::build(DisplayOrder::SignDeploy as usize))
+        .subcommand(MergeDeploys::build(DisplayOrder::MergeDeploys as usize))
+        .subcommand(InspectDeploy::build(DisplayOrder::InspectDeploy as usize))
+        .subcommand(CheckDeploy::build(DisplayOrder::CheckDeploy as usize))
+        .subcommand(EncodeDeploy::build(DisplayOrder::EncodeDeploy as usize))
+        .subcommand(DeploySchema::build(DisplayOrder::DeploySchema as usize))
+        .subcommand(LoadTest::build(DisplayOrder::LoadTest as usize))
         .subcommand(SendDeploy::build(DisplayOrder::SendDeploy as usize))
         .subcommand(Transfer::build(DisplayOrder::Transfer as usize))
+        .subcommand(WaitDeploy::build(DisplayOrder::WaitDeploy as usize))
         .subcommand(GetDeploy::build(DisplayOrder::GetDeploy as usize))
         .subcommand(GetBlock::build(DisplayOrder::GetBlock as usize))
         .subcommand(GetBlockTransfers::build(
@@ -81,6 +106,7 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             DisplayOrder::GetEraInfo as usize,
         ))
         .subcommand(GetAuctionInfo::build(DisplayOrder::GetAuctionInfo as usize))
+        .subcommand(Explore::build(DisplayOrder::Explore as usize))
         .subcommand(Keygen::build(DisplayOrder::Keygen as usize))
         .subcommand(GenerateCompletion::build(
             DisplayOrder::GenerateCompletion as usize,
@@ -93,26 +119,38 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
 async fn main() {
     let arg_matches = cli().get_matches();
     let (result, matches) = match arg_matches.subcommand() {
-        (PutDeploy::NAME, Some(matches)) => (PutDeploy::run(matches), matches),
-        (MakeDeploy::NAME, Some(matches)) => (MakeDeploy::run(matches), matches),
-        (SignDeploy::NAME, Some(matches)) => (SignDeploy::run(matches), matches),
-        (SendDeploy::NAME, Some(matches)) => (SendDeploy::run(matches), matches),
-        (Transfer::NAME, Some(matches)) => (Transfer::run(matches), matches),
-        (GetDeploy::NAME, Some(matches)) => (GetDeploy::run(matches), matches),
-        (GetBlock::NAME, Some(matches)) => (GetBlock::run(matches), matches),
-        (GetBlockTransfers::NAME, Some(matches)) => (GetBlockTransfers::run(matches), matches),
-        (ListDeploys::NAME, Some(matches)) => (ListDeploys::run(matches), matches),
-        (GetBalance::NAME, Some(matches)) => (GetBalance::run(matches), matches),
-        (GetStateRootHash::NAME, Some(matches)) => (GetStateRootHash::run(matches), matches),
-        (QueryState::NAME, Some(matches)) => (QueryState::run(matches), matches),
+        (PutDeploy::NAME, Some(matches)) => (PutDeploy::run(matches).await, matches),
+        (MakeDeploy::NAME, Some(matches)) => (MakeDeploy::run(matches).await, matches),
+        (SignDeploy::NAME, Some(matches)) => (SignDeploy::run(matches).await, matches),
+        (MergeDeploys::NAME, Some(matches)) => (MergeDeploys::run(matches).await, matches),
+        (InspectDeploy::NAME, Some(matches)) => (InspectDeploy::run(matches).await, matches),
+        (CheckDeploy::NAME, Some(matches)) => (CheckDeploy::run(matches).await, matches),
+        (EncodeDeploy::NAME, Some(matches)) => (EncodeDeploy::run(matches).await, matches),
+        (DeploySchema::NAME, Some(matches)) => (DeploySchema::run(matches).await, matches),
+        (LoadTest::NAME, Some(matches)) => (LoadTest::run(matches).await, matches),
+        (SendDeploy::NAME, Some(matches)) => (SendDeploy::run(matches).await, matches),
+        (Transfer::NAME, Some(matches)) => (Transfer::run(matches).await, matches),
+        (WaitDeploy::NAME, Some(matches)) => (WaitDeploy::run(matches).await, matches),
+        (GetDeploy::NAME, Some(matches)) => (GetDeploy::run(matches).await, matches),
+        (GetBlock::NAME, Some(matches)) => (GetBlock::run(matches).await, matches),
+        (GetBlockTransfers::NAME, Some(matches)) => {
+            (GetBlockTransfers::run(matches).await, matches)
+        }
+        (ListDeploys::NAME, Some(matches)) => (ListDeploys::run(matches).await, matches),
+        (GetBalance::NAME, Some(matches)) => (GetBalance::run(matches).await, matches),
+        (GetStateRootHash::NAME, Some(matches)) => (GetStateRootHash::run(matches).await, matches),
+        (QueryState::NAME, Some(matches)) => (QueryState::run(matches).await, matches),
         (GetEraInfoBySwitchBlock::NAME, Some(matches)) => {
-            (GetEraInfoBySwitchBlock::run(matches), matches)
+            (GetEraInfoBySwitchBlock::run(matches).await, matches)
+        }
+        (GetAuctionInfo::NAME, Some(matches)) => (GetAuctionInfo::run(matches).await, matches),
+        (Explore::NAME, Some(matches)) => (Explore::run(matches).await, matches),
+        (Keygen::NAME, Some(matches)) => (Keygen::run(matches).await, matches),
+        (GenerateCompletion::NAME, Some(matches)) => {
+            (GenerateCompletion::run(matches).await, matches)
         }
-        (GetAuctionInfo::NAME, Some(matches)) => (GetAuctionInfo::run(matches), matches),
-        (Keygen::NAME, Some(matches)) => (Keygen::run(matches), matches),
-        (GenerateCompletion::NAME, Some(matches)) => (GenerateCompletion::run(matches), matches),
-        (ListRpcs::NAME, Some(matches)) => (ListRpcs::run(matches), matches),
-        (AccountAddress::NAME, Some(matches)) => (AccountAddress::run(matches), matches),
+        (ListRpcs::NAME, Some(matches)) => (ListRpcs::run(matches).await, matches),
+        (AccountAddress::NAME, Some(matches)) => (AccountAddress::run(matches).await, matches),
         _ => {
             let _ = cli().print_long_help();
             println!();
@@ -130,10 +168,21 @@ async fn main() {
             casper_client::pretty_print_at_level(&response, verbosity_level)
         }
         Ok(Success::Output(output)) => println!("{}", output),
+        Ok(Success::Executed(executed)) => {
+            casper_client::pretty_print_at_level(&executed, verbosity_level);
+            if let Some(error_message) = executed.error_message() {
+                println!("Deploy execution failed: {}", error_message);
+                process::exit(EXECUTION_FAILED_EXIT_CODE);
+            }
+        }
         Err(Error::ResponseIsError(error)) => {
             casper_client::pretty_print_at_level(&error, verbosity_level);
             process::exit(1);
         }
+        Err(error @ Error::WaitForDeployTimedOut { .. }) => {
+            println!("{}", error);
+            process::exit(WAIT_TIMED_OUT_EXIT_CODE);
+        }
         Err(error) => {
             println!("{}", error);
             process::exit(1);
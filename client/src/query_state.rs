@@ -1,9 +1,10 @@
 use std::{fs, str};
 
 use clap::{App, Arg, ArgMatches, SubCommand};
+use futures::future::{FutureExt, LocalBoxFuture};
 
 use casper_client::Error;
-use casper_node::rpcs::state::GetItem;
+use casper_rpc_client::rpcs::state::GetItem;
 use casper_types::PublicKey;
 
 use crate::{command::ClientCommand, common, Success};
@@ -20,7 +21,7 @@ enum DisplayOrder {
 
 /// Handles providing the arg for and retrieval of the key.
 mod key {
-    use casper_node::crypto::AsymmetricKeyExt;
+    use casper_node_types::crypto::AsymmetricKeyExt;
     use casper_types::AsymmetricType;
 
     use super::*;
@@ -118,22 +119,26 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetItem {
             .arg(path::arg())
     }
 
-    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
-        let maybe_rpc_id = common::rpc_id::get(matches);
-        let node_address = common::node_address::get(matches);
-        let verbosity_level = common::verbose::get(matches);
-        let state_root_hash = common::state_root_hash::get(matches);
-        let key = key::get(matches)?;
-        let path = path::get(matches);
-
-        casper_client::get_item(
-            maybe_rpc_id,
-            node_address,
-            verbosity_level,
-            state_root_hash,
-            &key,
-            path,
-        )
-        .map(Success::from)
+    fn run<'m>(matches: &'m ArgMatches<'_>) -> LocalBoxFuture<'m, Result<Success, Error>> {
+        async move {
+            let maybe_rpc_id = common::rpc_id::get(matches);
+            let node_address = common::node_address::get(matches);
+            let verbosity_level = common::verbose::get(matches);
+            let state_root_hash = common::state_root_hash::get(matches);
+            let key = key::get(matches)?;
+            let path = path::get(matches);
+
+            casper_client::get_item(
+                maybe_rpc_id,
+                node_address,
+                verbosity_level,
+                state_root_hash,
+                &key,
+                path,
+            )
+            .await
+            .map(Success::from)
+        }
+        .boxed_local()
     }
 }
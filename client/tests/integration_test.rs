@@ -4,7 +4,7 @@ use futures::{channel::oneshot, future};
 use hyper::{Body, Response, Server};
 use serde::Deserialize;
 use tempfile::TempDir;
-use tokio::{sync::Mutex, task, task::JoinHandle};
+use tokio::{sync::Mutex, task::JoinHandle};
 use tower::builder::ServiceBuilder;
 use warp::{Filter, Rejection};
 use warp_json_rpc::Builder;
@@ -16,9 +16,10 @@ use casper_client::{DeployStrParams, Error, PaymentStrParams, SessionStrParams};
 use casper_node::rpcs::{
     account::{PutDeploy, PutDeployParams},
     chain::{GetStateRootHash, GetStateRootHashParams},
-    info::{GetDeploy, GetDeployParams},
+    docs::DocExample,
+    info::{GetDeploy, GetDeployConfig, GetDeployConfigResult, GetDeployParams},
     state::{GetBalance, GetBalanceParams},
-    RpcWithOptionalParams, RpcWithParams,
+    RpcWithOptionalParams, RpcWithParams, RpcWithoutParams,
 };
 
 const VALID_PURSE_UREF: &str =
@@ -49,6 +50,25 @@ fn test_filter_without_params(
         .map(|builder: Builder| builder.success(()).unwrap())
 }
 
+/// Responds to "account_put_deploy" requests, and to "info_get_deploy_config" requests with limits
+/// which the test deploys comply with.
+fn put_deploy_filter() -> impl Filter<Extract = (Response<Body>,), Error = Rejection> + Copy {
+    let deploy_config = warp_json_rpc::filters::json_rpc()
+        .and(warp_json_rpc::filters::method(GetDeployConfig::METHOD))
+        .map(|builder: Builder| {
+            let example = GetDeployConfigResult::doc_example();
+            let result = GetDeployConfigResult {
+                api_version: example.api_version.clone(),
+                chain_name: deploy_params::CHAIN_NAME.to_string(),
+                deploy_config: example.deploy_config,
+            };
+            builder.success(result).unwrap()
+        });
+    test_filter::<PutDeployParams>(PutDeploy::METHOD)
+        .or(deploy_config)
+        .unify()
+}
+
 type ServerJoiner = Option<Arc<Mutex<JoinHandle<Result<(), hyper::error::Error>>>>>;
 
 struct MockServerHandle {
@@ -79,6 +99,11 @@ impl MockServerHandle {
         )
     }
 
+    /// Will spawn a server on localhost and respond to JSON-RPC requests for sending deploys.
+    fn spawn_for_put_deploy() -> Self {
+        Self::spawn_with_filter(put_deploy_filter(), DEFAULT_RATE_LIMIT, DEFAULT_RATE_PER)
+    }
+
     /// Will spawn a server on localhost and respond to JSON-RPC requests that don't take
     /// parameters.
     fn spawn_without_params(method: &'static str) -> Self {
@@ -119,37 +144,47 @@ impl MockServerHandle {
         }
     }
 
-    fn get_balance(&self, state_root_hash: &str, purse_uref: &str) -> Result<(), ErrWrapper> {
+    async fn get_balance(&self, state_root_hash: &str, purse_uref: &str) -> Result<(), ErrWrapper> {
         casper_client::get_balance("1", &self.url(), 0, state_root_hash, purse_uref)
+            .await
             .map(|_| ())
             .map_err(ErrWrapper)
     }
 
-    fn get_deploy(&self, deploy_hash: &str) -> Result<(), ErrWrapper> {
+    async fn get_deploy(&self, deploy_hash: &str) -> Result<(), ErrWrapper> {
         casper_client::get_deploy("1", &self.url(), 0, deploy_hash)
+            .await
             .map(|_| ())
             .map_err(ErrWrapper)
     }
 
-    fn get_state_root_hash(&self, maybe_block_id: &str) -> Result<(), ErrWrapper> {
+    async fn get_state_root_hash(&self, maybe_block_id: &str) -> Result<(), ErrWrapper> {
         casper_client::get_state_root_hash("1", &self.url(), 0, maybe_block_id)
+            .await
             .map(|_| ())
             .map_err(ErrWrapper)
     }
 
-    fn get_block(&self, maybe_block_id: &str) -> Result<(), ErrWrapper> {
+    async fn get_block(&self, maybe_block_id: &str) -> Result<(), ErrWrapper> {
         casper_client::get_block("1", &self.url(), 0, maybe_block_id)
+            .await
             .map(|_| ())
             .map_err(ErrWrapper)
     }
 
-    fn get_item(&self, state_root_hash: &str, key: &str, path: &str) -> Result<(), ErrWrapper> {
+    async fn get_item(
+        &self,
+        state_root_hash: &str,
+        key: &str,
+        path: &str,
+    ) -> Result<(), ErrWrapper> {
         casper_client::get_item("1", &self.url(), 0, state_root_hash, key, path)
+            .await
             .map(|_| ())
             .map_err(ErrWrapper)
     }
 
-    fn transfer(
+    async fn transfer(
         &self,
         amount: &str,
         maybe_target_account: &str,
@@ -166,11 +201,12 @@ impl MockServerHandle {
             deploy_params,
             payment_params,
         )
+        .await
         .map(|_| ())
         .map_err(ErrWrapper)
     }
 
-    fn put_deploy(
+    async fn put_deploy(
         &self,
         deploy_params: DeployStrParams,
         session_params: SessionStrParams,
@@ -184,18 +220,21 @@ impl MockServerHandle {
             session_params,
             payment_params,
         )
+        .await
         .map(|_| ())
         .map_err(ErrWrapper)
     }
 
-    fn send_deploy_file(&self, input_path: &str) -> Result<(), ErrWrapper> {
+    async fn send_deploy_file(&self, input_path: &str) -> Result<(), ErrWrapper> {
         casper_client::send_deploy_file("1", &self.url(), 0, input_path)
+            .await
             .map(|_| ())
             .map_err(ErrWrapper)
     }
 
-    fn get_auction_info(&self) -> Result<(), ErrWrapper> {
+    async fn get_auction_info(&self) -> Result<(), ErrWrapper> {
         casper_client::get_auction_info("1", &self.url(), 0)
+            .await
             .map(|_| ())
             .map_err(ErrWrapper)
     }
@@ -230,11 +269,13 @@ impl Into<ErrWrapper> for Error {
 mod deploy_params {
     use super::*;
 
+    pub const CHAIN_NAME: &str = "casper-test-chain-name-1";
+
     pub fn test_data_valid() -> DeployStrParams<'static> {
         DeployStrParams {
             secret_key: "../resources/local/secret_keys/node-1.pem",
             ttl: "10s",
-            chain_name: "casper-test-chain-name-1",
+            chain_name: CHAIN_NAME,
             gas_price: "1",
             ..Default::default()
         }
@@ -255,6 +296,10 @@ mod payment_params {
     pub fn test_data_with_name() -> PaymentStrParams<'static> {
         PaymentStrParams::with_name(NAME, ENTRYPOINT, args_simple(), "")
     }
+
+    pub fn test_data_with_amount() -> PaymentStrParams<'static> {
+        PaymentStrParams::with_amount("100000000")
+    }
 }
 
 /// Sample data creation methods for SessionStrParams
@@ -284,7 +329,9 @@ mod get_balance {
     async fn should_succeed_with_valid_arguments() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance(VALID_STATE_ROOT_HASH, VALID_PURSE_UREF),
+            server_handle
+                .get_balance(VALID_STATE_ROOT_HASH, VALID_PURSE_UREF)
+                .await,
             // NOTE: this "success" means that we then fail to validate the response, but that
             // is outside the scope of this test.
             // The MockServerHandle could support a pre-baked response, which should successfully
@@ -299,7 +346,7 @@ mod get_balance {
     async fn should_fail_with_empty_arguments() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance("", ""),
+            server_handle.get_balance("", "").await,
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::InvalidStringLength)
@@ -312,7 +359,7 @@ mod get_balance {
     async fn should_fail_with_empty_state_root_hash() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance("", VALID_PURSE_UREF),
+            server_handle.get_balance("", VALID_PURSE_UREF).await,
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::InvalidStringLength)
@@ -325,7 +372,7 @@ mod get_balance {
     async fn should_fail_with_empty_purse_uref() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance(VALID_STATE_ROOT_HASH, ""),
+            server_handle.get_balance(VALID_STATE_ROOT_HASH, "").await,
             Err(Error::FailedToParseURef("purse_uref", URefFromStrError::InvalidPrefix).into())
         );
     }
@@ -334,7 +381,9 @@ mod get_balance {
     async fn should_fail_with_bad_state_root_hash() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance("deadbeef", VALID_PURSE_UREF),
+            server_handle
+                .get_balance("deadbeef", VALID_PURSE_UREF)
+                .await,
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::InvalidStringLength)
@@ -352,9 +401,11 @@ mod get_state_root_hash {
         let server_handle =
             MockServerHandle::spawn::<GetStateRootHashParams>(GetStateRootHash::METHOD);
         assert_eq!(
-            server_handle.get_state_root_hash(
-                "7a073a340bb5e0ca60f4c1dbb3254fb0641da79cda7c5aeb5303efa74fcc9eb1",
-            ),
+            server_handle
+                .get_state_root_hash(
+                    "7a073a340bb5e0ca60f4c1dbb3254fb0641da79cda7c5aeb5303efa74fcc9eb1",
+                )
+                .await,
             Ok(())
         );
     }
@@ -362,13 +413,13 @@ mod get_state_root_hash {
     #[tokio::test(threaded_scheduler)]
     async fn should_succeed_with_empty_block_id() {
         let server_handle = MockServerHandle::spawn_without_params(GetStateRootHash::METHOD);
-        assert_eq!(server_handle.get_state_root_hash(""), Ok(()));
+        assert_eq!(server_handle.get_state_root_hash("").await, Ok(()));
     }
 
     #[tokio::test(threaded_scheduler)]
     async fn should_succeed_with_valid_block_height() {
         let server_handle = MockServerHandle::spawn_without_params(GetStateRootHash::METHOD);
-        assert_eq!(server_handle.get_state_root_hash("1"), Ok(()));
+        assert_eq!(server_handle.get_state_root_hash("1").await, Ok(()));
     }
 
     #[tokio::test(threaded_scheduler)]
@@ -376,7 +427,7 @@ mod get_state_root_hash {
         let server_handle = MockServerHandle::spawn_without_params(GetStateRootHash::METHOD);
         let input = "<not a real block id>";
         assert!(
-            server_handle.get_state_root_hash(input).is_err(),
+            server_handle.get_state_root_hash(input).await.is_err(),
             "input '{}' should not parse to a valid block id",
             input
         );
@@ -396,7 +447,7 @@ mod get_block {
     async fn should_succeed_with_valid_block_hash() {
         let server_handle = MockServerHandle::spawn::<GetBlockParams>(GetBlock::METHOD);
         assert_eq!(
-            server_handle.get_block(VALID_STATE_ROOT_HASH),
+            server_handle.get_block(VALID_STATE_ROOT_HASH).await,
             Err(ErrWrapper(Error::InvalidResponse(
                 ValidateResponseError::NoBlockInResponse
             )))
@@ -407,7 +458,7 @@ mod get_block {
     async fn should_succeed_with_valid_block_height() {
         let server_handle = MockServerHandle::spawn::<GetBlockParams>(GetBlock::METHOD);
         assert_eq!(
-            server_handle.get_block("1"),
+            server_handle.get_block("1").await,
             Err(ErrWrapper(Error::InvalidResponse(
                 ValidateResponseError::NoBlockInResponse
             )))
@@ -418,7 +469,7 @@ mod get_block {
     async fn should_succeed_with_valid_empty_block_hash() {
         let server_handle = MockServerHandle::spawn_without_params(GetBlock::METHOD);
         assert_eq!(
-            server_handle.get_block(""),
+            server_handle.get_block("").await,
             Err(ErrWrapper(Error::InvalidResponse(
                 ValidateResponseError::NoBlockInResponse
             )))
@@ -428,7 +479,7 @@ mod get_block {
     #[tokio::test(threaded_scheduler)]
     async fn should_fail_with_invalid_block_id() {
         let server_handle = MockServerHandle::spawn::<GetBlockParams>(GetBlock::METHOD);
-        match server_handle.get_block("<not a valid hash>") {
+        match server_handle.get_block("<not a valid hash>").await {
             Err(ErrWrapper(Error::FailedToParseInt("block_identifier", _))) => {}
             other => panic!("incorrect error returned from client {:?}", other),
         }
@@ -449,7 +500,9 @@ mod get_item {
         // mock implementation fails to validate
 
         assert_eq!(
-            server_handle.get_item(VALID_STATE_ROOT_HASH, VALID_PURSE_UREF, ""),
+            server_handle
+                .get_item(VALID_STATE_ROOT_HASH, VALID_PURSE_UREF, "")
+                .await,
             Err(
                 Error::InvalidResponse(ValidateResponseError::ValidateResponseFailedToParse).into()
             )
@@ -460,7 +513,9 @@ mod get_item {
     async fn should_fail_with_invalid_state_root_hash() {
         let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
         assert_eq!(
-            server_handle.get_item("<invalid state root hash>", VALID_PURSE_UREF, ""),
+            server_handle
+                .get_item("<invalid state root hash>", VALID_PURSE_UREF, "")
+                .await,
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::OddLength)
@@ -473,7 +528,9 @@ mod get_item {
     async fn should_fail_with_invalid_key() {
         let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
         assert_eq!(
-            server_handle.get_item(VALID_STATE_ROOT_HASH, "invalid key", ""),
+            server_handle
+                .get_item(VALID_STATE_ROOT_HASH, "invalid key", "")
+                .await,
             Err(Error::FailedToParseKey.into())
         );
     }
@@ -482,7 +539,9 @@ mod get_item {
     async fn should_fail_with_empty_key() {
         let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
         assert_eq!(
-            server_handle.get_item("<invalid state root hash>", "", ""),
+            server_handle
+                .get_item("<invalid state root hash>", "", "")
+                .await,
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::OddLength)
@@ -509,7 +568,7 @@ mod get_deploy {
     async fn should_fail_with_invalid_hash() {
         let server_handle = MockServerHandle::spawn::<GetDeployParams>(GetDeploy::METHOD);
         assert_eq!(
-            server_handle.get_deploy("012345",),
+            server_handle.get_deploy("012345",).await,
             Err(Error::CryptoError {
                 context: "deploy_hash",
                 error: CryptoError::FromHex(FromHexError::InvalidStringLength)
@@ -527,7 +586,7 @@ mod get_auction_info {
     #[tokio::test(threaded_scheduler)]
     async fn should_succeed() {
         let server_handle = MockServerHandle::spawn_without_params(GetAuctionInfo::METHOD);
-        assert_eq!(server_handle.get_auction_info(), Ok(()));
+        assert_eq!(server_handle.get_auction_info().await, Ok(()));
     }
 }
 
@@ -571,11 +630,14 @@ mod send_deploy {
 
     #[tokio::test(threaded_scheduler)]
     async fn should_fail_with_bad_deploy_file_path() {
-        let server_handle = MockServerHandle::spawn::<PutDeployParams>(PutDeploy::METHOD);
+        let server_handle = MockServerHandle::spawn_for_put_deploy();
         if let Err(ErrWrapper(Error::IoError { context, .. })) =
-            server_handle.send_deploy_file("<not a valid path>")
+            server_handle.send_deploy_file("<not a valid path>").await
         {
-            assert_eq!(context, "unable to read input file \'<not a valid path>\'")
+            assert_eq!(
+                context,
+                "unable to read deploy file at \'<not a valid path>\'"
+            )
         }
     }
 
@@ -589,16 +651,42 @@ mod send_deploy {
                 file_path.to_str().unwrap(),
                 deploy_params::test_data_valid(),
                 session_params::test_data_with_package_hash(),
-                payment_params::test_data_with_name()
+                payment_params::test_data_with_amount()
             )
             .map_err(ErrWrapper),
             Ok(())
         );
-        let server_handle = MockServerHandle::spawn::<PutDeployParams>(PutDeploy::METHOD);
+        let server_handle = MockServerHandle::spawn_for_put_deploy();
+        assert_eq!(
+            server_handle
+                .send_deploy_file(file_path.to_str().unwrap())
+                .await,
+            Ok(())
+        );
+    }
+
+    #[tokio::test(threaded_scheduler)]
+    async fn should_fail_check_for_payment_without_amount() {
+        let temp_dir = TempDir::new()
+            .unwrap_or_else(|err| panic!("Failed to create temp dir with error: {}", err));
+        let file_path = temp_dir.path().join("test_send_deploy.json");
         assert_eq!(
-            server_handle.send_deploy_file(file_path.to_str().unwrap()),
+            casper_client::make_deploy(
+                file_path.to_str().unwrap(),
+                deploy_params::test_data_valid(),
+                session_params::test_data_with_package_hash(),
+                payment_params::test_data_with_name()
+            )
+            .map_err(ErrWrapper),
             Ok(())
         );
+        let server_handle = MockServerHandle::spawn_for_put_deploy();
+        assert!(matches!(
+            server_handle
+                .send_deploy_file(file_path.to_str().unwrap())
+                .await,
+            Err(ErrWrapper(Error::InvalidDeploy(_)))
+        ));
     }
 }
 
@@ -784,13 +872,15 @@ mod put_deploy {
 
     #[tokio::test(threaded_scheduler)]
     async fn should_send_put_deploy() {
-        let server_handle = MockServerHandle::spawn::<PutDeployParams>(PutDeploy::METHOD);
+        let server_handle = MockServerHandle::spawn_for_put_deploy();
         assert_eq!(
-            server_handle.put_deploy(
-                deploy_params::test_data_valid(),
-                session_params::test_data_with_package_hash(),
-                payment_params::test_data_with_name()
-            ),
+            server_handle
+                .put_deploy(
+                    deploy_params::test_data_valid(),
+                    session_params::test_data_with_package_hash(),
+                    payment_params::test_data_with_amount()
+                )
+                .await,
             Ok(())
         );
     }
@@ -803,9 +893,7 @@ mod rate_limit {
     #[tokio::test(threaded_scheduler)]
     async fn client_should_should_be_rate_limited_to_approx_1_qps() {
         // Transfer uses PutDeployParams + PutDeploy
-        let server_handle = Arc::new(MockServerHandle::spawn::<PutDeployParams>(
-            PutDeploy::METHOD,
-        ));
+        let server_handle = MockServerHandle::spawn::<PutDeployParams>(PutDeploy::METHOD);
 
         let now = Timestamp::now();
         // Our default is 1 req/s, so this will hit the threshold
@@ -814,20 +902,17 @@ mod rate_limit {
             let maybe_target_account =
                 "01522ef6c89038019cb7af05c340623804392dd2bb1f4dab5e4a9c3ab752fc0179";
 
-            // If you remove the tokio::task::spawn_blocking call wrapping the call to transfer, the
-            // client will eventually eat all executor threads and deadlock (at 64 consecutive
-            // requests). I believe this is happening because the server is executing on the same
-            // tokio runtime as the client.
-            let server_handle = server_handle.clone();
-            let join_handle = task::spawn_blocking(move || {
-                server_handle.transfer(
-                    amount,
-                    maybe_target_account,
-                    deploy_params::test_data_valid(),
-                    payment_params::test_data_with_name(),
-                )
-            });
-            assert_eq!(join_handle.await.unwrap(), Ok(()));
+            assert_eq!(
+                server_handle
+                    .transfer(
+                        amount,
+                        maybe_target_account,
+                        deploy_params::test_data_valid(),
+                        payment_params::test_data_with_name(),
+                    )
+                    .await,
+                Ok(())
+            );
         }
 
         let diff = Timestamp::now() - now;
@@ -855,12 +940,14 @@ mod transfer {
         let maybe_target_account =
             "01522ef6c89038019cb7af05c340623804392dd2bb1f4dab5e4a9c3ab752fc0179";
         assert_eq!(
-            server_handle.transfer(
-                amount,
-                maybe_target_account,
-                deploy_params::test_data_valid(),
-                payment_params::test_data_with_name()
-            ),
+            server_handle
+                .transfer(
+                    amount,
+                    maybe_target_account,
+                    deploy_params::test_data_valid(),
+                    payment_params::test_data_with_name()
+                )
+                .await,
             Ok(())
         );
     }
@@ -874,7 +961,7 @@ mod transfer {
                 "",
                 deploy_params::test_data_valid(),
                 payment_params::test_data_with_name()
-            ),
+            ).await,
             Err(Error::InvalidArgument(
                 "target_account",
                 "Invalid arguments to get_transfer_target - must provide either a target account. account=".to_string()).into())
@@ -1,6 +1,9 @@
 use std::collections::VecDeque;
 
-use casper_types::{bytesrepr::FromBytes, CLTyped, CLValue, CLValueError, Key, TransferAddr};
+use casper_types::{
+    bytesrepr::FromBytes, system::handle_payment::PaymentBreakdown, CLTyped, CLValue, CLValueError,
+    Key, TransferAddr,
+};
 
 use super::{error, execution_effect::ExecutionEffect, op::Op};
 use crate::{
@@ -51,12 +54,16 @@ pub enum ExecutionResult {
         effect: ExecutionEffect,
         transfers: Vec<TransferAddr>,
         cost: Gas,
+        /// How the payment was split between refund, proposer and burn.
+        payment: PaymentBreakdown,
     },
     /// Execution was finished successfully
     Success {
         effect: ExecutionEffect,
         transfers: Vec<TransferAddr>,
         cost: Gas,
+        /// How the payment was split between refund, proposer and burn.
+        payment: PaymentBreakdown,
     },
 }
 
@@ -66,6 +73,7 @@ impl Default for ExecutionResult {
             effect: ExecutionEffect::default(),
             transfers: Vec::default(),
             cost: Gas::default(),
+            payment: PaymentBreakdown::default(),
         }
     }
 }
@@ -92,6 +100,7 @@ impl ExecutionResult {
             effect: Default::default(),
             transfers: Vec::default(),
             cost: Gas::default(),
+            payment: PaymentBreakdown::default(),
         }
     }
 
@@ -139,25 +148,38 @@ impl ExecutionResult {
         }
     }
 
+    pub fn payment(&self) -> &PaymentBreakdown {
+        match self {
+            ExecutionResult::Failure { payment, .. } => payment,
+            ExecutionResult::Success { payment, .. } => payment,
+        }
+    }
+
     pub fn with_cost(self, cost: Gas) -> Self {
         match self {
             ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
+                payment,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
                 cost,
+                payment,
             },
             ExecutionResult::Success {
-                effect, transfers, ..
+                effect,
+                transfers,
+                payment,
+                ..
             } => ExecutionResult::Success {
                 effect,
                 transfers,
                 cost,
+                payment,
             },
         }
     }
@@ -168,19 +190,25 @@ impl ExecutionResult {
                 error,
                 cost,
                 transfers,
+                payment,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
                 cost,
+                payment,
             },
             ExecutionResult::Success {
-                cost, transfers, ..
+                cost,
+                transfers,
+                payment,
+                ..
             } => ExecutionResult::Success {
                 effect,
                 transfers,
                 cost,
+                payment,
             },
         }
     }
@@ -191,17 +219,54 @@ impl ExecutionResult {
                 error,
                 effect,
                 cost,
+                payment,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                effect,
+                transfers,
+                cost,
+                payment,
+            },
+            ExecutionResult::Success {
+                cost,
+                effect,
+                payment,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+                payment,
+            },
+        }
+    }
+
+    pub fn with_payment(self, payment: PaymentBreakdown) -> Self {
+        match self {
+            ExecutionResult::Failure {
+                error,
+                effect,
+                transfers,
+                cost,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
                 cost,
+                payment,
             },
-            ExecutionResult::Success { cost, effect, .. } => ExecutionResult::Success {
+            ExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+                ..
+            } => ExecutionResult::Success {
                 effect,
                 transfers,
                 cost,
+                payment,
             },
         }
     }
@@ -266,11 +331,17 @@ impl ExecutionResult {
             proposer_main_purse_balance_key,
         )?;
         let transfers = Vec::default();
+        // The whole of the maximum payment is forfeited to the proposer.
+        let payment = PaymentBreakdown {
+            proposer_reward: max_payment_cost.value(),
+            ..PaymentBreakdown::default()
+        };
         Ok(ExecutionResult::Failure {
             error,
             effect,
             transfers,
             cost: gas_cost,
+            payment,
         })
     }
 
@@ -290,20 +361,24 @@ impl From<&ExecutionResult> for casper_types::ExecutionResult {
                 effect,
                 transfers,
                 cost,
+                payment,
             } => casper_types::ExecutionResult::Success {
                 effect: effect.into(),
                 transfers: transfers.clone(),
                 cost: cost.value(),
+                payment: *payment,
             },
             ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
                 cost,
+                payment,
             } => casper_types::ExecutionResult::Failure {
                 effect: effect.into(),
                 transfers: transfers.clone(),
                 cost: cost.value(),
+                payment: *payment,
                 error_message: error.to_string(),
             },
         }
@@ -321,6 +396,7 @@ pub struct ExecutionResultBuilder {
     payment_execution_result: Option<ExecutionResult>,
     session_execution_result: Option<ExecutionResult>,
     finalize_execution_result: Option<ExecutionResult>,
+    payment_breakdown: PaymentBreakdown,
 }
 
 impl Default for ExecutionResultBuilder {
@@ -329,6 +405,7 @@ impl Default for ExecutionResultBuilder {
             payment_execution_result: None,
             session_execution_result: None,
             finalize_execution_result: None,
+            payment_breakdown: PaymentBreakdown::default(),
         }
     }
 }
@@ -359,6 +436,14 @@ impl ExecutionResultBuilder {
         self
     }
 
+    pub fn set_payment_breakdown(
+        &mut self,
+        payment_breakdown: PaymentBreakdown,
+    ) -> &mut ExecutionResultBuilder {
+        self.payment_breakdown = payment_breakdown;
+        self
+    }
+
     pub fn total_cost(&self) -> Gas {
         let payment_cost = self
             .payment_execution_result
@@ -395,6 +480,7 @@ impl ExecutionResultBuilder {
             effect: Default::default(),
             transfers,
             cost,
+            payment: self.payment_breakdown,
         };
 
         match self.payment_execution_result {
@@ -413,7 +499,7 @@ impl ExecutionResultBuilder {
         match self.session_execution_result {
             Some(result) => {
                 if result.is_failure() {
-                    ret = result.with_cost(cost);
+                    ret = result.with_cost(cost).with_payment(self.payment_breakdown);
                 } else {
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
                 }
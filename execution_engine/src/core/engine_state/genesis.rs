@@ -19,34 +19,42 @@ use casper_types::{
     runtime_args,
     system::{
         auction::{
-            Bid, Bids, DelegationRate, Delegator, SeigniorageRecipient, SeigniorageRecipients,
-            SeigniorageRecipientsSnapshot, UnbondingPurses, ValidatorWeights, ARG_DELEGATION_RATE,
-            ARG_DELEGATOR, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_PUBLIC_KEY, ARG_REWARD_FACTORS,
-            ARG_VALIDATOR, ARG_VALIDATOR_PUBLIC_KEY, AUCTION_DELAY_KEY,
-            DELEGATION_RATE_DENOMINATOR, ERA_END_TIMESTAMP_MILLIS_KEY, ERA_ID_KEY,
-            INITIAL_ERA_END_TIMESTAMP_MILLIS, INITIAL_ERA_ID, LOCKED_FUNDS_PERIOD_KEY,
-            METHOD_ACTIVATE_BID, METHOD_ADD_BID, METHOD_DELEGATE, METHOD_DISTRIBUTE,
+            Bid, Bids, DelegationRate, Delegator, Redelegations, ScheduledTransfers,
+            SeigniorageRecipient, SeigniorageRecipients, SeigniorageRecipientsSnapshot,
+            UnbondingPurses, ValidatorWeights, ARG_AUTO_COMPOUND, ARG_CONTACT, ARG_COUNT,
+            ARG_DELEGATION_RATE, ARG_DELEGATOR, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_ERA_ID,
+            ARG_ESCROW_PURSE, ARG_INTERVAL, ARG_MINIMUM_DELEGATION_AMOUNT, ARG_NAME,
+            ARG_NEW_VALIDATOR, ARG_PUBLIC_KEY, ARG_REWARD_FACTORS, ARG_URL, ARG_VALIDATOR,
+            ARG_VALIDATOR_PUBLIC_KEY, AUCTION_DELAY_KEY, DELEGATION_RATE_DENOMINATOR,
+            ERA_END_TIMESTAMP_MILLIS_KEY, ERA_ID_KEY, INITIAL_ERA_END_TIMESTAMP_MILLIS,
+            INITIAL_ERA_ID, LOCKED_FUNDS_PERIOD_KEY, MAX_DELEGATION_RATE_INCREASE_KEY,
+            MAX_DELEGATORS_PER_VALIDATOR_KEY, METHOD_ACTIVATE_BID, METHOD_ADD_BID,
+            METHOD_CANCEL_SCHEDULED_TRANSFER, METHOD_DELEGATE, METHOD_DISTRIBUTE,
             METHOD_GET_ERA_VALIDATORS, METHOD_READ_ERA_ID, METHOD_READ_SEIGNIORAGE_RECIPIENTS,
-            METHOD_RUN_AUCTION, METHOD_SLASH, METHOD_UNDELEGATE, METHOD_WITHDRAW_BID,
-            SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY, UNBONDING_PURSES_KEY,
-            VALIDATOR_SLOTS_KEY,
+            METHOD_REDELEGATE, METHOD_RUN_AUCTION, METHOD_SCHEDULE_TRANSFER,
+            METHOD_SET_AUTO_COMPOUND, METHOD_SET_BID_METADATA,
+            METHOD_SET_MINIMUM_DELEGATION_AMOUNT, METHOD_SLASH, METHOD_UNDELEGATE,
+            METHOD_WITHDRAW_BID, MINIMUM_DELEGATION_AMOUNT_KEY, REDELEGATIONS_KEY,
+            SCHEDULED_TRANSFERS_KEY, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY, UNBONDING_DELAY_KEY,
+            UNBONDING_PURSES_KEY, VALIDATOR_SLOTS_KEY,
         },
         handle_payment::{
-            self, ARG_ACCOUNT, METHOD_FINALIZE_PAYMENT, METHOD_GET_PAYMENT_PURSE,
+            self, PaymentBreakdown, ARG_ACCOUNT, METHOD_FINALIZE_PAYMENT, METHOD_GET_PAYMENT_PURSE,
             METHOD_GET_REFUND_PURSE, METHOD_SET_REFUND_PURSE,
         },
         mint::{
-            self, ARG_AMOUNT, ARG_ID, ARG_PURSE, ARG_ROUND_SEIGNIORAGE_RATE, ARG_SOURCE,
-            ARG_TARGET, METHOD_BALANCE, METHOD_CREATE, METHOD_MINT, METHOD_READ_BASE_ROUND_REWARD,
-            METHOD_REDUCE_TOTAL_SUPPLY, METHOD_TRANSFER, ROUND_SEIGNIORAGE_RATE_KEY,
-            TOTAL_SUPPLY_KEY,
+            self, ARG_AMOUNT, ARG_EXPIRY, ARG_ID, ARG_PURSE, ARG_ROUND_SEIGNIORAGE_RATE,
+            ARG_SOURCE, ARG_SPENDER, ARG_TARGET, METHOD_ALLOWANCE, METHOD_APPROVE, METHOD_BALANCE,
+            METHOD_BURN, METHOD_CREATE, METHOD_MINT, METHOD_READ_BASE_ROUND_REWARD,
+            METHOD_READ_TOTAL_BURNED, METHOD_READ_TOTAL_SUPPLY, METHOD_REDUCE_TOTAL_SUPPLY,
+            METHOD_TRANSFER, METHOD_TRANSFER_FROM, ROUND_SEIGNIORAGE_RATE_KEY, TOTAL_SUPPLY_KEY,
         },
         standard_payment::METHOD_PAY,
     },
     AccessRights, CLType, CLTyped, CLValue, Contract, ContractHash, ContractPackage,
     ContractPackageHash, ContractWasm, ContractWasmHash, DeployHash, EntryPoint, EntryPointAccess,
     EntryPointType, EntryPoints, Key, Parameter, Phase, ProtocolVersion, PublicKey, RuntimeArgs,
-    SecretKey, URef, U512,
+    SecretKey, URef, URefAddr, U512,
 };
 
 use super::SYSTEM_ACCOUNT_ADDR;
@@ -547,6 +555,9 @@ pub struct ExecConfig {
     locked_funds_period_millis: u64,
     round_seigniorage_rate: Ratio<u64>,
     unbonding_delay: u64,
+    minimum_delegation_amount: u64,
+    max_delegators_per_validator: u32,
+    max_delegation_rate_increase: DelegationRate,
     genesis_timestamp_millis: u64,
 }
 
@@ -561,6 +572,9 @@ impl ExecConfig {
         locked_funds_period_millis: u64,
         round_seigniorage_rate: Ratio<u64>,
         unbonding_delay: u64,
+        minimum_delegation_amount: u64,
+        max_delegators_per_validator: u32,
+        max_delegation_rate_increase: DelegationRate,
         genesis_timestamp_millis: u64,
     ) -> ExecConfig {
         ExecConfig {
@@ -572,6 +586,9 @@ impl ExecConfig {
             locked_funds_period_millis,
             round_seigniorage_rate,
             unbonding_delay,
+            minimum_delegation_amount,
+            max_delegators_per_validator,
+            max_delegation_rate_increase,
             genesis_timestamp_millis,
         }
     }
@@ -626,6 +643,18 @@ impl ExecConfig {
         self.unbonding_delay
     }
 
+    pub fn minimum_delegation_amount(&self) -> u64 {
+        self.minimum_delegation_amount
+    }
+
+    pub fn max_delegators_per_validator(&self) -> u32 {
+        self.max_delegators_per_validator
+    }
+
+    pub fn max_delegation_rate_increase(&self) -> DelegationRate {
+        self.max_delegation_rate_increase
+    }
+
     pub fn genesis_timestamp_millis(&self) -> u64 {
         self.genesis_timestamp_millis
     }
@@ -654,6 +683,12 @@ impl Distribution<ExecConfig> for Standard {
 
         let unbonding_delay = rng.gen();
 
+        let minimum_delegation_amount = rng.gen();
+
+        let max_delegators_per_validator = rng.gen();
+
+        let max_delegation_rate_increase = rng.gen_range(0, DELEGATION_RATE_DENOMINATOR + 1);
+
         let genesis_timestamp_millis = rng.gen();
 
         ExecConfig {
@@ -665,6 +700,9 @@ impl Distribution<ExecConfig> for Standard {
             locked_funds_period_millis,
             round_seigniorage_rate,
             unbonding_delay,
+            minimum_delegation_amount,
+            max_delegators_per_validator,
+            max_delegation_rate_increase,
             genesis_timestamp_millis,
         }
     }
@@ -830,7 +868,7 @@ where
             named_keys
         };
 
-        let entry_points = self.mint_entry_points();
+        let entry_points = mint_entry_points();
 
         let access_key = self
             .uref_address_generator
@@ -857,7 +895,7 @@ where
             named_keys
         };
 
-        let entry_points = self.handle_payment_entry_points();
+        let entry_points = handle_payment_entry_points();
 
         let access_key = self
             .uref_address_generator
@@ -1040,6 +1078,35 @@ where
         );
         named_keys.insert(UNBONDING_PURSES_KEY.into(), unbonding_purses_uref.into());
 
+        let redelegations_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            redelegations_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(Redelegations::new())
+                    .map_err(|_| GenesisError::CLValue(REDELEGATIONS_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(REDELEGATIONS_KEY.into(), redelegations_uref.into());
+
+        let scheduled_transfers_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            scheduled_transfers_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(ScheduledTransfers::new())
+                    .map_err(|_| GenesisError::CLValue(SCHEDULED_TRANSFERS_KEY.to_string()))?,
+            ),
+        );
+        named_keys.insert(
+            SCHEDULED_TRANSFERS_KEY.into(),
+            scheduled_transfers_uref.into(),
+        );
+
         let validator_slots = self.exec_config.validator_slots();
         let validator_slots_uref = self
             .uref_address_generator
@@ -1097,7 +1164,57 @@ where
         );
         named_keys.insert(UNBONDING_DELAY_KEY.into(), unbonding_delay_uref.into());
 
-        let entry_points = self.auction_entry_points();
+        let minimum_delegation_amount = self.exec_config.minimum_delegation_amount();
+        let minimum_delegation_amount_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            minimum_delegation_amount_uref.into(),
+            StoredValue::CLValue(
+                CLValue::from_t(minimum_delegation_amount).map_err(|_| {
+                    GenesisError::CLValue(MINIMUM_DELEGATION_AMOUNT_KEY.to_string())
+                })?,
+            ),
+        );
+        named_keys.insert(
+            MINIMUM_DELEGATION_AMOUNT_KEY.into(),
+            minimum_delegation_amount_uref.into(),
+        );
+
+        let max_delegators_per_validator = self.exec_config.max_delegators_per_validator();
+        let max_delegators_per_validator_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            max_delegators_per_validator_uref.into(),
+            StoredValue::CLValue(CLValue::from_t(max_delegators_per_validator).map_err(|_| {
+                GenesisError::CLValue(MAX_DELEGATORS_PER_VALIDATOR_KEY.to_string())
+            })?),
+        );
+        named_keys.insert(
+            MAX_DELEGATORS_PER_VALIDATOR_KEY.into(),
+            max_delegators_per_validator_uref.into(),
+        );
+
+        let max_delegation_rate_increase = self.exec_config.max_delegation_rate_increase();
+        let max_delegation_rate_increase_uref = self
+            .uref_address_generator
+            .borrow_mut()
+            .new_uref(AccessRights::READ_ADD_WRITE);
+        self.tracking_copy.borrow_mut().write(
+            max_delegation_rate_increase_uref.into(),
+            StoredValue::CLValue(CLValue::from_t(max_delegation_rate_increase).map_err(|_| {
+                GenesisError::CLValue(MAX_DELEGATION_RATE_INCREASE_KEY.to_string())
+            })?),
+        );
+        named_keys.insert(
+            MAX_DELEGATION_RATE_INCREASE_KEY.into(),
+            max_delegation_rate_increase_uref.into(),
+        );
+
+        let entry_points = auction_entry_points();
 
         let access_key = self
             .uref_address_generator
@@ -1112,7 +1229,7 @@ where
     pub(crate) fn create_standard_payment(&self) -> ContractHash {
         let named_keys = NamedKeys::new();
 
-        let entry_points = self.standard_payment_entry_points();
+        let entry_points = standard_payment_entry_points();
 
         let access_key = self
             .uref_address_generator
@@ -1282,269 +1399,433 @@ where
 
         (contract_package_hash, contract_hash)
     }
+}
 
-    fn mint_entry_points(&self) -> EntryPoints {
-        let mut entry_points = EntryPoints::new();
-
-        let entry_point = EntryPoint::new(
-            METHOD_MINT,
-            vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
-            CLType::Result {
-                ok: Box::new(CLType::URef),
-                err: Box::new(CLType::U8),
-            },
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_REDUCE_TOTAL_SUPPLY,
-            vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
-            CLType::Result {
-                ok: Box::new(CLType::Unit),
-                err: Box::new(CLType::U8),
-            },
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_CREATE,
-            Parameters::new(),
-            CLType::URef,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_BALANCE,
-            vec![Parameter::new(ARG_PURSE, CLType::URef)],
-            CLType::Option(Box::new(CLType::U512)),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_TRANSFER,
-            vec![
-                Parameter::new(ARG_SOURCE, CLType::URef),
-                Parameter::new(ARG_TARGET, CLType::URef),
-                Parameter::new(ARG_AMOUNT, CLType::U512),
-                Parameter::new(ARG_ID, CLType::Option(Box::new(CLType::U64))),
-            ],
-            CLType::Result {
-                ok: Box::new(CLType::Unit),
-                err: Box::new(CLType::U8),
-            },
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_READ_BASE_ROUND_REWARD,
-            Parameters::new(),
-            CLType::U512,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        entry_points
-    }
-
-    fn handle_payment_entry_points(&self) -> EntryPoints {
-        let mut entry_points = EntryPoints::new();
-
-        let get_payment_purse = EntryPoint::new(
-            METHOD_GET_PAYMENT_PURSE,
-            vec![],
-            CLType::URef,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(get_payment_purse);
-
-        let set_refund_purse = EntryPoint::new(
-            METHOD_SET_REFUND_PURSE,
-            vec![Parameter::new(ARG_PURSE, CLType::URef)],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(set_refund_purse);
-
-        let get_refund_purse = EntryPoint::new(
-            METHOD_GET_REFUND_PURSE,
-            vec![],
-            CLType::Option(Box::new(CLType::URef)),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(get_refund_purse);
-
-        let finalize_payment = EntryPoint::new(
-            METHOD_FINALIZE_PAYMENT,
-            vec![
-                Parameter::new(ARG_AMOUNT, CLType::U512),
-                Parameter::new(ARG_ACCOUNT, CLType::ByteArray(32)),
-            ],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(finalize_payment);
+/// Returns the entry points of the mint contract.
+pub(crate) fn mint_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
 
-        entry_points
-    }
+    let entry_point = EntryPoint::new(
+        METHOD_MINT,
+        vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
+        CLType::Result {
+            ok: Box::new(CLType::URef),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_REDUCE_TOTAL_SUPPLY,
+        vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_CREATE,
+        Parameters::new(),
+        CLType::URef,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_BALANCE,
+        vec![Parameter::new(ARG_PURSE, CLType::URef)],
+        CLType::Option(Box::new(CLType::U512)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_TRANSFER,
+        vec![
+            Parameter::new(ARG_SOURCE, CLType::URef),
+            Parameter::new(ARG_TARGET, CLType::URef),
+            Parameter::new(ARG_AMOUNT, CLType::U512),
+            Parameter::new(ARG_ID, CLType::Option(Box::new(CLType::U64))),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_READ_BASE_ROUND_REWARD,
+        Parameters::new(),
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_APPROVE,
+        vec![
+            Parameter::new(ARG_PURSE, CLType::URef),
+            Parameter::new(ARG_SPENDER, CLType::Key),
+            Parameter::new(ARG_AMOUNT, CLType::U512),
+            Parameter::new(ARG_EXPIRY, CLType::U64),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_ALLOWANCE,
+        vec![
+            Parameter::new(ARG_PURSE, URefAddr::cl_type()),
+            Parameter::new(ARG_SPENDER, CLType::Key),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::U512),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_TRANSFER_FROM,
+        vec![
+            Parameter::new(ARG_SOURCE, URefAddr::cl_type()),
+            Parameter::new(ARG_TARGET, CLType::URef),
+            Parameter::new(ARG_AMOUNT, CLType::U512),
+            Parameter::new(ARG_ID, CLType::Option(Box::new(CLType::U64))),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_READ_TOTAL_SUPPLY,
+        Parameters::new(),
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_READ_TOTAL_BURNED,
+        Parameters::new(),
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_BURN,
+        vec![
+            Parameter::new(ARG_PURSE, CLType::URef),
+            Parameter::new(ARG_AMOUNT, CLType::U512),
+        ],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U8),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
 
-    fn auction_entry_points(&self) -> EntryPoints {
-        let mut entry_points = EntryPoints::new();
+    entry_points
+}
 
-        let entry_point = EntryPoint::new(
-            METHOD_GET_ERA_VALIDATORS,
-            vec![],
-            Option::<ValidatorWeights>::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_READ_SEIGNIORAGE_RECIPIENTS,
-            vec![],
-            SeigniorageRecipients::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_ADD_BID,
-            vec![
-                Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
-                Parameter::new(ARG_DELEGATION_RATE, DelegationRate::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_WITHDRAW_BID,
-            vec![
-                Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_DELEGATE,
-            vec![
-                Parameter::new(ARG_DELEGATOR, PublicKey::cl_type()),
-                Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_UNDELEGATE,
-            vec![
-                Parameter::new(ARG_DELEGATOR, AccountHash::cl_type()),
-                Parameter::new(ARG_VALIDATOR, AccountHash::cl_type()),
-                Parameter::new(ARG_AMOUNT, U512::cl_type()),
-            ],
-            U512::cl_type(),
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_RUN_AUCTION,
-            vec![Parameter::new(ARG_ERA_END_TIMESTAMP_MILLIS, u64::cl_type())],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_SLASH,
-            vec![],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_DISTRIBUTE,
-            vec![Parameter::new(
-                ARG_REWARD_FACTORS,
-                CLType::Map {
-                    key: Box::new(CLType::PublicKey),
-                    value: Box::new(CLType::U64),
-                },
-            )],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_READ_ERA_ID,
-            vec![],
-            CLType::U64,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
-
-        let entry_point = EntryPoint::new(
-            METHOD_ACTIVATE_BID,
-            vec![Parameter::new(ARG_VALIDATOR_PUBLIC_KEY, CLType::PublicKey)],
-            CLType::Unit,
-            EntryPointAccess::Public,
-            EntryPointType::Contract,
-        );
-        entry_points.add_entry_point(entry_point);
+/// Returns the entry points of the handle payment contract.
+pub(crate) fn handle_payment_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    let get_payment_purse = EntryPoint::new(
+        METHOD_GET_PAYMENT_PURSE,
+        vec![],
+        CLType::URef,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(get_payment_purse);
+
+    let set_refund_purse = EntryPoint::new(
+        METHOD_SET_REFUND_PURSE,
+        vec![Parameter::new(ARG_PURSE, CLType::URef)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(set_refund_purse);
+
+    let get_refund_purse = EntryPoint::new(
+        METHOD_GET_REFUND_PURSE,
+        vec![],
+        CLType::Option(Box::new(CLType::URef)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(get_refund_purse);
+
+    let finalize_payment = EntryPoint::new(
+        METHOD_FINALIZE_PAYMENT,
+        vec![
+            Parameter::new(ARG_AMOUNT, CLType::U512),
+            Parameter::new(ARG_ACCOUNT, CLType::ByteArray(32)),
+        ],
+        PaymentBreakdown::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(finalize_payment);
+
+    entry_points
+}
 
-        entry_points
-    }
+/// Returns the entry points of the auction contract.
+pub(crate) fn auction_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
+
+    let entry_point = EntryPoint::new(
+        METHOD_GET_ERA_VALIDATORS,
+        vec![],
+        Option::<ValidatorWeights>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_READ_SEIGNIORAGE_RECIPIENTS,
+        vec![],
+        SeigniorageRecipients::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_ADD_BID,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
+            Parameter::new(ARG_DELEGATION_RATE, DelegationRate::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_WITHDRAW_BID,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, AccountHash::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_DELEGATE,
+        vec![
+            Parameter::new(ARG_DELEGATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_UNDELEGATE,
+        vec![
+            Parameter::new(ARG_DELEGATOR, AccountHash::cl_type()),
+            Parameter::new(ARG_VALIDATOR, AccountHash::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_REDELEGATE,
+        vec![
+            Parameter::new(ARG_DELEGATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+            Parameter::new(ARG_NEW_VALIDATOR, PublicKey::cl_type()),
+        ],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_RUN_AUCTION,
+        vec![Parameter::new(ARG_ERA_END_TIMESTAMP_MILLIS, u64::cl_type())],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_SLASH,
+        vec![],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_DISTRIBUTE,
+        vec![Parameter::new(
+            ARG_REWARD_FACTORS,
+            CLType::Map {
+                key: Box::new(CLType::PublicKey),
+                value: Box::new(CLType::U64),
+            },
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_READ_ERA_ID,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_ACTIVATE_BID,
+        vec![Parameter::new(ARG_VALIDATOR_PUBLIC_KEY, CLType::PublicKey)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_SET_AUTO_COMPOUND,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, PublicKey::cl_type()),
+            Parameter::new(ARG_VALIDATOR, PublicKey::cl_type()),
+            Parameter::new(ARG_AUTO_COMPOUND, bool::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_SET_MINIMUM_DELEGATION_AMOUNT,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, PublicKey::cl_type()),
+            Parameter::new(ARG_MINIMUM_DELEGATION_AMOUNT, U512::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_SET_BID_METADATA,
+        vec![
+            Parameter::new(ARG_PUBLIC_KEY, PublicKey::cl_type()),
+            Parameter::new(ARG_NAME, Option::<String>::cl_type()),
+            Parameter::new(ARG_URL, Option::<String>::cl_type()),
+            Parameter::new(ARG_CONTACT, Option::<String>::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_SCHEDULE_TRANSFER,
+        vec![
+            Parameter::new(ARG_TARGET, URef::cl_type()),
+            Parameter::new(ARG_AMOUNT, U512::cl_type()),
+            Parameter::new(ARG_ERA_ID, u64::cl_type()),
+            Parameter::new(ARG_INTERVAL, u64::cl_type()),
+            Parameter::new(ARG_COUNT, u64::cl_type()),
+            Parameter::new(ARG_ID, Option::<u64>::cl_type()),
+        ],
+        URefAddr::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    let entry_point = EntryPoint::new(
+        METHOD_CANCEL_SCHEDULED_TRANSFER,
+        vec![Parameter::new(ARG_ESCROW_PURSE, URefAddr::cl_type())],
+        U512::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    );
+    entry_points.add_entry_point(entry_point);
+
+    entry_points
+}
 
-    fn standard_payment_entry_points(&self) -> EntryPoints {
-        let mut entry_points = EntryPoints::new();
+/// Returns the entry points of the standard payment contract.
+pub(crate) fn standard_payment_entry_points() -> EntryPoints {
+    let mut entry_points = EntryPoints::new();
 
-        let entry_point = EntryPoint::new(
-            METHOD_PAY.to_string(),
-            vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
-            CLType::Result {
-                ok: Box::new(CLType::Unit),
-                err: Box::new(CLType::U32),
-            },
-            EntryPointAccess::Public,
-            EntryPointType::Session,
-        );
-        entry_points.add_entry_point(entry_point);
+    let entry_point = EntryPoint::new(
+        METHOD_PAY.to_string(),
+        vec![Parameter::new(ARG_AMOUNT, CLType::U512)],
+        CLType::Result {
+            ok: Box::new(CLType::Unit),
+            err: Box::new(CLType::U32),
+        },
+        EntryPointAccess::Public,
+        EntryPointType::Session,
+    );
+    entry_points.add_entry_point(entry_point);
 
-        entry_points
-    }
+    entry_points
 }
 
 #[cfg(test)]
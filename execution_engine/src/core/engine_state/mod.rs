@@ -39,7 +39,7 @@ use casper_types::{
             ARG_REWARD_FACTORS, ARG_VALIDATOR_PUBLIC_KEYS, AUCTION_DELAY_KEY,
             LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
         },
-        handle_payment,
+        handle_payment::{self, PaymentBreakdown},
         mint::{self, ROUND_SEIGNIORAGE_RATE_KEY},
     },
     AccessRights, ApiError, BlockTime, CLValue, Contract, DeployHash, DeployInfo, Key, KeyTag,
@@ -66,8 +66,10 @@ pub use self::{
 use crate::{
     core::{
         engine_state::{
-            executable_deploy_item::DeployMetadata, execution_result::ExecutionResultBuilder,
-            genesis::GenesisInstaller, put_trie::InsertedTrieKeyAndMissingDescendants,
+            executable_deploy_item::{DeployMetadata, ExecutableDeployItemExt},
+            execution_result::ExecutionResultBuilder,
+            genesis::GenesisInstaller,
+            put_trie::InsertedTrieKeyAndMissingDescendants,
             upgrade::SystemUpgrader,
         },
         execution::{self, DirectSystemContractCall, Executor},
@@ -259,19 +261,24 @@ where
             return Err(Error::InvalidProtocolVersion(new_protocol_version));
         }
 
+        let system_upgrader: SystemUpgrader<S> = SystemUpgrader::new(
+            new_protocol_version,
+            current_protocol_data,
+            tracking_copy.clone(),
+        );
+
         // 3.1.1.1.1.5 bump system contract major versions
         if upgrade_check_result.is_major_version() {
-            let system_upgrader: SystemUpgrader<S> = SystemUpgrader::new(
-                new_protocol_version,
-                current_protocol_data,
-                tracking_copy.clone(),
-            );
-
             system_upgrader
                 .upgrade_system_contracts_major_version(correlation_id)
                 .map_err(Error::ProtocolUpgrade)?;
         }
 
+        // Install the entry points the system contracts gained since the network was created
+        system_upgrader
+            .refresh_system_contract_entry_points(correlation_id)
+            .map_err(Error::ProtocolUpgrade)?;
+
         // 3.1.1.1.1.6 resolve wasm CostTable for new protocol version
         let new_wasm_config = match upgrade_config.wasm_config() {
             Some(new_wasm_costs) => new_wasm_costs,
@@ -951,7 +958,7 @@ where
         // charge for anything that happens while calling transfer entrypoint.
         session_result = session_result.with_cost(Gas::default());
 
-        let finalize_result = {
+        let (maybe_payment_breakdown, finalize_result) = {
             let handle_payment_args = {
                 // Gas spent during payment code execution
                 let finalize_cost_motes = {
@@ -989,28 +996,30 @@ where
             let tc = tracking_copy.borrow();
             let finalization_tc = Rc::new(RefCell::new(tc.fork()));
 
-            let (_ret, finalize_result): (Option<()>, ExecutionResult) = executor
-                .exec_system_contract(
-                    DirectSystemContractCall::FinalizePayment,
-                    system_module,
-                    handle_payment_args,
-                    &mut handle_payment_named_keys,
-                    Default::default(),
-                    Key::from(protocol_data.handle_payment()),
-                    &system_account,
-                    authorization_keys,
-                    blocktime,
-                    deploy_item.deploy_hash,
-                    gas_limit,
-                    protocol_version,
-                    correlation_id,
-                    finalization_tc,
-                    Phase::FinalizePayment,
-                    protocol_data,
-                    SystemContractCache::clone(&self.system_contract_cache),
-                );
+            let (maybe_payment_breakdown, finalize_result): (
+                Option<PaymentBreakdown>,
+                ExecutionResult,
+            ) = executor.exec_system_contract(
+                DirectSystemContractCall::FinalizePayment,
+                system_module,
+                handle_payment_args,
+                &mut handle_payment_named_keys,
+                Default::default(),
+                Key::from(protocol_data.handle_payment()),
+                &system_account,
+                authorization_keys,
+                blocktime,
+                deploy_item.deploy_hash,
+                gas_limit,
+                protocol_version,
+                correlation_id,
+                finalization_tc,
+                Phase::FinalizePayment,
+                protocol_data,
+                SystemContractCache::clone(&self.system_contract_cache),
+            );
 
-            finalize_result
+            (maybe_payment_breakdown, finalize_result)
         };
 
         // Create + persist deploy info.
@@ -1038,6 +1047,9 @@ where
         execution_result_builder.set_payment_execution_result(payment_result);
         execution_result_builder.set_session_execution_result(session_result);
         execution_result_builder.set_finalize_execution_result(finalize_result);
+        if let Some(payment_breakdown) = maybe_payment_breakdown {
+            execution_result_builder.set_payment_breakdown(payment_breakdown);
+        }
 
         let execution_result = execution_result_builder
             .build(tracking_copy.borrow().reader(), correlation_id)
@@ -1606,26 +1618,32 @@ where
             let gas_limit = Gas::new(U512::from(std::u64::MAX));
             let system_contract_cache = SystemContractCache::clone(&self.system_contract_cache);
 
-            let (_ret, finalize_result): (Option<()>, ExecutionResult) = executor
-                .exec_system_contract(
-                    DirectSystemContractCall::FinalizePayment,
-                    system_module,
-                    handle_payment_args,
-                    &mut handle_payment_keys,
-                    Default::default(),
-                    Key::from(protocol_data.handle_payment()),
-                    &system_account,
-                    authorization_keys,
-                    blocktime,
-                    deploy_hash,
-                    gas_limit,
-                    protocol_version,
-                    correlation_id,
-                    finalization_tc,
-                    Phase::FinalizePayment,
-                    protocol_data,
-                    system_contract_cache,
-                );
+            let (maybe_payment_breakdown, finalize_result): (
+                Option<PaymentBreakdown>,
+                ExecutionResult,
+            ) = executor.exec_system_contract(
+                DirectSystemContractCall::FinalizePayment,
+                system_module,
+                handle_payment_args,
+                &mut handle_payment_keys,
+                Default::default(),
+                Key::from(protocol_data.handle_payment()),
+                &system_account,
+                authorization_keys,
+                blocktime,
+                deploy_hash,
+                gas_limit,
+                protocol_version,
+                correlation_id,
+                finalization_tc,
+                Phase::FinalizePayment,
+                protocol_data,
+                system_contract_cache,
+            );
+
+            if let Some(payment_breakdown) = maybe_payment_breakdown {
+                execution_result_builder.set_payment_breakdown(payment_breakdown);
+            }
 
             finalize_result
         };
@@ -1879,8 +1897,10 @@ where
         let mut named_keys = auction_contract.named_keys().to_owned();
         let gas_limit = Gas::new(U512::from(std::u64::MAX));
         let deploy_hash = {
-            // seeds address generator w/ protocol version
-            let bytes: Vec<u8> = step_request.protocol_version.value().into_bytes()?.to_vec();
+            // seeds address generator w/ protocol version and era end timestamp, so that the
+            // addresses of transfers executed by the step are unique across eras
+            let mut bytes: Vec<u8> = step_request.protocol_version.value().into_bytes()?.to_vec();
+            bytes.extend(step_request.era_end_timestamp_millis.into_bytes()?);
             DeployHash::new(Blake2bHash::new(&bytes).value())
         };
 
@@ -6,11 +6,20 @@ use thiserror::Error;
 use casper_types::{
     bytesrepr,
     system::{AUCTION, HANDLE_PAYMENT, MINT, STANDARD_PAYMENT},
-    ContractHash, Key, ProtocolVersion,
+    Contract, ContractHash, EntryPoints, Key, ProtocolVersion,
 };
 
 use crate::{
-    core::{engine_state::execution_effect::ExecutionEffect, tracking_copy::TrackingCopy},
+    core::{
+        engine_state::{
+            execution_effect::ExecutionEffect,
+            genesis::{
+                auction_entry_points, handle_payment_entry_points, mint_entry_points,
+                standard_payment_entry_points,
+            },
+        },
+        tracking_copy::TrackingCopy,
+    },
     shared::{
         newtypes::{Blake2bHash, CorrelationId},
         stored_value::StoredValue,
@@ -233,6 +242,81 @@ where
         Ok(())
     }
 
+    /// Replaces the entry points of the system contracts with the current ones, so that entry
+    /// points introduced after the network was created become callable.
+    pub(crate) fn refresh_system_contract_entry_points(
+        &self,
+        correlation_id: CorrelationId,
+    ) -> Result<(), ProtocolUpgradeError> {
+        self.refresh_entry_points(
+            correlation_id,
+            self.protocol_data.mint(),
+            MINT,
+            mint_entry_points(),
+        )?;
+        self.refresh_entry_points(
+            correlation_id,
+            self.protocol_data.auction(),
+            AUCTION,
+            auction_entry_points(),
+        )?;
+        self.refresh_entry_points(
+            correlation_id,
+            self.protocol_data.handle_payment(),
+            HANDLE_PAYMENT,
+            handle_payment_entry_points(),
+        )?;
+        self.refresh_entry_points(
+            correlation_id,
+            self.protocol_data.standard_payment(),
+            STANDARD_PAYMENT,
+            standard_payment_entry_points(),
+        )?;
+
+        Ok(())
+    }
+
+    fn refresh_entry_points(
+        &self,
+        correlation_id: CorrelationId,
+        contract_hash: ContractHash,
+        contract_name: &str,
+        entry_points: EntryPoints,
+    ) -> Result<(), ProtocolUpgradeError> {
+        let contract_key = Key::Hash(contract_hash.value());
+
+        let contract = if let StoredValue::Contract(contract) = self
+            .tracking_copy
+            .borrow_mut()
+            .read(correlation_id, &contract_key)
+            .map_err(|_| {
+                ProtocolUpgradeError::UnableToRetrieveSystemContract(contract_name.to_string())
+            })?
+            .ok_or_else(|| {
+                ProtocolUpgradeError::UnableToRetrieveSystemContract(contract_name.to_string())
+            })? {
+            contract
+        } else {
+            return Err(ProtocolUpgradeError::UnableToRetrieveSystemContract(
+                contract_name.to_string(),
+            ));
+        };
+
+        let contract = Contract::new(
+            contract.contract_package_hash(),
+            contract.contract_wasm_hash(),
+            contract.named_keys().clone(),
+            entry_points,
+            contract.protocol_version(),
+        );
+
+        self.tracking_copy
+            .borrow_mut()
+            .write(contract_key, StoredValue::Contract(contract));
+
+        Ok(())
+    }
+
     fn store_contract(
         &self,
         correlation_id: CorrelationId,
@@ -8,7 +8,11 @@ use casper_types::{
     account::AccountHash,
     bytesrepr::FromBytes,
     contracts::NamedKeys,
-    system::{auction, handle_payment, mint},
+    system::{
+        auction,
+        handle_payment::{self, PaymentBreakdown},
+        mint,
+    },
     BlockTime, CLTyped, CLValue, ContractPackage, DeployHash, EntryPoint, EntryPointType, Key,
     Phase, ProtocolVersion, RuntimeArgs,
 };
@@ -50,6 +54,7 @@ macro_rules! on_fail_charge {
                     effect: Default::default(),
                     transfers: $transfers,
                     cost: $cost,
+                    payment: PaymentBreakdown::default(),
                 };
             }
         }
@@ -65,6 +70,7 @@ macro_rules! on_fail_charge {
                     effect: $effect,
                     transfers: $transfers,
                     cost: $cost,
+                    payment: PaymentBreakdown::default(),
                 };
             }
         }
@@ -192,6 +198,7 @@ impl Executor {
                         effect: runtime.context().effect(),
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        payment: PaymentBreakdown::default(),
                     };
                 }
                 Err(error) => {
@@ -200,6 +207,7 @@ impl Executor {
                         effect: effects_snapshot,
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        payment: PaymentBreakdown::default(),
                     };
                 }
             }
@@ -216,6 +224,7 @@ impl Executor {
                         effect: runtime.context().effect(),
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        payment: PaymentBreakdown::default(),
                     };
                 }
                 Err(error) => {
@@ -224,6 +233,7 @@ impl Executor {
                         effect: effects_snapshot,
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        payment: PaymentBreakdown::default(),
                     };
                 }
             }
@@ -240,6 +250,7 @@ impl Executor {
                         effect: runtime.context().effect(),
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        payment: PaymentBreakdown::default(),
                     }
                 }
                 Err(error) => {
@@ -248,6 +259,7 @@ impl Executor {
                         effect: effects_snapshot,
                         transfers: runtime.context().transfers().to_owned(),
                         cost: runtime.context().gas_counter(),
+                        payment: PaymentBreakdown::default(),
                     }
                 }
             }
@@ -263,6 +275,7 @@ impl Executor {
             effect: runtime.context().effect(),
             transfers: runtime.context().transfers().to_owned(),
             cost: runtime.context().gas_counter(),
+            payment: PaymentBreakdown::default(),
         }
     }
 
@@ -331,6 +344,7 @@ impl Executor {
                     effect: Default::default(),
                     transfers: Vec::default(),
                     cost: Gas::default(),
+                    payment: PaymentBreakdown::default(),
                 };
             }
         };
@@ -342,12 +356,14 @@ impl Executor {
                 effect: runtime.context().effect(),
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                payment: PaymentBreakdown::default(),
             },
             Err(error) => ExecutionResult::Failure {
                 error: error.into(),
                 effect: effects_snapshot,
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                payment: PaymentBreakdown::default(),
             },
         }
     }
@@ -463,6 +479,7 @@ impl Executor {
                     effect: effect_snapshot,
                     transfers,
                     cost: gas_counter,
+                    payment: PaymentBreakdown::default(),
                     error: error.into(),
                 }
                 .take_without_ret()
@@ -733,6 +750,7 @@ impl DirectSystemContractCall {
                     effect: runtime.context().effect(),
                     transfers: runtime.context().transfers().to_owned(),
                     cost: runtime.context().gas_counter(),
+                    payment: PaymentBreakdown::default(),
                 }
                 .take_with_ret(ret),
                 Err(error) => ExecutionResult::Failure {
@@ -740,6 +758,7 @@ impl DirectSystemContractCall {
                     effect: execution_effect,
                     transfers: runtime.context().transfers().to_owned(),
                     cost: runtime.context().gas_counter(),
+                    payment: PaymentBreakdown::default(),
                 }
                 .take_without_ret(),
             },
@@ -748,6 +767,7 @@ impl DirectSystemContractCall {
                 effect: execution_effect,
                 transfers: runtime.context().transfers().to_owned(),
                 cost: runtime.context().gas_counter(),
+                payment: PaymentBreakdown::default(),
             }
             .take_without_ret(),
         }
@@ -1,6 +1,6 @@
 use tracing::warn;
 
-use casper_types::{Key, U512};
+use casper_types::{system::handle_payment::PaymentBreakdown, Key, U512};
 
 use super::Error;
 use crate::{
@@ -21,6 +21,7 @@ fn on_fail_charge_test_helper<T>(
         effect: Default::default(),
         transfers,
         cost: success_cost,
+        payment: PaymentBreakdown::default(),
     }
 }
 
@@ -67,6 +68,7 @@ fn on_fail_charge_with_action() {
             effect: Default::default(),
             transfers: Vec::default(),
             cost: Gas::default(),
+            payment: PaymentBreakdown::default(),
         }
     };
     match f() {
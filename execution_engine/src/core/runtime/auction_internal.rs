@@ -1,14 +1,19 @@
 use std::collections::BTreeSet;
 
+use tracing::warn;
+
 use casper_types::{
     account,
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
-    system::auction::{
-        AccountProvider, Auction, Bid, EraInfo, Error, MintProvider, RuntimeProvider,
-        StorageProvider, SystemProvider,
+    system::{
+        auction::{
+            AccountProvider, Auction, Bid, EraInfo, Error, MintProvider, RuntimeProvider,
+            StorageProvider, SystemProvider,
+        },
+        mint,
     },
-    CLTyped, CLValue, Key, KeyTag, TransferredTo, URef, BLAKE2B_DIGEST_LENGTH, U512,
+    CLTyped, CLValue, Key, KeyTag, TransferAddr, TransferredTo, URef, BLAKE2B_DIGEST_LENGTH, U512,
 };
 
 use super::Runtime;
@@ -33,6 +38,13 @@ where
     R: StateReader<Key, StoredValue>,
     R::Error: Into<execution::Error>,
 {
+    fn new_uref<T: ToBytes + CLTyped>(&mut self, init: T) -> Result<URef, Error> {
+        let cl_value = CLValue::from_t(init).map_err(|_| Error::CLValue)?;
+        self.context
+            .new_uref(StoredValue::CLValue(cl_value))
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
     fn read<T: FromBytes + CLTyped>(&mut self, uref: URef) -> Result<Option<T>, Error> {
         match self.context.read_gs(&uref.into()) {
             Ok(Some(StoredValue::CLValue(cl_value))) => {
@@ -111,6 +123,12 @@ where
         Runtime::record_era_info(self, era_id, era_info)
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::RecordEraInfo))
     }
+
+    fn scheduled_transfer_fee(&self) -> U512 {
+        // Executions run as part of the step, so the gas is paid for at a conversion rate of 1.
+        let auction_costs = self.protocol_data().system_config().auction_costs();
+        U512::from(auction_costs.scheduled_transfer_execution)
+    }
 }
 
 impl<'a, R> RuntimeProvider for Runtime<'a, R>
@@ -126,6 +144,12 @@ where
         self.context.named_keys_get(name).cloned()
     }
 
+    fn put_key(&mut self, name: &str, key: Key) -> Result<(), Error> {
+        self.context
+            .put_key(name.to_string(), key)
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::Storage))
+    }
+
     fn get_keys(&mut self, key_tag: &KeyTag) -> Result<BTreeSet<Key>, Error> {
         self.context.get_keys(key_tag).map_err(|_| Error::Storage)
     }
@@ -167,17 +191,60 @@ where
         }
     }
 
+    fn transfer_purse_to_purse_with_id(
+        &mut self,
+        source: URef,
+        target: URef,
+        amount: U512,
+        id: Option<u64>,
+    ) -> Result<Option<TransferAddr>, Error> {
+        let mint_contract_hash = self.get_mint_contract();
+        let transfer_count = self.context.transfers().len();
+        match self.mint_transfer(mint_contract_hash, None, source, target, amount, id) {
+            Ok(Ok(_)) => Ok(self.context.transfers().get(transfer_count).copied()),
+            Ok(Err(_mint_error)) => Err(Error::Transfer),
+            Err(exec_error) => Err(<Option<Error>>::from(exec_error).unwrap_or(Error::Transfer)),
+        }
+    }
+
     fn balance(&mut self, purse: URef) -> Result<Option<U512>, Error> {
         self.get_balance(purse)
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::GetBalance))
     }
 
+    fn burn(&mut self, purse: URef, amount: U512) -> Result<(), Error> {
+        let mint_contract = self.get_mint_contract();
+        self.mint_burn(mint_contract, purse, amount)
+            .map_err(|exec_error| {
+                warn!(?purse, %amount, "failed to burn: {:?}", exec_error);
+                <Option<Error>>::from(exec_error).unwrap_or(Error::MintReduceTotalSupply)
+            })
+    }
+
     fn read_base_round_reward(&mut self) -> Result<U512, Error> {
         let mint_contract = self.get_mint_contract();
         self.mint_read_base_round_reward(mint_contract)
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::MissingValue))
     }
 
+    fn read_total_supply(&mut self) -> Result<U512, Error> {
+        let mint_contract = self.get_mint_contract();
+        self.read_mint_named_value(mint_contract, mint::TOTAL_SUPPLY_KEY)
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::MissingValue))?
+            .ok_or(Error::MissingValue)
+    }
+
+    fn read_total_burned(&mut self) -> Result<U512, Error> {
+        // Nothing has been burned until the mint first creates the key.
+        let mint_contract = self.get_mint_contract();
+        let total_burned = self
+            .read_mint_named_value(mint_contract, mint::TOTAL_BURNED_KEY)
+            .map_err(|exec_error| {
+                <Option<Error>>::from(exec_error).unwrap_or(Error::MissingValue)
+            })?;
+        Ok(total_burned.unwrap_or_default())
+    }
+
     fn mint(&mut self, amount: U512) -> Result<URef, Error> {
         let mint_contract = self.get_mint_contract();
         self.mint_mint(mint_contract, amount)
@@ -1,3 +1,5 @@
+use num_rational::Ratio;
+
 use casper_types::{
     account::AccountHash,
     system::handle_payment::{Error, HandlePayment, MintProvider, RuntimeProvider},
@@ -60,6 +62,12 @@ where
         self.get_balance(purse)
             .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::GetBalance))
     }
+
+    fn burn(&mut self, purse: URef, amount: U512) -> Result<(), Error> {
+        let mint_contract = self.get_mint_contract();
+        self.mint_burn(mint_contract, purse, amount)
+            .map_err(|exec_error| <Option<Error>>::from(exec_error).unwrap_or(Error::FailedToBurn))
+    }
 }
 
 // TODO: Update RuntimeProvider to better handle errors
@@ -95,6 +103,20 @@ where
     fn get_caller(&self) -> AccountHash {
         self.context.get_caller()
     }
+
+    fn get_refund_ratio(&self) -> Ratio<u64> {
+        self.protocol_data()
+            .system_config()
+            .payment_split()
+            .refund_ratio
+    }
+
+    fn get_burn_ratio(&self) -> Ratio<u64> {
+        self.protocol_data()
+            .system_config()
+            .payment_split()
+            .burn_ratio
+    }
 }
 
 impl<'a, R> HandlePayment for Runtime<'a, R>
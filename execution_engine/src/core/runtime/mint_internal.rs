@@ -2,7 +2,7 @@ use casper_types::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::mint::{Error, Mint, RuntimeProvider, StorageProvider, SystemProvider},
-    CLTyped, CLValue, Key, URef, U512,
+    BlockTime, CLTyped, CLValue, Key, URef, U512,
 };
 
 use super::Runtime;
@@ -31,6 +31,10 @@ where
         self.context.get_caller()
     }
 
+    fn get_blocktime(&self) -> BlockTime {
+        self.context.get_blocktime()
+    }
+
     fn put_key(&mut self, name: &str, key: Key) -> Result<(), Error> {
         self.context
             .put_key(name.to_string(), key)
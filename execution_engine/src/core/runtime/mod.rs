@@ -32,9 +32,10 @@ use casper_types::{
         standard_payment::{self, StandardPayment},
         SystemContractType,
     },
-    AccessRights, ApiError, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash,
+    AccessRights, ApiError, BlockTime, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash,
     ContractVersionKey, ContractWasm, DeployHash, EntryPointType, Key, Phase, ProtocolVersion,
-    PublicKey, RuntimeArgs, Transfer, TransferResult, TransferredTo, URef, U128, U256, U512,
+    PublicKey, RuntimeArgs, Transfer, TransferResult, TransferredTo, URef, URefAddr, U128, U256,
+    U512,
 };
 
 use crate::{
@@ -1311,6 +1312,13 @@ where
         };
         let authorization_keys = self.context.authorization_keys().to_owned();
         let account = self.context.account();
+        // The account or contract calling the mint, on whose behalf allowances are spent. When the
+        // mint is called directly by a deploy, the runtime's base key is the mint itself.
+        let caller_key = if self.is_mint(self.context.base_key()) {
+            Key::Account(self.context.get_caller())
+        } else {
+            self.context.base_key()
+        };
         let base_key = self.protocol_data().mint().into();
         let blocktime = self.context.get_blocktime();
         let deploy_hash = self.context.get_deploy_hash();
@@ -1377,6 +1385,16 @@ where
                 let result: Result<(), mint::Error> = mint_runtime.reduce_total_supply(amount);
                 CLValue::from_t(result).map_err(Self::reverter)
             })(),
+            // Type: `fn burn(purse: URef, amount: U512) -> Result<(), Error>`
+            mint::METHOD_BURN => (|| {
+                // Burning is a balance write on top of a total supply reduction
+                mint_runtime.charge_system_contract_call(mint_costs.reduce_total_supply)?;
+
+                let purse: URef = Self::get_named_argument(&runtime_args, mint::ARG_PURSE)?;
+                let amount: U512 = Self::get_named_argument(&runtime_args, mint::ARG_AMOUNT)?;
+                let result: Result<(), mint::Error> = Mint::burn(&mut mint_runtime, purse, amount);
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
             // Type: `fn create() -> URef`
             mint::METHOD_CREATE => (|| {
                 mint_runtime.charge_system_contract_call(mint_costs.create)?;
@@ -1408,6 +1426,43 @@ where
                     mint_runtime.transfer(maybe_to, source, target, amount, id);
                 CLValue::from_t(result).map_err(Self::reverter)
             })(),
+            // Type: `fn approve(purse: URef, spender: Key, amount: U512, expiry: u64) -> Result<(),
+            // Error>`
+            mint::METHOD_APPROVE => (|| {
+                mint_runtime.charge_system_contract_call(mint_costs.approve)?;
+
+                let purse: URef = Self::get_named_argument(&runtime_args, mint::ARG_PURSE)?;
+                let spender: Key = Self::get_named_argument(&runtime_args, mint::ARG_SPENDER)?;
+                let amount: U512 = Self::get_named_argument(&runtime_args, mint::ARG_AMOUNT)?;
+                let expiry: u64 = Self::get_named_argument(&runtime_args, mint::ARG_EXPIRY)?;
+                let result: Result<(), mint::Error> =
+                    mint_runtime.approve(purse, spender, amount, BlockTime::new(expiry));
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+            // Type: `fn allowance(purse: URefAddr, spender: Key) -> Result<U512, Error>`
+            mint::METHOD_ALLOWANCE => (|| {
+                mint_runtime.charge_system_contract_call(mint_costs.allowance)?;
+
+                let purse: URefAddr = Self::get_named_argument(&runtime_args, mint::ARG_PURSE)?;
+                let spender: Key = Self::get_named_argument(&runtime_args, mint::ARG_SPENDER)?;
+                let result: Result<U512, mint::Error> = mint_runtime.allowance(purse, spender);
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+            // Type: `fn transfer_from(maybe_to: Option<AccountHash>, source: URefAddr, target:
+            // URef, amount: U512, id: Option<u64>) -> Result<(), Error>`
+            mint::METHOD_TRANSFER_FROM => (|| {
+                mint_runtime.charge_system_contract_call(mint_costs.transfer_from)?;
+
+                let maybe_to: Option<AccountHash> =
+                    Self::get_named_argument(&runtime_args, mint::ARG_TO)?;
+                let source: URefAddr = Self::get_named_argument(&runtime_args, mint::ARG_SOURCE)?;
+                let target: URef = Self::get_named_argument(&runtime_args, mint::ARG_TARGET)?;
+                let amount: U512 = Self::get_named_argument(&runtime_args, mint::ARG_AMOUNT)?;
+                let id: Option<u64> = Self::get_named_argument(&runtime_args, mint::ARG_ID)?;
+                let result: Result<(), mint::Error> =
+                    mint_runtime.transfer_from(caller_key, maybe_to, source, target, amount, id);
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
             // Type: `fn read_base_round_reward() -> Result<U512, Error>`
             mint::METHOD_READ_BASE_ROUND_REWARD => (|| {
                 mint_runtime.charge_system_contract_call(mint_costs.read_base_round_reward)?;
@@ -1417,6 +1472,20 @@ where
                     .map_err(Self::reverter)?;
                 CLValue::from_t(result).map_err(Self::reverter)
             })(),
+            // Type: `fn read_total_supply() -> Result<U512, Error>`
+            mint::METHOD_READ_TOTAL_SUPPLY => (|| {
+                mint_runtime.charge_system_contract_call(mint_costs.read_total_supply)?;
+
+                let result: U512 = mint_runtime.read_total_supply().map_err(Self::reverter)?;
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+            // Type: `fn read_total_burned() -> Result<U512, Error>`
+            mint::METHOD_READ_TOTAL_BURNED => (|| {
+                mint_runtime.charge_system_contract_call(mint_costs.read_total_burned)?;
+
+                let result: U512 = mint_runtime.read_total_burned().map_err(Self::reverter)?;
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
 
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
@@ -1534,10 +1603,10 @@ where
                     Self::get_named_argument(&runtime_args, handle_payment::ARG_ACCOUNT)?;
                 let target: URef =
                     Self::get_named_argument(&runtime_args, handle_payment::ARG_TARGET)?;
-                runtime
+                let payment_breakdown = runtime
                     .finalize_payment(amount_spent, account, target)
                     .map_err(Self::reverter)?;
-                CLValue::from_t(()).map_err(Self::reverter)
+                CLValue::from_t(payment_breakdown).map_err(Self::reverter)
             })(),
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
@@ -1706,6 +1775,22 @@ where
                 CLValue::from_t(result).map_err(Self::reverter)
             })(),
 
+            auction::METHOD_REDELEGATE => (|| {
+                runtime.charge_system_contract_call(auction_costs.redelegate)?;
+
+                let delegator = Self::get_named_argument(&runtime_args, auction::ARG_DELEGATOR)?;
+                let validator = Self::get_named_argument(&runtime_args, auction::ARG_VALIDATOR)?;
+                let amount = Self::get_named_argument(&runtime_args, auction::ARG_AMOUNT)?;
+                let new_validator =
+                    Self::get_named_argument(&runtime_args, auction::ARG_NEW_VALIDATOR)?;
+
+                let result = runtime
+                    .redelegate(delegator, validator, amount, new_validator)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
             auction::METHOD_RUN_AUCTION => (|| {
                 runtime.charge_system_contract_call(auction_costs.run_auction)?;
 
@@ -1764,6 +1849,82 @@ where
                 CLValue::from_t(()).map_err(Self::reverter)
             })(),
 
+            auction::METHOD_SET_AUTO_COMPOUND => (|| {
+                runtime.charge_system_contract_call(auction_costs.set_auto_compound)?;
+
+                let public_key = Self::get_named_argument(&runtime_args, auction::ARG_PUBLIC_KEY)?;
+                let validator = Self::get_named_argument(&runtime_args, auction::ARG_VALIDATOR)?;
+                let auto_compound =
+                    Self::get_named_argument(&runtime_args, auction::ARG_AUTO_COMPOUND)?;
+
+                runtime
+                    .set_auto_compound(public_key, validator, auto_compound)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_SET_MINIMUM_DELEGATION_AMOUNT => (|| {
+                runtime.charge_system_contract_call(auction_costs.set_minimum_delegation_amount)?;
+
+                let public_key = Self::get_named_argument(&runtime_args, auction::ARG_PUBLIC_KEY)?;
+                let minimum_delegation_amount = Self::get_named_argument(
+                    &runtime_args,
+                    auction::ARG_MINIMUM_DELEGATION_AMOUNT,
+                )?;
+
+                runtime
+                    .set_minimum_delegation_amount(public_key, minimum_delegation_amount)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_SET_BID_METADATA => (|| {
+                runtime.charge_system_contract_call(auction_costs.set_bid_metadata)?;
+
+                let public_key = Self::get_named_argument(&runtime_args, auction::ARG_PUBLIC_KEY)?;
+                let name = Self::get_named_argument(&runtime_args, auction::ARG_NAME)?;
+                let url = Self::get_named_argument(&runtime_args, auction::ARG_URL)?;
+                let contact = Self::get_named_argument(&runtime_args, auction::ARG_CONTACT)?;
+
+                runtime
+                    .set_bid_metadata(public_key, name, url, contact)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(()).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_SCHEDULE_TRANSFER => (|| {
+                runtime.charge_system_contract_call(auction_costs.schedule_transfer)?;
+
+                let target = Self::get_named_argument(&runtime_args, auction::ARG_TARGET)?;
+                let amount = Self::get_named_argument(&runtime_args, auction::ARG_AMOUNT)?;
+                let era_id = Self::get_named_argument(&runtime_args, auction::ARG_ERA_ID)?;
+                let interval = Self::get_named_argument(&runtime_args, auction::ARG_INTERVAL)?;
+                let count = Self::get_named_argument(&runtime_args, auction::ARG_COUNT)?;
+                let id = Self::get_named_argument(&runtime_args, auction::ARG_ID)?;
+
+                let result = runtime
+                    .schedule_transfer(target, amount, era_id, interval, count, id)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
+            auction::METHOD_CANCEL_SCHEDULED_TRANSFER => (|| {
+                runtime.charge_system_contract_call(auction_costs.cancel_scheduled_transfer)?;
+
+                let escrow_purse =
+                    Self::get_named_argument(&runtime_args, auction::ARG_ESCROW_PURSE)?;
+
+                let result = runtime
+                    .cancel_scheduled_transfer(escrow_purse)
+                    .map_err(Self::reverter)?;
+
+                CLValue::from_t(result).map_err(Self::reverter)
+            })(),
+
             _ => CLValue::from_t(()).map_err(Self::reverter),
         };
 
@@ -2748,6 +2909,38 @@ where
         Ok(reward)
     }
 
+    /// Reads the value stored under one of the mint contract's named keys without calling into
+    /// the mint, so it doesn't depend on which entry points the installed mint exposes.
+    ///
+    /// Returns `None` if the mint has no such named key.
+    fn read_mint_named_value(
+        &mut self,
+        mint_contract_hash: ContractHash,
+        name: &str,
+    ) -> Result<Option<U512>, Error> {
+        let key = mint_contract_hash.into();
+        let mint_contract = match self.context.read_gs_direct(&key)? {
+            Some(StoredValue::Contract(contract)) => contract,
+            Some(_) => {
+                return Err(Error::FunctionNotFound(format!(
+                    "Value at {:?} is not a contract",
+                    key
+                )));
+            }
+            None => return Err(Error::KeyNotFound(key)),
+        };
+        let uref = match mint_contract.named_keys().get(name) {
+            Some(Key::URef(uref)) => *uref,
+            Some(other_key) => return Err(Error::KeyIsNotAURef(*other_key)),
+            None => return Ok(None),
+        };
+        match self.context.read_gs_direct(&uref.into())? {
+            Some(StoredValue::CLValue(cl_value)) => Ok(Some(cl_value.into_t()?)),
+            Some(_) => Err(Error::UnexpectedStoredValueVariant),
+            None => Ok(None),
+        }
+    }
+
     /// Calls the `mint` method on the mint contract at the given mint
     /// contract key
     fn mint_mint(&mut self, mint_contract_hash: ContractHash, amount: U512) -> Result<URef, Error> {
@@ -2788,6 +2981,28 @@ where
         Ok(result.map_err(system::Error::from)?)
     }
 
+    /// Calls the `burn` method on the mint contract at the given mint
+    /// contract key
+    fn mint_burn(
+        &mut self,
+        mint_contract_hash: ContractHash,
+        purse: URef,
+        amount: U512,
+    ) -> Result<(), Error> {
+        let gas_counter = self.gas_counter();
+        let runtime_args = {
+            let mut runtime_args = RuntimeArgs::new();
+            runtime_args.insert(mint::ARG_PURSE, purse)?;
+            runtime_args.insert(mint::ARG_AMOUNT, amount)?;
+            runtime_args
+        };
+        let call_result = self.call_contract(mint_contract_hash, mint::METHOD_BURN, runtime_args);
+        self.set_gas_counter(gas_counter);
+
+        let result: Result<(), mint::Error> = call_result?.into_t()?;
+        Ok(result.map_err(system::Error::from)?)
+    }
+
     /// Calls the "create" method on the mint contract at the given mint
     /// contract key
     fn mint_create(&mut self, mint_contract_hash: ContractHash) -> Result<URef, Error> {
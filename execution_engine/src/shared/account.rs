@@ -3,6 +3,9 @@ mod associated_keys;
 
 use std::collections::BTreeSet;
 
+use casper_node_types::json_compatibility::{
+    self, ActionThresholds as JsonActionThresholds, AssociatedKey as JsonAssociatedKey,
+};
 use casper_types::{
     account::{
         AccountHash, ActionType, AddKeyFailure, RemoveKeyFailure, SetThresholdFailure,
@@ -247,6 +250,27 @@ impl FromBytes for Account {
     }
 }
 
+impl From<&Account> for json_compatibility::Account {
+    fn from(ee_account: &Account) -> Self {
+        json_compatibility::Account::new(
+            ee_account.account_hash(),
+            json_compatibility::vectorize(ee_account.named_keys()),
+            ee_account.main_purse(),
+            ee_account
+                .associated_keys()
+                .map(|(account_hash, weight)| JsonAssociatedKey {
+                    account_hash: *account_hash,
+                    weight: weight.value(),
+                })
+                .collect(),
+            JsonActionThresholds {
+                deployment: ee_account.action_thresholds().deployment().value(),
+                key_management: ee_account.action_thresholds().key_management().value(),
+            },
+        )
+    }
+}
+
 #[cfg(any(feature = "gens", test))]
 pub mod gens {
     use proptest::prelude::*;
@@ -7,6 +7,7 @@ use blake2::{
 };
 use serde::{Deserialize, Serialize};
 
+use casper_node_types::crypto::hash::Digest;
 use casper_types::bytesrepr::{self, FromBytes, ToBytes};
 
 /// Represents a 32-byte BLAKE2b hash digest
@@ -91,6 +92,20 @@ impl AsRef<[u8]> for Blake2bHash {
     }
 }
 
+impl From<Digest> for Blake2bHash {
+    fn from(digest: Digest) -> Self {
+        let digest_bytes = digest.to_array();
+        Blake2bHash::from(digest_bytes)
+    }
+}
+
+impl From<Blake2bHash> for Digest {
+    fn from(blake2bhash: Blake2bHash) -> Self {
+        let bytes = blake2bhash.value();
+        Digest::from(bytes)
+    }
+}
+
 impl Into<[u8; Blake2bHash::LENGTH]> for Blake2bHash {
     fn into(self) -> [u8; Blake2bHash::LENGTH] {
         self.0
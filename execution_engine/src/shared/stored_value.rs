@@ -3,6 +3,7 @@ use std::{convert::TryFrom, fmt::Debug};
 use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 use serde_bytes::ByteBuf;
 
+use casper_node_types::json_compatibility;
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
     contracts::ContractPackage,
@@ -247,6 +248,42 @@ impl TryFrom<StoredValue> for EraInfo {
     }
 }
 
+impl TryFrom<&StoredValue> for json_compatibility::StoredValue {
+    type Error = bytesrepr::Error;
+
+    fn try_from(ee_stored_value: &StoredValue) -> Result<Self, Self::Error> {
+        let stored_value = match ee_stored_value {
+            StoredValue::CLValue(cl_value) => {
+                json_compatibility::StoredValue::CLValue(cl_value.clone())
+            }
+            StoredValue::Account(account) => {
+                json_compatibility::StoredValue::Account(account.into())
+            }
+            StoredValue::ContractWasm(contract_wasm) => {
+                json_compatibility::StoredValue::ContractWasm(hex::encode(
+                    &contract_wasm.to_bytes()?,
+                ))
+            }
+            StoredValue::Contract(contract) => {
+                json_compatibility::StoredValue::Contract(contract.into())
+            }
+            StoredValue::ContractPackage(contract_package) => {
+                json_compatibility::StoredValue::ContractPackage(contract_package.into())
+            }
+            StoredValue::Transfer(transfer) => json_compatibility::StoredValue::Transfer(*transfer),
+            StoredValue::DeployInfo(deploy_info) => {
+                json_compatibility::StoredValue::DeployInfo(deploy_info.clone())
+            }
+            StoredValue::EraInfo(era_info) => {
+                json_compatibility::StoredValue::EraInfo(era_info.clone())
+            }
+            StoredValue::Bid(bid) => json_compatibility::StoredValue::Bid(bid.clone()),
+        };
+
+        Ok(stored_value)
+    }
+}
+
 impl ToBytes for StoredValue {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
@@ -1,6 +1,7 @@
 pub mod auction_costs;
 pub mod handle_payment_costs;
 pub mod mint_costs;
+pub mod payment_split;
 pub mod standard_payment_costs;
 
 use datasize::DataSize;
@@ -11,10 +12,17 @@ use casper_types::bytesrepr::{self, FromBytes, ToBytes};
 
 use self::{
     auction_costs::AuctionCosts, handle_payment_costs::HandlePaymentCosts, mint_costs::MintCosts,
-    standard_payment_costs::StandardPaymentCosts,
+    payment_split::PaymentSplit, standard_payment_costs::StandardPaymentCosts,
 };
 use crate::storage::protocol_data::DEFAULT_WASMLESS_TRANSFER_COST;
 
+/// Precedes the versioned encoding of a `SystemConfig`.
+///
+/// Encodings without it start with the wasmless transfer cost and predate the payment split.
+const VERSIONED_ENCODING_MARKER: u32 = u32::MAX;
+/// The current version of the encoding of a `SystemConfig`.
+const ENCODING_VERSION: u8 = 1;
+
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
 pub struct SystemConfig {
     /// Wasmless transfer cost expressed in gas.
@@ -31,6 +39,9 @@ pub struct SystemConfig {
 
     /// Configuration of standard payment costs.
     standard_payment_costs: StandardPaymentCosts,
+
+    /// Configuration of how payment is split between refund, proposer and burn.
+    payment_split: PaymentSplit,
 }
 
 impl SystemConfig {
@@ -40,6 +51,7 @@ impl SystemConfig {
         mint_costs: MintCosts,
         handle_payment_costs: HandlePaymentCosts,
         standard_payment_costs: StandardPaymentCosts,
+        payment_split: PaymentSplit,
     ) -> Self {
         Self {
             wasmless_transfer_cost,
@@ -47,6 +59,7 @@ impl SystemConfig {
             mint_costs,
             handle_payment_costs,
             standard_payment_costs,
+            payment_split,
         }
     }
 
@@ -69,6 +82,10 @@ impl SystemConfig {
     pub fn standard_payment_costs(&self) -> &StandardPaymentCosts {
         &self.standard_payment_costs
     }
+
+    pub fn payment_split(&self) -> &PaymentSplit {
+        &self.payment_split
+    }
 }
 
 impl Default for SystemConfig {
@@ -79,6 +96,7 @@ impl Default for SystemConfig {
             mint_costs: MintCosts::default(),
             handle_payment_costs: HandlePaymentCosts::default(),
             standard_payment_costs: StandardPaymentCosts::default(),
+            payment_split: PaymentSplit::default(),
         }
     }
 }
@@ -91,6 +109,7 @@ impl Distribution<SystemConfig> for Standard {
             mint_costs: rng.gen(),
             handle_payment_costs: rng.gen(),
             standard_payment_costs: rng.gen(),
+            payment_split: rng.gen(),
         }
     }
 }
@@ -99,31 +118,53 @@ impl ToBytes for SystemConfig {
     fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
         let mut ret = bytesrepr::unchecked_allocate_buffer(self);
 
+        ret.append(&mut VERSIONED_ENCODING_MARKER.to_bytes()?);
+        ret.append(&mut ENCODING_VERSION.to_bytes()?);
         ret.append(&mut self.wasmless_transfer_cost.to_bytes()?);
         ret.append(&mut self.auction_costs.to_bytes()?);
         ret.append(&mut self.mint_costs.to_bytes()?);
         ret.append(&mut self.handle_payment_costs.to_bytes()?);
         ret.append(&mut self.standard_payment_costs.to_bytes()?);
+        ret.append(&mut self.payment_split.to_bytes()?);
 
         Ok(ret)
     }
 
     fn serialized_length(&self) -> usize {
-        self.wasmless_transfer_cost.serialized_length()
+        VERSIONED_ENCODING_MARKER.serialized_length()
+            + ENCODING_VERSION.serialized_length()
+            + self.wasmless_transfer_cost.serialized_length()
             + self.auction_costs.serialized_length()
             + self.mint_costs.serialized_length()
             + self.handle_payment_costs.serialized_length()
             + self.standard_payment_costs.serialized_length()
+            + self.payment_split.serialized_length()
     }
 }
 
 impl FromBytes for SystemConfig {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
-        let (wasmless_transfer_cost, rem) = FromBytes::from_bytes(bytes)?;
+        let (marker, rem) = u32::from_bytes(bytes)?;
+        let (is_legacy, rem) = if marker == VERSIONED_ENCODING_MARKER {
+            let (version, rem) = u8::from_bytes(rem)?;
+            if version != ENCODING_VERSION {
+                return Err(bytesrepr::Error::Formatting);
+            }
+            (false, rem)
+        } else {
+            (true, bytes)
+        };
+        let (wasmless_transfer_cost, rem) = FromBytes::from_bytes(rem)?;
         let (auction_costs, rem) = FromBytes::from_bytes(rem)?;
         let (mint_costs, rem) = FromBytes::from_bytes(rem)?;
         let (handle_payment_costs, rem) = FromBytes::from_bytes(rem)?;
         let (standard_payment_costs, rem) = FromBytes::from_bytes(rem)?;
+        // Configs stored before the payment split was introduced keep the previous behavior.
+        let (payment_split, rem) = if is_legacy {
+            (PaymentSplit::default(), rem)
+        } else {
+            FromBytes::from_bytes(rem)?
+        };
         Ok((
             SystemConfig::new(
                 wasmless_transfer_cost,
@@ -131,6 +172,7 @@ impl FromBytes for SystemConfig {
                 mint_costs,
                 handle_payment_costs,
                 standard_payment_costs,
+                payment_split,
             ),
             rem,
         ))
@@ -144,6 +186,7 @@ pub mod gens {
     use super::{
         auction_costs::gens::auction_costs_arb,
         handle_payment_costs::gens::handle_payment_costs_arb, mint_costs::gens::mint_costs_arb,
+        payment_split::gens::payment_split_arb,
         standard_payment_costs::gens::standard_payment_costs_arb, SystemConfig,
     };
 
@@ -154,6 +197,7 @@ pub mod gens {
             mint_costs in mint_costs_arb(),
             handle_payment_costs in handle_payment_costs_arb(),
             standard_payment_costs in standard_payment_costs_arb(),
+            payment_split in payment_split_arb(),
         ) -> SystemConfig {
             SystemConfig {
                 wasmless_transfer_cost,
@@ -161,6 +205,7 @@ pub mod gens {
                 mint_costs,
                 handle_payment_costs,
                 standard_payment_costs,
+                payment_split,
             }
         }
     }
@@ -16,6 +16,13 @@ pub const DEFAULT_WITHDRAW_DELEGATOR_REWARD_COST: u32 = 10_000;
 pub const DEFAULT_WITHDRAW_VALIDATOR_REWARD_COST: u32 = 10_000;
 pub const DEFAULT_READ_ERA_ID_COST: u32 = 10_000;
 pub const DEFAULT_ACTIVATE_BID_COST: u32 = 10_000;
+pub const DEFAULT_REDELEGATE_COST: u32 = 10_000;
+pub const DEFAULT_SET_AUTO_COMPOUND_COST: u32 = 10_000;
+pub const DEFAULT_SET_MINIMUM_DELEGATION_AMOUNT_COST: u32 = 10_000;
+pub const DEFAULT_SET_BID_METADATA_COST: u32 = 10_000;
+pub const DEFAULT_SCHEDULE_TRANSFER_COST: u32 = 10_000;
+pub const DEFAULT_CANCEL_SCHEDULED_TRANSFER_COST: u32 = 10_000;
+pub const DEFAULT_SCHEDULED_TRANSFER_EXECUTION_COST: u32 = 10_000;
 
 /// Description of costs of calling auction entrypoints.
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
@@ -33,6 +40,13 @@ pub struct AuctionCosts {
     pub withdraw_validator_reward: u32,
     pub read_era_id: u32,
     pub activate_bid: u32,
+    pub redelegate: u32,
+    pub set_auto_compound: u32,
+    pub set_minimum_delegation_amount: u32,
+    pub set_bid_metadata: u32,
+    pub schedule_transfer: u32,
+    pub cancel_scheduled_transfer: u32,
+    pub scheduled_transfer_execution: u32,
 }
 
 impl Default for AuctionCosts {
@@ -51,6 +65,13 @@ impl Default for AuctionCosts {
             withdraw_validator_reward: DEFAULT_WITHDRAW_VALIDATOR_REWARD_COST,
             read_era_id: DEFAULT_READ_ERA_ID_COST,
             activate_bid: DEFAULT_ACTIVATE_BID_COST,
+            redelegate: DEFAULT_REDELEGATE_COST,
+            set_auto_compound: DEFAULT_SET_AUTO_COMPOUND_COST,
+            set_minimum_delegation_amount: DEFAULT_SET_MINIMUM_DELEGATION_AMOUNT_COST,
+            set_bid_metadata: DEFAULT_SET_BID_METADATA_COST,
+            schedule_transfer: DEFAULT_SCHEDULE_TRANSFER_COST,
+            cancel_scheduled_transfer: DEFAULT_CANCEL_SCHEDULED_TRANSFER_COST,
+            scheduled_transfer_execution: DEFAULT_SCHEDULED_TRANSFER_EXECUTION_COST,
         }
     }
 }
@@ -72,6 +93,13 @@ impl ToBytes for AuctionCosts {
         ret.append(&mut self.withdraw_validator_reward.to_bytes()?);
         ret.append(&mut self.read_era_id.to_bytes()?);
         ret.append(&mut self.activate_bid.to_bytes()?);
+        ret.append(&mut self.redelegate.to_bytes()?);
+        ret.append(&mut self.set_auto_compound.to_bytes()?);
+        ret.append(&mut self.set_minimum_delegation_amount.to_bytes()?);
+        ret.append(&mut self.set_bid_metadata.to_bytes()?);
+        ret.append(&mut self.schedule_transfer.to_bytes()?);
+        ret.append(&mut self.cancel_scheduled_transfer.to_bytes()?);
+        ret.append(&mut self.scheduled_transfer_execution.to_bytes()?);
 
         Ok(ret)
     }
@@ -90,6 +118,13 @@ impl ToBytes for AuctionCosts {
             + self.withdraw_validator_reward.serialized_length()
             + self.read_era_id.serialized_length()
             + self.activate_bid.serialized_length()
+            + self.redelegate.serialized_length()
+            + self.set_auto_compound.serialized_length()
+            + self.set_minimum_delegation_amount.serialized_length()
+            + self.set_bid_metadata.serialized_length()
+            + self.schedule_transfer.serialized_length()
+            + self.cancel_scheduled_transfer.serialized_length()
+            + self.scheduled_transfer_execution.serialized_length()
     }
 }
 
@@ -108,6 +143,13 @@ impl FromBytes for AuctionCosts {
         let (withdraw_validator_reward, rem) = FromBytes::from_bytes(rem)?;
         let (read_era_id, rem) = FromBytes::from_bytes(rem)?;
         let (activate_bid, rem) = FromBytes::from_bytes(rem)?;
+        let (redelegate, rem) = FromBytes::from_bytes(rem)?;
+        let (set_auto_compound, rem) = FromBytes::from_bytes(rem)?;
+        let (set_minimum_delegation_amount, rem) = FromBytes::from_bytes(rem)?;
+        let (set_bid_metadata, rem) = FromBytes::from_bytes(rem)?;
+        let (schedule_transfer, rem) = FromBytes::from_bytes(rem)?;
+        let (cancel_scheduled_transfer, rem) = FromBytes::from_bytes(rem)?;
+        let (scheduled_transfer_execution, rem) = FromBytes::from_bytes(rem)?;
         Ok((
             Self {
                 get_era_validators,
@@ -123,6 +165,13 @@ impl FromBytes for AuctionCosts {
                 withdraw_validator_reward,
                 read_era_id,
                 activate_bid,
+                redelegate,
+                set_auto_compound,
+                set_minimum_delegation_amount,
+                set_bid_metadata,
+                schedule_transfer,
+                cancel_scheduled_transfer,
+                scheduled_transfer_execution,
             },
             rem,
         ))
@@ -145,6 +194,13 @@ impl Distribution<AuctionCosts> for Standard {
             withdraw_validator_reward: rng.gen(),
             read_era_id: rng.gen(),
             activate_bid: rng.gen(),
+            redelegate: rng.gen(),
+            set_auto_compound: rng.gen(),
+            set_minimum_delegation_amount: rng.gen(),
+            set_bid_metadata: rng.gen(),
+            schedule_transfer: rng.gen(),
+            cancel_scheduled_transfer: rng.gen(),
+            scheduled_transfer_execution: rng.gen(),
         }
     }
 }
@@ -170,6 +226,13 @@ pub mod gens {
             withdraw_validator_reward in num::u32::ANY,
             read_era_id in num::u32::ANY,
             activate_bid in num::u32::ANY,
+            redelegate in num::u32::ANY,
+            set_auto_compound in num::u32::ANY,
+            set_minimum_delegation_amount in num::u32::ANY,
+            set_bid_metadata in num::u32::ANY,
+            schedule_transfer in num::u32::ANY,
+            cancel_scheduled_transfer in num::u32::ANY,
+            scheduled_transfer_execution in num::u32::ANY,
         ) -> AuctionCosts {
             AuctionCosts {
                 get_era_validators,
@@ -185,6 +248,13 @@ pub mod gens {
                 withdraw_validator_reward,
                 read_era_id,
                 activate_bid,
+                redelegate,
+                set_auto_compound,
+                set_minimum_delegation_amount,
+                set_bid_metadata,
+                schedule_transfer,
+                cancel_scheduled_transfer,
+                scheduled_transfer_execution,
             }
         }
     }
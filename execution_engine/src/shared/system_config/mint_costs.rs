@@ -9,6 +9,11 @@ pub const DEFAULT_CREATE_COST: u32 = 10_000;
 pub const DEFAULT_BALANCE_COST: u32 = 10_000;
 pub const DEFAULT_TRANSFER_COST: u32 = 10_000;
 pub const DEFAULT_READ_BASE_ROUND_REWARD_COST: u32 = 10_000;
+pub const DEFAULT_APPROVE_COST: u32 = 10_000;
+pub const DEFAULT_ALLOWANCE_COST: u32 = 10_000;
+pub const DEFAULT_TRANSFER_FROM_COST: u32 = 10_000;
+pub const DEFAULT_READ_TOTAL_SUPPLY_COST: u32 = 10_000;
+pub const DEFAULT_READ_TOTAL_BURNED_COST: u32 = 10_000;
 
 /// Description of costs of calling mint entrypoints.
 #[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
@@ -19,6 +24,11 @@ pub struct MintCosts {
     pub balance: u32,
     pub transfer: u32,
     pub read_base_round_reward: u32,
+    pub approve: u32,
+    pub allowance: u32,
+    pub transfer_from: u32,
+    pub read_total_supply: u32,
+    pub read_total_burned: u32,
 }
 
 impl Default for MintCosts {
@@ -30,6 +40,11 @@ impl Default for MintCosts {
             balance: DEFAULT_BALANCE_COST,
             transfer: DEFAULT_TRANSFER_COST,
             read_base_round_reward: DEFAULT_READ_BASE_ROUND_REWARD_COST,
+            approve: DEFAULT_APPROVE_COST,
+            allowance: DEFAULT_ALLOWANCE_COST,
+            transfer_from: DEFAULT_TRANSFER_FROM_COST,
+            read_total_supply: DEFAULT_READ_TOTAL_SUPPLY_COST,
+            read_total_burned: DEFAULT_READ_TOTAL_BURNED_COST,
         }
     }
 }
@@ -44,6 +59,11 @@ impl ToBytes for MintCosts {
         ret.append(&mut self.balance.to_bytes()?);
         ret.append(&mut self.transfer.to_bytes()?);
         ret.append(&mut self.read_base_round_reward.to_bytes()?);
+        ret.append(&mut self.approve.to_bytes()?);
+        ret.append(&mut self.allowance.to_bytes()?);
+        ret.append(&mut self.transfer_from.to_bytes()?);
+        ret.append(&mut self.read_total_supply.to_bytes()?);
+        ret.append(&mut self.read_total_burned.to_bytes()?);
 
         Ok(ret)
     }
@@ -55,6 +75,11 @@ impl ToBytes for MintCosts {
             + self.balance.serialized_length()
             + self.transfer.serialized_length()
             + self.read_base_round_reward.serialized_length()
+            + self.approve.serialized_length()
+            + self.allowance.serialized_length()
+            + self.transfer_from.serialized_length()
+            + self.read_total_supply.serialized_length()
+            + self.read_total_burned.serialized_length()
     }
 }
 
@@ -66,6 +91,11 @@ impl FromBytes for MintCosts {
         let (balance, rem) = FromBytes::from_bytes(rem)?;
         let (transfer, rem) = FromBytes::from_bytes(rem)?;
         let (read_base_round_reward, rem) = FromBytes::from_bytes(rem)?;
+        let (approve, rem) = FromBytes::from_bytes(rem)?;
+        let (allowance, rem) = FromBytes::from_bytes(rem)?;
+        let (transfer_from, rem) = FromBytes::from_bytes(rem)?;
+        let (read_total_supply, rem) = FromBytes::from_bytes(rem)?;
+        let (read_total_burned, rem) = FromBytes::from_bytes(rem)?;
 
         Ok((
             Self {
@@ -75,6 +105,11 @@ impl FromBytes for MintCosts {
                 balance,
                 transfer,
                 read_base_round_reward,
+                approve,
+                allowance,
+                transfer_from,
+                read_total_supply,
+                read_total_burned,
             },
             rem,
         ))
@@ -90,6 +125,11 @@ impl Distribution<MintCosts> for Standard {
             balance: rng.gen(),
             transfer: rng.gen(),
             read_base_round_reward: rng.gen(),
+            approve: rng.gen(),
+            allowance: rng.gen(),
+            transfer_from: rng.gen(),
+            read_total_supply: rng.gen(),
+            read_total_burned: rng.gen(),
         }
     }
 }
@@ -108,6 +148,11 @@ pub mod gens {
             balance in num::u32::ANY,
             transfer in num::u32::ANY,
             read_base_round_reward in num::u32::ANY,
+            approve in num::u32::ANY,
+            allowance in num::u32::ANY,
+            transfer_from in num::u32::ANY,
+            read_total_supply in num::u32::ANY,
+            read_total_burned in num::u32::ANY,
         ) -> MintCosts {
             MintCosts {
                 mint,
@@ -116,6 +161,11 @@ pub mod gens {
                 balance,
                 transfer,
                 read_base_round_reward,
+                approve,
+                allowance,
+                transfer_from,
+                read_total_supply,
+                read_total_burned,
             }
         }
     }
@@ -0,0 +1,97 @@
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use datasize::DataSize;
+use num_rational::Ratio;
+use rand::{distributions::Standard, prelude::*, Rng};
+use serde::{Deserialize, Serialize};
+
+/// By default none of the unspent payment is refunded.
+pub const DEFAULT_REFUND_RATIO: (u64, u64) = (0, 1);
+/// By default none of the payment is burned.
+pub const DEFAULT_BURN_RATIO: (u64, u64) = (0, 1);
+
+/// Description of how the payment for a deploy is split when it is finalized.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
+pub struct PaymentSplit {
+    /// The share of the unspent payment refunded to the deploy's account.
+    #[data_size(skip)]
+    pub refund_ratio: Ratio<u64>,
+    /// The share of the non-refunded payment which is burned rather than paid to the proposer.
+    #[data_size(skip)]
+    pub burn_ratio: Ratio<u64>,
+}
+
+impl PaymentSplit {
+    /// Returns `true` if neither ratio is greater than one.
+    pub fn is_valid(&self) -> bool {
+        let one = Ratio::from_integer(1);
+        self.refund_ratio <= one && self.burn_ratio <= one
+    }
+}
+
+impl Default for PaymentSplit {
+    fn default() -> Self {
+        Self {
+            refund_ratio: DEFAULT_REFUND_RATIO.into(),
+            burn_ratio: DEFAULT_BURN_RATIO.into(),
+        }
+    }
+}
+
+impl ToBytes for PaymentSplit {
+    fn to_bytes(&self) -> Result<Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+
+        ret.append(&mut self.refund_ratio.to_bytes()?);
+        ret.append(&mut self.burn_ratio.to_bytes()?);
+
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.refund_ratio.serialized_length() + self.burn_ratio.serialized_length()
+    }
+}
+
+impl FromBytes for PaymentSplit {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), casper_types::bytesrepr::Error> {
+        let (refund_ratio, rem) = FromBytes::from_bytes(bytes)?;
+        let (burn_ratio, rem) = FromBytes::from_bytes(rem)?;
+
+        Ok((
+            Self {
+                refund_ratio,
+                burn_ratio,
+            },
+            rem,
+        ))
+    }
+}
+
+impl Distribution<PaymentSplit> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PaymentSplit {
+        PaymentSplit {
+            refund_ratio: Ratio::new(rng.gen_range(0, 100), 100),
+            burn_ratio: Ratio::new(rng.gen_range(0, 100), 100),
+        }
+    }
+}
+
+#[cfg(any(feature = "gens", test))]
+pub mod gens {
+    use num_rational::Ratio;
+    use proptest::prop_compose;
+
+    use super::PaymentSplit;
+
+    prop_compose! {
+        pub fn payment_split_arb()(
+            refund_numer in 0u64..=100,
+            burn_numer in 0u64..=100,
+        ) -> PaymentSplit {
+            PaymentSplit {
+                refund_ratio: Ratio::new(refund_numer, 100),
+                burn_ratio: Ratio::new(burn_numer, 100),
+            }
+        }
+    }
+}
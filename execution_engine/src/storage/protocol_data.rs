@@ -230,8 +230,14 @@ pub(crate) mod gens {
 mod tests {
     use proptest::proptest;
 
-    use crate::shared::{system_config::SystemConfig, wasm_config::WasmConfig};
-    use casper_types::{bytesrepr, ContractHash};
+    use crate::shared::{
+        system_config::{payment_split::PaymentSplit, SystemConfig},
+        wasm_config::WasmConfig,
+    };
+    use casper_types::{
+        bytesrepr::{self, ToBytes},
+        ContractHash,
+    };
 
     use super::{gens, ProtocolData};
 
@@ -301,6 +307,28 @@ mod tests {
         assert_eq!(actual[2], auction_reference);
     }
 
+    #[test]
+    fn should_deserialize_protocol_data_stored_without_payment_split() {
+        let system_config = SystemConfig::default();
+        let mut legacy_bytes = WasmConfig::default().to_bytes().unwrap();
+        legacy_bytes.extend(system_config.wasmless_transfer_cost().to_bytes().unwrap());
+        legacy_bytes.extend(system_config.auction_costs().to_bytes().unwrap());
+        legacy_bytes.extend(system_config.mint_costs().to_bytes().unwrap());
+        legacy_bytes.extend(system_config.handle_payment_costs().to_bytes().unwrap());
+        legacy_bytes.extend(system_config.standard_payment_costs().to_bytes().unwrap());
+        for hash_addr in &[[1u8; 32], [2u8; 32], [3u8; 32], [4u8; 32]] {
+            legacy_bytes.extend(hash_addr.to_bytes().unwrap());
+        }
+
+        let protocol_data: ProtocolData = bytesrepr::deserialize(legacy_bytes).unwrap();
+        assert_eq!(
+            protocol_data.system_config().payment_split(),
+            &PaymentSplit::default()
+        );
+        assert_eq!(protocol_data.mint(), [1u8; 32].into());
+        assert_eq!(protocol_data.auction(), [4u8; 32].into());
+    }
+
     proptest! {
         #[test]
         fn should_serialize_and_deserialize_with_arbitrary_values(
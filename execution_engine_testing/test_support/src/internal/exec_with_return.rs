@@ -4,7 +4,8 @@ use casper_execution_engine::{
     core::{
         engine_state,
         engine_state::{
-            executable_deploy_item::ExecutableDeployItem, execution_effect::ExecutionEffect,
+            executable_deploy_item::{ExecutableDeployItem, ExecutableDeployItemExt},
+            execution_effect::ExecutionEffect,
             EngineConfig,
         },
         execution::{self, AddressGenerator},
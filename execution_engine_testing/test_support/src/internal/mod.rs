@@ -19,7 +19,11 @@ use casper_execution_engine::{
         motes::Motes, newtypes::Blake2bHash, system_config::SystemConfig, wasm_config::WasmConfig,
     },
 };
-use casper_types::{account::AccountHash, ProtocolVersion, PublicKey, SecretKey, U512};
+use casper_types::{
+    account::AccountHash,
+    system::auction::{DelegationRate, DELEGATION_RATE_DENOMINATOR},
+    ProtocolVersion, PublicKey, SecretKey, U512,
+};
 
 use super::DEFAULT_ACCOUNT_INITIAL_BALANCE;
 
@@ -38,6 +42,12 @@ pub const DEFAULT_AUCTION_DELAY: u64 = 3;
 pub const DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS: u64 = 90 * 24 * 60 * 60 * 1000;
 /// Default number of eras that need to pass to be able to withdraw unbonded funds.
 pub const DEFAULT_UNBONDING_DELAY: u64 = 14;
+/// Default minimum amount of motes which can be delegated to a validator.
+pub const DEFAULT_MINIMUM_DELEGATION_AMOUNT: u64 = 0;
+/// Default maximum number of delegators per validator.
+pub const DEFAULT_MAX_DELEGATORS_PER_VALIDATOR: u32 = u32::MAX;
+/// Default maximum increase of a validator's delegation rate per era.
+pub const DEFAULT_MAX_DELEGATION_RATE_INCREASE: DelegationRate = DELEGATION_RATE_DENOMINATOR;
 
 /// Default round seigniorage rate represented as a fractional number.
 ///
@@ -102,6 +112,9 @@ pub static DEFAULT_EXEC_CONFIG: Lazy<ExecConfig> = Lazy::new(|| {
         DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
         DEFAULT_ROUND_SEIGNIORAGE_RATE,
         DEFAULT_UNBONDING_DELAY,
+        DEFAULT_MINIMUM_DELEGATION_AMOUNT,
+        DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
+        DEFAULT_MAX_DELEGATION_RATE_INCREASE,
         DEFAULT_GENESIS_TIMESTAMP_MILLIS,
     )
 });
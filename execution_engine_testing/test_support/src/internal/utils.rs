@@ -20,7 +20,11 @@ use casper_execution_engine::{
 };
 use casper_types::Key;
 
-use super::{DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY};
+use super::{
+    DEFAULT_MAX_DELEGATION_RATE_INCREASE, DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
+    DEFAULT_MINIMUM_DELEGATION_AMOUNT, DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG,
+    DEFAULT_UNBONDING_DELAY,
+};
 use crate::internal::{
     DEFAULT_AUCTION_DELAY, DEFAULT_CHAIN_NAME, DEFAULT_GENESIS_CONFIG_HASH,
     DEFAULT_GENESIS_TIMESTAMP_MILLIS, DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PROTOCOL_VERSION,
@@ -140,6 +144,9 @@ pub fn create_exec_config(accounts: Vec<GenesisAccount>) -> ExecConfig {
     let locked_funds_period_millis = DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
     let round_seigniorage_rate = DEFAULT_ROUND_SEIGNIORAGE_RATE;
     let unbonding_delay = DEFAULT_UNBONDING_DELAY;
+    let minimum_delegation_amount = DEFAULT_MINIMUM_DELEGATION_AMOUNT;
+    let max_delegators_per_validator = DEFAULT_MAX_DELEGATORS_PER_VALIDATOR;
+    let max_delegation_rate_increase = DEFAULT_MAX_DELEGATION_RATE_INCREASE;
     let genesis_timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS;
     ExecConfig::new(
         accounts,
@@ -150,6 +157,9 @@ pub fn create_exec_config(accounts: Vec<GenesisAccount>) -> ExecConfig {
         locked_funds_period_millis,
         round_seigniorage_rate,
         unbonding_delay,
+        minimum_delegation_amount,
+        max_delegators_per_validator,
+        max_delegation_rate_increase,
         genesis_timestamp_millis,
     )
 }
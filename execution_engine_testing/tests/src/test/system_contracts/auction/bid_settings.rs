@@ -0,0 +1,157 @@
+use casper_engine_test_support::internal::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
+    DEFAULT_MINIMUM_DELEGATION_AMOUNT,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    runtime_args,
+    system::auction::{
+        self, Bids, DelegationRate, ARG_CONTACT, ARG_NAME, ARG_PUBLIC_KEY, ARG_URL,
+        MAX_BID_METADATA_FIELD_LENGTH, METHOD_SET_BID_METADATA,
+    },
+    RuntimeArgs,
+};
+
+use super::helpers::{
+    self, add_bid_request, assert_auction_error, transfer_request, ADD_BID_DELEGATION_RATE,
+    SYSTEM_ADDR, SYSTEM_TRANSFER_AMOUNT, TRANSFER_AMOUNT, VALIDATOR_1_ADDR, VALIDATOR_1_PK,
+};
+
+const MAX_DELEGATION_RATE_INCREASE: DelegationRate = 5;
+
+fn set_bid_metadata_request(
+    builder: &InMemoryWasmTestBuilder,
+    name: Option<String>,
+    url: Option<String>,
+    contact: Option<String>,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *VALIDATOR_1_ADDR,
+        builder.get_auction_contract_hash(),
+        METHOD_SET_BID_METADATA,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *VALIDATOR_1_PK,
+            ARG_NAME => name,
+            ARG_URL => url,
+            ARG_CONTACT => contact,
+        },
+    )
+    .build()
+}
+
+/// Runs genesis with a limited delegation rate increase and sets up a single validator.
+fn setup() -> InMemoryWasmTestBuilder {
+    let run_genesis_request = helpers::run_genesis_request(
+        DEFAULT_MINIMUM_DELEGATION_AMOUNT,
+        DEFAULT_MAX_DELEGATORS_PER_VALIDATOR,
+        MAX_DELEGATION_RATE_INCREASE,
+    );
+
+    let post_genesis_requests = vec![
+        transfer_request(SYSTEM_ADDR, SYSTEM_TRANSFER_AMOUNT),
+        transfer_request(*VALIDATOR_1_ADDR, TRANSFER_AMOUNT),
+        add_bid_request(*VALIDATOR_1_ADDR, *VALIDATOR_1_PK, ADD_BID_DELEGATION_RATE),
+    ];
+
+    helpers::setup(&run_genesis_request, post_genesis_requests)
+}
+
+#[ignore]
+#[test]
+fn should_increase_delegation_rate_gradually() {
+    const NEW_DELEGATION_RATE: DelegationRate = 22;
+
+    let mut builder = setup();
+    let timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+
+    builder
+        .exec(add_bid_request(
+            *VALIDATOR_1_ADDR,
+            *VALIDATOR_1_PK,
+            NEW_DELEGATION_RATE,
+        ))
+        .commit()
+        .expect_success();
+
+    // The increase is visible, but not yet in effect
+    let bids: Bids = builder.get_bids();
+    let bid = &bids[&VALIDATOR_1_PK];
+    assert_eq!(*bid.delegation_rate(), ADD_BID_DELEGATION_RATE);
+    assert_eq!(bid.pending_delegation_rate(), Some(&NEW_DELEGATION_RATE));
+
+    let expected_delegation_rates = [
+        ADD_BID_DELEGATION_RATE + MAX_DELEGATION_RATE_INCREASE,
+        ADD_BID_DELEGATION_RATE + MAX_DELEGATION_RATE_INCREASE * 2,
+        NEW_DELEGATION_RATE,
+    ];
+    for expected_delegation_rate in expected_delegation_rates.iter() {
+        builder.run_auction(timestamp_millis, Vec::new());
+
+        let bids: Bids = builder.get_bids();
+        assert_eq!(
+            bids[&VALIDATOR_1_PK].delegation_rate(),
+            expected_delegation_rate
+        );
+    }
+
+    let bids: Bids = builder.get_bids();
+    assert_eq!(bids[&VALIDATOR_1_PK].pending_delegation_rate(), None);
+}
+
+#[ignore]
+#[test]
+fn should_decrease_delegation_rate_immediately() {
+    let mut builder = setup();
+
+    builder
+        .exec(add_bid_request(ADD_BID_DELEGATION_RATE + 20))
+        .commit()
+        .expect_success();
+
+    // A decrease cancels the pending increase
+    builder
+        .exec(add_bid_request(ADD_BID_DELEGATION_RATE - 5))
+        .commit()
+        .expect_success();
+
+    let bids: Bids = builder.get_bids();
+    let bid = &bids[&VALIDATOR_1_PK];
+    assert_eq!(*bid.delegation_rate(), ADD_BID_DELEGATION_RATE - 5);
+    assert_eq!(bid.pending_delegation_rate(), None);
+}
+
+#[ignore]
+#[test]
+fn should_set_bid_metadata() {
+    let mut builder = setup();
+
+    let request = set_bid_metadata_request(
+        &builder,
+        Some("Validator 1".to_string()),
+        Some("https://example.com".to_string()),
+        None,
+    );
+    builder.exec(request).commit().expect_success();
+
+    let bids: Bids = builder.get_bids();
+    let metadata = bids[&VALIDATOR_1_PK].metadata();
+    assert_eq!(metadata.name().map(String::as_str), Some("Validator 1"));
+    assert_eq!(
+        metadata.url().map(String::as_str),
+        Some("https://example.com")
+    );
+    assert_eq!(metadata.contact(), None);
+}
+
+#[ignore]
+#[test]
+fn should_not_set_too_long_bid_metadata() {
+    let mut builder = setup();
+
+    let name = "a".repeat(MAX_BID_METADATA_FIELD_LENGTH + 1);
+    let request = set_bid_metadata_request(&builder, Some(name), None, None);
+    builder.exec(request).commit();
+
+    assert_auction_error(&builder, auction::Error::BidMetadataTooLong);
+}
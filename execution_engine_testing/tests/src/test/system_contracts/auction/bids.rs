@@ -207,7 +207,12 @@ fn should_run_add_bid() {
         builder.get_purse_balance(*active_bid.bonding_purse()),
         U512::from(ADD_BID_AMOUNT_1 + BID_AMOUNT_2)
     );
-    assert_eq!(*active_bid.delegation_rate(), ADD_BID_DELEGATION_RATE_2);
+    // Delegation rate increases only take effect at the next auction
+    assert_eq!(*active_bid.delegation_rate(), ADD_BID_DELEGATION_RATE_1);
+    assert_eq!(
+        active_bid.pending_delegation_rate(),
+        Some(&ADD_BID_DELEGATION_RATE_2)
+    );
 
     // 3. withdraw some amount
     let exec_request_3 = ExecuteRequestBuilder::standard(
@@ -0,0 +1,204 @@
+use casper_engine_test_support::internal::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_MAX_DELEGATION_RATE_INCREASE,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{
+        self, Bids, ARG_AMOUNT, ARG_DELEGATOR, ARG_MINIMUM_DELEGATION_AMOUNT, ARG_PUBLIC_KEY,
+        ARG_VALIDATOR, METHOD_SET_MINIMUM_DELEGATION_AMOUNT,
+    },
+    PublicKey, RuntimeArgs, U512,
+};
+
+use super::helpers::{
+    self, add_bid_request, assert_auction_error, transfer_request, ADD_BID_DELEGATION_RATE,
+    CONTRACT_DELEGATE, DELEGATOR_1_ADDR, DELEGATOR_1_PK, DELEGATOR_2_ADDR, DELEGATOR_2_PK,
+    TRANSFER_AMOUNT, VALIDATOR_1_ADDR, VALIDATOR_1_PK,
+};
+
+const MINIMUM_DELEGATION_AMOUNT: u64 = 100_000;
+const VALIDATOR_MINIMUM_DELEGATION_AMOUNT: u64 = 150_000;
+
+fn delegate_request(
+    delegator_addr: AccountHash,
+    delegator_pk: PublicKey,
+    amount: u64,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        delegator_addr,
+        CONTRACT_DELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(amount),
+            ARG_VALIDATOR => *VALIDATOR_1_PK,
+            ARG_DELEGATOR => delegator_pk,
+        },
+    )
+    .build()
+}
+
+/// Runs genesis with the given delegation limits and sets up a single validator with two funded
+/// delegator accounts.
+fn setup(max_delegators_per_validator: u32) -> InMemoryWasmTestBuilder {
+    let run_genesis_request = helpers::run_genesis_request(
+        MINIMUM_DELEGATION_AMOUNT,
+        max_delegators_per_validator,
+        DEFAULT_MAX_DELEGATION_RATE_INCREASE,
+    );
+
+    let post_genesis_requests = vec![
+        transfer_request(*VALIDATOR_1_ADDR, TRANSFER_AMOUNT),
+        transfer_request(*DELEGATOR_1_ADDR, TRANSFER_AMOUNT),
+        transfer_request(*DELEGATOR_2_ADDR, TRANSFER_AMOUNT),
+        add_bid_request(*VALIDATOR_1_ADDR, *VALIDATOR_1_PK, ADD_BID_DELEGATION_RATE),
+    ];
+
+    helpers::setup(&run_genesis_request, post_genesis_requests)
+}
+
+#[ignore]
+#[test]
+fn should_not_delegate_less_than_minimum_delegation_amount() {
+    let mut builder = setup(u32::MAX);
+
+    builder
+        .exec(delegate_request(
+            *DELEGATOR_1_ADDR,
+            *DELEGATOR_1_PK,
+            MINIMUM_DELEGATION_AMOUNT - 1,
+        ))
+        .commit();
+
+    assert_auction_error(&builder, auction::Error::DelegationAmountTooSmall);
+
+    builder
+        .exec(delegate_request(
+            *DELEGATOR_1_ADDR,
+            *DELEGATOR_1_PK,
+            MINIMUM_DELEGATION_AMOUNT,
+        ))
+        .commit()
+        .expect_success();
+
+    // Topping off only needs the total to be above the minimum
+    builder
+        .exec(delegate_request(*DELEGATOR_1_ADDR, *DELEGATOR_1_PK, 1))
+        .commit()
+        .expect_success();
+
+    let bids: Bids = builder.get_bids();
+    assert_eq!(
+        *bids[&VALIDATOR_1_PK].delegators()[&DELEGATOR_1_PK].staked_amount(),
+        U512::from(MINIMUM_DELEGATION_AMOUNT + 1)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_exceed_max_delegators_per_validator() {
+    let mut builder = setup(1);
+
+    builder
+        .exec(delegate_request(
+            *DELEGATOR_1_ADDR,
+            *DELEGATOR_1_PK,
+            MINIMUM_DELEGATION_AMOUNT,
+        ))
+        .commit()
+        .expect_success();
+
+    builder
+        .exec(delegate_request(
+            *DELEGATOR_2_ADDR,
+            *DELEGATOR_2_PK,
+            MINIMUM_DELEGATION_AMOUNT,
+        ))
+        .commit();
+
+    assert_auction_error(&builder, auction::Error::ExceededDelegatorSizeLimit);
+
+    // Existing delegators can still top off their stake
+    builder
+        .exec(delegate_request(
+            *DELEGATOR_1_ADDR,
+            *DELEGATOR_1_PK,
+            MINIMUM_DELEGATION_AMOUNT,
+        ))
+        .commit()
+        .expect_success();
+
+    let bids: Bids = builder.get_bids();
+    let delegators = bids[&VALIDATOR_1_PK].delegators();
+    assert_eq!(delegators.len(), 1);
+    assert_eq!(
+        *delegators[&DELEGATOR_1_PK].staked_amount(),
+        U512::from(MINIMUM_DELEGATION_AMOUNT * 2)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_enforce_validator_minimum_delegation_amount() {
+    let mut builder = setup(u32::MAX);
+
+    let auction = builder.get_auction_contract_hash();
+    let set_minimum_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *VALIDATOR_1_ADDR,
+        auction,
+        METHOD_SET_MINIMUM_DELEGATION_AMOUNT,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *VALIDATOR_1_PK,
+            ARG_MINIMUM_DELEGATION_AMOUNT => U512::from(VALIDATOR_MINIMUM_DELEGATION_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(set_minimum_request).commit().expect_success();
+
+    let bids: Bids = builder.get_bids();
+    assert_eq!(
+        *bids[&VALIDATOR_1_PK].minimum_delegation_amount(),
+        U512::from(VALIDATOR_MINIMUM_DELEGATION_AMOUNT)
+    );
+
+    // Above the network minimum, but below the validator's own
+    builder
+        .exec(delegate_request(
+            *DELEGATOR_1_ADDR,
+            *DELEGATOR_1_PK,
+            MINIMUM_DELEGATION_AMOUNT,
+        ))
+        .commit();
+
+    assert_auction_error(&builder, auction::Error::DelegationAmountTooSmall);
+
+    builder
+        .exec(delegate_request(
+            *DELEGATOR_1_ADDR,
+            *DELEGATOR_1_PK,
+            VALIDATOR_MINIMUM_DELEGATION_AMOUNT,
+        ))
+        .commit()
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_not_set_minimum_delegation_amount_of_other_validator() {
+    let mut builder = setup(u32::MAX);
+
+    let auction = builder.get_auction_contract_hash();
+    let set_minimum_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DELEGATOR_1_ADDR,
+        auction,
+        METHOD_SET_MINIMUM_DELEGATION_AMOUNT,
+        runtime_args! {
+            ARG_PUBLIC_KEY => *VALIDATOR_1_PK,
+            ARG_MINIMUM_DELEGATION_AMOUNT => U512::from(VALIDATOR_MINIMUM_DELEGATION_AMOUNT),
+        },
+    )
+    .build();
+    builder.exec(set_minimum_request).commit();
+
+    assert_auction_error(&builder, auction::Error::InvalidPublicKey);
+}
@@ -12,16 +12,22 @@ use casper_engine_test_support::{
     },
     DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
+use casper_execution_engine::shared::{
+    additive_map::AdditiveMap, stored_value::StoredValue, transform::Transform,
+};
 use casper_types::{
     self,
     account::AccountHash,
     runtime_args,
-    system::auction::{
-        self, Bid, Bids, DelegationRate, Delegator, SeigniorageAllocation, ARG_AMOUNT,
-        ARG_DELEGATION_RATE, ARG_DELEGATOR, ARG_PUBLIC_KEY, ARG_REWARD_FACTORS, ARG_VALIDATOR,
-        BLOCK_REWARD, DELEGATION_RATE_DENOMINATOR, METHOD_DISTRIBUTE,
+    system::{
+        auction::{
+            self, Bid, Bids, DelegationRate, Delegator, SeigniorageAllocation, ARG_AMOUNT,
+            ARG_DELEGATION_RATE, ARG_DELEGATOR, ARG_PUBLIC_KEY, ARG_REWARD_FACTORS, ARG_VALIDATOR,
+            BLOCK_REWARD, DELEGATION_RATE_DENOMINATOR, METHOD_DISTRIBUTE,
+        },
+        mint::{METHOD_READ_TOTAL_BURNED, METHOD_READ_TOTAL_SUPPLY, TOTAL_BURNED_KEY},
     },
-    Key, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey, U512,
+    Contract, EntryPoints, Key, ProtocolVersion, PublicKey, RuntimeArgs, SecretKey, U512,
 };
 
 const ARG_ENTRY_POINT: &str = "entry_point";
@@ -118,6 +124,40 @@ fn undelegate(
     builder.exec(undelegate_request).expect_success().commit();
 }
 
+fn set_auto_compound(
+    builder: &mut InMemoryWasmTestBuilder,
+    sender: AccountHash,
+    public_key: PublicKey,
+    validator: PublicKey,
+    auto_compound: bool,
+) {
+    let auction = builder.get_auction_contract_hash();
+    let set_auto_compound_args = runtime_args! {
+        auction::ARG_PUBLIC_KEY => public_key,
+        auction::ARG_VALIDATOR => validator,
+        auction::ARG_AUTO_COMPOUND => auto_compound,
+    };
+    let set_auto_compound_request = ExecuteRequestBuilder::contract_call_by_hash(
+        sender,
+        auction,
+        auction::METHOD_SET_AUTO_COMPOUND,
+        set_auto_compound_args,
+    )
+    .build();
+    builder
+        .exec(set_auto_compound_request)
+        .expect_success()
+        .commit();
+}
+
+fn get_main_purse_balance(builder: &mut InMemoryWasmTestBuilder, account: AccountHash) -> U512 {
+    let main_purse = builder
+        .get_account(account)
+        .expect("should have account")
+        .main_purse();
+    builder.get_purse_balance(main_purse)
+}
+
 fn get_delegator_staked_amount(
     builder: &mut InMemoryWasmTestBuilder,
     validator: PublicKey,
@@ -3827,3 +3867,322 @@ fn should_distribute_delegation_rate_full_after_upgrading() {
     assert!(total_payout_before > total_payout_after); // expected total payout after decreasing
                                                        // rate is lower than the first payout
 }
+
+#[ignore]
+#[test]
+fn should_pay_out_rewards_when_auto_compound_disabled() {
+    const VALIDATOR_1_STAKE: u64 = 1_000_000;
+    const DELEGATOR_1_STAKE: u64 = 1_000_000;
+    const DELEGATOR_2_STAKE: u64 = 1_000_000;
+
+    const VALIDATOR_1_DELEGATION_RATE: DelegationRate = 0;
+
+    let participant_portion = Ratio::new(U512::one(), U512::from(3));
+
+    let fund_request = |target: AccountHash| {
+        ExecuteRequestBuilder::standard(
+            *DEFAULT_ACCOUNT_ADDR,
+            CONTRACT_TRANSFER_TO_ACCOUNT,
+            runtime_args! {
+                ARG_TARGET => target,
+                ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+            },
+        )
+        .build()
+    };
+
+    let validator_1_add_bid_request = ExecuteRequestBuilder::standard(
+        *VALIDATOR_1_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(VALIDATOR_1_STAKE),
+            ARG_DELEGATION_RATE => VALIDATOR_1_DELEGATION_RATE,
+            ARG_PUBLIC_KEY => *VALIDATOR_1,
+        },
+    )
+    .build();
+
+    let delegator_1_delegate_request = ExecuteRequestBuilder::standard(
+        *DELEGATOR_1_ADDR,
+        CONTRACT_DELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DELEGATOR_1_STAKE),
+            ARG_VALIDATOR => *VALIDATOR_1,
+            ARG_DELEGATOR => *DELEGATOR_1,
+        },
+    )
+    .build();
+
+    let delegator_2_delegate_request = ExecuteRequestBuilder::standard(
+        *DELEGATOR_2_ADDR,
+        CONTRACT_DELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DELEGATOR_2_STAKE),
+            ARG_VALIDATOR => *VALIDATOR_1,
+            ARG_DELEGATOR => *DELEGATOR_2,
+        },
+    )
+    .build();
+
+    let post_genesis_requests = vec![
+        fund_request(SYSTEM_ADDR),
+        fund_request(*VALIDATOR_1_ADDR),
+        fund_request(*DELEGATOR_1_ADDR),
+        fund_request(*DELEGATOR_2_ADDR),
+        validator_1_add_bid_request,
+        delegator_1_delegate_request,
+        delegator_2_delegate_request,
+    ];
+
+    let mut timestamp_millis =
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let initial_supply = builder.total_supply(None);
+    let expected_total_reward = *GENESIS_ROUND_SEIGNIORAGE_RATE * initial_supply;
+    let expected_participant_reward = (expected_total_reward * participant_portion).to_integer();
+    // The validator receives whatever is left after the delegators' rewards are truncated
+    let expected_validator_reward =
+        expected_total_reward.to_integer() - expected_participant_reward * 2;
+
+    for request in post_genesis_requests {
+        builder.exec(request).commit().expect_success();
+    }
+
+    // Validator 1 and delegator 1 have their rewards paid out, delegator 2 keeps compounding
+    set_auto_compound(
+        &mut builder,
+        *VALIDATOR_1_ADDR,
+        *VALIDATOR_1,
+        *VALIDATOR_1,
+        false,
+    );
+    set_auto_compound(
+        &mut builder,
+        *DELEGATOR_1_ADDR,
+        *DELEGATOR_1,
+        *VALIDATOR_1,
+        false,
+    );
+
+    for _ in 0..5 {
+        builder.run_auction(timestamp_millis, Vec::new());
+        timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+    }
+
+    let validator_1_balance_before = get_main_purse_balance(&mut builder, *VALIDATOR_1_ADDR);
+    let delegator_1_balance_before = get_main_purse_balance(&mut builder, *DELEGATOR_1_ADDR);
+    let delegator_2_balance_before = get_main_purse_balance(&mut builder, *DELEGATOR_2_ADDR);
+
+    let reward_factors: BTreeMap<PublicKey, u64> = {
+        let mut tmp = BTreeMap::new();
+        tmp.insert(*VALIDATOR_1, BLOCK_REWARD);
+        tmp
+    };
+
+    let distribute_request = ExecuteRequestBuilder::standard(
+        SYSTEM_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => METHOD_DISTRIBUTE,
+            ARG_REWARD_FACTORS => reward_factors
+        },
+    )
+    .build();
+
+    builder.exec(distribute_request).commit().expect_success();
+
+    let validator_1_bid =
+        get_validator_bid(&mut builder, *VALIDATOR_1).expect("should have validator bid");
+    assert!(!validator_1_bid.auto_compound());
+    assert_eq!(
+        *validator_1_bid.staked_amount(),
+        U512::from(VALIDATOR_1_STAKE)
+    );
+    assert_eq!(*validator_1_bid.total_rewards(), expected_validator_reward);
+    assert_eq!(
+        get_main_purse_balance(&mut builder, *VALIDATOR_1_ADDR),
+        validator_1_balance_before + expected_validator_reward
+    );
+
+    let delegator_1 = get_delegator_bid(&mut builder, *VALIDATOR_1, *DELEGATOR_1)
+        .expect("should have delegator 1");
+    assert!(!delegator_1.auto_compound());
+    assert_eq!(*delegator_1.staked_amount(), U512::from(DELEGATOR_1_STAKE));
+    assert_eq!(*delegator_1.total_rewards(), expected_participant_reward);
+    assert_eq!(
+        get_main_purse_balance(&mut builder, *DELEGATOR_1_ADDR),
+        delegator_1_balance_before + expected_participant_reward
+    );
+
+    let delegator_2 = get_delegator_bid(&mut builder, *VALIDATOR_1, *DELEGATOR_2)
+        .expect("should have delegator 2");
+    assert!(delegator_2.auto_compound());
+    assert_eq!(
+        *delegator_2.staked_amount(),
+        U512::from(DELEGATOR_2_STAKE) + expected_participant_reward
+    );
+    assert_eq!(*delegator_2.total_rewards(), expected_participant_reward);
+    assert_eq!(
+        get_main_purse_balance(&mut builder, *DELEGATOR_2_ADDR),
+        delegator_2_balance_before
+    );
+}
+
+/// Rewrites the mint the way it was installed before it exposed its total supply and total burned
+/// amount, i.e. without those entry points and without the total burned key.
+fn install_legacy_mint(builder: &mut InMemoryWasmTestBuilder) {
+    let mint_hash = builder.get_mint_contract_hash();
+    let mint_contract = builder
+        .get_contract(mint_hash)
+        .expect("should have mint contract");
+
+    let mut entry_points = EntryPoints::new();
+    for entry_point in mint_contract.entry_points().clone().take_entry_points() {
+        if entry_point.name() != METHOD_READ_TOTAL_SUPPLY
+            && entry_point.name() != METHOD_READ_TOTAL_BURNED
+        {
+            entry_points.add_entry_point(entry_point);
+        }
+    }
+    let mut named_keys = mint_contract.named_keys().clone();
+    named_keys.remove(TOTAL_BURNED_KEY);
+
+    let legacy_mint_contract = Contract::new(
+        mint_contract.contract_package_hash(),
+        mint_contract.contract_wasm_hash(),
+        named_keys,
+        entry_points,
+        mint_contract.protocol_version(),
+    );
+    let mut effects = AdditiveMap::new();
+    effects.insert(
+        mint_hash.into(),
+        Transform::Write(StoredValue::Contract(legacy_mint_contract)),
+    );
+    let post_state_hash = builder.get_post_state_hash();
+    builder.commit_effects(post_state_hash, effects);
+}
+
+#[ignore]
+#[test]
+fn should_distribute_on_network_installed_with_legacy_mint() {
+    const VALIDATOR_1_STAKE: u64 = 1_000_000;
+    const VALIDATOR_1_DELEGATION_RATE: DelegationRate = 0;
+
+    let system_fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => SYSTEM_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let validator_1_fund_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => *VALIDATOR_1_ADDR,
+            ARG_AMOUNT => U512::from(TRANSFER_AMOUNT)
+        },
+    )
+    .build();
+
+    let validator_1_add_bid_request = ExecuteRequestBuilder::standard(
+        *VALIDATOR_1_ADDR,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(VALIDATOR_1_STAKE),
+            ARG_DELEGATION_RATE => VALIDATOR_1_DELEGATION_RATE,
+            ARG_PUBLIC_KEY => *VALIDATOR_1,
+        },
+    )
+    .build();
+
+    let post_genesis_requests = vec![
+        system_fund_request,
+        validator_1_fund_request,
+        validator_1_add_bid_request,
+    ];
+
+    let mut timestamp_millis =
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    for request in post_genesis_requests {
+        builder.exec(request).commit().expect_success();
+    }
+
+    install_legacy_mint(&mut builder);
+
+    for _ in 0..5 {
+        builder.run_auction(timestamp_millis, Vec::new());
+        timestamp_millis += TIMESTAMP_MILLIS_INCREMENT;
+    }
+
+    let reward_factors: BTreeMap<PublicKey, u64> = {
+        let mut tmp = BTreeMap::new();
+        tmp.insert(*VALIDATOR_1, BLOCK_REWARD);
+        tmp
+    };
+
+    // The auction reads the supply straight from the mint, so distributing works before the
+    // upgrade installs the missing entry points...
+    let initial_supply = builder.total_supply(None);
+    let distribute_request = ExecuteRequestBuilder::standard(
+        SYSTEM_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => METHOD_DISTRIBUTE,
+            ARG_REWARD_FACTORS => reward_factors.clone()
+        },
+    )
+    .build();
+
+    builder.exec(distribute_request).commit().expect_success();
+
+    assert!(builder.total_supply(None) > initial_supply);
+
+    let old_protocol_version = *DEFAULT_PROTOCOL_VERSION;
+    let sem_ver = old_protocol_version.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = {
+        const DEFAULT_ACTIVATION_POINT: u64 = 1;
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(old_protocol_version)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .build()
+    };
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    // ...and after it.
+    let initial_supply = builder.total_supply(None);
+    let distribute_request = ExecuteRequestBuilder::standard(
+        SYSTEM_ADDR,
+        CONTRACT_AUCTION_BIDS,
+        runtime_args! {
+            ARG_ENTRY_POINT => METHOD_DISTRIBUTE,
+            ARG_REWARD_FACTORS => reward_factors
+        },
+    )
+    .with_protocol_version(new_protocol_version)
+    .build();
+
+    builder.exec(distribute_request).commit().expect_success();
+
+    assert!(builder.total_supply(None) > initial_supply);
+}
@@ -0,0 +1,146 @@
+//! Accounts, requests and assertions shared by the auction tests.
+
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS, DEFAULT_AUCTION_DELAY,
+        DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PROTOCOL_VERSION,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE, DEFAULT_SYSTEM_CONFIG, DEFAULT_UNBONDING_DELAY,
+        DEFAULT_VALIDATOR_SLOTS, DEFAULT_WASM_CONFIG,
+    },
+    DEFAULT_ACCOUNT_ADDR, MINIMUM_ACCOUNT_CREATION_BALANCE,
+};
+use casper_execution_engine::core::{
+    engine_state::{
+        self, genesis::ExecConfig, run_genesis_request::RunGenesisRequest, ExecuteRequest,
+    },
+    execution,
+};
+use casper_types::{
+    account::AccountHash,
+    api_error::ApiError,
+    runtime_args,
+    system::auction::{
+        self, DelegationRate, ARG_AMOUNT, ARG_DELEGATION_RATE, ARG_PUBLIC_KEY, ARG_TARGET,
+    },
+    PublicKey, RuntimeArgs, SecretKey, U512,
+};
+
+const CONTRACT_TRANSFER_TO_ACCOUNT: &str = "transfer_to_account_u512.wasm";
+const CONTRACT_ADD_BID: &str = "add_bid.wasm";
+pub(super) const CONTRACT_DELEGATE: &str = "delegate.wasm";
+
+pub(super) const TRANSFER_AMOUNT: u64 = MINIMUM_ACCOUNT_CREATION_BALANCE + 1000;
+pub(super) const SYSTEM_TRANSFER_AMOUNT: u64 = MINIMUM_ACCOUNT_CREATION_BALANCE;
+pub(super) const SYSTEM_ADDR: AccountHash = AccountHash::new([0u8; 32]);
+
+const ADD_BID_AMOUNT: u64 = 95_000;
+pub(super) const ADD_BID_DELEGATION_RATE: DelegationRate = 10;
+
+pub(super) static VALIDATOR_1_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([3; SecretKey::ED25519_LENGTH]).into());
+pub(super) static VALIDATOR_1_ADDR: Lazy<AccountHash> =
+    Lazy::new(|| AccountHash::from(&*VALIDATOR_1_PK));
+pub(super) static VALIDATOR_2_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([4; SecretKey::ED25519_LENGTH]).into());
+pub(super) static VALIDATOR_2_ADDR: Lazy<AccountHash> =
+    Lazy::new(|| AccountHash::from(&*VALIDATOR_2_PK));
+pub(super) static DELEGATOR_1_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([205; SecretKey::ED25519_LENGTH]).into());
+pub(super) static DELEGATOR_1_ADDR: Lazy<AccountHash> =
+    Lazy::new(|| AccountHash::from(&*DELEGATOR_1_PK));
+pub(super) static DELEGATOR_2_PK: Lazy<PublicKey> =
+    Lazy::new(|| SecretKey::ed25519([206; SecretKey::ED25519_LENGTH]).into());
+pub(super) static DELEGATOR_2_ADDR: Lazy<AccountHash> =
+    Lazy::new(|| AccountHash::from(&*DELEGATOR_2_PK));
+
+/// Builds a genesis request using the default config apart from the given delegation settings.
+pub(super) fn run_genesis_request(
+    minimum_delegation_amount: u64,
+    max_delegators_per_validator: u32,
+    max_delegation_rate_increase: DelegationRate,
+) -> RunGenesisRequest {
+    let exec_config = ExecConfig::new(
+        DEFAULT_ACCOUNTS.clone(),
+        *DEFAULT_WASM_CONFIG,
+        *DEFAULT_SYSTEM_CONFIG,
+        DEFAULT_VALIDATOR_SLOTS,
+        DEFAULT_AUCTION_DELAY,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS,
+        DEFAULT_ROUND_SEIGNIORAGE_RATE,
+        DEFAULT_UNBONDING_DELAY,
+        minimum_delegation_amount,
+        max_delegators_per_validator,
+        max_delegation_rate_increase,
+        DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    );
+    RunGenesisRequest::new(
+        *DEFAULT_GENESIS_CONFIG_HASH,
+        *DEFAULT_PROTOCOL_VERSION,
+        exec_config,
+    )
+}
+
+/// Runs genesis and then executes and commits each of the given requests, expecting success.
+pub(super) fn setup(
+    run_genesis_request: &RunGenesisRequest,
+    post_genesis_requests: Vec<ExecuteRequest>,
+) -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(run_genesis_request);
+
+    for request in post_genesis_requests {
+        builder.exec(request).commit().expect_success();
+    }
+
+    builder
+}
+
+pub(super) fn transfer_request(target: AccountHash, amount: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_TRANSFER_TO_ACCOUNT,
+        runtime_args! {
+            ARG_TARGET => target,
+            ARG_AMOUNT => U512::from(amount)
+        },
+    )
+    .build()
+}
+
+pub(super) fn add_bid_request(
+    validator_addr: AccountHash,
+    validator_pk: PublicKey,
+    delegation_rate: DelegationRate,
+) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        validator_addr,
+        CONTRACT_ADD_BID,
+        runtime_args! {
+            ARG_PUBLIC_KEY => validator_pk,
+            ARG_AMOUNT => U512::from(ADD_BID_AMOUNT),
+            ARG_DELEGATION_RATE => delegation_rate,
+        },
+    )
+    .build()
+}
+
+/// Asserts that the last executed request reverted with the given auction error.
+pub(super) fn assert_auction_error(builder: &InMemoryWasmTestBuilder, expected: auction::Error) {
+    let error = {
+        let response = builder
+            .get_exec_results()
+            .last()
+            .expect("should have last exec result");
+        let exec_response = response.last().expect("should have response");
+        exec_response.as_error().expect("should have error")
+    };
+
+    assert!(matches!(
+        error,
+        engine_state::Error::Exec(execution::Error::Revert(ApiError::AuctionError(auction_error)))
+        if *auction_error == expected as u8
+    ));
+}
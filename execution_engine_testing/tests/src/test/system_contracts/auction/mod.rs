@@ -1,2 +1,7 @@
+mod bid_settings;
 mod bids;
+mod delegation_limits;
 mod distribute;
+mod helpers;
+mod redelegate;
+mod scheduled_transfers;
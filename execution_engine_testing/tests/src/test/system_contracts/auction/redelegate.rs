@@ -0,0 +1,204 @@
+use casper_engine_test_support::internal::{
+    ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
+    DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_RUN_GENESIS_REQUEST,
+};
+use casper_execution_engine::core::engine_state::ExecuteRequest;
+use casper_types::{
+    runtime_args,
+    system::auction::{
+        self, Bids, Redelegations, UnbondingPurses, ARG_AMOUNT, ARG_DELEGATOR, ARG_NEW_VALIDATOR,
+        ARG_VALIDATOR, REDELEGATIONS_KEY, UNBONDING_PURSES_KEY,
+    },
+    PublicKey, RuntimeArgs, U512,
+};
+
+use super::helpers::{
+    self, add_bid_request, assert_auction_error, transfer_request, ADD_BID_DELEGATION_RATE,
+    CONTRACT_DELEGATE, DELEGATOR_1_ADDR, DELEGATOR_1_PK, SYSTEM_ADDR, SYSTEM_TRANSFER_AMOUNT,
+    TRANSFER_AMOUNT, VALIDATOR_1_ADDR, VALIDATOR_1_PK, VALIDATOR_2_ADDR, VALIDATOR_2_PK,
+};
+
+const CONTRACT_REDELEGATE: &str = "redelegate.wasm";
+
+const DELEGATE_AMOUNT: u64 = 125_000;
+const REDELEGATE_AMOUNT: u64 = 50_000;
+
+fn redelegate_request(amount: u64, new_validator: PublicKey) -> ExecuteRequest {
+    ExecuteRequestBuilder::standard(
+        *DELEGATOR_1_ADDR,
+        CONTRACT_REDELEGATE,
+        runtime_args! {
+            ARG_DELEGATOR => *DELEGATOR_1_PK,
+            ARG_VALIDATOR => *VALIDATOR_1_PK,
+            ARG_AMOUNT => U512::from(amount),
+            ARG_NEW_VALIDATOR => new_validator,
+        },
+    )
+    .build()
+}
+
+/// Sets up two validators with a single delegator delegating to the first one.
+fn setup() -> InMemoryWasmTestBuilder {
+    let delegate_request = ExecuteRequestBuilder::standard(
+        *DELEGATOR_1_ADDR,
+        CONTRACT_DELEGATE,
+        runtime_args! {
+            ARG_AMOUNT => U512::from(DELEGATE_AMOUNT),
+            ARG_VALIDATOR => *VALIDATOR_1_PK,
+            ARG_DELEGATOR => *DELEGATOR_1_PK,
+        },
+    )
+    .build();
+
+    let post_genesis_requests = vec![
+        transfer_request(SYSTEM_ADDR, SYSTEM_TRANSFER_AMOUNT),
+        transfer_request(*VALIDATOR_1_ADDR, TRANSFER_AMOUNT),
+        transfer_request(*VALIDATOR_2_ADDR, TRANSFER_AMOUNT),
+        transfer_request(*DELEGATOR_1_ADDR, TRANSFER_AMOUNT),
+        add_bid_request(*VALIDATOR_1_ADDR, *VALIDATOR_1_PK, ADD_BID_DELEGATION_RATE),
+        add_bid_request(*VALIDATOR_2_ADDR, *VALIDATOR_2_PK, ADD_BID_DELEGATION_RATE),
+        delegate_request,
+    ];
+
+    helpers::setup(&*DEFAULT_RUN_GENESIS_REQUEST, post_genesis_requests)
+}
+
+#[ignore]
+#[test]
+fn should_redelegate_at_next_auction() {
+    let mut builder = setup();
+    let timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+
+    let delegator_purse = builder
+        .get_account(*DELEGATOR_1_ADDR)
+        .expect("should have delegator account")
+        .main_purse();
+
+    builder
+        .exec(redelegate_request(REDELEGATE_AMOUNT, *VALIDATOR_2_PK))
+        .commit()
+        .expect_success();
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let redelegations: Redelegations = builder.get_value(auction_hash, REDELEGATIONS_KEY);
+    let redelegation_list = redelegations
+        .get(&VALIDATOR_1_PK)
+        .expect("should have pending redelegation");
+    assert_eq!(redelegation_list.len(), 1);
+    assert_eq!(
+        *redelegation_list[0].amount(),
+        U512::from(REDELEGATE_AMOUNT)
+    );
+
+    // Nothing moves until the auction is run
+    let bids: Bids = builder.get_bids();
+    assert_eq!(
+        *bids[&VALIDATOR_1_PK].delegators()[&DELEGATOR_1_PK].staked_amount(),
+        U512::from(DELEGATE_AMOUNT)
+    );
+    assert!(bids[&VALIDATOR_2_PK].delegators().is_empty());
+
+    let delegator_balance_before = builder.get_purse_balance(delegator_purse);
+
+    builder.run_auction(timestamp_millis, Vec::new());
+
+    let bids: Bids = builder.get_bids();
+    let old_delegator = &bids[&VALIDATOR_1_PK].delegators()[&DELEGATOR_1_PK];
+    assert_eq!(
+        *old_delegator.staked_amount(),
+        U512::from(DELEGATE_AMOUNT - REDELEGATE_AMOUNT)
+    );
+    assert_eq!(
+        builder.get_purse_balance(*old_delegator.bonding_purse()),
+        U512::from(DELEGATE_AMOUNT - REDELEGATE_AMOUNT)
+    );
+
+    let new_delegator = &bids[&VALIDATOR_2_PK].delegators()[&DELEGATOR_1_PK];
+    assert_eq!(
+        *new_delegator.staked_amount(),
+        U512::from(REDELEGATE_AMOUNT)
+    );
+    assert_eq!(
+        builder.get_purse_balance(*new_delegator.bonding_purse()),
+        U512::from(REDELEGATE_AMOUNT)
+    );
+
+    // The stake never left bonded status
+    let unbonding_purses: UnbondingPurses = builder.get_value(auction_hash, UNBONDING_PURSES_KEY);
+    assert!(unbonding_purses.is_empty());
+    assert_eq!(
+        builder.get_purse_balance(delegator_purse),
+        delegator_balance_before
+    );
+
+    let redelegations: Redelegations = builder.get_value(auction_hash, REDELEGATIONS_KEY);
+    assert!(redelegations.is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_remove_delegator_after_redelegating_whole_stake() {
+    let mut builder = setup();
+    let timestamp_millis = DEFAULT_GENESIS_TIMESTAMP_MILLIS + DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS;
+
+    builder
+        .exec(redelegate_request(DELEGATE_AMOUNT, *VALIDATOR_2_PK))
+        .commit()
+        .expect_success();
+
+    builder.run_auction(timestamp_millis, Vec::new());
+
+    let bids: Bids = builder.get_bids();
+    assert!(!bids[&VALIDATOR_1_PK]
+        .delegators()
+        .contains_key(&DELEGATOR_1_PK));
+    assert_eq!(
+        *bids[&VALIDATOR_2_PK].delegators()[&DELEGATOR_1_PK].staked_amount(),
+        U512::from(DELEGATE_AMOUNT)
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_redelegate_to_same_validator() {
+    let mut builder = setup();
+
+    builder
+        .exec(redelegate_request(REDELEGATE_AMOUNT, *VALIDATOR_1_PK))
+        .commit();
+
+    assert_auction_error(&builder, auction::Error::RedelegationToSameValidator);
+}
+
+#[ignore]
+#[test]
+fn should_not_redelegate_to_unknown_validator() {
+    let mut builder = setup();
+
+    builder
+        .exec(redelegate_request(REDELEGATE_AMOUNT, *DELEGATOR_1_PK))
+        .commit();
+
+    assert_auction_error(&builder, auction::Error::RedelegationValidatorNotFound);
+}
+
+#[ignore]
+#[test]
+fn should_not_redelegate_more_than_delegated() {
+    let mut builder = setup();
+
+    builder
+        .exec(redelegate_request(REDELEGATE_AMOUNT, *VALIDATOR_2_PK))
+        .commit()
+        .expect_success();
+
+    // Pending redelegations count against the delegated amount
+    builder
+        .exec(redelegate_request(
+            DELEGATE_AMOUNT - REDELEGATE_AMOUNT + 1,
+            *VALIDATOR_2_PK,
+        ))
+        .commit();
+
+    assert_auction_error(&builder, auction::Error::InvalidAmount);
+}
@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{
+    core::engine_state::ExecuteRequest, shared::stored_value::StoredValue,
+};
+use casper_types::{
+    account::AccountHash,
+    runtime_args,
+    system::auction::{
+        EraId, EraInfo, ScheduledTransfer, ScheduledTransfers, ARG_AMOUNT, ARG_COUNT, ARG_ERA_ID,
+        ARG_ESCROW_PURSE, ARG_ID, ARG_INTERVAL, ARG_REWARD_FACTORS, ARG_TARGET,
+        MAX_SCHEDULED_TRANSFERS_PER_ERA, METHOD_CANCEL_SCHEDULED_TRANSFER, METHOD_DISTRIBUTE,
+        METHOD_SCHEDULE_TRANSFER, SCHEDULED_TRANSFERS_KEY,
+    },
+    Key, PublicKey, RuntimeArgs, TransferAddr, URef, U512,
+};
+
+use super::helpers::{transfer_request, SYSTEM_ADDR, SYSTEM_TRANSFER_AMOUNT};
+
+const ACCOUNT_1_ADDR: AccountHash = AccountHash::new([1u8; 32]);
+
+const SCHEDULED_AMOUNT: u64 = 1_000;
+const SCHEDULED_INTERVAL: u64 = 2;
+const SCHEDULED_ID: u64 = 7;
+
+/// Funds the system account and account 1, and schedules `count` transfers from the default
+/// account to account 1 starting in the current era. Returns account 1's main purse and the
+/// stored schedule.
+fn setup(count: u64) -> (InMemoryWasmTestBuilder, URef, ScheduledTransfer) {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+    for target in &[SYSTEM_ADDR, ACCOUNT_1_ADDR] {
+        builder
+            .exec(transfer_request(*target, SYSTEM_TRANSFER_AMOUNT))
+            .commit()
+            .expect_success();
+    }
+
+    let target = builder
+        .get_account(ACCOUNT_1_ADDR)
+        .expect("should have account 1")
+        .main_purse();
+    builder
+        .exec(schedule_request(&builder, target, count))
+        .commit()
+        .expect_success();
+
+    let scheduled_transfers = get_scheduled_transfers(&mut builder);
+    assert_eq!(scheduled_transfers.len(), 1);
+    let scheduled_transfer = *scheduled_transfers
+        .values()
+        .next()
+        .expect("should have scheduled transfer");
+
+    (builder, target, scheduled_transfer)
+}
+
+/// Schedules `count` transfers from the default account to `target` starting in the current era.
+fn schedule_request(builder: &InMemoryWasmTestBuilder, target: URef, count: u64) -> ExecuteRequest {
+    ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        builder.get_auction_contract_hash(),
+        METHOD_SCHEDULE_TRANSFER,
+        runtime_args! {
+            ARG_TARGET => target,
+            ARG_AMOUNT => U512::from(SCHEDULED_AMOUNT),
+            ARG_ERA_ID => builder.get_era(),
+            ARG_INTERVAL => SCHEDULED_INTERVAL,
+            ARG_COUNT => count,
+            ARG_ID => Some(SCHEDULED_ID),
+        },
+    )
+    .build()
+}
+
+fn get_scheduled_transfers(builder: &mut InMemoryWasmTestBuilder) -> ScheduledTransfers {
+    let auction_hash = builder.get_auction_contract_hash();
+    builder.get_value(auction_hash, SCHEDULED_TRANSFERS_KEY)
+}
+
+/// Distributes the (empty) rewards of the current era, which executes the transfers due in it.
+fn distribute(builder: &mut InMemoryWasmTestBuilder) -> EraId {
+    let era_id = builder.get_era();
+    let reward_factors: BTreeMap<PublicKey, u64> = BTreeMap::new();
+    let distribute_request = ExecuteRequestBuilder::contract_call_by_hash(
+        SYSTEM_ADDR,
+        builder.get_auction_contract_hash(),
+        METHOD_DISTRIBUTE,
+        runtime_args! {
+            ARG_REWARD_FACTORS => reward_factors
+        },
+    )
+    .build();
+    builder.exec(distribute_request).commit().expect_success();
+    era_id
+}
+
+fn get_era_info(builder: &InMemoryWasmTestBuilder, era_id: EraId) -> EraInfo {
+    builder
+        .query(None, Key::EraInfo(era_id), &[])
+        .expect("should have era info")
+        .as_era_info()
+        .cloned()
+        .expect("should be era info")
+}
+
+fn assert_transfer_recorded(builder: &InMemoryWasmTestBuilder, transfer_addr: TransferAddr) {
+    match builder.query(None, Key::Transfer(transfer_addr), &[]) {
+        Ok(StoredValue::Transfer(transfer)) => {
+            assert_eq!(transfer.amount, U512::from(SCHEDULED_AMOUNT));
+            assert_eq!(transfer.id, Some(SCHEDULED_ID));
+        }
+        other => panic!("should have transfer: {:?}", other),
+    }
+}
+
+#[ignore]
+#[test]
+fn should_execute_scheduled_transfers_at_era_end() {
+    let (mut builder, target, scheduled_transfer) = setup(2);
+
+    let escrow_purse = *scheduled_transfer.escrow_purse();
+    let execution_amount = U512::from(SCHEDULED_AMOUNT) + *scheduled_transfer.fee();
+    let target_balance_before = builder.get_purse_balance(target);
+    assert_eq!(
+        builder.get_purse_balance(escrow_purse),
+        execution_amount * 2
+    );
+
+    // First execution is due at the end of the current era.
+    let era_id = distribute(&mut builder);
+    assert_eq!(
+        builder.get_purse_balance(target),
+        target_balance_before + U512::from(SCHEDULED_AMOUNT)
+    );
+    assert_eq!(builder.get_purse_balance(escrow_purse), execution_amount);
+    let era_info = get_era_info(&builder, era_id);
+    assert_eq!(era_info.transfers().len(), 1);
+    assert_transfer_recorded(&builder, era_info.transfers()[0]);
+
+    // Nothing is due in the next era.
+    builder.run_auction(1, Vec::new());
+    let era_id = distribute(&mut builder);
+    assert_eq!(
+        builder.get_purse_balance(target),
+        target_balance_before + U512::from(SCHEDULED_AMOUNT)
+    );
+    assert!(get_era_info(&builder, era_id).transfers().is_empty());
+
+    // The last execution empties the escrow purse and removes the schedule.
+    builder.run_auction(2, Vec::new());
+    let era_id = distribute(&mut builder);
+    assert_eq!(
+        builder.get_purse_balance(target),
+        target_balance_before + U512::from(SCHEDULED_AMOUNT * 2)
+    );
+    assert!(builder.get_purse_balance(escrow_purse).is_zero());
+    let era_info = get_era_info(&builder, era_id);
+    assert_eq!(era_info.transfers().len(), 1);
+    assert_transfer_recorded(&builder, era_info.transfers()[0]);
+    assert!(get_scheduled_transfers(&mut builder).is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_only_let_owner_cancel_scheduled_transfer() {
+    let (mut builder, _target, scheduled_transfer) = setup(3);
+
+    distribute(&mut builder);
+
+    let escrow_purse = *scheduled_transfer.escrow_purse();
+    let cancel_request = |sender| {
+        ExecuteRequestBuilder::contract_call_by_hash(
+            sender,
+            builder.get_auction_contract_hash(),
+            METHOD_CANCEL_SCHEDULED_TRANSFER,
+            runtime_args! {
+                ARG_ESCROW_PURSE => escrow_purse.addr(),
+            },
+        )
+        .build()
+    };
+    let other_cancel_request = cancel_request(ACCOUNT_1_ADDR);
+    let owner_cancel_request = cancel_request(*DEFAULT_ACCOUNT_ADDR);
+
+    builder.exec(other_cancel_request).commit();
+    assert!(builder.is_error());
+    assert_eq!(get_scheduled_transfers(&mut builder).len(), 1);
+
+    builder.exec(owner_cancel_request).commit().expect_success();
+    assert!(builder.get_purse_balance(escrow_purse).is_zero());
+    assert!(get_scheduled_transfers(&mut builder).is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_record_supply_including_burned_fees_in_era_info() {
+    let (mut builder, _target, scheduled_transfer) = setup(1);
+
+    let total_supply_before = builder.total_supply(None);
+
+    // The fee of the execution is burned, reducing the total supply.
+    let era_id = distribute(&mut builder);
+    let total_supply = builder.total_supply(None);
+    assert_eq!(
+        total_supply,
+        total_supply_before - *scheduled_transfer.fee()
+    );
+
+    let total_staked = builder
+        .get_bids()
+        .values()
+        .map(|bid| {
+            bid.total_staked_amount()
+                .expect("should have total staked amount")
+        })
+        .fold(U512::zero(), |total, staked| total + staked);
+
+    let supply = *get_era_info(&builder, era_id).supply();
+    assert_eq!(*supply.total_supply(), total_supply);
+    assert_eq!(*supply.total_staked(), total_staked);
+    assert_eq!(*supply.circulating_supply(), total_supply - total_staked);
+    assert!(supply.era_issuance().is_zero());
+    assert_eq!(*supply.total_burned(), *scheduled_transfer.fee());
+}
+
+#[ignore]
+#[test]
+fn should_limit_scheduled_transfers_executed_per_era() {
+    let (mut builder, target, _scheduled_transfer) = setup(1);
+
+    for _ in 0..MAX_SCHEDULED_TRANSFERS_PER_ERA {
+        builder
+            .exec(schedule_request(&builder, target, 1))
+            .commit()
+            .expect_success();
+    }
+    let target_balance_before = builder.get_purse_balance(target);
+
+    // One transfer more than the limit is due, so the last one is left for the next era.
+    let era_id = distribute(&mut builder);
+    assert_eq!(
+        get_era_info(&builder, era_id).transfers().len(),
+        MAX_SCHEDULED_TRANSFERS_PER_ERA
+    );
+    assert_eq!(get_scheduled_transfers(&mut builder).len(), 1);
+
+    builder.run_auction(1, Vec::new());
+    let era_id = distribute(&mut builder);
+    assert_eq!(get_era_info(&builder, era_id).transfers().len(), 1);
+    assert!(get_scheduled_transfers(&mut builder).is_empty());
+    assert_eq!(
+        builder.get_purse_balance(target),
+        target_balance_before
+            + U512::from(SCHEDULED_AMOUNT) * U512::from(MAX_SCHEDULED_TRANSFERS_PER_ERA + 1)
+    );
+}
@@ -1,3 +1,4 @@
 mod finalize_payment;
 mod get_payment_purse;
+mod payment_split;
 mod refund_purse;
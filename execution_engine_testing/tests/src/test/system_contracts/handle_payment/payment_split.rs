@@ -0,0 +1,151 @@
+use num_rational::Ratio;
+use once_cell::sync::Lazy;
+
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, UpgradeRequestBuilder, DEFAULT_GAS_PRICE,
+        DEFAULT_PAYMENT, DEFAULT_PROTOCOL_VERSION, DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{
+    core::engine_state::upgrade::ActivationPoint,
+    shared::{
+        motes::Motes,
+        system_config::{payment_split::PaymentSplit, SystemConfig},
+    },
+};
+use casper_types::{system::handle_payment::PaymentBreakdown, ProtocolVersion, RuntimeArgs};
+
+const DO_NOTHING_WASM: &str = "do_nothing.wasm";
+const DEFAULT_ACTIVATION_POINT: ActivationPoint = 1;
+
+static NEW_PROTOCOL_VERSION: Lazy<ProtocolVersion> = Lazy::new(|| {
+    ProtocolVersion::from_parts(
+        DEFAULT_PROTOCOL_VERSION.value().major,
+        DEFAULT_PROTOCOL_VERSION.value().minor,
+        DEFAULT_PROTOCOL_VERSION.value().patch + 1,
+    )
+});
+
+fn upgrade_payment_split(builder: &mut InMemoryWasmTestBuilder, payment_split: PaymentSplit) {
+    let default_system_config = SystemConfig::default();
+    let new_system_config = SystemConfig::new(
+        default_system_config.wasmless_transfer_cost(),
+        *default_system_config.auction_costs(),
+        *default_system_config.mint_costs(),
+        *default_system_config.handle_payment_costs(),
+        *default_system_config.standard_payment_costs(),
+        payment_split,
+    );
+
+    let mut upgrade_request = UpgradeRequestBuilder::new()
+        .with_current_protocol_version(*DEFAULT_PROTOCOL_VERSION)
+        .with_new_protocol_version(*NEW_PROTOCOL_VERSION)
+        .with_activation_point(DEFAULT_ACTIVATION_POINT)
+        .with_new_system_config(new_system_config)
+        .build();
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+}
+
+#[ignore]
+#[test]
+fn should_pay_whole_payment_to_proposer_by_default() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+    let proposer_balance_before = builder.get_proposer_purse_balance();
+    let total_supply_before = builder.total_supply(None);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        DO_NOTHING_WASM,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let exec_result = &builder.get_exec_result(0).expect("should have result")[0];
+    let payment = *exec_result.payment();
+    assert_eq!(
+        payment,
+        PaymentBreakdown {
+            proposer_reward: *DEFAULT_PAYMENT,
+            ..PaymentBreakdown::default()
+        }
+    );
+
+    assert_eq!(
+        builder.get_proposer_purse_balance(),
+        proposer_balance_before + *DEFAULT_PAYMENT
+    );
+    assert_eq!(builder.total_supply(None), total_supply_before);
+}
+
+#[ignore]
+#[test]
+fn should_refund_and_burn_after_upgrading_payment_split() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&*DEFAULT_RUN_GENESIS_REQUEST);
+
+    let payment_split = PaymentSplit {
+        refund_ratio: Ratio::new(1, 2),
+        burn_ratio: Ratio::new(1, 4),
+    };
+    upgrade_payment_split(&mut builder, payment_split);
+
+    let account_balance_before = {
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("should have account");
+        builder.get_purse_balance(account.main_purse())
+    };
+    let proposer_balance_before = builder.get_proposer_purse_balance();
+    let total_supply_before = builder.total_supply(None);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        DO_NOTHING_WASM,
+        RuntimeArgs::default(),
+    )
+    .with_protocol_version(*NEW_PROTOCOL_VERSION)
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+
+    let exec_result = &builder.get_exec_result(0).expect("should have result")[0];
+    let amount_spent = Motes::from_gas(exec_result.cost(), DEFAULT_GAS_PRICE)
+        .expect("should convert")
+        .value();
+    let expected_payment = PaymentBreakdown::new(
+        *DEFAULT_PAYMENT,
+        amount_spent,
+        payment_split.refund_ratio,
+        payment_split.burn_ratio,
+    );
+    let payment = *exec_result.payment();
+    assert_eq!(payment, expected_payment);
+    assert!(!payment.refund.is_zero());
+    assert!(!payment.burned.is_zero());
+
+    let account_balance_after = {
+        let account = builder
+            .get_account(*DEFAULT_ACCOUNT_ADDR)
+            .expect("should have account");
+        builder.get_purse_balance(account.main_purse())
+    };
+    assert_eq!(
+        account_balance_after,
+        account_balance_before - *DEFAULT_PAYMENT + payment.refund
+    );
+    assert_eq!(
+        builder.get_proposer_purse_balance(),
+        proposer_balance_before + payment.proposer_reward
+    );
+    assert_eq!(
+        builder.total_supply(None),
+        total_supply_before - payment.burned
+    );
+}
@@ -2,5 +2,6 @@ mod auction;
 mod auction_bidding;
 mod genesis;
 mod handle_payment;
+mod mint;
 mod standard_payment;
 mod upgrade;
@@ -8,6 +8,7 @@ use casper_engine_test_support::internal::{
 use casper_execution_engine::{
     core::engine_state::upgrade::ActivationPoint,
     shared::{
+        additive_map::AdditiveMap,
         host_function_costs::HostFunctionCosts,
         opcode_costs::{
             OpcodeCosts, DEFAULT_ADD_COST, DEFAULT_BIT_COST, DEFAULT_CONST_COST,
@@ -19,6 +20,7 @@ use casper_execution_engine::{
         },
         storage_costs::StorageCosts,
         stored_value::StoredValue,
+        transform::Transform,
         wasm_config::{WasmConfig, DEFAULT_MAX_STACK_HEIGHT, DEFAULT_WASM_MAX_MEMORY},
     },
 };
@@ -27,9 +29,9 @@ use casper_types::{
         auction::{
             AUCTION_DELAY_KEY, LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
         },
-        mint::ROUND_SEIGNIORAGE_RATE_KEY,
+        mint::{METHOD_BURN, ROUND_SEIGNIORAGE_RATE_KEY},
     },
-    CLValue, ProtocolVersion, U512,
+    CLValue, Contract, EntryPoints, ProtocolVersion, U512,
 };
 use num_rational::Ratio;
 
@@ -634,3 +636,61 @@ fn should_apply_global_state_upgrade() {
         "Should have modified locked funds period"
     );
 }
+
+#[ignore]
+#[test]
+fn should_install_system_contract_entry_points_added_since_genesis() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    // Make the mint look like it was installed before the `burn` entry point existed
+    let mint_hash = builder.get_mint_contract_hash();
+    let mint_contract = builder.get_contract(mint_hash).expect("mint should exist");
+    let mut legacy_entry_points = EntryPoints::new();
+    for entry_point in mint_contract.entry_points().clone().take_entry_points() {
+        if entry_point.name() != METHOD_BURN {
+            legacy_entry_points.add_entry_point(entry_point);
+        }
+    }
+    let legacy_mint_contract = Contract::new(
+        mint_contract.contract_package_hash(),
+        mint_contract.contract_wasm_hash(),
+        mint_contract.named_keys().clone(),
+        legacy_entry_points,
+        mint_contract.protocol_version(),
+    );
+    let mut effects = AdditiveMap::new();
+    effects.insert(
+        mint_hash.into(),
+        Transform::Write(StoredValue::Contract(legacy_mint_contract)),
+    );
+    let post_state_hash = builder.get_post_state_hash();
+    builder.commit_effects(post_state_hash, effects);
+
+    assert!(!builder
+        .get_contract(mint_hash)
+        .expect("mint should exist")
+        .has_entry_point(METHOD_BURN));
+
+    let sem_ver = PROTOCOL_VERSION.value();
+    let new_protocol_version =
+        ProtocolVersion::from_parts(sem_ver.major, sem_ver.minor, sem_ver.patch + 1);
+
+    let mut upgrade_request = {
+        UpgradeRequestBuilder::new()
+            .with_current_protocol_version(PROTOCOL_VERSION)
+            .with_new_protocol_version(new_protocol_version)
+            .with_activation_point(DEFAULT_ACTIVATION_POINT)
+            .build()
+    };
+
+    builder
+        .upgrade_with_upgrade_request(&mut upgrade_request)
+        .expect_upgrade_success();
+
+    assert!(builder
+        .get_contract(mint_hash)
+        .expect("mint should exist")
+        .has_entry_point(METHOD_BURN));
+}
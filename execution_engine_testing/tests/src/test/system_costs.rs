@@ -30,6 +30,7 @@ use casper_execution_engine::{
                 MintCosts, DEFAULT_BALANCE_COST, DEFAULT_MINT_COST,
                 DEFAULT_REDUCE_TOTAL_SUPPLY_COST, DEFAULT_TRANSFER_COST,
             },
+            payment_split::PaymentSplit,
             standard_payment_costs::StandardPaymentCosts,
             SystemConfig,
         },
@@ -188,6 +189,7 @@ fn upgraded_add_bid_and_withdraw_bid_have_expected_costs() {
         new_mint_costs,
         new_handle_payment_costs,
         new_standard_payment_costs,
+        PaymentSplit::default(),
     );
 
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -419,6 +421,7 @@ fn upgraded_delegate_and_undelegate_have_expected_costs() {
         new_mint_costs,
         new_handle_payment_costs,
         new_standard_payment_costs,
+        PaymentSplit::default(),
     );
 
     let mut builder = InMemoryWasmTestBuilder::default();
@@ -851,6 +854,7 @@ fn should_verify_wasm_add_bid_wasm_cost_is_not_recursive() {
         new_mint_costs,
         new_handle_payment_costs,
         new_standard_payment_costs,
+        PaymentSplit::default(),
     );
 
     let mut upgrade_request = {
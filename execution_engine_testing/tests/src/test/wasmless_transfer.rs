@@ -17,7 +17,8 @@ use casper_execution_engine::{
         motes::Motes,
         system_config::{
             auction_costs::AuctionCosts, handle_payment_costs::HandlePaymentCosts,
-            mint_costs::MintCosts, standard_payment_costs::StandardPaymentCosts, SystemConfig,
+            mint_costs::MintCosts, payment_split::PaymentSplit,
+            standard_payment_costs::StandardPaymentCosts, SystemConfig,
         },
     },
     storage::protocol_data::DEFAULT_WASMLESS_TRANSFER_COST,
@@ -928,6 +929,7 @@ fn transfer_wasmless_should_observe_upgraded_cost() {
         new_mint_costs,
         new_handle_payment_costs,
         new_standard_payment_costs,
+        PaymentSplit::default(),
     );
 
     let old_protocol_version = *DEFAULT_PROTOCOL_VERSION;
@@ -13,7 +13,7 @@ use itertools::Itertools;
 use prometheus::Registry;
 use semver::Version;
 use smallvec::SmallVec;
-use tracing::{debug, error, trace};
+use tracing::{debug, error, trace, warn};
 
 use casper_execution_engine::{
     core::engine_state::{
@@ -29,6 +29,7 @@ use casper_types::{ExecutionResult, ProtocolVersion, PublicKey, U512};
 use crate::{
     components::{
         block_executor::{event::State, metrics::BlockExecutorMetrics},
+        consensus::EraId,
         Component,
     },
     crypto::hash::Digest,
@@ -250,6 +251,36 @@ impl BlockExecutor {
         effects
     }
 
+    /// Records the stakes and rewards of the era that ended with the switch block being executed.
+    fn store_era_rewards<REv: ReactorEventT>(
+        &self,
+        effect_builder: EffectBuilder<REv>,
+        era_id: EraId,
+        pre_step_state_root_hash: Digest,
+        post_step_state_root_hash: Digest,
+    ) -> Effects<Event> {
+        let protocol_version = self.protocol_version;
+        async move {
+            match effect_builder
+                .get_era_rewards_from_global_state(
+                    era_id,
+                    protocol_version,
+                    pre_step_state_root_hash,
+                    post_step_state_root_hash,
+                )
+                .await
+            {
+                Some(era_rewards) => {
+                    effect_builder
+                        .put_era_rewards_to_storage(Box::new(era_rewards))
+                        .await;
+                }
+                None => warn!(%era_id, "could not read era rewards from global state"),
+            }
+        }
+        .ignore()
+    }
+
     /// Executes the first deploy in `state.remaining_deploys`, or creates the executed block if
     /// there are no remaining deploys left.
     fn execute_next_deploy_or_create_block<REv: ReactorEventT>(
@@ -614,12 +645,21 @@ impl<REv: ReactorEventT> Component<REv> for BlockExecutor {
                         post_state_hash,
                         next_era_validators,
                     }) => {
+                        let era_id = state.finalized_block.era_id();
+                        let pre_step_state_root_hash = state.state_root_hash;
                         state.state_root_hash = post_state_hash.into();
-                        self.finalize_block_execution(
+                        let mut effects = self.store_era_rewards(
+                            effect_builder,
+                            era_id,
+                            pre_step_state_root_hash,
+                            state.state_root_hash,
+                        );
+                        effects.extend(self.finalize_block_execution(
                             effect_builder,
                             state,
                             Some(next_era_validators),
-                        )
+                        ));
+                        effects
                     }
                     _ => {
                         // When step fails, the auction process is broken and we should panic.
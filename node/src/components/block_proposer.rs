@@ -3,9 +3,11 @@
 //! The block proposer stores deploy hashes in memory, tracking their suitability for inclusion into
 //! a new block. Upon request, it returns a list of candidates that can be included.
 
+mod config;
 mod deploy_sets;
 mod event;
 mod metrics;
+mod pending_deploys;
 
 #[cfg(test)]
 mod tests;
@@ -26,14 +28,19 @@ use crate::{
         requests::{BlockProposerRequest, ProtoBlockRequest, StateStoreRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects,
     },
-    types::{chainspec::DeployConfig, Chainspec, DeployHash, DeployHeader, ProtoBlock, Timestamp},
+    types::{
+        chainspec::DeployConfig, Chainspec, DeployHash, DeployHeader, DeployHeaderExt, ProtoBlock,
+        Timestamp,
+    },
     NodeRng,
 };
 use casper_execution_engine::shared::gas::Gas;
+pub use config::Config;
 pub(crate) use deploy_sets::BlockProposerDeploySets;
 pub(crate) use event::{DeployType, Event};
 use metrics::BlockProposerMetrics;
 use num_traits::Zero;
+use pending_deploys::PendingDeploys;
 
 /// Block proposer component.
 #[derive(DataSize, Debug)]
@@ -78,6 +85,8 @@ enum BlockProposerState {
         state_key: Vec<u8>,
         /// The deploy config from the current chainspec.
         deploy_config: DeployConfig,
+        /// The block proposer configuration.
+        config: Config,
     },
     /// Normal operation.
     Ready(BlockProposerReady),
@@ -90,6 +99,7 @@ impl BlockProposer {
         effect_builder: EffectBuilder<REv>,
         next_finalized_block: BlockHeight,
         chainspec: &Chainspec,
+        config: Config,
     ) -> Result<(Self, Effects<Event>), prometheus::Error>
     where
         REv: From<Event> + From<StorageRequest> + From<StateStoreRequest> + Send + 'static,
@@ -114,6 +124,7 @@ impl BlockProposer {
                 pending: Vec::new(),
                 state_key,
                 deploy_config: chainspec.deploy_config,
+                config,
             },
             metrics: BlockProposerMetrics::new(registry)?,
         };
@@ -146,6 +157,7 @@ where
                     ref mut pending,
                     state_key,
                     deploy_config,
+                    config,
                 },
                 Event::Loaded {
                     sets,
@@ -158,6 +170,7 @@ where
                         .with_next_finalized(next_finalized_block),
                     unhandled_finalized: Default::default(),
                     deploy_config: *deploy_config,
+                    config: *config,
                     state_key: state_key.clone(),
                     request_queue: Default::default(),
                 };
@@ -211,6 +224,8 @@ struct BlockProposerReady {
     unhandled_finalized: HashSet<DeployHash>,
     /// We don't need the whole Chainspec here, just the deploy config.
     deploy_config: DeployConfig,
+    /// The block proposer configuration.
+    config: Config,
     /// Key for storing the block proposer state.
     state_key: Vec<u8>,
     /// The queue of requests awaiting being handled.
@@ -341,7 +356,18 @@ impl BlockProposerReady {
             info!(%hash, "deploy rejected from the buffer");
         } else {
             self.sets.pending.insert(hash, deploy_or_transfer);
-            info!(%hash, "added deploy to the buffer");
+            let evicted = self
+                .sets
+                .pending
+                .evict_lowest_priority(self.config.max_pending_deploys as usize);
+            if evicted.contains(&hash) {
+                info!(%hash, "deploy rejected from the full buffer due to its low gas price");
+            } else {
+                info!(%hash, "added deploy to the buffer");
+            }
+            for evicted_hash in evicted.iter().filter(|evicted_hash| **evicted_hash != hash) {
+                info!(hash = %evicted_hash, "evicted deploy from the full buffer");
+            }
         }
     }
 
@@ -417,6 +443,9 @@ impl BlockProposerReady {
     }
 
     /// Returns a list of candidates for inclusion into a block.
+    ///
+    /// Pending deploys are considered in order of descending gas price and then age, while the
+    /// deploys of each account are kept in the order in which they were created.
     fn propose_proto_block(
         &mut self,
         deploy_config: DeployConfig,
@@ -434,7 +463,7 @@ impl BlockProposerReady {
         let mut block_gas_running_total = Gas::zero();
         let mut block_size_running_total = 0usize;
 
-        for (hash, deploy_type) in self.sets.pending.iter() {
+        for (hash, deploy_type) in self.sets.pending.in_proposal_order() {
             let at_max_transfers = transfers.len() == max_transfers;
             let at_max_deploys = wasm_deploys.len() == max_deploys
                 || (deploy_type.is_wasm()
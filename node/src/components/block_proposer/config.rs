@@ -0,0 +1,21 @@
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+/// The default maximum number of deploys held by the block proposer.
+const DEFAULT_MAX_PENDING_DEPLOYS: u32 = 100_000;
+
+/// Configuration options for the block proposer.
+#[derive(Copy, Clone, DataSize, Debug, Deserialize, Serialize)]
+pub struct Config {
+    /// The maximum number of deploys pending inclusion in a block.  Once exceeded, the deploys
+    /// with the lowest gas price, and among those the newest, are evicted.
+    pub max_pending_deploys: u32,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            max_pending_deploys: DEFAULT_MAX_PENDING_DEPLOYS,
+        }
+    }
+}
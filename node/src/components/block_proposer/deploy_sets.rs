@@ -6,14 +6,14 @@ use std::{
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
-use super::{event::DeployType, BlockHeight, FinalizationQueue};
+use super::{BlockHeight, FinalizationQueue, PendingDeploys};
 use crate::types::{Chainspec, DeployHash, DeployHeader, Timestamp};
 
 /// Stores the internal state of the BlockProposer.
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
 pub struct BlockProposerDeploySets {
-    /// The collection of deploys pending for inclusion in a block.
-    pub(super) pending: HashMap<DeployHash, DeployType>,
+    /// The collection of deploys pending for inclusion in a block, ordered by priority.
+    pub(super) pending: PendingDeploys,
     /// The deploys that have already been included in a finalized block.
     pub(super) finalized_deploys: HashMap<DeployHash, DeployHeader>,
     /// The next block height we expect to be finalized.
@@ -28,7 +28,7 @@ pub struct BlockProposerDeploySets {
 
 impl Default for BlockProposerDeploySets {
     fn default() -> Self {
-        let pending = HashMap::new();
+        let pending = Default::default();
         let finalized_deploys = Default::default();
         let next_finalized = Default::default();
         let finalization_queue = Default::default();
@@ -77,7 +77,9 @@ impl BlockProposerDeploySets {
     /// Prunes expired deploy information from the BlockProposerState, returns the total deploys
     /// pruned
     pub(crate) fn prune(&mut self, current_instant: Timestamp) -> usize {
-        let pending = prune_pending_deploys(&mut self.pending, current_instant);
+        let pending = self
+            .pending
+            .retain(|_hash, wrapper| !wrapper.header().expired(current_instant));
         let finalized = prune_deploys(&mut self.finalized_deploys, current_instant);
         pending + finalized
     }
@@ -93,14 +95,3 @@ pub(super) fn prune_deploys(
     deploys.retain(|_hash, header| !header.expired(current_instant));
     initial_len - deploys.len()
 }
-
-/// Prunes expired deploy information from an individual pending deploy collection, returns the
-/// total deploys pruned
-pub(super) fn prune_pending_deploys(
-    deploys: &mut HashMap<DeployHash, DeployType>,
-    current_instant: Timestamp,
-) -> usize {
-    let initial_len = deploys.len();
-    deploys.retain(|_hash, wrapper| !wrapper.header().expired(current_instant));
-    initial_len - deploys.len()
-}
@@ -0,0 +1,200 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::{BTreeSet, BinaryHeap, HashMap},
+};
+
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+use super::event::DeployType;
+use crate::types::{DeployHash, Timestamp};
+
+/// The position of a pending deploy in the fee market.
+///
+/// Deploys with a higher gas price are ordered first; among deploys with the same gas price, older
+/// ones are ordered first.  The hash breaks any remaining ties.
+#[derive(Clone, Copy, DataSize, Debug, Eq, PartialEq)]
+struct DeployPriority {
+    gas_price: u64,
+    timestamp: Timestamp,
+    hash: DeployHash,
+}
+
+impl DeployPriority {
+    fn new(hash: DeployHash, deploy_type: &DeployType) -> Self {
+        DeployPriority {
+            gas_price: deploy_type.header().gas_price(),
+            timestamp: deploy_type.header().timestamp(),
+            hash,
+        }
+    }
+}
+
+impl Ord for DeployPriority {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .gas_price
+            .cmp(&self.gas_price)
+            .then_with(|| self.timestamp.cmp(&other.timestamp))
+            .then_with(|| self.hash.cmp(&other.hash))
+    }
+}
+
+impl PartialOrd for DeployPriority {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The deploys pending for inclusion in a block, indexed by their priority.
+///
+/// Serialized as a plain map from deploy hash to deploy, the priority index being rebuilt on
+/// deserialization.
+#[derive(Clone, DataSize, Debug, Default, Deserialize, Serialize)]
+#[serde(
+    from = "HashMap<DeployHash, DeployType>",
+    into = "HashMap<DeployHash, DeployType>"
+)]
+pub(crate) struct PendingDeploys {
+    deploys: HashMap<DeployHash, DeployType>,
+    by_priority: BTreeSet<DeployPriority>,
+}
+
+impl PendingDeploys {
+    /// Returns the number of pending deploys.
+    pub(crate) fn len(&self) -> usize {
+        self.deploys.len()
+    }
+
+    /// Returns `true` if the deploy is pending.
+    #[cfg(test)]
+    pub(crate) fn contains_key(&self, hash: &DeployHash) -> bool {
+        self.deploys.contains_key(hash)
+    }
+
+    /// Adds a pending deploy, returning the previous entry for the same hash if any.
+    pub(crate) fn insert(
+        &mut self,
+        hash: DeployHash,
+        deploy_type: DeployType,
+    ) -> Option<DeployType> {
+        let _ = self
+            .by_priority
+            .insert(DeployPriority::new(hash, &deploy_type));
+        let previous = self.deploys.insert(hash, deploy_type);
+        if let Some(previous) = &previous {
+            let priority = DeployPriority::new(hash, previous);
+            if priority != DeployPriority::new(hash, &self.deploys[&hash]) {
+                let _ = self.by_priority.remove(&priority);
+            }
+        }
+        previous
+    }
+
+    /// Removes a pending deploy.
+    pub(crate) fn remove(&mut self, hash: &DeployHash) -> Option<DeployType> {
+        let deploy_type = self.deploys.remove(hash)?;
+        let _ = self
+            .by_priority
+            .remove(&DeployPriority::new(*hash, &deploy_type));
+        Some(deploy_type)
+    }
+
+    /// Removes the deploys for which `keep` returns `false`, returning how many were removed.
+    pub(crate) fn retain<F>(&mut self, mut keep: F) -> usize
+    where
+        F: FnMut(&DeployHash, &DeployType) -> bool,
+    {
+        let initial_len = self.deploys.len();
+        let by_priority = &mut self.by_priority;
+        self.deploys.retain(|hash, deploy_type| {
+            let retained = keep(hash, deploy_type);
+            if !retained {
+                let _ = by_priority.remove(&DeployPriority::new(*hash, deploy_type));
+            }
+            retained
+        });
+        initial_len - self.deploys.len()
+    }
+
+    /// Evicts the lowest-priority deploys until at most `max_len` remain, returning the hashes of
+    /// the evicted deploys.
+    pub(crate) fn evict_lowest_priority(&mut self, max_len: usize) -> Vec<DeployHash> {
+        let mut evicted = Vec::new();
+        while self.deploys.len() > max_len {
+            let lowest = match self.by_priority.iter().next_back() {
+                Some(lowest) => *lowest,
+                None => break,
+            };
+            let _ = self.remove(&lowest.hash);
+            evicted.push(lowest.hash);
+        }
+        evicted
+    }
+
+    /// Returns the pending deploys in the order in which they should be considered for a block.
+    ///
+    /// The deploys of each account are kept in the order in which they were created, and are
+    /// interleaved by the priority of each account's oldest remaining deploy, so an account can't
+    /// have a later deploy included ahead of its earlier ones by paying a higher gas price.
+    pub(crate) fn in_proposal_order(&self) -> Vec<(&DeployHash, &DeployType)> {
+        let mut by_account = HashMap::new();
+        for (hash, deploy_type) in &self.deploys {
+            by_account
+                .entry(deploy_type.header().account().to_account_hash())
+                .or_insert_with(Vec::new)
+                .push((deploy_type.header().timestamp(), hash, deploy_type));
+        }
+
+        // Each account's deploys, newest first so that the oldest can be popped off the end.
+        let mut queues: Vec<Vec<_>> = by_account
+            .into_iter()
+            .map(|(_, mut deploys)| {
+                deploys.sort_unstable_by(|lhs, rhs| (rhs.0, rhs.1).cmp(&(lhs.0, lhs.1)));
+                deploys
+            })
+            .collect();
+
+        let head_priority = |queue: &Vec<(Timestamp, &DeployHash, &DeployType)>| {
+            queue
+                .last()
+                .map(|(_, hash, deploy_type)| DeployPriority::new(**hash, deploy_type))
+        };
+        let mut heads: BinaryHeap<_> = queues
+            .iter()
+            .enumerate()
+            .filter_map(|(index, queue)| Some(Reverse((head_priority(queue)?, index))))
+            .collect();
+
+        let mut ordered = Vec::with_capacity(self.deploys.len());
+        while let Some(Reverse((_, index))) = heads.pop() {
+            let queue = &mut queues[index];
+            if let Some((_, hash, deploy_type)) = queue.pop() {
+                ordered.push((hash, deploy_type));
+            }
+            if let Some(priority) = head_priority(queue) {
+                heads.push(Reverse((priority, index)));
+            }
+        }
+        ordered
+    }
+}
+
+impl From<HashMap<DeployHash, DeployType>> for PendingDeploys {
+    fn from(deploys: HashMap<DeployHash, DeployType>) -> Self {
+        let by_priority = deploys
+            .iter()
+            .map(|(hash, deploy_type)| DeployPriority::new(*hash, deploy_type))
+            .collect();
+        PendingDeploys {
+            deploys,
+            by_priority,
+        }
+    }
+}
+
+impl From<PendingDeploys> for HashMap<DeployHash, DeployType> {
+    fn from(pending: PendingDeploys) -> Self {
+        pending.deploys
+    }
+}
@@ -10,7 +10,7 @@ use super::*;
 use crate::{
     crypto::AsymmetricKeyExt,
     testing::TestRng,
-    types::{BlockLike, Deploy, DeployHash, TimeDiff},
+    types::{BlockLike, Deploy, DeployExt, DeployHash, TimeDiff},
 };
 
 const DEFAULT_TEST_GAS_PRICE: u64 = 1;
@@ -94,6 +94,7 @@ fn create_test_proposer() -> BlockProposerReady {
     BlockProposerReady {
         sets: Default::default(),
         deploy_config: Default::default(),
+        config: Default::default(),
         state_key: b"block-proposer-test".to_vec(),
         request_queue: Default::default(),
         unhandled_finalized: Default::default(),
@@ -670,3 +671,156 @@ fn should_return_deploy_dependencies() {
     assert_eq!(deploys2.len(), 1);
     assert!(deploys2.contains(deploy2.id()));
 }
+
+/// Creates a wasm deploy from the given account, paying the default gas.
+fn generate_deploy_from_account(
+    rng: &mut TestRng,
+    secret_key: &SecretKey,
+    timestamp: Timestamp,
+    gas_price: u64,
+) -> Deploy {
+    let payment = ExecutableDeployItem::ModuleBytes {
+        module_bytes: Bytes::new(),
+        args: runtime_args! {
+            ARG_AMOUNT => default_gas_payment().value()
+        },
+    };
+    let session = ExecutableDeployItem::ModuleBytes {
+        module_bytes: Bytes::new(),
+        args: RuntimeArgs::new(),
+    };
+    Deploy::new(
+        timestamp,
+        TimeDiff::from(Duration::from_millis(100)),
+        gas_price,
+        vec![],
+        "chain".to_string(),
+        payment,
+        session,
+        secret_key,
+        rng,
+    )
+}
+
+#[test]
+fn should_propose_deploys_by_gas_price_then_age() {
+    let block_time = Timestamp::from(120);
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+
+    let cheap_old = generate_deploy_from_account(
+        &mut rng,
+        &SecretKey::random(&mut rng),
+        Timestamp::from(100),
+        1,
+    );
+    let cheap_new = generate_deploy_from_account(
+        &mut rng,
+        &SecretKey::random(&mut rng),
+        Timestamp::from(110),
+        1,
+    );
+    let expensive = generate_deploy_from_account(
+        &mut rng,
+        &SecretKey::random(&mut rng),
+        Timestamp::from(115),
+        5,
+    );
+    for deploy in &[&cheap_new, &expensive, &cheap_old] {
+        proposer.add_deploy_or_transfer(block_time, *deploy.id(), deploy.deploy_type().unwrap());
+    }
+
+    let mut config = DeployConfig::default();
+    config.block_max_deploy_count = 2;
+    let block = proposer.propose_proto_block(config, block_time, HashSet::new(), true);
+    assert_eq!(
+        block.wasm_deploys(),
+        &vec![*expensive.id(), *cheap_old.id()]
+    );
+}
+
+#[test]
+fn should_keep_account_deploys_in_creation_order() {
+    let block_time = Timestamp::from(120);
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+
+    let secret_key = SecretKey::random(&mut rng);
+    let first = generate_deploy_from_account(&mut rng, &secret_key, Timestamp::from(100), 1);
+    let second = generate_deploy_from_account(&mut rng, &secret_key, Timestamp::from(101), 10);
+    let other = generate_deploy_from_account(
+        &mut rng,
+        &SecretKey::random(&mut rng),
+        Timestamp::from(102),
+        5,
+    );
+    for deploy in &[&second, &other, &first] {
+        proposer.add_deploy_or_transfer(block_time, *deploy.id(), deploy.deploy_type().unwrap());
+    }
+
+    // The account's second deploy pays the most, but can't overtake the account's first one.
+    let block =
+        proposer.propose_proto_block(DeployConfig::default(), block_time, HashSet::new(), true);
+    assert_eq!(
+        block.wasm_deploys(),
+        &vec![*other.id(), *first.id(), *second.id()]
+    );
+}
+
+#[test]
+fn should_evict_lowest_priced_deploys_when_full() {
+    let block_time = Timestamp::from(120);
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+    proposer.config.max_pending_deploys = 2;
+
+    let mut add_deploy = |rng: &mut TestRng, timestamp: u64, gas_price: u64| {
+        let secret_key = SecretKey::random(rng);
+        let deploy =
+            generate_deploy_from_account(rng, &secret_key, Timestamp::from(timestamp), gas_price);
+        proposer.add_deploy_or_transfer(block_time, *deploy.id(), deploy.deploy_type().unwrap());
+        *deploy.id()
+    };
+    let cheap_old = add_deploy(&mut rng, 100, 1);
+    let cheap_new = add_deploy(&mut rng, 101, 1);
+    // The newest of the cheapest deploys is evicted to make room.
+    let expensive = add_deploy(&mut rng, 102, 3);
+    // A deploy paying less than all pending ones is rejected outright.
+    let too_cheap = add_deploy(&mut rng, 103, 0);
+
+    assert_eq!(proposer.sets.pending.len(), 2);
+    assert!(proposer.sets.pending.contains_key(&cheap_old));
+    assert!(proposer.sets.pending.contains_key(&expensive));
+    assert!(!proposer.sets.pending.contains_key(&cheap_new));
+    assert!(!proposer.sets.pending.contains_key(&too_cheap));
+}
+
+#[test]
+fn should_restore_priority_order_after_loading_state() {
+    let block_time = Timestamp::from(120);
+    let mut rng = crate::new_rng();
+    let mut proposer = create_test_proposer();
+
+    let cheap = generate_deploy_from_account(
+        &mut rng,
+        &SecretKey::random(&mut rng),
+        Timestamp::from(100),
+        1,
+    );
+    let expensive = generate_deploy_from_account(
+        &mut rng,
+        &SecretKey::random(&mut rng),
+        Timestamp::from(101),
+        2,
+    );
+    for deploy in &[&cheap, &expensive] {
+        proposer.add_deploy_or_transfer(block_time, *deploy.id(), deploy.deploy_type().unwrap());
+    }
+
+    let serialized = bincode::serialize(&proposer.sets).unwrap();
+    proposer.sets = bincode::deserialize(&serialized).unwrap();
+    let evicted = proposer.sets.pending.evict_lowest_priority(1);
+
+    assert_eq!(evicted, vec![*cheap.id()]);
+    assert!(proposer.sets.pending.contains_key(expensive.id()));
+}
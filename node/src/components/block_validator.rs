@@ -27,7 +27,7 @@ use crate::{
         requests::{BlockValidationRequest, FetcherRequest, StorageRequest},
         EffectBuilder, EffectExt, EffectOptionExt, Effects, Responder,
     },
-    types::{BlockLike, Chainspec, Deploy, DeployHash, Timestamp},
+    types::{BlockLike, Chainspec, Deploy, DeployHash, DeployHeaderExt, Timestamp},
     NodeRng,
 };
 use keyed_counter::KeyedCounter;
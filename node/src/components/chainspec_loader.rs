@@ -35,6 +35,7 @@ use casper_execution_engine::{
     },
     shared::stored_value::StoredValue,
 };
+use casper_rpc_client::rpcs::info as rpc_info;
 use casper_types::{bytesrepr::FromBytes, ProtocolVersion};
 
 #[cfg(test)]
@@ -147,6 +148,17 @@ impl From<ProtocolConfig> for NextUpgrade {
     }
 }
 
+impl From<NextUpgrade> for rpc_info::NextUpgrade {
+    fn from(next_upgrade: NextUpgrade) -> Self {
+        rpc_info::NextUpgrade {
+            activation_point: rpc_info::ActivationPoint {
+                era_id: next_upgrade.activation_point.era_id.0,
+            },
+            protocol_version: next_upgrade.protocol_version,
+        }
+    }
+}
+
 impl Display for NextUpgrade {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         write!(
@@ -625,6 +637,9 @@ where
             Event::Request(ChainspecLoaderRequest::GetChainspecInfo(responder)) => {
                 responder.respond(self.new_chainspec_info()).ignore()
             }
+            Event::Request(ChainspecLoaderRequest::GetChainspec(responder)) => {
+                responder.respond(Arc::clone(&self.chainspec)).ignore()
+            }
             Event::CheckForNextUpgrade => self.check_for_next_upgrade(effect_builder),
             Event::GotNextUpgrade(next_upgrade) => self.handle_got_next_upgrade(next_upgrade),
             Event::PutToStorage { version } => {
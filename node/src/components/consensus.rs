@@ -48,7 +48,7 @@ use crate::{
 pub use config::Config;
 pub(crate) use consensus_protocol::{BlockContext, EraReport};
 pub(crate) use era_supervisor::{EraId, EraSupervisor};
-pub(crate) use protocols::highway::HighwayProtocol;
+pub(crate) use protocols::{highway::HighwayProtocol, simple_bft::SimpleBftProtocol};
 use traits::NodeIdT;
 
 #[derive(DataSize, Clone, Serialize, Deserialize)]
@@ -9,7 +9,10 @@ use casper_types::SecretKey;
 use crate::{
     components::consensus::EraId,
     crypto::hash::Digest,
-    types::{chainspec::HighwayConfig, Chainspec, TimeDiff, Timestamp},
+    types::{
+        chainspec::{ConsensusProtocolName, HighwayConfig},
+        Chainspec, TimeDiff, Timestamp,
+    },
     utils::External,
 };
 
@@ -43,6 +46,8 @@ impl Default for Config {
 /// Consensus protocol configuration.
 #[derive(DataSize, Debug)]
 pub(crate) struct ProtocolConfig {
+    /// The consensus protocol to run in each era.
+    pub(crate) consensus_protocol: ConsensusProtocolName,
     pub(crate) highway_config: HighwayConfig,
     pub(crate) era_duration: TimeDiff,
     pub(crate) minimum_era_height: u64,
@@ -67,6 +72,7 @@ pub(crate) struct ProtocolConfig {
 impl From<&Chainspec> for ProtocolConfig {
     fn from(chainspec: &Chainspec) -> Self {
         ProtocolConfig {
+            consensus_protocol: chainspec.core_config.consensus_protocol,
             highway_config: chainspec.highway_config,
             era_duration: chainspec.core_config.era_duration,
             minimum_era_height: chainspec.core_config.minimum_era_height,
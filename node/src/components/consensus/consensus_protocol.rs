@@ -28,7 +28,7 @@ impl BlockContext {
         self.timestamp
     }
 
-    #[cfg(test)]
+    /// The block's relative height within the era.
     pub(crate) fn height(&self) -> u64 {
         self.height
     }
@@ -60,6 +60,33 @@ pub(crate) struct TerminalBlockData<C: Context> {
     pub(crate) inactive_validators: Vec<C::ValidatorId>,
 }
 
+/// The fault tolerance with which a block was finalized, as observed by this node.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Finality<C: Context> {
+    /// The level of the summit that finalized the block. Protocols with immediate finality use 1.
+    pub(crate) summit_level: u64,
+    /// The achieved fault tolerance threshold, in parts per million of the total weight: The block
+    /// can only be reverted if validators with at least this much weight equivocate.
+    pub(crate) fault_tolerance_ppm: u64,
+    /// The validators whose units or votes contributed to the summit.
+    pub(crate) contributors: Vec<C::ValidatorId>,
+}
+
+/// A validator's participation in a consensus instance, as observed by this node.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ValidatorStats<C: Context> {
+    /// The validator's ID.
+    pub(crate) validator_id: C::ValidatorId,
+    /// The number of units, or proposals and votes, created by the validator.
+    pub(crate) units_produced: u64,
+    /// The number of rounds in which the validator created at least one unit, proposal or vote.
+    pub(crate) rounds_participated: u64,
+    /// The validator's latest round exponent, if the protocol uses one.
+    pub(crate) round_exponent: Option<u8>,
+    /// Whether the validator is known to have equivocated, in this or an earlier instance.
+    pub(crate) equivocated: bool,
+}
+
 /// A finalized block. All nodes are guaranteed to see the same sequence of blocks, and to agree
 /// about all the information contained in this type, as long as the total weight of faulty
 /// validators remains below the threshold.
@@ -78,6 +105,9 @@ pub(crate) struct FinalizedBlock<C: Context> {
     pub(crate) terminal_block_data: Option<TerminalBlockData<C>>,
     /// Proposer of this value
     pub(crate) proposer: C::ValidatorId,
+    /// The fault tolerance with which the block was finalized. Unlike the other fields, this is
+    /// local information: Other nodes may have detected the block's finality at a different level.
+    pub(crate) finality: Finality<C>,
 }
 
 // TODO: get rid of anyhow::Error; use variant and derive Clone and PartialEq. This is for testing.
@@ -197,6 +227,9 @@ pub(crate) trait ConsensusProtocol<I, C: Context>: Send {
     /// Returns the list of all validators that were observed as faulty in this consensus instance.
     fn validators_with_evidence(&self) -> Vec<&C::ValidatorId>;
 
+    /// Returns each validator's participation in this instance so far.
+    fn validator_stats(&self) -> Vec<ValidatorStats<C>>;
+
     /// Returns true if the protocol has received some messages since initialization.
     fn has_received_messages(&self) -> bool;
 
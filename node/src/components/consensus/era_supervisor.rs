@@ -42,14 +42,16 @@ use crate::{
         },
         metrics::ConsensusMetrics,
         traits::NodeIdT,
-        ActionId, Config, ConsensusMessage, Event, ReactorEventT, TimerId,
+        ActionId, Config, ConsensusMessage, Event, HighwayProtocol, ReactorEventT,
+        SimpleBftProtocol, TimerId,
     },
     crypto::hash::Digest,
     effect::{requests::ConsensusRequest, EffectBuilder, EffectExt, Effects, Responder},
     fatal,
     types::{
-        ActivationPoint, Block, BlockHash, BlockHeader, BlockLike, FinalitySignature,
-        FinalizedBlock, ProtoBlock, TimeDiff, Timestamp,
+        chainspec::ConsensusProtocolName, ActivationPoint, Block, BlockFinality, BlockHash,
+        BlockHeader, BlockLike, EraValidatorPerformance, FinalitySignature, FinalizedBlock,
+        ProtoBlock, TimeDiff, Timestamp, ValidatorPerformance,
     },
     utils::WithDir,
     NodeRng,
@@ -149,7 +151,6 @@ where
         protocol_config: ProtocolConfig,
         next_upgrade_activation_point: Option<ActivationPoint>,
         registry: &Registry,
-        new_consensus: Box<ConsensusConstructor<I>>,
     ) -> Result<(Self, Effects<Event<I>>), Error> {
         if current_era < protocol_config.last_activation_point {
             panic!(
@@ -167,6 +168,12 @@ where
         let metrics = ConsensusMetrics::new(registry)
             .expect("failure to setup and register ConsensusMetrics");
         let genesis_start_time = protocol_config.timestamp;
+        let new_consensus: Box<ConsensusConstructor<I>> = match protocol_config.consensus_protocol {
+            ConsensusProtocolName::Highway => Box::new(HighwayProtocol::<I, ClContext>::new_boxed),
+            ConsensusProtocolName::SimpleBft => {
+                Box::new(SimpleBftProtocol::<I, ClContext>::new_boxed)
+            }
+        };
 
         let era_supervisor = Self {
             active_eras: Default::default(),
@@ -389,6 +396,19 @@ where
         self.active_eras.get(&era_id).map_or(false, has_validator)
     }
 
+    /// Returns the secret key we sign consensus messages with.
+    pub(crate) fn secret_signing_key(&self) -> &SecretKey {
+        &self.secret_signing_key
+    }
+
+    /// Returns the validators of all active eras.
+    pub(crate) fn active_validators(&self) -> HashSet<PublicKey> {
+        self.active_eras
+            .values()
+            .flat_map(|era| era.validators().keys().cloned())
+            .collect()
+    }
+
     /// Inspect the active eras.
     #[cfg(test)]
     pub(crate) fn active_eras(&self) -> &HashMap<EraId, Era<I>> {
@@ -658,12 +678,27 @@ where
         if faulty_num == old_faulty_num {
             info!(era = era_id.0, "stop voting in era");
             era.consensus.deactivate_validator();
+            let validators = era
+                .consensus
+                .validator_stats()
+                .into_iter()
+                .map(|stats| ValidatorPerformance {
+                    public_key: stats.validator_id,
+                    units_produced: stats.units_produced,
+                    rounds_participated: stats.rounds_participated,
+                    round_exponent: stats.round_exponent,
+                    equivocated: stats.equivocated,
+                })
+                .collect();
+            let performance = EraValidatorPerformance::new(era_id, validators);
             if self.should_upgrade_after(&era_id) {
                 // If the next era is at or after the upgrade activation point, stop the node.
                 info!(era = era_id.0, "shutting down for upgrade");
                 self.era_supervisor.stop_for_upgrade = true;
             }
-            Effects::new()
+            self.effect_builder
+                .put_era_validator_performance_to_storage(Box::new(performance))
+                .ignore()
         } else {
             let deactivate_era = move |_| Event::DeactivateEra {
                 era_id,
@@ -944,6 +979,7 @@ where
                 terminal_block_data,
                 equivocators,
                 proposer,
+                finality,
             }) => {
                 let era = self.era_supervisor.active_eras.get_mut(&era_id).unwrap();
                 era.add_accusations(&equivocators);
@@ -973,11 +1009,30 @@ where
                 self.era_supervisor
                     .metrics
                     .finalized_block(&finalized_block);
-                // Announce the finalized proto block.
-                let mut effects = self
-                    .effect_builder
-                    .announce_finalized_block(finalized_block.clone())
-                    .ignore();
+                // Record the fault tolerance with which we observed the block's finality.
+                let block_finality = BlockFinality::new(
+                    finalized_block.height(),
+                    era_id,
+                    finality.summit_level,
+                    finality.fault_tolerance_ppm,
+                    finality.contributors,
+                );
+                // The finality must be stored before the block is executed: The linear chain reads
+                // it from storage once the executed block has been added.
+                let effect_builder = self.effect_builder;
+                let block_to_execute = finalized_block.clone();
+                let mut effects = async move {
+                    effect_builder
+                        .put_block_finality_to_storage(Box::new(block_finality))
+                        .await;
+                    // Announce the finalized proto block.
+                    effect_builder
+                        .announce_finalized_block(block_to_execute.clone())
+                        .await;
+                    // Request execution of the finalized block.
+                    effect_builder.execute_block(block_to_execute).await;
+                }
+                .ignore();
                 self.era_supervisor.next_block_height = finalized_block.height() + 1;
                 if finalized_block.era_report().is_some() {
                     // This was the era's last block. Schedule deactivating this era.
@@ -990,8 +1045,6 @@ where
                     };
                     effects.extend(self.effect_builder.set_timeout(delay).event(deactivate_era));
                 }
-                // Request execution of the finalized block.
-                effects.extend(self.effect_builder.execute_block(finalized_block).ignore());
                 self.era_supervisor.update_consensus_pause();
                 effects
             }
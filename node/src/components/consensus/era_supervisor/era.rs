@@ -12,8 +12,10 @@ use casper_types::{PublicKey, U512};
 
 use crate::{
     components::consensus::{
-        candidate_block::CandidateBlock, cl_context::ClContext,
-        consensus_protocol::ConsensusProtocol, protocols::highway::HighwayProtocol,
+        candidate_block::CandidateBlock,
+        cl_context::ClContext,
+        consensus_protocol::ConsensusProtocol,
+        protocols::{highway::HighwayProtocol, simple_bft::SimpleBftProtocol},
     },
     types::{ProtoBlock, Timestamp},
 };
@@ -235,10 +237,12 @@ where
                 } else {
                     (*highway).estimate_heap_size()
                 }
+            } else if let Some(sbft) = any_ref.downcast_ref::<SimpleBftProtocol<I, ClContext>>() {
+                (*sbft).estimate_heap_size()
             } else {
                 warn!(
-                    "could not downcast consensus protocol to \
-                    HighwayProtocol<I, ClContext> to determine heap allocation size"
+                    "could not downcast consensus protocol to a known protocol type \
+                    to determine heap allocation size"
                 );
                 0
             }
@@ -8,7 +8,7 @@ use tracing::{trace, warn};
 
 use crate::{
     components::consensus::{
-        consensus_protocol::{FinalizedBlock, TerminalBlockData},
+        consensus_protocol::{Finality, FinalizedBlock, TerminalBlockData},
         highway_core::{
             highway::Highway,
             state::{Observation, State, Unit, Weight},
@@ -60,7 +60,7 @@ impl<C: Context> FinalityDetector<C> {
             return Err(FttExceeded(fault_w));
         }
         Ok(iter::from_fn(move || {
-            let bhash = self.next_finalized(state)?;
+            let (bhash, lvl) = self.next_finalized_with_level(state)?;
             // Index exists, since we have units from them.
             let to_id = |vidx: ValidatorIndex| highway.validators().id(vidx).unwrap().clone();
             let block = state.block(bhash);
@@ -75,6 +75,7 @@ impl<C: Context> FinalityDetector<C> {
                 terminal_block_data,
                 equivocators: unit.panorama.iter_faulty().map(to_id).collect(),
                 proposer: to_id(unit.creator),
+                finality: self.finality(lvl, bhash, highway),
             };
             trace!(panorama = ?state.panorama(), ?finalized_block, "finality detected");
             Some(finalized_block)
@@ -82,7 +83,18 @@ impl<C: Context> FinalityDetector<C> {
     }
 
     /// Returns the next block, if any has been finalized since the last call.
+    #[cfg(test)]
     pub(super) fn next_finalized<'a>(&mut self, state: &'a State<C>) -> Option<&'a C::Hash> {
+        self.next_finalized_with_level(state)
+            .map(|(bhash, _)| bhash)
+    }
+
+    /// Returns the next block, if any has been finalized since the last call, together with the
+    /// level of the summit that finalized it.
+    fn next_finalized_with_level<'a>(
+        &mut self,
+        state: &'a State<C>,
+    ) -> Option<(&'a C::Hash, usize)> {
         let start_time = Timestamp::now();
         let candidate = self.next_candidate(state)?;
         // For `lvl` → ∞, the quorum converges to a fixed value. After level 63, it is closer
@@ -96,7 +108,7 @@ impl<C: Context> FinalityDetector<C> {
                 self.last_finalized = Some(*candidate);
                 let elapsed = start_time.elapsed();
                 trace!(%elapsed, "found finalized block");
-                return Some(candidate);
+                return Some((candidate, lvl));
             }
             // The required quorum increases with decreasing level, so choosing `target_lvl`
             // greater than `lvl` would always yield a summit of level `lvl` or lower.
@@ -124,6 +136,46 @@ impl<C: Context> FinalityDetector<C> {
         horizons_iter.skip(1).take(target_lvl).count()
     }
 
+    /// Returns the fault tolerance achieved by the level-`lvl` summit for `candidate`, and the
+    /// validators that are part of it.
+    ///
+    /// The summit was found using the quorum for our configured FTT, but its committees may see
+    /// more than that. Its actual quorum is the lowest weight any member sees of its committee.
+    fn summit_ftt(
+        &self,
+        lvl: usize,
+        candidate: &C::Hash,
+        state: &State<C>,
+    ) -> (Weight, Vec<ValidatorIndex>) {
+        let total_w = state.total_weight();
+        let quorum = self.quorum_for_lvl(lvl, total_w);
+        let latest = state.panorama().iter().map(Observation::correct).collect();
+        let sec0 = Horizon::level0(candidate, state, &latest);
+        let horizons: Vec<_> = iter::successors(Some(sec0), |sec| sec.next(quorum))
+            .take(lvl + 1)
+            .collect();
+        let achieved_quorum = horizons
+            .windows(2)
+            .filter_map(|pair| pair[0].committee_quorum(&pair[1].committee()))
+            .min()
+            .unwrap_or(quorum);
+        // The level-1 committee contains the members of all higher levels.
+        let committee = horizons.get(1).map_or_else(Vec::new, Horizon::committee);
+        (Self::ftt_for_lvl(lvl, achieved_quorum, total_w), committee)
+    }
+
+    /// Returns the FTT of a summit with the specified level and quorum. This is the inverse of
+    /// `quorum_for_lvl`.
+    fn ftt_for_lvl(lvl: usize, quorum: Weight, total_w: Weight) -> Weight {
+        // ftt = (2 * quorum - total_w) (1 - 1/2^lvl)
+        //     = (2 * quorum - total_w) (2^lvl - 1) / 2^lvl
+        assert!(lvl < 64, "lvl must be less than 64");
+        let pow_lvl = 1u128 << lvl;
+        let excess = (2 * u128::from(quorum)).saturating_sub(u128::from(total_w));
+        // Since this is a guarantee, we round down when dividing.
+        Weight((excess * (pow_lvl - 1) / pow_lvl) as u64)
+    }
+
     /// Returns the quorum required by a summit with the specified level and the required FTT.
     fn quorum_for_lvl(&self, lvl: usize, total_w: Weight) -> Weight {
         // A level-lvl summit with quorum  total_w/2 + t  has relative FTT  2t(1 − 1/2^lvl). So:
@@ -163,6 +215,19 @@ impl<C: Context> FinalityDetector<C> {
         self.ftt
     }
 
+    /// Returns the fault tolerance with which `bhash` was finalized by a level-`lvl` summit.
+    fn finality(&self, lvl: usize, bhash: &C::Hash, highway: &Highway<C>) -> Finality<C> {
+        let to_id = |vidx: ValidatorIndex| highway.validators().id(vidx).unwrap().clone();
+        let state = highway.state();
+        let (ftt, committee) = self.summit_ftt(lvl, bhash, state);
+        let ftt_ppm = u128::from(ftt) * 1_000_000 / u128::from(state.total_weight());
+        Finality {
+            summit_level: lvl as u64,
+            fault_tolerance_ppm: ftt_ppm as u64,
+            contributors: committee.into_iter().map(to_id).collect(),
+        }
+    }
+
     /// Creates the information for the terminal block: which validators were inactive, and how
     /// rewards should be distributed.
     fn create_terminal_block_data(
@@ -235,6 +300,13 @@ mod tests {
         assert_eq!(Some(&b0), fd4.next_finalized(&state));
         assert_eq!(None, fd4.next_finalized(&state));
 
+        // The summit's committee is `{ALICE, BOB}`, and each member sees the other's unit, so
+        // its quorum is 9 and its fault tolerance is 4.
+        assert_eq!(
+            (Weight(4), vec![ALICE, BOB]),
+            fd4.summit_ftt(1, &b0, &state)
+        );
+
         // Adding another level to the summit increases `B0`'s fault tolerance to 6.
         let _a2 = add_unit!(state, rng, ALICE, None; a1, b1, c1)?;
         let _b2 = add_unit!(state, rng, BOB, None; a1, b1, c1)?;
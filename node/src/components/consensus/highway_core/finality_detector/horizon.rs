@@ -52,6 +52,11 @@ impl<'a, C: Context> Horizon<'a, C> {
         }
     }
 
+    /// Returns the committee, i.e. the validators that have a unit in this horizon.
+    pub(super) fn committee(&self) -> Committee {
+        self.sequence_numbers.keys_some().collect()
+    }
+
     /// Returns a horizon `s` of units each of which can see a quorum of units in `self` by
     /// validators that are part of `s`.
     pub(super) fn next(&self, quorum: Weight) -> Option<Self> {
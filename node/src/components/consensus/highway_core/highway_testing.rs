@@ -499,6 +499,7 @@ where
             terminal_block_data,
             equivocators: _,
             proposer: _,
+            finality: _,
         } in finalized_values
         {
             trace!(
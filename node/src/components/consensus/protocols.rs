@@ -1 +1,2 @@
 pub(crate) mod highway;
+pub(crate) mod simple_bft;
@@ -23,7 +23,7 @@ use casper_types::{system::auction::BLOCK_REWARD, U512};
 use crate::{
     components::consensus::{
         config::{Config, ProtocolConfig},
-        consensus_protocol::{BlockContext, ConsensusProtocol, ProtocolOutcome},
+        consensus_protocol::{BlockContext, ConsensusProtocol, ProtocolOutcome, ValidatorStats},
         highway_core::{
             active_validator::Effect as AvEffect,
             finality_detector::{FinalityDetector, FttExceeded},
@@ -754,6 +754,10 @@ where
         self.highway.validators_with_evidence().collect()
     }
 
+    fn validator_stats(&self) -> Vec<ValidatorStats<C>> {
+        participation::validator_stats(&self.highway)
+    }
+
     fn has_received_messages(&self) -> bool {
         !self.highway.state().is_empty()
             || !self.synchronizer.is_empty()
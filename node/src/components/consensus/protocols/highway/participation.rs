@@ -2,9 +2,10 @@ use std::cmp::Reverse;
 
 use crate::{
     components::consensus::{
+        consensus_protocol::ValidatorStats,
         highway_core::{
             highway::Highway,
-            state::{Fault, State},
+            state::{Fault, Observation, State},
             validators::ValidatorIndex,
         },
         traits::Context,
@@ -46,6 +47,56 @@ impl Status {
     }
 }
 
+/// The units a validator has contributed to a Highway instance.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+struct Activity {
+    units: u64,
+    rounds: u64,
+    round_exp: Option<u8>,
+}
+
+impl Activity {
+    /// Returns the `Activity` of a validator, based on their swimlane in our panorama. Faulty
+    /// validators' swimlanes are not well-defined, so they are reported without any units.
+    fn for_index<C: Context>(idx: ValidatorIndex, state: &State<C>) -> Activity {
+        let latest = match &state.panorama()[idx] {
+            Observation::Correct(hash) => hash,
+            Observation::None | Observation::Faulty => return Activity::default(),
+        };
+        // The swimlane is in reverse chronological order, so units in the same round are adjacent.
+        let mut round_ids: Vec<Timestamp> = state
+            .swimlane(latest)
+            .map(|(_, unit)| unit.round_id())
+            .collect();
+        round_ids.dedup();
+        let unit = state.unit(latest);
+        Activity {
+            units: unit.seq_number + 1,
+            rounds: round_ids.len() as u64,
+            round_exp: Some(unit.round_exp),
+        }
+    }
+}
+
+/// Returns the participation of each validator in the Highway instance.
+pub(crate) fn validator_stats<C: Context>(highway: &Highway<C>) -> Vec<ValidatorStats<C>> {
+    let state = highway.state();
+    highway
+        .validators()
+        .enumerate_ids()
+        .map(|(idx, v_id)| {
+            let activity = Activity::for_index(idx, state);
+            ValidatorStats {
+                validator_id: v_id.clone(),
+                units_produced: activity.units,
+                rounds_participated: activity.rounds,
+                round_exponent: activity.round_exp,
+                equivocated: state.is_faulty(idx),
+            }
+        })
+        .collect()
+}
+
 /// A map of status (faulty, inactive) by validator ID.
 #[derive(Debug)]
 pub(crate) struct Participation<C>
@@ -95,3 +146,36 @@ impl<C: Context> Participation<C> {
         }
     }
 }
+
+#[allow(unused_qualifications)] // This is to suppress warnings originating in the test macros.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::consensus::highway_core::state::{tests::*, Weight};
+
+    #[test]
+    fn activity_test() -> Result<(), AddUnitError<TestContext>> {
+        let mut state = State::new_test(&[Weight(5), Weight(4)], 0);
+        let mut rng = crate::new_rng();
+
+        // Round ID 0, length 32: Alice creates two units.
+        let a0 = add_unit!(state, rng, ALICE, 0, 5u8, None; N, N)?;
+        let a1 = add_unit!(state, rng, ALICE, 20, 5u8, None; a0, N)?;
+        // Round ID 32, length 32: Alice creates one unit.
+        let a2 = add_unit!(state, rng, ALICE, 52, 5u8, None; a1, N)?;
+        // Round ID 64: Alice doesn't participate.
+        // Round ID 96, length 16: Alice creates two units.
+        let a3 = add_unit!(state, rng, ALICE, 96, 4u8, None; a2, N)?;
+        add_unit!(state, rng, ALICE, 106, 4u8, None; a3, N)?;
+
+        let expected = Activity {
+            units: 5,
+            rounds: 3,
+            round_exp: Some(4),
+        };
+        assert_eq!(expected, Activity::for_index(ALICE, &state));
+        // Bob hasn't produced any units.
+        assert_eq!(Activity::default(), Activity::for_index(BOB, &state));
+        Ok(())
+    }
+}
@@ -0,0 +1,1905 @@
+//! A simple round-based Byzantine fault tolerant consensus protocol with immediate finality.
+//!
+//! Blocks are decided one height at a time. Each height consists of one or more rounds, and each
+//! round has a leader, chosen round-robin among the era's validators, who proposes a block.
+//! Validators then cast a _prevote_ and a _precommit_ for it, or for nil if they didn't receive an
+//! acceptable proposal in time. A block is finalized as soon as validators with more than two
+//! thirds of the total weight precommit it in the same round.
+//!
+//! Once a validator sees a prevote quorum for a block, it locks on it and won't prevote for any
+//! other block in later rounds of the same height, unless it sees a newer prevote quorum for that
+//! one. This ensures that two conflicting blocks can never both be finalized, as long as less than
+//! one third of the weight is faulty.
+//!
+//! Every proposal contains the precommits that finalized its parent. That way all nodes agree on
+//! which validators participated, and can compute the same rewards and inactive validators for
+//! the era's terminal block.
+//!
+//! Every validator sends a message to every other validator in each step, so this protocol is only
+//! suitable for small, permissioned networks.
+
+use std::{
+    any::Any,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fs::{self, File},
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use datasize::DataSize;
+use num_traits::AsPrimitive;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::{error, info, trace, warn};
+
+use casper_types::{system::auction::BLOCK_REWARD, U512};
+
+use crate::{
+    components::consensus::{
+        config::{Config, ProtocolConfig},
+        consensus_protocol::{
+            BlockContext, ConsensusProtocol, Finality, FinalizedBlock, ProtocolOutcome,
+            TerminalBlockData, ValidatorStats,
+        },
+        highway_core::{
+            validators::{ValidatorIndex, ValidatorMap, Validators},
+            Weight,
+        },
+        traits::{ConsensusValueT, Context, NodeIdT, ValidatorSecret},
+        ActionId, TimerId,
+    },
+    types::{TimeDiff, Timestamp},
+    NodeRng,
+};
+
+/// The timer for the start of a round, when the leader proposes a block.
+const TIMER_ID_ROUND_START: TimerId = TimerId(0);
+/// The timer for the current step's timeout.
+const TIMER_ID_TIMEOUT: TimerId = TimerId(1);
+
+/// Proposals and votes for heights this far or further ahead of ours are dropped.
+const MAX_FUTURE_HEIGHTS: u64 = 10;
+/// Proposals and votes for rounds this far or further ahead of the current one are dropped. For
+/// future heights, the current round is taken to be 0.
+const MAX_FUTURE_ROUNDS: u32 = 100;
+/// The step timeout doubles with every round of the same height, up to this many times.
+const MAX_TIMEOUT_DOUBLINGS: u32 = 10;
+/// The maximum number of finalized blocks sent in reply to a single sync request.
+const MAX_SYNC_HEIGHTS: u64 = 10;
+/// A peer's sync requests for heights we already sent it are ignored for this many block times.
+const SYNC_REQUEST_COOLDOWN: u64 = 10;
+
+type ProtocolOutcomes<I, C> = Vec<ProtocolOutcome<I, C>>;
+type ValueHash<C> = <<C as Context>::ConsensusValue as ConsensusValueT>::Hash;
+
+/// The two kinds of votes cast in every round.
+#[derive(
+    Clone, Copy, DataSize, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+enum VoteKind {
+    Prevote,
+    Precommit,
+}
+
+/// A signed prevote or precommit.
+#[derive(Clone, DataSize, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::Hash: Serialize",
+    deserialize = "C::Hash: Deserialize<'de>",
+))]
+struct SignedVote<C: Context> {
+    height: u64,
+    round: u32,
+    kind: VoteKind,
+    /// The hash of the proposal voted for, or `None` for a nil vote.
+    proposal_hash: Option<C::Hash>,
+    validator_idx: ValidatorIndex,
+    signature: C::Signature,
+}
+
+impl<C: Context> SignedVote<C> {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        instance_id: &C::InstanceId,
+        height: u64,
+        round: u32,
+        kind: VoteKind,
+        proposal_hash: Option<C::Hash>,
+        validator_idx: ValidatorIndex,
+        secret: &C::ValidatorSecret,
+        rng: &mut NodeRng,
+    ) -> Self {
+        let hash = Self::hash_to_sign(instance_id, height, round, kind, &proposal_hash);
+        SignedVote {
+            height,
+            round,
+            kind,
+            proposal_hash,
+            validator_idx,
+            signature: secret.sign(&hash, rng),
+        }
+    }
+
+    /// Returns whether the signature is valid for the given validator.
+    fn verify(&self, instance_id: &C::InstanceId, validator_id: &C::ValidatorId) -> bool {
+        let hash = Self::hash_to_sign(
+            instance_id,
+            self.height,
+            self.round,
+            self.kind,
+            &self.proposal_hash,
+        );
+        C::verify_signature(&hash, validator_id, &self.signature)
+    }
+
+    fn hash_to_sign(
+        instance_id: &C::InstanceId,
+        height: u64,
+        round: u32,
+        kind: VoteKind,
+        proposal_hash: &Option<C::Hash>,
+    ) -> C::Hash {
+        let data = (instance_id, height, round, kind, proposal_hash);
+        C::hash(&bincode::serialize(&data).expect("should serialize vote"))
+    }
+}
+
+/// The content of a block proposal. Validators vote on its hash.
+#[derive(Clone, DataSize, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::Hash: Serialize",
+    deserialize = "C::Hash: Deserialize<'de>",
+))]
+struct Proposal<C: Context> {
+    value: C::ConsensusValue,
+    timestamp: Timestamp,
+    /// The validator who originally created the proposal.
+    proposer: ValidatorIndex,
+    /// The precommits that finalized the previous block. Empty for the era's first block.
+    parent_commit: Vec<SignedVote<C>>,
+}
+
+impl<C: Context> Proposal<C> {
+    fn hash(&self) -> C::Hash {
+        C::hash(&bincode::serialize(self).expect("should serialize proposal"))
+    }
+}
+
+/// A proposal, signed by the leader of the round in which it is proposed.
+#[derive(Clone, DataSize, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::Hash: Serialize",
+    deserialize = "C::Hash: Deserialize<'de>",
+))]
+struct SignedProposal<C: Context> {
+    height: u64,
+    round: u32,
+    proposal: Proposal<C>,
+    /// If this re-proposes a block that got a prevote quorum in an earlier round, that round.
+    valid_round: Option<u32>,
+    signature: C::Signature,
+}
+
+impl<C: Context> SignedProposal<C> {
+    fn new(
+        instance_id: &C::InstanceId,
+        height: u64,
+        round: u32,
+        proposal: Proposal<C>,
+        valid_round: Option<u32>,
+        secret: &C::ValidatorSecret,
+        rng: &mut NodeRng,
+    ) -> Self {
+        let hash = Self::hash_to_sign(instance_id, height, round, &proposal.hash(), valid_round);
+        SignedProposal {
+            height,
+            round,
+            proposal,
+            valid_round,
+            signature: secret.sign(&hash, rng),
+        }
+    }
+
+    /// Returns whether the signature is valid for the given validator.
+    fn verify(&self, instance_id: &C::InstanceId, validator_id: &C::ValidatorId) -> bool {
+        let hash = Self::hash_to_sign(
+            instance_id,
+            self.height,
+            self.round,
+            &self.proposal.hash(),
+            self.valid_round,
+        );
+        C::verify_signature(&hash, validator_id, &self.signature)
+    }
+
+    fn hash_to_sign(
+        instance_id: &C::InstanceId,
+        height: u64,
+        round: u32,
+        proposal_hash: &C::Hash,
+        valid_round: Option<u32>,
+    ) -> C::Hash {
+        let data = (instance_id, height, round, proposal_hash, valid_round);
+        C::hash(&bincode::serialize(&data).expect("should serialize proposal"))
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::Hash: Serialize",
+    deserialize = "C::Hash: Deserialize<'de>",
+))]
+enum SimpleBftMessage<C: Context> {
+    Proposal(SignedProposal<C>),
+    Vote(SignedVote<C>),
+    /// Two conflicting votes signed by the same validator.
+    Evidence(SignedVote<C>, SignedVote<C>),
+    /// A request for everything needed to catch up, starting at the given height.
+    SyncRequest {
+        height: u64,
+    },
+}
+
+impl<C: Context> SimpleBftMessage<C> {
+    fn serialize(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("should serialize message")
+    }
+
+    /// Returns the height the message refers to, if it is a proposal or vote.
+    fn height(&self) -> Option<u64> {
+        match self {
+            SimpleBftMessage::Proposal(proposal) => Some(proposal.height),
+            SimpleBftMessage::Vote(vote) => Some(vote.height),
+            SimpleBftMessage::Evidence(..) | SimpleBftMessage::SyncRequest { .. } => None,
+        }
+    }
+}
+
+/// An error due to an invalid incoming message.
+#[derive(Debug, Error, PartialEq)]
+enum MessageError {
+    #[error("the validator index {0:?} is out of range")]
+    UnknownValidator(ValidatorIndex),
+    #[error("invalid signature")]
+    InvalidSignature,
+    #[error("the votes in the evidence are not conflicting")]
+    NotConflicting,
+}
+
+/// The reason why a validator is considered faulty.
+#[derive(Clone, DataSize, Debug)]
+enum Fault<C: Context> {
+    /// The validator was slashed in a previous era and is banned from this one.
+    Banned,
+    /// We have two conflicting votes signed by the validator.
+    Direct(SignedVote<C>, SignedVote<C>),
+    /// The validator was marked faulty based on evidence from a different era.
+    Indirect,
+}
+
+/// The step of the current round.
+#[derive(Clone, Copy, DataSize, Debug, Eq, PartialEq)]
+enum Step {
+    /// Waiting for the leader's proposal.
+    Propose,
+    /// Prevoted; waiting for a prevote quorum.
+    Prevote,
+    /// Precommitted; waiting for a precommit quorum.
+    Precommit,
+    /// The era's terminal block has been finalized.
+    Finished,
+}
+
+/// Whether a consensus value has been validated.
+#[derive(Clone, Copy, DataSize, Debug, Eq, PartialEq)]
+enum Validity {
+    Pending,
+    Valid,
+    Invalid,
+}
+
+/// The proposals and votes a validator has contributed to this era.
+#[derive(Clone, DataSize, Debug, Default)]
+struct Activity {
+    /// The number of proposals and votes signed by the validator.
+    messages: u64,
+    /// The heights and rounds in which the validator proposed or voted.
+    rounds: BTreeSet<(u64, u32)>,
+}
+
+/// The kinds of messages we sign in each round, in the order in which we sign them.
+#[derive(Clone, Copy, DataSize, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+enum MessageKind {
+    Proposal,
+    Vote(VoteKind),
+}
+
+/// The height, round and kind of a message we signed.
+#[derive(Clone, Copy, DataSize, Debug, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+struct SignedPosition {
+    height: u64,
+    round: u32,
+    kind: MessageKind,
+}
+
+/// Our own validator index and secret key, if we are an active validator.
+#[derive(DataSize)]
+struct ActiveValidator<C: Context> {
+    idx: ValidatorIndex,
+    secret: C::ValidatorSecret,
+    /// The file in which `last_signed` is persisted, so that it survives a restart.
+    last_signed_file: Option<PathBuf>,
+    /// The latest message we signed in this era. We never sign a message at or before it again,
+    /// since that could be an equivocation.
+    last_signed: Option<SignedPosition>,
+}
+
+impl<C: Context> ActiveValidator<C> {
+    fn new(
+        idx: ValidatorIndex,
+        secret: C::ValidatorSecret,
+        last_signed_file: Option<PathBuf>,
+    ) -> Self {
+        let last_signed = last_signed_file
+            .as_ref()
+            .map(Self::read_last_signed)
+            .transpose()
+            .map_err(|err| match err.kind() {
+                io::ErrorKind::NotFound => (),
+                _ => panic!(
+                    "got an error reading last signed file {:?}: {:?}",
+                    last_signed_file, err
+                ),
+            })
+            .ok()
+            .flatten();
+        ActiveValidator {
+            idx,
+            secret,
+            last_signed_file,
+            last_signed,
+        }
+    }
+
+    fn read_last_signed<P: AsRef<Path>>(path: P) -> io::Result<SignedPosition> {
+        let mut file = File::open(path)?;
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    fn write_last_signed(&self, position: SignedPosition) -> io::Result<()> {
+        let last_signed_file = match self.last_signed_file.as_ref() {
+            None => return Ok(()),
+            Some(file) => file,
+        };
+        if let Some(parent_directory) = last_signed_file.parent() {
+            fs::create_dir_all(parent_directory)?;
+        }
+        let mut file = File::create(last_signed_file)?;
+        let bytes = serde_json::to_vec(&position)?;
+        file.write_all(&bytes)
+    }
+
+    /// Returns whether we may sign a message at the given position. If so, the position is
+    /// persisted before returning, so the message must then be signed.
+    fn prepare_to_sign(&mut self, position: SignedPosition) -> bool {
+        if let Some(last_signed) = self.last_signed {
+            if position <= last_signed {
+                warn!(
+                    ?position,
+                    ?last_signed,
+                    "not signing; already signed a later message"
+                );
+                return false;
+            }
+        }
+        self.write_last_signed(position).unwrap_or_else(|err| {
+            panic!(
+                "should successfully write last signed position to {:?}, got {:?}",
+                self.last_signed_file, err
+            )
+        });
+        self.last_signed = Some(position);
+        true
+    }
+}
+
+#[derive(DataSize)]
+pub(crate) struct SimpleBftProtocol<I, C>
+where
+    I: DataSize,
+    C: Context,
+{
+    instance_id: C::InstanceId,
+    validators: Validators<C::ValidatorId>,
+    weights: ValidatorMap<Weight>,
+    total_weight: Weight,
+    /// The validators that take turns as leaders, i.e. all validators that are not banned.
+    leaders: Vec<ValidatorIndex>,
+    /// The era's random seed, which determines the first leader.
+    seed: u64,
+    faults: BTreeMap<ValidatorIndex, Fault<C>>,
+    /// The minimum delay between two blocks, and the timeout of the first round's steps.
+    block_time: TimeDiff,
+    era_start_time: Timestamp,
+    era_end_time: Timestamp,
+    minimum_era_height: u64,
+    /// The timestamp of the last finalized block.
+    last_timestamp: Option<Timestamp>,
+    /// The height of the next block to be finalized, relative to the era's start.
+    height: u64,
+    round: u32,
+    step: Step,
+    round_start: Timestamp,
+    /// The time at which the current step times out.
+    timeout: Option<Timestamp>,
+    /// The latest round in which we precommitted a proposal at the current height, and its hash.
+    locked: Option<(u32, C::Hash)>,
+    /// The latest round in which we saw a prevote quorum for a proposal, and its hash.
+    valid: Option<(u32, C::Hash)>,
+    /// Proposals by height and round.
+    proposals: BTreeMap<(u64, u32), SignedProposal<C>>,
+    /// Votes by height, round and kind.
+    votes: BTreeMap<(u64, u32, VoteKind), BTreeMap<ValidatorIndex, SignedVote<C>>>,
+    /// The round and proposal hash of each finalized block, by height.
+    finalized: Vec<(u32, C::Hash)>,
+    validity: HashMap<ValueHash<C>, Validity>,
+    /// The rewards earned by each validator for signing commits included in finalized blocks.
+    rewards: BTreeMap<ValidatorIndex, u64>,
+    /// The validators that proposed a finalized block or signed a commit included in one.
+    participants: BTreeSet<ValidatorIndex>,
+    /// The proposals and votes we received from each validator.
+    activity: BTreeMap<ValidatorIndex, Activity>,
+    active_validator: Option<ActiveValidator<C>>,
+    /// The height at which we last requested a sync from a peer.
+    sync_requested: Option<u64>,
+    /// The height and time of the latest sync request we answered, by the requesting peer.
+    sync_responses: HashMap<I, (u64, Timestamp)>,
+    paused: bool,
+    evidence_only: bool,
+    received_messages: bool,
+    ftt_exceeded: bool,
+}
+
+impl<I: NodeIdT, C: Context + 'static> SimpleBftProtocol<I, C> {
+    /// Creates a new boxed `SimpleBftProtocol` instance.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub(crate) fn new_boxed(
+        instance_id: C::InstanceId,
+        validator_stakes: BTreeMap<C::ValidatorId, U512>,
+        slashed: &HashSet<C::ValidatorId>,
+        protocol_config: &ProtocolConfig,
+        _config: &Config,
+        _prev_cp: Option<&dyn ConsensusProtocol<I, C>>,
+        era_start_time: Timestamp,
+        seed: u64,
+        now: Timestamp,
+    ) -> (Box<dyn ConsensusProtocol<I, C>>, ProtocolOutcomes<I, C>) {
+        let (sbft, outcomes) = Self::new(
+            instance_id,
+            validator_stakes,
+            slashed,
+            protocol_config,
+            era_start_time,
+            seed,
+            now,
+        );
+        (Box::new(sbft), outcomes)
+    }
+
+    /// Creates a new `SimpleBftProtocol` instance, and returns the outcomes for starting the first
+    /// round.
+    pub(crate) fn new(
+        instance_id: C::InstanceId,
+        validator_stakes: BTreeMap<C::ValidatorId, U512>,
+        slashed: &HashSet<C::ValidatorId>,
+        protocol_config: &ProtocolConfig,
+        era_start_time: Timestamp,
+        seed: u64,
+        now: Timestamp,
+    ) -> (Self, ProtocolOutcomes<I, C>) {
+        let sum_stakes: U512 = validator_stakes.iter().map(|(_, stake)| *stake).sum();
+        assert!(
+            !sum_stakes.is_zero(),
+            "cannot start era with total weight 0"
+        );
+        // Scale down by  sum / u64::MAX,  rounded up, so that the total weight fits into a u64.
+        let scaling_factor = (sum_stakes + U512::from(u64::MAX) - 1) / U512::from(u64::MAX);
+        let scale_stake = |(key, stake): (C::ValidatorId, U512)| {
+            (key, AsPrimitive::<u64>::as_(stake / scaling_factor))
+        };
+        let mut validators: Validators<C::ValidatorId> =
+            validator_stakes.into_iter().map(scale_stake).collect();
+        for vid in slashed {
+            validators.ban(vid);
+        }
+        let faults: BTreeMap<ValidatorIndex, Fault<C>> = validators
+            .iter_banned_idx()
+            .map(|idx| (idx, Fault::Banned))
+            .collect();
+        let leaders = validators
+            .enumerate_ids()
+            .map(|(idx, _)| idx)
+            .filter(|idx| !faults.contains_key(idx))
+            .collect();
+        let weights = validators.iter().map(|v| v.weight()).collect();
+        let total_weight = validators.total_weight();
+
+        // We reuse Highway's minimum round length as the block time.
+        let block_time =
+            TimeDiff::from(1u64 << protocol_config.highway_config.minimum_round_exponent);
+
+        info!(%block_time, "initializing SimpleBft instance");
+
+        let mut sbft = SimpleBftProtocol {
+            instance_id,
+            validators,
+            weights,
+            total_weight,
+            leaders,
+            seed,
+            faults,
+            block_time,
+            era_start_time,
+            era_end_time: era_start_time + protocol_config.era_duration,
+            minimum_era_height: protocol_config.minimum_era_height,
+            last_timestamp: None,
+            height: 0,
+            round: 0,
+            step: Step::Propose,
+            round_start: era_start_time,
+            timeout: None,
+            locked: None,
+            valid: None,
+            proposals: BTreeMap::new(),
+            votes: BTreeMap::new(),
+            finalized: Vec::new(),
+            validity: HashMap::new(),
+            rewards: BTreeMap::new(),
+            participants: BTreeSet::new(),
+            activity: BTreeMap::new(),
+            active_validator: None,
+            sync_requested: None,
+            sync_responses: HashMap::new(),
+            paused: false,
+            evidence_only: false,
+            received_messages: false,
+            ftt_exceeded: false,
+        };
+        let outcomes = sbft.start_round(0, now.max(era_start_time));
+        (sbft, outcomes)
+    }
+
+    /// Handles an incoming message, received at time `now`.
+    pub(crate) fn handle_message_at(
+        &mut self,
+        sender: I,
+        msg: Vec<u8>,
+        now: Timestamp,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let message: SimpleBftMessage<C> = match bincode::deserialize(msg.as_slice()) {
+            Err(err) => {
+                return vec![ProtocolOutcome::InvalidIncomingMessage(
+                    msg,
+                    sender,
+                    err.into(),
+                )]
+            }
+            Ok(message) => message,
+        };
+        self.received_messages = true;
+        let maybe_height = message.height();
+        let result = match message {
+            SimpleBftMessage::Evidence(vote0, vote1) => self.handle_evidence(vote0, vote1),
+            _ if self.evidence_only => {
+                trace!("received an irrelevant message in evidence-only mode");
+                return vec![];
+            }
+            SimpleBftMessage::SyncRequest { height } => {
+                return self.handle_sync_request(sender, height, now)
+            }
+            SimpleBftMessage::Proposal(proposal) => self.handle_proposal(sender.clone(), proposal),
+            SimpleBftMessage::Vote(vote) => self.handle_vote(vote),
+        };
+        let mut outcomes = match result {
+            Ok(outcomes) => outcomes,
+            Err(err) => {
+                return vec![ProtocolOutcome::InvalidIncomingMessage(
+                    msg,
+                    sender,
+                    err.into(),
+                )]
+            }
+        };
+        // If the sender is at least two heights ahead of us, we missed a finalized block.
+        if let Some(height) = maybe_height {
+            if height >= self.height + 2 && self.sync_requested != Some(self.height) {
+                self.sync_requested = Some(self.height);
+                let msg = SimpleBftMessage::<C>::SyncRequest {
+                    height: self.height,
+                };
+                outcomes.push(ProtocolOutcome::CreatedTargetedMessage(
+                    msg.serialize(),
+                    sender,
+                ));
+            }
+        }
+        outcomes.extend(self.update(now, rng));
+        outcomes
+    }
+
+    /// Proposes a new value, at time `now`.
+    pub(crate) fn propose_at(
+        &mut self,
+        value: C::ConsensusValue,
+        block_context: BlockContext,
+        now: Timestamp,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let (height, round) = (self.height, self.round);
+        if self.evidence_only
+            || self.step != Step::Propose
+            || block_context.height() != height
+            || block_context.timestamp() < self.round_start
+            || self.proposals.contains_key(&(height, round))
+        {
+            warn!(?block_context, %height, %round, "not proposing; outdated block context");
+            return vec![];
+        }
+        let leader = self.leader(height, round);
+        let parent_commit = self.parent_commit();
+        let av = match self.active_validator.as_mut() {
+            Some(av) if Some(av.idx) == leader => av,
+            _ => {
+                warn!(%height, %round, "not proposing; we are not the leader");
+                return vec![];
+            }
+        };
+        let position = SignedPosition {
+            height,
+            round,
+            kind: MessageKind::Proposal,
+        };
+        if !av.prepare_to_sign(position) {
+            return vec![];
+        }
+        let idx = av.idx;
+        let proposal = Proposal {
+            value,
+            timestamp: block_context.timestamp(),
+            proposer: idx,
+            parent_commit,
+        };
+        let signed_proposal = SignedProposal::new(
+            &self.instance_id,
+            height,
+            round,
+            proposal,
+            None,
+            &av.secret,
+            rng,
+        );
+        let _ = self
+            .validity
+            .insert(signed_proposal.proposal.value.hash(), Validity::Valid);
+        self.record_activity(idx, height, round);
+        self.proposals
+            .insert((height, round), signed_proposal.clone());
+        let msg = SimpleBftMessage::Proposal(signed_proposal);
+        let mut outcomes = vec![ProtocolOutcome::CreatedGossipMessage(msg.serialize())];
+        outcomes.extend(self.update(now, rng));
+        outcomes
+    }
+
+    /// Marks the value as valid or invalid, at time `now`.
+    pub(crate) fn resolve_validity_at(
+        &mut self,
+        value: &C::ConsensusValue,
+        valid: bool,
+        now: Timestamp,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let validity = if valid {
+            Validity::Valid
+        } else {
+            warn!(?value, "consensus value is invalid");
+            Validity::Invalid
+        };
+        let _ = self.validity.insert(value.hash(), validity);
+        self.update(now, rng)
+    }
+
+    /// Returns the leader of the given round at the given height, if there are any leaders.
+    fn leader(&self, height: u64, round: u32) -> Option<ValidatorIndex> {
+        if self.leaders.is_empty() {
+            return None;
+        }
+        let n = self.leaders.len() as u64;
+        let i = self.seed.wrapping_add(height).wrapping_add(round.into()) % n;
+        Some(self.leaders[i as usize])
+    }
+
+    /// Returns whether we are the leader of the current round.
+    fn is_leader(&self) -> bool {
+        match (&self.active_validator, self.leader(self.height, self.round)) {
+            (Some(av), Some(leader)) => av.idx == leader,
+            _ => false,
+        }
+    }
+
+    /// Records a proposal or vote by the given validator in the given height and round.
+    fn record_activity(&mut self, idx: ValidatorIndex, height: u64, round: u32) {
+        let activity = self.activity.entry(idx).or_default();
+        activity.messages += 1;
+        let _ = activity.rounds.insert((height, round));
+    }
+
+    /// Returns whether the weight is more than two thirds of the total weight.
+    fn is_quorum(&self, weight: Weight) -> bool {
+        u128::from(weight) * 3 > u128::from(self.total_weight) * 2
+    }
+
+    /// Returns whether proposals and votes for the given height and round are close enough to our
+    /// current height and round to be kept. This bounds the number of proposals and votes a
+    /// single validator can make us store.
+    fn is_in_window(&self, height: u64, round: u32) -> bool {
+        if height < self.height || height >= self.height + MAX_FUTURE_HEIGHTS {
+            return false;
+        }
+        let current_round = if height == self.height { self.round } else { 0 };
+        round < current_round.saturating_add(MAX_FUTURE_ROUNDS)
+    }
+
+    /// Adds the reward for the given commit to each of its signers, in proportion to their weight.
+    fn reward_commit(&mut self, commit: &[SignedVote<C>]) {
+        for vote in commit {
+            let weight = u128::from(self.weights[vote.validator_idx]);
+            let reward = u128::from(BLOCK_REWARD) * weight / u128::from(self.total_weight);
+            *self.rewards.entry(vote.validator_idx).or_default() += reward as u64;
+        }
+    }
+
+    /// Returns the timeout of each step in the current round.
+    fn step_timeout(&self) -> TimeDiff {
+        self.block_time * (1u64 << self.round.min(MAX_TIMEOUT_DOUBLINGS))
+    }
+
+    /// Returns the earliest timestamp an acceptable proposal at the current height can have.
+    fn min_timestamp(&self) -> Timestamp {
+        self.last_timestamp
+            .map_or(self.era_start_time, |timestamp| timestamp + self.block_time)
+    }
+
+    /// Returns whether the value is known to be valid.
+    fn is_valid_value(&self, value: &C::ConsensusValue) -> bool {
+        self.validity.get(&value.hash()) == Some(&Validity::Valid)
+    }
+
+    /// Returns the proposal with the given hash at the given height, if we have it.
+    fn proposal_with_hash(&self, height: u64, hash: &C::Hash) -> Option<&SignedProposal<C>> {
+        self.proposals
+            .range((height, 0)..=(height, u32::MAX))
+            .map(|(_, signed_proposal)| signed_proposal)
+            .find(|signed_proposal| signed_proposal.proposal.hash() == *hash)
+    }
+
+    /// Returns the value or nil that received a quorum of votes of the given kind in the given
+    /// round, if any.
+    fn quorum(&self, height: u64, round: u32, kind: VoteKind) -> Option<Option<C::Hash>> {
+        let mut tallies: BTreeMap<Option<C::Hash>, Weight> = BTreeMap::new();
+        for (idx, vote) in self.votes.get(&(height, round, kind))? {
+            *tallies.entry(vote.proposal_hash).or_default() += self.weights[*idx];
+        }
+        tallies
+            .into_iter()
+            .find(|(_, weight)| self.is_quorum(*weight))
+            .map(|(hash, _)| hash)
+    }
+
+    /// Returns the precommits that finalized the previous block, to be included in our proposal.
+    fn parent_commit(&self) -> Vec<SignedVote<C>> {
+        let (round, hash) = match self.height.checked_sub(1) {
+            None => return vec![],
+            Some(parent_height) => self.finalized[parent_height as usize],
+        };
+        self.votes
+            .get(&(self.height - 1, round, VoteKind::Precommit))
+            .into_iter()
+            .flat_map(BTreeMap::values)
+            .filter(|vote| vote.proposal_hash == Some(hash))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns whether the votes are a valid commit of the block finalized at the previous height.
+    fn is_valid_parent_commit(&self, commit: &[SignedVote<C>]) -> bool {
+        let hash = match self.height.checked_sub(1) {
+            None => return commit.is_empty(),
+            Some(parent_height) => self.finalized[parent_height as usize].1,
+        };
+        let round = commit.first().map(|vote| vote.round);
+        let mut signers = BTreeSet::new();
+        let mut weight = Weight(0);
+        for vote in commit {
+            let is_matching = vote.height + 1 == self.height
+                && Some(vote.round) == round
+                && vote.kind == VoteKind::Precommit
+                && vote.proposal_hash == Some(hash);
+            if !is_matching || !signers.insert(vote.validator_idx) {
+                return false;
+            }
+            match self.validators.id(vote.validator_idx) {
+                Some(vid) if vote.verify(&self.instance_id, vid) => {}
+                _ => return false,
+            }
+            weight += self.weights[vote.validator_idx];
+        }
+        self.is_quorum(weight)
+    }
+
+    /// Returns whether a new proposal for the current round should be accepted at time `now`.
+    fn is_acceptable(&self, proposal: &Proposal<C>, now: Timestamp) -> bool {
+        self.leader(self.height, self.round) == Some(proposal.proposer)
+            && proposal.timestamp >= self.min_timestamp()
+            && proposal.timestamp <= now + self.block_time
+            && self.is_valid_parent_commit(&proposal.parent_commit)
+    }
+
+    fn handle_proposal(
+        &mut self,
+        sender: I,
+        signed_proposal: SignedProposal<C>,
+    ) -> Result<ProtocolOutcomes<I, C>, MessageError> {
+        let (height, round) = (signed_proposal.height, signed_proposal.round);
+        if !self.is_in_window(height, round) || self.proposals.contains_key(&(height, round)) {
+            trace!(%height, %round, "received an irrelevant proposal");
+            return Ok(vec![]);
+        }
+        let leader = match self.leader(height, round) {
+            None => return Ok(vec![]),
+            Some(leader) => leader,
+        };
+        let leader_id = self
+            .validators
+            .id(leader)
+            .ok_or(MessageError::UnknownValidator(leader))?;
+        if !signed_proposal.verify(&self.instance_id, leader_id) {
+            return Err(MessageError::InvalidSignature);
+        }
+        self.record_activity(leader, height, round);
+        let mut outcomes = vec![];
+        let value = &signed_proposal.proposal.value;
+        let value_hash = value.hash();
+        if !self.validity.contains_key(&value_hash) {
+            if value.needs_validation() {
+                let _ = self.validity.insert(value_hash, Validity::Pending);
+                outcomes.push(ProtocolOutcome::ValidateConsensusValue(
+                    sender,
+                    value.clone(),
+                    signed_proposal.proposal.timestamp,
+                ));
+            } else {
+                let _ = self.validity.insert(value_hash, Validity::Valid);
+            }
+        }
+        self.proposals.insert((height, round), signed_proposal);
+        Ok(outcomes)
+    }
+
+    fn handle_vote(&mut self, vote: SignedVote<C>) -> Result<ProtocolOutcomes<I, C>, MessageError> {
+        if !self.is_in_window(vote.height, vote.round) {
+            trace!(height = %vote.height, round = %vote.round, "received an irrelevant vote");
+            return Ok(vec![]);
+        }
+        let validator_id = self
+            .validators
+            .id(vote.validator_idx)
+            .ok_or(MessageError::UnknownValidator(vote.validator_idx))?;
+        if !vote.verify(&self.instance_id, validator_id) {
+            return Err(MessageError::InvalidSignature);
+        }
+        if let Some(Fault::Banned) = self.faults.get(&vote.validator_idx) {
+            trace!(validator_idx = ?vote.validator_idx, "received a vote by a banned validator");
+            return Ok(vec![]);
+        }
+        let votes = self
+            .votes
+            .entry((vote.height, vote.round, vote.kind))
+            .or_default();
+        match votes.get(&vote.validator_idx) {
+            Some(existing) if existing.proposal_hash == vote.proposal_hash => Ok(vec![]),
+            Some(existing) => {
+                let existing = existing.clone();
+                Ok(self.add_evidence(existing, vote))
+            }
+            None => {
+                let (validator_idx, height, round) = (vote.validator_idx, vote.height, vote.round);
+                let _ = votes.insert(validator_idx, vote);
+                self.record_activity(validator_idx, height, round);
+                Ok(vec![])
+            }
+        }
+    }
+
+    fn handle_evidence(
+        &mut self,
+        vote0: SignedVote<C>,
+        vote1: SignedVote<C>,
+    ) -> Result<ProtocolOutcomes<I, C>, MessageError> {
+        if vote0.validator_idx != vote1.validator_idx
+            || vote0.height != vote1.height
+            || vote0.round != vote1.round
+            || vote0.kind != vote1.kind
+            || vote0.proposal_hash == vote1.proposal_hash
+        {
+            return Err(MessageError::NotConflicting);
+        }
+        let validator_id = self
+            .validators
+            .id(vote0.validator_idx)
+            .ok_or(MessageError::UnknownValidator(vote0.validator_idx))?;
+        if !vote0.verify(&self.instance_id, validator_id)
+            || !vote1.verify(&self.instance_id, validator_id)
+        {
+            return Err(MessageError::InvalidSignature);
+        }
+        Ok(self.add_evidence(vote0, vote1))
+    }
+
+    /// Records the two conflicting votes as evidence against their signer, unless we already have
+    /// direct evidence.
+    fn add_evidence(
+        &mut self,
+        vote0: SignedVote<C>,
+        vote1: SignedVote<C>,
+    ) -> ProtocolOutcomes<I, C> {
+        let idx = vote0.validator_idx;
+        if let Some(Fault::Direct(..)) = self.faults.get(&idx) {
+            return vec![];
+        }
+        let validator_id = self
+            .validators
+            .id(idx)
+            .expect("validator not found")
+            .clone();
+        info!(?validator_id, "validator equivocated");
+        let _ = self
+            .faults
+            .insert(idx, Fault::Direct(vote0.clone(), vote1.clone()));
+        let msg = SimpleBftMessage::Evidence(vote0, vote1);
+        let mut outcomes = vec![
+            ProtocolOutcome::NewEvidence(validator_id),
+            ProtocolOutcome::CreatedGossipMessage(msg.serialize()),
+        ];
+        if self.active_validator.as_ref().map(|av| av.idx) == Some(idx) {
+            error!("this validator is faulty");
+            outcomes.push(ProtocolOutcome::WeAreFaulty);
+        }
+        if !self.ftt_exceeded {
+            let faulty_weight: Weight = self.faults.keys().map(|idx| self.weights[*idx]).sum();
+            if u128::from(faulty_weight) * 3 >= u128::from(self.total_weight) {
+                error!(
+                    faulty_weight = %faulty_weight.0,
+                    total_weight = %self.total_weight.0,
+                    "too many faulty validators"
+                );
+                self.ftt_exceeded = true;
+                outcomes.push(ProtocolOutcome::FttExceeded);
+            }
+        }
+        outcomes
+    }
+
+    /// Sends the requesting peer the proposals and commits of up to `MAX_SYNC_HEIGHTS` blocks
+    /// finalized since `height` and, if that catches it up, all proposals and votes for later
+    /// heights. Repeated requests by the same peer are ignored for a while.
+    fn handle_sync_request(
+        &mut self,
+        sender: I,
+        height: u64,
+        now: Timestamp,
+    ) -> ProtocolOutcomes<I, C> {
+        if let Some((answered_height, answered_time)) = self.sync_responses.get(&sender) {
+            if height <= *answered_height
+                && now < *answered_time + self.block_time * SYNC_REQUEST_COOLDOWN
+            {
+                trace!(%sender, %height, "ignoring repeated sync request");
+                return vec![];
+            }
+        }
+        let _ = self.sync_responses.insert(sender.clone(), (height, now));
+        let end = height.saturating_add(MAX_SYNC_HEIGHTS);
+        let mut msgs = vec![];
+        for (h, (round, hash)) in self
+            .finalized
+            .iter()
+            .enumerate()
+            .skip(height as usize)
+            .take(MAX_SYNC_HEIGHTS as usize)
+        {
+            let h = h as u64;
+            if let Some(signed_proposal) = self.proposal_with_hash(h, hash) {
+                msgs.push(SimpleBftMessage::Proposal(signed_proposal.clone()));
+            }
+            let commit = self.votes.get(&(h, *round, VoteKind::Precommit));
+            msgs.extend(
+                commit
+                    .into_iter()
+                    .flat_map(BTreeMap::values)
+                    .cloned()
+                    .map(SimpleBftMessage::Vote),
+            );
+        }
+        if end >= self.height {
+            let start = height.max(self.height);
+            msgs.extend(
+                self.proposals
+                    .range((start, 0)..)
+                    .map(|(_, signed_proposal)| {
+                        SimpleBftMessage::Proposal(signed_proposal.clone())
+                    }),
+            );
+            msgs.extend(
+                self.votes
+                    .range((start, 0, VoteKind::Prevote)..)
+                    .flat_map(|(_, votes)| votes.values())
+                    .cloned()
+                    .map(SimpleBftMessage::Vote),
+            );
+        }
+        msgs.into_iter()
+            .map(|msg| ProtocolOutcome::CreatedTargetedMessage(msg.serialize(), sender.clone()))
+            .collect()
+    }
+
+    /// Handles the timer for the start of the round: If we are the leader, we request a new block,
+    /// or re-propose the block that got a prevote quorum in an earlier round.
+    fn handle_round_start(
+        &mut self,
+        timestamp: Timestamp,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let (height, round) = (self.height, self.round);
+        if timestamp < self.round_start
+            || self.step != Step::Propose
+            || self.paused
+            || !self.is_leader()
+            || self.proposals.contains_key(&(height, round))
+        {
+            return vec![];
+        }
+        let (valid_round, hash) = match self.valid {
+            None => {
+                let block_context = BlockContext::new(timestamp, height);
+                return vec![ProtocolOutcome::CreateNewBlock {
+                    block_context,
+                    past_values: vec![],
+                }];
+            }
+            Some(valid) => valid,
+        };
+        let proposal = match self.proposal_with_hash(height, &hash) {
+            None => {
+                error!(%height, %round, "missing proposal with a prevote quorum");
+                return vec![];
+            }
+            Some(signed_proposal) => signed_proposal.proposal.clone(),
+        };
+        let av = self
+            .active_validator
+            .as_mut()
+            .expect("leader must be active");
+        let position = SignedPosition {
+            height,
+            round,
+            kind: MessageKind::Proposal,
+        };
+        if !av.prepare_to_sign(position) {
+            return vec![];
+        }
+        let signed_proposal = SignedProposal::new(
+            &self.instance_id,
+            height,
+            round,
+            proposal,
+            Some(valid_round),
+            &av.secret,
+            rng,
+        );
+        self.proposals
+            .insert((height, round), signed_proposal.clone());
+        let msg = SimpleBftMessage::Proposal(signed_proposal);
+        let mut outcomes = vec![ProtocolOutcome::CreatedGossipMessage(msg.serialize())];
+        outcomes.extend(self.update(timestamp, rng));
+        outcomes
+    }
+
+    /// Handles the current step's timeout: we vote nil, or move on to the next round.
+    fn handle_timeout(
+        &mut self,
+        timestamp: Timestamp,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        if self.evidence_only || self.timeout != Some(timestamp) {
+            return vec![];
+        }
+        self.timeout = None;
+        let mut outcomes = match self.step {
+            Step::Propose => {
+                info!(height = %self.height, round = %self.round, "no acceptable proposal");
+                self.enter_step(VoteKind::Prevote, None, timestamp, rng)
+            }
+            Step::Prevote => self.enter_step(VoteKind::Precommit, None, timestamp, rng),
+            Step::Precommit => self.start_round(self.round + 1, timestamp),
+            Step::Finished => return vec![],
+        };
+        outcomes.extend(self.update(timestamp, rng));
+        outcomes
+    }
+
+    /// Starts the given round at the current height, at the given time.
+    fn start_round(&mut self, round: u32, start: Timestamp) -> ProtocolOutcomes<I, C> {
+        trace!(height = %self.height, %round, %start, "starting round");
+        self.round = round;
+        self.step = Step::Propose;
+        self.round_start = start;
+        let mut outcomes = self.schedule_timeout(start);
+        if self.is_leader() {
+            outcomes.push(ProtocolOutcome::ScheduleTimer(start, TIMER_ID_ROUND_START));
+        }
+        outcomes
+    }
+
+    /// Moves to the step in which we cast the given kind of vote, and casts it.
+    fn enter_step(
+        &mut self,
+        kind: VoteKind,
+        proposal_hash: Option<C::Hash>,
+        now: Timestamp,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        self.step = match kind {
+            VoteKind::Prevote => Step::Prevote,
+            VoteKind::Precommit => Step::Precommit,
+        };
+        let mut outcomes = self.schedule_timeout(now);
+        outcomes.extend(self.vote(kind, proposal_hash, rng));
+        outcomes
+    }
+
+    fn schedule_timeout(&mut self, now: Timestamp) -> ProtocolOutcomes<I, C> {
+        let timeout = now + self.step_timeout();
+        self.timeout = Some(timeout);
+        vec![ProtocolOutcome::ScheduleTimer(timeout, TIMER_ID_TIMEOUT)]
+    }
+
+    /// Casts and gossips our vote in the current round, if we are an active validator.
+    fn vote(
+        &mut self,
+        kind: VoteKind,
+        proposal_hash: Option<C::Hash>,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        let (height, round) = (self.height, self.round);
+        let key = (height, round, kind);
+        if self.paused {
+            return vec![];
+        }
+        let av = match self.active_validator.as_mut() {
+            Some(av) => av,
+            None => return vec![],
+        };
+        if self
+            .votes
+            .get(&key)
+            .map_or(false, |votes| votes.contains_key(&av.idx))
+        {
+            return vec![];
+        }
+        // If we already voted, e.g. before a restart, we must not vote again.
+        let position = SignedPosition {
+            height,
+            round,
+            kind: MessageKind::Vote(kind),
+        };
+        if !av.prepare_to_sign(position) {
+            return vec![];
+        }
+        let vote = SignedVote::new(
+            &self.instance_id,
+            height,
+            round,
+            kind,
+            proposal_hash,
+            av.idx,
+            &av.secret,
+            rng,
+        );
+        let _ = self
+            .votes
+            .entry(key)
+            .or_default()
+            .insert(vote.validator_idx, vote.clone());
+        self.record_activity(vote.validator_idx, vote.height, vote.round);
+        vec![ProtocolOutcome::CreatedGossipMessage(
+            SimpleBftMessage::Vote(vote).serialize(),
+        )]
+    }
+
+    /// Applies the protocol rules until no more progress can be made with the messages we have.
+    fn update(&mut self, now: Timestamp, rng: &mut NodeRng) -> ProtocolOutcomes<I, C> {
+        let mut outcomes = vec![];
+        loop {
+            if self.evidence_only || self.step == Step::Finished {
+                return outcomes;
+            }
+            if let Some((round, hash)) = self.decision() {
+                outcomes.extend(self.finalize(round, hash, now));
+                continue;
+            }
+            if let Some(round) = self.round_to_skip_to() {
+                outcomes.extend(self.start_round(round, now));
+                continue;
+            }
+            if let Some(Some(hash)) = self.quorum(self.height, self.round, VoteKind::Prevote) {
+                if self.proposal_with_hash(self.height, &hash).is_some() {
+                    self.valid = Some((self.round, hash));
+                }
+            }
+            let maybe_progress = match self.step {
+                Step::Propose => self
+                    .prevote_for(now)
+                    .map(|vote_for| self.enter_step(VoteKind::Prevote, vote_for, now, rng)),
+                Step::Prevote => self.precommit_for().map(|vote_for| {
+                    if let Some(hash) = vote_for {
+                        self.locked = Some((self.round, hash));
+                    }
+                    self.enter_step(VoteKind::Precommit, vote_for, now, rng)
+                }),
+                Step::Precommit => {
+                    match self.quorum(self.height, self.round, VoteKind::Precommit) {
+                        Some(None) => Some(self.start_round(self.round + 1, now)),
+                        _ => None,
+                    }
+                }
+                Step::Finished => None,
+            };
+            match maybe_progress {
+                None => return outcomes,
+                Some(new_outcomes) => outcomes.extend(new_outcomes),
+            }
+        }
+    }
+
+    /// Returns the round and hash of a proposal at the current height with a precommit quorum, if
+    /// we have it and know that its value is valid.
+    fn decision(&self) -> Option<(u32, C::Hash)> {
+        let height = self.height;
+        self.votes
+            .range((height, 0, VoteKind::Prevote)..=(height, u32::MAX, VoteKind::Precommit))
+            .filter(|((_, _, kind), _)| *kind == VoteKind::Precommit)
+            .filter_map(|((_, round, _), _)| {
+                match self.quorum(height, *round, VoteKind::Precommit) {
+                    Some(Some(hash)) => Some((*round, hash)),
+                    _ => None,
+                }
+            })
+            .find(|(_, hash)| {
+                self.proposal_with_hash(height, hash)
+                    .map_or(false, |sp| self.is_valid_value(&sp.proposal.value))
+            })
+    }
+
+    /// Returns the latest later round at the current height in which validators with more than a
+    /// third of the weight voted. At least one of them is correct, so we need to catch up.
+    fn round_to_skip_to(&self) -> Option<u32> {
+        let mut voters: BTreeMap<u32, BTreeSet<ValidatorIndex>> = BTreeMap::new();
+        let start = (self.height, self.round.saturating_add(1), VoteKind::Prevote);
+        let end = (self.height, u32::MAX, VoteKind::Precommit);
+        for ((_, round, _), votes) in self.votes.range(start..=end) {
+            voters
+                .entry(*round)
+                .or_default()
+                .extend(votes.keys().cloned());
+        }
+        voters
+            .into_iter()
+            .rev()
+            .find(|(_, idxs)| {
+                let weight: Weight = idxs.iter().map(|idx| self.weights[*idx]).sum();
+                u128::from(weight) * 3 > u128::from(self.total_weight)
+            })
+            .map(|(round, _)| round)
+    }
+
+    /// Returns what to prevote for in the current round, or `None` if we have to wait for more
+    /// information or the timeout.
+    fn prevote_for(&self, now: Timestamp) -> Option<Option<C::Hash>> {
+        let signed_proposal = self.proposals.get(&(self.height, self.round))?;
+        let proposal = &signed_proposal.proposal;
+        let hash = proposal.hash();
+        match self.validity.get(&proposal.value.hash()) {
+            None | Some(Validity::Pending) => return None,
+            Some(Validity::Invalid) => return Some(None),
+            Some(Validity::Valid) => {}
+        }
+        let vote_for = match signed_proposal.valid_round {
+            None if self.is_acceptable(proposal, now) => match self.locked {
+                Some((_, locked_hash)) if locked_hash != hash => None,
+                _ => Some(hash),
+            },
+            None => None,
+            Some(valid_round) if valid_round < self.round => {
+                // Wait for the prevote quorum that justifies the re-proposal.
+                if self.quorum(self.height, valid_round, VoteKind::Prevote)? != Some(hash) {
+                    return None;
+                }
+                match self.locked {
+                    Some((locked_round, locked_hash))
+                        if locked_round > valid_round && locked_hash != hash =>
+                    {
+                        None
+                    }
+                    _ => Some(hash),
+                }
+            }
+            Some(_) => None,
+        };
+        Some(vote_for)
+    }
+
+    /// Returns what to precommit in the current round, or `None` if there is no prevote quorum
+    /// yet.
+    fn precommit_for(&self) -> Option<Option<C::Hash>> {
+        match self.quorum(self.height, self.round, VoteKind::Prevote)? {
+            Some(hash) => {
+                let is_valid = self
+                    .proposal_with_hash(self.height, &hash)
+                    .map_or(false, |sp| self.is_valid_value(&sp.proposal.value));
+                if is_valid {
+                    Some(Some(hash))
+                } else {
+                    None
+                }
+            }
+            None => Some(None),
+        }
+    }
+
+    /// Returns the fault tolerance of the commit for the given proposal: Two conflicting commits
+    /// can only exist if the validators in their intersection equivocated.
+    fn commit_finality(&self, height: u64, round: u32, hash: &C::Hash) -> Finality<C> {
+        let signers: Vec<ValidatorIndex> = self
+            .votes
+            .get(&(height, round, VoteKind::Precommit))
+            .into_iter()
+            .flat_map(BTreeMap::values)
+            .filter(|vote| vote.proposal_hash.as_ref() == Some(hash))
+            .map(|vote| vote.validator_idx)
+            .collect();
+        let weight: Weight = signers.iter().map(|idx| self.weights[*idx]).sum();
+        let total_weight = u128::from(self.total_weight);
+        let ftt = (2 * u128::from(weight)).saturating_sub(total_weight);
+        Finality {
+            summit_level: 1,
+            fault_tolerance_ppm: (ftt * 1_000_000 / total_weight) as u64,
+            contributors: signers
+                .into_iter()
+                .filter_map(|idx| self.validators.id(idx).cloned())
+                .collect(),
+        }
+    }
+
+    /// Finalizes the proposal with the given hash at the current height, and moves on to the next
+    /// height, unless it was the terminal block.
+    fn finalize(&mut self, round: u32, hash: C::Hash, now: Timestamp) -> ProtocolOutcomes<I, C> {
+        let height = self.height;
+        let Proposal {
+            value,
+            timestamp,
+            proposer,
+            parent_commit,
+        } = self
+            .proposal_with_hash(height, &hash)
+            .expect("decided proposal must exist")
+            .proposal
+            .clone();
+
+        // Reward the signers of the parent block's commit. All nodes agree on it, since it is
+        // part of the finalized proposal.
+        let _ = self.participants.insert(proposer);
+        for vote in &parent_commit {
+            let _ = self.participants.insert(vote.validator_idx);
+        }
+        self.reward_commit(&parent_commit);
+
+        let terminal = height + 1 >= self.minimum_era_height && timestamp >= self.era_end_time;
+        let terminal_block_data = if terminal {
+            // The terminal block's own commit is not included in any proposal, so nodes can
+            // disagree about its signers. Its reward goes to the signers of the parent commit
+            // instead: the latest commit all nodes agree on.
+            self.reward_commit(&parent_commit);
+            let id = |idx: &ValidatorIndex| {
+                self.validators
+                    .id(*idx)
+                    .expect("validator not found")
+                    .clone()
+            };
+            let rewards = self
+                .rewards
+                .iter()
+                .map(|(idx, reward)| (id(idx), *reward))
+                .collect();
+            let inactive_validators = self
+                .validators
+                .enumerate_ids()
+                .filter(|(idx, _)| !self.participants.contains(idx))
+                .map(|(_, vid)| vid.clone())
+                .collect();
+            Some(TerminalBlockData {
+                rewards,
+                inactive_validators,
+            })
+        } else {
+            None
+        };
+        let finalized_block = FinalizedBlock {
+            value,
+            timestamp,
+            height,
+            // Equivocations are reported via accusations in the consensus values instead, since
+            // nodes might not agree on the set of known equivocators.
+            equivocators: vec![],
+            terminal_block_data,
+            proposer: self
+                .validators
+                .id(proposer)
+                .expect("validator not found")
+                .clone(),
+            finality: self.commit_finality(height, round, &hash),
+        };
+
+        // Only keep the finalized proposal and its commit, to help other nodes catch up.
+        let obsolete_proposals: Vec<_> = self
+            .proposals
+            .range((height, 0)..=(height, u32::MAX))
+            .filter(|(_, signed_proposal)| signed_proposal.proposal.hash() != hash)
+            .map(|(key, _)| *key)
+            .collect();
+        for key in obsolete_proposals {
+            let _ = self.proposals.remove(&key);
+        }
+        let obsolete_votes: Vec<_> = self
+            .votes
+            .range((height, 0, VoteKind::Prevote)..=(height, u32::MAX, VoteKind::Precommit))
+            .map(|(key, _)| *key)
+            .filter(|key| *key != (height, round, VoteKind::Precommit))
+            .collect();
+        for key in obsolete_votes {
+            let _ = self.votes.remove(&key);
+        }
+
+        self.finalized.push((round, hash));
+        self.last_timestamp = Some(timestamp);
+        self.height += 1;
+        self.locked = None;
+        self.valid = None;
+
+        let mut outcomes = vec![ProtocolOutcome::FinalizedBlock(finalized_block)];
+        if terminal {
+            info!(%height, "finalized the era's terminal block");
+            self.step = Step::Finished;
+            self.timeout = None;
+        } else {
+            outcomes.extend(self.start_round(0, now.max(timestamp + self.block_time)));
+        }
+        outcomes
+    }
+}
+
+impl<I, C> ConsensusProtocol<I, C> for SimpleBftProtocol<I, C>
+where
+    I: NodeIdT,
+    C: Context + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn handle_message(
+        &mut self,
+        sender: I,
+        msg: Vec<u8>,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        self.handle_message_at(sender, msg, Timestamp::now(), rng)
+    }
+
+    fn handle_new_peer(&mut self, peer_id: I) -> ProtocolOutcomes<I, C> {
+        if self.evidence_only {
+            return vec![];
+        }
+        trace!(?peer_id, "connected to a new peer");
+        let msg = SimpleBftMessage::<C>::SyncRequest {
+            height: self.height,
+        };
+        vec![ProtocolOutcome::CreatedTargetedMessage(
+            msg.serialize(),
+            peer_id,
+        )]
+    }
+
+    fn handle_timer(
+        &mut self,
+        timestamp: Timestamp,
+        timer_id: TimerId,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        match timer_id {
+            TIMER_ID_ROUND_START => self.handle_round_start(timestamp, rng),
+            TIMER_ID_TIMEOUT => self.handle_timeout(timestamp, rng),
+            _ => unreachable!("unexpected timer ID"),
+        }
+    }
+
+    fn handle_action(
+        &mut self,
+        _action_id: ActionId,
+        _rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        unreachable!("unexpected action ID")
+    }
+
+    fn propose(
+        &mut self,
+        value: C::ConsensusValue,
+        block_context: BlockContext,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        self.propose_at(value, block_context, Timestamp::now(), rng)
+    }
+
+    fn resolve_validity(
+        &mut self,
+        value: &C::ConsensusValue,
+        valid: bool,
+        rng: &mut NodeRng,
+    ) -> ProtocolOutcomes<I, C> {
+        self.resolve_validity_at(value, valid, Timestamp::now(), rng)
+    }
+
+    fn activate_validator(
+        &mut self,
+        our_id: C::ValidatorId,
+        secret: C::ValidatorSecret,
+        timestamp: Timestamp,
+        unit_hash_file: Option<PathBuf>,
+    ) -> ProtocolOutcomes<I, C> {
+        let idx = match self.validators.get_index(&our_id) {
+            None => {
+                error!(
+                    ?our_id,
+                    "not activating; we are not a validator in this era"
+                );
+                return vec![];
+            }
+            Some(idx) => idx,
+        };
+        self.active_validator = Some(ActiveValidator::new(idx, secret, unit_hash_file));
+        if self.step == Step::Propose && self.is_leader() {
+            let start = self.round_start.max(timestamp);
+            vec![ProtocolOutcome::ScheduleTimer(start, TIMER_ID_ROUND_START)]
+        } else {
+            vec![]
+        }
+    }
+
+    fn deactivate_validator(&mut self) {
+        self.active_validator = None;
+    }
+
+    fn set_evidence_only(&mut self) {
+        self.proposals.clear();
+        self.votes.clear();
+        self.validity.clear();
+        self.sync_responses.clear();
+        self.timeout = None;
+        self.evidence_only = true;
+    }
+
+    fn has_evidence(&self, vid: &C::ValidatorId) -> bool {
+        self.validators
+            .get_index(vid)
+            .and_then(|idx| self.faults.get(&idx))
+            .map_or(false, |fault| matches!(fault, Fault::Direct(..)))
+    }
+
+    fn mark_faulty(&mut self, vid: &C::ValidatorId) {
+        if let Some(idx) = self.validators.get_index(vid) {
+            let _ = self.faults.entry(idx).or_insert(Fault::Indirect);
+        }
+    }
+
+    fn request_evidence(&self, sender: I, vid: &C::ValidatorId) -> ProtocolOutcomes<I, C> {
+        match self
+            .validators
+            .get_index(vid)
+            .and_then(|idx| self.faults.get(&idx))
+        {
+            Some(Fault::Direct(vote0, vote1)) => {
+                let msg = SimpleBftMessage::Evidence(vote0.clone(), vote1.clone());
+                vec![ProtocolOutcome::CreatedTargetedMessage(
+                    msg.serialize(),
+                    sender,
+                )]
+            }
+            Some(Fault::Banned) | Some(Fault::Indirect) | None => vec![],
+        }
+    }
+
+    /// Sets the pause status: While paused we don't create any proposals or votes.
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    fn validators_with_evidence(&self) -> Vec<&C::ValidatorId> {
+        self.faults
+            .iter()
+            .filter(|(_, fault)| matches!(fault, Fault::Direct(..)))
+            .filter_map(|(idx, _)| self.validators.id(*idx))
+            .collect()
+    }
+
+    fn validator_stats(&self) -> Vec<ValidatorStats<C>> {
+        self.validators
+            .enumerate_ids()
+            .map(|(idx, vid)| {
+                let activity = self.activity.get(&idx);
+                ValidatorStats {
+                    validator_id: vid.clone(),
+                    units_produced: activity.map_or(0, |activity| activity.messages),
+                    rounds_participated: activity
+                        .map_or(0, |activity| activity.rounds.len() as u64),
+                    round_exponent: None,
+                    equivocated: self.faults.contains_key(&idx),
+                }
+            })
+            .collect()
+    }
+
+    fn has_received_messages(&self) -> bool {
+        self.received_messages
+    }
+
+    fn is_active(&self) -> bool {
+        self.active_validator.is_some()
+    }
+
+    fn instance_id(&self) -> &C::InstanceId {
+        &self.instance_id
+    }
+
+    fn recreate_timers(&self) -> ProtocolOutcomes<I, C> {
+        let mut outcomes = vec![];
+        if let Some(timeout) = self.timeout {
+            outcomes.push(ProtocolOutcome::ScheduleTimer(timeout, TIMER_ID_TIMEOUT));
+        }
+        if self.step == Step::Propose && self.is_leader() {
+            outcomes.push(ProtocolOutcome::ScheduleTimer(
+                self.round_start,
+                TIMER_ID_ROUND_START,
+            ));
+        }
+        outcomes
+    }
+
+    fn next_round_length(&self) -> Option<TimeDiff> {
+        Some(self.block_time)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        components::consensus::{
+            highway_core::highway_testing::{TestContext, TestSecret},
+            tests::{
+                consensus_des_testing::ValidatorId,
+                utils::{new_test_chainspec, ALICE_PUBLIC_KEY},
+            },
+        },
+        testing::TestRng,
+    };
+
+    #[test]
+    fn should_drop_votes_for_far_future_rounds() {
+        let mut rng = TestRng::new();
+        let chainspec = new_test_chainspec(vec![(*ALICE_PUBLIC_KEY, 100)]);
+        let protocol_config = (&chainspec).into();
+        let ids: Vec<ValidatorId> = (1..=4).map(ValidatorId).collect();
+        let start = Timestamp::from(0);
+        let (mut sbft, _outcomes): (_, ProtocolOutcomes<ValidatorId, TestContext>) =
+            SimpleBftProtocol::<ValidatorId, TestContext>::new(
+                0,
+                ids.iter().map(|id| (*id, U512::from(100))).collect(),
+                &HashSet::new(),
+                &protocol_config,
+                start,
+                0,
+                start,
+            );
+        let idx = sbft
+            .validators
+            .get_index(&ids[0])
+            .expect("validator should exist");
+
+        for round in &[MAX_FUTURE_ROUNDS - 1, MAX_FUTURE_ROUNDS, u32::MAX] {
+            let vote = SignedVote::<TestContext>::new(
+                &0,
+                0,
+                *round,
+                VoteKind::Prevote,
+                None,
+                idx,
+                &TestSecret(ids[0].0),
+                &mut rng,
+            );
+            let msg = SimpleBftMessage::Vote(vote).serialize();
+            let _ = sbft.handle_message_at(ids[1], msg, start, &mut rng);
+        }
+
+        let rounds: Vec<u32> = sbft.votes.keys().map(|(_, round, _)| *round).collect();
+        assert_eq!(vec![MAX_FUTURE_ROUNDS - 1], rounds);
+    }
+
+    /// Returns the votes among the gossiped messages.
+    fn gossiped_votes(
+        outcomes: ProtocolOutcomes<ValidatorId, TestContext>,
+    ) -> Vec<SignedVote<TestContext>> {
+        outcomes
+            .into_iter()
+            .filter_map(|outcome| match outcome {
+                ProtocolOutcome::CreatedGossipMessage(msg) => {
+                    match bincode::deserialize(&msg).expect("should deserialize message") {
+                        SimpleBftMessage::<TestContext>::Vote(vote) => Some(vote),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_not_vote_again_after_restart() {
+        let mut rng = TestRng::new();
+        let chainspec = new_test_chainspec(vec![(*ALICE_PUBLIC_KEY, 100)]);
+        let protocol_config = (&chainspec).into();
+        let ids: Vec<ValidatorId> = (1..=4).map(ValidatorId).collect();
+        let start = Timestamp::from(0);
+        let tempdir = tempfile::tempdir().expect("should get tempdir");
+        let unit_hash_file = tempdir.path().join("unit_hash.dat");
+
+        // Starts an instance with us as the first validator, as after a (re)start of the node.
+        let start_instance = || {
+            let (mut sbft, _outcomes): (_, ProtocolOutcomes<ValidatorId, TestContext>) =
+                SimpleBftProtocol::<ValidatorId, TestContext>::new(
+                    0,
+                    ids.iter().map(|id| (*id, U512::from(100))).collect(),
+                    &HashSet::new(),
+                    &protocol_config,
+                    start,
+                    0,
+                    start,
+                );
+            let _: ProtocolOutcomes<ValidatorId, TestContext> = sbft.activate_validator(
+                ids[0],
+                TestSecret(ids[0].0),
+                start,
+                Some(unit_hash_file.clone()),
+            );
+            sbft
+        };
+
+        // Without a proposal, the propose step times out and we prevote nil.
+        let mut sbft = start_instance();
+        let timeout = sbft.timeout.expect("should have a timeout");
+        let votes = gossiped_votes(sbft.handle_timeout(timeout, &mut rng));
+        assert_eq!(1, votes.len());
+        assert_eq!(
+            (0, 0, VoteKind::Prevote),
+            (votes[0].height, votes[0].round, votes[0].kind)
+        );
+
+        // After a restart we have forgotten our prevote, but must not sign another one in the
+        // same round.
+        let mut sbft = start_instance();
+        let timeout = sbft.timeout.expect("should have a timeout");
+        let votes = gossiped_votes(sbft.handle_timeout(timeout, &mut rng));
+        assert!(votes.is_empty());
+
+        // We haven't precommitted in that round yet, so we still may.
+        let timeout = sbft.timeout.expect("should have a timeout");
+        let votes = gossiped_votes(sbft.handle_timeout(timeout, &mut rng));
+        assert_eq!(1, votes.len());
+        assert_eq!(
+            (0, 0, VoteKind::Precommit),
+            (votes[0].height, votes[0].round, votes[0].kind)
+        );
+    }
+
+    #[test]
+    fn should_limit_sync_replies() {
+        let mut rng = TestRng::new();
+        let chainspec = new_test_chainspec(vec![(*ALICE_PUBLIC_KEY, 100)]);
+        let protocol_config = (&chainspec).into();
+        let ids: Vec<ValidatorId> = (1..=4).map(ValidatorId).collect();
+        let start = Timestamp::from(0);
+        let (mut sbft, _outcomes): (_, ProtocolOutcomes<ValidatorId, TestContext>) =
+            SimpleBftProtocol::<ValidatorId, TestContext>::new(
+                0,
+                ids.iter().map(|id| (*id, U512::from(100))).collect(),
+                &HashSet::new(),
+                &protocol_config,
+                start,
+                0,
+                start,
+            );
+        let idx = sbft
+            .validators
+            .get_index(&ids[0])
+            .expect("validator should exist");
+
+        // Pretend we finalized twice as many blocks as fit into a sync reply, each with a
+        // single precommit in round 0.
+        let finalized_count = 2 * MAX_SYNC_HEIGHTS;
+        for height in 0..finalized_count {
+            let hash = TestContext::hash(&height.to_le_bytes());
+            let vote = SignedVote::<TestContext>::new(
+                &0,
+                height,
+                0,
+                VoteKind::Precommit,
+                Some(hash),
+                idx,
+                &TestSecret(ids[0].0),
+                &mut rng,
+            );
+            let _ = sbft
+                .votes
+                .entry((height, 0, VoteKind::Precommit))
+                .or_default()
+                .insert(idx, vote);
+            sbft.finalized.push((0, hash));
+        }
+        sbft.height = finalized_count;
+
+        let request = |height| SimpleBftMessage::<TestContext>::SyncRequest { height }.serialize();
+        let reply_len = |outcomes: ProtocolOutcomes<ValidatorId, TestContext>| {
+            outcomes
+                .iter()
+                .filter(|outcome| matches!(outcome, ProtocolOutcome::CreatedTargetedMessage(..)))
+                .count() as u64
+        };
+
+        // A reply contains at most `MAX_SYNC_HEIGHTS` heights.
+        let outcomes = sbft.handle_message_at(ids[1], request(0), start, &mut rng);
+        assert_eq!(MAX_SYNC_HEIGHTS, reply_len(outcomes));
+
+        // Repeated requests by the same peer are ignored until the cooldown has passed.
+        let outcomes = sbft.handle_message_at(ids[1], request(0), start, &mut rng);
+        assert_eq!(0, reply_len(outcomes));
+        let later = start + sbft.block_time * SYNC_REQUEST_COOLDOWN;
+        let outcomes = sbft.handle_message_at(ids[1], request(0), later, &mut rng);
+        assert_eq!(MAX_SYNC_HEIGHTS, reply_len(outcomes));
+
+        // Other peers and requests for later heights are answered.
+        let outcomes = sbft.handle_message_at(ids[2], request(0), start, &mut rng);
+        assert_eq!(MAX_SYNC_HEIGHTS, reply_len(outcomes));
+        let outcomes = sbft.handle_message_at(ids[1], request(MAX_SYNC_HEIGHTS), later, &mut rng);
+        assert_eq!(finalized_count - MAX_SYNC_HEIGHTS, reply_len(outcomes));
+    }
+}
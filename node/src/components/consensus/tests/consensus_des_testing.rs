@@ -335,3 +335,268 @@ mod virtual_net_tests {
         );
     }
 }
+
+mod simple_bft_tests {
+    use std::collections::HashSet;
+
+    use casper_types::U512;
+
+    use super::{Message, Node, Target, TargetedMessage, Timestamp, ValidatorId, VirtualNet};
+    use crate::{
+        components::consensus::{
+            consensus_protocol::{
+                BlockContext, ConsensusProtocol, FinalizedBlock, ProtocolOutcome,
+            },
+            highway_core::highway_testing::{TestContext, TestSecret},
+            protocols::simple_bft::SimpleBftProtocol,
+            tests::utils::{new_test_chainspec, ALICE_PUBLIC_KEY},
+            TimerId,
+        },
+        testing::TestRng,
+        types::TimeDiff,
+    };
+
+    /// The network delay for all messages, in milliseconds.
+    const DELAY: u64 = 100;
+    /// The number of blocks in the era.
+    const ERA_HEIGHT: u64 = 10;
+    /// An upper bound for the number of messages the tests are expected to handle.
+    const MAX_STEPS: usize = 100_000;
+
+    #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+    enum SbftMessage {
+        Payload(Vec<u8>),
+        Timer(u8),
+        NewBlock(BlockContext),
+        Validated(Vec<u8>),
+    }
+
+    type SbftNode =
+        Node<FinalizedBlock<TestContext>, SbftMessage, SimpleBftProtocol<ValidatorId, TestContext>>;
+    type SbftNet = VirtualNet<
+        FinalizedBlock<TestContext>,
+        SbftMessage,
+        SimpleBftProtocol<ValidatorId, TestContext>,
+    >;
+
+    fn new_net(validator_count: u64) -> (SbftNet, Vec<(TargetedMessage<SbftMessage>, Timestamp)>) {
+        let mut chainspec = new_test_chainspec(vec![(*ALICE_PUBLIC_KEY, 100)]);
+        chainspec.core_config.minimum_era_height = ERA_HEIGHT;
+        chainspec.highway_config.minimum_round_exponent = 10;
+        let protocol_config = (&chainspec).into();
+        let start = Timestamp::from(0);
+        let ids: Vec<ValidatorId> = (1..=validator_count).map(ValidatorId).collect();
+        let mut nodes = vec![];
+        let mut messages = vec![];
+        for id in &ids {
+            let (mut sbft, mut outcomes) = SimpleBftProtocol::<ValidatorId, TestContext>::new(
+                0,
+                ids.iter().map(|id| (*id, U512::from(100))).collect(),
+                &HashSet::new(),
+                &protocol_config,
+                start,
+                0,
+                start,
+            );
+            outcomes.extend(ConsensusProtocol::activate_validator(
+                &mut sbft,
+                *id,
+                TestSecret(id.0),
+                start,
+                None,
+            ));
+            messages.extend(to_messages(*id, outcomes, start, &mut vec![]));
+            nodes.push(Node::new(*id, sbft));
+        }
+        (VirtualNet::new(nodes, vec![]), messages)
+    }
+
+    /// Converts the outcomes of a protocol instance into messages, and collects finalized blocks.
+    fn to_messages(
+        id: ValidatorId,
+        outcomes: Vec<ProtocolOutcome<ValidatorId, TestContext>>,
+        now: Timestamp,
+        finalized: &mut Vec<FinalizedBlock<TestContext>>,
+    ) -> Vec<(TargetedMessage<SbftMessage>, Timestamp)> {
+        let delivery = now + TimeDiff::from(DELAY);
+        let to_self =
+            |payload| TargetedMessage::new(Message::new(id, payload), Target::SingleValidator(id));
+        outcomes
+            .into_iter()
+            .filter_map(|outcome| match outcome {
+                ProtocolOutcome::CreatedGossipMessage(msg) => Some((
+                    TargetedMessage::new(
+                        Message::new(id, SbftMessage::Payload(msg)),
+                        Target::AllExcept(id),
+                    ),
+                    delivery,
+                )),
+                ProtocolOutcome::CreatedTargetedMessage(msg, recipient) => Some((
+                    TargetedMessage::new(
+                        Message::new(id, SbftMessage::Payload(msg)),
+                        Target::SingleValidator(recipient),
+                    ),
+                    delivery,
+                )),
+                ProtocolOutcome::ScheduleTimer(timestamp, TimerId(timer_id)) => {
+                    Some((to_self(SbftMessage::Timer(timer_id)), timestamp))
+                }
+                ProtocolOutcome::CreateNewBlock { block_context, .. } => {
+                    Some((to_self(SbftMessage::NewBlock(block_context)), now))
+                }
+                ProtocolOutcome::ValidateConsensusValue(_, value, _) => {
+                    Some((to_self(SbftMessage::Validated(value)), now))
+                }
+                ProtocolOutcome::FinalizedBlock(block) => {
+                    finalized.push(block);
+                    None
+                }
+                outcome => panic!("unexpected outcome: {:?}", outcome),
+            })
+            .collect()
+    }
+
+    /// Runs the network until the message queue is empty. Messages from the `mute` validators are
+    /// dropped.
+    fn run(net: &mut SbftNet, mute: &[ValidatorId], rng: &mut TestRng) {
+        for _ in 0..MAX_STEPS {
+            let entry = match net.pop_message() {
+                None => return,
+                Some(entry) => entry,
+            };
+            let now = entry.delivery_time;
+            let id = entry.recipient;
+            let sender = entry.message.sender;
+            let node: &mut SbftNode = net.node_mut(&id).expect("recipient should exist");
+            let sbft = node.validator_mut();
+            let outcomes = match entry.message.payload {
+                SbftMessage::Payload(msg) => sbft.handle_message_at(sender, msg, now, rng),
+                SbftMessage::Timer(timer_id) => {
+                    ConsensusProtocol::handle_timer(sbft, now, TimerId(timer_id), rng)
+                }
+                SbftMessage::NewBlock(block_context) => {
+                    let value = vec![block_context.height() as u8 + 1, id.0 as u8];
+                    sbft.propose_at(value, block_context, now, rng)
+                }
+                SbftMessage::Validated(value) => sbft.resolve_validity_at(&value, true, now, rng),
+            };
+            let mut finalized = vec![];
+            let messages = to_messages(id, outcomes, now, &mut finalized);
+            for block in finalized {
+                node.push_finalized(block);
+            }
+            if !mute.contains(&id) {
+                net.dispatch_messages(messages);
+            } else {
+                // Mute validators still receive their own timers.
+                let own = messages
+                    .into_iter()
+                    .filter(|(msg, _)| match msg.target {
+                        Target::SingleValidator(recipient) => recipient == id,
+                        Target::AllExcept(_) => false,
+                    })
+                    .collect();
+                net.dispatch_messages(own);
+            }
+        }
+        panic!("the message queue should become empty");
+    }
+
+    /// Asserts that all the given validators finalized the same full era.
+    fn assert_finalized_era(net: &SbftNet, ids: &[ValidatorId]) {
+        // The finality is local information, so we compare everything else.
+        let without_finality = |block: &FinalizedBlock<TestContext>| {
+            (
+                block.value.clone(),
+                block.timestamp,
+                block.height,
+                block.terminal_block_data.clone(),
+                block.proposer,
+            )
+        };
+        let reference: Vec<_> = net
+            .validator(&ids[0])
+            .expect("validator should exist")
+            .finalized_values()
+            .cloned()
+            .collect();
+        assert_eq!(ERA_HEIGHT as usize, reference.len());
+        for (height, block) in reference.iter().enumerate() {
+            assert_eq!(height as u64, block.height);
+            let is_last = height as u64 == ERA_HEIGHT - 1;
+            assert_eq!(is_last, block.terminal_block_data.is_some());
+            // At least three of the four validators signed each commit.
+            assert_eq!(1, block.finality.summit_level);
+            assert!(block.finality.fault_tolerance_ppm >= 500_000);
+            assert!(block.finality.contributors.len() >= 3);
+        }
+        for id in ids {
+            let node = net.validator(id).expect("validator should exist");
+            assert!(
+                node.finalized_values()
+                    .map(without_finality)
+                    .eq(reference.iter().map(without_finality)),
+                "validator {} finalized different blocks",
+                id
+            );
+        }
+    }
+
+    #[test]
+    fn honest_validators_finalize_era() {
+        let mut rng = TestRng::new();
+        let (mut net, messages) = new_net(4);
+        net.dispatch_messages(messages);
+        run(&mut net, &[], &mut rng);
+
+        let ids: Vec<ValidatorId> = net.validators_ids().cloned().collect();
+        assert_finalized_era(&net, &ids);
+        let last = net
+            .validator(&ids[0])
+            .and_then(|node| node.finalized_values().last())
+            .expect("should have finalized blocks");
+        let terminal_block_data = last.terminal_block_data.as_ref().unwrap();
+        assert!(terminal_block_data.inactive_validators.is_empty());
+        assert_eq!(4, terminal_block_data.rewards.len());
+    }
+
+    #[test]
+    fn finalize_era_with_mute_validator() {
+        let mut rng = TestRng::new();
+        let (mut net, messages) = new_net(4);
+        let mute = ValidatorId(4);
+        let messages = messages
+            .into_iter()
+            .filter(|(msg, _)| {
+                msg.message.sender != mute || matches!(msg.target, Target::SingleValidator(_))
+            })
+            .collect();
+        net.dispatch_messages(messages);
+        run(&mut net, &[mute], &mut rng);
+
+        let honest = [ValidatorId(1), ValidatorId(2), ValidatorId(3)];
+        assert_finalized_era(&net, &honest);
+        let last = net
+            .validator(&honest[0])
+            .and_then(|node| node.finalized_values().last())
+            .expect("should have finalized blocks");
+        let terminal_block_data = last.terminal_block_data.as_ref().unwrap();
+        assert_eq!(vec![mute], terminal_block_data.inactive_validators);
+        assert!(!terminal_block_data.rewards.contains_key(&mute));
+
+        // The honest validators voted in every height; the mute one didn't send anything.
+        let sbft = net.validator(&honest[0]).unwrap().validator();
+        let stats = ConsensusProtocol::<ValidatorId, TestContext>::validator_stats(sbft);
+        for stat in stats {
+            assert!(!stat.equivocated);
+            assert_eq!(None, stat.round_exponent);
+            if stat.validator_id == mute {
+                assert_eq!(0, stat.units_produced);
+                assert_eq!(0, stat.rounds_participated);
+            } else {
+                assert!(stat.rounds_participated >= ERA_HEIGHT);
+                assert!(stat.units_produced >= 2 * ERA_HEIGHT);
+            }
+        }
+    }
+}
@@ -13,7 +13,9 @@ use crate::{
         requests::{ContractRuntimeRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects,
     },
-    types::{chainspec::DeployConfig, Chainspec, Deploy, DeployValidationFailure, NodeId},
+    types::{
+        chainspec::DeployConfig, Chainspec, Deploy, DeployExt, DeployValidationFailure, NodeId,
+    },
     utils::Source,
     NodeRng,
 };
@@ -91,9 +91,14 @@ where
         event: Self::Event,
     ) -> Effects<Self::Event> {
         match event {
-            Event::BlockAdded { block_hash, block } => self.broadcast(SseData::BlockAdded {
+            Event::BlockAdded {
+                block_hash,
+                block,
+                finality,
+            } => self.broadcast(SseData::BlockAdded {
                 block_hash,
                 block: Box::new(*block),
+                finality,
             }),
             Event::DeployProcessed {
                 deploy_hash,
@@ -4,7 +4,9 @@ use casper_types::{ExecutionResult, PublicKey};
 
 use crate::{
     components::consensus::EraId,
-    types::{Block, BlockHash, DeployHash, DeployHeader, FinalitySignature, Timestamp},
+    types::{
+        Block, BlockFinality, BlockHash, DeployHash, DeployHeader, FinalitySignature, Timestamp,
+    },
 };
 
 #[derive(Debug)]
@@ -12,6 +14,7 @@ pub enum Event {
     BlockAdded {
         block_hash: BlockHash,
         block: Box<Block>,
+        finality: Option<BlockFinality>,
     },
     DeployProcessed {
         deploy_hash: DeployHash,
@@ -19,7 +19,7 @@ use casper_types::{ExecutionResult, PublicKey};
 
 use crate::{
     components::consensus::EraId,
-    types::{Block, BlockHash, DeployHash, FinalitySignature, TimeDiff, Timestamp},
+    types::{Block, BlockFinality, BlockHash, DeployHash, FinalitySignature, TimeDiff, Timestamp},
 };
 
 /// The URL path.
@@ -39,6 +39,8 @@ pub enum SseData {
     BlockAdded {
         block_hash: BlockHash,
         block: Box<Block>,
+        /// The fault tolerance with which this node observed the block's finality, if known.
+        finality: Option<BlockFinality>,
     },
     /// The given deploy has been executed, committed and forms part of the given block.
     DeployProcessed {
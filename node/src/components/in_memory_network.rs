@@ -590,6 +590,8 @@ where
                     responder.respond(Default::default()).ignore()
                 }
             }
+            NetworkRequest::ReportMisbehavior { responder, .. } => responder.respond(()).ignore(),
+            NetworkRequest::SetValidators { responder, .. } => responder.respond(()).ignore(),
         }
     }
 }
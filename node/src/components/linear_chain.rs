@@ -435,9 +435,13 @@ where
                         .map_some(move |fs| Event::FinalitySignatureReceived(Box::new(fs))),
                 );
                 effects.extend(
-                    effect_builder
-                        .announce_block_added(block_hash, block)
-                        .ignore(),
+                    async move {
+                        let finality = effect_builder.get_block_finality_from_storage(height).await;
+                        effect_builder
+                            .announce_block_added(block_hash, block, finality)
+                            .await
+                    }
+                    .ignore(),
                 );
                 effects
             }
@@ -994,6 +994,19 @@ impl<REv: ReactorEventT<P>, P: PayloadT> Component<REv> for Network<REv, P> {
                     let sent_to = self.send_message_to_n_peers(rng, *payload, count, exclude);
                     responder.respond(sent_to).ignore()
                 }
+                NetworkRequest::ReportMisbehavior {
+                    peer,
+                    misbehavior,
+                    responder,
+                } => {
+                    // TODO - support peer reputation in the libp2p network.
+                    debug!(%peer, %misbehavior, "ignoring misbehavior report");
+                    responder.respond(()).ignore()
+                }
+                NetworkRequest::SetValidators { responder, .. } => {
+                    // TODO - support prioritizing validators in the libp2p network.
+                    responder.respond(()).ignore()
+                }
             },
             Event::NetworkInfoRequest { info_request } => match info_request {
                 NetworkInfoRequest::GetPeers { responder } => {
@@ -1006,6 +1019,9 @@ impl<REv: ReactorEventT<P>, P: PayloadT> Component<REv> for Network<REv, P> {
                         .collect();
                     responder.respond(peers).ignore()
                 }
+                NetworkInfoRequest::GetBannedPeers { responder } => {
+                    responder.respond(Vec::new()).ignore()
+                }
             },
         }
     }
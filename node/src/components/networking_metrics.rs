@@ -1,4 +1,6 @@
-use prometheus::{IntCounter, IntGauge, Registry};
+use prometheus::{IntCounter, IntCounterVec, IntGauge, Opts, Registry};
+
+use crate::{components::small_network::MessageClass, types::NodeId};
 
 /// Network-type agnostic networking metrics.
 pub(crate) struct NetworkingMetrics {
@@ -12,6 +14,16 @@ pub(crate) struct NetworkingMetrics {
     pub(crate) queued_messages: IntGauge,
     /// Number of connected peers.
     pub(crate) peers: IntGauge,
+    /// Number of times a misbehaving peer was banned.
+    pub(crate) peer_bans: IntCounter,
+    /// Number of incoming messages delayed due to rate limiting, by peer and message class.
+    pub(crate) incoming_throttled: IntCounterVec,
+    /// Number of incoming messages dropped due to rate limiting, by peer and message class.
+    pub(crate) incoming_dropped: IntCounterVec,
+    /// Number of outgoing messages delayed due to rate limiting, by peer and message class.
+    pub(crate) outgoing_throttled: IntCounterVec,
+    /// Number of outgoing messages dropped due to a full queue, by peer and message class.
+    pub(crate) outgoing_dropped: IntCounterVec,
 
     // Potentially temporary metrics, not supported by all networking components:
     /// Number of do-nothing futures that have not finished executing for read requests.
@@ -43,6 +55,38 @@ impl NetworkingMetrics {
             "number of messages waiting to be sent out",
         )?;
         let peers = IntGauge::new("peers", "Number of connected peers.")?;
+        let peer_bans = IntCounter::new(
+            "net_peer_bans",
+            "number of times a misbehaving peer was banned",
+        )?;
+        let incoming_throttled = IntCounterVec::new(
+            Opts::new(
+                "net_incoming_throttled_messages",
+                "number of incoming messages delayed due to rate limiting",
+            ),
+            &["peer", "class"],
+        )?;
+        let incoming_dropped = IntCounterVec::new(
+            Opts::new(
+                "net_incoming_dropped_messages",
+                "number of incoming messages dropped due to rate limiting",
+            ),
+            &["peer", "class"],
+        )?;
+        let outgoing_throttled = IntCounterVec::new(
+            Opts::new(
+                "net_outgoing_throttled_messages",
+                "number of outgoing messages delayed due to rate limiting",
+            ),
+            &["peer", "class"],
+        )?;
+        let outgoing_dropped = IntCounterVec::new(
+            Opts::new(
+                "net_outgoing_dropped_messages",
+                "number of outgoing messages dropped due to a full queue",
+            ),
+            &["peer", "class"],
+        )?;
 
         let read_futures_in_flight = prometheus::Gauge::new(
             "owm_read_futures_in_flight",
@@ -66,6 +110,11 @@ impl NetworkingMetrics {
         registry.register(Box::new(open_connections.clone()))?;
         registry.register(Box::new(queued_messages.clone()))?;
         registry.register(Box::new(peers.clone()))?;
+        registry.register(Box::new(peer_bans.clone()))?;
+        registry.register(Box::new(incoming_throttled.clone()))?;
+        registry.register(Box::new(incoming_dropped.clone()))?;
+        registry.register(Box::new(outgoing_throttled.clone()))?;
+        registry.register(Box::new(outgoing_dropped.clone()))?;
 
         registry.register(Box::new(read_futures_in_flight.clone()))?;
         registry.register(Box::new(read_futures_total.clone()))?;
@@ -78,6 +127,11 @@ impl NetworkingMetrics {
             open_connections,
             queued_messages,
             peers,
+            peer_bans,
+            incoming_throttled,
+            incoming_dropped,
+            outgoing_throttled,
+            outgoing_dropped,
             read_futures_in_flight,
             read_futures_total,
             write_futures_in_flight,
@@ -85,6 +139,18 @@ impl NetworkingMetrics {
             registry: registry.clone(),
         })
     }
+
+    /// Removes the rate limiting metrics of a disconnected peer.
+    pub(crate) fn remove_peer(&self, peer_id: &NodeId) {
+        let peer = peer_id.to_string();
+        for class in MessageClass::ALL.iter() {
+            let labels = [peer.as_str(), class.label()];
+            let _ = self.incoming_throttled.remove_label_values(&labels);
+            let _ = self.incoming_dropped.remove_label_values(&labels);
+            let _ = self.outgoing_throttled.remove_label_values(&labels);
+            let _ = self.outgoing_dropped.remove_label_values(&labels);
+        }
+    }
 }
 
 impl Drop for NetworkingMetrics {
@@ -104,6 +170,21 @@ impl Drop for NetworkingMetrics {
         self.registry
             .unregister(Box::new(self.peers.clone()))
             .expect("did not expect deregistering peers to fail");
+        self.registry
+            .unregister(Box::new(self.peer_bans.clone()))
+            .expect("did not expect deregistering peer_bans to fail");
+        self.registry
+            .unregister(Box::new(self.incoming_throttled.clone()))
+            .expect("did not expect deregistering incoming_throttled to fail");
+        self.registry
+            .unregister(Box::new(self.incoming_dropped.clone()))
+            .expect("did not expect deregistering incoming_dropped to fail");
+        self.registry
+            .unregister(Box::new(self.outgoing_throttled.clone()))
+            .expect("did not expect deregistering outgoing_throttled to fail");
+        self.registry
+            .unregister(Box::new(self.outgoing_dropped.clone()))
+            .expect("did not expect deregistering outgoing_dropped to fail");
 
         self.registry
             .unregister(Box::new(self.read_futures_in_flight.clone()))
@@ -143,6 +143,11 @@ where
                     text,
                     main_responder: responder,
                 }),
+            Event::RestRequest(RestRequest::GetBannedPeers { responder }) => async move {
+                let banned_peers = effect_builder.network_banned_peers().await;
+                responder.respond(banned_peers).await;
+            }
+            .ignore(),
             Event::GetMetricsResult {
                 text,
                 main_responder,
@@ -15,7 +15,6 @@ use super::ReactorEventT;
 use crate::{
     effect::{requests::RestRequest, EffectBuilder},
     reactor::QueueKind,
-    types::GetStatusResult,
 };
 
 /// The status URL path.
@@ -24,6 +23,9 @@ pub const STATUS_API_PATH: &str = "status";
 /// The metrics URL path.
 pub const METRICS_API_PATH: &str = "metrics";
 
+/// The banned peers URL path.
+pub const BANNED_PEERS_API_PATH: &str = "banned-peers";
+
 pub(super) fn create_status_filter<REv: ReactorEventT>(
     effect_builder: EffectBuilder<REv>,
     api_version: Version,
@@ -38,13 +40,29 @@ pub(super) fn create_status_filter<REv: ReactorEventT>(
                     QueueKind::Api,
                 )
                 .map(|status_feed| {
-                    let body = GetStatusResult::new(status_feed, api_version_cloned);
+                    let body = status_feed.into_status_result(api_version_cloned);
                     Ok::<_, Rejection>(reply::json(&body).into_response())
                 })
         })
         .boxed()
 }
 
+pub(super) fn create_banned_peers_filter<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+) -> BoxedFilter<(Response<Body>,)> {
+    warp::get()
+        .and(warp::path(BANNED_PEERS_API_PATH))
+        .and_then(move || {
+            effect_builder
+                .make_request(
+                    |responder| RestRequest::GetBannedPeers { responder },
+                    QueueKind::Api,
+                )
+                .map(|banned_peers| Ok::<_, Rejection>(reply::json(&banned_peers).into_response()))
+        })
+        .boxed()
+}
+
 pub(super) fn create_metrics_filter<REv: ReactorEventT>(
     effect_builder: EffectBuilder<REv>,
 ) -> BoxedFilter<(Response<Body>,)> {
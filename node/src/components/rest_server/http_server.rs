@@ -24,8 +24,9 @@ pub(super) async fn run<REv: ReactorEventT>(
     // REST filters.
     let rest_status = filters::create_status_filter(effect_builder, api_version);
     let rest_metrics = filters::create_metrics_filter(effect_builder);
+    let rest_banned_peers = filters::create_banned_peers_filter(effect_builder);
 
-    let service = warp_json_rpc::service(rest_status.or(rest_metrics));
+    let service = warp_json_rpc::service(rest_status.or(rest_metrics).or(rest_banned_peers));
 
     // Start the server, passing a oneshot receiver to allow the server to be shut down gracefully.
     let make_svc =
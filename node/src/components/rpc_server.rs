@@ -234,6 +234,36 @@ where
                     result: Box::new(result),
                     main_responder: responder,
                 }),
+            Event::RpcRequest(RpcRequest::GetBlockFinality {
+                block_height,
+                responder,
+            }) => effect_builder
+                .get_block_finality_from_storage(block_height)
+                .event(move |result| Event::GetBlockFinalityResult {
+                    block_height,
+                    result: Box::new(result),
+                    main_responder: responder,
+                }),
+            Event::RpcRequest(RpcRequest::GetEraValidatorPerformance { era_id, responder }) => {
+                effect_builder
+                    .get_era_validator_performance_from_storage(era_id)
+                    .event(move |result| Event::GetEraValidatorPerformanceResult {
+                        era_id,
+                        result: Box::new(result),
+                        main_responder: responder,
+                    })
+            }
+            Event::RpcRequest(RpcRequest::GetEraRewardHistory {
+                public_key,
+                from_era_id,
+                to_era_id,
+                responder,
+            }) => effect_builder
+                .get_era_reward_history_from_storage(*public_key, from_era_id, to_era_id)
+                .event(move |result| Event::GetEraRewardHistoryResult {
+                    result,
+                    main_responder: responder,
+                }),
             Event::RpcRequest(RpcRequest::QueryProtocolData {
                 protocol_version,
                 responder,
@@ -284,6 +314,12 @@ where
                     peers,
                     main_responder: responder,
                 }),
+            Event::RpcRequest(RpcRequest::GetBannedPeers { responder }) => effect_builder
+                .network_banned_peers()
+                .event(move |banned_peers| Event::GetBannedPeersResult {
+                    banned_peers,
+                    main_responder: responder,
+                }),
             Event::RpcRequest(RpcRequest::GetStatus { responder }) => async move {
                 let (last_added_block, peers, chainspec_info, consensus_status) = join!(
                     effect_builder.get_highest_block_from_storage(),
@@ -312,6 +348,20 @@ where
                 main_responder,
                 ..
             } => main_responder.respond(*result).ignore(),
+            Event::GetBlockFinalityResult {
+                result,
+                main_responder,
+                ..
+            } => main_responder.respond(*result).ignore(),
+            Event::GetEraValidatorPerformanceResult {
+                result,
+                main_responder,
+                ..
+            } => main_responder.respond(*result).ignore(),
+            Event::GetEraRewardHistoryResult {
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
             Event::QueryProtocolDataResult {
                 result,
                 main_responder,
@@ -341,6 +391,10 @@ where
                 peers,
                 main_responder,
             } => main_responder.respond(peers).ignore(),
+            Event::GetBannedPeersResult {
+                banned_peers,
+                main_responder,
+            } => main_responder.respond(banned_peers).ignore(),
             Event::GetMetricsResult {
                 text,
                 main_responder,
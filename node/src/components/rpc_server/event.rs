@@ -12,9 +12,13 @@ use casper_execution_engine::{
 use casper_types::{system::auction::EraValidators, Transfer};
 
 use crate::{
+    components::{consensus::EraId, small_network::BannedPeer},
     effect::{requests::RpcRequest, Responder},
     rpcs::chain::BlockIdentifier,
-    types::{Block, BlockHash, BlockSignatures, Deploy, DeployHash, DeployMetadata, NodeId},
+    types::{
+        Block, BlockFinality, BlockHash, BlockSignatures, Deploy, DeployHash, DeployMetadata,
+        EraRewards, EraValidatorPerformance, NodeId,
+    },
 };
 
 #[derive(Debug, From)]
@@ -31,6 +35,20 @@ pub enum Event {
         result: Box<Option<Vec<Transfer>>>,
         main_responder: Responder<Option<Vec<Transfer>>>,
     },
+    GetBlockFinalityResult {
+        block_height: u64,
+        result: Box<Option<BlockFinality>>,
+        main_responder: Responder<Option<BlockFinality>>,
+    },
+    GetEraValidatorPerformanceResult {
+        era_id: EraId,
+        result: Box<Option<EraValidatorPerformance>>,
+        main_responder: Responder<Option<EraValidatorPerformance>>,
+    },
+    GetEraRewardHistoryResult {
+        result: Vec<EraRewards>,
+        main_responder: Responder<Vec<EraRewards>>,
+    },
     QueryProtocolDataResult {
         result: Result<Option<Box<ProtocolData>>, engine_state::Error>,
         main_responder: Responder<Result<Option<Box<ProtocolData>>, engine_state::Error>>,
@@ -56,6 +74,10 @@ pub enum Event {
         peers: BTreeMap<NodeId, String>,
         main_responder: Responder<BTreeMap<NodeId, String>>,
     },
+    GetBannedPeersResult {
+        banned_peers: Vec<BannedPeer>,
+        main_responder: Responder<Vec<BannedPeer>>,
+    },
     GetMetricsResult {
         text: Option<String>,
         main_responder: Responder<Option<String>>,
@@ -92,6 +114,23 @@ impl Display for Event {
                 "get block transfers result for block_hash {}: {:?}",
                 block_hash, result
             ),
+            Event::GetBlockFinalityResult {
+                block_height,
+                result,
+                ..
+            } => write!(
+                formatter,
+                "get block finality result for height {}: {:?}",
+                block_height, result
+            ),
+            Event::GetEraValidatorPerformanceResult { era_id, result, .. } => write!(
+                formatter,
+                "get validator performance result for era {}: {:?}",
+                era_id, result
+            ),
+            Event::GetEraRewardHistoryResult { result, .. } => {
+                write!(formatter, "get era reward history result: {:?}", result)
+            }
             Event::QueryProtocolDataResult { result, .. } => {
                 write!(formatter, "query protocol data result: {:?}", result)
             }
@@ -111,6 +150,9 @@ impl Display for Event {
                 write!(formatter, "get deploy result for {}: {:?}", hash, result)
             }
             Event::GetPeersResult { peers, .. } => write!(formatter, "get peers: {}", peers.len()),
+            Event::GetBannedPeersResult { banned_peers, .. } => {
+                write!(formatter, "get banned peers: {}", banned_peers.len())
+            }
             Event::GetMetricsResult { text, .. } => match text {
                 Some(txt) => write!(formatter, "get metrics ({} bytes)", txt.len()),
                 None => write!(formatter, "get metrics (failed)"),
@@ -56,6 +56,8 @@ pub(super) async fn run<REv: ReactorEventT>(
     let rpc_get_block = rpcs::chain::GetBlock::create_filter(effect_builder, api_version.clone());
     let rpc_get_block_transfers =
         rpcs::chain::GetBlockTransfers::create_filter(effect_builder, api_version.clone());
+    let rpc_get_block_finality =
+        rpcs::chain::GetBlockFinality::create_filter(effect_builder, api_version.clone());
     let rpc_get_state_root_hash =
         rpcs::chain::GetStateRootHash::create_filter(effect_builder, api_version.clone());
     let rpc_get_item = rpcs::state::GetItem::create_filter(effect_builder, api_version.clone());
@@ -64,8 +66,15 @@ pub(super) async fn run<REv: ReactorEventT>(
     let rpc_get_deploy = rpcs::info::GetDeploy::create_filter(effect_builder, api_version.clone());
     let rpc_get_peers = rpcs::info::GetPeers::create_filter(effect_builder, api_version.clone());
     let rpc_get_status = rpcs::info::GetStatus::create_filter(effect_builder, api_version.clone());
+    let rpc_get_deploy_config =
+        rpcs::info::GetDeployConfig::create_filter(effect_builder, api_version.clone());
+    let rpc_get_supply = rpcs::info::GetSupply::create_filter(effect_builder, api_version.clone());
     let rpc_get_era_info =
         rpcs::chain::GetEraInfoBySwitchBlock::create_filter(effect_builder, api_version.clone());
+    let rpc_get_era_validator_performance =
+        rpcs::chain::GetEraValidatorPerformance::create_filter(effect_builder, api_version.clone());
+    let rpc_get_era_reward_history =
+        rpcs::chain::GetEraRewardHistory::create_filter(effect_builder, api_version.clone());
     let rpc_get_auction_info =
         rpcs::state::GetAuctionInfo::create_filter(effect_builder, api_version.clone());
     let rpc_get_rpcs = rpcs::docs::ListRpcs::create_filter(effect_builder, api_version);
@@ -92,13 +101,18 @@ pub(super) async fn run<REv: ReactorEventT>(
         rpc_put_deploy
             .or(rpc_get_block)
             .or(rpc_get_block_transfers)
+            .or(rpc_get_block_finality)
             .or(rpc_get_state_root_hash)
             .or(rpc_get_item)
             .or(rpc_get_balance)
             .or(rpc_get_deploy)
             .or(rpc_get_peers)
             .or(rpc_get_status)
+            .or(rpc_get_deploy_config)
+            .or(rpc_get_supply)
             .or(rpc_get_era_info)
+            .or(rpc_get_era_validator_performance)
+            .or(rpc_get_era_reward_history)
             .or(rpc_get_auction_info)
             .or(rpc_get_rpcs)
             .or(unknown_method)
@@ -1,29 +1,25 @@
 //! RPCs related to accounts.
 
-// TODO - remove once schemars stops causing warning.
-#![allow(clippy::field_reassign_with_default)]
-
 use std::str;
 
 use futures::{future::BoxFuture, FutureExt};
 use http::Response;
 use hyper::Body;
 use once_cell::sync::Lazy;
-use schemars::JsonSchema;
 use semver::Version;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
+pub use casper_rpc_client::rpcs::account::{PutDeploy, PutDeployParams, PutDeployResult};
+use casper_rpc_client::RpcMethod;
+
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     Error, ReactorEventT, RpcRequest, RpcWithParams, RpcWithParamsExt,
 };
 use crate::{
-    components::rpc_server::rpcs::ErrorCode,
-    effect::EffectBuilder,
-    reactor::QueueKind,
-    types::{Deploy, DeployHash},
+    components::rpc_server::rpcs::ErrorCode, effect::EffectBuilder, reactor::QueueKind,
+    types::Deploy,
 };
 
 static PUT_DEPLOY_PARAMS: Lazy<PutDeployParams> = Lazy::new(|| PutDeployParams {
@@ -34,42 +30,20 @@ static PUT_DEPLOY_RESULT: Lazy<PutDeployResult> = Lazy::new(|| PutDeployResult {
     deploy_hash: *Deploy::doc_example().id(),
 });
 
-/// Params for "account_put_deploy" RPC request.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct PutDeployParams {
-    /// The `Deploy`.
-    pub deploy: Deploy,
-}
-
 impl DocExample for PutDeployParams {
     fn doc_example() -> &'static Self {
         &*PUT_DEPLOY_PARAMS
     }
 }
 
-/// Result for "account_put_deploy" RPC response.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct PutDeployResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    pub api_version: Version,
-    /// The deploy hash.
-    pub deploy_hash: DeployHash,
-}
-
 impl DocExample for PutDeployResult {
     fn doc_example() -> &'static Self {
         &*PUT_DEPLOY_RESULT
     }
 }
 
-/// "account_put_deploy" RPC
-pub struct PutDeploy {}
-
 impl RpcWithParams for PutDeploy {
-    const METHOD: &'static str = "account_put_deploy";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type RequestParams = PutDeployParams;
     type ResponseResult = PutDeployResult;
 }
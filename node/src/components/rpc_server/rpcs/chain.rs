@@ -1,37 +1,50 @@
 //! RPCs related to the block chain.
 
-// TODO - remove once schemars stops causing warning.
-#![allow(clippy::field_reassign_with_default)]
-
-mod era_summary;
-
 use std::str;
 
 use futures::{future::BoxFuture, FutureExt};
 use http::Response;
 use hyper::Body;
 use once_cell::sync::Lazy;
-use schemars::JsonSchema;
 use semver::Version;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_types::{Key, Transfer};
+pub use casper_node_types::BlockIdentifier;
+pub use casper_rpc_client::rpcs::chain::{
+    EraSummary, GetBlock, GetBlockFinality, GetBlockFinalityParams, GetBlockFinalityResult,
+    GetBlockParams, GetBlockResult, GetBlockTransfers, GetBlockTransfersParams,
+    GetBlockTransfersResult, GetEraInfoBySwitchBlock, GetEraInfoParams, GetEraInfoResult,
+    GetEraRewardHistory, GetEraRewardHistoryParams, GetEraRewardHistoryResult,
+    GetEraValidatorPerformance, GetEraValidatorPerformanceParams, GetEraValidatorPerformanceResult,
+    GetStateRootHash, GetStateRootHashParams, GetStateRootHashResult,
+};
+use casper_rpc_client::{types::Block as JsonBlock, RpcMethod};
+use casper_types::{system::auction::EraInfo, Key, Transfer};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithOptionalParams, RpcWithOptionalParamsExt,
+    RpcWithParams, RpcWithParamsExt,
 };
 use crate::{
-    crypto::hash::Digest,
+    components::consensus::EraId,
     effect::EffectBuilder,
     reactor::QueueKind,
-    rpcs::common::{self},
-    types::{Block, BlockHash, BlockSignatures, Item, JsonBlock},
+    rpcs::common::{self, MERKLE_PROOF},
+    types::{
+        json_compatibility::StoredValue, Block, BlockFinality, BlockSignatures, EraRewards,
+        EraValidatorPerformance, Item,
+    },
 };
-pub use era_summary::EraSummary;
-use era_summary::ERA_SUMMARY;
+
+pub(super) static ERA_SUMMARY: Lazy<EraSummary> = Lazy::new(|| EraSummary {
+    block_hash: Block::doc_example().id(),
+    era_id: 42,
+    stored_value: StoredValue::EraInfo(EraInfo::new()),
+    state_root_hash: *Block::doc_example().header().state_root_hash(),
+    merkle_proof: MERKLE_PROOF.clone(),
+});
 
 static GET_BLOCK_PARAMS: Lazy<GetBlockParams> = Lazy::new(|| GetBlockParams {
     block_identifier: BlockIdentifier::Hash(Block::doc_example().id()),
@@ -50,6 +63,36 @@ static GET_BLOCK_TRANSFERS_RESULT: Lazy<GetBlockTransfersResult> =
         block_hash: Some(Block::doc_example().id()),
         transfers: Some(vec![Transfer::default()]),
     });
+static GET_BLOCK_FINALITY_PARAMS: Lazy<GetBlockFinalityParams> =
+    Lazy::new(|| GetBlockFinalityParams {
+        block_identifier: BlockIdentifier::Hash(Block::doc_example().id()),
+    });
+static GET_BLOCK_FINALITY_RESULT: Lazy<GetBlockFinalityResult> =
+    Lazy::new(|| GetBlockFinalityResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        block_hash: Some(Block::doc_example().id()),
+        finality: Some(BlockFinality::doc_example().clone().into()),
+    });
+static GET_ERA_VALIDATOR_PERFORMANCE_PARAMS: Lazy<GetEraValidatorPerformanceParams> =
+    Lazy::new(|| GetEraValidatorPerformanceParams {
+        era_id: EraValidatorPerformance::doc_example().era_id().0,
+    });
+static GET_ERA_VALIDATOR_PERFORMANCE_RESULT: Lazy<GetEraValidatorPerformanceResult> =
+    Lazy::new(|| GetEraValidatorPerformanceResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        performance: Some(EraValidatorPerformance::doc_example().clone().into()),
+    });
+static GET_ERA_REWARD_HISTORY_PARAMS: Lazy<GetEraRewardHistoryParams> =
+    Lazy::new(|| GetEraRewardHistoryParams {
+        public_key: EraRewards::doc_example().rewards[0].validator_public_key,
+        from_era_id: EraRewards::doc_example().era_id().0,
+        to_era_id: EraRewards::doc_example().era_id().0,
+    });
+static GET_ERA_REWARD_HISTORY_RESULT: Lazy<GetEraRewardHistoryResult> =
+    Lazy::new(|| GetEraRewardHistoryResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        era_rewards: vec![EraRewards::doc_example().clone().into()],
+    });
 static GET_STATE_ROOT_HASH_PARAMS: Lazy<GetStateRootHashParams> =
     Lazy::new(|| GetStateRootHashParams {
         block_identifier: BlockIdentifier::Height(Block::doc_example().header().height()),
@@ -67,52 +110,20 @@ static GET_ERA_INFO_RESULT: Lazy<GetEraInfoResult> = Lazy::new(|| GetEraInfoResu
     era_summary: Some(ERA_SUMMARY.clone()),
 });
 
-/// Identifier for possible ways to retrieve a block.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub enum BlockIdentifier {
-    /// Identify and retrieve the block with its hash.
-    Hash(BlockHash),
-    /// Identify and retrieve the block with its height.
-    Height(u64),
-}
-
-/// Params for "chain_get_block" RPC request.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetBlockParams {
-    /// The block hash.
-    pub block_identifier: BlockIdentifier,
-}
-
 impl DocExample for GetBlockParams {
     fn doc_example() -> &'static Self {
         &*GET_BLOCK_PARAMS
     }
 }
 
-/// Result for "chain_get_block" RPC response.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetBlockResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    pub api_version: Version,
-    /// The block, if found.
-    pub block: Option<JsonBlock>,
-}
-
 impl DocExample for GetBlockResult {
     fn doc_example() -> &'static Self {
         &*GET_BLOCK_RESULT
     }
 }
 
-/// "chain_get_block" RPC.
-pub struct GetBlock {}
-
 impl RpcWithOptionalParams for GetBlock {
-    const METHOD: &'static str = "chain_get_block";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type OptionalRequestParams = GetBlockParams;
     type ResponseResult = GetBlockResult;
 }
@@ -140,7 +151,7 @@ impl RpcWithOptionalParamsExt for GetBlock {
                     Err(error) => return Ok(response_builder.error(error)?),
                 };
 
-            let json_block = JsonBlock::new(block, signatures);
+            let json_block = block.into_json(signatures);
 
             // Return the result.
             let result = Self::ResponseResult {
@@ -153,59 +164,20 @@ impl RpcWithOptionalParamsExt for GetBlock {
     }
 }
 
-/// Params for "chain_get_block_transfers" RPC request.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetBlockTransfersParams {
-    /// The block hash.
-    pub block_identifier: BlockIdentifier,
-}
-
 impl DocExample for GetBlockTransfersParams {
     fn doc_example() -> &'static Self {
         &*GET_BLOCK_TRANSFERS_PARAMS
     }
 }
 
-/// Result for "chain_get_block_transfers" RPC response.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetBlockTransfersResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    pub api_version: Version,
-    /// The block hash, if found.
-    pub block_hash: Option<BlockHash>,
-    /// The block's transfers, if found.
-    pub transfers: Option<Vec<Transfer>>,
-}
-
-impl GetBlockTransfersResult {
-    /// Create an instance of GetBlockTransfersResult.
-    pub fn new(
-        api_version: Version,
-        block_hash: Option<BlockHash>,
-        transfers: Option<Vec<Transfer>>,
-    ) -> Self {
-        GetBlockTransfersResult {
-            api_version,
-            block_hash,
-            transfers,
-        }
-    }
-}
-
 impl DocExample for GetBlockTransfersResult {
     fn doc_example() -> &'static Self {
         &*GET_BLOCK_TRANSFERS_RESULT
     }
 }
 
-/// "chain_get_block_transfers" RPC.
-pub struct GetBlockTransfers {}
-
 impl RpcWithOptionalParams for GetBlockTransfers {
-    const METHOD: &'static str = "chain_get_block_transfers";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type OptionalRequestParams = GetBlockTransfersParams;
     type ResponseResult = GetBlockTransfersResult;
 }
@@ -223,11 +195,11 @@ impl RpcWithOptionalParamsExt for GetBlockTransfers {
             let block_hash = match get_block(maybe_block_id, effect_builder).await {
                 Ok(Some(block)) => *block.hash(),
                 Ok(None) => {
-                    return Ok(response_builder.success(Self::ResponseResult::new(
+                    return Ok(response_builder.success(Self::ResponseResult {
                         api_version,
-                        None,
-                        None,
-                    ))?)
+                        block_hash: None,
+                        transfers: None,
+                    })?)
                 }
                 Err(error) => return Ok(response_builder.error(error)?),
             };
@@ -243,36 +215,180 @@ impl RpcWithOptionalParamsExt for GetBlockTransfers {
                 .await;
 
             // Return the result.
-            let result = Self::ResponseResult::new(api_version, Some(block_hash), transfers);
+            let result = Self::ResponseResult {
+                api_version,
+                block_hash: Some(block_hash),
+                transfers,
+            };
             Ok(response_builder.success(result)?)
         }
         .boxed()
     }
 }
 
-/// Params for "chain_get_state_root_hash" RPC request.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetStateRootHashParams {
-    /// The block hash.
-    pub block_identifier: BlockIdentifier,
+impl DocExample for GetBlockFinalityParams {
+    fn doc_example() -> &'static Self {
+        &*GET_BLOCK_FINALITY_PARAMS
+    }
 }
 
-impl DocExample for GetStateRootHashParams {
+impl DocExample for GetBlockFinalityResult {
     fn doc_example() -> &'static Self {
-        &*GET_STATE_ROOT_HASH_PARAMS
+        &*GET_BLOCK_FINALITY_RESULT
     }
 }
 
-/// Result for "chain_get_state_root_hash" RPC response.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetStateRootHashResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    pub api_version: Version,
-    /// Hex-encoded hash of the state root.
-    pub state_root_hash: Option<Digest>,
+impl RpcWithOptionalParams for GetBlockFinality {
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
+    type OptionalRequestParams = GetBlockFinalityParams;
+    type ResponseResult = GetBlockFinalityResult;
+}
+
+impl RpcWithOptionalParamsExt for GetBlockFinality {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        maybe_params: Option<Self::OptionalRequestParams>,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            // Get the block.
+            let maybe_block_id = maybe_params.map(|params| params.block_identifier);
+            let block = match get_block(maybe_block_id, effect_builder).await {
+                Ok(Some(block)) => block,
+                Ok(None) => {
+                    return Ok(response_builder.success(Self::ResponseResult {
+                        api_version,
+                        block_hash: None,
+                        finality: None,
+                    })?)
+                }
+                Err(error) => return Ok(response_builder.error(error)?),
+            };
+
+            let block_height = block.height();
+            let finality = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetBlockFinality {
+                        block_height,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            // Return the result.
+            let result = Self::ResponseResult {
+                api_version,
+                block_hash: Some(*block.hash()),
+                finality: finality.map(Into::into),
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+impl DocExample for GetEraValidatorPerformanceParams {
+    fn doc_example() -> &'static Self {
+        &*GET_ERA_VALIDATOR_PERFORMANCE_PARAMS
+    }
+}
+
+impl DocExample for GetEraValidatorPerformanceResult {
+    fn doc_example() -> &'static Self {
+        &*GET_ERA_VALIDATOR_PERFORMANCE_RESULT
+    }
+}
+
+impl RpcWithParams for GetEraValidatorPerformance {
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
+    type RequestParams = GetEraValidatorPerformanceParams;
+    type ResponseResult = GetEraValidatorPerformanceResult;
+}
+
+impl RpcWithParamsExt for GetEraValidatorPerformance {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let performance = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetEraValidatorPerformance {
+                        era_id: EraId(params.era_id),
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            // Return the result.
+            let result = Self::ResponseResult {
+                api_version,
+                performance: performance.map(Into::into),
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+impl DocExample for GetEraRewardHistoryParams {
+    fn doc_example() -> &'static Self {
+        &*GET_ERA_REWARD_HISTORY_PARAMS
+    }
+}
+
+impl DocExample for GetEraRewardHistoryResult {
+    fn doc_example() -> &'static Self {
+        &*GET_ERA_REWARD_HISTORY_RESULT
+    }
+}
+
+impl RpcWithParams for GetEraRewardHistory {
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
+    type RequestParams = GetEraRewardHistoryParams;
+    type ResponseResult = GetEraRewardHistoryResult;
+}
+
+impl RpcWithParamsExt for GetEraRewardHistory {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let era_rewards = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetEraRewardHistory {
+                        public_key: Box::new(params.public_key),
+                        from_era_id: EraId(params.from_era_id),
+                        to_era_id: EraId(params.to_era_id),
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            // Return the result.
+            let result = Self::ResponseResult {
+                api_version,
+                era_rewards: era_rewards.into_iter().map(Into::into).collect(),
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+impl DocExample for GetStateRootHashParams {
+    fn doc_example() -> &'static Self {
+        &*GET_STATE_ROOT_HASH_PARAMS
+    }
 }
 
 impl DocExample for GetStateRootHashResult {
@@ -281,11 +397,8 @@ impl DocExample for GetStateRootHashResult {
     }
 }
 
-/// "chain_get_state_root_hash" RPC.
-pub struct GetStateRootHash {}
-
 impl RpcWithOptionalParams for GetStateRootHash {
-    const METHOD: &'static str = "chain_get_state_root_hash";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type OptionalRequestParams = GetStateRootHashParams;
     type ResponseResult = GetStateRootHashResult;
 }
@@ -316,42 +429,20 @@ impl RpcWithOptionalParamsExt for GetStateRootHash {
     }
 }
 
-/// Params for "chain_get_era_info" RPC request.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetEraInfoParams {
-    /// The block identifier.
-    pub block_identifier: BlockIdentifier,
-}
-
 impl DocExample for GetEraInfoParams {
     fn doc_example() -> &'static Self {
         &*GET_ERA_INFO_PARAMS
     }
 }
 
-/// Result for "chain_get_era_info" RPC response.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetEraInfoResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    pub api_version: Version,
-    /// The era summary.
-    pub era_summary: Option<EraSummary>,
-}
-
 impl DocExample for GetEraInfoResult {
     fn doc_example() -> &'static Self {
         &*GET_ERA_INFO_RESULT
     }
 }
 
-/// "chain_get_era_info_by_switch_block" RPC
-pub struct GetEraInfoBySwitchBlock {}
-
 impl RpcWithOptionalParams for GetEraInfoBySwitchBlock {
-    const METHOD: &'static str = "chain_get_era_info_by_switch_block";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type OptionalRequestParams = GetEraInfoParams;
     type ResponseResult = GetEraInfoResult;
 }
@@ -381,51 +472,14 @@ impl RpcWithOptionalParamsExt for GetEraInfoBySwitchBlock {
                 }
             };
 
-            let era_id = match block.header().era_end() {
-                Some(_) => block.header().era_id().0,
-                None => {
-                    return Ok(response_builder.success(Self::ResponseResult {
-                        api_version,
-                        era_summary: None,
-                    })?)
-                }
-            };
-
-            let state_root_hash = block.state_root_hash().to_owned();
-            let base_key = Key::EraInfo(era_id);
-            let path = Vec::new();
-            let query_result = effect_builder
-                .make_request(
-                    |responder| RpcRequest::QueryGlobalState {
-                        state_root_hash,
-                        base_key,
-                        path,
-                        responder,
-                    },
-                    QueueKind::Api,
-                )
-                .await;
-
-            let (stored_value, proof_bytes) = match common::extract_query_result(query_result) {
-                Ok(tuple) => tuple,
-                Err((error_code, error_msg)) => {
-                    info!("{}", error_msg);
-                    return Ok(response_builder
-                        .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
-                }
+            let era_summary = match get_era_summary(&block, effect_builder).await {
+                Ok(era_summary) => era_summary,
+                Err(error) => return Ok(response_builder.error(error)?),
             };
 
-            let block_hash = block.hash().to_owned();
-
             let result = Self::ResponseResult {
                 api_version,
-                era_summary: Some(EraSummary {
-                    block_hash,
-                    era_id,
-                    stored_value,
-                    state_root_hash,
-                    merkle_proof: hex::encode(proof_bytes),
-                }),
+                era_summary,
             };
 
             Ok(response_builder.success(result)?)
@@ -434,7 +488,52 @@ impl RpcWithOptionalParamsExt for GetEraInfoBySwitchBlock {
     }
 }
 
-async fn get_block<REv: ReactorEventT>(
+/// Queries the `EraInfo` recorded at the end of the era of the given block, along with its merkle
+/// proof.  Returns `None` if the block is not a switch block.
+pub(super) async fn get_era_summary<REv: ReactorEventT>(
+    block: &Block,
+    effect_builder: EffectBuilder<REv>,
+) -> Result<Option<EraSummary>, warp_json_rpc::Error> {
+    let era_id = match block.header().era_end() {
+        Some(_) => block.header().era_id().0,
+        None => return Ok(None),
+    };
+
+    let state_root_hash = block.state_root_hash().to_owned();
+    let base_key = Key::EraInfo(era_id);
+    let path = Vec::new();
+    let query_result = effect_builder
+        .make_request(
+            |responder| RpcRequest::QueryGlobalState {
+                state_root_hash,
+                base_key,
+                path,
+                responder,
+            },
+            QueueKind::Api,
+        )
+        .await;
+
+    let (stored_value, proof_bytes) = match common::extract_query_result(query_result) {
+        Ok(tuple) => tuple,
+        Err((error_code, error_msg)) => {
+            info!("{}", error_msg);
+            return Err(warp_json_rpc::Error::custom(error_code as i64, error_msg));
+        }
+    };
+
+    let block_hash = block.hash().to_owned();
+
+    Ok(Some(EraSummary {
+        block_hash,
+        era_id,
+        stored_value,
+        state_root_hash,
+        merkle_proof: hex::encode(proof_bytes),
+    }))
+}
+
+pub(super) async fn get_block<REv: ReactorEventT>(
     maybe_id: Option<BlockIdentifier>,
     effect_builder: EffectBuilder<REv>,
 ) -> Result<Option<Block>, warp_json_rpc::Error> {
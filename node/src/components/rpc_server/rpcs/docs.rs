@@ -17,10 +17,16 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use warp_json_rpc::Builder;
 
+pub use casper_rpc_client::rpcs::docs::{ListRpcs, ListRpcsResult};
+use casper_rpc_client::RpcMethod;
+
 use super::{
     account::PutDeploy,
-    chain::{GetBlock, GetBlockTransfers, GetStateRootHash},
-    info::{GetDeploy, GetPeers, GetStatus},
+    chain::{
+        GetBlock, GetBlockFinality, GetBlockTransfers, GetEraRewardHistory,
+        GetEraValidatorPerformance, GetStateRootHash,
+    },
+    info::{GetDeploy, GetDeployConfig, GetPeers, GetStatus, GetSupply},
     state::{GetAuctionInfo, GetBalance, GetItem},
     Error, ReactorEventT, RpcWithOptionalParams, RpcWithParams, RpcWithoutParams,
     RpcWithoutParamsExt,
@@ -70,10 +76,19 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
     schema.push_with_params::<GetDeploy>("returns a Deploy from the network");
     schema.push_without_params::<GetPeers>("returns a list of peers connected to the node");
     schema.push_without_params::<GetStatus>("returns the current status of the node");
+    schema.push_without_params::<GetDeployConfig>(
+        "returns the chain name and the limits which deploys must comply with",
+    );
+    schema.push_with_optional_params::<GetSupply>(
+        "returns the total supply and, at a switch Block, the supply accounting of its era",
+    );
     schema.push_with_optional_params::<GetBlock>("returns a Block from the network");
     schema.push_with_optional_params::<GetBlockTransfers>(
         "returns all transfers for a Block from the network",
     );
+    schema.push_with_optional_params::<GetBlockFinality>(
+        "returns the fault tolerance with which a Block was finalized, as observed by the node",
+    );
     schema.push_with_optional_params::<GetStateRootHash>(
         "returns a state root hash at a given Block",
     );
@@ -82,6 +97,12 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
     schema.push_with_optional_params::<GetEraInfoBySwitchBlock>(
         "returns an EraInfo from the network",
     );
+    schema.push_with_params::<GetEraValidatorPerformance>(
+        "returns the validators' participation in an era's consensus, as observed by the node",
+    );
+    schema.push_with_params::<GetEraRewardHistory>(
+        "returns a validator's or delegator's stake and rewards in a range of eras",
+    );
     schema.push_without_params::<GetAuctionInfo>(
         "returns the bids and validators as of the most recently added Block",
     );
@@ -91,7 +112,7 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
 static LIST_RPCS_RESULT: Lazy<ListRpcsResult> = Lazy::new(|| ListRpcsResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     name: "OpenRPC Schema".to_string(),
-    schema: OPEN_RPC_SCHEMA.clone(),
+    schema: serde_json::to_value(&*OPEN_RPC_SCHEMA).expect("should serialize schema"),
 });
 
 /// A trait used to generate a static hardcoded example of `Self`.
@@ -369,34 +390,14 @@ struct Components {
     schemas: Map<String, Schema>,
 }
 
-/// Result for "rpc.discover" RPC response.
-//
-// Fields named as per https://spec.open-rpc.org/#service-discovery-method.
-#[derive(Clone, Serialize, Deserialize, JsonSchema, Debug)]
-#[serde(deny_unknown_fields)]
-pub struct ListRpcsResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    api_version: Version,
-    name: String,
-    /// The list of supported RPCs.
-    #[schemars(skip)]
-    schema: OpenRpcSchema,
-}
-
 impl DocExample for ListRpcsResult {
     fn doc_example() -> &'static Self {
         &*LIST_RPCS_RESULT
     }
 }
 
-/// "rpc.discover" RPC.
-#[derive(Clone, Serialize, Deserialize, Debug)]
-pub struct ListRpcs {}
-
 impl RpcWithoutParams for ListRpcs {
-    // Named as per https://spec.open-rpc.org/#service-discovery-method.
-    const METHOD: &'static str = "rpc.discover";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type ResponseResult = ListRpcsResult;
 }
 
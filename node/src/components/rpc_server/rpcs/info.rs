@@ -1,31 +1,37 @@
 //! RPCs returning ancillary information.
 
-// TODO - remove once schemars stops causing warning.
-#![allow(clippy::field_reassign_with_default)]
-
 use std::str;
 
 use futures::{future::BoxFuture, FutureExt};
 use http::Response;
 use hyper::Body;
 use once_cell::sync::Lazy;
-use schemars::JsonSchema;
 use semver::Version;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_types::ExecutionResult;
+pub use casper_rpc_client::rpcs::info::{
+    BannedPeer, GetDeploy, GetDeployConfig, GetDeployConfigResult, GetDeployParams,
+    GetDeployResult, GetPeers, GetPeersResult, GetStatus, GetStatusResult, GetSupply,
+    GetSupplyParams, GetSupplyResult, JsonExecutionResult,
+};
+use casper_rpc_client::RpcMethod;
+use casper_types::{system::mint::TOTAL_SUPPLY_KEY, ExecutionResult, Key, ProtocolVersion, U512};
 
 use super::{
+    chain::{self, BlockIdentifier, ERA_SUMMARY},
+    common::{self, MERKLE_PROOF},
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
-    Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithParams, RpcWithParamsExt, RpcWithoutParams,
-    RpcWithoutParamsExt,
+    Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithOptionalParams, RpcWithOptionalParamsExt,
+    RpcWithParams, RpcWithParamsExt, RpcWithoutParams, RpcWithoutParamsExt,
 };
 use crate::{
     effect::EffectBuilder,
     reactor::QueueKind,
-    types::{Block, BlockHash, Deploy, DeployHash, GetStatusResult, Item, PeersMap},
+    types::{
+        chainspec::DeployConfig, json_compatibility::StoredValue, peer_entries, Block, Deploy,
+        Item, Timestamp,
+    },
 };
 
 static GET_DEPLOY_PARAMS: Lazy<GetDeployParams> = Lazy::new(|| GetDeployParams {
@@ -42,15 +48,29 @@ static GET_DEPLOY_RESULT: Lazy<GetDeployResult> = Lazy::new(|| GetDeployResult {
 static GET_PEERS_RESULT: Lazy<GetPeersResult> = Lazy::new(|| GetPeersResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
     peers: GetStatusResult::doc_example().peers.clone(),
+    banned_peers: vec![BannedPeer {
+        peer: "203.0.113.7".to_string(),
+        banned_until: *Timestamp::doc_example(),
+        ban_count: 1,
+    }],
+});
+static GET_DEPLOY_CONFIG_RESULT: Lazy<GetDeployConfigResult> =
+    Lazy::new(|| GetDeployConfigResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+        chain_name: String::from("casper-example"),
+        deploy_config: (*DeployConfig::doc_example()).into(),
+    });
+static GET_SUPPLY_PARAMS: Lazy<GetSupplyParams> = Lazy::new(|| GetSupplyParams {
+    block_identifier: BlockIdentifier::Hash(Block::doc_example().id()),
+});
+static GET_SUPPLY_RESULT: Lazy<GetSupplyResult> = Lazy::new(|| GetSupplyResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION.clone(),
+    block_hash: Block::doc_example().id(),
+    state_root_hash: *Block::doc_example().header().state_root_hash(),
+    total_supply: U512::from(10_000_000_000_000_000u64),
+    merkle_proof: MERKLE_PROOF.clone(),
+    era_summary: Some(ERA_SUMMARY.clone()),
 });
-
-/// Params for "info_get_deploy" RPC request.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetDeployParams {
-    /// The deploy hash.
-    pub deploy_hash: DeployHash,
-}
 
 impl DocExample for GetDeployParams {
     fn doc_example() -> &'static Self {
@@ -58,40 +78,14 @@ impl DocExample for GetDeployParams {
     }
 }
 
-/// The execution result of a single deploy.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct JsonExecutionResult {
-    /// The block hash.
-    pub block_hash: BlockHash,
-    /// Execution result.
-    pub result: ExecutionResult,
-}
-
-/// Result for "info_get_deploy" RPC response.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetDeployResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    pub api_version: Version,
-    /// The deploy.
-    pub deploy: Deploy,
-    /// The map of block hash to execution result.
-    pub execution_results: Vec<JsonExecutionResult>,
-}
-
 impl DocExample for GetDeployResult {
     fn doc_example() -> &'static Self {
         &*GET_DEPLOY_RESULT
     }
 }
 
-/// "info_get_deploy" RPC.
-pub struct GetDeploy {}
-
 impl RpcWithParams for GetDeploy {
-    const METHOD: &'static str = "info_get_deploy";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type RequestParams = GetDeployParams;
     type ResponseResult = GetDeployResult;
 }
@@ -147,28 +141,14 @@ impl RpcWithParamsExt for GetDeploy {
     }
 }
 
-/// Result for "info_get_peers" RPC response.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetPeersResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    pub api_version: Version,
-    /// The node ID and network address of each connected peer.
-    pub peers: PeersMap,
-}
-
 impl DocExample for GetPeersResult {
     fn doc_example() -> &'static Self {
         &*GET_PEERS_RESULT
     }
 }
 
-/// "info_get_peers" RPC.
-pub struct GetPeers {}
-
 impl RpcWithoutParams for GetPeers {
-    const METHOD: &'static str = "info_get_peers";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type ResponseResult = GetPeersResult;
 }
 
@@ -185,10 +165,17 @@ impl RpcWithoutParamsExt for GetPeers {
                     QueueKind::Api,
                 )
                 .await;
+            let banned_peers = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetBannedPeers { responder },
+                    QueueKind::Api,
+                )
+                .await;
 
             let result = Self::ResponseResult {
                 api_version,
-                peers: PeersMap::from(peers),
+                peers: peer_entries(peers),
+                banned_peers,
             };
             Ok(response_builder.success(result)?)
         }
@@ -196,11 +183,8 @@ impl RpcWithoutParamsExt for GetPeers {
     }
 }
 
-/// "info_get_status" RPC.
-pub struct GetStatus {}
-
 impl RpcWithoutParams for GetStatus {
-    const METHOD: &'static str = "info_get_status";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type ResponseResult = GetStatusResult;
 }
 
@@ -220,9 +204,176 @@ impl RpcWithoutParamsExt for GetStatus {
                 .await;
 
             // Convert to `ResponseResult` and send.
-            let body = Self::ResponseResult::new(status_feed, api_version);
+            let body = status_feed.into_status_result(api_version);
             Ok(response_builder.success(body)?)
         }
         .boxed()
     }
 }
+
+impl DocExample for GetDeployConfigResult {
+    fn doc_example() -> &'static Self {
+        &*GET_DEPLOY_CONFIG_RESULT
+    }
+}
+
+impl RpcWithoutParams for GetDeployConfig {
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
+    type ResponseResult = GetDeployConfigResult;
+}
+
+impl RpcWithoutParamsExt for GetDeployConfig {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let chainspec = effect_builder.get_chainspec().await;
+            let result = Self::ResponseResult {
+                api_version,
+                chain_name: chainspec.network_config.name.clone(),
+                deploy_config: chainspec.deploy_config.into(),
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+impl DocExample for GetSupplyParams {
+    fn doc_example() -> &'static Self {
+        &*GET_SUPPLY_PARAMS
+    }
+}
+
+impl DocExample for GetSupplyResult {
+    fn doc_example() -> &'static Self {
+        &*GET_SUPPLY_RESULT
+    }
+}
+
+impl RpcWithOptionalParams for GetSupply {
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
+    type OptionalRequestParams = GetSupplyParams;
+    type ResponseResult = GetSupplyResult;
+}
+
+impl RpcWithOptionalParamsExt for GetSupply {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        maybe_params: Option<Self::OptionalRequestParams>,
+        api_version: Version,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            // Get the block.
+            let maybe_block_id = maybe_params.map(|params| params.block_identifier);
+            let block = match chain::get_block(maybe_block_id, effect_builder).await {
+                Ok(Some(block)) => block,
+                Ok(None) => {
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::NoSuchBlock as i64,
+                        "block not known",
+                    ))?)
+                }
+                Err(error) => return Ok(response_builder.error(error)?),
+            };
+
+            // Get the mint contract hash.
+            let protocol_version = ProtocolVersion::from_parts(
+                api_version.major as u32,
+                api_version.minor as u32,
+                api_version.patch as u32,
+            );
+            let protocol_data_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::QueryProtocolData {
+                        protocol_version,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+            let mint_hash = match protocol_data_result {
+                Ok(Some(protocol_data)) => protocol_data.mint(),
+                Ok(None) => {
+                    let error_msg = format!("no protocol data for {}", protocol_version);
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::QueryFailed as i64,
+                        error_msg,
+                    ))?);
+                }
+                Err(error) => {
+                    let error_msg = format!("protocol data query failed to execute: {}", error);
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::QueryFailedToExecute as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            // Query the total supply through the mint's named keys, so that the proof covers the
+            // whole path from the state root.
+            let state_root_hash = *block.state_root_hash();
+            let base_key = Key::from(mint_hash);
+            let path = vec![TOTAL_SUPPLY_KEY.to_string()];
+            let query_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::QueryGlobalState {
+                        state_root_hash,
+                        base_key,
+                        path,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let (stored_value, proof_bytes) = match common::extract_query_result(query_result) {
+                Ok(tuple) => tuple,
+                Err((error_code, error_msg)) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder
+                        .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
+                }
+            };
+
+            let total_supply = match stored_value {
+                StoredValue::CLValue(cl_value) => cl_value.into_t::<U512>().ok(),
+                _ => None,
+            };
+            let total_supply = match total_supply {
+                Some(total_supply) => total_supply,
+                None => {
+                    let error_msg = "total supply is not a U512 value".to_string();
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::QueryFailed as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            // Get the era summary if the block is a switch block.
+            let era_summary = match chain::get_era_summary(&block, effect_builder).await {
+                Ok(era_summary) => era_summary,
+                Err(error) => return Ok(response_builder.error(error)?),
+            };
+
+            // Return the result.
+            let result = Self::ResponseResult {
+                api_version,
+                block_hash: *block.hash(),
+                state_root_hash,
+                total_supply,
+                merkle_proof: hex::encode(proof_bytes),
+                era_summary,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
@@ -1,21 +1,21 @@
 //! RPCs related to the state.
 
-// TODO - remove once schemars stops causing warning.
-#![allow(clippy::field_reassign_with_default)]
-
 use std::str;
 
 use futures::{future::BoxFuture, FutureExt};
 use http::Response;
 use hyper::Body;
 use once_cell::sync::Lazy;
-use schemars::JsonSchema;
 use semver::Version;
-use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
 use casper_execution_engine::core::engine_state::{BalanceResult, GetBidsResult};
+pub use casper_rpc_client::rpcs::state::{
+    GetAuctionInfo, GetAuctionInfoResult, GetBalance, GetBalanceParams, GetBalanceResult, GetItem,
+    GetItemParams, GetItemResult,
+};
+use casper_rpc_client::RpcMethod;
 use casper_types::{bytesrepr::ToBytes, CLValue, Key, ProtocolVersion, URef, U512};
 
 use super::{
@@ -23,7 +23,6 @@ use super::{
     Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithParams, RpcWithParamsExt,
 };
 use crate::{
-    crypto::hash::Digest,
     effect::EffectBuilder,
     reactor::QueueKind,
     rpcs::{
@@ -61,49 +60,20 @@ static GET_AUCTION_INFO_RESULT: Lazy<GetAuctionInfoResult> = Lazy::new(|| GetAuc
     auction_state: AuctionState::doc_example().clone(),
 });
 
-/// Params for "state_get_item" RPC request.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetItemParams {
-    /// Hash of the state root.
-    pub state_root_hash: Digest,
-    /// `casper_types::Key` as formatted string.
-    pub key: String,
-    /// The path components starting from the key as base.
-    #[serde(default)]
-    pub path: Vec<String>,
-}
-
 impl DocExample for GetItemParams {
     fn doc_example() -> &'static Self {
         &*GET_ITEM_PARAMS
     }
 }
 
-/// Result for "state_get_item" RPC response.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetItemResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    pub api_version: Version,
-    /// The stored value.
-    pub stored_value: StoredValue,
-    /// The merkle proof.
-    pub merkle_proof: String,
-}
-
 impl DocExample for GetItemResult {
     fn doc_example() -> &'static Self {
         &*GET_ITEM_RESULT
     }
 }
 
-/// "state_get_item" RPC.
-pub struct GetItem {}
-
 impl RpcWithParams for GetItem {
-    const METHOD: &'static str = "state_get_item";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type RequestParams = GetItemParams;
     type ResponseResult = GetItemResult;
 }
@@ -164,46 +134,20 @@ impl RpcWithParamsExt for GetItem {
     }
 }
 
-/// Params for "state_get_balance" RPC request.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetBalanceParams {
-    /// The hash of state root.
-    pub state_root_hash: Digest,
-    /// Formatted URef.
-    pub purse_uref: String,
-}
-
 impl DocExample for GetBalanceParams {
     fn doc_example() -> &'static Self {
         &*GET_BALANCE_PARAMS
     }
 }
 
-/// Result for "state_get_balance" RPC response.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetBalanceResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    pub api_version: Version,
-    /// The balance value.
-    pub balance_value: U512,
-    /// The merkle proof.
-    pub merkle_proof: String,
-}
-
 impl DocExample for GetBalanceResult {
     fn doc_example() -> &'static Self {
         &*GET_BALANCE_RESULT
     }
 }
 
-/// "state_get_balance" RPC.
-pub struct GetBalance {}
-
 impl RpcWithParams for GetBalance {
-    const METHOD: &'static str = "state_get_balance";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type RequestParams = GetBalanceParams;
     type ResponseResult = GetBalanceResult;
 }
@@ -284,28 +228,14 @@ impl RpcWithParamsExt for GetBalance {
     }
 }
 
-/// Result for "state_get_auction_info" RPC response.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetAuctionInfoResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    pub api_version: Version,
-    /// The auction state.
-    pub auction_state: AuctionState,
-}
-
 impl DocExample for GetAuctionInfoResult {
     fn doc_example() -> &'static Self {
         &*GET_AUCTION_INFO_RESULT
     }
 }
 
-/// "state_get_auction_info" RPC.
-pub struct GetAuctionInfo {}
-
 impl RpcWithoutParams for GetAuctionInfo {
-    const METHOD: &'static str = "state_get_auction_info";
+    const METHOD: &'static str = <Self as RpcMethod>::METHOD;
     type ResponseResult = GetAuctionInfoResult;
 }
 
@@ -31,12 +31,20 @@
 //! On losing an incoming or outgoing connection for a given peer, the other connection is closed.
 //! No explicit reconnect is attempted. Instead, if the peer is still online, the normal gossiping
 //! process will cause both peers to connect again.
+//!
+//! # Reputation
+//!
+//! Components report misbehaving peers through `NetworkRequest::ReportMisbehavior`. A peer whose
+//! node ID or IP address accumulates too many penalties is disconnected and temporarily banned,
+//! see the `reputation` module for details.
 
 mod config;
 mod error;
 mod event;
 mod gossiped_address;
+mod limiter;
 mod message;
+mod reputation;
 #[cfg(test)]
 mod tests;
 
@@ -49,13 +57,14 @@ use std::{
     net::{SocketAddr, TcpListener},
     result,
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicUsize, Ordering},
         Arc,
     },
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use anyhow::Context;
+use bytes::{Bytes, BytesMut};
 use datasize::DataSize;
 use futures::{
     future::{select, BoxFuture, Either},
@@ -65,7 +74,7 @@ use futures::{
 use once_cell::sync::Lazy;
 use openssl::{error::ErrorStack as OpenSslErrorStack, pkey};
 use pkey::{PKey, Private};
-use prometheus::{IntGauge, Registry};
+use prometheus::{IntCounterVec, IntGauge, Registry};
 use rand::seq::IteratorRandom;
 use serde::{de::DeserializeOwned, Serialize};
 use thiserror::Error;
@@ -78,12 +87,21 @@ use tokio::{
     task::JoinHandle,
 };
 use tokio_openssl::SslStream;
-use tokio_serde::{formats::SymmetricalMessagePack, SymmetricallyFramed};
+use tokio_serde::{formats::SymmetricalMessagePack, Deserializer, Serializer};
 use tokio_util::codec::{Framed, LengthDelimitedCodec};
 use tracing::{debug, error, info, trace, warn};
 
-use self::error::Result;
-pub(crate) use self::{event::Event, gossiped_address::GossipedAddress, message::Message};
+use self::{
+    error::Result,
+    limiter::{RateLimiter, RateLimits},
+    reputation::Reputation,
+};
+pub(crate) use self::{
+    event::Event,
+    gossiped_address::GossipedAddress,
+    limiter::{Classify, MessageClass},
+    message::{Message, ValidatorProof},
+};
 use crate::{
     components::{
         network::ENABLE_LIBP2P_NET_ENV_VAR, networking_metrics::NetworkingMetrics, Component,
@@ -99,10 +117,16 @@ use crate::{
     types::{NodeId, TimeDiff, Timestamp},
     utils, NodeRng,
 };
+use casper_types::PublicKey;
 pub use config::Config;
 pub use error::Error;
+pub use limiter::RateLimit;
+pub use reputation::{BannedPeer, Misbehavior};
 
 const MAX_ASYMMETRIC_CONNECTION_SEEN: u16 = 3;
+/// The maximum number of messages queued for a peer that is not a validator. Since messages to
+/// such peers are delayed by the rate limits, further messages are dropped.
+const MAX_THROTTLED_QUEUE_LENGTH: usize = 1000;
 static BLOCKLIST_RETAIN_DURATION: Lazy<TimeDiff> =
     Lazy::new(|| Duration::from_secs(60 * 10).into());
 
@@ -110,6 +134,9 @@ static BLOCKLIST_RETAIN_DURATION: Lazy<TimeDiff> =
 pub(crate) struct OutgoingConnection<P> {
     #[data_size(skip)] // Unfortunately, there is no way to inspect an `UnboundedSender`.
     sender: UnboundedSender<Message<P>>,
+    /// The number of messages queued for sending, shared with the sender task.
+    #[data_size(skip)]
+    queued: Arc<AtomicUsize>,
     peer_address: SocketAddr,
 
     // for keeping track of connection asymmetry, tracking the number of times we've seen this
@@ -151,6 +178,20 @@ where
 
     /// List of addresses which this node will avoid connecting to and the time they were added.
     blocklist: HashMap<SocketAddr, Timestamp>,
+    /// Reputations of misbehaving peers, used to ban them temporarily.
+    reputation: Reputation,
+    /// Per-peer rate limits applied to every connection.
+    rate_limits: RateLimits,
+    /// Proof of our validator key, sent in our handshakes if we have one.
+    validator_proof: Option<ValidatorProof>,
+    /// The current validators, whose traffic is prioritized.
+    validators: HashSet<PublicKey>,
+    /// The validator keys which connected peers proved to control in their handshakes.
+    peer_validator_keys: HashMap<NodeId, PublicKey>,
+    /// Flags indicating whether connected peers are current validators, shared with the tasks
+    /// handling their connections.
+    #[data_size(skip)]
+    validator_flags: HashMap<NodeId, Arc<AtomicBool>>,
 
     /// Pending outgoing connections: ones for which we are currently trying to make a connection.
     pending: HashSet<SocketAddr>,
@@ -183,13 +224,16 @@ where
 
 impl<REv, P> SmallNetwork<REv, P>
 where
-    P: Serialize + DeserializeOwned + Clone + Debug + Display + Send + 'static,
+    P: Serialize + DeserializeOwned + Classify + Clone + Debug + Display + Send + 'static,
     REv: Send + From<Event<P>> + From<NetworkAnnouncement<NodeId, P>>,
 {
     /// Creates a new small network component instance.
     ///
     /// If `notify` is set to `false`, no systemd notifications will be sent, regardless of
     /// configuration.
+    ///
+    /// If the node is a validator, `validator_proof` is sent to peers so that they prioritize our
+    /// traffic.
     #[allow(clippy::type_complexity)]
     pub(crate) fn new(
         event_queue: EventQueueHandle<REv>,
@@ -198,6 +242,7 @@ where
         small_network_identity: SmallNetworkIdentity,
         network_name: String,
         notify: bool,
+        validator_proof: Option<ValidatorProof>,
     ) -> Result<(SmallNetwork<REv, P>, Effects<Event<P>>)> {
         // Assert we have at least one known address in the config.
         if cfg.known_addresses.is_empty() {
@@ -227,6 +272,12 @@ where
                 outgoing: HashMap::new(),
                 pending: HashSet::new(),
                 blocklist: HashMap::new(),
+                reputation: Reputation::new(&cfg),
+                rate_limits: RateLimits::new(&cfg),
+                validator_proof,
+                validators: HashSet::new(),
+                peer_validator_keys: HashMap::new(),
+                validator_flags: HashMap::new(),
                 gossip_interval: cfg.gossip_interval,
                 network_name,
                 shutdown_sender: None,
@@ -291,6 +342,12 @@ where
             outgoing: HashMap::new(),
             pending: HashSet::new(),
             blocklist: HashMap::new(),
+            reputation: Reputation::new(&cfg),
+            rate_limits: RateLimits::new(&cfg),
+            validator_proof,
+            validators: HashSet::new(),
+            peer_validator_keys: HashMap::new(),
+            validator_flags: HashMap::new(),
             gossip_interval: cfg.gossip_interval,
             network_name,
             shutdown_sender: Some(server_shutdown_sender),
@@ -397,10 +454,23 @@ where
     fn send_message(&self, dest: NodeId, msg: Message<P>) {
         // Try to send the message.
         if let Some(connection) = self.outgoing.get(&dest) {
+            if connection.queued.load(Ordering::Relaxed) >= MAX_THROTTLED_QUEUE_LENGTH
+                && !self.is_validator(&dest)
+            {
+                debug!(our_id=%self.our_id, %dest, ?msg, "dropped outgoing message, queue full");
+                if let Message::Payload(payload) = &msg {
+                    self.net_metrics
+                        .outgoing_dropped
+                        .with_label_values(&[&dest.to_string(), payload.message_class().label()])
+                        .inc();
+                }
+                return;
+            }
             if let Err(msg) = connection.sender.send(msg) {
                 // We lost the connection, but that fact has not reached us yet.
                 warn!(our_id=%self.our_id, %dest, ?msg, "dropped outgoing message, lost connection");
             } else {
+                connection.queued.fetch_add(1, Ordering::Relaxed);
                 self.net_metrics.queued_messages.inc();
             }
         } else {
@@ -429,6 +499,12 @@ where
                     return Effects::new();
                 }
 
+                // If the peer is banned, allow the connection to drop.
+                if self.is_banned(&peer_id, &peer_address) {
+                    debug!(our_id=%self.our_id, %peer_id, %peer_address, "dropping incoming connection from banned peer");
+                    return Effects::new();
+                }
+
                 // If the peer has already disconnected, allow the connection to drop.
                 if let Err(error) = transport.get_ref().peer_addr() {
                     debug!(
@@ -443,12 +519,12 @@ where
 
                 debug!(our_id=%self.our_id, %peer_id, %peer_address, "established incoming connection");
                 // The sink is only used to send a single handshake message, then dropped.
-                let (mut sink, stream) = framed::<P>(transport).split();
-                let handshake = Message::Handshake {
-                    network_name: self.network_name.clone(),
-                };
+                let (mut sink, stream) = framed(transport).split();
+                let handshake = self.handshake();
                 let mut effects = async move {
-                    let _ = sink.send(handshake).await;
+                    if let Ok(frame) = encode_message(&handshake) {
+                        let _ = sink.send(frame).await;
+                    }
                 }
                 .ignore::<Event<P>>();
 
@@ -473,6 +549,10 @@ where
                         self.shutdown_receiver.clone(),
                         self.our_id.clone(),
                         peer_id.clone(),
+                        RateLimiter::new(self.rate_limits),
+                        self.validator_flag(&peer_id),
+                        self.net_metrics.incoming_throttled.clone(),
+                        self.net_metrics.incoming_dropped.clone(),
                     )
                     .event(move |result| Event::IncomingClosed {
                         result,
@@ -524,14 +604,22 @@ where
             return Effects::new();
         }
 
+        // If the peer is banned, allow the connection to drop.
+        if self.is_banned(&peer_id, &peer_address) {
+            debug!(our_id=%self.our_id, %peer_id, %peer_address, "dropping outgoing connection to banned peer");
+            return Effects::new();
+        }
+
         // The stream is only used to receive a single handshake message and then dropped.
-        let (sink, stream) = framed::<P>(transport).split();
+        let (sink, stream) = framed(transport).split();
         debug!(our_id=%self.our_id, %peer_id, %peer_address, "established outgoing connection");
 
         let (sender, receiver) = mpsc::unbounded_channel();
+        let queued = Arc::new(AtomicUsize::new(0));
         let connection = OutgoingConnection {
             peer_address,
             sender,
+            queued: Arc::clone(&queued),
             times_seen_asymmetric: 0,
         };
         if self.outgoing.insert(peer_id.clone(), connection).is_some() {
@@ -543,16 +631,19 @@ where
 
         let mut effects = self.check_connection_complete(effect_builder, peer_id.clone());
 
-        let handshake = Message::Handshake {
-            network_name: self.network_name.clone(),
-        };
+        let handshake = self.handshake();
         let peer_id_cloned = peer_id.clone();
         effects.extend(
             message_sender(
                 receiver,
                 sink,
                 self.net_metrics.queued_messages.clone(),
+                queued,
                 handshake,
+                peer_id.clone(),
+                RateLimiter::new(self.rate_limits),
+                self.validator_flag(&peer_id),
+                self.net_metrics.outgoing_throttled.clone(),
             )
             .event(move |result| Event::OutgoingFailed {
                 peer_id: Box::new(Some(peer_id)),
@@ -617,6 +708,11 @@ where
                 .open_connections
                 .set(self.incoming.len() as i64);
         }
+        self.net_metrics.remove_peer(peer_id);
+        let _ = self.peer_validator_keys.remove(peer_id);
+        if let Some(flag) = self.validator_flags.remove(peer_id) {
+            flag.store(false, Ordering::Relaxed);
+        }
         if let Some(outgoing) = self.outgoing.remove(&peer_id) {
             trace!(our_id=%self.our_id, %peer_id, "removing peer from the outgoing connections");
             let peer_ip = format!("{}", outgoing.peer_address.ip());
@@ -626,9 +722,81 @@ where
                     .insert(outgoing.peer_address, Timestamp::now());
             }
         }
+        self.reputation.prune(Timestamp::now());
         self.terminate_if_isolated(effect_builder)
     }
 
+    /// Returns the handshake message we send to peers.
+    fn handshake(&self) -> Message<P> {
+        Message::Handshake {
+            network_name: self.network_name.clone(),
+            validator: self.validator_proof.clone(),
+        }
+    }
+
+    /// Returns whether the peer has proven control of a current validator's key.
+    fn is_validator(&self, peer_id: &NodeId) -> bool {
+        self.peer_validator_keys
+            .get(peer_id)
+            .map_or(false, |public_key| self.validators.contains(public_key))
+    }
+
+    /// Returns the flag indicating whether the peer is a current validator, shared with the tasks
+    /// handling the peer's connections.
+    fn validator_flag(&mut self, peer_id: &NodeId) -> Arc<AtomicBool> {
+        let is_validator = self.is_validator(peer_id);
+        let flag = self
+            .validator_flags
+            .entry(peer_id.clone())
+            .or_insert_with(|| Arc::new(AtomicBool::new(false)));
+        flag.store(is_validator, Ordering::Relaxed);
+        Arc::clone(flag)
+    }
+
+    /// Updates the flags of all connected peers indicating whether they are current validators.
+    fn update_validator_flags(&self) {
+        for (peer_id, flag) in &self.validator_flags {
+            flag.store(self.is_validator(peer_id), Ordering::Relaxed);
+        }
+    }
+
+    /// Returns whether the peer's node ID or IP address is currently banned.
+    fn is_banned(&self, peer_id: &NodeId, peer_address: &SocketAddr) -> bool {
+        let now = Timestamp::now();
+        self.reputation.is_node_banned(peer_id, now)
+            || self.reputation.is_ip_banned(&peer_address.ip(), now)
+    }
+
+    /// Lowers the reputation of a misbehaving peer, and disconnects from it if it got banned.
+    fn report_misbehavior(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        peer_id: NodeId,
+        misbehavior: Misbehavior,
+    ) -> Effects<Event<P>> {
+        let peer_ip = self
+            .incoming
+            .get(&peer_id)
+            .map(|connection| connection.peer_address.ip())
+            .or_else(|| {
+                self.outgoing
+                    .get(&peer_id)
+                    .map(|connection| connection.peer_address.ip())
+            });
+        debug!(our_id=%self.our_id, %peer_id, %misbehavior, "peer misbehaved");
+        if !self
+            .reputation
+            .report(&peer_id, peer_ip, misbehavior, Timestamp::now())
+        {
+            return Effects::new();
+        }
+        warn!(our_id=%self.our_id, %peer_id, ?peer_ip, %misbehavior, "banning peer");
+        self.net_metrics.peer_bans.inc();
+        let effects = self.remove(effect_builder, &peer_id, false);
+        self.update_peers_metric();
+        effects
+    }
+
     /// Gossips our public listening address, and schedules the next such gossip round.
     fn gossip_our_address(&mut self, effect_builder: EffectBuilder<REv>) -> Effects<Event<P>> {
         let our_address = GossipedAddress::new(self.public_address);
@@ -690,7 +858,10 @@ where
         REv: From<NetworkAnnouncement<NodeId, P>>,
     {
         match msg {
-            Message::Handshake { network_name } => {
+            Message::Handshake {
+                network_name,
+                validator,
+            } => {
                 if network_name != self.network_name {
                     info!(
                         our_id=%self.our_id,
@@ -703,12 +874,31 @@ where
                     self.update_peers_metric();
                     return remove;
                 }
+                if let Some(proof) = validator {
+                    match proof.verify(&peer_id) {
+                        Some(public_key) => {
+                            let _ = self.peer_validator_keys.insert(peer_id, public_key);
+                            self.update_validator_flags();
+                        }
+                        None => {
+                            warn!(our_id=%self.our_id, %peer_id, "invalid validator proof in handshake");
+                        }
+                    }
+                }
                 self.update_peers_metric();
                 Effects::new()
             }
-            Message::Payload(payload) => effect_builder
-                .announce_message_received(peer_id, payload)
-                .ignore(),
+            Message::Payload(payload) => {
+                // The reader of an incoming connection keeps running until the peer closes it, so
+                // we have to drop messages from peers that were banned in the meantime.
+                if self.reputation.is_node_banned(&peer_id, Timestamp::now()) {
+                    trace!(our_id=%self.our_id, %peer_id, "dropping message from banned peer");
+                    return Effects::new();
+                }
+                effect_builder
+                    .announce_message_received(peer_id, payload)
+                    .ignore()
+            }
         }
     }
 
@@ -721,13 +911,16 @@ where
             .retain(|_, ts| *ts > Timestamp::now() - *BLOCKLIST_RETAIN_DURATION);
         if self.pending.contains(&peer_address)
             || self.blocklist.contains_key(&peer_address)
+            || self
+                .reputation
+                .is_ip_banned(&peer_address.ip(), Timestamp::now())
             || self
                 .outgoing
                 .iter()
                 .any(|(_peer_id, connection)| connection.peer_address == peer_address)
         {
             // We're already trying to connect, are connected, or the connection is on the blocklist
-            // - do nothing.
+            // or banned - do nothing.
             Effects::new()
         } else {
             // We need to connect.
@@ -851,7 +1044,7 @@ where
 impl<REv, P> Component<REv> for SmallNetwork<REv, P>
 where
     REv: Send + From<Event<P>> + From<NetworkAnnouncement<NodeId, P>>,
-    P: Serialize + DeserializeOwned + Clone + Debug + Display + Send + 'static,
+    P: Serialize + DeserializeOwned + Classify + Clone + Debug + Display + Send + 'static,
 {
     type Event = Event<P>;
     type ConstructionError = Infallible;
@@ -950,12 +1143,33 @@ where
                             self.gossip_message(rng, Message::Payload(*payload), count, exclude);
                         responder.respond(sent_to).ignore()
                     }
+                    NetworkRequest::ReportMisbehavior {
+                        peer,
+                        misbehavior,
+                        responder,
+                    } => {
+                        let mut effects =
+                            self.report_misbehavior(effect_builder, *peer, misbehavior);
+                        effects.extend(responder.respond(()).ignore());
+                        effects
+                    }
+                    NetworkRequest::SetValidators {
+                        validators,
+                        responder,
+                    } => {
+                        self.validators = *validators;
+                        self.update_validator_flags();
+                        responder.respond(()).ignore()
+                    }
                 }
             }
             Event::NetworkInfoRequest { req } => match *req {
                 NetworkInfoRequest::GetPeers { responder } => {
                     responder.respond(self.peers()).ignore()
                 }
+                NetworkInfoRequest::GetBannedPeers { responder } => responder
+                    .respond(self.reputation.banned_peers(Timestamp::now()))
+                    .ignore(),
             },
             Event::GossipOurAddress => {
                 let mut effects = self.gossip_our_address(effect_builder);
@@ -1105,7 +1319,7 @@ async fn setup_tls(
 /// Network handshake reader for single handshake message received by outgoing connection.
 async fn handshake_reader<REv, P>(
     event_queue: EventQueueHandle<REv>,
-    mut stream: SplitStream<FramedTransport<P>>,
+    mut stream: SplitStream<FramedTransport>,
     our_id: NodeId,
     peer_id: NodeId,
     peer_address: SocketAddr,
@@ -1113,7 +1327,11 @@ async fn handshake_reader<REv, P>(
     P: DeserializeOwned + Send + Display,
     REv: From<Event<P>>,
 {
-    if let Some(Ok(msg @ Message::Handshake { .. })) = stream.next().await {
+    let handshake = match stream.next().await {
+        Some(Ok(frame)) => decode_message::<P>(&frame).ok(),
+        _ => None,
+    };
+    if let Some(msg @ Message::Handshake { .. }) = handshake {
         debug!(%our_id, %msg, %peer_id, "handshake received");
         return event_queue
             .schedule(
@@ -1141,24 +1359,51 @@ async fn handshake_reader<REv, P>(
 /// Network message reader.
 ///
 /// Schedules all received messages until the stream is closed or an error occurs.
+///
+/// Payloads exceeding the peer's rate limits are dropped, unless the peer is a validator, in which
+/// case reading is paused until the limits allow them.
+#[allow(clippy::too_many_arguments)]
 async fn message_reader<REv, P>(
     event_queue: EventQueueHandle<REv>,
-    mut stream: SplitStream<FramedTransport<P>>,
+    mut stream: SplitStream<FramedTransport>,
     mut shutdown_receiver: watch::Receiver<()>,
     our_id: NodeId,
     peer_id: NodeId,
+    mut limiter: RateLimiter,
+    is_validator: Arc<AtomicBool>,
+    throttled: IntCounterVec,
+    dropped: IntCounterVec,
 ) -> io::Result<()>
 where
-    P: DeserializeOwned + Send + Display,
+    P: DeserializeOwned + Classify + Send + Display,
     REv: From<Event<P>>,
 {
     let our_id_ref = &our_id;
     let peer_id_cloned = peer_id.clone();
+    let peer_label = peer_id.to_string();
     let read_messages = async move {
-        while let Some(msg_result) = stream.next().await {
+        while let Some(frame_result) = stream.next().await {
+            let msg_result = frame_result
+                .and_then(|frame| decode_message::<P>(&frame).map(|msg| (msg, frame.len() as u64)));
             match msg_result {
-                Ok(msg) => {
+                Ok((msg, size)) => {
                     debug!(our_id=%our_id_ref, %msg, peer_id=%peer_id_cloned, "message received");
+                    if let Message::Payload(payload) = &msg {
+                        let class = payload.message_class();
+                        let delay = limiter.throttle(class, size, Instant::now());
+                        if delay > Duration::default() {
+                            let labels = [peer_label.as_str(), class.label()];
+                            if !is_validator.load(Ordering::Relaxed) {
+                                debug!(our_id=%our_id_ref, %class, peer_id=%peer_id_cloned, "rate limit exceeded, dropping message");
+                                dropped.with_label_values(&labels).inc();
+                                continue;
+                            }
+                            debug!(our_id=%our_id_ref, %class, peer_id=%peer_id_cloned, ?delay, "rate limit exceeded, delaying message");
+                            throttled.with_label_values(&labels).inc();
+                            tokio::time::delay_for(delay).await;
+                            limiter.consume(class, size, Instant::now());
+                        }
+                    }
                     // We've received a message, push it to the reactor.
                     event_queue
                         .schedule(
@@ -1201,20 +1446,49 @@ where
 ///
 /// Initially sends a handshake including the `chainspec_hash` as a final handshake step.  If the
 /// recipient's `chainspec_hash` doesn't match, the connection will be closed.
+///
+/// Payloads exceeding the peer's rate limits are delayed, unless the peer is a validator.
+#[allow(clippy::too_many_arguments)]
 async fn message_sender<P>(
     mut queue: UnboundedReceiver<Message<P>>,
-    mut sink: SplitSink<FramedTransport<P>, Message<P>>,
+    mut sink: SplitSink<FramedTransport, Bytes>,
     counter: IntGauge,
+    queued: Arc<AtomicUsize>,
     handshake: Message<P>,
+    peer_id: NodeId,
+    mut limiter: RateLimiter,
+    is_validator: Arc<AtomicBool>,
+    throttled: IntCounterVec,
 ) -> Result<()>
 where
-    P: Serialize + Send,
+    P: Serialize + Classify + Send,
 {
+    let peer_label = peer_id.to_string();
+    let handshake = encode_message(&handshake).map_err(Error::MessageNotSent)?;
     sink.send(handshake).await.map_err(Error::MessageNotSent)?;
-    while let Some(payload) = queue.recv().await {
+    while let Some(msg) = queue.recv().await {
         counter.dec();
+        queued.fetch_sub(1, Ordering::Relaxed);
+        let frame = encode_message(&msg).map_err(Error::MessageNotSent)?;
+        if let Message::Payload(payload) = &msg {
+            let class = payload.message_class();
+            let size = frame.len() as u64;
+            if is_validator.load(Ordering::Relaxed) {
+                limiter.consume(class, size, Instant::now());
+            } else {
+                let delay = limiter.throttle(class, size, Instant::now());
+                if delay > Duration::default() {
+                    trace!(%peer_id, %class, ?delay, "rate limit exceeded, delaying message");
+                    throttled
+                        .with_label_values(&[peer_label.as_str(), class.label()])
+                        .inc();
+                    tokio::time::delay_for(delay).await;
+                    limiter.consume(class, size, Instant::now());
+                }
+            }
+        }
         // We simply error-out if the sink fails, it means that our connection broke.
-        sink.send(payload).await.map_err(Error::MessageNotSent)?;
+        sink.send(frame).await.map_err(Error::MessageNotSent)?;
     }
 
     Ok(())
@@ -1223,20 +1497,29 @@ where
 /// Transport type alias for base encrypted connections.
 type Transport = SslStream<TcpStream>;
 
-/// A framed transport for `Message`s.
-type FramedTransport<P> = SymmetricallyFramed<
-    Framed<Transport, LengthDelimitedCodec>,
-    Message<P>,
-    SymmetricalMessagePack<Message<P>>,
->;
+/// A framed transport, each frame containing a MessagePack-encoded `Message`.
+///
+/// Messages are encoded and decoded separately from the framing, so that the rate limits can
+/// account for the actual size of each frame.
+type FramedTransport = Framed<Transport, LengthDelimitedCodec>;
 
 /// Constructs a new framed transport on a stream.
-fn framed<P>(stream: Transport) -> FramedTransport<P> {
-    let length_delimited = Framed::new(stream, LengthDelimitedCodec::new());
-    SymmetricallyFramed::new(
-        length_delimited,
-        SymmetricalMessagePack::<Message<P>>::default(),
-    )
+fn framed(stream: Transport) -> FramedTransport {
+    Framed::new(stream, LengthDelimitedCodec::new())
+}
+
+/// Encodes a message into a frame.
+fn encode_message<P: Serialize>(msg: &Message<P>) -> io::Result<Bytes> {
+    Box::pin(SymmetricalMessagePack::<Message<P>>::default())
+        .as_mut()
+        .serialize(msg)
+}
+
+/// Decodes a message from a frame.
+fn decode_message<P: DeserializeOwned>(frame: &BytesMut) -> io::Result<Message<P>> {
+    Box::pin(SymmetricalMessagePack::<Message<P>>::default())
+        .as_mut()
+        .deserialize(frame)
 }
 
 /// Initiates a TLS connection to a remote address.
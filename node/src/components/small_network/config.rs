@@ -5,6 +5,8 @@ use std::time::Duration;
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
+use super::RateLimit;
+
 /// Default binding address.
 ///
 /// Uses a fixed port per node, but binds on any interface.
@@ -18,6 +20,27 @@ const DEFAULT_PUBLIC_ADDRESS: &str = "127.0.0.1:0";
 /// Default interval for gossiping network addresses.
 const DEFAULT_GOSSIP_INTERVAL: Duration = Duration::from_secs(30);
 
+/// Default accumulated misbehavior penalty at which a node ID gets banned.
+const DEFAULT_BAN_THRESHOLD: u32 = 100;
+
+/// Default accumulated misbehavior penalty at which an IP address gets banned.
+const DEFAULT_IP_BAN_THRESHOLD: u32 = 300;
+
+/// Default duration of a peer's first ban.
+const DEFAULT_BAN_DURATION: Duration = Duration::from_secs(60 * 10);
+
+/// Default maximum duration of a ban.
+const DEFAULT_MAX_BAN_DURATION: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// Default per-peer rate limit for consensus messages.
+const DEFAULT_CONSENSUS_RATE_LIMIT: RateLimit = RateLimit::new(1000, 10 * 1024 * 1024);
+
+/// Default per-peer rate limit for gossip messages.
+const DEFAULT_GOSSIP_RATE_LIMIT: RateLimit = RateLimit::new(500, 5 * 1024 * 1024);
+
+/// Default per-peer rate limit for get requests and responses.
+const DEFAULT_GET_RATE_LIMIT: RateLimit = RateLimit::new(200, 10 * 1024 * 1024);
+
 // Default values for networking configuration:
 impl Default for Config {
     fn default() -> Self {
@@ -27,6 +50,13 @@ impl Default for Config {
             known_addresses: Vec::new(),
             gossip_interval: DEFAULT_GOSSIP_INTERVAL,
             systemd_support: false,
+            ban_threshold: DEFAULT_BAN_THRESHOLD,
+            ip_ban_threshold: DEFAULT_IP_BAN_THRESHOLD,
+            ban_duration: DEFAULT_BAN_DURATION,
+            max_ban_duration: DEFAULT_MAX_BAN_DURATION,
+            consensus_rate_limit: DEFAULT_CONSENSUS_RATE_LIMIT,
+            gossip_rate_limit: DEFAULT_GOSSIP_RATE_LIMIT,
+            get_rate_limit: DEFAULT_GET_RATE_LIMIT,
         }
     }
 }
@@ -49,6 +79,22 @@ pub struct Config {
     pub gossip_interval: Duration,
     /// Enable systemd startup notification.
     pub systemd_support: bool,
+    /// Accumulated misbehavior penalty at which a peer's node ID gets banned.
+    pub ban_threshold: u32,
+    /// Accumulated misbehavior penalty at which a peer's IP address gets banned.
+    pub ip_ban_threshold: u32,
+    /// Duration in milliseconds of a peer's first ban. Each further ban lasts twice as long.
+    #[serde(with = "crate::utils::milliseconds")]
+    pub ban_duration: Duration,
+    /// Maximum duration in milliseconds of a ban.
+    #[serde(with = "crate::utils::milliseconds")]
+    pub max_ban_duration: Duration,
+    /// Per-peer rate limit for consensus messages and finality signatures.
+    pub consensus_rate_limit: RateLimit,
+    /// Per-peer rate limit for gossiped deploys and addresses.
+    pub gossip_rate_limit: RateLimit,
+    /// Per-peer rate limit for get requests and responses.
+    pub get_rate_limit: RateLimit,
 }
 
 #[cfg(test)]
@@ -70,6 +116,7 @@ impl Config {
             known_addresses: vec![bind_address.to_string()],
             gossip_interval: DEFAULT_TEST_GOSSIP_INTERVAL,
             systemd_support: false,
+            ..Default::default()
         }
     }
 
@@ -88,6 +135,7 @@ impl Config {
             ],
             gossip_interval: DEFAULT_TEST_GOSSIP_INTERVAL,
             systemd_support: false,
+            ..Default::default()
         }
     }
 }
@@ -0,0 +1,302 @@
+//! Per-peer rate limiting.
+//!
+//! Every connection owns a `RateLimiter` which tracks, for each class of messages, how many
+//! messages and bytes the peer may still send (or be sent) using token buckets. The buckets hold
+//! at most one second worth of traffic and are refilled continuously.
+//!
+//! Traffic of current validators takes priority: incoming messages from validators exceeding the
+//! limit are delayed rather than dropped, and outgoing ones to validators are never delayed. A peer
+//! counts as a validator once it has proven control of a validator key in its handshake.
+
+use std::{
+    fmt::{self, Display, Formatter},
+    time::{Duration, Instant},
+};
+
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+use super::Config;
+
+/// The class of a message, each of which has its own rate limits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum MessageClass {
+    /// Consensus messages and finality signatures.
+    Consensus,
+    /// Gossiped deploys and addresses.
+    Gossip,
+    /// Requests for items and the corresponding responses.
+    Get,
+}
+
+impl MessageClass {
+    /// All message classes.
+    pub(crate) const ALL: [MessageClass; 3] = [
+        MessageClass::Consensus,
+        MessageClass::Gossip,
+        MessageClass::Get,
+    ];
+
+    /// Returns the label used for this class in metrics.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            MessageClass::Consensus => "consensus",
+            MessageClass::Gossip => "gossip",
+            MessageClass::Get => "get",
+        }
+    }
+}
+
+impl Display for MessageClass {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.label())
+    }
+}
+
+/// A network payload which can be classified for rate limiting.
+pub(crate) trait Classify {
+    /// Returns the class of the message.
+    fn message_class(&self) -> MessageClass;
+}
+
+/// Rate limits for a single class of messages, per peer. Zero means unlimited.
+#[derive(DataSize, Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct RateLimit {
+    /// Maximum number of messages per second.
+    pub messages_per_second: u32,
+    /// Maximum number of bytes per second.
+    pub bytes_per_second: u32,
+}
+
+impl RateLimit {
+    /// Creates a new rate limit.
+    pub const fn new(messages_per_second: u32, bytes_per_second: u32) -> Self {
+        RateLimit {
+            messages_per_second,
+            bytes_per_second,
+        }
+    }
+}
+
+/// The per-peer rate limits of all message classes.
+#[derive(DataSize, Debug, Clone, Copy)]
+pub(super) struct RateLimits {
+    consensus: RateLimit,
+    gossip: RateLimit,
+    get: RateLimit,
+}
+
+impl RateLimits {
+    pub(super) fn new(cfg: &Config) -> Self {
+        RateLimits {
+            consensus: cfg.consensus_rate_limit,
+            gossip: cfg.gossip_rate_limit,
+            get: cfg.get_rate_limit,
+        }
+    }
+}
+
+/// A token bucket, refilled continuously at a fixed rate and holding at most one second's worth.
+#[derive(Debug)]
+struct TokenBucket {
+    /// Tokens added per second, and the capacity of the bucket.
+    rate: u64,
+    /// The number of tokens currently in the bucket.
+    tokens: f64,
+    /// The time of the latest refill.
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket, or `None` if the rate is zero, i.e. unlimited.
+    fn new(rate: u32, now: Instant) -> Option<Self> {
+        if rate == 0 {
+            return None;
+        }
+        Some(TokenBucket {
+            rate: u64::from(rate),
+            tokens: f64::from(rate),
+            last_refill: now,
+        })
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill);
+        self.tokens =
+            (self.tokens + elapsed.as_secs_f64() * self.rate as f64).min(self.rate as f64);
+        self.last_refill = now;
+    }
+
+    /// Returns how long it takes until `cost` tokens are available.
+    ///
+    /// Costs exceeding the capacity are capped, so that large messages pass once the bucket is
+    /// full.
+    fn time_until_available(&mut self, cost: u64, now: Instant) -> Duration {
+        self.refill(now);
+        let missing = cost.min(self.rate) as f64 - self.tokens;
+        if missing <= 0.0 {
+            Duration::from_secs(0)
+        } else {
+            Duration::from_secs_f64(missing / self.rate as f64)
+        }
+    }
+
+    fn consume(&mut self, cost: u64) {
+        self.tokens = (self.tokens - cost.min(self.rate) as f64).max(0.0);
+    }
+}
+
+/// The message and byte buckets of a single message class.
+#[derive(Debug)]
+struct ClassLimiter {
+    messages: Option<TokenBucket>,
+    bytes: Option<TokenBucket>,
+}
+
+impl ClassLimiter {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        ClassLimiter {
+            messages: TokenBucket::new(limit.messages_per_second, now),
+            bytes: TokenBucket::new(limit.bytes_per_second, now),
+        }
+    }
+
+    fn time_until_available(&mut self, size: u64, now: Instant) -> Duration {
+        let messages = self
+            .messages
+            .as_mut()
+            .map_or_else(Duration::default, |bucket| {
+                bucket.time_until_available(1, now)
+            });
+        let bytes = self
+            .bytes
+            .as_mut()
+            .map_or_else(Duration::default, |bucket| {
+                bucket.time_until_available(size, now)
+            });
+        messages.max(bytes)
+    }
+
+    fn consume(&mut self, size: u64) {
+        if let Some(bucket) = self.messages.as_mut() {
+            bucket.consume(1);
+        }
+        if let Some(bucket) = self.bytes.as_mut() {
+            bucket.consume(size);
+        }
+    }
+}
+
+/// The rate limits of a single connection.
+#[derive(Debug)]
+pub(super) struct RateLimiter {
+    consensus: ClassLimiter,
+    gossip: ClassLimiter,
+    get: ClassLimiter,
+}
+
+impl RateLimiter {
+    pub(super) fn new(limits: RateLimits) -> Self {
+        let now = Instant::now();
+        RateLimiter {
+            consensus: ClassLimiter::new(limits.consensus, now),
+            gossip: ClassLimiter::new(limits.gossip, now),
+            get: ClassLimiter::new(limits.get, now),
+        }
+    }
+
+    fn class_limiter(&mut self, class: MessageClass) -> &mut ClassLimiter {
+        match class {
+            MessageClass::Consensus => &mut self.consensus,
+            MessageClass::Gossip => &mut self.gossip,
+            MessageClass::Get => &mut self.get,
+        }
+    }
+
+    /// Tries to account for a message of the given class and serialized size.
+    ///
+    /// If the limits allow it, the message is accounted for and a zero duration is returned.
+    /// Otherwise nothing is accounted for, and the time until the message would be allowed is
+    /// returned.
+    pub(super) fn throttle(&mut self, class: MessageClass, size: u64, now: Instant) -> Duration {
+        let limiter = self.class_limiter(class);
+        let delay = limiter.time_until_available(size, now);
+        if delay == Duration::default() {
+            limiter.consume(size);
+        }
+        delay
+    }
+
+    /// Accounts for a message regardless of the limits.
+    pub(super) fn consume(&mut self, class: MessageClass, size: u64, now: Instant) {
+        let limiter = self.class_limiter(class);
+        // Refill first, so that the time elapsed before this message isn't lost.
+        let _ = limiter.time_until_available(size, now);
+        limiter.consume(size);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNLIMITED: RateLimit = RateLimit::new(0, 0);
+
+    fn limiter(consensus: RateLimit, gossip: RateLimit, get: RateLimit) -> RateLimiter {
+        RateLimiter::new(RateLimits {
+            consensus,
+            gossip,
+            get,
+        })
+    }
+
+    #[test]
+    fn should_limit_message_rate() {
+        let mut limiter = limiter(RateLimit::new(2, 0), UNLIMITED, UNLIMITED);
+        let start = Instant::now();
+        let zero = Duration::default();
+
+        assert_eq!(zero, limiter.throttle(MessageClass::Consensus, 100, start));
+        assert_eq!(zero, limiter.throttle(MessageClass::Consensus, 100, start));
+        let delay = limiter.throttle(MessageClass::Consensus, 100, start);
+        assert_eq!(Duration::from_millis(500), delay);
+        assert_eq!(
+            zero,
+            limiter.throttle(MessageClass::Consensus, 100, start + delay)
+        );
+
+        // Other classes are unaffected.
+        for _ in 0..100 {
+            assert_eq!(zero, limiter.throttle(MessageClass::Gossip, 1000, start));
+        }
+    }
+
+    #[test]
+    fn should_limit_byte_rate() {
+        let mut limiter = limiter(UNLIMITED, UNLIMITED, RateLimit::new(0, 1000));
+        let start = Instant::now();
+        let zero = Duration::default();
+
+        assert_eq!(zero, limiter.throttle(MessageClass::Get, 800, start));
+        let delay = limiter.throttle(MessageClass::Get, 400, start);
+        assert_eq!(Duration::from_millis(200), delay);
+
+        // Messages larger than the capacity pass once the bucket is full.
+        let later = start + Duration::from_secs(1);
+        assert_eq!(zero, limiter.throttle(MessageClass::Get, 5000, later));
+        assert_eq!(
+            Duration::from_secs(1),
+            limiter.throttle(MessageClass::Get, 5000, later)
+        );
+
+        // Forced consumption drains the bucket without failing.
+        let much_later = later + Duration::from_secs(2);
+        limiter.consume(MessageClass::Get, 1000, much_later);
+        assert_eq!(
+            Duration::from_millis(100),
+            limiter.throttle(MessageClass::Get, 100, much_later)
+        );
+    }
+}
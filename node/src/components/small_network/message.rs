@@ -1,18 +1,96 @@
 use std::fmt::{self, Debug, Display, Formatter};
 
+use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
+use casper_types::{PublicKey, SecretKey, Signature};
+
+use crate::{crypto, types::NodeId, NodeRng};
+
+/// Prefix of the signed data in a `ValidatorProof`, so the signature can't be used elsewhere.
+const VALIDATOR_PROOF_PREFIX: &[u8] = b"casper-small-network-validator";
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Message<P> {
-    Handshake { network_name: String },
+    Handshake {
+        network_name: String,
+        /// Proof that the sender controls a validator key, if it has one.
+        #[serde(default)]
+        validator: Option<ValidatorProof>,
+    },
     Payload(P),
 }
 
 impl<P: Display> Display for Message<P> {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            Message::Handshake { network_name } => write!(f, "handshake: {}", network_name),
+            Message::Handshake {
+                network_name,
+                validator: None,
+            } => write!(f, "handshake: {}", network_name),
+            Message::Handshake {
+                network_name,
+                validator: Some(proof),
+            } => write!(
+                f,
+                "handshake: {}, validator {}",
+                network_name, proof.public_key
+            ),
             Message::Payload(payload) => write!(f, "payload: {}", payload),
         }
     }
 }
+
+/// A validator's signature of its node's ID, proving that the node controls the validator key.
+///
+/// The node ID is the fingerprint of the TLS certificate the connection is authenticated with, so
+/// the proof can't be replayed by another node.
+#[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
+pub struct ValidatorProof {
+    public_key: PublicKey,
+    signature: Signature,
+}
+
+impl ValidatorProof {
+    /// Signs the given node ID with the validator's secret key.
+    pub(crate) fn new(node_id: &NodeId, secret_key: &SecretKey, rng: &mut NodeRng) -> Self {
+        let public_key = PublicKey::from(secret_key);
+        let signature = crypto::sign(signed_data(node_id), secret_key, &public_key, rng);
+        ValidatorProof {
+            public_key,
+            signature,
+        }
+    }
+
+    /// Returns the validator's public key if the proof is valid for the given node ID.
+    pub(super) fn verify(&self, node_id: &NodeId) -> Option<PublicKey> {
+        crypto::verify(signed_data(node_id), &self.signature, &self.public_key).ok()?;
+        Some(self.public_key)
+    }
+}
+
+/// Returns the data signed in a `ValidatorProof` for the given node ID.
+fn signed_data(node_id: &NodeId) -> Vec<u8> {
+    let mut data = VALIDATOR_PROOF_PREFIX.to_vec();
+    // Node IDs always serialize successfully.
+    data.extend(bincode::serialize(node_id).unwrap_or_default());
+    data
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{crypto::AsymmetricKeyExt, testing::TestRng};
+
+    #[test]
+    fn should_verify_validator_proof_only_for_signed_node_id() {
+        let mut rng = TestRng::new();
+        let secret_key = SecretKey::random(&mut rng);
+        let node_id = NodeId::random_tls(&mut rng);
+        let other_node_id = NodeId::random_tls(&mut rng);
+
+        let proof = ValidatorProof::new(&node_id, &secret_key, &mut rng);
+        assert_eq!(Some(PublicKey::from(&secret_key)), proof.verify(&node_id));
+        assert_eq!(None, proof.verify(&other_node_id));
+    }
+}
@@ -0,0 +1,364 @@
+//! Peer reputation tracking.
+//!
+//! Components that detect a peer misbehaving report it to the network, which lowers the
+//! reputation of both the peer's node ID and its IP address. Once the accumulated penalty of
+//! either reaches the configured threshold, it is banned for a while: the ban duration doubles
+//! with every further ban, up to a configured maximum.
+//!
+//! Entries are pruned once they are no longer relevant: when the score would have been forgiven
+//! and the latest ban expired more than the maximum ban duration ago.
+
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    net::IpAddr,
+};
+
+use datasize::DataSize;
+use serde::Serialize;
+
+pub use casper_rpc_client::rpcs::info::BannedPeer;
+
+use super::Config;
+use crate::types::{NodeId, TimeDiff, Timestamp};
+
+/// The maximum exponent used when doubling ban durations, to avoid overflows.
+const MAX_BAN_EXPONENT: u32 = 32;
+
+/// A kind of misbehavior of a peer, detected by a component.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum Misbehavior {
+    /// The peer sent a deploy that failed validation.
+    InvalidDeploy,
+    /// The peer sent a response to a `Get` request that could not be parsed.
+    InvalidGetResponse,
+    /// The peer sent a consensus message that was invalid or could not be parsed.
+    InvalidConsensusMessage,
+}
+
+impl Misbehavior {
+    /// Returns the penalty to apply to the peer's reputation.
+    fn penalty(self) -> u32 {
+        match self {
+            Misbehavior::InvalidDeploy => 20,
+            Misbehavior::InvalidGetResponse => 50,
+            Misbehavior::InvalidConsensusMessage => 50,
+        }
+    }
+}
+
+impl Display for Misbehavior {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Misbehavior::InvalidDeploy => write!(formatter, "invalid deploy"),
+            Misbehavior::InvalidGetResponse => write!(formatter, "invalid get response"),
+            Misbehavior::InvalidConsensusMessage => write!(formatter, "invalid consensus message"),
+        }
+    }
+}
+
+/// The reputation of a single node ID or IP address.
+#[derive(DataSize, Debug, Default)]
+struct Standing {
+    /// The accumulated penalty since the last ban.
+    score: u32,
+    /// The time of the latest reported misbehavior.
+    last_offense: Option<Timestamp>,
+    /// The number of times this peer has been banned.
+    ban_count: u32,
+    /// The time at which the current or latest ban expires.
+    banned_until: Option<Timestamp>,
+}
+
+impl Standing {
+    fn is_banned(&self, now: Timestamp) -> bool {
+        self.banned_until.map_or(false, |until| until > now)
+    }
+
+    /// Adds the penalty to the score, and bans the peer if the threshold is reached.
+    ///
+    /// The score is forgiven if the previous offense is older than the base ban duration. Returns
+    /// whether the peer got banned.
+    fn penalize(
+        &mut self,
+        penalty: u32,
+        threshold: u32,
+        base_duration: TimeDiff,
+        max_duration: TimeDiff,
+        now: Timestamp,
+    ) -> bool {
+        if self.is_banned(now) {
+            return false;
+        }
+        if let Some(last_offense) = self.last_offense {
+            if now.saturating_diff(last_offense) > base_duration {
+                self.score = 0;
+            }
+        }
+        self.last_offense = Some(now);
+        self.score = self.score.saturating_add(penalty);
+        if self.score < threshold {
+            return false;
+        }
+        let exponent = self.ban_count.min(MAX_BAN_EXPONENT);
+        let duration = (base_duration * 2u64.pow(exponent)).min(max_duration);
+        self.ban_count += 1;
+        self.banned_until = Some(now + duration);
+        self.score = 0;
+        true
+    }
+
+    /// Returns whether this standing still affects future penalties or bans.
+    fn is_relevant(&self, base_duration: TimeDiff, max_duration: TimeDiff, now: Timestamp) -> bool {
+        let score_relevant = self.score > 0
+            && self.last_offense.map_or(false, |last_offense| {
+                now.saturating_diff(last_offense) <= base_duration
+            });
+        let ban_relevant = self.banned_until.map_or(false, |until| {
+            until > now || now.saturating_diff(until) <= max_duration
+        });
+        score_relevant || ban_relevant
+    }
+
+    fn banned_peer(&self, peer: String, now: Timestamp) -> Option<BannedPeer> {
+        let banned_until = self.banned_until.filter(|until| *until > now)?;
+        Some(BannedPeer {
+            peer,
+            banned_until,
+            ban_count: self.ban_count,
+        })
+    }
+}
+
+/// The reputations of all peers that have misbehaved.
+#[derive(DataSize, Debug)]
+pub(super) struct Reputation {
+    /// The penalty at which a node ID is banned.
+    ban_threshold: u32,
+    /// The penalty at which an IP address is banned.
+    ip_ban_threshold: u32,
+    /// The duration of the first ban.
+    ban_duration: TimeDiff,
+    /// The maximum duration of a ban.
+    max_ban_duration: TimeDiff,
+    /// The standing of each node ID.
+    nodes: HashMap<NodeId, Standing>,
+    /// The standing of each IP address.
+    #[data_size(skip)]
+    addresses: HashMap<IpAddr, Standing>,
+}
+
+impl Reputation {
+    pub(super) fn new(cfg: &Config) -> Self {
+        Reputation {
+            ban_threshold: cfg.ban_threshold,
+            ip_ban_threshold: cfg.ip_ban_threshold,
+            ban_duration: cfg.ban_duration.into(),
+            max_ban_duration: cfg.max_ban_duration.into(),
+            nodes: HashMap::new(),
+            addresses: HashMap::new(),
+        }
+    }
+
+    /// Records a misbehavior of the given peer, connected from the given IP address if known.
+    ///
+    /// Returns whether the node ID or the IP address got banned as a result. Loopback addresses
+    /// are never banned, since all nodes of a local test network share them.
+    pub(super) fn report(
+        &mut self,
+        peer_id: &NodeId,
+        ip: Option<IpAddr>,
+        misbehavior: Misbehavior,
+        now: Timestamp,
+    ) -> bool {
+        let penalty = misbehavior.penalty();
+        let node_banned = self.nodes.entry(peer_id.clone()).or_default().penalize(
+            penalty,
+            self.ban_threshold,
+            self.ban_duration,
+            self.max_ban_duration,
+            now,
+        );
+        let ip_banned = match ip {
+            Some(ip) if !ip.is_loopback() => self.addresses.entry(ip).or_default().penalize(
+                penalty,
+                self.ip_ban_threshold,
+                self.ban_duration,
+                self.max_ban_duration,
+                now,
+            ),
+            _ => false,
+        };
+        node_banned || ip_banned
+    }
+
+    /// Returns whether the node ID is currently banned.
+    pub(super) fn is_node_banned(&self, peer_id: &NodeId, now: Timestamp) -> bool {
+        self.nodes
+            .get(peer_id)
+            .map_or(false, |standing| standing.is_banned(now))
+    }
+
+    /// Returns whether the IP address is currently banned.
+    pub(super) fn is_ip_banned(&self, ip: &IpAddr, now: Timestamp) -> bool {
+        self.addresses
+            .get(ip)
+            .map_or(false, |standing| standing.is_banned(now))
+    }
+
+    /// Removes the standings that no longer affect future penalties or bans.
+    pub(super) fn prune(&mut self, now: Timestamp) {
+        let base_duration = self.ban_duration;
+        let max_duration = self.max_ban_duration;
+        self.nodes
+            .retain(|_, standing| standing.is_relevant(base_duration, max_duration, now));
+        self.addresses
+            .retain(|_, standing| standing.is_relevant(base_duration, max_duration, now));
+    }
+
+    /// Returns all node IDs and IP addresses that are currently banned.
+    pub(super) fn banned_peers(&self, now: Timestamp) -> Vec<BannedPeer> {
+        let nodes = self
+            .nodes
+            .iter()
+            .filter_map(|(node_id, standing)| standing.banned_peer(node_id.to_string(), now));
+        let addresses = self
+            .addresses
+            .iter()
+            .filter_map(|(ip, standing)| standing.banned_peer(ip.to_string(), now));
+        let mut banned: Vec<_> = nodes.chain(addresses).collect();
+        banned.sort_by(|a, b| a.peer.cmp(&b.peer));
+        banned
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{Ipv4Addr, Ipv6Addr},
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::testing::TestRng;
+
+    fn config() -> Config {
+        Config {
+            ban_threshold: 100,
+            ip_ban_threshold: 200,
+            ban_duration: Duration::from_secs(60),
+            max_ban_duration: Duration::from_secs(200),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn should_ban_with_exponential_back_off() {
+        let mut rng = TestRng::new();
+        let peer_id = NodeId::random(&mut rng);
+        let mut reputation = Reputation::new(&config());
+        let minute: TimeDiff = Duration::from_secs(60).into();
+        let mut now = Timestamp::from(1_000_000);
+
+        // Four invalid deploys are tolerated, the fifth one causes a one-minute ban.
+        for _ in 0..4 {
+            assert!(!reputation.report(&peer_id, None, Misbehavior::InvalidDeploy, now));
+        }
+        assert!(reputation.report(&peer_id, None, Misbehavior::InvalidDeploy, now));
+        assert!(reputation.is_node_banned(&peer_id, now));
+        assert!(!reputation.is_node_banned(&peer_id, now + minute));
+
+        // The second ban lasts two minutes.
+        now = now + minute;
+        assert!(!reputation.report(&peer_id, None, Misbehavior::InvalidConsensusMessage, now));
+        assert!(reputation.report(&peer_id, None, Misbehavior::InvalidConsensusMessage, now));
+        assert!(reputation.is_node_banned(&peer_id, now + minute));
+        assert!(!reputation.is_node_banned(&peer_id, now + minute * 2));
+
+        // The third one would last four minutes, but is capped at the maximum duration.
+        now = now + minute * 2;
+        assert!(!reputation.report(&peer_id, None, Misbehavior::InvalidConsensusMessage, now));
+        assert!(reputation.report(&peer_id, None, Misbehavior::InvalidConsensusMessage, now));
+        let banned = reputation.banned_peers(now);
+        assert_eq!(1, banned.len());
+        assert_eq!(3, banned[0].ban_count);
+        assert_eq!(
+            now + Duration::from_secs(200).into(),
+            banned[0].banned_until
+        );
+    }
+
+    #[test]
+    fn should_forgive_old_offenses() {
+        let mut rng = TestRng::new();
+        let peer_id = NodeId::random(&mut rng);
+        let mut reputation = Reputation::new(&config());
+        let mut now = Timestamp::from(1_000_000);
+
+        for _ in 0..10 {
+            assert!(!reputation.report(&peer_id, None, Misbehavior::InvalidGetResponse, now));
+            now = now + Duration::from_secs(61).into();
+        }
+        assert!(reputation.banned_peers(now).is_empty());
+    }
+
+    #[test]
+    fn should_ban_ip_addresses_except_loopback() {
+        let mut rng = TestRng::new();
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let mut reputation = Reputation::new(&config());
+        let now = Timestamp::from(1_000_000);
+
+        // Two different node IDs from the same address reach the IP threshold.
+        for _ in 0..2 {
+            let peer_id = NodeId::random(&mut rng);
+            let misbehavior = Misbehavior::InvalidConsensusMessage;
+            assert!(!reputation.report(&peer_id, Some(ip), misbehavior, now));
+            assert!(reputation.report(&peer_id, Some(ip), misbehavior, now));
+        }
+        assert!(reputation.is_ip_banned(&ip, now));
+        assert_eq!(3, reputation.banned_peers(now).len());
+
+        let loopback = IpAddr::V6(Ipv6Addr::LOCALHOST);
+        for _ in 0..2 {
+            let peer_id = NodeId::random(&mut rng);
+            reputation.report(
+                &peer_id,
+                Some(loopback),
+                Misbehavior::InvalidConsensusMessage,
+                now,
+            );
+        }
+        assert!(!reputation.is_ip_banned(&loopback, now));
+    }
+
+    #[test]
+    fn should_prune_irrelevant_standings() {
+        let mut rng = TestRng::new();
+        let offender = NodeId::random(&mut rng);
+        let banned = NodeId::random(&mut rng);
+        let mut reputation = Reputation::new(&config());
+        let mut now = Timestamp::from(1_000_000);
+
+        assert!(!reputation.report(&offender, None, Misbehavior::InvalidDeploy, now));
+        for _ in 0..2 {
+            reputation.report(&banned, None, Misbehavior::InvalidConsensusMessage, now);
+        }
+        reputation.prune(now);
+        assert_eq!(2, reputation.nodes.len());
+
+        // The offender's score is forgiven after the base ban duration.
+        now = now + Duration::from_secs(61).into();
+        reputation.prune(now);
+        assert!(!reputation.nodes.contains_key(&offender));
+        assert!(reputation.nodes.contains_key(&banned));
+
+        // The ban count is kept for the maximum ban duration after the ban expired.
+        now = now + Duration::from_secs(199).into();
+        reputation.prune(now);
+        assert!(reputation.nodes.contains_key(&banned));
+        now = now + Duration::from_secs(1).into();
+        reputation.prune(now);
+        assert!(reputation.nodes.is_empty());
+    }
+}
@@ -16,7 +16,9 @@ use prometheus::Registry;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
-use super::{Config, Event as SmallNetworkEvent, GossipedAddress, SmallNetwork};
+use super::{
+    Classify, Config, Event as SmallNetworkEvent, GossipedAddress, MessageClass, SmallNetwork,
+};
 use crate::{
     components::{
         gossiper::{self, Gossiper},
@@ -100,6 +102,12 @@ impl Display for Message {
     }
 }
 
+impl Classify for Message {
+    fn message_class(&self) -> MessageClass {
+        MessageClass::Gossip
+    }
+}
+
 /// Test reactor.
 ///
 /// Runs a single small network.
@@ -128,6 +136,7 @@ impl Reactor for TestReactor {
             small_network_identity,
             "test_network".to_string(),
             false,
+            None,
         )?;
         let gossiper_config = gossiper::Config::new_with_small_timeouts();
         let address_gossiper =
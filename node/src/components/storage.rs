@@ -41,9 +41,9 @@ mod lmdb_ext;
 mod tests;
 
 #[cfg(test)]
-use std::{collections::BTreeSet, convert::TryFrom};
+use std::convert::TryFrom;
 use std::{
-    collections::{btree_map::Entry, BTreeMap},
+    collections::{btree_map::Entry, BTreeMap, BTreeSet, HashMap},
     fmt::{self, Display, Formatter},
     fs, io, mem,
     path::PathBuf,
@@ -52,7 +52,8 @@ use std::{
 use datasize::DataSize;
 use derive_more::From;
 use lmdb::{
-    Cursor, Database, DatabaseFlags, Environment, EnvironmentFlags, Transaction, WriteFlags,
+    Cursor, Database, DatabaseFlags, Environment, EnvironmentFlags, RwTransaction, Transaction,
+    WriteFlags,
 };
 use serde::{Deserialize, Serialize};
 use static_assertions::const_assert;
@@ -72,13 +73,16 @@ use crate::{
     },
     fatal,
     types::{
-        Block, BlockBody, BlockHash, BlockHeader, BlockSignatures, Deploy, DeployHash,
-        DeployMetadata,
+        Block, BlockBody, BlockFinality, BlockHash, BlockHeader, BlockSignatures, Deploy,
+        DeployHash, DeployMetadata, EraRewards,
     },
     utils::WithDir,
     NodeRng,
 };
-use casper_types::{ExecutionResult, Transfer, Transform};
+use casper_types::{
+    system::handle_payment::PaymentBreakdown, ExecutionEffect, ExecutionResult, PublicKey,
+    Signature, Transfer, TransferAddr, Transform, U512,
+};
 use lmdb_ext::{LmdbExtError, TransactionExt, WriteTransactionExt};
 
 /// Filename for the LMDB database created by the Storage component.
@@ -100,7 +104,7 @@ const DEFAULT_MAX_DEPLOY_METADATA_STORE_SIZE: usize = 300 * GIB;
 /// Default max state store size.
 const DEFAULT_MAX_STATE_STORE_SIZE: usize = 10 * GIB;
 /// Maximum number of allowed dbs.
-const MAX_DB_COUNT: u32 = 7;
+const MAX_DB_COUNT: u32 = 10;
 
 /// OS-specific lmdb flags.
 #[cfg(not(target_os = "macos"))]
@@ -171,6 +175,51 @@ impl From<lmdb::Error> for Error {
     }
 }
 
+/// An execution result as stored before the payment breakdown was recorded.
+#[derive(Deserialize)]
+enum LegacyExecutionResult {
+    Failure {
+        effect: ExecutionEffect,
+        transfers: Vec<TransferAddr>,
+        cost: U512,
+        error_message: String,
+    },
+    Success {
+        effect: ExecutionEffect,
+        transfers: Vec<TransferAddr>,
+        cost: U512,
+    },
+}
+
+impl From<LegacyExecutionResult> for ExecutionResult {
+    fn from(legacy: LegacyExecutionResult) -> Self {
+        match legacy {
+            LegacyExecutionResult::Failure {
+                effect,
+                transfers,
+                cost,
+                error_message,
+            } => ExecutionResult::Failure {
+                effect,
+                transfers,
+                cost,
+                payment: PaymentBreakdown::default(),
+                error_message,
+            },
+            LegacyExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+            } => ExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+                payment: PaymentBreakdown::default(),
+            },
+        }
+    }
+}
+
 #[derive(DataSize, Debug)]
 pub struct Storage {
     /// Storage location.
@@ -187,6 +236,16 @@ pub struct Storage {
     /// The block metadata db.
     #[data_size(skip)]
     block_metadata_db: Database,
+    /// The finality of blocks that have not been stored yet, keyed by block height. Once the block
+    /// is stored, its finality is moved into its entry in the block metadata db.
+    #[data_size(skip)]
+    block_finality_db: Database,
+    /// The validator performance database, keyed by era ID.
+    #[data_size(skip)]
+    validator_performance_db: Database,
+    /// The era rewards database, keyed by era ID.
+    #[data_size(skip)]
+    era_rewards_db: Database,
     /// The deploy database.
     #[data_size(skip)]
     deploy_db: Database,
@@ -203,6 +262,8 @@ pub struct Storage {
     block_height_index: BTreeMap<u64, BlockHash>,
     /// A map of era ID to switch block ID.
     switch_block_era_id_index: BTreeMap<EraId, BlockHash>,
+    /// A map of validator or delegator public key to the eras in which it received rewards.
+    era_rewards_index: BTreeMap<PublicKey, BTreeSet<EraId>>,
 }
 
 impl<REv> Component<REv> for Storage {
@@ -269,6 +330,10 @@ impl Storage {
 
         let block_header_db = env.create_db(Some("block_header"), DatabaseFlags::empty())?;
         let block_metadata_db = env.create_db(Some("block_metadata"), DatabaseFlags::empty())?;
+        let block_finality_db = env.create_db(Some("block_finality"), DatabaseFlags::empty())?;
+        let validator_performance_db =
+            env.create_db(Some("validator_performance"), DatabaseFlags::empty())?;
+        let era_rewards_db = env.create_db(Some("era_rewards"), DatabaseFlags::empty())?;
         let deploy_db = env.create_db(Some("deploys"), DatabaseFlags::empty())?;
         let deploy_metadata_db = env.create_db(Some("deploy_metadata"), DatabaseFlags::empty())?;
         let transfer_db = env.create_db(Some("transfer"), DatabaseFlags::empty())?;
@@ -305,6 +370,20 @@ impl Storage {
         }
         info!("block store reindexing complete");
         drop(cursor);
+
+        let mut era_rewards_index = BTreeMap::new();
+        let mut cursor = block_txn.open_ro_cursor(era_rewards_db)?;
+        for (_, raw_val) in cursor.iter() {
+            let era_rewards: EraRewards = lmdb_ext::deserialize(raw_val)?;
+            if let Some(invalid_era) = hard_reset_to_start_of_era {
+                if era_rewards.era_id() >= invalid_era {
+                    continue;
+                }
+            }
+            insert_to_era_rewards_index(&mut era_rewards_index, &era_rewards);
+        }
+        info!("era rewards reindexing complete");
+        drop(cursor);
         drop(block_txn);
 
         Ok(Storage {
@@ -313,12 +392,16 @@ impl Storage {
             block_header_db,
             block_body_db,
             block_metadata_db,
+            block_finality_db,
+            validator_performance_db,
+            era_rewards_db,
             deploy_db,
             deploy_metadata_db,
             transfer_db,
             state_store_db,
             block_height_index,
             switch_block_era_id_index,
+            era_rewards_index,
         })
     }
 
@@ -415,6 +498,7 @@ impl Storage {
                     txn.abort();
                     return Ok(responder.respond(false).ignore());
                 }
+                self.move_pending_finality(&mut txn, block.header())?;
                 txn.commit()?;
                 insert_to_block_header_indices(
                     &mut self.block_height_index,
@@ -657,14 +741,16 @@ impl Storage {
                 responder,
             } => {
                 let mut txn = self.env.begin_rw_txn()?;
-                let old_data: Option<BlockSignatures> =
-                    txn.get_value(self.block_metadata_db, &signatures.block_hash)?;
+                let old_data = self.get_finality_signatures(&mut txn, &signatures.block_hash)?;
                 let new_data = match old_data {
                     None => signatures,
                     Some(mut data) => {
                         for (pk, sig) in signatures.proofs {
                             data.insert_proof(pk, sig);
                         }
+                        if data.finality.is_none() {
+                            data.finality = signatures.finality;
+                        }
                         data
                     }
                 };
@@ -685,6 +771,105 @@ impl Storage {
                     self.get_finality_signatures(&mut self.env.begin_ro_txn()?, &block_hash)?;
                 responder.respond(result).ignore()
             }
+            StorageRequest::PutBlockFinality {
+                finality,
+                responder,
+            } => {
+                let mut txn = self.env.begin_rw_txn()?;
+                let outcome = match self.block_height_index.get(&finality.height()) {
+                    // The block is already stored: Record the finality in its metadata.
+                    Some(block_hash) => {
+                        let mut signatures = self
+                            .get_finality_signatures(&mut txn, block_hash)?
+                            .unwrap_or_else(|| BlockSignatures::new(*block_hash, finality.era_id));
+                        signatures.finality = Some(*finality);
+                        txn.put_value(self.block_metadata_db, block_hash, &signatures, true)?
+                    }
+                    None => txn.put_value(
+                        self.block_finality_db,
+                        &finality.height().to_le_bytes(),
+                        &*finality,
+                        true,
+                    )?,
+                };
+                txn.commit()?;
+                responder.respond(outcome).ignore()
+            }
+            StorageRequest::GetBlockFinality {
+                block_height,
+                responder,
+            } => {
+                let mut txn = self.env.begin_ro_txn()?;
+                let stored_finality = match self.block_height_index.get(&block_height) {
+                    Some(block_hash) => self
+                        .get_finality_signatures(&mut txn, block_hash)?
+                        .and_then(|signatures| signatures.finality),
+                    None => None,
+                };
+                let result = match stored_finality {
+                    Some(finality) => Some(finality),
+                    None => txn.get_value(self.block_finality_db, &block_height.to_le_bytes())?,
+                };
+                responder.respond(result).ignore()
+            }
+            StorageRequest::PutEraValidatorPerformance {
+                performance,
+                responder,
+            } => {
+                let mut txn = self.env.begin_rw_txn()?;
+                let outcome = txn.put_value(
+                    self.validator_performance_db,
+                    &performance.era_id().0.to_le_bytes(),
+                    &*performance,
+                    true,
+                )?;
+                txn.commit()?;
+                responder.respond(outcome).ignore()
+            }
+            StorageRequest::GetEraValidatorPerformance { era_id, responder } => {
+                let result = self
+                    .env
+                    .begin_ro_txn()?
+                    .get_value(self.validator_performance_db, &era_id.0.to_le_bytes())?;
+                responder.respond(result).ignore()
+            }
+            StorageRequest::PutEraRewards {
+                era_rewards,
+                responder,
+            } => {
+                let mut txn = self.env.begin_rw_txn()?;
+                let outcome = txn.put_value(
+                    self.era_rewards_db,
+                    &era_rewards.era_id().0.to_le_bytes(),
+                    &*era_rewards,
+                    true,
+                )?;
+                txn.commit()?;
+                insert_to_era_rewards_index(&mut self.era_rewards_index, &era_rewards);
+                responder.respond(outcome).ignore()
+            }
+            StorageRequest::GetEraRewardHistory {
+                public_key,
+                from_era_id,
+                to_era_id,
+                responder,
+            } => {
+                let era_ids = match self.era_rewards_index.get(&public_key) {
+                    Some(era_ids) if from_era_id <= to_era_id => era_ids,
+                    _ => return Ok(responder.respond(Vec::new()).ignore()),
+                };
+                let mut txn = self.env.begin_ro_txn()?;
+                let mut history = Vec::new();
+                for era_id in era_ids.range(from_era_id..=to_era_id) {
+                    let era_rewards: Option<EraRewards> =
+                        txn.get_value(self.era_rewards_db, &era_id.0.to_le_bytes())?;
+                    if let Some(era_rewards) = era_rewards {
+                        history.push(era_rewards.select(&public_key));
+                    }
+                }
+                txn.commit()?;
+                responder.respond(history).ignore()
+            }
         })
     }
 
@@ -766,7 +951,21 @@ impl Storage {
         tx: &mut Tx,
         deploy_hash: &DeployHash,
     ) -> Result<Option<DeployMetadata>, Error> {
-        Ok(tx.get_value(self.deploy_metadata_db, deploy_hash)?)
+        let raw = match tx.get(self.deploy_metadata_db, deploy_hash) {
+            Ok(raw) => raw,
+            Err(lmdb::Error::NotFound) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        if let Ok(metadata) = lmdb_ext::deserialize::<DeployMetadata>(raw) {
+            return Ok(Some(metadata));
+        }
+        // Entries written before the payment breakdown was part of the execution results.
+        let legacy_results: HashMap<BlockHash, LegacyExecutionResult> = lmdb_ext::deserialize(raw)?;
+        let execution_results = legacy_results
+            .into_iter()
+            .map(|(block_hash, legacy)| (block_hash, ExecutionResult::from(legacy)))
+            .collect();
+        Ok(Some(DeployMetadata { execution_results }))
     }
 
     /// Retrieves transfers associated with block.
@@ -787,7 +986,44 @@ impl Storage {
         tx: &mut Tx,
         block_hash: &BlockHash,
     ) -> Result<Option<BlockSignatures>, Error> {
-        Ok(tx.get_value(self.block_metadata_db, block_hash)?)
+        let raw = match tx.get(self.block_metadata_db, block_hash) {
+            Ok(raw) => raw,
+            Err(lmdb::Error::NotFound) => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        if let Ok(signatures) = lmdb_ext::deserialize::<BlockSignatures>(raw) {
+            return Ok(Some(signatures));
+        }
+        // Entries written before the finality was part of the block metadata.
+        let (block_hash, era_id, proofs): (BlockHash, EraId, BTreeMap<PublicKey, Signature>) =
+            lmdb_ext::deserialize(raw)?;
+        Ok(Some(BlockSignatures {
+            block_hash,
+            era_id,
+            proofs,
+            finality: None,
+        }))
+    }
+
+    /// Moves the finality recorded for the given block's height, if any, into its metadata.
+    fn move_pending_finality(
+        &self,
+        txn: &mut RwTransaction,
+        block_header: &BlockHeader,
+    ) -> Result<(), Error> {
+        let height_key = block_header.height().to_le_bytes();
+        let finality: BlockFinality = match txn.get_value(self.block_finality_db, &height_key)? {
+            Some(finality) => finality,
+            None => return Ok(()),
+        };
+        let block_hash = block_header.hash();
+        let mut signatures = self
+            .get_finality_signatures(txn, &block_hash)?
+            .unwrap_or_else(|| BlockSignatures::new(block_hash, block_header.era_id()));
+        signatures.finality = Some(finality);
+        txn.put_value(self.block_metadata_db, &block_hash, &signatures, true)?;
+        txn.del(self.block_finality_db, &height_key, None)?;
+        Ok(())
     }
 
     /// Get the lmdb environment
@@ -837,6 +1073,19 @@ fn insert_to_block_header_indices(
     Ok(())
 }
 
+/// Inserts the era into the index of every validator and delegator that was a recipient in it.
+fn insert_to_era_rewards_index(
+    era_rewards_index: &mut BTreeMap<PublicKey, BTreeSet<EraId>>,
+    era_rewards: &EraRewards,
+) {
+    for public_key in era_rewards.public_keys() {
+        let _ = era_rewards_index
+            .entry(*public_key)
+            .or_default()
+            .insert(era_rewards.era_id());
+    }
+}
+
 /// On-disk storage configuration.
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -6,17 +6,21 @@ use rand::{prelude::SliceRandom, Rng};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use smallvec::smallvec;
 
-use casper_types::ExecutionResult;
+use casper_types::{ExecutionResult, PublicKey, U512};
 
 use super::{Config, Storage};
 use crate::{
     components::consensus::EraId,
+    crypto::AsymmetricKeyExt,
     effect::{
         requests::{StateStoreRequest, StorageRequest},
         Multiple,
     },
     testing::{ComponentHarness, TestRng},
-    types::{Block, BlockHash, Deploy, DeployHash, DeployMetadata},
+    types::{
+        Block, BlockFinality, BlockHash, BlockSignatures, Deploy, DeployHash, DeployMetadata,
+        EraReward, EraRewards,
+    },
     utils::WithDir,
 };
 
@@ -166,6 +170,57 @@ fn put_block(harness: &mut ComponentHarness<()>, storage: &mut Storage, block: B
     response
 }
 
+/// Stores the finality of a block in a storage component.
+fn put_block_finality(
+    harness: &mut ComponentHarness<()>,
+    storage: &mut Storage,
+    finality: BlockFinality,
+) -> bool {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::PutBlockFinality {
+            finality: Box::new(finality),
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
+/// Loads the finality of a block at a given height from a storage component.
+fn get_block_finality(
+    harness: &mut ComponentHarness<()>,
+    storage: &mut Storage,
+    block_height: u64,
+) -> Option<BlockFinality> {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::GetBlockFinality {
+            block_height,
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
+/// Loads the metadata of a block from a storage component.
+fn get_block_signatures(
+    harness: &mut ComponentHarness<()>,
+    storage: &mut Storage,
+    block_hash: BlockHash,
+) -> Option<BlockSignatures> {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::GetBlockSignatures {
+            block_hash,
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
 /// Stores a deploy in a storage component.
 fn put_deploy(
     harness: &mut ComponentHarness<()>,
@@ -198,6 +253,44 @@ fn put_execution_results(
     response
 }
 
+/// Stores the rewards of an era in a storage component.
+fn put_era_rewards(
+    harness: &mut ComponentHarness<()>,
+    storage: &mut Storage,
+    era_rewards: EraRewards,
+) -> bool {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::PutEraRewards {
+            era_rewards: Box::new(era_rewards),
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
+/// Requests the rewards of a validator or delegator in a range of eras from a storage component.
+fn get_era_reward_history(
+    harness: &mut ComponentHarness<()>,
+    storage: &mut Storage,
+    public_key: PublicKey,
+    from_era_id: u64,
+    to_era_id: u64,
+) -> Vec<EraRewards> {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::GetEraRewardHistory {
+            public_key: Box::new(public_key),
+            from_era_id: EraId(from_era_id),
+            to_era_id: EraId(to_era_id),
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
 /// Saves state from the storage component.
 fn save_state<T>(
     harness: &mut ComponentHarness<()>,
@@ -846,3 +939,120 @@ fn should_hard_reset() {
     // Check the highest block is `None`.
     assert!(get_highest_block(&mut harness, &mut storage).is_none());
 }
+
+#[test]
+fn store_and_load_era_reward_history() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let validator = PublicKey::random(&mut harness.rng);
+    let delegator = PublicKey::random(&mut harness.rng);
+    let validator_reward = |amount: u64| EraReward {
+        validator_public_key: validator,
+        delegator_public_key: None,
+        delegation_rate: 10,
+        stake: U512::from(1_000),
+        amount: U512::from(amount),
+    };
+    let delegator_reward = EraReward {
+        validator_public_key: validator,
+        delegator_public_key: Some(delegator),
+        delegation_rate: 10,
+        stake: U512::from(500),
+        amount: U512::from(3),
+    };
+
+    // The delegator only joined in era 2.
+    let era_1 = EraRewards::new(EraId(1), vec![validator_reward(5)]);
+    let era_2 = EraRewards::new(
+        EraId(2),
+        vec![validator_reward(6), delegator_reward.clone()],
+    );
+    assert!(put_era_rewards(&mut harness, &mut storage, era_1.clone()));
+    assert!(put_era_rewards(&mut harness, &mut storage, era_2));
+
+    let history = get_era_reward_history(&mut harness, &mut storage, validator, 0, 10);
+    assert_eq!(
+        history,
+        vec![
+            era_1.clone(),
+            EraRewards::new(EraId(2), vec![validator_reward(6)])
+        ]
+    );
+    let history = get_era_reward_history(&mut harness, &mut storage, validator, 2, 2);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].era_id, EraId(2));
+    assert!(get_era_reward_history(&mut harness, &mut storage, validator, 2, 1).is_empty());
+
+    let history = get_era_reward_history(&mut harness, &mut storage, delegator, 0, 10);
+    assert_eq!(
+        history,
+        vec![EraRewards::new(EraId(2), vec![delegator_reward.clone()])]
+    );
+
+    // The index is restored on instantiation, except for eras affected by a hard reset.
+    let mut storage = storage_fixture(&harness);
+    let history = get_era_reward_history(&mut harness, &mut storage, delegator, 0, 10);
+    assert_eq!(
+        history,
+        vec![EraRewards::new(EraId(2), vec![delegator_reward])]
+    );
+
+    let mut storage = storage_fixture_with_hard_reset(&harness, EraId(2));
+    assert!(get_era_reward_history(&mut harness, &mut storage, delegator, 0, 10).is_empty());
+    let history = get_era_reward_history(&mut harness, &mut storage, validator, 0, 10);
+    assert_eq!(history, vec![era_1]);
+}
+
+#[test]
+fn store_block_finality_in_block_metadata() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let block = random_block_at_height(&mut harness.rng, 3);
+    let block_hash = *block.hash();
+    let era_id = block.header().era_id();
+    let validator = PublicKey::random(&mut harness.rng);
+    let finality = BlockFinality::new(3, era_id, 1, 333_333, vec![validator]);
+
+    // The finality is usually recorded before the block has been executed and stored.
+    assert!(put_block_finality(
+        &mut harness,
+        &mut storage,
+        finality.clone()
+    ));
+    assert_eq!(
+        get_block_finality(&mut harness, &mut storage, 3),
+        Some(finality.clone())
+    );
+    assert!(get_block_signatures(&mut harness, &mut storage, block_hash).is_none());
+
+    // Storing the block moves the finality into the block's metadata.
+    assert!(put_block(&mut harness, &mut storage, block));
+    let signatures = get_block_signatures(&mut harness, &mut storage, block_hash)
+        .expect("block metadata should exist");
+    assert_eq!(signatures.finality, Some(finality.clone()));
+    assert_eq!(
+        get_block_finality(&mut harness, &mut storage, 3),
+        Some(finality.clone())
+    );
+
+    // Finality recorded after the block has been stored goes straight into its metadata.
+    let block = random_block_at_height(&mut harness.rng, 4);
+    let block_hash = *block.hash();
+    let finality = BlockFinality::new(4, block.header().era_id(), 2, 100_000, vec![validator]);
+    assert!(put_block(&mut harness, &mut storage, block));
+    assert!(get_block_finality(&mut harness, &mut storage, 4).is_none());
+    assert!(put_block_finality(
+        &mut harness,
+        &mut storage,
+        finality.clone()
+    ));
+    let signatures = get_block_signatures(&mut harness, &mut storage, block_hash)
+        .expect("block metadata should exist");
+    assert_eq!(signatures.finality, Some(finality.clone()));
+    assert_eq!(
+        get_block_finality(&mut harness, &mut storage, 4),
+        Some(finality)
+    );
+}
@@ -97,7 +97,10 @@ use casper_execution_engine::{
     storage::{global_state::CommitResult, protocol_data::ProtocolData, trie::Trie},
 };
 use casper_types::{
-    system::auction::EraValidators, ExecutionResult, Key, ProtocolVersion, PublicKey, Transfer,
+    system::auction::{
+        EraValidators, SeigniorageRecipientsSnapshot, SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY,
+    },
+    ExecutionResult, Key, ProtocolVersion, PublicKey, Transfer,
 };
 
 use crate::{
@@ -107,15 +110,16 @@ use crate::{
         contract_runtime::EraValidatorsRequest,
         deploy_acceptor,
         fetcher::FetchResult,
-        small_network::GossipedAddress,
+        small_network::{BannedPeer, GossipedAddress, Misbehavior},
     },
     crypto::hash::Digest,
     effect::requests::LinearChainRequest,
     reactor::{EventQueueHandle, QueueKind},
     types::{
-        Block, BlockByHeight, BlockHash, BlockHeader, BlockLike, BlockSignatures, Chainspec,
-        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, FinalitySignature,
-        FinalizedBlock, Item, ProtoBlock, TimeDiff, Timestamp,
+        Block, BlockByHeight, BlockFinality, BlockHash, BlockHeader, BlockLike, BlockSignatures,
+        Chainspec, ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, EraRewards,
+        EraValidatorPerformance, FinalitySignature, FinalizedBlock, Item, ProtoBlock, TimeDiff,
+        Timestamp,
     },
     utils::Source,
 };
@@ -534,6 +538,50 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Reports that a peer misbehaved, lowering its reputation and possibly getting it banned.
+    pub(crate) async fn report_misbehavior<I, P>(self, peer: I, misbehavior: Misbehavior)
+    where
+        REv: From<NetworkRequest<I, P>>,
+    {
+        self.make_request(
+            |responder| NetworkRequest::ReportMisbehavior {
+                peer: Box::new(peer),
+                misbehavior,
+                responder,
+            },
+            QueueKind::Network,
+        )
+        .await
+    }
+
+    /// Sets the current validators, whose network traffic is prioritized.
+    pub(crate) async fn set_network_validators<I, P>(self, validators: HashSet<PublicKey>)
+    where
+        REv: From<NetworkRequest<I, P>>,
+    {
+        self.make_request(
+            |responder| NetworkRequest::SetValidators {
+                validators: Box::new(validators),
+                responder,
+            },
+            QueueKind::Network,
+        )
+        .await
+    }
+
+    /// Gets the node IDs and IP addresses which are currently banned.
+    pub async fn network_banned_peers<I>(self) -> Vec<BannedPeer>
+    where
+        REv: From<NetworkInfoRequest<I>>,
+        I: Send + 'static,
+    {
+        self.make_request(
+            |responder| NetworkInfoRequest::GetBannedPeers { responder },
+            QueueKind::Api,
+        )
+        .await
+    }
+
     /// Gets connected network peers.
     pub async fn network_peers<I>(self) -> BTreeMap<I, String>
     where
@@ -742,6 +790,111 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Puts the fault tolerance with which a block was finalized into storage.
+    pub(crate) async fn put_block_finality_to_storage(self, finality: Box<BlockFinality>) -> bool
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::PutBlockFinality {
+                finality,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Gets the fault tolerance with which the block at the given height was finalized from
+    /// storage.
+    pub(crate) async fn get_block_finality_from_storage(
+        self,
+        block_height: u64,
+    ) -> Option<BlockFinality>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetBlockFinality {
+                block_height,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Puts the validators' performance in an era into storage.
+    pub(crate) async fn put_era_validator_performance_to_storage(
+        self,
+        performance: Box<EraValidatorPerformance>,
+    ) -> bool
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::PutEraValidatorPerformance {
+                performance,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Gets the validators' performance in the given era from storage.
+    pub(crate) async fn get_era_validator_performance_from_storage(
+        self,
+        era_id: EraId,
+    ) -> Option<EraValidatorPerformance>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetEraValidatorPerformance { era_id, responder },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Puts the stakes and rewards of all validators and delegators in an era into storage.
+    pub(crate) async fn put_era_rewards_to_storage(self, era_rewards: Box<EraRewards>) -> bool
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::PutEraRewards {
+                era_rewards,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Gets the stakes and rewards of the given validator or delegator in a range of eras from
+    /// storage.
+    pub(crate) async fn get_era_reward_history_from_storage(
+        self,
+        public_key: PublicKey,
+        from_era_id: EraId,
+        to_era_id: EraId,
+    ) -> Vec<EraRewards>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetEraRewardHistory {
+                public_key: Box::new(public_key),
+                from_era_id,
+                to_era_id,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Gets the requested block's transfers from storage.
     pub(crate) async fn get_block_transfers_from_storage(
         self,
@@ -1162,13 +1315,21 @@ impl<REv> EffectBuilder<REv> {
     }
 
     /// The linear chain has stored a newly-created block.
-    pub(crate) async fn announce_block_added(self, block_hash: BlockHash, block: Box<Block>)
-    where
+    pub(crate) async fn announce_block_added(
+        self,
+        block_hash: BlockHash,
+        block: Box<Block>,
+        finality: Option<BlockFinality>,
+    ) where
         REv: From<LinearChainAnnouncement>,
     {
         self.0
             .schedule(
-                LinearChainAnnouncement::BlockAdded { block_hash, block },
+                LinearChainAnnouncement::BlockAdded {
+                    block_hash,
+                    block,
+                    finality,
+                },
                 QueueKind::Regular,
             )
             .await
@@ -1232,6 +1393,15 @@ impl<REv> EffectBuilder<REv> {
             .await
     }
 
+    /// Gets the chainspec from the chainspec loader.
+    pub(crate) async fn get_chainspec(self) -> Arc<Chainspec>
+    where
+        REv: From<ChainspecLoaderRequest> + Send,
+    {
+        self.make_request(ChainspecLoaderRequest::GetChainspec, QueueKind::Regular)
+            .await
+    }
+
     /// Loads potentially previously stored state from storage.
     ///
     /// Key must be a unique key across the the application, as all keys share a common namespace.
@@ -1377,6 +1547,56 @@ impl<REv> EffectBuilder<REv> {
         None
     }
 
+    /// Reads the stakes and rewards of all validators and delegators in the given era from global
+    /// state.
+    ///
+    /// The era's seigniorage recipients are read from the state before its step was run, since the
+    /// auction removes them from its snapshot, and the allocations from the state after it.
+    pub(crate) async fn get_era_rewards_from_global_state(
+        self,
+        era_id: EraId,
+        protocol_version: ProtocolVersion,
+        pre_step_state_root_hash: Digest,
+        post_step_state_root_hash: Digest,
+    ) -> Option<EraRewards>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        let auction = self
+            .get_protocol_data(protocol_version)
+            .await
+            .ok()??
+            .auction();
+        let query_request = QueryRequest::new(
+            pre_step_state_root_hash.into(),
+            auction.into(),
+            vec![SEIGNIORAGE_RECIPIENTS_SNAPSHOT_KEY.to_string()],
+        );
+        let mut snapshot: SeigniorageRecipientsSnapshot =
+            match self.query_global_state(query_request).await {
+                Ok(QueryResult::Success { value, .. }) => match *value {
+                    StoredValue::CLValue(cl_value) => cl_value.into_t().ok()?,
+                    _ => return None,
+                },
+                _ => return None,
+            };
+        let recipients = snapshot.remove(&era_id.0)?;
+
+        let query_request = QueryRequest::new(
+            post_step_state_root_hash.into(),
+            Key::EraInfo(era_id.0),
+            vec![],
+        );
+        let era_info = match self.query_global_state(query_request).await {
+            Ok(QueryResult::Success { value, .. }) => match *value {
+                StoredValue::EraInfo(era_info) => era_info,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        Some(EraRewards::from_era_info(era_id, &recipients, &era_info))
+    }
+
     /// Requests a query be executed on the Contract Runtime component.
     pub(crate) async fn get_balance(
         self,
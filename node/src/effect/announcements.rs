@@ -19,8 +19,8 @@ use crate::{
     },
     effect::Responder,
     types::{
-        Block, BlockHash, Deploy, DeployHash, DeployHeader, FinalitySignature, FinalizedBlock,
-        Item, Timestamp,
+        Block, BlockFinality, BlockHash, Deploy, DeployHash, DeployHeader, FinalitySignature,
+        FinalizedBlock, Item, Timestamp,
     },
     utils::Source,
 };
@@ -220,6 +220,8 @@ pub enum LinearChainAnnouncement {
         block_hash: BlockHash,
         /// Block.
         block: Box<Block>,
+        /// The fault tolerance with which the block was finalized, if known.
+        finality: Option<BlockFinality>,
     },
     /// New finality signature received.
     NewFinalitySignature(Box<FinalitySignature>),
@@ -47,13 +47,15 @@ use crate::{
         contract_runtime::{EraValidatorsRequest, ValidatorWeightsByEraIdRequest},
         deploy_acceptor::Error,
         fetcher::FetchResult,
+        small_network::{BannedPeer, Misbehavior},
     },
     crypto::hash::Digest,
     rpcs::chain::BlockIdentifier,
     types::{
-        Block as LinearBlock, Block, BlockHash, BlockHeader, BlockSignatures, Chainspec,
-        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, FinalitySignature,
-        FinalizedBlock, Item, NodeId, ProtoBlock, StatusFeed, TimeDiff, Timestamp,
+        Block as LinearBlock, Block, BlockFinality, BlockHash, BlockHeader, BlockSignatures,
+        Chainspec, ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, EraRewards,
+        EraValidatorPerformance, FinalitySignature, FinalizedBlock, Item, NodeId, ProtoBlock,
+        StatusFeed, TimeDiff, Timestamp,
     },
     utils::DisplayIter,
 };
@@ -121,6 +123,24 @@ pub enum NetworkRequest<I, P> {
         #[serde(skip_serializing)]
         responder: Responder<HashSet<I>>,
     },
+    /// Report that a peer misbehaved, lowering its reputation.
+    ReportMisbehavior {
+        /// The misbehaving peer.
+        peer: Box<I>,
+        /// The kind of misbehavior.
+        misbehavior: Misbehavior,
+        /// Responder to be called when the report has been processed.
+        #[serde(skip_serializing)]
+        responder: Responder<()>,
+    },
+    /// Set the current validators, whose traffic is prioritized.
+    SetValidators {
+        /// The public keys of the validators.
+        validators: Box<HashSet<PublicKey>>,
+        /// Responder to be called when the validators have been updated.
+        #[serde(skip_serializing)]
+        responder: Responder<()>,
+    },
 }
 
 impl<I, P> NetworkRequest<I, P> {
@@ -156,6 +176,22 @@ impl<I, P> NetworkRequest<I, P> {
                 exclude,
                 responder,
             },
+            NetworkRequest::ReportMisbehavior {
+                peer,
+                misbehavior,
+                responder,
+            } => NetworkRequest::ReportMisbehavior {
+                peer,
+                misbehavior,
+                responder,
+            },
+            NetworkRequest::SetValidators {
+                validators,
+                responder,
+            } => NetworkRequest::SetValidators {
+                validators,
+                responder,
+            },
         }
     }
 }
@@ -174,6 +210,12 @@ where
                 write!(formatter, "broadcast: {}", payload)
             }
             NetworkRequest::Gossip { payload, .. } => write!(formatter, "gossip: {}", payload),
+            NetworkRequest::ReportMisbehavior {
+                peer, misbehavior, ..
+            } => write!(formatter, "report {} by {}", misbehavior, peer),
+            NetworkRequest::SetValidators { validators, .. } => {
+                write!(formatter, "set {} validators", validators.len())
+            }
         }
     }
 }
@@ -188,6 +230,11 @@ pub enum NetworkInfoRequest<I> {
         // TODO - change the `String` field to a `libp2p::Multiaddr` once small_network is removed.
         responder: Responder<BTreeMap<I, String>>,
     },
+    /// Get the node IDs and IP addresses which are currently banned.
+    GetBannedPeers {
+        /// Responder to be called with all banned peers.
+        responder: Responder<Vec<BannedPeer>>,
+    },
 }
 
 impl<I> Display for NetworkInfoRequest<I>
@@ -197,6 +244,9 @@ where
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         match self {
             NetworkInfoRequest::GetPeers { responder: _ } => write!(formatter, "get peers"),
+            NetworkInfoRequest::GetBannedPeers { responder: _ } => {
+                write!(formatter, "get banned peers")
+            }
         }
     }
 }
@@ -340,6 +390,53 @@ pub enum StorageRequest {
         /// stored.
         responder: Responder<bool>,
     },
+    /// Store the fault tolerance with which a block was finalized.
+    PutBlockFinality {
+        /// The block's finality.
+        finality: Box<BlockFinality>,
+        /// Responder to call with the result, if true then the finality was successfully stored.
+        responder: Responder<bool>,
+    },
+    /// Get the fault tolerance with which the block at the given height was finalized.
+    GetBlockFinality {
+        /// The height of the block.
+        block_height: u64,
+        /// Responder to call with the result.
+        responder: Responder<Option<BlockFinality>>,
+    },
+    /// Store the validators' performance in an era.
+    PutEraValidatorPerformance {
+        /// The validators' performance.
+        performance: Box<EraValidatorPerformance>,
+        /// Responder to call with the result, if true then the report was successfully stored.
+        responder: Responder<bool>,
+    },
+    /// Get the validators' performance in the given era.
+    GetEraValidatorPerformance {
+        /// The era ID.
+        era_id: EraId,
+        /// Responder to call with the result.
+        responder: Responder<Option<EraValidatorPerformance>>,
+    },
+    /// Store the stakes and rewards of all validators and delegators in an era.
+    PutEraRewards {
+        /// The era's rewards.
+        era_rewards: Box<EraRewards>,
+        /// Responder to call with the result, if true then the rewards were successfully stored.
+        responder: Responder<bool>,
+    },
+    /// Get the stakes and rewards of the given validator or delegator in a range of eras.
+    GetEraRewardHistory {
+        /// The public key of the validator or delegator.
+        public_key: Box<PublicKey>,
+        /// The first era ID of the range.
+        from_era_id: EraId,
+        /// The last era ID of the range, inclusive.
+        to_era_id: EraId,
+        /// Responder to call with the result, one entry for each era in which the key was a
+        /// recipient.
+        responder: Responder<Vec<EraRewards>>,
+    },
 }
 
 impl Display for StorageRequest {
@@ -405,6 +502,35 @@ impl Display for StorageRequest {
             StorageRequest::PutBlockSignatures { .. } => {
                 write!(formatter, "put finality signatures")
             }
+            StorageRequest::PutBlockFinality { finality, .. } => {
+                write!(formatter, "put {}", finality)
+            }
+            StorageRequest::GetBlockFinality { block_height, .. } => {
+                write!(
+                    formatter,
+                    "get finality of block at height {}",
+                    block_height
+                )
+            }
+            StorageRequest::PutEraValidatorPerformance { performance, .. } => {
+                write!(formatter, "put {}", performance)
+            }
+            StorageRequest::GetEraValidatorPerformance { era_id, .. } => {
+                write!(formatter, "get validator performance in era {}", era_id)
+            }
+            StorageRequest::PutEraRewards { era_rewards, .. } => {
+                write!(formatter, "put {}", era_rewards)
+            }
+            StorageRequest::GetEraRewardHistory {
+                public_key,
+                from_era_id,
+                to_era_id,
+                ..
+            } => write!(
+                formatter,
+                "get rewards of {} in eras {} to {}",
+                public_key, from_era_id, to_era_id
+            ),
         }
     }
 }
@@ -520,6 +646,32 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Option<Vec<Transfer>>>,
     },
+    /// Return the fault tolerance with which the block at the given height was finalized, if
+    /// known.
+    GetBlockFinality {
+        /// The height of the block.
+        block_height: u64,
+        /// Responder to call with the result.
+        responder: Responder<Option<BlockFinality>>,
+    },
+    /// Return the validators' performance in the given era, if known.
+    GetEraValidatorPerformance {
+        /// The era ID.
+        era_id: EraId,
+        /// Responder to call with the result.
+        responder: Responder<Option<EraValidatorPerformance>>,
+    },
+    /// Return the stakes and rewards of the given validator or delegator in a range of eras.
+    GetEraRewardHistory {
+        /// The public key of the validator or delegator.
+        public_key: Box<PublicKey>,
+        /// The first era ID of the range.
+        from_era_id: EraId,
+        /// The last era ID of the range, inclusive.
+        to_era_id: EraId,
+        /// Responder to call with the result.
+        responder: Responder<Vec<EraRewards>>,
+    },
     /// Query the global state at the given root hash.
     QueryGlobalState {
         /// The state root hash.
@@ -575,6 +727,11 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<BTreeMap<I, String>>,
     },
+    /// Return the banned peers.
+    GetBannedPeers {
+        /// Responder to call with the result.
+        responder: Responder<Vec<BannedPeer>>,
+    },
     /// Return string formatted status or `None` if an error occurred.
     GetStatus {
         /// Responder to call with the result.
@@ -603,6 +760,22 @@ impl<I> Display for RpcRequest<I> {
             RpcRequest::GetBlockTransfers { block_hash, .. } => {
                 write!(formatter, "get transfers {}", block_hash)
             }
+            RpcRequest::GetBlockFinality { block_height, .. } => {
+                write!(formatter, "get finality {}", block_height)
+            }
+            RpcRequest::GetEraValidatorPerformance { era_id, .. } => {
+                write!(formatter, "get validator performance {}", era_id)
+            }
+            RpcRequest::GetEraRewardHistory {
+                public_key,
+                from_era_id,
+                to_era_id,
+                ..
+            } => write!(
+                formatter,
+                "get era reward history {} {} {}",
+                public_key, from_era_id, to_era_id
+            ),
             RpcRequest::QueryProtocolData {
                 protocol_version, ..
             } => write!(formatter, "protocol_version {}", protocol_version),
@@ -635,6 +808,7 @@ impl<I> Display for RpcRequest<I> {
             ),
             RpcRequest::GetDeploy { hash, .. } => write!(formatter, "get {}", hash),
             RpcRequest::GetPeers { .. } => write!(formatter, "get peers"),
+            RpcRequest::GetBannedPeers { .. } => write!(formatter, "get banned peers"),
             RpcRequest::GetStatus { .. } => write!(formatter, "get status"),
             RpcRequest::GetMetrics { .. } => write!(formatter, "get metrics"),
         }
@@ -658,6 +832,11 @@ pub enum RestRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Option<String>>,
     },
+    /// Return the banned peers.
+    GetBannedPeers {
+        /// Responder to call with the result.
+        responder: Responder<Vec<BannedPeer>>,
+    },
 }
 
 impl<I> Display for RestRequest<I> {
@@ -665,6 +844,7 @@ impl<I> Display for RestRequest<I> {
         match self {
             RestRequest::GetStatus { .. } => write!(formatter, "get status"),
             RestRequest::GetMetrics { .. } => write!(formatter, "get metrics"),
+            RestRequest::GetBannedPeers { .. } => write!(formatter, "get banned peers"),
         }
     }
 }
@@ -994,12 +1174,15 @@ pub enum ConsensusRequest {
 pub enum ChainspecLoaderRequest {
     /// Chainspec info request.
     GetChainspecInfo(Responder<ChainspecInfo>),
+    /// Request for the full chainspec.
+    GetChainspec(Responder<Arc<Chainspec>>),
 }
 
 impl Display for ChainspecLoaderRequest {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             ChainspecLoaderRequest::GetChainspecInfo(_) => write!(f, "get chainspec info"),
+            ChainspecLoaderRequest::GetChainspec(_) => write!(f, "get chainspec"),
         }
     }
 }
@@ -8,7 +8,10 @@ use hex_fmt::HexFmt;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    components::{consensus, gossiper, small_network::GossipedAddress},
+    components::{
+        consensus, gossiper,
+        small_network::{Classify, GossipedAddress, MessageClass},
+    },
     types::{Deploy, FinalitySignature, Item, Tag},
 };
 
@@ -59,6 +62,16 @@ impl Message {
     }
 }
 
+impl Classify for Message {
+    fn message_class(&self) -> MessageClass {
+        match self {
+            Message::Consensus(_) | Message::FinalitySignature(_) => MessageClass::Consensus,
+            Message::DeployGossiper(_) | Message::AddressGossiper(_) => MessageClass::Gossip,
+            Message::GetRequest { .. } | Message::GetResponse { .. } => MessageClass::Get,
+        }
+    }
+}
+
 impl Debug for Message {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
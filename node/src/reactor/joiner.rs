@@ -32,7 +32,7 @@ use crate::{
         block_executor::{self, BlockExecutor},
         block_validator::{self, BlockValidator},
         chainspec_loader::{self, ChainspecLoader},
-        consensus::{self, EraSupervisor, HighwayProtocol},
+        consensus::{self, EraSupervisor},
         contract_runtime::{self, ContractRuntime},
         deploy_acceptor::{self, DeployAcceptor},
         event_stream_server,
@@ -43,7 +43,7 @@ use crate::{
         metrics::Metrics,
         network::{self, Network, NetworkIdentity, ENABLE_LIBP2P_NET_ENV_VAR},
         rest_server::{self, RestServer},
-        small_network::{self, GossipedAddress, SmallNetwork, SmallNetworkIdentity},
+        small_network::{self, GossipedAddress, Misbehavior, SmallNetwork, SmallNetworkIdentity},
         storage::{self, Storage},
         Component,
     },
@@ -416,6 +416,7 @@ impl reactor::Reactor for Reactor {
             small_network_identity,
             network_name,
             false,
+            None,
         )?;
 
         let linear_chain_fetcher = Fetcher::new("linear_chain", config.fetcher, &registry)?;
@@ -516,7 +517,6 @@ impl reactor::Reactor for Reactor {
             chainspec_loader.chainspec().as_ref().into(),
             maybe_next_activation_point,
             registry,
-            Box::new(HighwayProtocol::new_boxed),
         )?;
         effects.extend(reactor::wrap_effects(
             Event::Consensus,
@@ -593,7 +593,12 @@ impl reactor::Reactor for Reactor {
                         Ok(block) => Box::new(block),
                         Err(err) => {
                             error!("failed to decode block from {}: {}", sender, err);
-                            return Effects::new();
+                            return effect_builder
+                                .report_misbehavior::<_, Message>(
+                                    sender,
+                                    Misbehavior::InvalidGetResponse,
+                                )
+                                .ignore();
                         }
                     };
                     let event = fetcher::Event::GotRemotely {
@@ -611,7 +616,12 @@ impl reactor::Reactor for Reactor {
                             Ok(maybe_block) => maybe_block,
                             Err(err) => {
                                 error!("failed to decode block from {}: {}", sender, err);
-                                return Effects::new();
+                                return effect_builder
+                                    .report_misbehavior::<_, Message>(
+                                        sender,
+                                        Misbehavior::InvalidGetResponse,
+                                    )
+                                    .ignore();
                             }
                         };
 
@@ -635,7 +645,12 @@ impl reactor::Reactor for Reactor {
                         Ok(deploy) => Box::new(deploy),
                         Err(err) => {
                             error!("failed to decode deploy from {}: {}", sender, err);
-                            return Effects::new();
+                            return effect_builder
+                                .report_misbehavior::<_, Message>(
+                                    sender,
+                                    Misbehavior::InvalidGetResponse,
+                                )
+                                .ignore();
                         }
                     };
                     let event = Event::DeployAcceptor(deploy_acceptor::Event::Accept {
@@ -678,7 +693,12 @@ impl reactor::Reactor for Reactor {
                 let deploy_hash = *deploy.id();
                 let peer = source;
                 warn!(?deploy_hash, ?peer, "Invalid deploy received from a peer.");
-                Effects::new()
+                match peer {
+                    Source::Peer(peer) => effect_builder
+                        .report_misbehavior::<_, Message>(peer, Misbehavior::InvalidDeploy)
+                        .ignore(),
+                    Source::Client => Effects::new(),
+                }
             }
             Event::Storage(event) => reactor::wrap_effects(
                 Event::Storage,
@@ -809,11 +829,9 @@ impl reactor::Reactor for Reactor {
                         },
                     ),
                 ),
-                ConsensusAnnouncement::DisconnectFromPeer(_peer) => {
-                    // TODO: handle the announcement and actually disconnect
-                    warn!("disconnecting from a given peer not yet implemented.");
-                    Effects::new()
-                }
+                ConsensusAnnouncement::DisconnectFromPeer(peer) => effect_builder
+                    .report_misbehavior::<_, Message>(peer, Misbehavior::InvalidConsensusMessage)
+                    .ignore(),
             },
             Event::BlockProposerRequest(request) => {
                 // Consensus component should not be trying to create new blocks during joining
@@ -843,12 +861,17 @@ impl reactor::Reactor for Reactor {
             Event::LinearChainAnnouncement(LinearChainAnnouncement::BlockAdded {
                 block_hash,
                 block,
+                finality,
             }) => reactor::wrap_effects(
                 Event::EventStreamServer,
                 self.event_stream_server.handle_event(
                     effect_builder,
                     rng,
-                    event_stream_server::Event::BlockAdded { block_hash, block },
+                    event_stream_server::Event::BlockAdded {
+                        block_hash,
+                        block,
+                        finality,
+                    },
                 ),
             ),
             Event::LinearChainAnnouncement(LinearChainAnnouncement::NewFinalitySignature(fs)) => {
@@ -40,7 +40,9 @@ use crate::{
         network::{self, Network, NetworkIdentity, ENABLE_LIBP2P_NET_ENV_VAR},
         rest_server::{self, RestServer},
         rpc_server::{self, RpcServer},
-        small_network::{self, GossipedAddress, SmallNetwork, SmallNetworkIdentity},
+        small_network::{
+            self, GossipedAddress, Misbehavior, SmallNetwork, SmallNetworkIdentity, ValidatorProof,
+        },
         storage::{self, Storage},
         Component,
     },
@@ -60,7 +62,7 @@ use crate::{
     },
     protocol::Message,
     reactor::{self, event_queue_metrics::EventQueueMetrics, EventQueueHandle, ReactorExit},
-    types::{Block, Deploy, ExitCode, NodeId, ProtoBlock, Tag, TimeDiff, Timestamp},
+    types::{Block, Deploy, DeployExt, ExitCode, NodeId, ProtoBlock, Tag, TimeDiff, Timestamp},
     utils::Source,
     NodeRng,
 };
@@ -407,6 +409,11 @@ impl reactor::Reactor for Reactor {
             true,
         )?;
         let network_name = chainspec_loader.chainspec().network_config.name.clone();
+        let validator_proof = ValidatorProof::new(
+            &NodeId::from(&small_network_identity),
+            consensus.secret_signing_key(),
+            rng,
+        );
         let (small_network, small_network_effects) = SmallNetwork::new(
             event_queue,
             config.network,
@@ -414,6 +421,7 @@ impl reactor::Reactor for Reactor {
             small_network_identity,
             network_name,
             true,
+            Some(validator_proof),
         )?;
 
         let address_gossiper =
@@ -448,6 +456,7 @@ impl reactor::Reactor for Reactor {
                 .map(|block| block.height() + 1)
                 .unwrap_or(0),
             chainspec_loader.chainspec().as_ref(),
+            config.block_proposer,
         )?;
         let mut effects = reactor::wrap_effects(Event::BlockProposer, block_proposer_effects);
         let block_executor = BlockExecutor::new(
@@ -465,6 +474,12 @@ impl reactor::Reactor for Reactor {
             Event::SmallNetwork,
             small_network_effects,
         ));
+        // Let the small network prioritize the validators of the active eras.
+        effects.extend(
+            effect_builder
+                .set_network_validators::<NodeId, Message>(consensus.active_validators())
+                .ignore(),
+        );
         // This is a workaround for dropping the Era Supervisor's timer event when transitioning
         // from the joiner.
         // TODO: Remove this once the consensus component is removed from the Joiner reactor.
@@ -746,7 +761,12 @@ impl reactor::Reactor for Reactor {
                                 Ok(deploy) => Box::new(deploy),
                                 Err(error) => {
                                     error!("failed to decode deploy from {}: {}", sender, error);
-                                    return Effects::new();
+                                    return effect_builder
+                                        .report_misbehavior::<_, Message>(
+                                            sender,
+                                            Misbehavior::InvalidGetResponse,
+                                        )
+                                        .ignore();
                                 }
                             };
                             Event::DeployAcceptor(deploy_acceptor::Event::Accept {
@@ -831,8 +851,13 @@ impl reactor::Reactor for Reactor {
             }
             Event::DeployAcceptorAnnouncement(DeployAcceptorAnnouncement::InvalidDeploy {
                 deploy: _,
-                source: _,
-            }) => Effects::new(),
+                source,
+            }) => match source {
+                Source::Peer(peer) => effect_builder
+                    .report_misbehavior::<_, Message>(peer, Misbehavior::InvalidDeploy)
+                    .ignore(),
+                Source::Client => Effects::new(),
+            },
             Event::ConsensusAnnouncement(consensus_announcement) => {
                 match consensus_announcement {
                     ConsensusAnnouncement::Finalized(block) => {
@@ -865,10 +890,12 @@ impl reactor::Reactor for Reactor {
                             });
                         self.dispatch_event(effect_builder, rng, reactor_event)
                     }
-                    ConsensusAnnouncement::DisconnectFromPeer(_peer) => {
-                        // TODO: handle the announcement and actually disconnect
-                        warn!("Disconnecting from a given peer not yet implemented.");
-                        Effects::new()
+                    ConsensusAnnouncement::DisconnectFromPeer(peer) => {
+                        // Consensus asks to disconnect from peers that sent invalid messages.
+                        let misbehavior = Misbehavior::InvalidConsensusMessage;
+                        effect_builder
+                            .report_misbehavior::<_, Message>(peer, misbehavior)
+                            .ignore()
                     }
                 }
             }
@@ -916,13 +943,27 @@ impl reactor::Reactor for Reactor {
             Event::LinearChainAnnouncement(LinearChainAnnouncement::BlockAdded {
                 block_hash,
                 block,
+                finality,
             }) => {
+                let mut effects = Effects::new();
+                if let Some(next_era_validators) = block.header().next_era_validator_weights() {
+                    // A switch block: prioritize the upcoming era's validators, too.
+                    let mut validators = self.consensus.active_validators();
+                    validators.extend(next_era_validators.keys().cloned());
+                    effects.extend(
+                        effect_builder
+                            .set_network_validators::<NodeId, Message>(validators)
+                            .ignore(),
+                    );
+                }
                 let reactor_event =
                     Event::EventStreamServer(event_stream_server::Event::BlockAdded {
                         block_hash,
                         block,
+                        finality,
                     });
-                self.dispatch_event(effect_builder, rng, reactor_event)
+                effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
+                effects
             }
             Event::LinearChainAnnouncement(LinearChainAnnouncement::NewFinalitySignature(fs)) => {
                 let reactor_event =
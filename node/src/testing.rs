@@ -6,7 +6,6 @@
 mod condition_check_reactor;
 mod multi_stage_test_reactor;
 pub mod network;
-mod test_rng;
 
 use std::{
     any::type_name,
@@ -28,9 +27,9 @@ use crate::{
     reactor::{EventQueueHandle, QueueKind, Scheduler},
 };
 use anyhow::Context;
+pub(crate) use casper_node_types::testing::TestRng;
 pub(crate) use condition_check_reactor::ConditionCheckReactor;
 pub(crate) use multi_stage_test_reactor::MultiStageTestReactor;
-pub(crate) use test_rng::TestRng;
 
 // Lower bound for the port, below there's a high chance of hitting a system service.
 const PORT_LOWER_BOUND: u16 = 10_000;
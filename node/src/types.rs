@@ -3,6 +3,7 @@
 mod block;
 pub mod chainspec;
 mod deploy;
+mod era_rewards;
 mod exit_code;
 mod item;
 pub mod json_compatibility;
@@ -11,29 +12,33 @@ mod node_id;
 mod peers_map;
 mod status_feed;
 mod timestamp;
+mod validator_performance;
 
 use rand::{CryptoRng, RngCore};
 #[cfg(not(test))]
 use rand_chacha::ChaCha20Rng;
 
+pub use casper_node_types::{
+    Approval, BlockHash, Deploy, DeployError, DeployHash, DeployHeader, DeployValidationFailure,
+    TimeDiff, Timestamp,
+};
+
 pub use block::{
-    json_compatibility::JsonBlock, Block, BlockBody, BlockHash, BlockHeader, BlockSignatures,
-    BlockValidationError, FinalitySignature,
+    Block, BlockBody, BlockFinality, BlockHeader, BlockSignatures, BlockValidationError,
+    FinalitySignature,
 };
 pub(crate) use block::{BlockByHeight, BlockLike, FinalizedBlock, ProtoBlock};
 pub(crate) use chainspec::ActivationPoint;
 pub use chainspec::Chainspec;
-pub use deploy::{
-    Approval, Deploy, DeployHash, DeployHeader, DeployMetadata, DeployValidationFailure,
-    Error as DeployError,
-};
+pub use deploy::{DeployExt, DeployHeaderExt, DeployMetadata};
+pub use era_rewards::{EraReward, EraRewards};
 pub use exit_code::ExitCode;
 pub use item::{Item, Tag};
 pub use node_config::NodeConfig;
 pub(crate) use node_id::NodeId;
-pub use peers_map::PeersMap;
-pub use status_feed::{ChainspecInfo, GetStatusResult, StatusFeed};
-pub use timestamp::{TimeDiff, Timestamp};
+pub(crate) use peers_map::peer_entries;
+pub use status_feed::{ChainspecInfo, StatusFeed};
+pub use validator_performance::{EraValidatorPerformance, ValidatorPerformance};
 
 /// An object-safe RNG trait that requires a cryptographically strong random number generator.
 pub trait CryptoRngCore: CryptoRng + RngCore {}
@@ -27,6 +27,7 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use casper_rpc_client::{rpcs::chain as rpc_chain, types::Block as JsonBlock};
 #[cfg(test)]
 use casper_types::system::auction::BLOCK_REWARD;
 use casper_types::{
@@ -45,7 +46,7 @@ use crate::{
         AsymmetricKeyExt,
     },
     rpcs::docs::DocExample,
-    types::{Deploy, DeployHash, JsonBlock, NodeRng},
+    types::{BlockHash, Deploy, DeployHash, NodeRng},
     utils::DisplayIter,
 };
 
@@ -152,7 +153,19 @@ static JSON_BLOCK: Lazy<JsonBlock> = Lazy::new(|| {
     let signature = crypto::sign(block.hash.inner(), &secret_key, &public_key, &mut rng);
     block_signature.insert_proof(public_key, signature);
 
-    JsonBlock::new(block, block_signature)
+    block.into_json(block_signature)
+});
+
+static BLOCK_FINALITY: Lazy<BlockFinality> = Lazy::new(|| {
+    let header = Block::doc_example().header();
+    let public_key = PublicKey::from(SecretKey::doc_example());
+    BlockFinality::new(
+        header.height(),
+        header.era_id(),
+        1,
+        500_000,
+        vec![public_key],
+    )
 });
 
 /// Error returned from constructing or validating a `Block`.
@@ -535,79 +548,6 @@ impl Display for FinalizedBlock {
     }
 }
 
-/// A cryptographic hash identifying a [`Block`](struct.Block.html).
-#[derive(
-    Copy,
-    Clone,
-    DataSize,
-    Ord,
-    PartialOrd,
-    Eq,
-    PartialEq,
-    Hash,
-    Serialize,
-    Deserialize,
-    Debug,
-    JsonSchema,
-)]
-#[serde(deny_unknown_fields)]
-pub struct BlockHash(Digest);
-
-impl BlockHash {
-    /// Constructs a new `BlockHash`.
-    pub fn new(hash: Digest) -> Self {
-        BlockHash(hash)
-    }
-
-    /// Returns the wrapped inner hash.
-    pub fn inner(&self) -> &Digest {
-        &self.0
-    }
-
-    /// Creates a random block hash.
-    #[cfg(test)]
-    pub fn random(rng: &mut TestRng) -> Self {
-        let hash = Digest::random(rng);
-        BlockHash(hash)
-    }
-}
-
-impl Display for BlockHash {
-    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
-        write!(formatter, "block-hash({})", self.0,)
-    }
-}
-
-impl From<Digest> for BlockHash {
-    fn from(digest: Digest) -> Self {
-        Self(digest)
-    }
-}
-
-impl AsRef<[u8]> for BlockHash {
-    fn as_ref(&self) -> &[u8] {
-        self.0.as_ref()
-    }
-}
-
-impl ToBytes for BlockHash {
-    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
-        self.0.to_bytes()
-    }
-
-    fn serialized_length(&self) -> usize {
-        self.0.serialized_length()
-    }
-}
-
-impl FromBytes for BlockHash {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (hash, remainder) = Digest::from_bytes(bytes)?;
-        let block_hash = BlockHash(hash);
-        Ok((block_hash, remainder))
-    }
-}
-
 #[derive(Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize, Debug)]
 /// A struct to contain information related to the end of an era and validator weights for the
 /// following era.
@@ -975,6 +915,8 @@ pub struct BlockSignatures {
     pub(crate) era_id: EraId,
     /// The signatures associated with the block hash.
     pub(crate) proofs: BTreeMap<PublicKey, Signature>,
+    /// The fault tolerance with which this node observed the block's finality, if known.
+    pub(crate) finality: Option<BlockFinality>,
 }
 
 impl BlockSignatures {
@@ -983,6 +925,7 @@ impl BlockSignatures {
             block_hash,
             era_id,
             proofs: BTreeMap::new(),
+            finality: None,
         }
     }
 
@@ -1011,6 +954,78 @@ impl Display for BlockSignatures {
     }
 }
 
+/// The fault tolerance with which a block was finalized, as observed by this node.
+///
+/// Unlike the finality signatures, this is local information: Other nodes may have detected the
+/// block's finality at a different level.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BlockFinality {
+    /// The height of the finalized block.
+    pub(crate) height: u64,
+    /// The era in which the block was finalized.
+    pub(crate) era_id: EraId,
+    /// The level of the summit that finalized the block. Protocols with immediate finality use 1.
+    pub(crate) summit_level: u64,
+    /// The achieved fault tolerance threshold, in parts per million of the total validator
+    /// weight: The block can only be reverted if validators with at least this much weight
+    /// equivocate.
+    pub(crate) fault_tolerance_ppm: u64,
+    /// The validators whose units or votes contributed to the summit.
+    pub(crate) summit_contributors: Vec<PublicKey>,
+}
+
+impl BlockFinality {
+    pub(crate) fn new(
+        height: u64,
+        era_id: EraId,
+        summit_level: u64,
+        fault_tolerance_ppm: u64,
+        summit_contributors: Vec<PublicKey>,
+    ) -> Self {
+        BlockFinality {
+            height,
+            era_id,
+            summit_level,
+            fault_tolerance_ppm,
+            summit_contributors,
+        }
+    }
+
+    /// Returns the height of the finalized block.
+    pub(crate) fn height(&self) -> u64 {
+        self.height
+    }
+}
+
+impl Display for BlockFinality {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "finality of block at height {} in era {}: summit level {}, fault tolerance {} ppm",
+            self.height, self.era_id, self.summit_level, self.fault_tolerance_ppm
+        )
+    }
+}
+
+impl DocExample for BlockFinality {
+    fn doc_example() -> &'static Self {
+        &*BLOCK_FINALITY
+    }
+}
+
+impl From<BlockFinality> for rpc_chain::BlockFinality {
+    fn from(finality: BlockFinality) -> Self {
+        rpc_chain::BlockFinality {
+            height: finality.height,
+            era_id: finality.era_id.0,
+            summit_level: finality.summit_level,
+            fault_tolerance_ppm: finality.fault_tolerance_ppm,
+            summit_contributors: finality.summit_contributors,
+        }
+    }
+}
+
 /// A proto-block after execution, with the resulting post-state-hash.  This is the core component
 /// of the Casper linear blockchain.
 #[derive(DataSize, Clone, Debug, PartialOrd, Ord, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -1301,47 +1316,26 @@ impl Item for BlockByHeight {
 }
 
 pub(crate) mod json_compatibility {
-    use super::*;
+    use casper_rpc_client::types as json;
 
-    #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
-    #[serde(deny_unknown_fields)]
-    struct Reward {
-        validator: PublicKey,
-        amount: u64,
-    }
-
-    #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
-    #[serde(deny_unknown_fields)]
-    struct ValidatorWeight {
-        validator: PublicKey,
-        weight: U512,
-    }
-
-    /// Equivocation and reward information to be included in the terminal block.
-    #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
-    #[serde(deny_unknown_fields)]
-    struct JsonEraReport {
-        equivocators: Vec<PublicKey>,
-        rewards: Vec<Reward>,
-        inactive_validators: Vec<PublicKey>,
-    }
+    use super::*;
 
-    impl From<EraReport> for JsonEraReport {
+    impl From<EraReport> for json::EraReport {
         fn from(era_report: EraReport) -> Self {
-            JsonEraReport {
+            json::EraReport {
                 equivocators: era_report.equivocators,
                 rewards: era_report
                     .rewards
                     .into_iter()
-                    .map(|(validator, amount)| Reward { validator, amount })
+                    .map(|(validator, amount)| json::Reward { validator, amount })
                     .collect(),
                 inactive_validators: era_report.inactive_validators,
             }
         }
     }
 
-    impl From<JsonEraReport> for EraReport {
-        fn from(era_report: JsonEraReport) -> Self {
+    impl From<json::EraReport> for EraReport {
+        fn from(era_report: json::EraReport) -> Self {
             let equivocators = era_report.equivocators;
             let rewards = era_report
                 .rewards
@@ -1357,33 +1351,26 @@ pub(crate) mod json_compatibility {
         }
     }
 
-    #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
-    #[serde(deny_unknown_fields)]
-    struct JsonEraEnd {
-        era_report: JsonEraReport,
-        next_era_validator_weights: Vec<ValidatorWeight>,
-    }
-
-    impl From<EraEnd> for JsonEraEnd {
+    impl From<EraEnd> for json::EraEnd {
         fn from(data: EraEnd) -> Self {
-            let json_era_end = JsonEraReport::from(data.era_report);
+            let json_era_end = json::EraReport::from(data.era_report);
             let json_validator_weights = data
                 .next_era_validator_weights
                 .iter()
-                .map(|(validator, weight)| ValidatorWeight {
+                .map(|(validator, weight)| json::ValidatorWeight {
                     validator: *validator,
                     weight: *weight,
                 })
                 .collect();
-            JsonEraEnd {
+            json::EraEnd {
                 era_report: json_era_end,
                 next_era_validator_weights: json_validator_weights,
             }
         }
     }
 
-    impl From<JsonEraEnd> for EraEnd {
-        fn from(json_data: JsonEraEnd) -> Self {
+    impl From<json::EraEnd> for EraEnd {
+        fn from(json_data: json::EraEnd) -> Self {
             let era_report = EraReport::from(json_data.era_report);
             let validator_weights = json_data
                 .next_era_validator_weights
@@ -1394,40 +1381,25 @@ pub(crate) mod json_compatibility {
         }
     }
 
-    #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
-    #[serde(deny_unknown_fields)]
-    struct JsonBlockHeader {
-        parent_hash: BlockHash,
-        state_root_hash: Digest,
-        body_hash: Digest,
-        random_bit: bool,
-        accumulated_seed: Digest,
-        era_end: Option<JsonEraEnd>,
-        timestamp: Timestamp,
-        era_id: EraId,
-        height: u64,
-        protocol_version: ProtocolVersion,
-    }
-
-    impl From<BlockHeader> for JsonBlockHeader {
+    impl From<BlockHeader> for json::BlockHeader {
         fn from(block_header: BlockHeader) -> Self {
-            JsonBlockHeader {
+            json::BlockHeader {
                 parent_hash: block_header.parent_hash,
                 state_root_hash: block_header.state_root_hash,
                 body_hash: block_header.body_hash,
                 random_bit: block_header.random_bit,
                 accumulated_seed: block_header.accumulated_seed,
-                era_end: block_header.era_end.map(JsonEraEnd::from),
+                era_end: block_header.era_end.map(json::EraEnd::from),
                 timestamp: block_header.timestamp,
-                era_id: block_header.era_id,
+                era_id: block_header.era_id.0,
                 height: block_header.height,
                 protocol_version: block_header.protocol_version,
             }
         }
     }
 
-    impl From<JsonBlockHeader> for BlockHeader {
-        fn from(block_header: JsonBlockHeader) -> Self {
+    impl From<json::BlockHeader> for BlockHeader {
+        fn from(block_header: json::BlockHeader) -> Self {
             BlockHeader {
                 parent_hash: block_header.parent_hash,
                 state_root_hash: block_header.state_root_hash,
@@ -1436,34 +1408,25 @@ pub(crate) mod json_compatibility {
                 accumulated_seed: block_header.accumulated_seed,
                 era_end: block_header.era_end.map(EraEnd::from),
                 timestamp: block_header.timestamp,
-                era_id: block_header.era_id,
+                era_id: EraId(block_header.era_id),
                 height: block_header.height,
                 protocol_version: block_header.protocol_version,
             }
         }
     }
 
-    /// A JSON-friendly representation of `Body`
-    #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
-    #[serde(deny_unknown_fields)]
-    pub struct JsonBlockBody {
-        proposer: PublicKey,
-        deploy_hashes: Vec<DeployHash>,
-        transfer_hashes: Vec<DeployHash>,
-    }
-
-    impl From<BlockBody> for JsonBlockBody {
+    impl From<BlockBody> for json::BlockBody {
         fn from(body: BlockBody) -> Self {
-            JsonBlockBody {
-                proposer: *body.proposer(),
-                deploy_hashes: body.deploy_hashes().clone(),
-                transfer_hashes: body.transfer_hashes().clone(),
+            json::BlockBody {
+                proposer: body.proposer,
+                deploy_hashes: body.deploy_hashes,
+                transfer_hashes: body.transfer_hashes,
             }
         }
     }
 
-    impl From<JsonBlockBody> for BlockBody {
-        fn from(json_body: JsonBlockBody) -> Self {
+    impl From<json::BlockBody> for BlockBody {
+        fn from(json_body: json::BlockBody) -> Self {
             BlockBody {
                 proposer: json_body.proposer,
                 deploy_hashes: json_body.deploy_hashes,
@@ -1472,51 +1435,33 @@ pub(crate) mod json_compatibility {
         }
     }
 
-    /// A JSON-friendly representation of `Block`.
-    #[derive(Serialize, Deserialize, Debug, JsonSchema, Clone)]
-    #[serde(deny_unknown_fields)]
-    pub struct JsonBlock {
-        hash: BlockHash,
-        header: JsonBlockHeader,
-        body: JsonBlockBody,
-        proofs: Vec<JsonProof>,
-    }
-
-    impl JsonBlock {
-        /// Create a new JSON Block with a Linear chain block and its associated signatures.
-        pub fn new(block: Block, signatures: BlockSignatures) -> Self {
-            let hash = *block.hash();
-            let header = JsonBlockHeader::from(block.header.clone());
-            let body = JsonBlockBody::from(block.body);
-            let proofs = signatures.proofs.into_iter().map(JsonProof::from).collect();
-
-            JsonBlock {
-                hash,
-                header,
-                body,
-                proofs,
+    impl Block {
+        /// Returns the JSON representation of the block along with its finality signatures.
+        pub fn into_json(self, signatures: BlockSignatures) -> json::Block {
+            json::Block {
+                hash: self.hash,
+                header: json::BlockHeader::from(self.header),
+                body: json::BlockBody::from(self.body),
+                proofs: signatures
+                    .proofs
+                    .into_iter()
+                    .map(|(public_key, signature)| json::Proof {
+                        public_key,
+                        signature,
+                    })
+                    .collect(),
             }
         }
-
-        /// Returns the hashes of the `Deploy`s included in the `Block`.
-        pub fn deploy_hashes(&self) -> &Vec<DeployHash> {
-            &self.body.deploy_hashes
-        }
-
-        /// Returns the hashes of the transfer `Deploy`s included in the `Block`.
-        pub fn transfer_hashes(&self) -> &Vec<DeployHash> {
-            &self.body.transfer_hashes
-        }
     }
 
-    impl DocExample for JsonBlock {
+    impl DocExample for json::Block {
         fn doc_example() -> &'static Self {
             &*JSON_BLOCK
         }
     }
 
-    impl From<JsonBlock> for Block {
-        fn from(block: JsonBlock) -> Self {
+    impl From<json::Block> for Block {
+        fn from(block: json::Block) -> Self {
             Block {
                 hash: block.hash,
                 header: BlockHeader::from(block.header),
@@ -1525,35 +1470,12 @@ pub(crate) mod json_compatibility {
         }
     }
 
-    /// A JSON-friendly representation of a proof, i.e. a block's finality signature.
-    #[derive(Debug, Serialize, Deserialize, JsonSchema, Clone)]
-    #[serde(deny_unknown_fields)]
-    pub struct JsonProof {
-        public_key: PublicKey,
-        signature: Signature,
-    }
-
-    impl From<(PublicKey, Signature)> for JsonProof {
-        fn from((public_key, signature): (PublicKey, Signature)) -> JsonProof {
-            JsonProof {
-                public_key,
-                signature,
-            }
-        }
-    }
-
-    impl From<JsonProof> for (PublicKey, Signature) {
-        fn from(proof: JsonProof) -> (PublicKey, Signature) {
-            (proof.public_key, proof.signature)
-        }
-    }
-
     #[test]
     fn block_json_roundtrip() {
         let mut rng = TestRng::new();
         let block: Block = Block::random(&mut rng);
         let empty_signatures = BlockSignatures::new(*block.hash(), block.header().era_id);
-        let json_block = JsonBlock::new(block.clone(), empty_signatures);
+        let json_block = block.clone().into_json(empty_signatures);
         let block_deserialized = Block::from(json_block);
         assert_eq!(block, block_deserialized);
     }
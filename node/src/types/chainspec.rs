@@ -28,11 +28,14 @@ use casper_types::bytesrepr::{self, FromBytes, ToBytes};
 #[cfg(test)]
 pub(crate) use self::accounts_config::{AccountConfig, ValidatorConfig};
 pub(crate) use self::{
-    accounts_config::AccountsConfig, core_config::CoreConfig, deploy_config::DeployConfig,
-    global_state_update::GlobalStateUpdate, highway_config::HighwayConfig,
-    network_config::NetworkConfig, protocol_config::ProtocolConfig,
+    accounts_config::AccountsConfig,
+    core_config::{ConsensusProtocolName, CoreConfig},
+    global_state_update::GlobalStateUpdate,
+    highway_config::HighwayConfig,
+    network_config::NetworkConfig,
+    protocol_config::ProtocolConfig,
 };
-pub use self::{error::Error, protocol_config::ActivationPoint};
+pub use self::{deploy_config::DeployConfig, error::Error, protocol_config::ActivationPoint};
 #[cfg(test)]
 use crate::testing::TestRng;
 use crate::{
@@ -78,6 +81,16 @@ impl Chainspec {
         }
 
         self.highway_config.validate_config();
+
+        let payment_split = self.system_costs_config.payment_split();
+        if !payment_split.is_valid() {
+            panic!(
+                "Payment split ratios are not in the range [0, 1]! Refund ratio: {refund}, burn \
+                 ratio: {burn}",
+                refund = payment_split.refund_ratio,
+                burn = payment_split.burn_ratio
+            );
+        }
     }
 
     /// Serializes `self` and hashes the resulting bytes.
@@ -184,6 +197,9 @@ impl From<&Chainspec> for ExecConfig {
             chainspec.core_config.locked_funds_period.millis(),
             chainspec.core_config.round_seigniorage_rate,
             chainspec.core_config.unbonding_delay,
+            chainspec.core_config.minimum_delegation_amount,
+            chainspec.core_config.max_delegators_per_validator,
+            chainspec.core_config.max_delegation_rate_increase,
             chainspec.network_config.timestamp.millis(),
         )
     }
@@ -313,6 +329,14 @@ mod tests {
 
         assert_eq!(spec.core_config.era_duration, TimeDiff::from(180000));
         assert_eq!(spec.core_config.minimum_era_height, 9);
+        assert_eq!(spec.core_config.minimum_delegation_amount, 500_000);
+        assert_eq!(spec.core_config.max_delegators_per_validator, 99);
+        assert_eq!(spec.core_config.max_delegation_rate_increase, 7);
+        // The 0.9.0 chainspec predates the `consensus_protocol` key, so must default to Highway.
+        assert_eq!(
+            spec.core_config.consensus_protocol,
+            ConsensusProtocolName::Highway
+        );
         assert_eq!(
             spec.highway_config.finality_threshold_fraction,
             Ratio::new(2, 25)
@@ -335,6 +359,10 @@ mod tests {
         assert_eq!(spec.deploy_config.block_gas_limit, 13);
 
         assert_eq!(spec.wasm_config, *EXPECTED_GENESIS_WASM_COSTS);
+
+        let payment_split = spec.system_costs_config.payment_split();
+        assert_eq!(payment_split.refund_ratio, Ratio::new(1, 2));
+        assert_eq!(payment_split.burn_ratio, Ratio::new(1, 4));
     }
 
     #[test]
@@ -4,7 +4,10 @@ use num::rational::Ratio;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::DelegationRate,
+};
 
 #[cfg(test)]
 use crate::testing::TestRng;
@@ -25,9 +28,62 @@ pub struct CoreConfig {
     pub(crate) locked_funds_period: TimeDiff,
     /// The delay in number of eras for paying out the the unbonding amount.
     pub(crate) unbonding_delay: u64,
+    /// The minimum amount of motes a delegator must have delegated to a validator.
+    pub(crate) minimum_delegation_amount: u64,
+    /// The maximum number of delegators a single validator can have.
+    pub(crate) max_delegators_per_validator: u32,
+    /// The maximum number of percentage points a validator's delegation rate can be increased by
+    /// per era.
+    pub(crate) max_delegation_rate_increase: DelegationRate,
     /// Round seigniorage rate represented as a fractional number.
     #[data_size(skip)]
     pub(crate) round_seigniorage_rate: Ratio<u64>,
+    /// The consensus protocol run in each era.  Chainspecs which predate the choice run Highway.
+    #[serde(default)]
+    pub(crate) consensus_protocol: ConsensusProtocolName,
+}
+
+/// The consensus protocol a network runs.
+#[derive(Copy, Clone, DataSize, PartialEq, Eq, Serialize, Deserialize, Debug)]
+pub enum ConsensusProtocolName {
+    /// Highway: DAG-based, with finality fault tolerance thresholds configured in the chainspec.
+    Highway,
+    /// A simple round-based BFT protocol with immediate finality, for small permissioned networks.
+    SimpleBft,
+}
+
+impl Default for ConsensusProtocolName {
+    fn default() -> Self {
+        ConsensusProtocolName::Highway
+    }
+}
+
+const HIGHWAY_TAG: u8 = 0;
+const SIMPLE_BFT_TAG: u8 = 1;
+
+impl ToBytes for ConsensusProtocolName {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let tag = match self {
+            ConsensusProtocolName::Highway => HIGHWAY_TAG,
+            ConsensusProtocolName::SimpleBft => SIMPLE_BFT_TAG,
+        };
+        Ok(vec![tag])
+    }
+
+    fn serialized_length(&self) -> usize {
+        bytesrepr::U8_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for ConsensusProtocolName {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            HIGHWAY_TAG => Ok((ConsensusProtocolName::Highway, remainder)),
+            SIMPLE_BFT_TAG => Ok((ConsensusProtocolName::SimpleBft, remainder)),
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -40,10 +96,18 @@ impl CoreConfig {
         let auction_delay = rng.gen::<u32>() as u64;
         let locked_funds_period = TimeDiff::from(rng.gen_range(600_000, 604_800_000));
         let unbonding_delay = rng.gen_range(1, 1_000_000_000);
+        let minimum_delegation_amount = rng.gen();
+        let max_delegators_per_validator = rng.gen();
+        let max_delegation_rate_increase = rng.gen();
         let round_seigniorage_rate = Ratio::new(
             rng.gen_range(1, 1_000_000_000),
             rng.gen_range(1, 1_000_000_000),
         );
+        let consensus_protocol = if rng.gen() {
+            ConsensusProtocolName::Highway
+        } else {
+            ConsensusProtocolName::SimpleBft
+        };
 
         CoreConfig {
             era_duration,
@@ -52,7 +116,11 @@ impl CoreConfig {
             auction_delay,
             locked_funds_period,
             unbonding_delay,
+            minimum_delegation_amount,
+            max_delegators_per_validator,
+            max_delegation_rate_increase,
             round_seigniorage_rate,
+            consensus_protocol,
         }
     }
 }
@@ -66,7 +134,11 @@ impl ToBytes for CoreConfig {
         buffer.extend(self.auction_delay.to_bytes()?);
         buffer.extend(self.locked_funds_period.to_bytes()?);
         buffer.extend(self.unbonding_delay.to_bytes()?);
+        buffer.extend(self.minimum_delegation_amount.to_bytes()?);
+        buffer.extend(self.max_delegators_per_validator.to_bytes()?);
+        buffer.extend(self.max_delegation_rate_increase.to_bytes()?);
         buffer.extend(self.round_seigniorage_rate.to_bytes()?);
+        buffer.extend(self.consensus_protocol.to_bytes()?);
         Ok(buffer)
     }
 
@@ -77,7 +149,11 @@ impl ToBytes for CoreConfig {
             + self.auction_delay.serialized_length()
             + self.locked_funds_period.serialized_length()
             + self.unbonding_delay.serialized_length()
+            + self.minimum_delegation_amount.serialized_length()
+            + self.max_delegators_per_validator.serialized_length()
+            + self.max_delegation_rate_increase.serialized_length()
             + self.round_seigniorage_rate.serialized_length()
+            + self.consensus_protocol.serialized_length()
     }
 }
 
@@ -89,7 +165,11 @@ impl FromBytes for CoreConfig {
         let (auction_delay, remainder) = u64::from_bytes(remainder)?;
         let (locked_funds_period, remainder) = TimeDiff::from_bytes(remainder)?;
         let (unbonding_delay, remainder) = u64::from_bytes(remainder)?;
+        let (minimum_delegation_amount, remainder) = u64::from_bytes(remainder)?;
+        let (max_delegators_per_validator, remainder) = u32::from_bytes(remainder)?;
+        let (max_delegation_rate_increase, remainder) = DelegationRate::from_bytes(remainder)?;
         let (round_seigniorage_rate, remainder) = Ratio::<u64>::from_bytes(remainder)?;
+        let (consensus_protocol, remainder) = ConsensusProtocolName::from_bytes(remainder)?;
         let config = CoreConfig {
             era_duration,
             minimum_era_height,
@@ -97,7 +177,11 @@ impl FromBytes for CoreConfig {
             auction_delay,
             locked_funds_period,
             unbonding_delay,
+            minimum_delegation_amount,
+            max_delegators_per_validator,
+            max_delegation_rate_increase,
             round_seigniorage_rate,
+            consensus_protocol,
         };
         Ok((config, remainder))
     }
@@ -122,4 +206,20 @@ mod tests {
         let decoded = toml::from_str(&encoded).unwrap();
         assert_eq!(config, decoded);
     }
+
+    #[test]
+    fn should_default_to_highway_if_consensus_protocol_missing() {
+        let mut rng = crate::new_rng();
+        let config = CoreConfig::random(&mut rng);
+        let encoded = toml::to_string_pretty(&config).unwrap();
+        let legacy: String = encoded
+            .lines()
+            .filter(|line| !line.starts_with("consensus_protocol"))
+            .map(|line| format!("{}\n", line))
+            .collect();
+        assert_ne!(encoded, legacy);
+
+        let decoded: CoreConfig = toml::from_str(&legacy).unwrap();
+        assert_eq!(decoded.consensus_protocol, ConsensusProtocolName::Highway);
+    }
 }
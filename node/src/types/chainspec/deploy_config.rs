@@ -4,13 +4,16 @@ use std::str::FromStr;
 use datasize::DataSize;
 #[cfg(test)]
 use num_traits::Zero;
+use once_cell::sync::Lazy;
 #[cfg(test)]
 use rand::Rng;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 #[cfg(test)]
 use casper_execution_engine::core::engine_state::MAX_PAYMENT_AMOUNT;
 use casper_execution_engine::shared::motes::Motes;
+use casper_rpc_client::rpcs::info as rpc_info;
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
     U512,
@@ -18,12 +21,27 @@ use casper_types::{
 
 #[cfg(test)]
 use crate::testing::TestRng;
-use crate::types::TimeDiff;
+use crate::{components::rpc_server::rpcs::docs::DocExample, types::TimeDiff};
 
-#[derive(Copy, Clone, DataSize, PartialEq, Eq, Serialize, Deserialize, Debug)]
+static DEPLOY_CONFIG: Lazy<DeployConfig> = Lazy::new(|| DeployConfig {
+    max_payment_cost: Motes::new(U512::zero()),
+    max_ttl: TimeDiff::from(86_400_000),
+    max_dependencies: 10,
+    max_block_size: 10_485_760,
+    block_max_deploy_count: 100,
+    block_max_transfer_count: 1000,
+    block_gas_limit: 10_000_000_000_000,
+    payment_args_max_length: 1024,
+    session_args_max_length: 1024,
+    native_transfer_minimum_motes: 2_500_000_000,
+});
+
+/// The limits applied to deploys, as configured in the `[deploys]` section of the chainspec.
+#[derive(Copy, Clone, DataSize, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
 // Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
 #[serde(deny_unknown_fields)]
 pub struct DeployConfig {
+    #[schemars(with = "String")]
     pub(crate) max_payment_cost: Motes,
     pub(crate) max_ttl: TimeDiff,
     pub(crate) max_dependencies: u8,
@@ -36,6 +54,68 @@ pub struct DeployConfig {
     pub(crate) native_transfer_minimum_motes: u64,
 }
 
+impl DeployConfig {
+    /// The maximum number of motes allowed to be spent during payment, where zero means unlimited.
+    pub fn max_payment_cost(&self) -> Motes {
+        self.max_payment_cost
+    }
+
+    /// The maximum time-to-live of a deploy.
+    pub fn max_ttl(&self) -> TimeDiff {
+        self.max_ttl
+    }
+
+    /// The maximum size of a block in bytes, where zero means unlimited.
+    pub fn max_block_size(&self) -> u32 {
+        self.max_block_size
+    }
+
+    /// The upper limit of the total gas of all deploys in a block.
+    pub fn block_gas_limit(&self) -> u64 {
+        self.block_gas_limit
+    }
+}
+
+impl DocExample for DeployConfig {
+    fn doc_example() -> &'static Self {
+        &*DEPLOY_CONFIG
+    }
+}
+
+impl From<DeployConfig> for rpc_info::DeployConfig {
+    fn from(deploy_config: DeployConfig) -> Self {
+        rpc_info::DeployConfig {
+            max_payment_cost: deploy_config.max_payment_cost.value(),
+            max_ttl: deploy_config.max_ttl,
+            max_dependencies: deploy_config.max_dependencies,
+            max_block_size: deploy_config.max_block_size,
+            block_max_deploy_count: deploy_config.block_max_deploy_count,
+            block_max_transfer_count: deploy_config.block_max_transfer_count,
+            block_gas_limit: deploy_config.block_gas_limit,
+            payment_args_max_length: deploy_config.payment_args_max_length,
+            session_args_max_length: deploy_config.session_args_max_length,
+            native_transfer_minimum_motes: deploy_config.native_transfer_minimum_motes,
+        }
+    }
+}
+
+impl From<rpc_info::DeployConfig> for DeployConfig {
+    fn from(deploy_config: rpc_info::DeployConfig) -> Self {
+        DeployConfig {
+            max_payment_cost: Motes::new(deploy_config.max_payment_cost),
+            max_ttl: deploy_config.max_ttl,
+            max_dependencies: deploy_config.max_dependencies,
+            max_block_size: deploy_config.max_block_size,
+            block_max_deploy_count: deploy_config.block_max_deploy_count,
+            block_max_transfer_count: deploy_config.block_max_transfer_count,
+            block_gas_limit: deploy_config.block_gas_limit,
+            payment_args_max_length: deploy_config.payment_args_max_length,
+            session_args_max_length: deploy_config.session_args_max_length,
+            native_transfer_minimum_motes: deploy_config.native_transfer_minimum_motes,
+        }
+    }
+}
+
 #[cfg(test)]
 impl DeployConfig {
     /// Generates a random instance using a `TestRng`.
@@ -165,4 +245,16 @@ mod tests {
         let decoded = toml::from_str(&encoded).unwrap();
         assert_eq!(config, decoded);
     }
+
+    #[test]
+    fn rpc_roundtrip() {
+        let mut rng = crate::new_rng();
+        let config = DeployConfig::random(&mut rng);
+        let rpc_config = rpc_info::DeployConfig::from(config);
+        assert_eq!(
+            serde_json::to_value(&rpc_config).unwrap(),
+            serde_json::to_value(&config).unwrap()
+        );
+        assert_eq!(DeployConfig::from(rpc_config), config);
+    }
 }
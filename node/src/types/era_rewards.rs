@@ -0,0 +1,237 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+};
+
+use datasize::DataSize;
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_rpc_client::rpcs::chain as rpc_chain;
+use casper_types::{
+    system::auction::{DelegationRate, EraInfo, SeigniorageAllocation, SeigniorageRecipients},
+    PublicKey, U512,
+};
+
+use crate::{
+    components::{consensus::EraId, rpc_server::rpcs::docs::DocExample},
+    crypto::AsymmetricKeyExt,
+};
+
+static ERA_REWARDS: Lazy<EraRewards> = Lazy::new(|| {
+    let reward = EraReward {
+        validator_public_key: *PublicKey::doc_example(),
+        delegator_public_key: None,
+        delegation_rate: 10,
+        stake: U512::from(1_000_000_000_000u64),
+        amount: U512::from(4_200_000_000u64),
+    };
+    EraRewards::new(EraId(42), vec![reward])
+});
+
+/// The stake and seigniorage allocation of a single validator or delegator in an era.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EraReward {
+    /// The public key of the validator, or of the validator delegated to.
+    pub validator_public_key: PublicKey,
+    /// The public key of the delegator, if this is a delegator's reward.
+    pub delegator_public_key: Option<PublicKey>,
+    /// The validator's delegation rate, i.e. the commission taken from its delegators' rewards.
+    pub delegation_rate: DelegationRate,
+    /// The amount staked in the era.
+    pub stake: U512,
+    /// The amount allocated at the end of the era.
+    pub amount: U512,
+}
+
+impl EraReward {
+    /// Returns the public key of the recipient, i.e. the delegator if there is one, otherwise the
+    /// validator.
+    pub fn public_key(&self) -> &PublicKey {
+        self.delegator_public_key
+            .as_ref()
+            .unwrap_or(&self.validator_public_key)
+    }
+}
+
+/// The stakes and seigniorage allocations of all validators and delegators in an era.
+///
+/// The record is created by this node when it executes the era's switch block.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EraRewards {
+    /// The era ID.
+    pub era_id: EraId,
+    /// The stake and reward of each validator and delegator in the era.
+    pub rewards: Vec<EraReward>,
+}
+
+impl EraRewards {
+    pub(crate) fn new(era_id: EraId, rewards: Vec<EraReward>) -> Self {
+        EraRewards { era_id, rewards }
+    }
+
+    /// Combines the era's seigniorage recipients with the allocations recorded in its `EraInfo`.
+    ///
+    /// Recipients that were not allocated anything are included with an amount of zero.
+    pub(crate) fn from_era_info(
+        era_id: EraId,
+        recipients: &SeigniorageRecipients,
+        era_info: &EraInfo,
+    ) -> Self {
+        let mut amounts: BTreeMap<(PublicKey, Option<PublicKey>), U512> = BTreeMap::new();
+        for allocation in era_info.seigniorage_allocations() {
+            let (key, amount) = match allocation {
+                SeigniorageAllocation::Validator {
+                    validator_public_key,
+                    amount,
+                } => ((*validator_public_key, None), amount),
+                SeigniorageAllocation::Delegator {
+                    delegator_public_key,
+                    validator_public_key,
+                    amount,
+                } => ((*validator_public_key, Some(*delegator_public_key)), amount),
+            };
+            *amounts.entry(key).or_default() += *amount;
+        }
+
+        let mut rewards = Vec::new();
+        for (validator_public_key, recipient) in recipients {
+            let delegation_rate = *recipient.delegation_rate();
+            let amount = amounts
+                .get(&(*validator_public_key, None))
+                .copied()
+                .unwrap_or_default();
+            rewards.push(EraReward {
+                validator_public_key: *validator_public_key,
+                delegator_public_key: None,
+                delegation_rate,
+                stake: *recipient.stake(),
+                amount,
+            });
+            for (delegator_public_key, delegator) in recipient.delegators() {
+                let amount = amounts
+                    .get(&(*validator_public_key, Some(*delegator_public_key)))
+                    .copied()
+                    .unwrap_or_default();
+                rewards.push(EraReward {
+                    validator_public_key: *validator_public_key,
+                    delegator_public_key: Some(*delegator_public_key),
+                    delegation_rate,
+                    stake: *delegator.staked_amount(),
+                    amount,
+                });
+            }
+        }
+        EraRewards::new(era_id, rewards)
+    }
+
+    /// Returns the era ID.
+    pub fn era_id(&self) -> EraId {
+        self.era_id
+    }
+
+    /// Returns the recipients' public keys, i.e. the keys of all validators and delegators.
+    pub(crate) fn public_keys(&self) -> impl Iterator<Item = &PublicKey> {
+        self.rewards.iter().map(EraReward::public_key)
+    }
+
+    /// Returns a copy containing only the rewards received by the given validator or delegator.
+    pub(crate) fn select(&self, public_key: &PublicKey) -> Self {
+        let rewards = self
+            .rewards
+            .iter()
+            .filter(|reward| reward.public_key() == public_key)
+            .cloned()
+            .collect();
+        EraRewards::new(self.era_id, rewards)
+    }
+}
+
+impl Display for EraRewards {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "{} rewards in era {}",
+            self.rewards.len(),
+            self.era_id
+        )
+    }
+}
+
+impl DocExample for EraRewards {
+    fn doc_example() -> &'static Self {
+        &*ERA_REWARDS
+    }
+}
+
+impl From<EraReward> for rpc_chain::EraReward {
+    fn from(reward: EraReward) -> Self {
+        rpc_chain::EraReward {
+            validator_public_key: reward.validator_public_key,
+            delegator_public_key: reward.delegator_public_key,
+            delegation_rate: reward.delegation_rate,
+            stake: reward.stake,
+            amount: reward.amount,
+        }
+    }
+}
+
+impl From<EraRewards> for rpc_chain::EraRewards {
+    fn from(era_rewards: EraRewards) -> Self {
+        rpc_chain::EraRewards {
+            era_id: era_rewards.era_id.0,
+            rewards: era_rewards.rewards.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::{
+        system::auction::{Bid, Delegator, SeigniorageRecipient},
+        AccessRights, SecretKey, URef,
+    };
+
+    use super::*;
+
+    #[test]
+    fn should_combine_recipients_and_allocations() {
+        let validator: PublicKey = SecretKey::ed25519([1; SecretKey::ED25519_LENGTH]).into();
+        let delegator: PublicKey = SecretKey::ed25519([2; SecretKey::ED25519_LENGTH]).into();
+        let bonding_purse = URef::new([0; 32], AccessRights::READ_ADD_WRITE);
+
+        let mut bid = Bid::unlocked(validator, bonding_purse, U512::from(1_000), 10);
+        bid.delegators_mut().insert(
+            delegator,
+            Delegator::unlocked(delegator, U512::from(500), bonding_purse, validator),
+        );
+        let mut recipients = SeigniorageRecipients::new();
+        recipients.insert(validator, SeigniorageRecipient::from(&bid));
+
+        let mut era_info = EraInfo::new();
+        era_info
+            .seigniorage_allocations_mut()
+            .push(SeigniorageAllocation::validator(validator, U512::from(7)));
+
+        let era_rewards = EraRewards::from_era_info(EraId(3), &recipients, &era_info);
+        assert_eq!(era_rewards.rewards.len(), 2);
+
+        let validator_rewards = era_rewards.select(&validator);
+        assert_eq!(validator_rewards.rewards.len(), 1);
+        assert_eq!(validator_rewards.rewards[0].stake, U512::from(1_000));
+        assert_eq!(validator_rewards.rewards[0].amount, U512::from(7));
+
+        // The delegator wasn't allocated anything, but its stake is still recorded.
+        let delegator_rewards = era_rewards.select(&delegator);
+        assert_eq!(delegator_rewards.rewards.len(), 1);
+        assert_eq!(delegator_rewards.rewards[0].stake, U512::from(500));
+        assert_eq!(delegator_rewards.rewards[0].amount, U512::zero());
+        assert_eq!(delegator_rewards.rewards[0].delegation_rate, 10);
+    }
+}
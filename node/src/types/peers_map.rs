@@ -1,34 +1,16 @@
-// TODO - remove once schemars stops causing warning.
-#![allow(clippy::field_reassign_with_default)]
-
 use std::collections::BTreeMap;
 
-use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use casper_rpc_client::rpcs::info::PeerEntry;
 
 use crate::types::NodeId;
 
-#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, JsonSchema)]
-#[serde(deny_unknown_fields)]
-struct PeerEntry {
-    node_id: String,
-    address: String,
-}
-
-/// Map of peer IDs to network addresses.
-#[derive(Clone, Serialize, Deserialize, Debug, PartialEq, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct PeersMap(Vec<PeerEntry>);
-
-impl From<BTreeMap<NodeId, String>> for PeersMap {
-    fn from(input: BTreeMap<NodeId, String>) -> Self {
-        let ret = input
-            .into_iter()
-            .map(|(node_id, address)| PeerEntry {
-                node_id: node_id.to_string(),
-                address,
-            })
-            .collect();
-        PeersMap(ret)
-    }
+/// Converts a map of peer IDs to network addresses into the entries returned by the RPCs.
+pub(crate) fn peer_entries(peers: BTreeMap<NodeId, String>) -> Vec<PeerEntry> {
+    peers
+        .into_iter()
+        .map(|(node_id, address)| PeerEntry {
+            node_id: node_id.to_string(),
+            address,
+        })
+        .collect()
 }
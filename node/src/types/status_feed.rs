@@ -1,6 +1,3 @@
-// TODO - remove once schemars stops causing warning.
-#![allow(clippy::field_reassign_with_default)]
-
 use std::{
     collections::BTreeMap,
     hash::Hash,
@@ -8,10 +5,10 @@ use std::{
 };
 
 use once_cell::sync::Lazy;
-use schemars::JsonSchema;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 
+use casper_rpc_client::rpcs::info::{GetStatusResult, MinimalBlockInfo};
 use casper_types::PublicKey;
 
 use crate::{
@@ -21,7 +18,7 @@ use crate::{
         rpc_server::rpcs::docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     },
     crypto::{hash::Digest, AsymmetricKeyExt},
-    types::{ActivationPoint, Block, BlockHash, NodeId, PeersMap, TimeDiff, Timestamp},
+    types::{peer_entries, ActivationPoint, Block, NodeId, TimeDiff},
 };
 
 static CHAINSPEC_INFO: Lazy<ChainspecInfo> = Lazy::new(|| {
@@ -47,7 +44,7 @@ static GET_STATUS_RESULT: Lazy<GetStatusResult> = Lazy::new(|| {
         round_length: Some(TimeDiff::from(1 << 16)),
         version: crate::VERSION_STRING.as_str(),
     };
-    GetStatusResult::new(status_feed, DOCS_EXAMPLE_PROTOCOL_VERSION.clone())
+    status_feed.into_status_result(DOCS_EXAMPLE_PROTOCOL_VERSION.clone())
 });
 
 /// Summary information from the chainspec.
@@ -118,75 +115,34 @@ impl<I> StatusFeed<I> {
     }
 }
 
-/// Minimal info of a `Block`.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct MinimalBlockInfo {
-    hash: BlockHash,
-    timestamp: Timestamp,
-    era_id: EraId,
-    height: u64,
-    state_root_hash: Digest,
-    creator: PublicKey,
-}
-
-impl From<Block> for MinimalBlockInfo {
-    fn from(block: Block) -> Self {
-        MinimalBlockInfo {
-            hash: *block.hash(),
-            timestamp: block.header().timestamp(),
-            era_id: block.header().era_id(),
-            height: block.header().height(),
-            state_root_hash: *block.header().state_root_hash(),
-            creator: *block.body().proposer(),
-        }
-    }
-}
-
-/// Result for "info_get_status" RPC response.
-#[derive(Serialize, Deserialize, Debug, JsonSchema)]
-#[serde(deny_unknown_fields)]
-pub struct GetStatusResult {
-    /// The RPC API version.
-    #[schemars(with = "String")]
-    pub api_version: Version,
-    /// The chainspec name.
-    pub chainspec_name: String,
-    /// The state root hash used at the start of the current session.
-    pub starting_state_root_hash: String,
-    /// The node ID and network address of each connected peer.
-    pub peers: PeersMap,
-    /// The minimal info of the last block from the linear chain.
-    pub last_added_block_info: Option<MinimalBlockInfo>,
-    /// Our public signing key.
-    pub our_public_signing_key: PublicKey,
-    /// The next round length if this node is a validator.
-    pub round_length: Option<TimeDiff>,
-    /// Information about the next scheduled upgrade.
-    pub next_upgrade: Option<NextUpgrade>,
-    /// The compiled node version.
-    pub build_version: String,
-}
-
-impl GetStatusResult {
-    pub(crate) fn new(status_feed: StatusFeed<NodeId>, api_version: Version) -> Self {
+impl StatusFeed<NodeId> {
+    /// Converts the status into the result of the "info_get_status" RPC.
+    pub(crate) fn into_status_result(self, api_version: Version) -> GetStatusResult {
         GetStatusResult {
             api_version,
-            chainspec_name: status_feed.chainspec_info.name,
-            starting_state_root_hash: status_feed
-                .chainspec_info
-                .starting_state_root_hash
-                .to_string(),
-            peers: PeersMap::from(status_feed.peers),
-            last_added_block_info: status_feed.last_added_block.map(Into::into),
-            our_public_signing_key: status_feed.our_public_signing_key,
-            round_length: status_feed.round_length,
-            next_upgrade: status_feed.chainspec_info.next_upgrade,
+            chainspec_name: self.chainspec_info.name,
+            starting_state_root_hash: self.chainspec_info.starting_state_root_hash.to_string(),
+            peers: peer_entries(self.peers),
+            last_added_block_info: self.last_added_block.map(minimal_block_info),
+            our_public_signing_key: self.our_public_signing_key,
+            round_length: self.round_length,
+            next_upgrade: self.chainspec_info.next_upgrade.map(Into::into),
             build_version: crate::VERSION_STRING.clone(),
         }
     }
 }
 
+fn minimal_block_info(block: Block) -> MinimalBlockInfo {
+    MinimalBlockInfo {
+        hash: *block.hash(),
+        timestamp: block.header().timestamp(),
+        era_id: block.header().era_id().0,
+        height: block.header().height(),
+        state_root_hash: *block.header().state_root_hash(),
+        creator: *block.body().proposer(),
+    }
+}
+
 impl DocExample for GetStatusResult {
     fn doc_example() -> &'static Self {
         &*GET_STATUS_RESULT
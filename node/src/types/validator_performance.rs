@@ -0,0 +1,106 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use std::fmt::{self, Display, Formatter};
+
+use datasize::DataSize;
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_rpc_client::rpcs::chain as rpc_chain;
+use casper_types::PublicKey;
+
+use crate::{
+    components::{consensus::EraId, rpc_server::rpcs::docs::DocExample},
+    crypto::AsymmetricKeyExt,
+};
+
+static ERA_VALIDATOR_PERFORMANCE: Lazy<EraValidatorPerformance> = Lazy::new(|| {
+    let validator = ValidatorPerformance {
+        public_key: *PublicKey::doc_example(),
+        units_produced: 384,
+        rounds_participated: 192,
+        round_exponent: Some(16),
+        equivocated: false,
+    };
+    EraValidatorPerformance::new(EraId(42), vec![validator])
+});
+
+/// A validator's participation in an era's consensus, as observed by this node.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ValidatorPerformance {
+    /// The validator's public key.
+    pub public_key: PublicKey,
+    /// The number of units, or proposals and votes, created by the validator.
+    pub units_produced: u64,
+    /// The number of rounds in which the validator created at least one unit, proposal or vote.
+    pub rounds_participated: u64,
+    /// The validator's latest round exponent, if the consensus protocol uses one.
+    pub round_exponent: Option<u8>,
+    /// Whether the validator is known to have equivocated, in this or an earlier era.
+    pub equivocated: bool,
+}
+
+/// The participation of every validator in an era's consensus, as observed by this node.
+///
+/// The report is created when the node stops voting in the era. Nodes may disagree about it,
+/// since they don't necessarily receive the same units.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EraValidatorPerformance {
+    /// The era ID.
+    pub era_id: EraId,
+    /// The participation of each validator in the era.
+    pub validators: Vec<ValidatorPerformance>,
+}
+
+impl EraValidatorPerformance {
+    pub(crate) fn new(era_id: EraId, validators: Vec<ValidatorPerformance>) -> Self {
+        EraValidatorPerformance { era_id, validators }
+    }
+
+    /// Returns the era ID.
+    pub fn era_id(&self) -> EraId {
+        self.era_id
+    }
+}
+
+impl Display for EraValidatorPerformance {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "performance of {} validators in era {}",
+            self.validators.len(),
+            self.era_id
+        )
+    }
+}
+
+impl DocExample for EraValidatorPerformance {
+    fn doc_example() -> &'static Self {
+        &*ERA_VALIDATOR_PERFORMANCE
+    }
+}
+
+impl From<ValidatorPerformance> for rpc_chain::ValidatorPerformance {
+    fn from(performance: ValidatorPerformance) -> Self {
+        rpc_chain::ValidatorPerformance {
+            public_key: performance.public_key,
+            units_produced: performance.units_produced,
+            rounds_participated: performance.rounds_participated,
+            round_exponent: performance.round_exponent,
+            equivocated: performance.equivocated,
+        }
+    }
+}
+
+impl From<EraValidatorPerformance> for rpc_chain::EraValidatorPerformance {
+    fn from(performance: EraValidatorPerformance) -> Self {
+        rpc_chain::EraValidatorPerformance {
+            era_id: performance.era_id.0,
+            validators: performance.validators.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use std::fmt::{self, Display, Formatter};
+
+use datasize::DataSize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+
+use crate::crypto::hash::Digest;
+#[cfg(any(feature = "testing", test))]
+use crate::testing::TestRng;
+
+/// A cryptographic hash identifying a block.
+#[derive(
+    Copy,
+    Clone,
+    DataSize,
+    Ord,
+    PartialOrd,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Debug,
+    JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub struct BlockHash(Digest);
+
+impl BlockHash {
+    /// Constructs a new `BlockHash`.
+    pub fn new(hash: Digest) -> Self {
+        BlockHash(hash)
+    }
+
+    /// Returns the wrapped inner hash.
+    pub fn inner(&self) -> &Digest {
+        &self.0
+    }
+
+    /// Creates a random block hash.
+    #[cfg(any(feature = "testing", test))]
+    pub fn random(rng: &mut TestRng) -> Self {
+        let hash = Digest::random(rng);
+        BlockHash(hash)
+    }
+}
+
+impl Display for BlockHash {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "block-hash({})", self.0,)
+    }
+}
+
+impl From<Digest> for BlockHash {
+    fn from(digest: Digest) -> Self {
+        Self(digest)
+    }
+}
+
+impl AsRef<[u8]> for BlockHash {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl ToBytes for BlockHash {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for BlockHash {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (hash, remainder) = Digest::from_bytes(bytes)?;
+        let block_hash = BlockHash(hash);
+        Ok((block_hash, remainder))
+    }
+}
+
+/// Identifier for possible ways to retrieve a block.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum BlockIdentifier {
+    /// Identify and retrieve the block with its hash.
+    Hash(BlockHash),
+    /// Identify and retrieve the block with its height.
+    Height(u64),
+}
@@ -5,12 +5,13 @@
 
 use ed25519_dalek::ExpandedSecretKey;
 use k256::{ecdsa, ecdsa::signature::Verifier};
+use rand::{CryptoRng, RngCore};
 use signature::{RandomizedSigner, Signature as _Signature};
 
 use casper_types::{PublicKey, SecretKey, Signature};
 
 pub use super::{Error, Result};
-use crate::{crypto::AsymmetricKeyExt, types::NodeRng};
+use crate::crypto::AsymmetricKeyExt;
 
 /// Generates an Ed25519 keypair using the operating system's cryptographically secure random number
 /// generator.
@@ -21,11 +22,11 @@ pub fn generate_ed25519_keypair() -> (SecretKey, PublicKey) {
 }
 
 /// Signs the given message using the given key pair.
-pub fn sign<T: AsRef<[u8]>>(
+pub fn sign<T: AsRef<[u8]>, R: CryptoRng + RngCore>(
     message: T,
     secret_key: &SecretKey,
     public_key: &PublicKey,
-    rng: &mut NodeRng,
+    rng: &mut R,
 ) -> Signature {
     match (secret_key, public_key) {
         (SecretKey::System, PublicKey::System) => {
@@ -296,7 +297,7 @@ mod tests {
 
         #[test]
         fn secret_key_serialization_roundtrip() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_ed25519(&mut rng);
             super::secret_key_serialization_roundtrip(secret_key)
         }
@@ -314,7 +315,7 @@ mod tests {
 
         #[test]
         fn secret_key_to_and_from_der() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_ed25519(&mut rng);
             let der_encoded = secret_key.to_der().unwrap();
             secret_key_der_roundtrip(secret_key);
@@ -325,7 +326,7 @@ mod tests {
 
         #[test]
         fn secret_key_to_and_from_pem() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_ed25519(&mut rng);
             secret_key_pem_roundtrip(secret_key);
         }
@@ -343,14 +344,14 @@ MC4CAQAwBQYDK2VwBCIEINTuctv5E1hK1bbY8fdp+K06/nwoy/HU++CXqI9EdVhC
 
         #[test]
         fn secret_key_to_and_from_file() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_ed25519(&mut rng);
             secret_key_file_roundtrip(secret_key);
         }
 
         #[test]
         fn public_key_serialization_roundtrip() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_ed25519(&mut rng);
             super::public_key_serialization_roundtrip(public_key);
         }
@@ -359,7 +360,7 @@ MC4CAQAwBQYDK2VwBCIEINTuctv5E1hK1bbY8fdp+K06/nwoy/HU++CXqI9EdVhC
         fn public_key_from_bytes() {
             // Public key should be `PublicKey::ED25519_LENGTH` bytes.  Create vec with an extra
             // byte.
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_ed25519(&mut rng);
             let bytes: Vec<u8> = iter::once(rng.gen())
                 .chain(public_key.as_ref().iter().copied())
@@ -374,14 +375,14 @@ MC4CAQAwBQYDK2VwBCIEINTuctv5E1hK1bbY8fdp+K06/nwoy/HU++CXqI9EdVhC
 
         #[test]
         fn public_key_to_and_from_der() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_ed25519(&mut rng);
             public_key_der_roundtrip(public_key);
         }
 
         #[test]
         fn public_key_to_and_from_pem() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_ed25519(&mut rng);
             public_key_pem_roundtrip(public_key);
         }
@@ -399,21 +400,21 @@ MCowBQYDK2VwAyEAGb9ECWmEzf6FQbrBZ9w7lshQhqowtrbLDFw4rXAxZuE=
 
         #[test]
         fn public_key_to_and_from_file() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_ed25519(&mut rng);
             public_key_file_roundtrip(public_key);
         }
 
         #[test]
         fn public_key_to_and_from_hex() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_ed25519(&mut rng);
             public_key_hex_roundtrip(public_key);
         }
 
         #[test]
         fn signature_serialization_roundtrip() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_ed25519(&mut rng);
             let public_key = PublicKey::from(&secret_key);
             let data = b"data";
@@ -438,7 +439,7 @@ MCowBQYDK2VwAyEAGb9ECWmEzf6FQbrBZ9w7lshQhqowtrbLDFw4rXAxZuE=
 
         #[test]
         fn signature_key_to_and_from_hex() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_ed25519(&mut rng);
             let public_key = PublicKey::from(&secret_key);
             let data = b"data";
@@ -471,7 +472,7 @@ MCowBQYDK2VwAyEAGb9ECWmEzf6FQbrBZ9w7lshQhqowtrbLDFw4rXAxZuE=
 
         #[test]
         fn sign_and_verify() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_ed25519(&mut rng);
 
             let public_key = PublicKey::from(&secret_key);
@@ -511,7 +512,7 @@ MCowBQYDK2VwAyEAGb9ECWmEzf6FQbrBZ9w7lshQhqowtrbLDFw4rXAxZuE=
 
         #[test]
         fn secret_key_serialization_roundtrip() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_secp256k1(&mut rng);
             super::secret_key_serialization_roundtrip(secret_key)
         }
@@ -529,14 +530,14 @@ MCowBQYDK2VwAyEAGb9ECWmEzf6FQbrBZ9w7lshQhqowtrbLDFw4rXAxZuE=
 
         #[test]
         fn secret_key_to_and_from_der() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_secp256k1(&mut rng);
             secret_key_der_roundtrip(secret_key);
         }
 
         #[test]
         fn secret_key_to_and_from_pem() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_secp256k1(&mut rng);
             secret_key_pem_roundtrip(secret_key);
         }
@@ -556,14 +557,14 @@ Yj9oTB9fx9+vvQdxJOhMtu46kGo0Uw==
 
         #[test]
         fn secret_key_to_and_from_file() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_secp256k1(&mut rng);
             secret_key_file_roundtrip(secret_key);
         }
 
         #[test]
         fn public_key_serialization_roundtrip() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_secp256k1(&mut rng);
             super::public_key_serialization_roundtrip(public_key);
         }
@@ -572,7 +573,7 @@ Yj9oTB9fx9+vvQdxJOhMtu46kGo0Uw==
         fn public_key_from_bytes() {
             // Public key should be `PublicKey::SECP256K1_LENGTH` bytes.  Create vec with an extra
             // byte.
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_secp256k1(&mut rng);
             let bytes: Vec<u8> = iter::once(rng.gen())
                 .chain(public_key.as_ref().iter().copied())
@@ -587,14 +588,14 @@ Yj9oTB9fx9+vvQdxJOhMtu46kGo0Uw==
 
         #[test]
         fn public_key_to_and_from_der() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_secp256k1(&mut rng);
             public_key_der_roundtrip(public_key);
         }
 
         #[test]
         fn public_key_to_and_from_pem() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_secp256k1(&mut rng);
             public_key_pem_roundtrip(public_key);
         }
@@ -613,21 +614,21 @@ kv+kBR5u4ISEAkuc2TFWQHX0Yj9oTB9fx9+vvQdxJOhMtu46kGo0Uw==
 
         #[test]
         fn public_key_to_and_from_file() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_secp256k1(&mut rng);
             public_key_file_roundtrip(public_key);
         }
 
         #[test]
         fn public_key_to_and_from_hex() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_secp256k1(&mut rng);
             public_key_hex_roundtrip(public_key);
         }
 
         #[test]
         fn signature_serialization_roundtrip() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_secp256k1(&mut rng);
             let public_key = PublicKey::from(&secret_key);
             let data = b"data";
@@ -658,7 +659,7 @@ kv+kBR5u4ISEAkuc2TFWQHX0Yj9oTB9fx9+vvQdxJOhMtu46kGo0Uw==
 
         #[test]
         fn signature_key_to_and_from_hex() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let secret_key = SecretKey::random_secp256k1(&mut rng);
             let public_key = PublicKey::from(&secret_key);
             let data = b"data";
@@ -668,7 +669,7 @@ kv+kBR5u4ISEAkuc2TFWQHX0Yj9oTB9fx9+vvQdxJOhMtu46kGo0Uw==
 
         #[test]
         fn public_key_traits() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key1 = PublicKey::random_secp256k1(&mut rng);
             let public_key2 = PublicKey::random_secp256k1(&mut rng);
             if public_key1.as_ref() < public_key2.as_ref() {
@@ -680,7 +681,7 @@ kv+kBR5u4ISEAkuc2TFWQHX0Yj9oTB9fx9+vvQdxJOhMtu46kGo0Uw==
 
         #[test]
         fn public_key_to_account_hash() {
-            let mut rng = crate::new_rng();
+            let mut rng = TestRng::new();
             let public_key = PublicKey::random_secp256k1(&mut rng);
             assert_ne!(public_key.to_account_hash().as_ref(), public_key.as_ref());
         }
@@ -695,7 +696,7 @@ kv+kBR5u4ISEAkuc2TFWQHX0Yj9oTB9fx9+vvQdxJOhMtu46kGo0Uw==
 
     #[test]
     fn public_key_traits() {
-        let mut rng = crate::new_rng();
+        let mut rng = TestRng::new();
         let ed25519_public_key = PublicKey::random_ed25519(&mut rng);
         let secp256k1_public_key = PublicKey::random_secp256k1(&mut rng);
         check_ord_and_hash(ed25519_public_key, secp256k1_public_key);
@@ -710,7 +711,7 @@ kv+kBR5u4ISEAkuc2TFWQHX0Yj9oTB9fx9+vvQdxJOhMtu46kGo0Uw==
 
     #[test]
     fn sign_and_verify() {
-        let mut rng = crate::new_rng();
+        let mut rng = TestRng::new();
         let ed25519_secret_key = SecretKey::random_ed25519(&mut rng);
         let secp256k1_secret_key = SecretKey::random_secp256k1(&mut rng);
 
@@ -744,7 +745,7 @@ kv+kBR5u4ISEAkuc2TFWQHX0Yj9oTB9fx9+vvQdxJOhMtu46kGo0Uw==
 
     #[test]
     fn should_construct_secp256k1_from_uncompressed_bytes() {
-        let mut rng = crate::new_rng();
+        let mut rng = TestRng::new();
 
         // Construct a secp256k1 secret key and use that to construct an uncompressed public key.
         let secp256k1_secret_key = {
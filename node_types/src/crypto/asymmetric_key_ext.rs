@@ -5,13 +5,13 @@ use std::path::Path;
 use derp::{Der, Tag};
 use once_cell::sync::Lazy;
 use pem::Pem;
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 use rand::{Rng, RngCore};
 use untrusted::Input;
 
 use casper_types::{AsymmetricType, PublicKey, SecretKey, ED25519_TAG, SECP256K1_TAG, SYSTEM_TAG};
 
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 use crate::testing::TestRng;
 use crate::{crypto::Error, utils};
 
@@ -70,19 +70,19 @@ pub trait AsymmetricKeyExt: Sized {
     /// Duplicates a secret key.
     ///
     /// Only available for testing and named other than `clone` to prevent accidental use.
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn duplicate(&self) -> Self;
 
     /// Generates a random instance using a `TestRng`.
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn random(rng: &mut TestRng) -> Self;
 
     /// Generates a random ed25519 instance using a `TestRng`.
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn random_ed25519(rng: &mut TestRng) -> Self;
 
     /// Generates a random secp256k1 instance using a `TestRng`.
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn random_secp256k1(rng: &mut TestRng) -> Self;
 
     /// Returns an example value for documentation purposes.
@@ -263,7 +263,7 @@ impl AsymmetricKeyExt for SecretKey {
         Ok(secret_key)
     }
 
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn duplicate(&self) -> Self {
         match self {
             SecretKey::System => SecretKey::System,
@@ -277,7 +277,7 @@ impl AsymmetricKeyExt for SecretKey {
         }
     }
 
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn random(rng: &mut TestRng) -> Self {
         if rng.gen() {
             Self::random_ed25519(rng)
@@ -286,14 +286,14 @@ impl AsymmetricKeyExt for SecretKey {
         }
     }
 
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn random_ed25519(rng: &mut TestRng) -> Self {
         let mut bytes = [0u8; Self::ED25519_LENGTH];
         rng.fill_bytes(&mut bytes[..]);
         SecretKey::ed25519(bytes)
     }
 
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn random_secp256k1(rng: &mut TestRng) -> Self {
         let mut bytes = [0u8; Self::SECP256K1_LENGTH];
         rng.fill_bytes(&mut bytes[..]);
@@ -430,7 +430,7 @@ impl AsymmetricKeyExt for PublicKey {
         Ok(public_key)
     }
 
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn duplicate(&self) -> Self {
         match self {
             PublicKey::System => PublicKey::System,
@@ -443,19 +443,19 @@ impl AsymmetricKeyExt for PublicKey {
         }
     }
 
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn random(rng: &mut TestRng) -> Self {
         let secret_key = SecretKey::random(rng);
         PublicKey::from(&secret_key)
     }
 
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn random_ed25519(rng: &mut TestRng) -> Self {
         let secret_key = SecretKey::random_ed25519(rng);
         PublicKey::from(&secret_key)
     }
 
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     fn random_secp256k1(rng: &mut TestRng) -> Self {
         let secret_key = SecretKey::random_secp256k1(rng);
         PublicKey::from(&secret_key)
@@ -16,16 +16,15 @@ use blake2::{
 use datasize::DataSize;
 use hex_buffer_serde::{Hex, HexForm};
 use hex_fmt::HexFmt;
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 use rand::Rng;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use casper_execution_engine::shared::newtypes::Blake2bHash;
 use casper_types::bytesrepr::{self, FromBytes, ToBytes};
 
 use super::Error;
-#[cfg(test)]
+#[cfg(any(feature = "testing", test))]
 use crate::testing::TestRng;
 
 /// The hash digest; a wrapped `u8` array.
@@ -73,7 +72,7 @@ impl Digest {
     }
 
     /// Generates a random instance using a `TestRng`.
-    #[cfg(test)]
+    #[cfg(any(feature = "testing", test))]
     pub fn random(rng: &mut TestRng) -> Self {
         Digest(rng.gen::<[u8; Digest::LENGTH]>())
     }
@@ -158,20 +157,6 @@ pub fn hash<T: AsRef<[u8]>>(data: T) -> Digest {
     Digest(result)
 }
 
-impl From<Digest> for Blake2bHash {
-    fn from(digest: Digest) -> Self {
-        let digest_bytes = digest.to_array();
-        Blake2bHash::from(digest_bytes)
-    }
-}
-
-impl From<Blake2bHash> for Digest {
-    fn from(blake2bhash: Blake2bHash) -> Self {
-        let bytes = blake2bhash.value();
-        Digest::from(bytes)
-    }
-}
-
 #[cfg(test)]
 mod test {
     use std::iter::{self, FromIterator};
@@ -266,7 +251,7 @@ mod test {
 
     #[test]
     fn bytesrepr_roundtrip() {
-        let mut rng = crate::new_rng();
+        let mut rng = TestRng::new();
         let hash = Digest::random(&mut rng);
         bytesrepr::test_serialization_roundtrip(&hash);
     }
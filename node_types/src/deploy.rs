@@ -0,0 +1,878 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use std::{
+    array::TryFromSliceError,
+    error::Error as StdError,
+    fmt::{self, Debug, Display, Formatter},
+};
+
+use datasize::DataSize;
+use hex::FromHexError;
+use itertools::Itertools;
+use once_cell::sync::Lazy;
+#[cfg(any(feature = "testing", test))]
+use rand::Rng;
+use rand::{CryptoRng, RngCore};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::warn;
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    runtime_args, AsymmetricType, PublicKey, RuntimeArgs, SecretKey, Signature, U512,
+};
+
+#[cfg(any(feature = "testing", test))]
+use crate::testing::TestRng;
+use crate::{
+    crypto,
+    crypto::{
+        hash::{self, Digest},
+        AsymmetricKeyExt,
+    },
+    utils::DisplayIter,
+    ExecutableDeployItem, TimeDiff, Timestamp,
+};
+
+static DEPLOY: Lazy<Deploy> = Lazy::new(|| {
+    let payment_args = runtime_args! {
+        "quantity" => 1000
+    };
+    let payment = ExecutableDeployItem::StoredContractByName {
+        name: String::from("casper-example"),
+        entry_point: String::from("example-entry-point"),
+        args: payment_args,
+    };
+    let session_args = runtime_args! {
+        "amount" => 1000
+    };
+    let session = ExecutableDeployItem::Transfer { args: session_args };
+    let serialized_body = serialize_body(&payment, &session);
+    let body_hash = hash::hash(&serialized_body);
+
+    let secret_key = SecretKey::doc_example();
+    let header = DeployHeader {
+        account: PublicKey::from(secret_key),
+        timestamp: *Timestamp::example(),
+        ttl: TimeDiff::from(3_600_000),
+        gas_price: 1,
+        body_hash,
+        dependencies: vec![DeployHash::new(Digest::from([1u8; Digest::LENGTH]))],
+        chain_name: String::from("casper-example"),
+    };
+    let serialized_header = serialize_header(&header);
+    let hash = DeployHash::new(hash::hash(&serialized_header));
+
+    let signature = Signature::from_hex(
+        "012dbf03817a51794a8e19e0724884075e6d1fbec326b766ecfa6658b41f81290da85e23b24e88b1c8d976\
+            1185c961daee1adab0649912a6477bcd2e69bd91bd08"
+            .as_bytes(),
+    )
+    .unwrap();
+    let approval = Approval {
+        signer: PublicKey::from(secret_key),
+        signature,
+    };
+
+    Deploy {
+        hash,
+        header,
+        payment,
+        session,
+        approvals: vec![approval],
+        is_valid: None,
+    }
+});
+
+/// A representation of the way in which a deploy failed validation checks.
+#[derive(Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Debug, Error)]
+pub enum DeployValidationFailure {
+    /// Invalid chain name.
+    #[error("invalid chain name: expected {expected}, got {got}")]
+    InvalidChainName {
+        /// The expected chain name.
+        expected: String,
+        /// The received chain name.
+        got: String,
+    },
+
+    /// Too many dependencies.
+    #[error("{got} dependencies exceeds limit of {max_dependencies}")]
+    ExcessiveDependencies {
+        /// The dependencies limit.
+        max_dependencies: u8,
+        /// The actual number of dependencies provided.
+        got: usize,
+    },
+
+    /// Excessive time-to-live.
+    #[error("time-to-live of {got} exceeds limit of {max_ttl}")]
+    ExcessiveTimeToLive {
+        /// The time-to-live limit.
+        max_ttl: TimeDiff,
+        /// The received time-to-live.
+        got: TimeDiff,
+    },
+
+    /// The provided body hash does not match the actual hash of the body.
+    #[error("the provided body hash does not match the actual hash of the body")]
+    InvalidBodyHash,
+
+    /// The provided deploy hash does not match the actual hash of the deploy.
+    #[error("the provided hash does not match the actual hash of the deploy")]
+    InvalidDeployHash,
+
+    /// Invalid approval.
+    #[error("the approval at index {index} is invalid: {error_msg}")]
+    InvalidApproval {
+        /// The index of the approval at fault.
+        index: usize,
+        /// The approval validation error.
+        error_msg: String,
+    },
+
+    /// Excessive length of deploy's session args.
+    #[error("serialized session code runtime args of {got} exceeds limit of {max_length}")]
+    ExcessiveSessionArgsLength {
+        /// The byte size limit of session arguments.
+        max_length: usize,
+        /// The received length of session arguments.
+        got: usize,
+    },
+
+    /// Excessive length of deploy's payment args.
+    #[error("serialized payment code runtime args of {got} exceeds limit of {max_length}")]
+    ExcessivePaymentArgsLength {
+        /// The byte size limit of payment arguments.
+        max_length: usize,
+        /// The received length of payment arguments.
+        got: usize,
+    },
+
+    /// Missing transfer amount.
+    #[error("missing transfer amount")]
+    MissingTransferAmount,
+
+    /// Invalid transfer amount.
+    #[error("invalid transfer amount")]
+    InvalidTransferAmount,
+
+    /// Insufficient transfer amount.
+    #[error("insufficient transfer amount; minimum: {minimum} attempted: {attempted}")]
+    InsufficientTransferAmount {
+        /// The minimum transfer amount.
+        minimum: U512,
+        /// The attempted transfer amount.
+        attempted: U512,
+    },
+}
+
+/// Errors other than validation failures relating to `Deploy`s.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Error while encoding to JSON.
+    #[error("encoding to JSON: {0}")]
+    EncodeToJson(#[from] serde_json::Error),
+
+    /// Error while decoding from JSON.
+    #[error("decoding from JSON: {0}")]
+    DecodeFromJson(Box<dyn StdError>),
+
+    /// Failed to get "amount" from `payment()`'s runtime args.
+    #[error("invalid payment: missing \"amount\" arg")]
+    InvalidPayment,
+}
+
+impl From<FromHexError> for Error {
+    fn from(error: FromHexError) -> Self {
+        Error::DecodeFromJson(Box::new(error))
+    }
+}
+
+impl From<TryFromSliceError> for Error {
+    fn from(error: TryFromSliceError) -> Self {
+        Error::DecodeFromJson(Box::new(error))
+    }
+}
+
+/// The cryptographic hash of a [`Deploy`](struct.Deploy.html).
+#[derive(
+    Copy,
+    Clone,
+    DataSize,
+    Ord,
+    PartialOrd,
+    Eq,
+    PartialEq,
+    Hash,
+    Serialize,
+    Deserialize,
+    Debug,
+    Default,
+    JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+#[schemars(with = "String", description = "Hex-encoded deploy hash.")]
+pub struct DeployHash(#[schemars(skip)] Digest);
+
+impl DeployHash {
+    /// Constructs a new `DeployHash`.
+    pub fn new(hash: Digest) -> Self {
+        DeployHash(hash)
+    }
+
+    /// Returns the wrapped inner hash.
+    pub fn inner(&self) -> &Digest {
+        &self.0
+    }
+
+    /// Creates a random deploy hash.
+    #[cfg(any(feature = "testing", test))]
+    pub fn random(rng: &mut TestRng) -> Self {
+        let hash = Digest::random(rng);
+        DeployHash(hash)
+    }
+}
+
+impl Display for DeployHash {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "deploy-hash({})", self.0,)
+    }
+}
+
+impl From<Digest> for DeployHash {
+    fn from(digest: Digest) -> Self {
+        Self(digest)
+    }
+}
+
+impl AsRef<[u8]> for DeployHash {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl ToBytes for DeployHash {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for DeployHash {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        Digest::from_bytes(bytes).map(|(inner, remainder)| (DeployHash(inner), remainder))
+    }
+}
+
+/// The header portion of a [`Deploy`](struct.Deploy.html).
+#[derive(
+    Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize, Debug, JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub struct DeployHeader {
+    account: PublicKey,
+    timestamp: Timestamp,
+    ttl: TimeDiff,
+    gas_price: u64,
+    body_hash: Digest,
+    dependencies: Vec<DeployHash>,
+    chain_name: String,
+}
+
+impl DeployHeader {
+    /// The account within which the deploy will be run.
+    pub fn account(&self) -> &PublicKey {
+        &self.account
+    }
+
+    /// When the deploy was created.
+    pub fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    /// How long the deploy will stay valid.
+    pub fn ttl(&self) -> TimeDiff {
+        self.ttl
+    }
+
+    /// Has this deploy expired?
+    pub fn expired(&self, current_instant: Timestamp) -> bool {
+        let lifespan = self.timestamp + self.ttl;
+        lifespan < current_instant
+    }
+
+    /// Price per gas unit for this deploy.
+    pub fn gas_price(&self) -> u64 {
+        self.gas_price
+    }
+
+    /// Hash of the Wasm code.
+    pub fn body_hash(&self) -> &Digest {
+        &self.body_hash
+    }
+
+    /// Other deploys that have to be run before this one.
+    pub fn dependencies(&self) -> &Vec<DeployHash> {
+        &self.dependencies
+    }
+
+    /// Which chain the deploy is supposed to be run on.
+    pub fn chain_name(&self) -> &str {
+        &self.chain_name
+    }
+}
+
+impl DeployHeader {
+    /// Returns the timestamp of when the deploy expires, i.e. `self.timestamp + self.ttl`.
+    pub fn expires(&self) -> Timestamp {
+        self.timestamp + self.ttl
+    }
+}
+
+impl ToBytes for DeployHeader {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.account.to_bytes()?);
+        buffer.extend(self.timestamp.to_bytes()?);
+        buffer.extend(self.ttl.to_bytes()?);
+        buffer.extend(self.gas_price.to_bytes()?);
+        buffer.extend(self.body_hash.to_bytes()?);
+        buffer.extend(self.dependencies.to_bytes()?);
+        buffer.extend(self.chain_name.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.account.serialized_length()
+            + self.timestamp.serialized_length()
+            + self.ttl.serialized_length()
+            + self.gas_price.serialized_length()
+            + self.body_hash.serialized_length()
+            + self.dependencies.serialized_length()
+            + self.chain_name.serialized_length()
+    }
+}
+
+impl FromBytes for DeployHeader {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (account, remainder) = PublicKey::from_bytes(bytes)?;
+        let (timestamp, remainder) = Timestamp::from_bytes(remainder)?;
+        let (ttl, remainder) = TimeDiff::from_bytes(remainder)?;
+        let (gas_price, remainder) = u64::from_bytes(remainder)?;
+        let (body_hash, remainder) = Digest::from_bytes(remainder)?;
+        let (dependencies, remainder) = Vec::<DeployHash>::from_bytes(remainder)?;
+        let (chain_name, remainder) = String::from_bytes(remainder)?;
+        let deploy_header = DeployHeader {
+            account,
+            timestamp,
+            ttl,
+            gas_price,
+            body_hash,
+            dependencies,
+            chain_name,
+        };
+        Ok((deploy_header, remainder))
+    }
+}
+
+impl Display for DeployHeader {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "deploy-header[account: {}, timestamp: {}, ttl: {}, gas_price: {}, body_hash: {}, dependencies: [{}], chain_name: {}]",
+            self.account,
+            self.timestamp,
+            self.ttl,
+            self.gas_price,
+            self.body_hash,
+            DisplayIter::new(self.dependencies.iter()),
+            self.chain_name,
+        )
+    }
+}
+
+/// A struct containing a signature and the public key of the signer.
+#[derive(
+    Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize, Debug, JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub struct Approval {
+    signer: PublicKey,
+    signature: Signature,
+}
+
+impl Approval {
+    /// Returns the public key of the approval's signer.
+    pub fn signer(&self) -> &PublicKey {
+        &self.signer
+    }
+
+    /// Returns the approval signature.
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+}
+
+impl Display for Approval {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "approval({})", self.signer)
+    }
+}
+
+impl ToBytes for Approval {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.signer.to_bytes()?);
+        buffer.extend(self.signature.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.signer.serialized_length() + self.signature.serialized_length()
+    }
+}
+
+impl FromBytes for Approval {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (signer, remainder) = PublicKey::from_bytes(bytes)?;
+        let (signature, remainder) = Signature::from_bytes(remainder)?;
+        let approval = Approval { signer, signature };
+        Ok((approval, remainder))
+    }
+}
+
+/// A deploy; an item containing a smart contract along with the requester's signature(s).
+#[derive(
+    Clone, DataSize, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize, Debug, JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub struct Deploy {
+    hash: DeployHash,
+    header: DeployHeader,
+    payment: ExecutableDeployItem,
+    session: ExecutableDeployItem,
+    approvals: Vec<Approval>,
+    #[serde(skip)]
+    is_valid: Option<Result<(), DeployValidationFailure>>,
+}
+
+impl Deploy {
+    /// Constructs a new signed `Deploy`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new<R: CryptoRng + RngCore>(
+        timestamp: Timestamp,
+        ttl: TimeDiff,
+        gas_price: u64,
+        dependencies: Vec<DeployHash>,
+        chain_name: String,
+        payment: ExecutableDeployItem,
+        session: ExecutableDeployItem,
+        secret_key: &SecretKey,
+        rng: &mut R,
+    ) -> Deploy {
+        let serialized_body = serialize_body(&payment, &session);
+        let body_hash = hash::hash(&serialized_body);
+
+        let account = PublicKey::from(secret_key);
+        // Remove duplicates.
+        let dependencies = dependencies.into_iter().unique().collect();
+        let header = DeployHeader {
+            account,
+            timestamp,
+            ttl,
+            gas_price,
+            body_hash,
+            dependencies,
+            chain_name,
+        };
+        let serialized_header = serialize_header(&header);
+        let hash = DeployHash::new(hash::hash(&serialized_header));
+
+        let mut deploy = Deploy {
+            hash,
+            header,
+            payment,
+            session,
+            approvals: vec![],
+            is_valid: None,
+        };
+
+        deploy.sign(secret_key, rng);
+        deploy
+    }
+
+    /// Adds a signature of this deploy's hash to its approvals.
+    ///
+    /// Does nothing if the key has approved this deploy already.
+    pub fn sign<R: CryptoRng + RngCore>(&mut self, secret_key: &SecretKey, rng: &mut R) {
+        let signer = PublicKey::from(secret_key);
+        let signature = crypto::sign(&self.hash, secret_key, &signer, rng);
+        let approval = Approval { signer, signature };
+        self.add_approval(approval);
+    }
+
+    /// Adds the given approval, e.g. one collected from a copy of this deploy signed elsewhere.
+    ///
+    /// Returns `false` without adding it if the signer has approved this deploy already.  The
+    /// approval's signature is not verified here, but as part of `is_valid`.
+    pub fn add_approval(&mut self, approval: Approval) -> bool {
+        if self
+            .approvals
+            .iter()
+            .any(|existing| existing.signer == approval.signer)
+        {
+            return false;
+        }
+        self.approvals.push(approval);
+        self.is_valid = None;
+        true
+    }
+
+    /// Returns the `DeployHash` identifying this `Deploy`.
+    pub fn id(&self) -> &DeployHash {
+        &self.hash
+    }
+
+    /// Returns a reference to the `DeployHeader` of this `Deploy`.
+    pub fn header(&self) -> &DeployHeader {
+        &self.header
+    }
+
+    /// Returns the `DeployHeader` of this `Deploy`.
+    pub fn take_header(self) -> DeployHeader {
+        self.header
+    }
+
+    /// Returns the `ExecutableDeployItem` for payment code.
+    pub fn payment(&self) -> &ExecutableDeployItem {
+        &self.payment
+    }
+
+    /// Returns the `ExecutableDeployItem` for session code.
+    pub fn session(&self) -> &ExecutableDeployItem {
+        &self.session
+    }
+
+    /// Returns the `Approval`s for this deploy.
+    pub fn approvals(&self) -> &[Approval] {
+        &self.approvals
+    }
+
+    /// Returns true if and only if:
+    ///   * the deploy hash is correct (should be the hash of the header), and
+    ///   * the body hash is correct (should be the hash of the body), and
+    ///   * all approvals are valid signatures of the deploy hash
+    pub fn is_valid(&mut self) -> Result<(), DeployValidationFailure> {
+        match self.is_valid.as_ref() {
+            None => {
+                let validity = validate_deploy(self);
+                self.is_valid = Some(validity.clone());
+                validity
+            }
+            Some(validity) => validity.clone(),
+        }
+    }
+
+    /// Returns the result of the last call to `is_valid`, or `None` if it hasn't been called since
+    /// the deploy was constructed or last had an approval added.
+    #[cfg(any(feature = "testing", test))]
+    pub fn validity(&self) -> Option<&Result<(), DeployValidationFailure>> {
+        self.is_valid.as_ref()
+    }
+
+    /// Returns an example value for documentation purposes.
+    pub fn example() -> &'static Self {
+        &*DEPLOY
+    }
+
+    /// Generates a random instance using a `TestRng`.
+    #[cfg(any(feature = "testing", test))]
+    pub fn random(rng: &mut TestRng) -> Self {
+        let timestamp = Timestamp::random(rng);
+        let ttl = TimeDiff::from(rng.gen_range(60_000, 3_600_000));
+        let gas_price = rng.gen_range(1, 100);
+
+        let dependencies = vec![
+            DeployHash::new(hash::hash(rng.next_u64().to_le_bytes())),
+            DeployHash::new(hash::hash(rng.next_u64().to_le_bytes())),
+            DeployHash::new(hash::hash(rng.next_u64().to_le_bytes())),
+        ];
+        let chain_name = String::from("casper-example");
+
+        let payment = rng.gen();
+        let session = rng.gen();
+
+        let secret_key = SecretKey::random(rng);
+
+        Deploy::new(
+            timestamp,
+            ttl,
+            gas_price,
+            dependencies,
+            chain_name,
+            payment,
+            session,
+            &secret_key,
+            rng,
+        )
+    }
+}
+
+fn serialize_header(header: &DeployHeader) -> Vec<u8> {
+    header
+        .to_bytes()
+        .unwrap_or_else(|error| panic!("should serialize deploy header: {}", error))
+}
+
+fn serialize_body(payment: &ExecutableDeployItem, session: &ExecutableDeployItem) -> Vec<u8> {
+    let mut buffer = payment
+        .to_bytes()
+        .unwrap_or_else(|error| panic!("should serialize payment code: {}", error));
+    buffer.extend(
+        session
+            .to_bytes()
+            .unwrap_or_else(|error| panic!("should serialize session code: {}", error)),
+    );
+    buffer
+}
+
+// Computationally expensive validity check for a given deploy instance, including
+// asymmetric_key signing verification.
+fn validate_deploy(deploy: &Deploy) -> Result<(), DeployValidationFailure> {
+    let serialized_body = serialize_body(&deploy.payment, &deploy.session);
+    let body_hash = hash::hash(&serialized_body);
+    if body_hash != deploy.header.body_hash {
+        warn!(?deploy, ?body_hash, "invalid deploy body hash");
+        return Err(DeployValidationFailure::InvalidBodyHash);
+    }
+
+    let serialized_header = serialize_header(&deploy.header);
+    let hash = DeployHash::new(hash::hash(&serialized_header));
+    if hash != deploy.hash {
+        warn!(?deploy, ?hash, "invalid deploy hash");
+        return Err(DeployValidationFailure::InvalidDeployHash);
+    }
+
+    // We don't need to check for an empty set here. EE checks that the correct number and weight of
+    // signatures are provided when executing the deploy, so all we need to do here is check that
+    // any provided signatures are valid.
+    for (index, approval) in deploy.approvals.iter().enumerate() {
+        if let Err(error) = crypto::verify(&deploy.hash, &approval.signature, &approval.signer) {
+            warn!(?deploy, "failed to verify approval {}: {}", index, error);
+            return Err(DeployValidationFailure::InvalidApproval {
+                index,
+                error_msg: error.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+impl Display for Deploy {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "deploy[{}, {}, payment_code: {}, session_code: {}, approvals: {}]",
+            self.hash,
+            self.header,
+            self.payment,
+            self.session,
+            DisplayIter::new(self.approvals.iter())
+        )
+    }
+}
+
+impl ToBytes for Deploy {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.header.to_bytes()?);
+        buffer.extend(self.hash.to_bytes()?);
+        buffer.extend(self.payment.to_bytes()?);
+        buffer.extend(self.session.to_bytes()?);
+        buffer.extend(self.approvals.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.header.serialized_length()
+            + self.hash.serialized_length()
+            + self.payment.serialized_length()
+            + self.session.serialized_length()
+            + self.approvals.serialized_length()
+    }
+}
+
+impl FromBytes for Deploy {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (header, remainder) = DeployHeader::from_bytes(bytes)?;
+        let (hash, remainder) = DeployHash::from_bytes(remainder)?;
+        let (payment, remainder) = ExecutableDeployItem::from_bytes(remainder)?;
+        let (session, remainder) = ExecutableDeployItem::from_bytes(remainder)?;
+        let (approvals, remainder) = Vec::<Approval>::from_bytes(remainder)?;
+        let maybe_valid_deploy = Deploy {
+            header,
+            hash,
+            payment,
+            session,
+            approvals,
+            is_valid: None,
+        };
+        Ok((maybe_valid_deploy, remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use casper_types::{bytesrepr::Bytes, system::standard_payment::ARG_AMOUNT, CLValue};
+
+    use super::*;
+
+    #[test]
+    fn json_roundtrip() {
+        let mut rng = TestRng::new();
+        let deploy = Deploy::random(&mut rng);
+        let json_string = serde_json::to_string_pretty(&deploy).unwrap();
+        let decoded = serde_json::from_str(&json_string).unwrap();
+        assert_eq!(deploy, decoded);
+    }
+
+    #[test]
+    fn bincode_roundtrip() {
+        let mut rng = TestRng::new();
+        let deploy = Deploy::random(&mut rng);
+        let serialized = bincode::serialize(&deploy).unwrap();
+        let deserialized = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deploy, deserialized);
+    }
+
+    #[test]
+    fn bytesrepr_roundtrip() {
+        let mut rng = TestRng::new();
+        let hash = DeployHash(Digest::random(&mut rng));
+        bytesrepr::test_serialization_roundtrip(&hash);
+
+        let deploy = Deploy::random(&mut rng);
+        bytesrepr::test_serialization_roundtrip(deploy.header());
+        bytesrepr::test_serialization_roundtrip(&deploy);
+    }
+
+    fn create_deploy(rng: &mut TestRng) -> Deploy {
+        let secret_key = SecretKey::random(rng);
+        let transfer_args = {
+            let mut transfer_args = RuntimeArgs::new();
+            let value =
+                CLValue::from_t(U512::from(2_500_000_000u64)).expect("should create CLValue");
+            transfer_args.insert_cl_value(ARG_AMOUNT, value);
+            transfer_args
+        };
+        Deploy::new(
+            Timestamp::now(),
+            TimeDiff::from(Duration::from_secs(3_600)),
+            1,
+            vec![],
+            "net-1".to_string(),
+            ExecutableDeployItem::ModuleBytes {
+                module_bytes: Bytes::new(),
+                args: RuntimeArgs::new(),
+            },
+            ExecutableDeployItem::Transfer {
+                args: transfer_args,
+            },
+            &secret_key,
+            rng,
+        )
+    }
+
+    #[test]
+    fn is_valid() {
+        let mut rng = TestRng::new();
+        let mut deploy = create_deploy(&mut rng);
+        assert_eq!(deploy.is_valid, None, "is valid should initially be None");
+        deploy.is_valid().expect("should be valid");
+        assert_eq!(deploy.is_valid, Some(Ok(())), "is valid should be true");
+    }
+
+    fn check_is_not_valid(mut invalid_deploy: Deploy, expected_error: DeployValidationFailure) {
+        assert!(
+            invalid_deploy.is_valid.is_none(),
+            "is valid should initially be None"
+        );
+        let actual_error = invalid_deploy.is_valid().unwrap_err();
+
+        // Ignore the `error_msg` field of `InvalidApproval` when comparing to expected error, as
+        // this makes the test too fragile.  Otherwise expect the actual error should exactly match
+        // the expected error.
+        match expected_error {
+            DeployValidationFailure::InvalidApproval {
+                index: expected_index,
+                ..
+            } => match actual_error {
+                DeployValidationFailure::InvalidApproval {
+                    index: actual_index,
+                    ..
+                } => {
+                    assert_eq!(actual_index, expected_index);
+                }
+                _ => panic!("expected {}, got: {}", expected_error, actual_error),
+            },
+            _ => {
+                assert_eq!(actual_error, expected_error,);
+            }
+        }
+
+        // The actual error should have been lazily initialized correctly.
+        assert_eq!(
+            invalid_deploy.is_valid,
+            Some(Err(actual_error)),
+            "is valid should now be Some"
+        );
+    }
+
+    #[test]
+    fn not_valid_due_to_invalid_body_hash() {
+        let mut rng = TestRng::new();
+        let mut deploy = create_deploy(&mut rng);
+
+        deploy.session = ExecutableDeployItem::Transfer {
+            args: runtime_args! {
+                "amount" => 1
+            },
+        };
+        check_is_not_valid(deploy, DeployValidationFailure::InvalidBodyHash);
+    }
+
+    #[test]
+    fn not_valid_due_to_invalid_deploy_hash() {
+        let mut rng = TestRng::new();
+        let mut deploy = create_deploy(&mut rng);
+
+        deploy.header.gas_price = 2;
+        check_is_not_valid(deploy, DeployValidationFailure::InvalidDeployHash);
+    }
+
+    #[test]
+    fn not_valid_due_to_invalid_approval() {
+        let mut rng = TestRng::new();
+        let mut deploy = create_deploy(&mut rng);
+
+        let deploy2 = Deploy::random(&mut rng);
+
+        deploy.approvals.extend(deploy2.approvals);
+        check_is_not_valid(
+            deploy,
+            DeployValidationFailure::InvalidApproval {
+                index: 1,
+                error_msg: String::new(), // This field is ignored in the check.
+            },
+        );
+    }
+}
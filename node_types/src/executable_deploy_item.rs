@@ -0,0 +1,514 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use std::fmt::{self, Debug, Display, Formatter};
+
+use datasize::DataSize;
+use hex_buffer_serde::{Hex, HexForm};
+use hex_fmt::HexFmt;
+use rand::{
+    distributions::{Alphanumeric, Distribution, Standard},
+    Rng,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    contracts::{ContractVersion, DEFAULT_ENTRY_POINT_NAME},
+    system::mint::ARG_AMOUNT,
+    CLValue, ContractHash, ContractPackageHash, RuntimeArgs, U512,
+};
+
+/// The minimum amount of a randomly generated transfer; matches the execution engine's maximum
+/// payment amount.
+const MAX_PAYMENT_AMOUNT: u64 = 2_500_000_000;
+
+const TAG_LENGTH: usize = U8_SERIALIZED_LENGTH;
+const MODULE_BYTES_TAG: u8 = 0;
+const STORED_CONTRACT_BY_HASH_TAG: u8 = 1;
+const STORED_CONTRACT_BY_NAME_TAG: u8 = 2;
+const STORED_VERSIONED_CONTRACT_BY_HASH_TAG: u8 = 3;
+const STORED_VERSIONED_CONTRACT_BY_NAME_TAG: u8 = 4;
+const TRANSFER_TAG: u8 = 5;
+
+/// The executable component of a deploy: either Wasm to run or a reference to stored code.
+#[derive(
+    Clone, DataSize, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(deny_unknown_fields)]
+pub enum ExecutableDeployItem {
+    /// Raw Wasm bytes, calling the implicit `call` entry point.
+    ModuleBytes {
+        /// The raw Wasm bytes.
+        #[serde(with = "HexForm")]
+        #[schemars(with = "String", description = "Hex-encoded raw Wasm bytes.")]
+        module_bytes: Bytes,
+        /// The runtime arguments.
+        args: RuntimeArgs,
+    },
+    /// A stored contract referenced by its hash.
+    StoredContractByHash {
+        /// The contract hash.
+        #[serde(with = "HexForm")]
+        #[schemars(with = "String", description = "Hex-encoded hash.")]
+        hash: ContractHash,
+        /// The entry point to call.
+        entry_point: String,
+        /// The runtime arguments.
+        args: RuntimeArgs,
+    },
+    /// A stored contract referenced by a named key in the caller's account.
+    StoredContractByName {
+        /// The named key under which the contract is stored.
+        name: String,
+        /// The entry point to call.
+        entry_point: String,
+        /// The runtime arguments.
+        args: RuntimeArgs,
+    },
+    /// A versioned stored contract referenced by its package hash.
+    StoredVersionedContractByHash {
+        /// The contract package hash.
+        #[serde(with = "HexForm")]
+        #[schemars(with = "String", description = "Hex-encoded hash.")]
+        hash: ContractPackageHash,
+        /// The contract version; defaults to the highest enabled version if `None`.
+        version: Option<ContractVersion>
+        /// The entry point to call.
+        entry_point: String,
+        /// The runtime arguments.
+        args: RuntimeArgs,
+    },
+    /// A versioned stored contract referenced by a named key in the caller's account.
+    StoredVersionedContractByName {
+        /// The named key under which the contract is stored.
+        name: String,
+        /// The contract version; defaults to the highest enabled version if `None`.
+        version: Option<ContractVersion>
+        /// The entry point to call.
+        entry_point: String,
+        /// The runtime arguments.
+        args: RuntimeArgs,
+    },
+    /// A native transfer.
+    Transfer {
+        /// The transfer's runtime arguments.
+        args: RuntimeArgs,
+    },
+}
+
+impl ExecutableDeployItem {
+    /// Returns the name of the entry point to be called.
+    pub fn entry_point_name(&self) -> &str {
+        match self {
+            ExecutableDeployItem::ModuleBytes { .. } | ExecutableDeployItem::Transfer { .. } => {
+                DEFAULT_ENTRY_POINT_NAME
+            }
+            ExecutableDeployItem::StoredVersionedContractByName { entry_point, .. }
+            | ExecutableDeployItem::StoredVersionedContractByHash { entry_point, .. }
+            | ExecutableDeployItem::StoredContractByHash { entry_point, .. }
+            | ExecutableDeployItem::StoredContractByName { entry_point, .. } => &entry_point,
+        }
+    }
+
+    /// Returns the runtime arguments.
+    pub fn args(&self) -> &RuntimeArgs {
+        match self {
+            ExecutableDeployItem::ModuleBytes { args, .. }
+            | ExecutableDeployItem::StoredContractByHash { args, .. }
+            | ExecutableDeployItem::StoredContractByName { args, .. }
+            | ExecutableDeployItem::StoredVersionedContractByHash { args, .. }
+            | ExecutableDeployItem::StoredVersionedContractByName { args, .. }
+            | ExecutableDeployItem::Transfer { args } => args,
+        }
+    }
+
+    /// Returns `true` if this is a native transfer.
+    pub fn is_transfer(&self) -> bool {
+        matches!(self, ExecutableDeployItem::Transfer { .. })
+    }
+}
+
+impl ToBytes for ExecutableDeployItem {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        match self {
+            ExecutableDeployItem::ModuleBytes { module_bytes, args } => {
+                buffer.insert(0, MODULE_BYTES_TAG);
+                buffer.extend(module_bytes.to_bytes()?);
+                buffer.extend(args.to_bytes()?);
+            }
+            ExecutableDeployItem::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+            } => {
+                buffer.insert(0, STORED_CONTRACT_BY_HASH_TAG);
+                buffer.extend(hash.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?)
+            }
+            ExecutableDeployItem::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            } => {
+                buffer.insert(0, STORED_CONTRACT_BY_NAME_TAG);
+                buffer.extend(name.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?)
+            }
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash,
+                version,
+                entry_point,
+                args,
+            } => {
+                buffer.insert(0, STORED_VERSIONED_CONTRACT_BY_HASH_TAG);
+                buffer.extend(hash.to_bytes()?);
+                buffer.extend(version.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?)
+            }
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                args,
+            } => {
+                buffer.insert(0, STORED_VERSIONED_CONTRACT_BY_NAME_TAG);
+                buffer.extend(name.to_bytes()?);
+                buffer.extend(version.to_bytes()?);
+                buffer.extend(entry_point.to_bytes()?);
+                buffer.extend(args.to_bytes()?)
+            }
+            ExecutableDeployItem::Transfer { args } => {
+                buffer.insert(0, TRANSFER_TAG);
+                buffer.extend(args.to_bytes()?)
+            }
+        }
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        TAG_LENGTH
+            + match self {
+                ExecutableDeployItem::ModuleBytes { module_bytes, args } => {
+                    module_bytes.serialized_length() + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredContractByHash {
+                    hash,
+                    entry_point,
+                    args,
+                } => {
+                    hash.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredContractByName {
+                    name,
+                    entry_point,
+                    args,
+                } => {
+                    name.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredVersionedContractByHash {
+                    hash,
+                    version,
+                    entry_point,
+                    args,
+                } => {
+                    hash.serialized_length()
+                        + version.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::StoredVersionedContractByName {
+                    name,
+                    version,
+                    entry_point,
+                    args,
+                } => {
+                    name.serialized_length()
+                        + version.serialized_length()
+                        + entry_point.serialized_length()
+                        + args.serialized_length()
+                }
+                ExecutableDeployItem::Transfer { args } => args.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for ExecutableDeployItem {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            MODULE_BYTES_TAG => {
+                let (module_bytes, remainder) = FromBytes::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::ModuleBytes { module_bytes, args },
+                    remainder,
+                ))
+            }
+            STORED_CONTRACT_BY_HASH_TAG => {
+                let (hash, remainder) = FromBytes::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredContractByHash {
+                        hash,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_CONTRACT_BY_NAME_TAG => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredContractByName {
+                        name,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_VERSIONED_CONTRACT_BY_HASH_TAG => {
+                let (hash, remainder) = FromBytes::from_bytes(remainder)?;
+                let (version, remainder) = Option::<ContractVersion>::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredVersionedContractByHash {
+                        hash,
+                        version,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            STORED_VERSIONED_CONTRACT_BY_NAME_TAG => {
+                let (name, remainder) = String::from_bytes(remainder)?;
+                let (version, remainder) = Option::<ContractVersion>::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((
+                    ExecutableDeployItem::StoredVersionedContractByName {
+                        name,
+                        version,
+                        entry_point,
+                        args,
+                    },
+                    remainder,
+                ))
+            }
+            TRANSFER_TAG => {
+                let (args, remainder) = FromBytes::from_bytes(remainder)?;
+                Ok((ExecutableDeployItem::Transfer { args }, remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+impl Display for ExecutableDeployItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutableDeployItem::ModuleBytes { module_bytes, .. } => {
+                write!(f, "module-bytes [{} bytes]", module_bytes.len())
+            }
+            ExecutableDeployItem::StoredContractByHash {
+                hash, entry_point, ..
+            } => write!(
+                f,
+                "stored-contract-by-hash: {:10}, entry-point: {}",
+                HexFmt(hash),
+                entry_point,
+            ),
+            ExecutableDeployItem::StoredContractByName {
+                name, entry_point, ..
+            } => write!(
+                f,
+                "stored-contract-by-name: {}, entry-point: {}",
+                name, entry_point,
+            ),
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash,
+                version: Some(ver),
+                entry_point,
+                ..
+            } => write!(
+                f,
+                "stored-versioned-contract-by-hash: {:10}, version: {}, entry-point: {}",
+                HexFmt(hash),
+                ver,
+                entry_point,
+            ),
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash, entry_point, ..
+            } => write!(
+                f,
+                "stored-versioned-contract-by-hash: {:10}, version: latest, entry-point: {}",
+                HexFmt(hash),
+                entry_point,
+            ),
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name,
+                version: Some(ver),
+                entry_point,
+                ..
+            } => write!(
+                f,
+                "stored-versioned-contract: {}, version: {}, entry-point: {}",
+                name, ver, entry_point,
+            ),
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name, entry_point, ..
+            } => write!(
+                f,
+                "stored-versioned-contract: {}, version: latest, entry-point: {}",
+                name, entry_point,
+            ),
+            ExecutableDeployItem::Transfer { .. } => write!(f, "transfer"),
+        }
+    }
+}
+
+impl Debug for ExecutableDeployItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutableDeployItem::ModuleBytes { module_bytes, args } => f
+                .debug_struct("ModuleBytes")
+                .field("module_bytes", &format!("[{} bytes]", module_bytes.len()))
+                .field("args", args)
+                .finish(),
+            ExecutableDeployItem::StoredContractByHash {
+                hash,
+                entry_point,
+                args,
+            } => f
+                .debug_struct("StoredContractByHash")
+                .field("hash", &HexFmt(hash))
+                .field("entry_point", &entry_point)
+                .field("args", args)
+                .finish(),
+            ExecutableDeployItem::StoredContractByName {
+                name,
+                entry_point,
+                args,
+            } => f
+                .debug_struct("StoredContractByName")
+                .field("name", &name)
+                .field("entry_point", &entry_point)
+                .field("args", args)
+                .finish(),
+            ExecutableDeployItem::StoredVersionedContractByHash {
+                hash,
+                version,
+                entry_point,
+                args,
+            } => f
+                .debug_struct("StoredVersionedContractByHash")
+                .field("hash", &HexFmt(hash))
+                .field("version", version)
+                .field("entry_point", &entry_point)
+                .field("args", args)
+                .finish(),
+            ExecutableDeployItem::StoredVersionedContractByName {
+                name,
+                version,
+                entry_point,
+                args,
+            } => f
+                .debug_struct("StoredVersionedContractByName")
+                .field("name", &name)
+                .field("version", version)
+                .field("entry_point", &entry_point)
+                .field("args", args)
+                .finish(),
+            ExecutableDeployItem::Transfer { args } => {
+                f.debug_struct("Transfer").field("args", args).finish()
+            }
+        }
+    }
+}
+
+impl Distribution<ExecutableDeployItem> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ExecutableDeployItem {
+        fn random_bytes<R: Rng + ?Sized>(rng: &mut R) -> Vec<u8> {
+            let mut bytes = vec![0u8; rng.gen_range(0, 100)];
+            rng.fill_bytes(bytes.as_mut());
+            bytes
+        }
+
+        fn random_string<R: Rng + ?Sized>(rng: &mut R) -> String {
+            rng.sample_iter(&Alphanumeric).take(20).collect()
+        }
+
+        let mut args = RuntimeArgs::new();
+        let _ = args.insert(random_string(rng), Bytes::from(random_bytes(rng)));
+
+        match rng.gen_range(0, 5) {
+            0 => ExecutableDeployItem::ModuleBytes {
+                module_bytes: random_bytes(rng).into(),
+                args,
+            },
+            1 => ExecutableDeployItem::StoredContractByHash {
+                hash: ContractHash::new(rng.gen()),
+                entry_point: random_string(rng),
+                args,
+            },
+            2 => ExecutableDeployItem::StoredContractByName {
+                name: random_string(rng),
+                entry_point: random_string(rng),
+                args,
+            },
+            3 => ExecutableDeployItem::StoredVersionedContractByHash {
+                hash: ContractPackageHash::new(rng.gen()),
+                version: rng.gen(),
+                entry_point: random_string(rng),
+                args,
+            },
+            4 => ExecutableDeployItem::StoredVersionedContractByName {
+                name: random_string(rng),
+                version: rng.gen(),
+                entry_point: random_string(rng),
+                args,
+            },
+            5 => {
+                let amount = rng.gen_range(MAX_PAYMENT_AMOUNT, 1_000_000_000_000_000);
+                let mut transfer_args = RuntimeArgs::new();
+                transfer_args.insert_cl_value(
+                    ARG_AMOUNT,
+                    CLValue::from_t(U512::from(amount)).expect("should get CLValue from U512"),
+                );
+                ExecutableDeployItem::Transfer {
+                    args: transfer_args,
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization_roundtrip() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let executable_deploy_item: ExecutableDeployItem = rng.gen();
+            bytesrepr::test_serialization_roundtrip(&executable_deploy_item);
+        }
+    }
+}
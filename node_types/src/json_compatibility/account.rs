@@ -0,0 +1,70 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use datasize::DataSize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{account::AccountHash, NamedKey, URef};
+
+/// An associated key and its weight.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize, DataSize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AssociatedKey {
+    /// The account hash of the associated key.
+    pub account_hash: AccountHash,
+    /// The weight of the associated key.
+    pub weight: u8,
+}
+
+/// Thresholds that have to be met when executing an action of a certain type.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize, DataSize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ActionThresholds {
+    /// Threshold for deploy execution.
+    pub deployment: u8,
+    /// Threshold for managing action threshold.
+    pub key_management: u8,
+}
+
+/// Structure representing a user's account, stored in global state.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize, DataSize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Account {
+    account_hash: AccountHash,
+    #[data_size(skip)]
+    named_keys: Vec<NamedKey>,
+    #[data_size(skip)]
+    main_purse: URef,
+    associated_keys: Vec<AssociatedKey>,
+    action_thresholds: ActionThresholds,
+}
+
+impl Account {
+    /// Constructs a new `Account`.
+    pub fn new(
+        account_hash: AccountHash,
+        named_keys: Vec<NamedKey>,
+        main_purse: URef,
+        associated_keys: Vec<AssociatedKey>,
+        action_thresholds: ActionThresholds,
+    ) -> Self {
+        Account {
+            account_hash,
+            named_keys,
+            main_purse,
+            associated_keys,
+            action_thresholds,
+        }
+    }
+
+    /// Returns the named keys of the account.
+    pub fn named_keys(&self) -> &[NamedKey] {
+        &self.named_keys
+    }
+
+    /// Returns the main purse of the account.
+    pub fn main_purse(&self) -> URef {
+        self.main_purse
+    }
+}
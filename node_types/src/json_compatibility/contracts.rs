@@ -6,12 +6,13 @@ use schemars::JsonSchema;
 use semver::Version;
 use serde::{Deserialize, Serialize};
 
-use crate::types::json_compatibility::vectorize;
 use casper_types::{
     Contract as DomainContract, ContractHash, ContractPackage as DomainContractPackage,
     ContractPackageHash, ContractWasmHash, EntryPoint, NamedKey, URef,
 };
 
+use super::vectorize;
+
 #[derive(
     Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, DataSize, JsonSchema,
 )]
@@ -21,6 +22,23 @@ pub struct ContractVersion {
     contract_hash: ContractHash,
 }
 
+impl ContractVersion {
+    /// Returns the major version of the protocol under which the contract was added.
+    pub fn protocol_version_major(&self) -> u32 {
+        self.protocol_version_major
+    }
+
+    /// Returns the version of the contract within the package.
+    pub fn contract_version(&self) -> u32 {
+        self.contract_version
+    }
+
+    /// Returns the hash of the contract.
+    pub fn contract_hash(&self) -> ContractHash {
+        self.contract_hash
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, DataSize, JsonSchema)]
 pub struct DisabledVersion {
     protocol_version_major: u32,
@@ -49,6 +67,18 @@ pub struct Contract {
     protocol_version: Version,
 }
 
+impl Contract {
+    /// Returns the hash of the package containing the contract.
+    pub fn contract_package_hash(&self) -> ContractPackageHash {
+        self.contract_package_hash
+    }
+
+    /// Returns the named keys of the contract.
+    pub fn named_keys(&self) -> &[NamedKey] {
+        &self.named_keys
+    }
+}
+
 impl From<&DomainContract> for Contract {
     fn from(contract: &DomainContract) -> Self {
         let entry_points = contract.entry_points().clone().take_entry_points();
@@ -78,6 +108,13 @@ pub struct ContractPackage {
     groups: Vec<Groups>,
 }
 
+impl ContractPackage {
+    /// Returns the versions of the contracts in the package, including disabled ones.
+    pub fn versions(&self) -> &[ContractVersion] {
+        &self.versions
+    }
+}
+
 impl From<&DomainContractPackage> for ContractPackage {
     fn from(contract_package: &DomainContractPackage) -> Self {
         let versions = contract_package
@@ -0,0 +1,42 @@
+//! This file provides a type mirroring the execution engine's `StoredValue` which can be
+//! serialized to a valid JSON representation.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{
+    system::auction::{Bid, EraInfo},
+    CLValue, DeployInfo, Transfer,
+};
+
+use super::{Account, Contract, ContractPackage};
+
+/// Representation of a value stored in global state.
+///
+/// `Account`, `Contract` and `ContractPackage` have their own `json_compatibility` representations
+/// (see their docs for further info).
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum StoredValue {
+    /// A CasperLabs value.
+    CLValue(CLValue),
+    /// An account.
+    Account(Account),
+    /// A contract's Wasm
+    ContractWasm(String),
+    /// Methods and type signatures supported by a contract.
+    Contract(Contract),
+    /// A contract definition, metadata, and security container.
+    ContractPackage(ContractPackage),
+    /// A record of a transfer
+    Transfer(Transfer),
+    /// A record of a deploy
+    DeployInfo(DeployInfo),
+    /// Auction metadata
+    EraInfo(EraInfo),
+    /// A bid
+    Bid(Box<Bid>),
+}
@@ -0,0 +1,37 @@
+//! Types shared by the Casper node and its clients.
+//!
+//! This crate holds the types which appear in the node's JSON-RPC API and which clients need in
+//! order to create and sign deploys: hashes, timestamps, deploys and the JSON representations of
+//! values stored in global state.  It depends on neither the node nor the execution engine, so
+//! clients and SDKs can use it without building either, including for `wasm32-unknown-unknown`
+//! (enable the `js` feature there).
+
+#![doc(html_root_url = "https://docs.rs/casper-node-types/0.9.0")]
+#![doc(
+    html_favicon_url = "https://raw.githubusercontent.com/CasperLabs/casper-node/master/images/CasperLabs_Logo_Favicon_RGB_50px.png",
+    html_logo_url = "https://raw.githubusercontent.com/CasperLabs/casper-node/master/images/CasperLabs_Logo_Symbol_RGB.png",
+    test(attr(forbid(warnings)))
+)]
+#![warn(
+    missing_docs,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_qualifications
+)]
+
+mod block_hash;
+pub mod crypto;
+mod deploy;
+mod executable_deploy_item;
+pub mod json_compatibility;
+#[cfg(any(feature = "testing", test))]
+pub mod testing;
+mod timestamp;
+pub mod utils;
+
+pub use block_hash::{BlockHash, BlockIdentifier};
+pub use deploy::{
+    Approval, Deploy, DeployHash, DeployHeader, DeployValidationFailure, Error as DeployError,
+};
+pub use executable_deploy_item::ExecutableDeployItem;
+pub use timestamp::{TimeDiff, Timestamp};
@@ -1,4 +1,4 @@
-#![cfg(test)]
+//! Helpers for tests, enabled by the `testing` feature.
 
 use std::{
     cell::RefCell,
@@ -149,6 +149,7 @@ impl RngCore for TestRng {
 
 impl CryptoRng for TestRng {}
 
+#[cfg(test)]
 mod tests {
     use super::*;
 
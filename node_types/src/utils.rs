@@ -0,0 +1,92 @@
+//! Various helpers shared by the node and its clients.
+
+use std::{
+    cell::RefCell,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use thiserror::Error;
+
+/// A display-helper that shows iterators display joined by ",".
+#[derive(Debug)]
+pub struct DisplayIter<T>(RefCell<Option<T>>);
+
+impl<T> DisplayIter<T> {
+    /// Constructs a new `DisplayIter` wrapping `item`.
+    pub fn new(item: T) -> Self {
+        DisplayIter(RefCell::new(Some(item)))
+    }
+}
+
+impl<I, T> Display for DisplayIter<I>
+where
+    I: IntoIterator<Item = T>,
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(src) = self.0.borrow_mut().take() {
+            let mut first = true;
+            for item in src.into_iter().take(f.width().unwrap_or(usize::MAX)) {
+                if first {
+                    first = false;
+                    write!(f, "{}", item)?;
+                } else {
+                    write!(f, ", {}", item)?;
+                }
+            }
+
+            Ok(())
+        } else {
+            write!(f, "DisplayIter:GONE")
+        }
+    }
+}
+
+/// Error reading a file.
+#[derive(Debug, Error)]
+#[error("could not read '{0}': {error}", .path.display())]
+pub struct ReadFileError {
+    /// Path that failed to be read.
+    path: PathBuf,
+    /// The underlying OS error.
+    #[source]
+    error: io::Error,
+}
+
+/// Error writing a file
+#[derive(Debug, Error)]
+#[error("could not write to '{0}': {error}", .path.display())]
+pub struct WriteFileError {
+    /// Path that failed to be written to.
+    path: PathBuf,
+    /// The underlying OS error.
+    #[source]
+    error: io::Error,
+}
+
+/// Read complete at `path` into memory.
+///
+/// Wraps `fs::read`, but preserves the filename for better error printing.
+pub fn read_file<P: AsRef<Path>>(filename: P) -> Result<Vec<u8>, ReadFileError> {
+    let path = filename.as_ref();
+    fs::read(path).map_err(|error| ReadFileError {
+        path: path.to_owned(),
+        error,
+    })
+}
+
+/// Write data to `path`.
+///
+/// Wraps `fs::write`, but preserves the filename for better error printing.
+pub fn write_file<P: AsRef<Path>, B: AsRef<[u8]>>(
+    filename: P,
+    data: B,
+) -> Result<(), WriteFileError> {
+    let path = filename.as_ref();
+    fs::write(path, data.as_ref()).map_err(|error| WriteFileError {
+        path: path.to_owned(),
+        error,
+    })
+}
@@ -0,0 +1,344 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use casper_types::{Key, PublicKey, URef};
+
+use crate::{
+    error::{Error, Result},
+    rpcs::{
+        account::{PutDeploy, PutDeployParams, PutDeployResult},
+        chain::{
+            GetBlock, GetBlockFinality, GetBlockFinalityParams, GetBlockFinalityResult,
+            GetBlockParams, GetBlockResult, GetBlockTransfers, GetBlockTransfersParams,
+            GetBlockTransfersResult, GetEraInfoBySwitchBlock, GetEraInfoParams, GetEraInfoResult,
+            GetEraRewardHistory, GetEraRewardHistoryParams, GetEraRewardHistoryResult,
+            GetEraValidatorPerformance, GetEraValidatorPerformanceParams,
+            GetEraValidatorPerformanceResult, GetStateRootHash, GetStateRootHashParams,
+            GetStateRootHashResult,
+        },
+        docs::{ListRpcs, ListRpcsResult},
+        info::{
+            GetDeploy, GetDeployConfig, GetDeployConfigResult, GetDeployParams, GetDeployResult,
+            GetPeers, GetPeersResult, GetStatus, GetStatusResult, GetSupply, GetSupplyParams,
+            GetSupplyResult,
+        },
+        state::{
+            GetAuctionInfo, GetAuctionInfoResult, GetBalance, GetBalanceParams, GetBalanceResult,
+            GetItem, GetItemParams, GetItemResult,
+        },
+        RpcMethod, RPC_API_PATH,
+    },
+    types::{BlockIdentifier, Deploy, DeployHash, Digest},
+};
+
+/// The highest power of two by which the retry delay is multiplied.
+const MAX_BACKOFF_EXPONENT: u32 = 10;
+
+/// Timeouts and retry behavior of a `CasperClient`.
+#[derive(Clone, Debug)]
+pub struct ClientConfig {
+    /// The timeout for a single attempt of a request, from connecting until the response body has
+    /// been read.
+    pub request_timeout: Duration,
+    /// The timeout for establishing a connection.
+    pub connect_timeout: Duration,
+    /// The number of times a request is retried after a timeout, connection failure or server
+    /// error.
+    pub max_retries: u32,
+    /// The delay before the first retry. It doubles with every further retry.
+    pub retry_delay: Duration,
+    /// The maximum number of idle connections kept open to the node.
+    pub max_idle_connections: usize,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            request_timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(10),
+            max_retries: 3,
+            retry_delay: Duration::from_millis(500),
+            max_idle_connections: 8,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRpcRequest<'a> {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    params: Option<&'a Value>,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcError {
+    code: i64,
+    message: String,
+    #[serde(default)]
+    data: Option<Value>,
+}
+
+#[derive(Deserialize)]
+struct JsonRpcResponse {
+    #[serde(default)]
+    result: Option<Value>,
+    #[serde(default)]
+    error: Option<JsonRpcError>,
+}
+
+/// An async client for the JSON-RPC API of a Casper node.
+///
+/// Connections are pooled and reused across requests, and the client is cheap to clone: clones
+/// share the same pool.
+#[derive(Clone, Debug)]
+pub struct CasperClient {
+    http: reqwest::Client,
+    url: String,
+    config: ClientConfig,
+    next_id: Arc<AtomicU64>,
+}
+
+impl CasperClient {
+    /// Creates a client for the node whose HTTP server is at `node_address`, e.g.
+    /// `"http://127.0.0.1:7777"`, using the default config.
+    pub fn new(node_address: &str) -> Result<Self> {
+        Self::with_config(node_address, ClientConfig::default())
+    }
+
+    /// Creates a client for the node whose HTTP server is at `node_address`.
+    pub fn with_config(node_address: &str, config: ClientConfig) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            .connect_timeout(config.connect_timeout)
+            .pool_max_idle_per_host(config.max_idle_connections)
+            .build()
+            .map_err(Error::BuildHttpClient)?;
+        Ok(CasperClient {
+            http,
+            url: format!("{}/{}", node_address.trim_end_matches('/'), RPC_API_PATH),
+            config,
+            next_id: Arc::new(AtomicU64::new(1)),
+        })
+    }
+
+    /// Returns the client's config.
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    /// Calls the given RPC, retrying as configured.
+    pub async fn request<M: RpcMethod>(&self, params: M::Params) -> Result<M::Result> {
+        let value = serde_json::to_value(&params).map_err(Error::SerializeParams)?;
+        let params = if value.is_null() { None } else { Some(&value) };
+        let mut attempt = 0;
+        loop {
+            match self.send(M::METHOD, params).await {
+                Err(error) if error.is_retryable() && attempt < self.config.max_retries => {
+                    let backoff = 2u32.pow(attempt.min(MAX_BACKOFF_EXPONENT));
+                    tokio::time::delay_for(self.config.retry_delay * backoff).await;
+                    attempt += 1;
+                }
+                Err(error) => return Err(error),
+                Ok(result) => {
+                    return serde_json::from_value(result).map_err(Error::InvalidResponse)
+                }
+            }
+        }
+    }
+
+    /// Sends a single request and returns the "result" of the response.
+    async fn send(&self, method: &'static str, params: Option<&Value>) -> Result<Value> {
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0",
+            id: self.next_id.fetch_add(1, Ordering::Relaxed),
+            method,
+            params,
+        };
+        let response = self
+            .http
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(Error::Http)?;
+        if !response.status().is_success() {
+            return Err(Error::HttpStatus(response.status()));
+        }
+        let body = response.bytes().await.map_err(Error::Http)?;
+        let response: JsonRpcResponse =
+            serde_json::from_slice(&body).map_err(Error::InvalidResponse)?;
+        match response {
+            JsonRpcResponse {
+                error: Some(error), ..
+            } => Err(Error::ResponseIsError {
+                code: error.code,
+                message: error.message,
+                data: error.data,
+            }),
+            JsonRpcResponse {
+                result: Some(result),
+                ..
+            } => Ok(result),
+            _ => Err(Error::MissingResult),
+        }
+    }
+
+    /// Sends a deploy to the network for execution.
+    pub async fn put_deploy(&self, deploy: Deploy) -> Result<PutDeployResult> {
+        self.request::<PutDeploy>(PutDeployParams { deploy }).await
+    }
+
+    /// Retrieves a deploy and its execution results.
+    pub async fn get_deploy(&self, deploy_hash: DeployHash) -> Result<GetDeployResult> {
+        self.request::<GetDeploy>(GetDeployParams { deploy_hash })
+            .await
+    }
+
+    /// Retrieves a block, or the latest block if `block_identifier` is `None`.
+    pub async fn get_block(
+        &self,
+        block_identifier: Option<BlockIdentifier>,
+    ) -> Result<GetBlockResult> {
+        let params = block_identifier.map(|block_identifier| GetBlockParams { block_identifier });
+        self.request::<GetBlock>(params).await
+    }
+
+    /// Retrieves the transfers of a block, or of the latest block if `block_identifier` is `None`.
+    pub async fn get_block_transfers(
+        &self,
+        block_identifier: Option<BlockIdentifier>,
+    ) -> Result<GetBlockTransfersResult> {
+        let params =
+            block_identifier.map(|block_identifier| GetBlockTransfersParams { block_identifier });
+        self.request::<GetBlockTransfers>(params).await
+    }
+
+    /// Retrieves the finality of a block, or of the latest block if `block_identifier` is `None`.
+    pub async fn get_block_finality(
+        &self,
+        block_identifier: Option<BlockIdentifier>,
+    ) -> Result<GetBlockFinalityResult> {
+        let params =
+            block_identifier.map(|block_identifier| GetBlockFinalityParams { block_identifier });
+        self.request::<GetBlockFinality>(params).await
+    }
+
+    /// Retrieves the validators' performance in the given era.
+    pub async fn get_era_validator_performance(
+        &self,
+        era_id: u64,
+    ) -> Result<GetEraValidatorPerformanceResult> {
+        self.request::<GetEraValidatorPerformance>(GetEraValidatorPerformanceParams { era_id })
+            .await
+    }
+
+    /// Retrieves the stake and rewards of the given validator or delegator in each era from
+    /// `from_era_id` to `to_era_id` inclusive.
+    pub async fn get_era_reward_history(
+        &self,
+        public_key: PublicKey,
+        from_era_id: u64,
+        to_era_id: u64,
+    ) -> Result<GetEraRewardHistoryResult> {
+        let params = GetEraRewardHistoryParams {
+            public_key,
+            from_era_id,
+            to_era_id,
+        };
+        self.request::<GetEraRewardHistory>(params).await
+    }
+
+    /// Retrieves the state root hash of a block, or of the latest block if `block_identifier` is
+    /// `None`.
+    pub async fn get_state_root_hash(
+        &self,
+        block_identifier: Option<BlockIdentifier>,
+    ) -> Result<GetStateRootHashResult> {
+        let params =
+            block_identifier.map(|block_identifier| GetStateRootHashParams { block_identifier });
+        self.request::<GetStateRootHash>(params).await
+    }
+
+    /// Retrieves the era info stored by a switch block, or by the latest block if
+    /// `block_identifier` is `None`.
+    pub async fn get_era_info_by_switch_block(
+        &self,
+        block_identifier: Option<BlockIdentifier>,
+    ) -> Result<GetEraInfoResult> {
+        let params = block_identifier.map(|block_identifier| GetEraInfoParams { block_identifier });
+        self.request::<GetEraInfoBySwitchBlock>(params).await
+    }
+
+    /// Retrieves a stored value from the global state.
+    pub async fn get_item(
+        &self,
+        state_root_hash: Digest,
+        key: Key,
+        path: Vec<String>,
+    ) -> Result<GetItemResult> {
+        let params = GetItemParams {
+            state_root_hash,
+            key: key.to_formatted_string(),
+            path,
+        };
+        self.request::<GetItem>(params).await
+    }
+
+    /// Retrieves the balance of a purse.
+    pub async fn get_balance(
+        &self,
+        state_root_hash: Digest,
+        purse: URef,
+    ) -> Result<GetBalanceResult> {
+        let params = GetBalanceParams {
+            state_root_hash,
+            purse_uref: purse.to_formatted_string(),
+        };
+        self.request::<GetBalance>(params).await
+    }
+
+    /// Retrieves the bids and validators as of the latest block.
+    pub async fn get_auction_info(&self) -> Result<GetAuctionInfoResult> {
+        self.request::<GetAuctionInfo>(()).await
+    }
+
+    /// Retrieves the node's connected and banned peers.
+    pub async fn get_peers(&self) -> Result<GetPeersResult> {
+        self.request::<GetPeers>(()).await
+    }
+
+    /// Retrieves the node's status.
+    pub async fn get_status(&self) -> Result<GetStatusResult> {
+        self.request::<GetStatus>(()).await
+    }
+
+    /// Retrieves the chain name and the limits the node applies to deploys.
+    pub async fn get_deploy_config(&self) -> Result<GetDeployConfigResult> {
+        self.request::<GetDeployConfig>(()).await
+    }
+
+    /// Retrieves the total supply at a block, or at the latest block if `block_identifier` is
+    /// `None`, along with the supply accounting of its era if it is a switch block.
+    pub async fn get_supply(
+        &self,
+        block_identifier: Option<BlockIdentifier>,
+    ) -> Result<GetSupplyResult> {
+        let params = block_identifier.map(|block_identifier| GetSupplyParams { block_identifier });
+        self.request::<GetSupply>(params).await
+    }
+
+    /// Retrieves the OpenRPC schema of the node's API.
+    pub async fn list_rpcs(&self) -> Result<ListRpcsResult> {
+        self.request::<ListRpcs>(()).await
+    }
+}
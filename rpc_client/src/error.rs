@@ -0,0 +1,60 @@
+use reqwest::StatusCode;
+use serde_json::Value;
+use thiserror::Error;
+
+/// A specialized `std::result::Result` type for the client.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Error that can be returned by the `CasperClient`.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Failed to build the HTTP client.
+    #[error("failed to build HTTP client: {0}")]
+    BuildHttpClient(#[source] reqwest::Error),
+
+    /// Failed to serialize the request params.
+    #[error("failed to serialize params: {0}")]
+    SerializeParams(#[source] serde_json::Error),
+
+    /// Failed to send the request or to receive the response, e.g. due to a timeout.
+    #[error("failed to get response: {0}")]
+    Http(#[source] reqwest::Error),
+
+    /// The node responded with an unsuccessful HTTP status.
+    #[error("node responded with HTTP status {0}")]
+    HttpStatus(StatusCode),
+
+    /// The response could not be parsed.
+    #[error("failed to parse response: {0}")]
+    InvalidResponse(#[source] serde_json::Error),
+
+    /// The response contained neither a result nor an error.
+    #[error("response contained neither a result nor an error")]
+    MissingResult,
+
+    /// The node returned a JSON-RPC error.
+    #[error("node returned error {code}: {message}")]
+    ResponseIsError {
+        /// The error code.
+        code: i64,
+        /// The error message.
+        message: String,
+        /// Additional information about the error, if any.
+        data: Option<Value>,
+    },
+}
+
+impl Error {
+    /// Returns whether the request may succeed when retried.
+    ///
+    /// Errors returned by the RPC itself are not retried, since the node processed the request.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Http(error) => error.is_timeout() || error.is_connect() || error.is_request(),
+            Error::HttpStatus(status) => {
+                status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
+        }
+    }
+}
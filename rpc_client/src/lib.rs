@@ -0,0 +1,37 @@
+//! # Casper node RPC client
+//!
+//! An async client for the JSON-RPC API of a Casper node, and typed requests and responses for
+//! every RPC the node serves.
+//!
+//! The request and response types in [`rpcs`] are the ones the node itself uses to serve its
+//! RPCs, and don't depend on `casper-node`, so that they can be used by services which only talk
+//! to a node over the network.  Deploys, hashes and stored values are the node's own types from
+//! `casper-node-types`, re-exported in [`types`].
+//!
+//! With the default `client` feature enabled, [`CasperClient`] sends these requests using a
+//! pooled HTTP client, with configurable timeouts and retries.
+#![doc(html_root_url = "https://docs.rs/casper-rpc-client/0.9.0")]
+#![doc(
+    html_favicon_url = "https://raw.githubusercontent.com/CasperLabs/casper-node/master/images/CasperLabs_Logo_Favicon_RGB_50px.png",
+    html_logo_url = "https://raw.githubusercontent.com/CasperLabs/casper-node/master/images/CasperLabs_Logo_Symbol_RGB.png",
+    test(attr(forbid(warnings)))
+)]
+#![warn(
+    missing_docs,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_qualifications
+)]
+
+#[cfg(feature = "client")]
+mod client;
+#[cfg(feature = "client")]
+mod error;
+pub mod rpcs;
+pub mod types;
+
+#[cfg(feature = "client")]
+pub use client::{CasperClient, ClientConfig};
+#[cfg(feature = "client")]
+pub use error::{Error, Result};
+pub use rpcs::RpcMethod;
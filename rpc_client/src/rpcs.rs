@@ -0,0 +1,30 @@
+//! The JSON-RPCs served by a Casper node, with their request parameters and response results.
+//!
+//! Each RPC is represented by a unit struct implementing [`RpcMethod`], named as the corresponding
+//! type in the node's `rpcs` module.
+
+pub mod account;
+pub mod chain;
+pub mod docs;
+pub mod info;
+pub mod state;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+/// The URL path of the node's JSON-RPC endpoint.
+pub const RPC_API_PATH: &str = "rpc";
+
+/// A JSON-RPC served by the node.
+pub trait RpcMethod {
+    /// The JSON-RPC "method" name.
+    const METHOD: &'static str;
+
+    /// The JSON-RPC request's "params" type.
+    ///
+    /// This is `()` for RPCs without params, and an `Option` for RPCs with optional params.  Params
+    /// which serialize to `null` are omitted from the request.
+    type Params: Serialize + DeserializeOwned + Send + Sync;
+
+    /// The JSON-RPC response's "result" type.
+    type Result: Serialize + DeserializeOwned + Send;
+}
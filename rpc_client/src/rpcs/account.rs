@@ -0,0 +1,38 @@
+//! RPCs related to accounts.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use schemars::JsonSchema;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use super::RpcMethod;
+use crate::types::{Deploy, DeployHash};
+
+/// Params for "account_put_deploy" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PutDeployParams {
+    /// The `Deploy`.
+    pub deploy: Deploy,
+}
+
+/// Result for "account_put_deploy" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct PutDeployResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+}
+
+/// "account_put_deploy" RPC.
+pub struct PutDeploy;
+
+impl RpcMethod for PutDeploy {
+    const METHOD: &'static str = "account_put_deploy";
+    type Params = PutDeployParams;
+    type Result = PutDeployResult;
+}
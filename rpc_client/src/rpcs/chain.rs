@@ -0,0 +1,290 @@
+//! RPCs related to the block chain.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use schemars::JsonSchema;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{system::auction::DelegationRate, PublicKey, Transfer, U512};
+
+use super::RpcMethod;
+use crate::types::{Block, BlockHash, BlockIdentifier, Digest, StoredValue};
+
+/// Params for "chain_get_block" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetBlockParams {
+    /// The block identifier.
+    pub block_identifier: BlockIdentifier,
+}
+
+/// Result for "chain_get_block" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetBlockResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The block, if found.
+    pub block: Option<Block>,
+}
+
+/// "chain_get_block" RPC.
+pub struct GetBlock;
+
+impl RpcMethod for GetBlock {
+    const METHOD: &'static str = "chain_get_block";
+    type Params = Option<GetBlockParams>;
+    type Result = GetBlockResult;
+}
+
+/// Params for "chain_get_block_transfers" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetBlockTransfersParams {
+    /// The block hash.
+    pub block_identifier: BlockIdentifier,
+}
+
+/// Result for "chain_get_block_transfers" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetBlockTransfersResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The block hash, if found.
+    pub block_hash: Option<BlockHash>,
+    /// The block's successful transfers, if found.
+    pub transfers: Option<Vec<Transfer>>,
+}
+
+/// "chain_get_block_transfers" RPC.
+pub struct GetBlockTransfers;
+
+impl RpcMethod for GetBlockTransfers {
+    const METHOD: &'static str = "chain_get_block_transfers";
+    type Params = Option<GetBlockTransfersParams>;
+    type Result = GetBlockTransfersResult;
+}
+
+/// The fault tolerance with which a node observed a block's finality.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct BlockFinality {
+    /// The height of the finalized block.
+    pub height: u64,
+    /// The era in which the block was finalized.
+    pub era_id: u64,
+    /// The level of the summit that finalized the block. Protocols with immediate finality use 1.
+    pub summit_level: u64,
+    /// The achieved fault tolerance threshold, in parts per million of the total validator
+    /// weight.
+    pub fault_tolerance_ppm: u64,
+    /// The validators whose units or votes contributed to the summit.
+    pub summit_contributors: Vec<PublicKey>,
+}
+
+/// Params for "chain_get_block_finality" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetBlockFinalityParams {
+    /// The block identifier.
+    pub block_identifier: BlockIdentifier,
+}
+
+/// Result for "chain_get_block_finality" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetBlockFinalityResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The block hash, if found.
+    pub block_hash: Option<BlockHash>,
+    /// The fault tolerance with which the node observed the block's finality, if known.
+    pub finality: Option<BlockFinality>,
+}
+
+/// "chain_get_block_finality" RPC.
+pub struct GetBlockFinality;
+
+impl RpcMethod for GetBlockFinality {
+    const METHOD: &'static str = "chain_get_block_finality";
+    type Params = Option<GetBlockFinalityParams>;
+    type Result = GetBlockFinalityResult;
+}
+
+/// A validator's participation in an era's consensus, as observed by the node.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct ValidatorPerformance {
+    /// The validator's public key.
+    pub public_key: PublicKey,
+    /// The number of units, or proposals and votes, created by the validator.
+    pub units_produced: u64,
+    /// The number of rounds in which the validator created at least one unit, proposal or vote.
+    pub rounds_participated: u64,
+    /// The validator's latest round exponent, if the consensus protocol uses one.
+    pub round_exponent: Option<u8>,
+    /// Whether the validator is known to have equivocated, in this or an earlier era.
+    pub equivocated: bool,
+}
+
+/// The participation of every validator in an era's consensus, as observed by the node.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct EraValidatorPerformance {
+    /// The era ID.
+    pub era_id: u64,
+    /// The participation of each validator in the era.
+    pub validators: Vec<ValidatorPerformance>,
+}
+
+/// Params for "chain_get_era_validator_performance" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetEraValidatorPerformanceParams {
+    /// The era ID.
+    pub era_id: u64,
+}
+
+/// Result for "chain_get_era_validator_performance" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetEraValidatorPerformanceResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The validators' performance in the era, if the node recorded it.
+    pub performance: Option<EraValidatorPerformance>,
+}
+
+/// "chain_get_era_validator_performance" RPC.
+pub struct GetEraValidatorPerformance;
+
+impl RpcMethod for GetEraValidatorPerformance {
+    const METHOD: &'static str = "chain_get_era_validator_performance";
+    type Params = GetEraValidatorPerformanceParams;
+    type Result = GetEraValidatorPerformanceResult;
+}
+
+/// The stake and seigniorage allocation of a single validator or delegator in an era.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct EraReward {
+    /// The public key of the validator, or of the validator delegated to.
+    pub validator_public_key: PublicKey,
+    /// The public key of the delegator, if this is a delegator's reward.
+    pub delegator_public_key: Option<PublicKey>,
+    /// The validator's delegation rate, i.e. the commission taken from its delegators' rewards.
+    pub delegation_rate: DelegationRate,
+    /// The amount staked in the era.
+    pub stake: U512,
+    /// The amount allocated at the end of the era.
+    pub amount: U512,
+}
+
+/// The stakes and seigniorage allocations of validators and delegators in an era.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct EraRewards {
+    /// The era ID.
+    pub era_id: u64,
+    /// The stake and reward of each validator and delegator in the era.
+    pub rewards: Vec<EraReward>,
+}
+
+/// Params for "chain_get_era_reward_history" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetEraRewardHistoryParams {
+    /// The public key of the validator or delegator.
+    pub public_key: PublicKey,
+    /// The first era ID of the range.
+    pub from_era_id: u64,
+    /// The last era ID of the range, inclusive.
+    pub to_era_id: u64,
+}
+
+/// Result for "chain_get_era_reward_history" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetEraRewardHistoryResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The stake and rewards of the validator or delegator in each era of the range in which it
+    /// was a recipient, as far as the node recorded them.
+    pub era_rewards: Vec<EraRewards>,
+}
+
+/// "chain_get_era_reward_history" RPC.
+pub struct GetEraRewardHistory;
+
+impl RpcMethod for GetEraRewardHistory {
+    const METHOD: &'static str = "chain_get_era_reward_history";
+    type Params = GetEraRewardHistoryParams;
+    type Result = GetEraRewardHistoryResult;
+}
+
+/// Params for "chain_get_state_root_hash" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetStateRootHashParams {
+    /// The block hash.
+    pub block_identifier: BlockIdentifier,
+}
+
+/// Result for "chain_get_state_root_hash" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetStateRootHashResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// Hex-encoded hash of the state root.
+    pub state_root_hash: Option<Digest>,
+}
+
+/// "chain_get_state_root_hash" RPC.
+pub struct GetStateRootHash;
+
+impl RpcMethod for GetStateRootHash {
+    const METHOD: &'static str = "chain_get_state_root_hash";
+    type Params = Option<GetStateRootHashParams>;
+    type Result = GetStateRootHashResult;
+}
+
+/// The summary of an era.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct EraSummary {
+    /// The block hash.
+    pub block_hash: BlockHash,
+    /// The era id.
+    pub era_id: u64,
+    /// The stored value containing era information.
+    pub stored_value: StoredValue,
+    /// Hex-encoded hash of the state root.
+    pub state_root_hash: Digest,
+    /// The merkle proof.
+    pub merkle_proof: String,
+}
+
+/// Params for "chain_get_era_info_by_switch_block" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetEraInfoParams {
+    /// The block identifier.
+    pub block_identifier: BlockIdentifier,
+}
+
+/// Result for "chain_get_era_info_by_switch_block" RPC response.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetEraInfoResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The era summary, if the block is a switch block.
+    pub era_summary: Option<EraSummary>,
+}
+
+/// "chain_get_era_info_by_switch_block" RPC.
+pub struct GetEraInfoBySwitchBlock;
+
+impl RpcMethod for GetEraInfoBySwitchBlock {
+    const METHOD: &'static str = "chain_get_era_info_by_switch_block";
+    type Params = Option<GetEraInfoParams>;
+    type Result = GetEraInfoResult;
+}
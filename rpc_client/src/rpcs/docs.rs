@@ -0,0 +1,34 @@
+//! RPCs describing the RPC API.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use schemars::JsonSchema;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::RpcMethod;
+
+/// Result for "rpc.discover" RPC response.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct ListRpcsResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The name of the schema.
+    pub name: String,
+    /// The OpenRPC schema, see <https://spec.open-rpc.org>.
+    #[schemars(skip)]
+    pub schema: Value,
+}
+
+/// "rpc.discover" RPC.
+pub struct ListRpcs;
+
+impl RpcMethod for ListRpcs {
+    // Named as per https://spec.open-rpc.org/#service-discovery-method.
+    const METHOD: &'static str = "rpc.discover";
+    type Params = ();
+    type Result = ListRpcsResult;
+}
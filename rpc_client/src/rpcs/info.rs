@@ -0,0 +1,241 @@
+//! RPCs returning information about the node and deploys.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use schemars::JsonSchema;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{ExecutionResult, PublicKey, U512};
+
+use super::{chain::EraSummary, RpcMethod};
+use crate::types::{BlockHash, BlockIdentifier, Deploy, DeployHash, Digest, TimeDiff, Timestamp};
+
+/// Params for "info_get_deploy" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDeployParams {
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+}
+
+/// The execution result of a single deploy.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct JsonExecutionResult {
+    /// The block hash.
+    pub block_hash: BlockHash,
+    /// Execution result.
+    pub result: ExecutionResult,
+}
+
+/// Result for "info_get_deploy" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetDeployResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The deploy.
+    pub deploy: Deploy,
+    /// The execution results of the deploy in each block that included it.
+    pub execution_results: Vec<JsonExecutionResult>,
+}
+
+/// "info_get_deploy" RPC.
+pub struct GetDeploy;
+
+impl RpcMethod for GetDeploy {
+    const METHOD: &'static str = "info_get_deploy";
+    type Params = GetDeployParams;
+    type Result = GetDeployResult;
+}
+
+/// The node ID and network address of a peer.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct PeerEntry {
+    /// The node ID.
+    pub node_id: String,
+    /// The network address.
+    pub address: String,
+}
+
+/// A node ID or IP address which is banned due to misbehavior.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct BannedPeer {
+    /// The banned node ID or IP address.
+    pub peer: String,
+    /// The time at which the ban expires.
+    pub banned_until: Timestamp,
+    /// The number of times the peer has been banned, including the current ban.
+    pub ban_count: u32,
+}
+
+/// Result for "info_get_peers" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetPeersResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The node ID and network address of each connected peer.
+    pub peers: Vec<PeerEntry>,
+    /// The node IDs and IP addresses which are currently banned due to misbehavior.
+    pub banned_peers: Vec<BannedPeer>,
+}
+
+/// "info_get_peers" RPC.
+pub struct GetPeers;
+
+impl RpcMethod for GetPeers {
+    const METHOD: &'static str = "info_get_peers";
+    type Params = ();
+    type Result = GetPeersResult;
+}
+
+/// Minimal info of a block.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct MinimalBlockInfo {
+    /// The block hash.
+    pub hash: BlockHash,
+    /// The block's timestamp.
+    pub timestamp: Timestamp,
+    /// The era ID.
+    pub era_id: u64,
+    /// The height of the block.
+    pub height: u64,
+    /// The global state root hash after executing the block.
+    pub state_root_hash: Digest,
+    /// The block's proposer.
+    pub creator: PublicKey,
+}
+
+/// The first era to which a protocol upgrade applies.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct ActivationPoint {
+    /// The era ID.
+    pub era_id: u64,
+}
+
+/// Information about the next protocol upgrade.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct NextUpgrade {
+    /// The activation point of the upgrade.
+    pub activation_point: ActivationPoint,
+    /// The protocol version after the upgrade.
+    #[schemars(with = "String")]
+    pub protocol_version: Version,
+}
+
+/// Result for "info_get_status" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetStatusResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The chainspec name.
+    pub chainspec_name: String,
+    /// The state root hash used at the start of the current session.
+    pub starting_state_root_hash: String,
+    /// The node ID and network address of each connected peer.
+    pub peers: Vec<PeerEntry>,
+    /// The minimal info of the last block from the linear chain.
+    pub last_added_block_info: Option<MinimalBlockInfo>,
+    /// The node's public signing key.
+    pub our_public_signing_key: PublicKey,
+    /// The next round length if the node is a validator.
+    pub round_length: Option<TimeDiff>,
+    /// Information about the next scheduled upgrade.
+    pub next_upgrade: Option<NextUpgrade>,
+    /// The compiled node version.
+    pub build_version: String,
+}
+
+/// "info_get_status" RPC.
+pub struct GetStatus;
+
+impl RpcMethod for GetStatus {
+    const METHOD: &'static str = "info_get_status";
+    type Params = ();
+    type Result = GetStatusResult;
+}
+
+/// The limits applied to deploys, as configured in the `[deploys]` section of the chainspec.
+#[derive(Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct DeployConfig {
+    /// The maximum number of motes allowed to be spent during payment, where zero means unlimited.
+    pub max_payment_cost: U512,
+    /// The maximum time-to-live of a deploy.
+    pub max_ttl: TimeDiff,
+    /// The maximum number of dependencies of a deploy.
+    pub max_dependencies: u8,
+    /// The maximum size of a block in bytes, where zero means unlimited.
+    pub max_block_size: u32,
+    /// The maximum number of non-transfer deploys in a block.
+    pub block_max_deploy_count: u32,
+    /// The maximum number of transfers in a block.
+    pub block_max_transfer_count: u32,
+    /// The upper limit of the total gas of all deploys in a block.
+    pub block_gas_limit: u64,
+    /// The maximum length in bytes of the serialized payment args.
+    pub payment_args_max_length: u32,
+    /// The maximum length in bytes of the serialized session args.
+    pub session_args_max_length: u32,
+    /// The minimum amount of motes of a native transfer.
+    pub native_transfer_minimum_motes: u64,
+}
+
+/// Result for "info_get_deploy_config" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetDeployConfigResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The name of the chain, which every deploy's `chain_name` must match.
+    pub chain_name: String,
+    /// The limits applied to deploys.
+    pub deploy_config: DeployConfig,
+}
+
+/// "info_get_deploy_config" RPC.
+pub struct GetDeployConfig;
+
+impl RpcMethod for GetDeployConfig {
+    const METHOD: &'static str = "info_get_deploy_config";
+    type Params = ();
+    type Result = GetDeployConfigResult;
+}
+
+/// Params for "info_get_supply" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetSupplyParams {
+    /// The block identifier.
+    pub block_identifier: BlockIdentifier,
+}
+
+/// Result for "info_get_supply" RPC response.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetSupplyResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The hash of the block at which the supply was queried.
+    pub block_hash: BlockHash,
+    /// Hex-encoded hash of the state root of the block.
+    pub state_root_hash: Digest,
+    /// The total supply held by the mint.
+    pub total_supply: U512,
+    /// The merkle proof of the total supply.
+    pub merkle_proof: String,
+    /// The era summary, including the supply accounting of the era, if the block is a switch
+    /// block.
+    pub era_summary: Option<EraSummary>,
+}
+
+/// "info_get_supply" RPC.
+pub struct GetSupply;
+
+impl RpcMethod for GetSupply {
+    const METHOD: &'static str = "info_get_supply";
+    type Params = Option<GetSupplyParams>;
+    type Result = GetSupplyResult;
+}
@@ -0,0 +1,97 @@
+//! RPCs related to the global state.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use schemars::JsonSchema;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use casper_types::U512;
+
+use super::RpcMethod;
+use crate::types::{AuctionState, Digest, StoredValue};
+
+/// Params for "state_get_item" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetItemParams {
+    /// Hash of the state root.
+    pub state_root_hash: Digest,
+    /// `casper_types::Key` as formatted string.
+    pub key: String,
+    /// The path components starting from the key as base.
+    #[serde(default)]
+    pub path: Vec<String>,
+}
+
+/// Result for "state_get_item" RPC response.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetItemResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The stored value.
+    pub stored_value: StoredValue,
+    /// The merkle proof.
+    pub merkle_proof: String,
+}
+
+/// "state_get_item" RPC.
+pub struct GetItem;
+
+impl RpcMethod for GetItem {
+    const METHOD: &'static str = "state_get_item";
+    type Params = GetItemParams;
+    type Result = GetItemResult;
+}
+
+/// Params for "state_get_balance" RPC request.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetBalanceParams {
+    /// The hash of state root.
+    pub state_root_hash: Digest,
+    /// Formatted URef.
+    pub purse_uref: String,
+}
+
+/// Result for "state_get_balance" RPC response.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetBalanceResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The balance value.
+    pub balance_value: U512,
+    /// The merkle proof.
+    pub merkle_proof: String,
+}
+
+/// "state_get_balance" RPC.
+pub struct GetBalance;
+
+impl RpcMethod for GetBalance {
+    const METHOD: &'static str = "state_get_balance";
+    type Params = GetBalanceParams;
+    type Result = GetBalanceResult;
+}
+
+/// Result for "state_get_auction_info" RPC response.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct GetAuctionInfoResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: Version,
+    /// The auction state.
+    pub auction_state: AuctionState,
+}
+
+/// "state_get_auction_info" RPC.
+pub struct GetAuctionInfo;
+
+impl RpcMethod for GetAuctionInfo {
+    const METHOD: &'static str = "state_get_auction_info";
+    type Params = ();
+    type Result = GetAuctionInfoResult;
+}
@@ -0,0 +1,16 @@
+//! Types shared by several RPCs.
+//!
+//! Hashes, deploys and the JSON representations of values in global state are re-exported from
+//! `casper-node-types`, the crate the node itself uses for them.  Blocks are defined here in their
+//! JSON form, as the node's block types are tied to its consensus and storage code; the node
+//! converts its blocks into these when responding.
+
+mod block;
+
+pub use block::{Block, BlockBody, BlockHeader, EraEnd, EraReport, Proof, Reward, ValidatorWeight};
+pub use casper_node_types::{
+    crypto::hash::Digest,
+    json_compatibility::{Account, AuctionState, Contract, ContractPackage, StoredValue},
+    Approval, BlockHash, BlockIdentifier, Deploy, DeployHash, DeployHeader, ExecutableDeployItem,
+    TimeDiff, Timestamp,
+};
@@ -0,0 +1,107 @@
+//! The JSON representation of a block.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{ProtocolVersion, PublicKey, Signature, U512};
+
+use super::{BlockHash, DeployHash, Digest, Timestamp};
+
+/// A validator's reward for an era.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct Reward {
+    /// The validator's public key.
+    pub validator: PublicKey,
+    /// The reward amount.
+    pub amount: u64,
+}
+
+/// A validator's weight in the next era.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct ValidatorWeight {
+    /// The validator's public key.
+    pub validator: PublicKey,
+    /// The validator's weight.
+    pub weight: U512,
+}
+
+/// Equivocation and reward information included in the switch block of an era.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct EraReport {
+    /// The validators which equivocated in the era.
+    pub equivocators: Vec<PublicKey>,
+    /// The validators' rewards.
+    pub rewards: Vec<Reward>,
+    /// The validators which were inactive in the era.
+    pub inactive_validators: Vec<PublicKey>,
+}
+
+/// The information included in the last block of an era.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct EraEnd {
+    /// The era report.
+    pub era_report: EraReport,
+    /// The validators' weights in the next era.
+    pub next_era_validator_weights: Vec<ValidatorWeight>,
+}
+
+/// The header of a block.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct BlockHeader {
+    /// The parent block's hash.
+    pub parent_hash: BlockHash,
+    /// The global state root hash after executing the block.
+    pub state_root_hash: Digest,
+    /// The hash of the block's body.
+    pub body_hash: Digest,
+    /// The random bit contributed by the block's proposer.
+    pub random_bit: bool,
+    /// The accumulated seed used to derive randomness.
+    pub accumulated_seed: Digest,
+    /// The era end, if this is the last block of an era.
+    pub era_end: Option<EraEnd>,
+    /// The block's timestamp.
+    pub timestamp: Timestamp,
+    /// The era ID.
+    pub era_id: u64,
+    /// The height of the block.
+    pub height: u64,
+    /// The protocol version.
+    pub protocol_version: ProtocolVersion,
+}
+
+/// The body of a block.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct BlockBody {
+    /// The block's proposer.
+    pub proposer: PublicKey,
+    /// The hashes of the deploys included in the block.
+    pub deploy_hashes: Vec<DeployHash>,
+    /// The hashes of the transfers included in the block.
+    pub transfer_hashes: Vec<DeployHash>,
+}
+
+/// A validator's signature of a block.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct Proof {
+    /// The validator's public key.
+    pub public_key: PublicKey,
+    /// The signature of the block hash.
+    pub signature: Signature,
+}
+
+/// A block along with the finality signatures known to the node.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, JsonSchema)]
+pub struct Block {
+    /// The block hash.
+    pub hash: BlockHash,
+    /// The block header.
+    pub header: BlockHeader,
+    /// The block body.
+    pub body: BlockBody,
+    /// The finality signatures.
+    pub proofs: Vec<Proof>,
+}
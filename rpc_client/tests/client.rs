@@ -0,0 +1,128 @@
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use serde_json::{json, Value};
+use warp::{http::StatusCode, Filter};
+
+use casper_rpc_client::{CasperClient, ClientConfig, Error};
+
+/// How the mock node responds to a request, given the number of requests received before it.
+type Responder = fn(usize, &Value) -> (StatusCode, Value);
+
+/// Spawns a mock node, returning its address and the number of requests it has received.
+fn spawn_node(responder: Responder, delay: Duration) -> (SocketAddr, Arc<AtomicUsize>) {
+    let requests = Arc::new(AtomicUsize::new(0));
+    let requests_cloned = Arc::clone(&requests);
+    let route = warp::path("rpc")
+        .and(warp::post())
+        .and(warp::body::json())
+        .and_then(move |request: Value| {
+            let count = requests_cloned.fetch_add(1, Ordering::SeqCst);
+            async move {
+                tokio::time::delay_for(delay).await;
+                let (status, body) = responder(count, &request);
+                Ok::<_, warp::Rejection>(warp::reply::with_status(warp::reply::json(&body), status))
+            }
+        });
+    let (address, server) = warp::serve(route).bind_ephemeral(([127, 0, 0, 1], 0));
+    tokio::spawn(server);
+    (address, requests)
+}
+
+fn config(max_retries: u32) -> ClientConfig {
+    ClientConfig {
+        request_timeout: Duration::from_secs(5),
+        max_retries,
+        retry_delay: Duration::from_millis(1),
+        ..ClientConfig::default()
+    }
+}
+
+fn peers_response(request: &Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": request["id"],
+        "result": {
+            "api_version": "1.0.0",
+            "peers": [{ "node_id": "NodeId::Tls(0102..0304)", "address": "127.0.0.1:54321" }],
+            "banned_peers": [],
+        },
+    })
+}
+
+#[tokio::test]
+async fn should_retry_server_errors() {
+    let responder: Responder = |count, request| {
+        assert_eq!(request["method"], "info_get_peers");
+        assert!(request.get("params").is_none());
+        if count < 2 {
+            (StatusCode::SERVICE_UNAVAILABLE, Value::Null)
+        } else {
+            (StatusCode::OK, peers_response(request))
+        }
+    };
+    let (address, requests) = spawn_node(responder, Duration::default());
+
+    let client = CasperClient::with_config(&format!("http://{}/", address), config(3)).unwrap();
+    let result = client.get_peers().await.unwrap();
+    assert_eq!(result.peers[0].address, "127.0.0.1:54321");
+    assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+    let client = CasperClient::with_config(&format!("http://{}", address), config(1)).unwrap();
+    requests.store(0, Ordering::SeqCst);
+    match client.get_peers().await {
+        Err(Error::HttpStatus(status)) => assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn should_not_retry_rpc_errors() {
+    let responder: Responder = |_, request| {
+        let response = json!({
+            "jsonrpc": "2.0",
+            "id": request["id"],
+            "error": { "code": -32000, "message": "deploy not known" },
+        });
+        (StatusCode::OK, response)
+    };
+    let (address, requests) = spawn_node(responder, Duration::default());
+
+    let client = CasperClient::with_config(&format!("http://{}", address), config(3)).unwrap();
+    let deploy_hash = [1; 32].into();
+    match client
+        .get_deploy(casper_rpc_client::types::DeployHash::new(deploy_hash))
+        .await
+    {
+        Err(Error::ResponseIsError { code, message, .. }) => {
+            assert_eq!(code, -32000);
+            assert_eq!(message, "deploy not known");
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert_eq!(requests.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn should_time_out() {
+    let responder: Responder = |_, request| (StatusCode::OK, peers_response(request));
+    let (address, requests) = spawn_node(responder, Duration::from_secs(2));
+
+    let config = ClientConfig {
+        request_timeout: Duration::from_millis(50),
+        ..config(1)
+    };
+    let client = CasperClient::with_config(&format!("http://{}", address), config).unwrap();
+    match client.get_peers().await {
+        Err(Error::Http(error)) => assert!(error.is_timeout()),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+}
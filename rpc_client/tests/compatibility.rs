@@ -0,0 +1,105 @@
+//! Checks that the node serves its RPCs with this crate's types, and that the node's RPC doc
+//! examples round trip through them.
+//!
+//! The trait bounds below only hold if the node's request and response types are the ones defined
+//! here, so a divergence fails to compile rather than at runtime.
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use casper_node::rpcs::{docs::DocExample, RpcWithOptionalParams, RpcWithParams, RpcWithoutParams};
+use casper_rpc_client::{rpcs, RpcMethod};
+
+/// Asserts that the node's example of `T` serializes to JSON which parses back to the same value.
+fn assert_round_trip<T>()
+where
+    T: DocExample + Serialize + DeserializeOwned + PartialEq + std::fmt::Debug + 'static,
+{
+    let json = serde_json::to_value(T::doc_example()).unwrap();
+    let parsed: T = serde_json::from_value(json.clone()).unwrap_or_else(|error| {
+        panic!(
+            "failed to parse {} as {}: {}",
+            json,
+            std::any::type_name::<T>(),
+            error
+        )
+    });
+    assert_eq!(&parsed, T::doc_example());
+}
+
+fn check_with_params<R>()
+where
+    R: RpcWithParams + RpcMethod<Params = R::RequestParams, Result = R::ResponseResult>,
+    R::RequestParams: PartialEq + std::fmt::Debug,
+    R::ResponseResult: PartialEq + std::fmt::Debug,
+{
+    assert_eq!(<R as RpcWithParams>::METHOD, <R as RpcMethod>::METHOD);
+    assert_round_trip::<R::RequestParams>();
+    assert_round_trip::<R::ResponseResult>();
+}
+
+fn check_with_optional_params<R>()
+where
+    R: RpcWithOptionalParams
+        + RpcMethod<Params = Option<R::OptionalRequestParams>, Result = R::ResponseResult>,
+    R::OptionalRequestParams: PartialEq + std::fmt::Debug,
+    R::ResponseResult: PartialEq + std::fmt::Debug,
+{
+    assert_eq!(
+        <R as RpcWithOptionalParams>::METHOD,
+        <R as RpcMethod>::METHOD
+    );
+    assert_round_trip::<R::OptionalRequestParams>();
+    assert_round_trip::<R::ResponseResult>();
+}
+
+fn check_without_params<R>()
+where
+    R: RpcWithoutParams + RpcMethod<Params = (), Result = R::ResponseResult>,
+    R::ResponseResult: PartialEq + std::fmt::Debug,
+{
+    assert_eq!(<R as RpcWithoutParams>::METHOD, <R as RpcMethod>::METHOD);
+    assert_round_trip::<R::ResponseResult>();
+}
+
+#[test]
+fn should_match_account_rpcs() {
+    check_with_params::<rpcs::account::PutDeploy>();
+}
+
+#[test]
+fn should_match_chain_rpcs() {
+    use rpcs::chain::*;
+
+    check_with_optional_params::<GetBlock>();
+    check_with_optional_params::<GetBlockTransfers>();
+    check_with_optional_params::<GetBlockFinality>();
+    check_with_params::<GetEraValidatorPerformance>();
+    check_with_params::<GetEraRewardHistory>();
+    check_with_optional_params::<GetStateRootHash>();
+    check_with_optional_params::<GetEraInfoBySwitchBlock>();
+}
+
+#[test]
+fn should_match_info_rpcs() {
+    use rpcs::info::*;
+
+    check_with_params::<GetDeploy>();
+    check_without_params::<GetPeers>();
+    check_without_params::<GetStatus>();
+    check_without_params::<GetDeployConfig>();
+    check_with_optional_params::<GetSupply>();
+}
+
+#[test]
+fn should_match_state_rpcs() {
+    use rpcs::state::*;
+
+    check_with_params::<GetItem>();
+    check_with_params::<GetBalance>();
+    check_without_params::<GetAuctionInfo>();
+}
+
+#[test]
+fn should_match_docs_rpcs() {
+    check_without_params::<rpcs::docs::ListRpcs>();
+}
@@ -5,13 +5,13 @@ use core::mem::MaybeUninit;
 
 use casper_types::{
     account::AccountHash,
-    api_error, bytesrepr,
+    api_error, bytesrepr, runtime_args,
     system::{
         auction::{self, EraId, EraInfo},
-        SystemContractType,
+        mint, SystemContractType,
     },
-    ApiError, ContractHash, HashAddr, TransferResult, TransferredTo, URef, U512,
-    UREF_SERIALIZED_LENGTH,
+    ApiError, ContractHash, HashAddr, Key, RuntimeArgs, TransferResult, TransferredTo, URef,
+    URefAddr, U512, UREF_SERIALIZED_LENGTH,
 };
 
 use crate::{
@@ -207,6 +207,57 @@ pub fn transfer_from_purse_to_purse(
     api_error::result_from(result)
 }
 
+/// Grants `spender` an allowance to transfer up to `amount` of motes out of `purse` until the
+/// block time reaches `expiry`, replacing any previous allowance.  An `amount` of zero revokes the
+/// allowance.  `purse` must be writeable by the caller.
+pub fn approve_spending(
+    purse: URef,
+    spender: Key,
+    amount: U512,
+    expiry: u64,
+) -> Result<(), ApiError> {
+    let args = runtime_args! {
+        mint::ARG_PURSE => purse,
+        mint::ARG_SPENDER => spender,
+        mint::ARG_AMOUNT => amount,
+        mint::ARG_EXPIRY => expiry,
+    };
+    let result: Result<(), u8> = runtime::call_contract(get_mint(), mint::METHOD_APPROVE, args);
+    result.map_err(ApiError::Mint)
+}
+
+/// Returns the amount of motes `spender` may still transfer out of the purse at address `purse`,
+/// or zero if it has no allowance or the allowance has expired.
+pub fn get_allowance(purse: URefAddr, spender: Key) -> U512 {
+    let args = runtime_args! {
+        mint::ARG_PURSE => purse,
+        mint::ARG_SPENDER => spender,
+    };
+    let result: Result<U512, u8> = runtime::call_contract(get_mint(), mint::METHOD_ALLOWANCE, args);
+    result.map_err(ApiError::Mint).unwrap_or_revert()
+}
+
+/// Transfers `amount` of motes from the purse at address `source` to `target` purse, using an
+/// allowance granted to the caller by the owner of `source`.  If `target` does not exist the
+/// transfer fails.
+pub fn transfer_from_purse_with_allowance(
+    source: URefAddr,
+    target: URef,
+    amount: U512,
+    id: Option<u64>,
+) -> Result<(), ApiError> {
+    let args = runtime_args! {
+        mint::ARG_TO => Option::<AccountHash>::None,
+        mint::ARG_SOURCE => source,
+        mint::ARG_TARGET => target,
+        mint::ARG_AMOUNT => amount,
+        mint::ARG_ID => id,
+    };
+    let result: Result<(), u8> =
+        runtime::call_contract(get_mint(), mint::METHOD_TRANSFER_FROM, args);
+    result.map_err(ApiError::Mint)
+}
+
 /// Records a transfer.  Can only be called from within the mint contract.
 /// Needed to support system contract-based execution.
 #[doc(hidden)]
@@ -31,13 +31,20 @@ use crate::KEY_HASH_LENGTH;
 use crate::{
     account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
-    system::auction::{Bid, EraInfo},
+    system::{
+        auction::{Bid, EraInfo},
+        handle_payment::PaymentBreakdown,
+    },
     CLValue, DeployInfo, NamedKey, Transfer, TransferAddr, U128, U256, U512,
 };
 
 /// Constants to track ExecutionResult serialization.
-const EXECUTION_RESULT_FAILURE_TAG: u8 = 0;
-const EXECUTION_RESULT_SUCCESS_TAG: u8 = 1;
+///
+/// Results tagged as legacy were encoded before the payment breakdown was recorded.
+const EXECUTION_RESULT_LEGACY_FAILURE_TAG: u8 = 0;
+const EXECUTION_RESULT_LEGACY_SUCCESS_TAG: u8 = 1;
+const EXECUTION_RESULT_FAILURE_TAG: u8 = 2;
+const EXECUTION_RESULT_SUCCESS_TAG: u8 = 3;
 
 /// Constants to track operation serialization.
 const OP_READ_TAG: u8 = 0;
@@ -98,10 +105,17 @@ static EXECUTION_RESULT: Lazy<ExecutionResult> = Lazy::new(|| {
         TransferAddr::new([130; KEY_HASH_LENGTH]),
     ];
 
+    let payment = PaymentBreakdown {
+        refund: U512::from(1_000),
+        proposer_reward: U512::from(123_456),
+        burned: U512::zero(),
+    };
+
     ExecutionResult::Success {
         effect,
         transfers,
         cost: U512::from(123_456),
+        payment,
     }
 });
 
@@ -118,6 +132,11 @@ pub enum ExecutionResult {
         transfers: Vec<TransferAddr>,
         /// The cost of executing the deploy.
         cost: U512,
+        /// How the payment for the deploy was split between refund, proposer and burn.
+        ///
+        /// Zero for results recorded before the breakdown was introduced.
+        #[serde(default)]
+        payment: PaymentBreakdown,
         /// The error message associated with executing the deploy.
         error_message: String,
     },
@@ -129,6 +148,11 @@ pub enum ExecutionResult {
         transfers: Vec<TransferAddr>,
         /// The cost of executing the deploy.
         cost: U512,
+        /// How the payment for the deploy was split between refund, proposer and burn.
+        ///
+        /// Zero for results recorded before the breakdown was introduced.
+        #[serde(default)]
+        payment: PaymentBreakdown,
     },
 }
 
@@ -180,6 +204,7 @@ impl Distribution<ExecutionResult> for Standard {
                 effect,
                 transfers,
                 cost: rng.gen::<u64>().into(),
+                payment: rng.gen(),
                 error_message: format!("Error message {}", rng.gen::<u64>()),
             }
         } else {
@@ -187,6 +212,7 @@ impl Distribution<ExecutionResult> for Standard {
                 effect,
                 transfers,
                 cost: rng.gen::<u64>().into(),
+                payment: rng.gen(),
             }
         }
     }
@@ -200,23 +226,27 @@ impl ToBytes for ExecutionResult {
                 effect,
                 transfers,
                 cost,
+                payment,
                 error_message,
             } => {
                 buffer.push(EXECUTION_RESULT_FAILURE_TAG);
                 buffer.extend(effect.to_bytes()?);
                 buffer.extend(transfers.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
+                buffer.extend(payment.to_bytes()?);
                 buffer.extend(error_message.to_bytes()?);
             }
             ExecutionResult::Success {
                 effect,
                 transfers,
                 cost,
+                payment,
             } => {
                 buffer.push(EXECUTION_RESULT_SUCCESS_TAG);
                 buffer.extend(effect.to_bytes()?);
                 buffer.extend(transfers.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
+                buffer.extend(payment.to_bytes()?);
             }
         }
         Ok(buffer)
@@ -229,21 +259,25 @@ impl ToBytes for ExecutionResult {
                     effect,
                     transfers,
                     cost,
+                    payment,
                     error_message,
                 } => {
                     effect.serialized_length()
                         + transfers.serialized_length()
                         + cost.serialized_length()
+                        + payment.serialized_length()
                         + error_message.serialized_length()
                 }
                 ExecutionResult::Success {
                     effect,
                     transfers,
                     cost,
+                    payment,
                 } => {
                     effect.serialized_length()
                         + transfers.serialized_length()
                         + cost.serialized_length()
+                        + payment.serialized_length()
                 }
             }
     }
@@ -253,27 +287,39 @@ impl FromBytes for ExecutionResult {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
         let (tag, remainder) = u8::from_bytes(bytes)?;
         match tag {
-            EXECUTION_RESULT_FAILURE_TAG => {
+            EXECUTION_RESULT_LEGACY_FAILURE_TAG | EXECUTION_RESULT_FAILURE_TAG => {
                 let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
+                let (payment, remainder) = if tag == EXECUTION_RESULT_FAILURE_TAG {
+                    PaymentBreakdown::from_bytes(remainder)?
+                } else {
+                    (PaymentBreakdown::default(), remainder)
+                };
                 let (error_message, remainder) = String::from_bytes(remainder)?;
                 let execution_result = ExecutionResult::Failure {
                     effect,
                     transfers,
                     cost,
+                    payment,
                     error_message,
                 };
                 Ok((execution_result, remainder))
             }
-            EXECUTION_RESULT_SUCCESS_TAG => {
+            EXECUTION_RESULT_LEGACY_SUCCESS_TAG | EXECUTION_RESULT_SUCCESS_TAG => {
                 let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
+                let (payment, remainder) = if tag == EXECUTION_RESULT_SUCCESS_TAG {
+                    PaymentBreakdown::from_bytes(remainder)?
+                } else {
+                    (PaymentBreakdown::default(), remainder)
+                };
                 let execution_result = ExecutionResult::Success {
                     effect,
                     transfers,
                     cost,
+                    payment,
                 };
                 Ok((execution_result, remainder))
             }
@@ -672,4 +718,48 @@ mod tests {
         let execution_result: ExecutionResult = rng.gen();
         bytesrepr::test_serialization_roundtrip(&execution_result);
     }
+
+    #[test]
+    fn should_deserialize_legacy_execution_result() {
+        let mut rng = get_rng();
+        let effect = ExecutionEffect {
+            operations: vec![],
+            transforms: vec![TransformEntry {
+                key: rng.gen::<u64>().to_string(),
+                transform: rng.gen(),
+            }],
+        };
+        let transfers = vec![TransferAddr::new(rng.gen())];
+        let cost = U512::from(rng.gen::<u64>());
+        let error_message = "Error message".to_string();
+
+        let mut legacy_bytes = vec![EXECUTION_RESULT_LEGACY_FAILURE_TAG];
+        legacy_bytes.extend(effect.to_bytes().unwrap());
+        legacy_bytes.extend(transfers.to_bytes().unwrap());
+        legacy_bytes.extend(cost.to_bytes().unwrap());
+        legacy_bytes.extend(error_message.to_bytes().unwrap());
+
+        let expected = ExecutionResult::Failure {
+            effect,
+            transfers,
+            cost,
+            payment: PaymentBreakdown::default(),
+            error_message,
+        };
+        let execution_result: ExecutionResult = bytesrepr::deserialize(legacy_bytes).unwrap();
+        assert_eq!(execution_result, expected);
+    }
+
+    #[test]
+    fn should_deserialize_json_execution_result_without_payment() {
+        let json = r#"{"Success":{"effect":{"operations":[],"transforms":[]},"transfers":[],"cost":"123"}}"#;
+        let execution_result: ExecutionResult = serde_json::from_str(json).unwrap();
+        let expected = ExecutionResult::Success {
+            effect: ExecutionEffect::default(),
+            transfers: vec![],
+            cost: U512::from(123),
+            payment: PaymentBreakdown::default(),
+        };
+        assert_eq!(execution_result, expected);
+    }
 }
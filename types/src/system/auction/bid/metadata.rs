@@ -0,0 +1,113 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::{string::String, vec::Vec};
+
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::{Error, MAX_BID_METADATA_FIELD_LENGTH},
+};
+
+/// Optional information a validator can attach to their bid for display purposes.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct BidMetadata {
+    /// Display name of the validator.
+    name: Option<String>,
+    /// Website of the validator.
+    url: Option<String>,
+    /// Contact details of the validator, e.g. an email address.
+    contact: Option<String>,
+}
+
+impl BidMetadata {
+    /// Creates new instance of [`BidMetadata`], checking that none of the fields exceeds
+    /// [`MAX_BID_METADATA_FIELD_LENGTH`] bytes.
+    pub fn new(
+        name: Option<String>,
+        url: Option<String>,
+        contact: Option<String>,
+    ) -> Result<Self, Error> {
+        let is_too_long = |field: &Option<String>| {
+            field
+                .as_ref()
+                .map_or(false, |value| value.len() > MAX_BID_METADATA_FIELD_LENGTH)
+        };
+        if is_too_long(&name) || is_too_long(&url) || is_too_long(&contact) {
+            return Err(Error::BidMetadataTooLong);
+        }
+        Ok(BidMetadata { name, url, contact })
+    }
+
+    /// Returns the display name of the validator.
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+
+    /// Returns the website of the validator.
+    pub fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+
+    /// Returns the contact details of the validator.
+    pub fn contact(&self) -> Option<&String> {
+        self.contact.as_ref()
+    }
+}
+
+impl ToBytes for BidMetadata {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.name.to_bytes()?);
+        result.extend(self.url.to_bytes()?);
+        result.extend(self.contact.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.name.serialized_length()
+            + self.url.serialized_length()
+            + self.contact.serialized_length()
+    }
+}
+
+impl FromBytes for BidMetadata {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, bytes) = FromBytes::from_bytes(bytes)?;
+        let (url, bytes) = FromBytes::from_bytes(bytes)?;
+        let (contact, bytes) = FromBytes::from_bytes(bytes)?;
+        Ok((BidMetadata { name, url, contact }, bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn serialization_roundtrip() {
+        let metadata = BidMetadata::new(
+            Some("Validator".to_string()),
+            None,
+            Some("validator@example.com".to_string()),
+        )
+        .unwrap();
+        bytesrepr::test_serialization_roundtrip(&metadata);
+    }
+
+    #[test]
+    fn should_not_create_metadata_with_too_long_field() {
+        let url = "a".repeat(MAX_BID_METADATA_FIELD_LENGTH + 1);
+        assert_eq!(
+            BidMetadata::new(None, Some(url), None),
+            Err(Error::BidMetadataTooLong)
+        );
+    }
+}
@@ -1,6 +1,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
+mod metadata;
 mod vesting;
 
 use alloc::{collections::BTreeMap, vec::Vec};
@@ -15,8 +16,45 @@ use crate::{
     CLType, CLTyped, PublicKey, URef, U512,
 };
 
+pub use metadata::BidMetadata;
 pub use vesting::VestingSchedule;
 
+/// Precedes the versioned encodings of a [`Bid`] and a [`Delegator`].
+///
+/// Encodings without it start with a public key tag and predate auto-compounding, delegation
+/// limits and bid metadata.
+const VERSIONED_ENCODING_MARKER: u8 = u8::MAX;
+/// The current version of the encodings of a [`Bid`] and a [`Delegator`].
+const ENCODING_VERSION: u8 = 1;
+
+/// Writes the prefix of the versioned encoding of a [`Bid`] or a [`Delegator`].
+pub(crate) fn write_encoding_version(buffer: &mut Vec<u8>) {
+    buffer.push(VERSIONED_ENCODING_MARKER);
+    buffer.push(ENCODING_VERSION);
+}
+
+/// Returns the length of the prefix of the versioned encoding of a [`Bid`] or a [`Delegator`].
+pub(crate) fn encoding_version_serialized_length() -> usize {
+    VERSIONED_ENCODING_MARKER.serialized_length() + ENCODING_VERSION.serialized_length()
+}
+
+/// Consumes the prefix of the versioned encoding of a [`Bid`] or a [`Delegator`], if present.
+///
+/// Returns `true` along with the unconsumed bytes if the encoding is a legacy one.
+pub(crate) fn read_encoding_version(bytes: &[u8]) -> Result<(bool, &[u8]), bytesrepr::Error> {
+    match bytes.split_first() {
+        Some((&VERSIONED_ENCODING_MARKER, rem)) => {
+            let (version, rem) = u8::from_bytes(rem)?;
+            if version != ENCODING_VERSION {
+                return Err(bytesrepr::Error::Formatting);
+            }
+            Ok((false, rem))
+        }
+        Some(_) => Ok((true, bytes)),
+        None => Err(bytesrepr::Error::EarlyEndOfStream),
+    }
+}
+
 /// An entry in the validator map.
 #[derive(PartialEq, Eq, Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -36,6 +74,18 @@ pub struct Bid {
     delegators: BTreeMap<PublicKey, Delegator>,
     /// `true` if validator has been "evicted"
     inactive: bool,
+    /// `true` if the validator's rewards are added to their stake, `false` if they are paid out to
+    /// the validator's main purse.
+    auto_compound: bool,
+    /// The total of all rewards the validator has earned, whether compounded or paid out.
+    total_rewards: U512,
+    /// The minimum amount the validator accepts from a single delegator, on top of the network's
+    /// own minimum.
+    minimum_delegation_amount: U512,
+    /// A delegation rate increase which is applied gradually at the upcoming auctions.
+    pending_delegation_rate: Option<DelegationRate>,
+    /// Optional information about the validator for display purposes.
+    metadata: BidMetadata,
 }
 
 impl Bid {
@@ -50,6 +100,8 @@ impl Bid {
         let vesting_schedule = Some(VestingSchedule::new(release_timestamp_millis));
         let delegators = BTreeMap::new();
         let inactive = false;
+        let auto_compound = true;
+        let total_rewards = U512::zero();
         Self {
             validator_public_key,
             bonding_purse,
@@ -58,6 +110,11 @@ impl Bid {
             vesting_schedule,
             delegators,
             inactive,
+            auto_compound,
+            total_rewards,
+            minimum_delegation_amount: U512::zero(),
+            pending_delegation_rate: None,
+            metadata: BidMetadata::default(),
         }
     }
 
@@ -71,6 +128,8 @@ impl Bid {
         let vesting_schedule = None;
         let delegators = BTreeMap::new();
         let inactive = false;
+        let auto_compound = true;
+        let total_rewards = U512::zero();
         Self {
             validator_public_key,
             bonding_purse,
@@ -79,6 +138,11 @@ impl Bid {
             vesting_schedule,
             delegators,
             inactive,
+            auto_compound,
+            total_rewards,
+            minimum_delegation_amount: U512::zero(),
+            pending_delegation_rate: None,
+            metadata: BidMetadata::default(),
         }
     }
 
@@ -134,6 +198,45 @@ impl Bid {
         self.inactive
     }
 
+    /// Returns `true` if the validator's rewards are added to their stake rather than paid out.
+    pub fn auto_compound(&self) -> bool {
+        self.auto_compound
+    }
+
+    /// Sets whether the validator's rewards are added to their stake or paid out.
+    pub fn set_auto_compound(&mut self, auto_compound: bool) {
+        self.auto_compound = auto_compound;
+    }
+
+    /// Returns the total of all rewards the validator has earned.
+    pub fn total_rewards(&self) -> &U512 {
+        &self.total_rewards
+    }
+
+    /// Adds a reward to the validator's reward total, and to their stake if they auto-compound.
+    ///
+    /// Returns `true` if the reward was added to the stake.
+    pub fn add_reward(&mut self, amount: U512) -> Result<bool, Error> {
+        self.total_rewards = self
+            .total_rewards
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        if self.auto_compound {
+            self.increase_stake(amount)?;
+        }
+        Ok(self.auto_compound)
+    }
+
+    /// Returns the minimum amount the validator accepts from a single delegator.
+    pub fn minimum_delegation_amount(&self) -> &U512 {
+        &self.minimum_delegation_amount
+    }
+
+    /// Sets the minimum amount the validator accepts from a single delegator.
+    pub fn set_minimum_delegation_amount(&mut self, minimum_delegation_amount: U512) {
+        self.minimum_delegation_amount = minimum_delegation_amount;
+    }
+
     /// Decreases the stake of the provided bid
     pub fn decrease_stake(
         &mut self,
@@ -187,6 +290,67 @@ impl Bid {
         self
     }
 
+    /// Returns the delegation rate the validator is moving towards, if an increase is pending.
+    pub fn pending_delegation_rate(&self) -> Option<&DelegationRate> {
+        self.pending_delegation_rate.as_ref()
+    }
+
+    /// Requests a change of the delegation rate of the provided bid.
+    ///
+    /// A decrease takes effect immediately and cancels any pending increase, while an increase is
+    /// only recorded as pending and applied by [`Bid::process_delegation_rate_change`].  Increases
+    /// are rejected if `max_increase` is zero, as they would never be applied.
+    pub fn request_delegation_rate(
+        &mut self,
+        delegation_rate: DelegationRate,
+        max_increase: DelegationRate,
+    ) -> Result<&mut Self, Error> {
+        if delegation_rate > self.delegation_rate {
+            if max_increase == 0 {
+                return Err(Error::DelegationRateIncreaseNotAllowed);
+            }
+            self.pending_delegation_rate = Some(delegation_rate);
+        } else {
+            self.delegation_rate = delegation_rate;
+            self.pending_delegation_rate = None;
+        }
+        Ok(self)
+    }
+
+    /// Moves the delegation rate towards the pending delegation rate by at most `max_increase`.
+    ///
+    /// If `max_increase` is zero, e.g. after the limit was lowered by an upgrade, the pending
+    /// increase is dropped instead.  Returns `true` if the provided bid was modified.
+    pub fn process_delegation_rate_change(&mut self, max_increase: DelegationRate) -> bool {
+        let pending_delegation_rate = match self.pending_delegation_rate {
+            Some(pending_delegation_rate) => pending_delegation_rate,
+            None => return false,
+        };
+        if max_increase == 0 {
+            self.pending_delegation_rate = None;
+            return true;
+        }
+
+        self.delegation_rate = self
+            .delegation_rate
+            .saturating_add(max_increase)
+            .min(pending_delegation_rate);
+        if self.delegation_rate == pending_delegation_rate {
+            self.pending_delegation_rate = None;
+        }
+        true
+    }
+
+    /// Returns the metadata of the provided bid.
+    pub fn metadata(&self) -> &BidMetadata {
+        &self.metadata
+    }
+
+    /// Sets the metadata of the provided bid.
+    pub fn set_metadata(&mut self, metadata: BidMetadata) {
+        self.metadata = metadata;
+    }
+
     /// Initializes the vesting schedule of provided bid if the provided timestamp is greater than
     /// or equal to the bid's initial release timestamp and the bid is owned by a genesis
     /// validator.
@@ -256,6 +420,7 @@ impl CLTyped for Bid {
 impl ToBytes for Bid {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
+        write_encoding_version(&mut result);
         result.extend(self.validator_public_key.to_bytes()?);
         result.extend(self.bonding_purse.to_bytes()?);
         result.extend(self.staked_amount.to_bytes()?);
@@ -263,22 +428,34 @@ impl ToBytes for Bid {
         result.extend(self.vesting_schedule.to_bytes()?);
         result.extend(self.delegators.to_bytes()?);
         result.extend(self.inactive.to_bytes()?);
+        result.extend(self.auto_compound.to_bytes()?);
+        result.extend(self.total_rewards.to_bytes()?);
+        result.extend(self.minimum_delegation_amount.to_bytes()?);
+        result.extend(self.pending_delegation_rate.to_bytes()?);
+        result.extend(self.metadata.to_bytes()?);
         Ok(result)
     }
 
     fn serialized_length(&self) -> usize {
-        self.validator_public_key.serialized_length()
+        encoding_version_serialized_length()
+            + self.validator_public_key.serialized_length()
             + self.bonding_purse.serialized_length()
             + self.staked_amount.serialized_length()
             + self.delegation_rate.serialized_length()
             + self.vesting_schedule.serialized_length()
             + self.delegators.serialized_length()
             + self.inactive.serialized_length()
+            + self.auto_compound.serialized_length()
+            + self.total_rewards.serialized_length()
+            + self.minimum_delegation_amount.serialized_length()
+            + self.pending_delegation_rate.serialized_length()
+            + self.metadata.serialized_length()
     }
 }
 
 impl FromBytes for Bid {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (is_legacy, bytes) = read_encoding_version(bytes)?;
         let (validator_public_key, bytes) = FromBytes::from_bytes(bytes)?;
         let (bonding_purse, bytes) = FromBytes::from_bytes(bytes)?;
         let (staked_amount, bytes) = FromBytes::from_bytes(bytes)?;
@@ -286,6 +463,24 @@ impl FromBytes for Bid {
         let (vesting_schedule, bytes) = FromBytes::from_bytes(bytes)?;
         let (delegators, bytes) = FromBytes::from_bytes(bytes)?;
         let (inactive, bytes) = FromBytes::from_bytes(bytes)?;
+        if is_legacy {
+            // Bids stored before these settings were introduced get the defaults of a new bid.
+            let mut bid = Bid::unlocked(
+                validator_public_key,
+                bonding_purse,
+                staked_amount,
+                delegation_rate,
+            );
+            bid.vesting_schedule = vesting_schedule;
+            bid.delegators = delegators;
+            bid.inactive = inactive;
+            return Ok((bid, bytes));
+        }
+        let (auto_compound, bytes) = FromBytes::from_bytes(bytes)?;
+        let (total_rewards, bytes) = FromBytes::from_bytes(bytes)?;
+        let (minimum_delegation_amount, bytes) = FromBytes::from_bytes(bytes)?;
+        let (pending_delegation_rate, bytes) = FromBytes::from_bytes(bytes)?;
+        let (metadata, bytes) = FromBytes::from_bytes(bytes)?;
         Ok((
             Bid {
                 validator_public_key,
@@ -295,6 +490,11 @@ impl FromBytes for Bid {
                 vesting_schedule,
                 delegators,
                 inactive,
+                auto_compound,
+                total_rewards,
+                minimum_delegation_amount,
+                pending_delegation_rate,
+                metadata,
             },
             bytes,
         ))
@@ -303,11 +503,14 @@ impl FromBytes for Bid {
 
 #[cfg(test)]
 mod tests {
-    use alloc::collections::BTreeMap;
+    use alloc::{collections::BTreeMap, vec::Vec};
 
     use crate::{
-        bytesrepr,
-        system::auction::{bid::VestingSchedule, Bid, DelegationRate, Delegator},
+        bytesrepr::{self, ToBytes},
+        system::auction::{
+            bid::{BidMetadata, VestingSchedule},
+            Bid, DelegationRate, Delegator, Error,
+        },
         AccessRights, PublicKey, SecretKey, URef, U512,
     };
 
@@ -323,10 +526,72 @@ mod tests {
             vesting_schedule: Some(VestingSchedule::default()),
             delegators: BTreeMap::default(),
             inactive: true,
+            auto_compound: false,
+            total_rewards: U512::from(42),
+            minimum_delegation_amount: U512::from(500),
+            pending_delegation_rate: Some(20),
+            metadata: BidMetadata::new(Some("Validator".into()), None, None).unwrap(),
         };
         bytesrepr::test_serialization_roundtrip(&founding_validator);
     }
 
+    #[test]
+    fn should_deserialize_legacy_bid() {
+        const RELEASE_TIMESTAMP_MILLIS: u64 = 42;
+
+        let validator_public_key: PublicKey =
+            SecretKey::ed25519([0u8; SecretKey::ED25519_LENGTH]).into();
+        let delegator_public_key: PublicKey =
+            SecretKey::ed25519([1u8; SecretKey::ED25519_LENGTH]).into();
+        let bonding_purse = URef::new([42; 32], AccessRights::READ_ADD_WRITE);
+        let staked_amount = U512::from(10);
+        let delegation_rate: DelegationRate = 10;
+
+        // Encoded as before the auction settings and the version prefix were added
+        let mut legacy_delegator = Vec::new();
+        legacy_delegator.extend(delegator_public_key.to_bytes().unwrap());
+        legacy_delegator.extend(staked_amount.to_bytes().unwrap());
+        legacy_delegator.extend(bonding_purse.to_bytes().unwrap());
+        legacy_delegator.extend(validator_public_key.to_bytes().unwrap());
+        legacy_delegator.extend(Option::<VestingSchedule>::None.to_bytes().unwrap());
+
+        let mut legacy_bid = Vec::new();
+        legacy_bid.extend(validator_public_key.to_bytes().unwrap());
+        legacy_bid.extend(bonding_purse.to_bytes().unwrap());
+        legacy_bid.extend(staked_amount.to_bytes().unwrap());
+        legacy_bid.extend(delegation_rate.to_bytes().unwrap());
+        legacy_bid.extend(
+            Some(VestingSchedule::new(RELEASE_TIMESTAMP_MILLIS))
+                .to_bytes()
+                .unwrap(),
+        );
+        legacy_bid.extend(1u32.to_bytes().unwrap());
+        legacy_bid.extend(delegator_public_key.to_bytes().unwrap());
+        legacy_bid.extend(legacy_delegator);
+        legacy_bid.extend(false.to_bytes().unwrap());
+
+        let bid: Bid = bytesrepr::deserialize(legacy_bid).expect("should deserialize legacy bid");
+
+        let mut expected_bid = Bid::locked(
+            validator_public_key,
+            bonding_purse,
+            staked_amount,
+            delegation_rate,
+            RELEASE_TIMESTAMP_MILLIS,
+        );
+        expected_bid.delegators_mut().insert(
+            delegator_public_key,
+            Delegator::unlocked(
+                delegator_public_key,
+                staked_amount,
+                bonding_purse,
+                validator_public_key,
+            ),
+        );
+        assert_eq!(bid, expected_bid);
+        bytesrepr::test_serialization_roundtrip(&bid);
+    }
+
     #[test]
     fn should_initialize_delegators_different_timestamps() {
         const WEEK_MILLIS: u64 = 7 * 24 * 60 * 60 * 1000;
@@ -424,4 +689,67 @@ mod tests {
         // Validator initialized, and all delegators initialized
         assert!(!bid.process(delegator_2_release_timestamp + 1));
     }
+
+    #[test]
+    fn should_limit_delegation_rate_increase() {
+        let validator_pk = SecretKey::ed25519([42; 32]).into();
+        let bonding_purse = URef::new([42; 32], AccessRights::ADD);
+        let mut bid = Bid::unlocked(validator_pk, bonding_purse, U512::from(1000), 10);
+
+        // Increases are only applied when processed
+        bid.request_delegation_rate(25, 10).unwrap();
+        assert_eq!(*bid.delegation_rate(), 10);
+        assert_eq!(bid.pending_delegation_rate(), Some(&25));
+
+        assert!(bid.process_delegation_rate_change(10));
+        assert_eq!(*bid.delegation_rate(), 20);
+        assert_eq!(bid.pending_delegation_rate(), Some(&25));
+
+        assert!(bid.process_delegation_rate_change(10));
+        assert_eq!(*bid.delegation_rate(), 25);
+        assert_eq!(bid.pending_delegation_rate(), None);
+
+        assert!(!bid.process_delegation_rate_change(10));
+
+        // Decreases are applied immediately and cancel pending increases
+        bid.request_delegation_rate(50, 10).unwrap();
+        bid.request_delegation_rate(5, 10).unwrap();
+        assert_eq!(*bid.delegation_rate(), 5);
+        assert_eq!(bid.pending_delegation_rate(), None);
+    }
+
+    #[test]
+    fn should_reject_delegation_rate_increase_if_not_allowed() {
+        let validator_pk = SecretKey::ed25519([42; 32]).into();
+        let bonding_purse = URef::new([42; 32], AccessRights::ADD);
+        let mut bid = Bid::unlocked(validator_pk, bonding_purse, U512::from(1000), 10);
+
+        assert_eq!(
+            bid.request_delegation_rate(25, 0).unwrap_err(),
+            Error::DelegationRateIncreaseNotAllowed
+        );
+        assert_eq!(*bid.delegation_rate(), 10);
+        assert_eq!(bid.pending_delegation_rate(), None);
+
+        // Decreases are still applied immediately
+        bid.request_delegation_rate(5, 0).unwrap();
+        assert_eq!(*bid.delegation_rate(), 5);
+    }
+
+    #[test]
+    fn should_drop_pending_delegation_rate_if_increases_not_allowed() {
+        let validator_pk = SecretKey::ed25519([42; 32]).into();
+        let bonding_purse = URef::new([42; 32], AccessRights::ADD);
+        let mut bid = Bid::unlocked(validator_pk, bonding_purse, U512::from(1000), 10);
+
+        // An increase requested while the limit was still positive
+        bid.request_delegation_rate(25, 10).unwrap();
+        assert_eq!(bid.pending_delegation_rate(), Some(&25));
+
+        assert!(bid.process_delegation_rate_change(0));
+        assert_eq!(*bid.delegation_rate(), 10);
+        assert_eq!(bid.pending_delegation_rate(), None);
+
+        assert!(!bid.process_delegation_rate_change(0));
+    }
 }
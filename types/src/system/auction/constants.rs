@@ -19,6 +19,15 @@ pub const DELEGATION_RATE_DENOMINATOR: DelegationRate = 100;
 /// fractions, and small enough for many block rewards to fit into a u64.
 pub const BLOCK_REWARD: u64 = 1_000_000_000_000;
 
+/// Maximum length in bytes of each of the fields of a validator's bid metadata.
+pub const MAX_BID_METADATA_FIELD_LENGTH: usize = 256;
+
+/// Maximum number of transfer schedules which can be pending at any time.
+pub const MAX_SCHEDULED_TRANSFERS: usize = 1000;
+
+/// Maximum number of scheduled transfers executed at the end of each era.
+pub const MAX_SCHEDULED_TRANSFERS_PER_ERA: usize = 100;
+
 /// Named constant for `amount`.
 pub const ARG_AMOUNT: &str = "amount";
 /// Named constant for `delegation_rate`.
@@ -41,6 +50,10 @@ pub const ARG_ERA_ID: &str = "era_id";
 pub const ARG_REWARD_FACTORS: &str = "reward_factors";
 /// Named constant for `validator_public_key`.
 pub const ARG_VALIDATOR_PUBLIC_KEY: &str = "validator_public_key";
+/// Named constant for `auto_compound`.
+pub const ARG_AUTO_COMPOUND: &str = "auto_compound";
+/// Named constant for `new_validator`.
+pub const ARG_NEW_VALIDATOR: &str = "new_validator";
 /// Named constant for `delegator_public_key`.
 pub const ARG_DELEGATOR_PUBLIC_KEY: &str = "delegator_public_key";
 /// Named constant for `validator_slots` argument.
@@ -55,10 +68,32 @@ pub const ARG_AUCTION_DELAY: &str = "auction_delay";
 pub const ARG_LOCKED_FUNDS_PERIOD: &str = "locked_funds_period";
 /// Named constant for `unbonding_delay`
 pub const ARG_UNBONDING_DELAY: &str = "unbonding_delay";
+/// Named constant for `minimum_delegation_amount`
+pub const ARG_MINIMUM_DELEGATION_AMOUNT: &str = MINIMUM_DELEGATION_AMOUNT_KEY;
+/// Named constant for `max_delegators_per_validator`
+pub const ARG_MAX_DELEGATORS_PER_VALIDATOR: &str = MAX_DELEGATORS_PER_VALIDATOR_KEY;
+/// Named constant for `max_delegation_rate_increase`
+pub const ARG_MAX_DELEGATION_RATE_INCREASE: &str = MAX_DELEGATION_RATE_INCREASE_KEY;
+/// Named constant for `name`.
+pub const ARG_NAME: &str = "name";
+/// Named constant for `url`.
+pub const ARG_URL: &str = "url";
+/// Named constant for `contact`.
+pub const ARG_CONTACT: &str = "contact";
 /// Named constant for `era_end_timestamp_millis`;
 pub const ARG_ERA_END_TIMESTAMP_MILLIS: &str = "era_end_timestamp_millis";
 /// Named constant for `evicted_validators`;
 pub const ARG_EVICTED_VALIDATORS: &str = "evicted_validators";
+/// Named constant for `target`.
+pub const ARG_TARGET: &str = "target";
+/// Named constant for `interval`.
+pub const ARG_INTERVAL: &str = "interval";
+/// Named constant for `count`.
+pub const ARG_COUNT: &str = "count";
+/// Named constant for `id`.
+pub const ARG_ID: &str = "id";
+/// Named constant for `escrow_purse`.
+pub const ARG_ESCROW_PURSE: &str = "escrow_purse";
 
 /// Named constant for method `get_era_validators`.
 pub const METHOD_GET_ERA_VALIDATORS: &str = "get_era_validators";
@@ -72,6 +107,8 @@ pub const METHOD_WITHDRAW_BID: &str = "withdraw_bid";
 pub const METHOD_DELEGATE: &str = "delegate";
 /// Named constant for method `undelegate`.
 pub const METHOD_UNDELEGATE: &str = "undelegate";
+/// Named constant for method `redelegate`.
+pub const METHOD_REDELEGATE: &str = "redelegate";
 /// Named constant for method `run_auction`.
 pub const METHOD_RUN_AUCTION: &str = "run_auction";
 /// Named constant for method `slash`.
@@ -82,9 +119,23 @@ pub const METHOD_DISTRIBUTE: &str = "distribute";
 pub const METHOD_READ_ERA_ID: &str = "read_era_id";
 /// Named constant for method `activate_bid`.
 pub const METHOD_ACTIVATE_BID: &str = "activate_bid";
+/// Named constant for method `set_auto_compound`.
+pub const METHOD_SET_AUTO_COMPOUND: &str = "set_auto_compound";
+/// Named constant for method `set_minimum_delegation_amount`.
+pub const METHOD_SET_MINIMUM_DELEGATION_AMOUNT: &str = "set_minimum_delegation_amount";
+/// Named constant for method `set_bid_metadata`.
+pub const METHOD_SET_BID_METADATA: &str = "set_bid_metadata";
+/// Named constant for method `schedule_transfer`.
+pub const METHOD_SCHEDULE_TRANSFER: &str = "schedule_transfer";
+/// Named constant for method `cancel_scheduled_transfer`.
+pub const METHOD_CANCEL_SCHEDULED_TRANSFER: &str = "cancel_scheduled_transfer";
 
 /// Storage for `UnbondingPurses`
 pub const UNBONDING_PURSES_KEY: &str = "unbonding_purses";
+/// Storage for `Redelegations`
+pub const REDELEGATIONS_KEY: &str = "redelegations";
+/// Storage for `ScheduledTransfers`
+pub const SCHEDULED_TRANSFERS_KEY: &str = "scheduled_transfers";
 /// Storage for `EraId`.
 pub const ERA_ID_KEY: &str = "era_id";
 /// Storage for era-end timestamp.
@@ -99,3 +150,9 @@ pub const AUCTION_DELAY_KEY: &str = "auction_delay";
 pub const LOCKED_FUNDS_PERIOD_KEY: &str = "locked_funds_period";
 /// Unbonding delay expressed in eras.
 pub const UNBONDING_DELAY_KEY: &str = "unbonding_delay";
+/// Minimum amount of motes a delegator must have delegated to a single validator.
+pub const MINIMUM_DELEGATION_AMOUNT_KEY: &str = "minimum_delegation_amount";
+/// Maximum number of delegators a single validator can have.
+pub const MAX_DELEGATORS_PER_VALIDATOR_KEY: &str = "max_delegators_per_validator";
+/// Maximum amount a validator's delegation rate can be increased by in a single era.
+pub const MAX_DELEGATION_RATE_INCREASE_KEY: &str = "max_delegation_rate_increase";
@@ -9,7 +9,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
-    system::auction::{bid::VestingSchedule, Error},
+    system::auction::{
+        bid::{
+            encoding_version_serialized_length, read_encoding_version, write_encoding_version,
+            VestingSchedule,
+        },
+        Error,
+    },
     CLType, CLTyped, PublicKey, URef, U512,
 };
 
@@ -23,6 +29,8 @@ pub struct Delegator {
     bonding_purse: URef,
     validator_public_key: PublicKey,
     vesting_schedule: Option<VestingSchedule>,
+    auto_compound: bool,
+    total_rewards: U512,
 }
 
 impl Delegator {
@@ -40,6 +48,8 @@ impl Delegator {
             bonding_purse,
             validator_public_key,
             vesting_schedule,
+            auto_compound: true,
+            total_rewards: U512::zero(),
         }
     }
 
@@ -58,6 +68,8 @@ impl Delegator {
             bonding_purse,
             validator_public_key,
             vesting_schedule,
+            auto_compound: true,
+            total_rewards: U512::zero(),
         }
     }
 
@@ -76,6 +88,35 @@ impl Delegator {
         &self.validator_public_key
     }
 
+    /// Returns `true` if the delegator's rewards are added to their stake rather than paid out.
+    pub fn auto_compound(&self) -> bool {
+        self.auto_compound
+    }
+
+    /// Sets whether the delegator's rewards are added to their stake or paid out.
+    pub fn set_auto_compound(&mut self, auto_compound: bool) {
+        self.auto_compound = auto_compound;
+    }
+
+    /// Returns the total of all rewards the delegator has earned from this validator.
+    pub fn total_rewards(&self) -> &U512 {
+        &self.total_rewards
+    }
+
+    /// Adds a reward to the delegator's reward total, and to their stake if they auto-compound.
+    ///
+    /// Returns `true` if the reward was added to the stake.
+    pub fn add_reward(&mut self, amount: U512) -> Result<bool, Error> {
+        self.total_rewards = self
+            .total_rewards
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        if self.auto_compound {
+            self.increase_stake(amount)?;
+        }
+        Ok(self.auto_compound)
+    }
+
     /// Decreases the stake of the provided bid
     pub fn decrease_stake(
         &mut self,
@@ -145,30 +186,48 @@ impl CLTyped for Delegator {
 impl ToBytes for Delegator {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
+        write_encoding_version(&mut buffer);
         buffer.extend(self.delegator_public_key.to_bytes()?);
         buffer.extend(self.staked_amount.to_bytes()?);
         buffer.extend(self.bonding_purse.to_bytes()?);
         buffer.extend(self.validator_public_key.to_bytes()?);
         buffer.extend(self.vesting_schedule.to_bytes()?);
+        buffer.extend(self.auto_compound.to_bytes()?);
+        buffer.extend(self.total_rewards.to_bytes()?);
         Ok(buffer)
     }
 
     fn serialized_length(&self) -> usize {
-        self.delegator_public_key.serialized_length()
+        encoding_version_serialized_length()
+            + self.delegator_public_key.serialized_length()
             + self.staked_amount.serialized_length()
             + self.bonding_purse.serialized_length()
             + self.validator_public_key.serialized_length()
             + self.vesting_schedule.serialized_length()
+            + self.auto_compound.serialized_length()
+            + self.total_rewards.serialized_length()
     }
 }
 
 impl FromBytes for Delegator {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (is_legacy, bytes) = read_encoding_version(bytes)?;
         let (delegator_public_key, bytes) = PublicKey::from_bytes(bytes)?;
         let (staked_amount, bytes) = U512::from_bytes(bytes)?;
         let (bonding_purse, bytes) = URef::from_bytes(bytes)?;
         let (validator_public_key, bytes) = PublicKey::from_bytes(bytes)?;
         let (vesting_schedule, bytes) = FromBytes::from_bytes(bytes)?;
+        // Delegators stored before auto-compounding was introduced always compounded.
+        let (auto_compound, bytes) = if is_legacy {
+            (true, bytes)
+        } else {
+            bool::from_bytes(bytes)?
+        };
+        let (total_rewards, bytes) = if is_legacy {
+            (U512::zero(), bytes)
+        } else {
+            U512::from_bytes(bytes)?
+        };
         Ok((
             Delegator {
                 delegator_public_key,
@@ -176,6 +235,8 @@ impl FromBytes for Delegator {
                 bonding_purse,
                 validator_public_key,
                 vesting_schedule,
+                auto_compound,
+                total_rewards,
             },
             bytes,
         ))
@@ -210,4 +271,30 @@ mod tests {
         );
         bytesrepr::test_serialization_roundtrip(&locked_delegator);
     }
+
+    #[test]
+    fn should_only_compound_rewards_when_enabled() {
+        let staked_amount = U512::from(100);
+        let bonding_purse = URef::new([42; 32], AccessRights::READ_ADD_WRITE);
+        let delegator_public_key = SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into();
+        let validator_public_key = SecretKey::ed25519([43; SecretKey::ED25519_LENGTH]).into();
+        let mut delegator = Delegator::unlocked(
+            delegator_public_key,
+            staked_amount,
+            bonding_purse,
+            validator_public_key,
+        );
+        assert!(delegator.auto_compound());
+
+        assert!(delegator.add_reward(U512::from(10)).unwrap());
+        assert_eq!(*delegator.staked_amount(), U512::from(110));
+        assert_eq!(*delegator.total_rewards(), U512::from(10));
+
+        delegator.set_auto_compound(false);
+        assert!(!delegator.add_reward(U512::from(5)).unwrap());
+        assert_eq!(*delegator.staked_amount(), U512::from(110));
+        assert_eq!(*delegator.total_rewards(), U512::from(15));
+
+        bytesrepr::test_serialization_roundtrip(&delegator);
+    }
 }
@@ -7,10 +7,11 @@ use crate::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::auction::{
-        constants::*, Auction, Bids, EraId, Error, RuntimeProvider, SeigniorageAllocation,
+        constants::*, Auction, Bid, Bids, DelegationRate, Delegator, EraId, Error, MintProvider,
+        Redelegations, RuntimeProvider, ScheduledTransfers, SeigniorageAllocation,
         SeigniorageRecipientsSnapshot, StorageProvider, UnbondingPurse, UnbondingPurses,
     },
-    CLTyped, Key, KeyTag, PublicKey, URef, U512,
+    CLTyped, Key, KeyTag, PublicKey, TransferAddr, URef, U512,
 };
 
 fn read_from<P, T>(provider: &mut P, name: &str) -> Result<T, Error>
@@ -35,6 +36,39 @@ where
     Ok(())
 }
 
+/// Reads the value under a named key which is created lazily, defaulting if it doesn't exist yet.
+fn read_or_default_from<P, T>(provider: &mut P, name: &str) -> Result<T, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+    T: FromBytes + CLTyped + Default,
+{
+    match provider.named_keys_get(name) {
+        Some(key) => {
+            let uref = key.into_uref().ok_or(Error::InvalidKeyVariant)?;
+            Ok(provider.read(uref)?.unwrap_or_default())
+        }
+        None => Ok(T::default()),
+    }
+}
+
+/// Writes the value under a named key which is created lazily, creating it if it doesn't exist yet.
+fn write_or_create<P, T>(provider: &mut P, name: &str, value: T) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+    T: ToBytes + CLTyped,
+{
+    match provider.named_keys_get(name) {
+        Some(key) => {
+            let uref = key.into_uref().ok_or(Error::InvalidKeyVariant)?;
+            provider.write(uref, value)
+        }
+        None => {
+            let uref = provider.new_uref(value)?;
+            provider.put_key(name, uref.into())
+        }
+    }
+}
+
 pub fn get_bids<P>(provider: &mut P) -> Result<Bids, Error>
 where
     P: StorageProvider + RuntimeProvider + ?Sized,
@@ -86,6 +120,41 @@ where
     write_to(provider, UNBONDING_PURSES_KEY, unbonding_purses)
 }
 
+/// Returns the pending redelegations. Networks created before redelegation was introduced have no
+/// such key until the first request is made.
+pub fn get_redelegations<P>(provider: &mut P) -> Result<Redelegations, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    read_or_default_from(provider, REDELEGATIONS_KEY)
+}
+
+pub fn set_redelegations<P>(provider: &mut P, redelegations: Redelegations) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    write_or_create(provider, REDELEGATIONS_KEY, redelegations)
+}
+
+/// Returns the scheduled transfers. Networks created before scheduled transfers were introduced
+/// have no such key until the first transfer is scheduled.
+pub fn get_scheduled_transfers<P>(provider: &mut P) -> Result<ScheduledTransfers, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    read_or_default_from(provider, SCHEDULED_TRANSFERS_KEY)
+}
+
+pub fn set_scheduled_transfers<P>(
+    provider: &mut P,
+    scheduled_transfers: ScheduledTransfers,
+) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    write_or_create(provider, SCHEDULED_TRANSFERS_KEY, scheduled_transfers)
+}
+
 pub fn get_era_id<P>(provider: &mut P) -> Result<EraId, Error>
 where
     P: StorageProvider + RuntimeProvider + ?Sized,
@@ -166,6 +235,75 @@ where
     read_from(provider, UNBONDING_DELAY_KEY)
 }
 
+fn get_minimum_delegation_amount<P>(provider: &mut P) -> Result<u64, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    // Networks created before the limit was introduced accept delegations of any amount
+    if provider
+        .named_keys_get(MINIMUM_DELEGATION_AMOUNT_KEY)
+        .is_none()
+    {
+        return Ok(0);
+    }
+    read_from(provider, MINIMUM_DELEGATION_AMOUNT_KEY)
+}
+
+pub fn get_max_delegation_rate_increase<P>(provider: &mut P) -> Result<DelegationRate, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    // Networks created before the limit was introduced apply pending increases in full
+    if provider
+        .named_keys_get(MAX_DELEGATION_RATE_INCREASE_KEY)
+        .is_none()
+    {
+        return Ok(DELEGATION_RATE_DENOMINATOR);
+    }
+    read_from(provider, MAX_DELEGATION_RATE_INCREASE_KEY)
+}
+
+fn get_max_delegators_per_validator<P>(provider: &mut P) -> Result<u32, Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    // Networks created before the limit was introduced accept any number of delegators
+    if provider
+        .named_keys_get(MAX_DELEGATORS_PER_VALIDATOR_KEY)
+        .is_none()
+    {
+        return Ok(u32::MAX);
+    }
+    read_from(provider, MAX_DELEGATORS_PER_VALIDATOR_KEY)
+}
+
+/// Checks that a delegator would end up with `total_amount` delegated to the validator owning
+/// `bid` without violating the network's delegation limits or the validator's own minimum.
+pub(crate) fn check_delegation_limits<P>(
+    provider: &mut P,
+    bid: &Bid,
+    delegator_public_key: &PublicKey,
+    total_amount: U512,
+) -> Result<(), Error>
+where
+    P: StorageProvider + RuntimeProvider + ?Sized,
+{
+    let network_minimum = U512::from(get_minimum_delegation_amount(provider)?);
+    let minimum_delegation_amount = network_minimum.max(*bid.minimum_delegation_amount());
+    if total_amount < minimum_delegation_amount {
+        return Err(Error::DelegationAmountTooSmall);
+    }
+
+    let max_delegators_per_validator = get_max_delegators_per_validator(provider)?;
+    if !bid.delegators().contains_key(delegator_public_key)
+        && bid.delegators().len() >= max_delegators_per_validator as usize
+    {
+        return Err(Error::ExceededDelegatorSizeLimit);
+    }
+
+    Ok(())
+}
+
 /// Iterates over unbonding entries and checks if a locked amount can be paid already if
 /// a specific era is reached.
 ///
@@ -219,6 +357,197 @@ pub(crate) fn process_unbond_requests<P: Auction + ?Sized>(provider: &mut P) ->
     Ok(())
 }
 
+/// Moves the stake of every pending redelegation request from the delegator's entry under the
+/// current validator to their entry under the new validator.
+///
+/// Requests which can no longer be fulfilled, e.g. because the delegator has undelegated in the
+/// meantime or the new validator's bid was withdrawn, are dropped and the stake stays where it is.
+///
+/// This function can be called by the system only.
+pub(crate) fn process_redelegations<P: Auction + ?Sized>(provider: &mut P) -> Result<(), Error> {
+    if provider.get_caller() != SYSTEM_ACCOUNT {
+        return Err(Error::InvalidCaller);
+    }
+
+    let redelegations = get_redelegations(provider)?;
+    if redelegations.is_empty() {
+        return Ok(());
+    }
+
+    let era_end_timestamp_millis = get_era_end_timestamp_millis(provider)?;
+
+    for redelegation in redelegations.values().flatten() {
+        let validator_account_hash = AccountHash::from(redelegation.validator_public_key());
+        let new_validator_account_hash = AccountHash::from(redelegation.new_validator_public_key());
+
+        let mut bid = match provider.read_bid(&validator_account_hash)? {
+            Some(bid) => bid,
+            None => continue,
+        };
+        let mut new_bid = match provider.read_bid(&new_validator_account_hash)? {
+            Some(new_bid) if !new_bid.inactive() => new_bid,
+            _ => continue,
+        };
+
+        // Limits may have been hit by other delegations since the request was made
+        let new_total_amount = new_bid
+            .delegators()
+            .get(redelegation.delegator_public_key())
+            .map(|new_delegator| *new_delegator.staked_amount())
+            .unwrap_or_default()
+            .saturating_add(*redelegation.amount());
+        if check_delegation_limits(
+            provider,
+            &new_bid,
+            redelegation.delegator_public_key(),
+            new_total_amount,
+        )
+        .is_err()
+        {
+            continue;
+        }
+
+        let delegators = bid.delegators_mut();
+        let delegator = match delegators.get_mut(redelegation.delegator_public_key()) {
+            Some(delegator) => delegator,
+            None => continue,
+        };
+        let source = *delegator.bonding_purse();
+        let auto_compound = delegator.auto_compound();
+        let updated_stake =
+            match delegator.decrease_stake(*redelegation.amount(), era_end_timestamp_millis) {
+                Ok(updated_stake) => updated_stake,
+                Err(_) => continue,
+            };
+        if updated_stake.is_zero() {
+            delegators.remove(redelegation.delegator_public_key());
+        }
+
+        // A failed transfer or purse creation drops the request, leaving both bids as they were.
+        let new_delegators = new_bid.delegators_mut();
+        match new_delegators.get_mut(redelegation.delegator_public_key()) {
+            Some(new_delegator) => {
+                let target = *new_delegator.bonding_purse();
+                if new_delegator
+                    .increase_stake(*redelegation.amount())
+                    .is_err()
+                {
+                    continue;
+                }
+                if provider
+                    .transfer_purse_to_purse(source, target, *redelegation.amount())
+                    .is_err()
+                {
+                    continue;
+                }
+            }
+            None => {
+                let bonding_purse = match provider.create_purse() {
+                    Ok(bonding_purse) => bonding_purse,
+                    Err(_) => continue,
+                };
+                if provider
+                    .transfer_purse_to_purse(source, bonding_purse, *redelegation.amount())
+                    .is_err()
+                {
+                    continue;
+                }
+                let mut new_delegator = Delegator::unlocked(
+                    *redelegation.delegator_public_key(),
+                    *redelegation.amount(),
+                    bonding_purse,
+                    *redelegation.new_validator_public_key(),
+                );
+                new_delegator.set_auto_compound(auto_compound);
+                new_delegators.insert(*redelegation.delegator_public_key(), new_delegator);
+            }
+        }
+
+        provider.write_bid(validator_account_hash, bid)?;
+        provider.write_bid(new_validator_account_hash, new_bid)?;
+    }
+
+    set_redelegations(provider, Redelegations::new())?;
+    Ok(())
+}
+
+/// Executes the scheduled transfers which are due at the end of the given era and burns the fee
+/// of each execution from its escrow purse. Returns the addresses of the executed transfers.
+///
+/// At most [`MAX_SCHEDULED_TRANSFERS_PER_ERA`] transfers are executed per era; any further due
+/// transfers stay due and are executed at the end of a later era.
+///
+/// A failed execution, e.g. because the target purse no longer exists or the fee could not be
+/// burned, still counts towards the schedule and its amount stays in the escrow purse, from where
+/// the owner can reclaim it by cancelling. Schedules are pruned once they have no executions left
+/// and their escrow purse is empty.
+///
+/// This function can be called by the system only.
+pub(crate) fn process_scheduled_transfers<P: Auction + ?Sized>(
+    provider: &mut P,
+    era_id: EraId,
+) -> Result<Vec<TransferAddr>, Error> {
+    if provider.get_caller() != SYSTEM_ACCOUNT {
+        return Err(Error::InvalidCaller);
+    }
+
+    let mut transfers = Vec::new();
+
+    let mut scheduled_transfers = get_scheduled_transfers(provider)?;
+    if !scheduled_transfers
+        .values()
+        .any(|scheduled_transfer| scheduled_transfer.is_due(era_id))
+    {
+        return Ok(transfers);
+    }
+
+    let mut finished = Vec::new();
+    let mut executed = 0;
+
+    for (escrow_purse_addr, scheduled_transfer) in scheduled_transfers.iter_mut() {
+        if executed == MAX_SCHEDULED_TRANSFERS_PER_ERA {
+            break;
+        }
+        if !scheduled_transfer.is_due(era_id) {
+            continue;
+        }
+        executed += 1;
+
+        // A failed burn must not fail the auction step, so it only skips this execution.
+        let escrow_purse = *scheduled_transfer.escrow_purse();
+        if provider
+            .burn(escrow_purse, *scheduled_transfer.fee())
+            .is_ok()
+        {
+            if let Ok(Some(transfer_addr)) = provider.transfer_purse_to_purse_with_id(
+                escrow_purse,
+                *scheduled_transfer.target(),
+                *scheduled_transfer.amount(),
+                scheduled_transfer.id(),
+            ) {
+                transfers.push(transfer_addr);
+            }
+        }
+        scheduled_transfer.advance();
+
+        if scheduled_transfer.remaining() == 0
+            && provider
+                .balance(escrow_purse)?
+                .unwrap_or_default()
+                .is_zero()
+        {
+            finished.push(*escrow_purse_addr);
+        }
+    }
+
+    for escrow_purse_addr in finished {
+        scheduled_transfers.remove(&escrow_purse_addr);
+    }
+
+    set_scheduled_transfers(provider, scheduled_transfers)?;
+    Ok(transfers)
+}
+
 /// Creates a new purse in unbonding_purses given a validator's key, amount, and a destination
 /// unbonding purse. Returns the amount of motes remaining in the validator's bid purse.
 pub(crate) fn create_unbonding_purse<P: Auction + ?Sized>(
@@ -250,13 +579,24 @@ pub(crate) fn create_unbonding_purse<P: Auction + ?Sized>(
     Ok(())
 }
 
-/// Reinvests delegator reward by increasing its stake.
-pub fn reinvest_delegator_rewards<P>(
+/// Where a minted reward is transferred to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RewardTarget {
+    /// The reward was added to the stake, so is transferred into the bonding purse.
+    BondingPurse(URef),
+    /// The reward is paid out to the main purse of the given account.
+    Account(AccountHash),
+}
+
+/// Records the delegators' rewards, increasing the stake of those who auto-compound.
+///
+/// Returns each delegator's reward along with where it should be transferred to.
+pub fn distribute_delegator_rewards<P>(
     provider: &mut P,
     seigniorage_allocations: &mut Vec<SeigniorageAllocation>,
     validator_public_key: PublicKey,
     rewards: impl Iterator<Item = (PublicKey, Ratio<U512>)>,
-) -> Result<Vec<(U512, URef)>, Error>
+) -> Result<Vec<(U512, RewardTarget)>, Error>
 where
     P: StorageProvider,
 {
@@ -279,9 +619,13 @@ where
 
         let delegator_reward_trunc = delegator_reward.to_integer();
 
-        delegator.increase_stake(delegator_reward_trunc)?;
+        let reward_target = if delegator.add_reward(delegator_reward_trunc)? {
+            RewardTarget::BondingPurse(*delegator.bonding_purse())
+        } else {
+            RewardTarget::Account(AccountHash::from(&delegator_key))
+        };
 
-        delegator_payouts.push((delegator_reward_trunc, *delegator.bonding_purse()));
+        delegator_payouts.push((delegator_reward_trunc, reward_target));
 
         let allocation = SeigniorageAllocation::delegator(
             delegator_key,
@@ -297,13 +641,14 @@ where
     Ok(delegator_payouts)
 }
 
-/// Reinvests validator reward by increasing its stake and returns its bonding purse.
-pub fn reinvest_validator_reward<P>(
+/// Records the validator's reward, increasing its stake if it auto-compounds, and returns where
+/// the reward should be transferred to.
+pub fn distribute_validator_reward<P>(
     provider: &mut P,
     seigniorage_allocations: &mut Vec<SeigniorageAllocation>,
     validator_public_key: PublicKey,
     amount: U512,
-) -> Result<URef, Error>
+) -> Result<RewardTarget, Error>
 where
     P: StorageProvider,
 {
@@ -316,15 +661,37 @@ where
         }
     };
 
-    bid.increase_stake(amount)?;
+    let reward_target = if bid.add_reward(amount)? {
+        RewardTarget::BondingPurse(*bid.bonding_purse())
+    } else {
+        RewardTarget::Account(validator_account_hash)
+    };
 
     let allocation = SeigniorageAllocation::validator(validator_public_key, amount);
 
     seigniorage_allocations.push(allocation);
 
-    let bonding_purse = *bid.bonding_purse();
-
     provider.write_bid(validator_account_hash, bid)?;
 
-    Ok(bonding_purse)
+    Ok(reward_target)
+}
+
+/// Transfers a reward from the purse it was minted into to its target.
+pub fn transfer_reward<P>(
+    provider: &mut P,
+    source: URef,
+    reward_target: RewardTarget,
+    amount: U512,
+) -> Result<(), Error>
+where
+    P: MintProvider + ?Sized,
+{
+    match reward_target {
+        RewardTarget::BondingPurse(bonding_purse) => {
+            provider.transfer_purse_to_purse(source, bonding_purse, amount)
+        }
+        RewardTarget::Account(account_hash) => provider
+            .transfer_purse_to_account(source, account_hash, amount)
+            .map(|_| ()),
+    }
 }
@@ -1,7 +1,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use alloc::{boxed::Box, vec::Vec};
+use alloc::vec::Vec;
 
 #[cfg(feature = "std")]
 use schemars::JsonSchema;
@@ -9,12 +9,20 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     bytesrepr::{self, FromBytes, ToBytes},
-    CLType, CLTyped, PublicKey, U512,
+    CLType, CLTyped, PublicKey, TransferAddr, U512,
 };
 
 const SEIGNIORAGE_ALLOCATION_VALIDATOR_TAG: u8 = 0;
 const SEIGNIORAGE_ALLOCATION_DELEGATOR_TAG: u8 = 1;
 
+/// Precedes the versioned encoding of an [`EraInfo`].
+///
+/// Encodings without it are a plain list of seigniorage allocations and predate the recording of
+/// transfers and supply.
+const ERA_INFO_VERSIONED_ENCODING_MARKER: u32 = u32::MAX;
+/// The current version of the encoding of an [`EraInfo`].
+const ERA_INFO_ENCODING_VERSION: u8 = 1;
+
 /// Information about a seigniorage allocation
 #[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -157,20 +165,129 @@ impl CLTyped for SeigniorageAllocation {
     }
 }
 
+/// Supply accounting of an era, recorded as of the end of the era.
+#[derive(Debug, Default, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct EraSupply {
+    total_supply: U512,
+    circulating_supply: U512,
+    total_staked: U512,
+    era_issuance: U512,
+    total_burned: U512,
+}
+
+impl EraSupply {
+    /// Constructs an [`EraSupply`]. The circulating supply is the part of the total supply which
+    /// is not staked.
+    pub fn new(
+        total_supply: U512,
+        total_staked: U512,
+        era_issuance: U512,
+        total_burned: U512,
+    ) -> Self {
+        let circulating_supply = total_supply.saturating_sub(total_staked);
+        EraSupply {
+            total_supply,
+            circulating_supply,
+            total_staked,
+            era_issuance,
+            total_burned,
+        }
+    }
+
+    /// Returns the total supply.
+    pub fn total_supply(&self) -> &U512 {
+        &self.total_supply
+    }
+
+    /// Returns the part of the total supply which is not staked.
+    pub fn circulating_supply(&self) -> &U512 {
+        &self.circulating_supply
+    }
+
+    /// Returns the amount staked by validators and delegators.
+    pub fn total_staked(&self) -> &U512 {
+        &self.total_staked
+    }
+
+    /// Returns the amount minted as seigniorage in the era.
+    pub fn era_issuance(&self) -> &U512 {
+        &self.era_issuance
+    }
+
+    /// Returns the cumulative amount by which the total supply was reduced.
+    pub fn total_burned(&self) -> &U512 {
+        &self.total_burned
+    }
+}
+
+impl ToBytes for EraSupply {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.total_supply.to_bytes()?);
+        result.extend(self.circulating_supply.to_bytes()?);
+        result.extend(self.total_staked.to_bytes()?);
+        result.extend(self.era_issuance.to_bytes()?);
+        result.extend(self.total_burned.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.total_supply.serialized_length()
+            + self.circulating_supply.serialized_length()
+            + self.total_staked.serialized_length()
+            + self.era_issuance.serialized_length()
+            + self.total_burned.serialized_length()
+    }
+}
+
+impl FromBytes for EraSupply {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (total_supply, rem) = U512::from_bytes(bytes)?;
+        let (circulating_supply, rem) = U512::from_bytes(rem)?;
+        let (total_staked, rem) = U512::from_bytes(rem)?;
+        let (era_issuance, rem) = U512::from_bytes(rem)?;
+        let (total_burned, rem) = U512::from_bytes(rem)?;
+        Ok((
+            EraSupply {
+                total_supply,
+                circulating_supply,
+                total_staked,
+                era_issuance,
+                total_burned,
+            },
+            rem,
+        ))
+    }
+}
+
+impl CLTyped for EraSupply {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
 /// Auction metadata.  Intended to be recorded at each era.
 #[derive(Debug, Default, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
 #[serde(deny_unknown_fields)]
 pub struct EraInfo {
     seigniorage_allocations: Vec<SeigniorageAllocation>,
+    transfers: Vec<TransferAddr>,
+    supply: EraSupply,
 }
 
 impl EraInfo {
     /// Constructs a [`EraInfo`].
     pub fn new() -> Self {
         let seigniorage_allocations = Vec::new();
+        let transfers = Vec::new();
+        let supply = EraSupply::default();
         EraInfo {
             seigniorage_allocations,
+            transfers,
+            supply,
         }
     }
 
@@ -184,6 +301,28 @@ impl EraInfo {
         &mut self.seigniorage_allocations
     }
 
+    /// Returns the addresses of the transfers executed by the system at the end of the era, i.e.
+    /// the scheduled transfers which were due.
+    pub fn transfers(&self) -> &Vec<TransferAddr> {
+        &self.transfers
+    }
+
+    /// Returns a mutable reference to the addresses of the transfers executed at the end of the
+    /// era.
+    pub fn transfers_mut(&mut self) -> &mut Vec<TransferAddr> {
+        &mut self.transfers
+    }
+
+    /// Returns the supply accounting of the era.
+    pub fn supply(&self) -> &EraSupply {
+        &self.supply
+    }
+
+    /// Returns a mutable reference to the supply accounting of the era.
+    pub fn supply_mut(&mut self) -> &mut EraSupply {
+        &mut self.supply
+    }
+
     /// Returns all seigniorage allocations that match the provided public key
     /// using the following criteria:
     /// * If the match candidate is a validator allocation, the provided public key is matched
@@ -208,20 +347,46 @@ impl EraInfo {
 
 impl ToBytes for EraInfo {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
-        self.seigniorage_allocations.to_bytes()
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(ERA_INFO_VERSIONED_ENCODING_MARKER.to_bytes()?);
+        result.extend(ERA_INFO_ENCODING_VERSION.to_bytes()?);
+        result.extend(self.seigniorage_allocations.to_bytes()?);
+        result.extend(self.transfers.to_bytes()?);
+        result.extend(self.supply.to_bytes()?);
+        Ok(result)
     }
 
     fn serialized_length(&self) -> usize {
-        self.seigniorage_allocations.serialized_length()
+        ERA_INFO_VERSIONED_ENCODING_MARKER.serialized_length()
+            + ERA_INFO_ENCODING_VERSION.serialized_length()
+            + self.seigniorage_allocations.serialized_length()
+            + self.transfers.serialized_length()
+            + self.supply.serialized_length()
     }
 }
 
 impl FromBytes for EraInfo {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (seigniorage_allocations, rem) = Vec::<SeigniorageAllocation>::from_bytes(bytes)?;
+        let (marker, rem) = u32::from_bytes(bytes)?;
+        if marker != ERA_INFO_VERSIONED_ENCODING_MARKER {
+            // The marker is the length of the allocations list of a legacy encoding
+            let (seigniorage_allocations, rem) = Vec::<SeigniorageAllocation>::from_bytes(bytes)?;
+            let mut era_info = EraInfo::new();
+            era_info.seigniorage_allocations = seigniorage_allocations;
+            return Ok((era_info, rem));
+        }
+        let (version, rem) = u8::from_bytes(rem)?;
+        if version != ERA_INFO_ENCODING_VERSION {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let (seigniorage_allocations, rem) = Vec::<SeigniorageAllocation>::from_bytes(rem)?;
+        let (transfers, rem) = Vec::<TransferAddr>::from_bytes(rem)?;
+        let (supply, rem) = EraSupply::from_bytes(rem)?;
         Ok((
             EraInfo {
                 seigniorage_allocations,
+                transfers,
+                supply,
             },
             rem,
         ))
@@ -230,11 +395,11 @@ impl FromBytes for EraInfo {
 
 impl CLTyped for EraInfo {
     fn cl_type() -> CLType {
-        CLType::List(Box::new(SeigniorageAllocation::cl_type()))
+        CLType::Any
     }
 }
 
-/// Generators for [`SeigniorageAllocation`] and [`EraInfo`]
+/// Generators for [`SeigniorageAllocation`], [`EraSupply`] and [`EraInfo`]
 #[cfg(any(feature = "gens", test))]
 pub mod gens {
     use proptest::{
@@ -245,8 +410,9 @@ pub mod gens {
 
     use crate::{
         crypto::gens::public_key_arb,
+        deploy_info::gens::transfer_addr_arb,
         gens::u512_arb,
-        system::auction::{EraInfo, SeigniorageAllocation},
+        system::auction::{EraInfo, EraSupply, SeigniorageAllocation},
     };
 
     fn seigniorage_allocation_validator_arb() -> impl Strategy<Value = SeigniorageAllocation> {
@@ -271,28 +437,97 @@ pub mod gens {
         ]
     }
 
+    /// Creates an arbitrary [`EraSupply`]
+    pub fn era_supply_arb() -> impl Strategy<Value = EraSupply> {
+        (u512_arb(), u512_arb(), u512_arb(), u512_arb()).prop_map(
+            |(total_supply, total_staked, era_issuance, total_burned)| {
+                EraSupply::new(total_supply, total_staked, era_issuance, total_burned)
+            },
+        )
+    }
+
     /// Creates an arbitrary [`EraInfo`]
     pub fn era_info_arb(size: impl Into<SizeRange>) -> impl Strategy<Value = EraInfo> {
-        collection::vec(seigniorage_allocation_arb(), size).prop_map(|allocations| {
-            let mut era_info = EraInfo::new();
-            *era_info.seigniorage_allocations_mut() = allocations;
-            era_info
-        })
+        let size = size.into();
+        (
+            collection::vec(seigniorage_allocation_arb(), size.clone()),
+            collection::vec(transfer_addr_arb(), size),
+            era_supply_arb(),
+        )
+            .prop_map(|(allocations, transfers, supply)| {
+                let mut era_info = EraInfo::new();
+                *era_info.seigniorage_allocations_mut() = allocations;
+                *era_info.transfers_mut() = transfers;
+                *era_info.supply_mut() = supply;
+                era_info
+            })
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use alloc::vec;
+
     use proptest::prelude::*;
 
-    use crate::bytesrepr;
+    use crate::{bytesrepr, PublicKey, SecretKey, U512};
 
-    use super::gens;
+    use super::{gens, EraInfo, EraSupply, SeigniorageAllocation};
+
+    #[test]
+    fn should_deserialize_legacy_era_info() {
+        let validator_public_key: PublicKey =
+            SecretKey::ed25519([0u8; SecretKey::ED25519_LENGTH]).into();
+        let delegator_public_key: PublicKey =
+            SecretKey::ed25519([1u8; SecretKey::ED25519_LENGTH]).into();
+        let seigniorage_allocations = vec![
+            SeigniorageAllocation::validator(validator_public_key, U512::from(10)),
+            SeigniorageAllocation::delegator(
+                delegator_public_key,
+                validator_public_key,
+                U512::from(5),
+            ),
+        ];
+
+        // Encoded as before transfers and supply were recorded
+        let legacy_bytes = bytesrepr::serialize(&seigniorage_allocations).unwrap();
+        let era_info: EraInfo =
+            bytesrepr::deserialize(legacy_bytes).expect("should deserialize legacy era info");
+
+        let mut expected_era_info = EraInfo::new();
+        *expected_era_info.seigniorage_allocations_mut() = seigniorage_allocations;
+        assert_eq!(era_info, expected_era_info);
+        bytesrepr::test_serialization_roundtrip(&era_info);
+    }
+
+    #[test]
+    fn should_not_count_staked_amount_as_circulating() {
+        let supply = EraSupply::new(
+            U512::from(1_000),
+            U512::from(400),
+            U512::from(10),
+            U512::one(),
+        );
+        assert_eq!(*supply.circulating_supply(), U512::from(600));
+
+        let supply = EraSupply::new(
+            U512::from(1_000),
+            U512::from(2_000),
+            U512::zero(),
+            U512::zero(),
+        );
+        assert!(supply.circulating_supply().is_zero());
+    }
 
     proptest! {
         #[test]
         fn test_serialization_roundtrip(era_info in gens::era_info_arb(0..32)) {
             bytesrepr::test_serialization_roundtrip(&era_info)
         }
+
+        #[test]
+        fn test_era_supply_serialization_roundtrip(supply in gens::era_supply_arb()) {
+            bytesrepr::test_serialization_roundtrip(&supply)
+        }
     }
 }
@@ -139,11 +139,43 @@ pub enum Error {
     #[fail(display = "Delegator's funds are locked")]
     DelegatorFundsLocked = 38,
 
-    // NOTE: These variants below and related plumbing will be removed once support for WASM
-    // system contracts will be dropped.
+    // NOTE: The variant below and related plumbing will be removed once support for WASM system
+    // contracts will be dropped.  Its value must not change until then.
     #[doc(hidden)]
     #[fail(display = "GasLimit")]
-    GasLimit,
+    GasLimit = 39,
+
+    /// Raised when a delegator attempts to redelegate to the validator they are already
+    /// delegating to.
+    #[fail(display = "Cannot redelegate to the same validator")]
+    RedelegationToSameValidator = 40,
+    /// Raised when the validator targeted by a redelegation does not have a bid.
+    #[fail(display = "Redelegation target validator not found")]
+    RedelegationValidatorNotFound = 41,
+    /// Raised when a delegation would leave the delegator with less than the minimum amount
+    /// delegated to the validator.
+    #[fail(display = "Delegation amount too small")]
+    DelegationAmountTooSmall = 42,
+    /// Raised when a validator already has the maximum number of delegators allowed.
+    #[fail(display = "Exceeded the maximum number of delegators per validator")]
+    ExceededDelegatorSizeLimit = 43,
+    /// Raised when a field of a validator's bid metadata is too long.
+    #[fail(display = "Bid metadata too long")]
+    BidMetadataTooLong = 44,
+    /// Raised when a transfer schedule has no executions, more than one execution with a zero
+    /// interval, a first execution in a past era or a target purse which can't be added to.
+    #[fail(display = "Invalid transfer schedule")]
+    InvalidTransferSchedule = 45,
+    /// Raised when there is no scheduled transfer with the given escrow purse owned by the caller.
+    #[fail(display = "Scheduled transfer not found")]
+    ScheduledTransferNotFound = 46,
+    /// Raised when the maximum number of pending transfer schedules has been reached.
+    #[fail(display = "Too many scheduled transfers")]
+    TooManyScheduledTransfers = 47,
+    /// Raised when a validator requests an increase of its delegation rate while the network
+    /// doesn't allow delegation rates to increase.
+    #[fail(display = "Delegation rate increase not allowed")]
+    DelegationRateIncreaseNotAllowed = 48,
 
     #[cfg(test)]
     #[doc(hidden)]
@@ -218,6 +250,27 @@ impl TryFrom<u8> for Error {
             d if d == Error::DelegationRateTooLarge as u8 => Ok(Error::DelegationRateTooLarge),
             d if d == Error::DelegatorFundsLocked as u8 => Ok(Error::DelegatorFundsLocked),
             d if d == Error::GasLimit as u8 => Ok(Error::GasLimit),
+            d if d == Error::RedelegationToSameValidator as u8 => {
+                Ok(Error::RedelegationToSameValidator)
+            }
+            d if d == Error::RedelegationValidatorNotFound as u8 => {
+                Ok(Error::RedelegationValidatorNotFound)
+            }
+            d if d == Error::DelegationAmountTooSmall as u8 => Ok(Error::DelegationAmountTooSmall),
+            d if d == Error::ExceededDelegatorSizeLimit as u8 => {
+                Ok(Error::ExceededDelegatorSizeLimit)
+            }
+            d if d == Error::BidMetadataTooLong as u8 => Ok(Error::BidMetadataTooLong),
+            d if d == Error::InvalidTransferSchedule as u8 => Ok(Error::InvalidTransferSchedule),
+            d if d == Error::ScheduledTransferNotFound as u8 => {
+                Ok(Error::ScheduledTransferNotFound)
+            }
+            d if d == Error::TooManyScheduledTransfers as u8 => {
+                Ok(Error::TooManyScheduledTransfers)
+            }
+            d if d == Error::DelegationRateIncreaseNotAllowed as u8 => {
+                Ok(Error::DelegationRateIncreaseNotAllowed)
+            }
             _ => Err(TryFromU8ForError(())),
         }
     }
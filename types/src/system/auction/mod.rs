@@ -6,16 +6,18 @@ mod detail;
 mod era_info;
 mod error;
 mod providers;
+mod redelegation;
+mod scheduled_transfer;
 mod seigniorage_recipient;
 mod unbonding_purse;
 
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 
 use num_rational::Ratio;
 
-use crate::{account::AccountHash, PublicKey, U512};
+use crate::{account::AccountHash, AccessRights, PublicKey, URef, URefAddr, U512};
 
-pub use bid::Bid;
+pub use bid::{Bid, BidMetadata};
 pub use constants::*;
 pub use delegator::Delegator;
 pub use era_info::*;
@@ -23,6 +25,8 @@ pub use error::Error;
 pub use providers::{
     AccountProvider, MintProvider, RuntimeProvider, StorageProvider, SystemProvider,
 };
+pub use redelegation::Redelegation;
+pub use scheduled_transfer::ScheduledTransfer;
 pub use seigniorage_recipient::SeigniorageRecipient;
 pub use unbonding_purse::UnbondingPurse;
 
@@ -50,6 +54,12 @@ pub type SeigniorageRecipientsSnapshot = BTreeMap<EraId, SeigniorageRecipients>;
 /// Validators and delegators mapped to their unbonding purses.
 pub type UnbondingPurses = BTreeMap<PublicKey, Vec<UnbondingPurse>>;
 
+/// Validators mapped to the pending redelegations away from them.
+pub type Redelegations = BTreeMap<PublicKey, Vec<Redelegation>>;
+
+/// Scheduled transfers mapped by the address of their escrow purse.
+pub type ScheduledTransfers = BTreeMap<URefAddr, ScheduledTransfer>;
+
 /// Bonding auction contract interface
 pub trait Auction:
     StorageProvider + SystemProvider + RuntimeProvider + MintProvider + AccountProvider + Sized
@@ -90,7 +100,9 @@ pub trait Auction:
 
     /// For a non-founder validator, this adds, or modifies, an entry in the `bids` collection and
     /// calls `bond` in the Mint contract to create (or top off) a bid purse. It also adjusts the
-    /// delegation rate.
+    /// delegation rate: a decrease is applied immediately, while an increase is applied gradually
+    /// at the upcoming auctions, limited by the maximum delegation rate increase per era.
+    /// Increases are rejected if that maximum is zero.
     fn add_bid(
         &mut self,
         public_key: PublicKey,
@@ -120,11 +132,11 @@ pub trait Auction:
                 if bid.inactive() {
                     bid.activate();
                 }
+                let max_delegation_rate_increase = detail::get_max_delegation_rate_increase(self)?;
+                bid.request_delegation_rate(delegation_rate, max_delegation_rate_increase)?;
                 self.transfer_purse_to_purse(source, *bid.bonding_purse(), amount)
                     .map_err(|_| Error::TransferToBidPurse)?;
-                let updated_amount = bid
-                    .with_delegation_rate(delegation_rate)
-                    .increase_stake(amount)?;
+                let updated_amount = bid.increase_stake(amount)?;
                 self.write_bid(account_hash, bid)?;
                 updated_amount
             }
@@ -227,6 +239,15 @@ pub trait Auction:
             }
         };
 
+        let total_amount = bid
+            .delegators()
+            .get(&delegator_public_key)
+            .map(|delegator| *delegator.staked_amount())
+            .unwrap_or_default()
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        detail::check_delegation_limits(self, &bid, &delegator_public_key, total_amount)?;
+
         let delegators = bid.delegators_mut();
 
         let new_delegation_amount = match delegators.get_mut(&delegator_public_key) {
@@ -306,6 +327,96 @@ pub trait Auction:
         Ok(new_amount)
     }
 
+    /// Requests that an amount of motes delegated to one validator is delegated to another one
+    /// instead.
+    ///
+    /// The motes stay bonded throughout; the stake is moved between the validators' delegator
+    /// entries when the next auction is run. Returns the amount which will remain delegated to the
+    /// current validator once all of the delegator's pending redelegations are processed.
+    fn redelegate(
+        &mut self,
+        delegator_public_key: PublicKey,
+        validator_public_key: PublicKey,
+        amount: U512,
+        new_validator_public_key: PublicKey,
+    ) -> Result<U512, Error> {
+        let account_hash = AccountHash::from_public_key(&delegator_public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        if amount.is_zero() {
+            return Err(Error::InvalidAmount);
+        }
+
+        if validator_public_key == new_validator_public_key {
+            return Err(Error::RedelegationToSameValidator);
+        }
+
+        let validator_account_hash = AccountHash::from(&validator_public_key);
+        let bid = match self.read_bid(&validator_account_hash)? {
+            Some(bid) => bid,
+            None => return Err(Error::ValidatorNotFound),
+        };
+
+        let mut delegator = match bid.delegators().get(&delegator_public_key) {
+            Some(delegator) => *delegator,
+            None => return Err(Error::DelegatorNotFound),
+        };
+
+        let new_validator_account_hash = AccountHash::from(&new_validator_public_key);
+        let new_bid = match self.read_bid(&new_validator_account_hash)? {
+            Some(new_bid) if !new_bid.inactive() => new_bid,
+            _ => return Err(Error::RedelegationValidatorNotFound),
+        };
+
+        let new_total_amount = new_bid
+            .delegators()
+            .get(&delegator_public_key)
+            .map(|new_delegator| *new_delegator.staked_amount())
+            .unwrap_or_default()
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        detail::check_delegation_limits(self, &new_bid, &delegator_public_key, new_total_amount)?;
+
+        let mut redelegations = detail::get_redelegations(self)?;
+
+        let pending_amount: U512 = redelegations
+            .get(&validator_public_key)
+            .map(|redelegation_list| {
+                redelegation_list
+                    .iter()
+                    .filter(|redelegation| {
+                        redelegation.delegator_public_key() == &delegator_public_key
+                    })
+                    .map(|redelegation| *redelegation.amount())
+                    .sum()
+            })
+            .unwrap_or_default();
+
+        let total_amount = pending_amount
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+
+        // Check the redelegation against a copy of the delegator so that locked funds can't be
+        // moved away from their validator.
+        let era_end_timestamp_millis = detail::get_era_end_timestamp_millis(self)?;
+        let remaining_amount = delegator.decrease_stake(total_amount, era_end_timestamp_millis)?;
+
+        redelegations
+            .entry(validator_public_key)
+            .or_default()
+            .push(Redelegation::new(
+                delegator_public_key,
+                validator_public_key,
+                new_validator_public_key,
+                amount,
+            ));
+        detail::set_redelegations(self, redelegations)?;
+
+        Ok(remaining_amount)
+    }
+
     /// Slashes each validator.
     ///
     /// This can be only invoked through a system call.
@@ -366,6 +477,11 @@ pub trait Auction:
         let auction_delay = detail::get_auction_delay(self)?;
         let snapshot_size = auction_delay as usize + 1;
         let mut era_id = detail::get_era_id(self)?;
+        let max_delegation_rate_increase = detail::get_max_delegation_rate_increase(self)?;
+
+        // Process redelegation requests before reading bids, as they move stake between them
+        detail::process_redelegations(self)?;
+
         let mut bids = detail::get_bids(self)?;
 
         // Process unbond requests
@@ -378,6 +494,10 @@ pub trait Auction:
                 bids_modified = true;
             }
 
+            if bid.process_delegation_rate_change(max_delegation_rate_increase) {
+                bids_modified = true;
+            }
+
             if evicted_validators.contains(validator_public_key) {
                 bids_modified = bid.deactivate()
             }
@@ -471,6 +591,7 @@ pub trait Auction:
 
         let mut era_info = EraInfo::new();
         let mut seigniorage_allocations = era_info.seigniorage_allocations_mut();
+        let mut era_issuance = U512::zero();
 
         for (public_key, reward_factor) in reward_factors {
             let recipient = seigniorage_recipients
@@ -511,7 +632,7 @@ pub trait Auction:
                         let reward = delegators_part * reward_multiplier;
                         (*delegator_key, reward)
                     });
-            let delegator_payouts = detail::reinvest_delegator_rewards(
+            let delegator_payouts = detail::distribute_delegator_rewards(
                 self,
                 &mut seigniorage_allocations,
                 public_key,
@@ -519,12 +640,12 @@ pub trait Auction:
             )?;
             let total_delegator_payout = delegator_payouts
                 .iter()
-                .map(|(amount, _bonding_purse)| *amount)
+                .map(|(amount, _reward_target)| *amount)
                 .sum();
 
             let validators_part: Ratio<U512> = total_reward - Ratio::from(total_delegator_payout);
             let validator_reward = validators_part.to_integer();
-            let validator_bonding_purse = detail::reinvest_validator_reward(
+            let validator_reward_target = detail::distribute_validator_reward(
                 self,
                 &mut seigniorage_allocations,
                 public_key,
@@ -533,9 +654,10 @@ pub trait Auction:
             // TODO: add "mint into existing purse" facility
             let tmp_validator_reward_purse =
                 self.mint(validator_reward).map_err(|_| Error::MintReward)?;
-            self.transfer_purse_to_purse(
+            detail::transfer_reward(
+                self,
                 tmp_validator_reward_purse,
-                validator_bonding_purse,
+                validator_reward_target,
                 validator_reward,
             )
             .map_err(|_| Error::ValidatorRewardTransfer)?;
@@ -544,16 +666,32 @@ pub trait Auction:
             let tmp_delegator_reward_purse = self
                 .mint(total_delegator_payout)
                 .map_err(|_| Error::MintReward)?;
-            for (delegator_payout, bonding_purse) in delegator_payouts {
-                self.transfer_purse_to_purse(
+            for (delegator_payout, reward_target) in delegator_payouts {
+                detail::transfer_reward(
+                    self,
                     tmp_delegator_reward_purse,
-                    bonding_purse,
+                    reward_target,
                     delegator_payout,
                 )
                 .map_err(|_| Error::DelegatorRewardTransfer)?;
             }
+
+            era_issuance += validator_reward + total_delegator_payout;
         }
 
+        *era_info.transfers_mut() = detail::process_scheduled_transfers(self, era_id)?;
+
+        let mut total_staked = U512::zero();
+        for bid in detail::get_bids(self)?.values() {
+            total_staked += bid.total_staked_amount()?;
+        }
+        *era_info.supply_mut() = EraSupply::new(
+            self.read_total_supply()?,
+            total_staked,
+            era_issuance,
+            self.read_total_burned()?,
+        );
+
         self.record_era_info(era_id, era_info)?;
 
         Ok(())
@@ -583,4 +721,188 @@ pub trait Auction:
 
         Ok(())
     }
+
+    /// Sets whether rewards are added to the stake or paid out to the main purse of the account.
+    ///
+    /// If `public_key` is the same as `validator_public_key` the setting applies to the
+    /// validator's own rewards, otherwise to the rewards of the delegator identified by
+    /// `public_key`.
+    fn set_auto_compound(
+        &mut self,
+        public_key: PublicKey,
+        validator_public_key: PublicKey,
+        auto_compound: bool,
+    ) -> Result<(), Error> {
+        let account_hash = AccountHash::from_public_key(&public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let validator_account_hash = AccountHash::from(&validator_public_key);
+        let mut bid = match self.read_bid(&validator_account_hash)? {
+            Some(bid) => bid,
+            None => return Err(Error::ValidatorNotFound),
+        };
+
+        if public_key == validator_public_key {
+            bid.set_auto_compound(auto_compound);
+        } else {
+            match bid.delegators_mut().get_mut(&public_key) {
+                Some(delegator) => delegator.set_auto_compound(auto_compound),
+                None => return Err(Error::DelegatorNotFound),
+            }
+        }
+
+        self.write_bid(validator_account_hash, bid)?;
+
+        Ok(())
+    }
+
+    /// Sets the minimum amount the validator accepts from a single delegator.
+    ///
+    /// The network-wide minimum delegation amount still applies when it is higher. Existing
+    /// delegations below the new minimum are left untouched, but can only be topped off to an
+    /// amount at or above it.
+    fn set_minimum_delegation_amount(
+        &mut self,
+        validator_public_key: PublicKey,
+        minimum_delegation_amount: U512,
+    ) -> Result<(), Error> {
+        let account_hash = AccountHash::from_public_key(&validator_public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let mut bid = match self.read_bid(&account_hash)? {
+            Some(bid) => bid,
+            None => return Err(Error::ValidatorNotFound),
+        };
+
+        bid.set_minimum_delegation_amount(minimum_delegation_amount);
+
+        self.write_bid(account_hash, bid)?;
+
+        Ok(())
+    }
+
+    /// Sets the optional name, URL and contact details displayed for the validator.
+    ///
+    /// Each call replaces all of the bid's metadata, so fields which are not provided are cleared.
+    fn set_bid_metadata(
+        &mut self,
+        validator_public_key: PublicKey,
+        name: Option<String>,
+        url: Option<String>,
+        contact: Option<String>,
+    ) -> Result<(), Error> {
+        let account_hash = AccountHash::from_public_key(&validator_public_key, |x| self.blake2b(x));
+        if self.get_caller() != account_hash {
+            return Err(Error::InvalidPublicKey);
+        }
+
+        let mut bid = match self.read_bid(&account_hash)? {
+            Some(bid) => bid,
+            None => return Err(Error::ValidatorNotFound),
+        };
+
+        bid.set_metadata(BidMetadata::new(name, url, contact)?);
+
+        self.write_bid(account_hash, bid)?;
+
+        Ok(())
+    }
+
+    /// Schedules a transfer of `amount` from the caller's main purse to the `target` purse, which
+    /// the system executes at the end of era `era_id` and then every `interval` eras, `count`
+    /// times in total.
+    ///
+    /// The amounts and execution fees of all `count` transfers are moved up front into a new
+    /// escrow purse. The address of the escrow purse identifies the schedule and is returned.
+    fn schedule_transfer(
+        &mut self,
+        target: URef,
+        amount: U512,
+        era_id: EraId,
+        interval: u64,
+        count: u64,
+        id: Option<u64>,
+    ) -> Result<URefAddr, Error> {
+        if !target.is_addable() {
+            return Err(Error::InvalidTransferSchedule);
+        }
+
+        if amount.is_zero() {
+            return Err(Error::InvalidAmount);
+        }
+
+        if count == 0 || (count > 1 && interval == 0) || era_id < detail::get_era_id(self)? {
+            return Err(Error::InvalidTransferSchedule);
+        }
+
+        let mut scheduled_transfers = detail::get_scheduled_transfers(self)?;
+        if scheduled_transfers.len() >= MAX_SCHEDULED_TRANSFERS {
+            return Err(Error::TooManyScheduledTransfers);
+        }
+
+        let fee = self.scheduled_transfer_fee();
+        let escrow_amount = amount
+            .checked_add(fee)
+            .and_then(|execution_amount| execution_amount.checked_mul(U512::from(count)))
+            .ok_or(Error::InvalidAmount)?;
+
+        let source = self.get_main_purse()?;
+        let escrow_purse = self.create_purse()?;
+        self.transfer_purse_to_purse(source, escrow_purse, escrow_amount)
+            .map_err(|_| Error::Transfer)?;
+
+        let scheduled_transfer = ScheduledTransfer::new(
+            self.get_caller(),
+            source,
+            escrow_purse,
+            target.with_access_rights(AccessRights::ADD),
+            amount,
+            fee,
+            era_id,
+            interval,
+            count,
+            id,
+        );
+
+        scheduled_transfers.insert(escrow_purse.addr(), scheduled_transfer);
+        detail::set_scheduled_transfers(self, scheduled_transfers)?;
+
+        Ok(escrow_purse.addr())
+    }
+
+    /// Cancels the remaining executions of the scheduled transfer identified by the address of
+    /// its escrow purse, and refunds what is left in the escrow purse to the purse it was funded
+    /// from. Returns the refunded amount.
+    ///
+    /// Only the account which scheduled the transfer can cancel it.
+    fn cancel_scheduled_transfer(&mut self, escrow_purse: URefAddr) -> Result<U512, Error> {
+        let mut scheduled_transfers = detail::get_scheduled_transfers(self)?;
+
+        let scheduled_transfer = match scheduled_transfers.remove(&escrow_purse) {
+            Some(scheduled_transfer) if *scheduled_transfer.owner() == self.get_caller() => {
+                scheduled_transfer
+            }
+            _ => return Err(Error::ScheduledTransferNotFound),
+        };
+
+        let refund_amount = self
+            .balance(*scheduled_transfer.escrow_purse())?
+            .unwrap_or_default();
+        if !refund_amount.is_zero() {
+            self.transfer_purse_to_purse(
+                *scheduled_transfer.escrow_purse(),
+                *scheduled_transfer.source(),
+                refund_amount,
+            )
+            .map_err(|_| Error::Transfer)?;
+        }
+
+        detail::set_scheduled_transfers(self, scheduled_transfers)?;
+
+        Ok(refund_amount)
+    }
 }
@@ -4,7 +4,7 @@ use crate::{
     account::AccountHash,
     bytesrepr::{FromBytes, ToBytes},
     system::auction::{Bid, EraId, EraInfo, Error},
-    CLTyped, Key, KeyTag, TransferredTo, URef, BLAKE2B_DIGEST_LENGTH, U512,
+    CLTyped, Key, KeyTag, TransferAddr, TransferredTo, URef, BLAKE2B_DIGEST_LENGTH, U512,
 };
 
 /// Provider of runtime host functionality.
@@ -15,6 +15,9 @@ pub trait RuntimeProvider {
     /// Gets named key under a `name`.
     fn named_keys_get(&self, name: &str) -> Option<Key>;
 
+    /// Stores the given [`Key`] under `name`.
+    fn put_key(&mut self, name: &str, key: Key) -> Result<(), Error>;
+
     /// Gets keys in a given keyspace
     fn get_keys(&mut self, key_tag: &KeyTag) -> Result<BTreeSet<Key>, Error>;
 
@@ -24,6 +27,9 @@ pub trait RuntimeProvider {
 
 /// Provides functionality of a contract storage.
 pub trait StorageProvider {
+    /// Creates a new [`URef`] holding the given value.
+    fn new_uref<T: ToBytes + CLTyped>(&mut self, init: T) -> Result<URef, Error>;
+
     /// Reads data from [`URef`].
     fn read<T: FromBytes + CLTyped>(&mut self, uref: URef) -> Result<Option<T>, Error>;
 
@@ -55,6 +61,9 @@ pub trait SystemProvider {
 
     /// Records era info at the given era id.
     fn record_era_info(&mut self, era_id: EraId, era_info: EraInfo) -> Result<(), Error>;
+
+    /// Returns the fee charged for each execution of a scheduled transfer.
+    fn scheduled_transfer_fee(&self) -> U512;
 }
 
 /// Provides an access to mint.
@@ -75,12 +84,31 @@ pub trait MintProvider {
         amount: U512,
     ) -> Result<(), Error>;
 
+    /// Transfers `amount` from `source` purse to a `target` purse, recording the transfer under
+    /// the given `id`. Returns the address of the recorded transfer, if any.
+    fn transfer_purse_to_purse_with_id(
+        &mut self,
+        source: URef,
+        target: URef,
+        amount: U512,
+        id: Option<u64>,
+    ) -> Result<Option<TransferAddr>, Error>;
+
     /// Checks balance of a `purse`. Returns `None` if given purse does not exist.
     fn balance(&mut self, purse: URef) -> Result<Option<U512>, Error>;
 
+    /// Burns `amount` from the given `purse`, reducing the total supply accordingly.
+    fn burn(&mut self, purse: URef, amount: U512) -> Result<(), Error>;
+
     /// Reads the base round reward.
     fn read_base_round_reward(&mut self) -> Result<U512, Error>;
 
+    /// Reads the total supply.
+    fn read_total_supply(&mut self) -> Result<U512, Error>;
+
+    /// Reads the cumulative amount by which the total supply was reduced.
+    fn read_total_burned(&mut self) -> Result<U512, Error>;
+
     /// Mints new token with given `initial_balance` balance. Returns new purse on success,
     /// otherwise an error.
     fn mint(&mut self, amount: U512) -> Result<URef, Error>;
@@ -0,0 +1,124 @@
+use alloc::vec::Vec;
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, PublicKey, U512,
+};
+
+/// A pending request to move part of a delegator's stake from one validator to another.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Redelegation {
+    /// Delegators public key.
+    delegator_public_key: PublicKey,
+    /// Public key of the validator the stake is currently delegated to.
+    validator_public_key: PublicKey,
+    /// Public key of the validator the stake will be delegated to.
+    new_validator_public_key: PublicKey,
+    /// Redelegated amount.
+    amount: U512,
+}
+
+impl Redelegation {
+    /// Creates [`Redelegation`] instance for a redelegation request.
+    pub const fn new(
+        delegator_public_key: PublicKey,
+        validator_public_key: PublicKey,
+        new_validator_public_key: PublicKey,
+        amount: U512,
+    ) -> Self {
+        Self {
+            delegator_public_key,
+            validator_public_key,
+            new_validator_public_key,
+            amount,
+        }
+    }
+
+    /// Returns public key of delegator.
+    pub fn delegator_public_key(&self) -> &PublicKey {
+        &self.delegator_public_key
+    }
+
+    /// Returns public key of the validator the stake is moved away from.
+    pub fn validator_public_key(&self) -> &PublicKey {
+        &self.validator_public_key
+    }
+
+    /// Returns public key of the validator the stake is moved to.
+    pub fn new_validator_public_key(&self) -> &PublicKey {
+        &self.new_validator_public_key
+    }
+
+    /// Returns redelegated amount.
+    pub fn amount(&self) -> &U512 {
+        &self.amount
+    }
+}
+
+impl ToBytes for Redelegation {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(&self.delegator_public_key.to_bytes()?);
+        result.extend(&self.validator_public_key.to_bytes()?);
+        result.extend(&self.new_validator_public_key.to_bytes()?);
+        result.extend(&self.amount.to_bytes()?);
+        Ok(result)
+    }
+    fn serialized_length(&self) -> usize {
+        self.delegator_public_key.serialized_length()
+            + self.validator_public_key.serialized_length()
+            + self.new_validator_public_key.serialized_length()
+            + self.amount.serialized_length()
+    }
+}
+
+impl FromBytes for Redelegation {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (delegator_public_key, bytes) = FromBytes::from_bytes(bytes)?;
+        let (validator_public_key, bytes) = FromBytes::from_bytes(bytes)?;
+        let (new_validator_public_key, bytes) = FromBytes::from_bytes(bytes)?;
+        let (amount, bytes) = FromBytes::from_bytes(bytes)?;
+        Ok((
+            Redelegation {
+                delegator_public_key,
+                validator_public_key,
+                new_validator_public_key,
+                amount,
+            },
+            bytes,
+        ))
+    }
+}
+
+impl CLTyped for Redelegation {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use once_cell::sync::Lazy;
+
+    use crate::{bytesrepr, system::auction::Redelegation, PublicKey, SecretKey, U512};
+
+    static DELEGATOR_PUBLIC_KEY: Lazy<PublicKey> =
+        Lazy::new(|| SecretKey::ed25519([41; SecretKey::ED25519_LENGTH]).into());
+    static VALIDATOR_PUBLIC_KEY: Lazy<PublicKey> =
+        Lazy::new(|| SecretKey::ed25519([42; SecretKey::ED25519_LENGTH]).into());
+    static NEW_VALIDATOR_PUBLIC_KEY: Lazy<PublicKey> =
+        Lazy::new(|| SecretKey::ed25519([43; SecretKey::ED25519_LENGTH]).into());
+    static AMOUNT: Lazy<U512> = Lazy::new(|| U512::max_value() - 1);
+
+    #[test]
+    fn serialization_roundtrip() {
+        let redelegation = Redelegation::new(
+            *DELEGATOR_PUBLIC_KEY,
+            *VALIDATOR_PUBLIC_KEY,
+            *NEW_VALIDATOR_PUBLIC_KEY,
+            *AMOUNT,
+        );
+
+        bytesrepr::test_serialization_roundtrip(&redelegation);
+    }
+}
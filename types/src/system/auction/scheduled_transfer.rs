@@ -0,0 +1,235 @@
+use alloc::vec::Vec;
+
+use crate::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes},
+    system::auction::EraId,
+    CLType, CLTyped, URef, U512,
+};
+
+/// A transfer executed by the system at the end of one or more eras, funded from an escrow purse
+/// which holds the transferred amounts and the fees of all remaining executions.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ScheduledTransfer {
+    /// Account which scheduled the transfer.
+    owner: AccountHash,
+    /// Purse the escrow was funded from, which receives any refund.
+    source: URef,
+    /// Purse holding the funds of the remaining executions.
+    escrow_purse: URef,
+    /// Purse receiving the transfers.
+    target: URef,
+    /// Amount transferred by each execution.
+    amount: U512,
+    /// Fee charged from the escrow purse for each execution.
+    fee: U512,
+    /// Era at the end of which the next execution is due.
+    next_era_id: EraId,
+    /// Number of eras between executions.
+    interval: u64,
+    /// Number of executions left.
+    remaining: u64,
+    /// User-defined id recorded in each executed transfer.
+    id: Option<u64>,
+}
+
+impl ScheduledTransfer {
+    /// Creates a new [`ScheduledTransfer`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        owner: AccountHash,
+        source: URef,
+        escrow_purse: URef,
+        target: URef,
+        amount: U512,
+        fee: U512,
+        next_era_id: EraId,
+        interval: u64,
+        remaining: u64,
+        id: Option<u64>,
+    ) -> Self {
+        ScheduledTransfer {
+            owner,
+            source,
+            escrow_purse,
+            target,
+            amount,
+            fee,
+            next_era_id,
+            interval,
+            remaining,
+            id,
+        }
+    }
+
+    /// Returns the account which scheduled the transfer.
+    pub fn owner(&self) -> &AccountHash {
+        &self.owner
+    }
+
+    /// Returns the purse the escrow was funded from.
+    pub fn source(&self) -> &URef {
+        &self.source
+    }
+
+    /// Returns the purse holding the funds of the remaining executions.
+    pub fn escrow_purse(&self) -> &URef {
+        &self.escrow_purse
+    }
+
+    /// Returns the purse receiving the transfers.
+    pub fn target(&self) -> &URef {
+        &self.target
+    }
+
+    /// Returns the amount transferred by each execution.
+    pub fn amount(&self) -> &U512 {
+        &self.amount
+    }
+
+    /// Returns the fee charged for each execution.
+    pub fn fee(&self) -> &U512 {
+        &self.fee
+    }
+
+    /// Returns the era at the end of which the next execution is due.
+    pub fn next_era_id(&self) -> EraId {
+        self.next_era_id
+    }
+
+    /// Returns the number of eras between executions.
+    pub fn interval(&self) -> u64 {
+        self.interval
+    }
+
+    /// Returns the number of executions left.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Returns the user-defined id recorded in each executed transfer.
+    pub fn id(&self) -> Option<u64> {
+        self.id
+    }
+
+    /// Checks if an execution is due at the end of the given era.
+    pub fn is_due(&self, era_id: EraId) -> bool {
+        self.remaining > 0 && self.next_era_id <= era_id
+    }
+
+    /// Moves the schedule past one execution.
+    pub fn advance(&mut self) {
+        self.remaining = self.remaining.saturating_sub(1);
+        self.next_era_id = self.next_era_id.saturating_add(self.interval);
+    }
+}
+
+impl CLTyped for ScheduledTransfer {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for ScheduledTransfer {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.owner.to_bytes()?);
+        result.extend(self.source.to_bytes()?);
+        result.extend(self.escrow_purse.to_bytes()?);
+        result.extend(self.target.to_bytes()?);
+        result.extend(self.amount.to_bytes()?);
+        result.extend(self.fee.to_bytes()?);
+        result.extend(self.next_era_id.to_bytes()?);
+        result.extend(self.interval.to_bytes()?);
+        result.extend(self.remaining.to_bytes()?);
+        result.extend(self.id.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.owner.serialized_length()
+            + self.source.serialized_length()
+            + self.escrow_purse.serialized_length()
+            + self.target.serialized_length()
+            + self.amount.serialized_length()
+            + self.fee.serialized_length()
+            + self.next_era_id.serialized_length()
+            + self.interval.serialized_length()
+            + self.remaining.serialized_length()
+            + self.id.serialized_length()
+    }
+}
+
+impl FromBytes for ScheduledTransfer {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (owner, bytes) = FromBytes::from_bytes(bytes)?;
+        let (source, bytes) = FromBytes::from_bytes(bytes)?;
+        let (escrow_purse, bytes) = FromBytes::from_bytes(bytes)?;
+        let (target, bytes) = FromBytes::from_bytes(bytes)?;
+        let (amount, bytes) = FromBytes::from_bytes(bytes)?;
+        let (fee, bytes) = FromBytes::from_bytes(bytes)?;
+        let (next_era_id, bytes) = FromBytes::from_bytes(bytes)?;
+        let (interval, bytes) = FromBytes::from_bytes(bytes)?;
+        let (remaining, bytes) = FromBytes::from_bytes(bytes)?;
+        let (id, bytes) = FromBytes::from_bytes(bytes)?;
+        Ok((
+            ScheduledTransfer {
+                owner,
+                source,
+                escrow_purse,
+                target,
+                amount,
+                fee,
+                next_era_id,
+                interval,
+                remaining,
+                id,
+            },
+            bytes,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{account::AccountHash, bytesrepr, AccessRights, URef, U512};
+
+    use super::ScheduledTransfer;
+
+    fn scheduled_transfer() -> ScheduledTransfer {
+        ScheduledTransfer::new(
+            AccountHash::new([42; 32]),
+            URef::new([1; 32], AccessRights::ADD),
+            URef::new([2; 32], AccessRights::READ_ADD_WRITE),
+            URef::new([3; 32], AccessRights::ADD),
+            U512::from(1_000),
+            U512::from(10),
+            5,
+            2,
+            3,
+            Some(7),
+        )
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        bytesrepr::test_serialization_roundtrip(&scheduled_transfer());
+    }
+
+    #[test]
+    fn should_advance_by_interval_until_exhausted() {
+        let mut scheduled_transfer = scheduled_transfer();
+        assert!(!scheduled_transfer.is_due(4));
+        assert!(scheduled_transfer.is_due(5));
+
+        scheduled_transfer.advance();
+        assert_eq!(scheduled_transfer.next_era_id(), 7);
+        assert!(!scheduled_transfer.is_due(6));
+        assert!(scheduled_transfer.is_due(7));
+
+        scheduled_transfer.advance();
+        scheduled_transfer.advance();
+        assert_eq!(scheduled_transfer.remaining(), 0);
+        assert!(!scheduled_transfer.is_due(u64::max_value()));
+    }
+}
@@ -119,11 +119,15 @@ pub enum Error {
     /// Failed to transfer funds.
     #[fail(display = "Failed to transfer funds")]
     Transfer,
-    // NOTE: These variants below will be removed once support for WASM system contracts will be
-    // dropped.
+    // NOTE: The variant below will be removed once support for WASM system contracts will be
+    // dropped.  Its value must not change until then.
     #[doc(hidden)]
     #[fail(display = "GasLimit")]
-    GasLimit,
+    GasLimit = 31,
+    /// Internal error: while finalizing payment, failed to burn the configured share of the
+    /// payment.
+    #[fail(display = "Failed to burn payment")]
+    FailedToBurn = 32,
 }
 
 impl CLTyped for Error {
@@ -2,6 +2,7 @@
 mod constants;
 mod error;
 mod mint_provider;
+mod payment_breakdown;
 mod runtime_provider;
 
 use core::marker::Sized;
@@ -9,12 +10,10 @@ use core::marker::Sized;
 use crate::{account::AccountHash, AccessRights, URef, U512};
 
 pub use crate::system::handle_payment::{
-    constants::*, error::Error, mint_provider::MintProvider, runtime_provider::RuntimeProvider,
+    constants::*, error::Error, mint_provider::MintProvider, payment_breakdown::PaymentBreakdown,
+    runtime_provider::RuntimeProvider,
 };
 
-// A simplified representation of a refund percentage which is currently hardcoded to 0%.
-const REFUND_PERCENTAGE: U512 = U512::zero();
-
 /// Handle payment functionality implementation.
 pub trait HandlePayment: MintProvider + RuntimeProvider + Sized {
     /// Get payment purse.
@@ -38,13 +37,14 @@ pub trait HandlePayment: MintProvider + RuntimeProvider + Sized {
         Ok(maybe_purse.map(|p| p.remove_access_rights()))
     }
 
-    /// Finalize payment with `amount_spent` and a given `account`.
+    /// Finalize payment with `amount_spent` and a given `account`, returning how the payment was
+    /// distributed.
     fn finalize_payment(
         &mut self,
         amount_spent: U512,
         account: AccountHash,
         target: URef,
-    ) -> Result<(), Error> {
+    ) -> Result<PaymentBreakdown, Error> {
         internal::finalize_payment(self, amount_spent, account, target)
     }
 }
@@ -52,11 +52,11 @@ pub trait HandlePayment: MintProvider + RuntimeProvider + Sized {
 mod internal {
     use crate::{
         account::AccountHash,
-        system::handle_payment::{Error, MintProvider, RuntimeProvider},
+        system::handle_payment::{Error, MintProvider, PaymentBreakdown, RuntimeProvider},
         Key, Phase, URef, U512,
     };
 
-    use super::{PAYMENT_PURSE_KEY, REFUND_PERCENTAGE, REFUND_PURSE_KEY};
+    use super::{PAYMENT_PURSE_KEY, REFUND_PURSE_KEY};
 
     /// Account used to run system functions (in particular `finalize_payment`).
     const SYSTEM_ACCOUNT: AccountHash = AccountHash::new([0u8; 32]);
@@ -96,15 +96,16 @@ mod internal {
     }
 
     /// Transfers funds from the payment purse to the validator rewards purse, as well as to the
-    /// refund purse, depending on how much was spent on the computation. This function maintains
-    /// the invariant that the balance of the payment purse is zero at the beginning and end of each
-    /// deploy and that the refund purse is unset at the beginning and end of each deploy.
+    /// refund purse, depending on how much was spent on the computation, and burns the configured
+    /// share of the rest. This function maintains the invariant that the balance of the payment
+    /// purse is zero at the beginning and end of each deploy and that the refund purse is unset at
+    /// the beginning and end of each deploy.
     pub fn finalize_payment<P: MintProvider + RuntimeProvider>(
         provider: &mut P,
         amount_spent: U512,
         account: AccountHash,
         target: URef,
-    ) -> Result<(), Error> {
+    ) -> Result<PaymentBreakdown, Error> {
         let caller = provider.get_caller();
         if caller != SYSTEM_ACCOUNT {
             return Err(Error::SystemFunctionCalledByUserAccount);
@@ -120,43 +121,53 @@ mod internal {
             return Err(Error::InsufficientPaymentForAmountSpent);
         }
 
-        // User's part
-        let refund_amount = (total - amount_spent) * REFUND_PERCENTAGE;
-
-        // Validator reward
-        let validator_reward = total - refund_amount;
+        let breakdown = PaymentBreakdown::new(
+            total,
+            amount_spent,
+            provider.get_refund_ratio(),
+            provider.get_burn_ratio(),
+        );
 
-        // Makes sure both parts: for user, and for validator sums to the total amount in the
-        // payment's purse.
-        debug_assert_eq!(validator_reward + refund_amount, total);
+        // Makes sure all the parts: for user, for validator and burned sum to the total amount in
+        // the payment's purse.
+        debug_assert_eq!(breakdown.total(), total);
 
         let refund_purse = get_refund_purse(provider)?;
         provider.remove_key(REFUND_PURSE_KEY)?; //unset refund purse after reading it
 
         // pay target validator
         provider
-            .transfer_purse_to_purse(payment_purse, target, validator_reward)
+            .transfer_purse_to_purse(payment_purse, target, breakdown.proposer_reward)
             .map_err(|_| Error::FailedTransferToRewardsPurse)?;
 
-        if refund_amount.is_zero() {
-            return Ok(());
+        if !breakdown.burned.is_zero() {
+            provider
+                .burn(payment_purse, breakdown.burned)
+                .map_err(|_| Error::FailedToBurn)?;
+        }
+
+        if breakdown.refund.is_zero() {
+            return Ok(breakdown);
         }
 
         // give refund
         let refund_purse = match refund_purse {
             Some(uref) => uref,
-            None => return refund_to_account::<P>(provider, payment_purse, account, refund_amount),
+            None => {
+                refund_to_account::<P>(provider, payment_purse, account, breakdown.refund)?;
+                return Ok(breakdown);
+            }
         };
 
         // in case of failure to transfer to refund purse we fall back on the account's main purse
         if provider
-            .transfer_purse_to_purse(payment_purse, refund_purse, refund_amount)
+            .transfer_purse_to_purse(payment_purse, refund_purse, breakdown.refund)
             .is_err()
         {
-            return refund_to_account::<P>(provider, payment_purse, account, refund_amount);
+            refund_to_account::<P>(provider, payment_purse, account, breakdown.refund)?;
         }
 
-        Ok(())
+        Ok(breakdown)
     }
 
     pub fn refund_to_account<M: MintProvider>(
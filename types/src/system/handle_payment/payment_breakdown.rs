@@ -0,0 +1,172 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::vec::Vec;
+
+use num_rational::Ratio;
+use rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U512,
+};
+
+/// How the funds in the payment purse were distributed when a payment was finalized.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct PaymentBreakdown {
+    /// The part of the unspent payment returned to the refund purse or the account's main purse.
+    pub refund: U512,
+    /// The part of the payment transferred to the block proposer.
+    pub proposer_reward: U512,
+    /// The part of the payment removed from the total supply.
+    pub burned: U512,
+}
+
+impl PaymentBreakdown {
+    /// Splits the `total` held in the payment purse, of which `amount_spent` was spent on
+    /// execution.
+    ///
+    /// `refund_ratio` of the unspent part is refunded, then `burn_ratio` of what remains is burned
+    /// and the rest goes to the proposer.  Ratios greater than one are treated as one.
+    pub fn new(
+        total: U512,
+        amount_spent: U512,
+        refund_ratio: Ratio<u64>,
+        burn_ratio: Ratio<u64>,
+    ) -> Self {
+        let refund = apply_ratio(total.saturating_sub(amount_spent), refund_ratio);
+        let burned = apply_ratio(total - refund, burn_ratio);
+        let proposer_reward = total - refund - burned;
+        PaymentBreakdown {
+            refund,
+            proposer_reward,
+            burned,
+        }
+    }
+
+    /// Returns the sum of all the parts, i.e. the total amount which was in the payment purse.
+    pub fn total(&self) -> U512 {
+        self.refund + self.proposer_reward + self.burned
+    }
+}
+
+/// Returns `amount * ratio` rounded down, capped at `amount`.
+fn apply_ratio(amount: U512, ratio: Ratio<u64>) -> U512 {
+    if ratio.numer() >= ratio.denom() {
+        return amount;
+    }
+    let ratio = Ratio::new(U512::from(*ratio.numer()), U512::from(*ratio.denom()));
+    (Ratio::from(amount) * ratio).to_integer()
+}
+
+impl ToBytes for PaymentBreakdown {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.refund.to_bytes()?);
+        buffer.extend(self.proposer_reward.to_bytes()?);
+        buffer.extend(self.burned.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.refund.serialized_length()
+            + self.proposer_reward.serialized_length()
+            + self.burned.serialized_length()
+    }
+}
+
+impl FromBytes for PaymentBreakdown {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (refund, remainder) = U512::from_bytes(bytes)?;
+        let (proposer_reward, remainder) = U512::from_bytes(remainder)?;
+        let (burned, remainder) = U512::from_bytes(remainder)?;
+        let payment_breakdown = PaymentBreakdown {
+            refund,
+            proposer_reward,
+            burned,
+        };
+        Ok((payment_breakdown, remainder))
+    }
+}
+
+impl CLTyped for PaymentBreakdown {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl Distribution<PaymentBreakdown> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> PaymentBreakdown {
+        PaymentBreakdown {
+            refund: rng.gen::<u64>().into(),
+            proposer_reward: rng.gen::<u64>().into(),
+            burned: rng.gen::<u64>().into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_rational::Ratio;
+
+    use super::PaymentBreakdown;
+    use crate::{bytesrepr, U512};
+
+    #[test]
+    fn should_split_payment() {
+        let breakdown = PaymentBreakdown::new(
+            U512::from(1_000),
+            U512::from(600),
+            Ratio::new(1, 2),
+            Ratio::new(1, 4),
+        );
+        assert_eq!(breakdown.refund, U512::from(200));
+        assert_eq!(breakdown.burned, U512::from(200));
+        assert_eq!(breakdown.proposer_reward, U512::from(600));
+        assert_eq!(breakdown.total(), U512::from(1_000));
+    }
+
+    #[test]
+    fn should_pay_everything_to_proposer_by_default() {
+        let breakdown = PaymentBreakdown::new(
+            U512::from(1_000),
+            U512::from(600),
+            Ratio::new(0, 1),
+            Ratio::new(0, 1),
+        );
+        assert_eq!(breakdown.refund, U512::zero());
+        assert_eq!(breakdown.burned, U512::zero());
+        assert_eq!(breakdown.proposer_reward, U512::from(1_000));
+    }
+
+    #[test]
+    fn should_cap_ratios_at_one() {
+        let breakdown = PaymentBreakdown::new(
+            U512::from(1_000),
+            U512::from(600),
+            Ratio::new(3, 2),
+            Ratio::new(5, 1),
+        );
+        assert_eq!(breakdown.refund, U512::from(400));
+        assert_eq!(breakdown.burned, U512::from(600));
+        assert_eq!(breakdown.proposer_reward, U512::zero());
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        let breakdown = PaymentBreakdown {
+            refund: U512::from(1),
+            proposer_reward: U512::from(2),
+            burned: U512::from(3),
+        };
+        bytesrepr::test_serialization_roundtrip(&breakdown);
+    }
+}
@@ -1,3 +1,5 @@
+use num_rational::Ratio;
+
 use crate::{account::AccountHash, system::handle_payment::Error, BlockTime, Key, Phase};
 
 /// Provider of runtime host functionality.
@@ -19,4 +21,10 @@ pub trait RuntimeProvider {
 
     /// Get caller.
     fn get_caller(&self) -> AccountHash;
+
+    /// Get the share of unspent payment refunded to the caller.
+    fn get_refund_ratio(&self) -> Ratio<u64>;
+
+    /// Get the share of the non-refunded payment which is burned rather than paid to the proposer.
+    fn get_burn_ratio(&self) -> Ratio<u64>;
 }
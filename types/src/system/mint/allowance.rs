@@ -0,0 +1,90 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use crate::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    BlockTime, CLType, CLTyped, Key, URefAddr, U512,
+};
+
+/// Allowances granted on each purse, keyed by the purse's address and then by the spender.
+pub type Allowances = BTreeMap<URefAddr, BTreeMap<Key, Allowance>>;
+
+/// An amount a spender may transfer out of a purse it doesn't own, until the allowance expires.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Allowance {
+    /// Remaining amount the spender may transfer.
+    amount: U512,
+    /// Block time from which the allowance can no longer be used.
+    expiry: BlockTime,
+}
+
+impl Allowance {
+    /// Creates a new [`Allowance`].
+    pub fn new(amount: U512, expiry: BlockTime) -> Self {
+        Allowance { amount, expiry }
+    }
+
+    /// Returns the remaining amount the spender may transfer.
+    pub fn amount(&self) -> &U512 {
+        &self.amount
+    }
+
+    /// Returns the block time from which the allowance can no longer be used.
+    pub fn expiry(&self) -> BlockTime {
+        self.expiry
+    }
+
+    /// Checks if the allowance has expired at the given block time.
+    pub fn is_expired(&self, blocktime: BlockTime) -> bool {
+        blocktime >= self.expiry
+    }
+
+    /// Reduces the remaining amount, saturating at zero.
+    pub fn decrease(&mut self, amount: U512) {
+        self.amount = self.amount.saturating_sub(amount);
+    }
+}
+
+impl CLTyped for Allowance {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Allowance {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.extend(self.amount.to_bytes()?);
+        result.extend(self.expiry.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.amount.serialized_length() + self.expiry.serialized_length()
+    }
+}
+
+impl FromBytes for Allowance {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (amount, bytes) = FromBytes::from_bytes(bytes)?;
+        let (expiry, bytes) = FromBytes::from_bytes(bytes)?;
+        Ok((Allowance { amount, expiry }, bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialization_roundtrip() {
+        let allowance = Allowance::new(U512::from(1_000), BlockTime::new(42));
+        bytesrepr::test_serialization_roundtrip(&allowance);
+    }
+
+    #[test]
+    fn should_expire_at_expiry() {
+        let allowance = Allowance::new(U512::from(1_000), BlockTime::new(42));
+        assert!(!allowance.is_expired(BlockTime::new(41)));
+        assert!(allowance.is_expired(BlockTime::new(42)));
+    }
+}
@@ -12,6 +12,10 @@ pub const ARG_SOURCE: &str = "source";
 pub const ARG_TARGET: &str = "target";
 /// Named constant for `round_seigniorage_rate` used in installer.
 pub const ARG_ROUND_SEIGNIORAGE_RATE: &str = "round_seigniorage_rate";
+/// Named constant for `spender`.
+pub const ARG_SPENDER: &str = "spender";
+/// Named constant for `expiry`.
+pub const ARG_EXPIRY: &str = "expiry";
 
 /// Named constant for method `mint`.
 pub const METHOD_MINT: &str = "mint";
@@ -25,6 +29,18 @@ pub const METHOD_BALANCE: &str = "balance";
 pub const METHOD_TRANSFER: &str = "transfer";
 /// Named constant for method `read_base_round_reward`.
 pub const METHOD_READ_BASE_ROUND_REWARD: &str = "read_base_round_reward";
+/// Named constant for method `approve`.
+pub const METHOD_APPROVE: &str = "approve";
+/// Named constant for method `allowance`.
+pub const METHOD_ALLOWANCE: &str = "allowance";
+/// Named constant for method `transfer_from`.
+pub const METHOD_TRANSFER_FROM: &str = "transfer_from";
+/// Named constant for method `read_total_supply`.
+pub const METHOD_READ_TOTAL_SUPPLY: &str = "read_total_supply";
+/// Named constant for method `read_total_burned`.
+pub const METHOD_READ_TOTAL_BURNED: &str = "read_total_burned";
+/// Named constant for method `burn`.
+pub const METHOD_BURN: &str = "burn";
 
 /// Storage for mint contract hash.
 pub const HASH_KEY: &str = "mint_hash";
@@ -34,5 +50,9 @@ pub const ACCESS_KEY: &str = "mint_access";
 pub const BASE_ROUND_REWARD_KEY: &str = "mint_base_round_reward";
 /// Storage for mint total supply key.
 pub const TOTAL_SUPPLY_KEY: &str = "total_supply";
+/// Storage for the cumulative amount by which the total supply was reduced.
+pub const TOTAL_BURNED_KEY: &str = "total_burned";
 /// Storage for mint round seigniorage rate.
 pub const ROUND_SEIGNIORAGE_RATE_KEY: &str = "round_seigniorage_rate";
+/// Storage for the allowances granted on purses.
+pub const ALLOWANCES_KEY: &str = "allowances";
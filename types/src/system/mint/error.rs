@@ -69,12 +69,22 @@ pub enum Error {
     #[fail(display = "Invalid target purse")]
     EqualSourceAndTarget = 17,
 
-    // NOTE: These variants below will be removed once support for WASM system contracts will be
-    // dropped.
+    // NOTE: The variant below will be removed once support for WASM system contracts will be
+    // dropped.  Its value must not change until then.
     #[doc(hidden)]
     #[fail(display = "GasLimit")]
     GasLimit = 18,
 
+    /// The spender has not been granted an allowance on the source purse.
+    #[fail(display = "Allowance not found")]
+    AllowanceNotFound = 19,
+    /// The allowance on the source purse has expired.
+    #[fail(display = "Allowance expired")]
+    AllowanceExpired = 20,
+    /// The amount exceeds the remaining allowance on the source purse.
+    #[fail(display = "Insufficient allowance")]
+    InsufficientAllowance = 21,
+
     #[cfg(test)]
     #[doc(hidden)]
     #[fail(display = "Sentinel error")]
@@ -139,6 +149,9 @@ impl TryFrom<u8> for Error {
             d if d == Error::Serialize as u8 => Ok(Error::Serialize),
             d if d == Error::EqualSourceAndTarget as u8 => Ok(Error::EqualSourceAndTarget),
             d if d == Error::GasLimit as u8 => Ok(Error::GasLimit),
+            d if d == Error::AllowanceNotFound as u8 => Ok(Error::AllowanceNotFound),
+            d if d == Error::AllowanceExpired as u8 => Ok(Error::AllowanceExpired),
+            d if d == Error::InsufficientAllowance as u8 => Ok(Error::InsufficientAllowance),
             _ => Err(TryFromU8ForError(())),
         }
     }
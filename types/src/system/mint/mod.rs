@@ -1,4 +1,5 @@
 //! Contains implementation of a Mint contract functionality.
+mod allowance;
 mod constants;
 mod error;
 mod runtime_provider;
@@ -7,11 +8,15 @@ mod system_provider;
 
 use num_rational::Ratio;
 
-use crate::{account::AccountHash, Key, URef, U512};
+use crate::{account::AccountHash, AccessRights, BlockTime, Key, URef, URefAddr, U512};
 
 pub use crate::system::mint::{
-    constants::*, error::Error, runtime_provider::RuntimeProvider,
-    storage_provider::StorageProvider, system_provider::SystemProvider,
+    allowance::{Allowance, Allowances},
+    constants::*,
+    error::Error,
+    runtime_provider::RuntimeProvider,
+    storage_provider::StorageProvider,
+    system_provider::SystemProvider,
 };
 
 const SYSTEM_ACCOUNT: AccountHash = AccountHash::new([0; 32]);
@@ -58,27 +63,25 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
             return Err(Error::InvalidTotalSupplyReductionAttempt);
         }
 
-        if amount.is_zero() {
-            return Ok(()); // no change to supply
-        }
+        decrease_total_supply(self, amount)
+    }
 
-        // get total supply or error
-        let total_supply_uref = match self.get_key(TOTAL_SUPPLY_KEY) {
-            Some(Key::URef(uref)) => uref,
-            Some(_) => return Err(Error::MissingKey), // TODO
-            None => return Err(Error::MissingKey),
+    /// Burns `amount` of tokens from `purse`, reducing the total supply by the same amount.
+    ///
+    /// Only a holder of the purse's [`URef`] with write access can burn from it.
+    fn burn(&mut self, purse: URef, amount: U512) -> Result<(), Error> {
+        if !purse.is_writeable() {
+            return Err(Error::InvalidAccessRights);
+        }
+        let balance: U512 = match self.read_balance(purse)? {
+            Some(balance) => balance,
+            None => return Err(Error::PurseNotFound),
         };
-        let total_supply: U512 = self
-            .read(total_supply_uref)?
-            .ok_or(Error::TotalSupplyNotFound)?;
-
-        // decrease total supply
-        let reduced_total_supply = total_supply - amount;
-
-        // update total supply
-        self.write(total_supply_uref, reduced_total_supply)?;
-
-        Ok(())
+        if amount > balance {
+            return Err(Error::InsufficientFunds);
+        }
+        self.write_balance(purse, balance - amount)?;
+        decrease_total_supply(self, amount)
     }
 
     /// Read balance of given `purse`.
@@ -117,6 +120,132 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
         Ok(())
     }
 
+    /// Grants `spender` an allowance to transfer up to `amount` of tokens out of `purse` until the
+    /// block time reaches `expiry`, replacing any previous allowance. An `amount` of zero revokes
+    /// the allowance.
+    ///
+    /// Only the owner of the purse, i.e. a holder of its [`URef`] with write access, can grant an
+    /// allowance on it.
+    fn approve(
+        &mut self,
+        purse: URef,
+        spender: Key,
+        amount: U512,
+        expiry: BlockTime,
+    ) -> Result<(), Error> {
+        if !purse.is_writeable() {
+            return Err(Error::InvalidAccessRights);
+        }
+        if self.read_balance(purse)?.is_none() {
+            return Err(Error::PurseNotFound);
+        }
+
+        let mut allowances = read_allowances(self)?;
+        let purse_allowances = allowances.entry(purse.addr()).or_default();
+        if amount.is_zero() {
+            purse_allowances.remove(&spender);
+        } else {
+            purse_allowances.insert(spender, Allowance::new(amount, expiry));
+        }
+        if purse_allowances.is_empty() {
+            allowances.remove(&purse.addr());
+        }
+        write_allowances(self, allowances)
+    }
+
+    /// Returns the amount `spender` may still transfer out of the purse at address `purse`, or zero
+    /// if it has no allowance or the allowance has expired.
+    fn allowance(&mut self, purse: URefAddr, spender: Key) -> Result<U512, Error> {
+        let blocktime = self.get_blocktime();
+        let allowances = read_allowances(self)?;
+        let remaining = allowances
+            .get(&purse)
+            .and_then(|purse_allowances| purse_allowances.get(&spender))
+            .filter(|allowance| !allowance.is_expired(blocktime))
+            .map(|allowance| *allowance.amount())
+            .unwrap_or_default();
+        Ok(remaining)
+    }
+
+    /// Transfers `amount` of tokens from the purse at address `source` to a `target` purse, on
+    /// behalf of a `spender` which has been granted an allowance on the source purse.
+    ///
+    /// The spender doesn't need to hold the source purse's [`URef`]. The transferred amount is
+    /// deducted from the allowance.
+    fn transfer_from(
+        &mut self,
+        spender: Key,
+        maybe_to: Option<AccountHash>,
+        source: URefAddr,
+        target: URef,
+        amount: U512,
+        id: Option<u64>,
+    ) -> Result<(), Error> {
+        if !target.is_addable() {
+            return Err(Error::InvalidAccessRights);
+        }
+
+        let blocktime = self.get_blocktime();
+        let mut allowances = read_allowances(self)?;
+        let purse_allowances = allowances
+            .get_mut(&source)
+            .ok_or(Error::AllowanceNotFound)?;
+        let allowance = purse_allowances
+            .get_mut(&spender)
+            .ok_or(Error::AllowanceNotFound)?;
+        if allowance.is_expired(blocktime) {
+            return Err(Error::AllowanceExpired);
+        }
+        if amount > *allowance.amount() {
+            return Err(Error::InsufficientAllowance);
+        }
+        allowance.decrease(amount);
+        if allowance.amount().is_zero() {
+            purse_allowances.remove(&spender);
+        }
+        if purse_allowances.is_empty() {
+            allowances.remove(&source);
+        }
+
+        let source = URef::new(source, AccessRights::NONE);
+        let source_balance: U512 = match self.read_balance(source)? {
+            Some(source_balance) => source_balance,
+            None => return Err(Error::SourceNotFound),
+        };
+        if amount > source_balance {
+            return Err(Error::InsufficientFunds);
+        }
+        if self.read_balance(target)?.is_none() {
+            return Err(Error::DestNotFound);
+        }
+        write_allowances(self, allowances)?;
+        self.write_balance(source, source_balance - amount)?;
+        self.add_balance(target, amount)?;
+        self.record_transfer(maybe_to, source, target, amount, id)?;
+        Ok(())
+    }
+
+    /// Retrieves the total supply.
+    fn read_total_supply(&mut self) -> Result<U512, Error> {
+        let total_supply_uref = match self.get_key(TOTAL_SUPPLY_KEY) {
+            Some(Key::URef(uref)) => uref,
+            Some(_) => return Err(Error::MissingKey),
+            None => return Err(Error::MissingKey),
+        };
+        self.read(total_supply_uref)?
+            .ok_or(Error::TotalSupplyNotFound)
+    }
+
+    /// Retrieves the cumulative amount by which the total supply was reduced, which is zero until
+    /// the first reduction.
+    fn read_total_burned(&mut self) -> Result<U512, Error> {
+        match self.get_key(TOTAL_BURNED_KEY) {
+            Some(Key::URef(uref)) => Ok(self.read(uref)?.unwrap_or_default()),
+            Some(_) => Err(Error::MissingKey),
+            None => Ok(U512::zero()),
+        }
+    }
+
     /// Retrieves the base round reward.
     fn read_base_round_reward(&mut self) -> Result<U512, Error> {
         let total_supply_uref = match self.get_key(TOTAL_SUPPLY_KEY) {
@@ -142,3 +271,68 @@ pub trait Mint: RuntimeProvider + StorageProvider + SystemProvider {
         Ok(ret)
     }
 }
+
+/// Decreases the total supply by `amount` and adds it to the total burned.
+fn decrease_total_supply<P>(provider: &mut P, amount: U512) -> Result<(), Error>
+where
+    P: RuntimeProvider + StorageProvider + ?Sized,
+{
+    if amount.is_zero() {
+        return Ok(()); // no change to supply
+    }
+
+    // get total supply or error
+    let total_supply_uref = match provider.get_key(TOTAL_SUPPLY_KEY) {
+        Some(Key::URef(uref)) => uref,
+        Some(_) => return Err(Error::MissingKey), // TODO
+        None => return Err(Error::MissingKey),
+    };
+    let total_supply: U512 = provider
+        .read(total_supply_uref)?
+        .ok_or(Error::TotalSupplyNotFound)?;
+
+    // decrease total supply
+    let reduced_total_supply = total_supply - amount;
+
+    // update total supply
+    provider.write(total_supply_uref, reduced_total_supply)?;
+
+    // get total burned uref if exists, otherwise create it.
+    match provider.get_key(TOTAL_BURNED_KEY) {
+        None => {
+            let uref: URef = provider.new_uref(amount)?;
+            provider.put_key(TOTAL_BURNED_KEY, uref.into())?;
+        }
+        Some(Key::URef(uref)) => provider.add(uref, amount)?,
+        Some(_) => return Err(Error::MissingKey),
+    }
+
+    Ok(())
+}
+
+/// Reads the allowances granted on all purses, which are stored lazily on first use.
+fn read_allowances<P>(provider: &mut P) -> Result<Allowances, Error>
+where
+    P: RuntimeProvider + StorageProvider + ?Sized,
+{
+    match provider.get_key(ALLOWANCES_KEY) {
+        Some(Key::URef(uref)) => Ok(provider.read(uref)?.unwrap_or_default()),
+        Some(_) => Err(Error::MissingKey),
+        None => Ok(Allowances::new()),
+    }
+}
+
+/// Writes the allowances granted on all purses, creating their storage on first use.
+fn write_allowances<P>(provider: &mut P, allowances: Allowances) -> Result<(), Error>
+where
+    P: RuntimeProvider + StorageProvider + ?Sized,
+{
+    match provider.get_key(ALLOWANCES_KEY) {
+        Some(Key::URef(uref)) => provider.write(uref, allowances),
+        Some(_) => Err(Error::MissingKey),
+        None => {
+            let uref = provider.new_uref(allowances)?;
+            provider.put_key(ALLOWANCES_KEY, uref.into())
+        }
+    }
+}
@@ -1,10 +1,13 @@
-use crate::{account::AccountHash, system::mint::Error, Key};
+use crate::{account::AccountHash, system::mint::Error, BlockTime, Key};
 
 /// Provider of runtime host functionality.
 pub trait RuntimeProvider {
     /// This method should return the caller of the current context.
     fn get_caller(&self) -> AccountHash;
 
+    /// This method should return the block time of the current context.
+    fn get_blocktime(&self) -> BlockTime;
+
     /// This method should handle storing given [`Key`] under `name`.
     fn put_key(&mut self, name: &str, key: Key) -> Result<(), Error>;
 
@@ -23,6 +23,7 @@ fi
 # Build client side contracts.
 make build-contract-rs/add-bid
 make build-contract-rs/delegate
+make build-contract-rs/redelegate
 make build-contract-rs/transfer-to-account-u512
 make build-contract-rs/transfer-to-account-u512-stored
 make build-contract-rs/undelegate
@@ -25,6 +25,7 @@ export NCTL_BASE_PORT_NETWORK=34452
 export NCTL_CONTRACTS_CLIENT=(
     add_bid.wasm
     delegate.wasm
+    redelegate.wasm
     transfer_to_account_u512.wasm
     transfer_to_account_u512_stored.wasm
     undelegate.wasm